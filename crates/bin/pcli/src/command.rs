@@ -1,7 +1,9 @@
 pub use debug::DebugCmd;
 pub use init::InitCmd;
 pub use migrate::MigrateCmd;
+pub use monitor_client::MonitorClientCmd;
 pub use query::QueryCmd;
+pub use relay::RelayCmd;
 pub use threshold::ThresholdCmd;
 pub use tx::TxCmd;
 pub use validator::ValidatorCmd;
@@ -12,7 +14,9 @@ use self::tx::TxCmdWithOptions;
 mod debug;
 mod init;
 mod migrate;
+mod monitor_client;
 mod query;
+mod relay;
 mod threshold;
 mod tx;
 mod utils;
@@ -60,6 +64,12 @@ pub enum Command {
     /// Migrate your balance to another wallet.
     #[clap(subcommand, display_order = 600)]
     Migrate(MigrateCmd),
+    /// Relay the packets pending on an IBC channel, in both directions.
+    #[clap(display_order = 700)]
+    Relay(RelayCmd),
+    /// Monitor one of Penumbra's IBC clients for misbehaviour by its counterparty chain.
+    #[clap(display_order = 800)]
+    MonitorClient(MonitorClientCmd),
     /// Manage a validator.
     #[clap(subcommand, display_order = 900)]
     Validator(ValidatorCmd),
@@ -80,6 +90,8 @@ impl Command {
             Command::Debug(cmd) => cmd.offline(),
             Command::Threshold(cmd) => cmd.offline(),
             Command::Migrate(_) => false,
+            Command::Relay(cmd) => cmd.offline(),
+            Command::MonitorClient(cmd) => cmd.offline(),
         }
     }
 }
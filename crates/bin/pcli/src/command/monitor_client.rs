@@ -0,0 +1,165 @@
+use std::{str::FromStr as _, time::Duration};
+
+use anyhow::{Context, Result};
+use ibc_types::core::client::ClientId;
+use penumbra_ibc::{monitor::ClientMonitor, IbcRelay};
+use penumbra_keys::keys::AddressIndex;
+use penumbra_proto::view::v1::GasPricesRequest;
+use penumbra_view::ViewClient;
+use penumbra_wallet::plan::Planner;
+use rand_core::OsRng;
+use url::Url;
+
+use super::relay::CometBft;
+use crate::App;
+
+/// Monitor one of Penumbra's IBC clients for misbehaviour by its counterparty chain.
+///
+/// This compares the consensus states stored by the client against the headers served by the
+/// counterparty chain's CometBFT RPC endpoint, which should be one you trust. When a conflict is
+/// found, evidence of the misbehaviour is submitted to freeze the client, in a transaction paid
+/// for by the wallet.
+///
+/// Penumbra's CometBFT node must index transactions, so that the headers which installed
+/// conflicting consensus states can be found.
+///
+/// Consensus states which can't be checked, for instance because the counterparty's node has
+/// pruned their heights, are skipped with a warning. When watching the client, they are checked
+/// again at the next interval, as is the client after a failed check.
+#[derive(Debug, clap::Parser)]
+pub struct MonitorClientCmd {
+    /// The client to monitor, e.g. `07-tendermint-0`.
+    /// You can search for clients via e.g. `pcli query ibc clients`.
+    #[clap(long)]
+    pub client_id: String,
+    /// The URL of the counterparty chain's CometBFT RPC endpoint.
+    #[clap(long)]
+    pub counterparty_rpc_url: Url,
+    /// The URL of Penumbra's CometBFT RPC endpoint, used to look up the client's updates.
+    #[clap(long)]
+    pub rpc_url: Url,
+    /// Keep monitoring the client until it is frozen, rather than checking it once.
+    #[clap(long)]
+    pub watch: bool,
+    /// The number of seconds to wait between checks when watching the client.
+    #[clap(long, default_value = "60")]
+    pub interval: u64,
+    /// Only report conflicts, without submitting evidence of misbehaviour.
+    #[clap(long)]
+    pub alert_only: bool,
+    /// The account paying for the misbehaviour transaction.
+    #[clap(long, default_value = "0")]
+    pub source: u32,
+}
+
+impl MonitorClientCmd {
+    pub async fn exec(&self, app: &mut App) -> Result<()> {
+        let client_id = ClientId::from_str(&self.client_id)
+            .with_context(|| format!("invalid client id {}", self.client_id))?;
+        let signer = app
+            .config
+            .full_viewing_key
+            .incoming()
+            .payment_address(AddressIndex::new(self.source))
+            .0
+            .to_string();
+        let mut monitor = ClientMonitor::new(
+            app.pd_channel().await?,
+            client_id.clone(),
+            signer,
+            CometBft::new(&self.counterparty_rpc_url)?,
+            CometBft::new(&self.rpc_url)?,
+        );
+
+        loop {
+            let conflicts = match monitor.check().await {
+                Ok(conflicts) => conflicts,
+                Err(error) if self.watch => {
+                    eprintln!(
+                        "failed to check {client_id}, retrying in {}s: {error:#}",
+                        self.interval
+                    );
+                    tokio::time::sleep(Duration::from_secs(self.interval)).await;
+                    continue;
+                }
+                Err(error) => return Err(error),
+            };
+            if conflicts.is_empty() {
+                println!("no conflicts found for {client_id}");
+            }
+
+            for conflict in conflicts {
+                println!(
+                    "ALERT: {client_id} stores a consensus state at {} \
+                     which conflicts with the counterparty's header",
+                    conflict.height
+                );
+                println!(
+                    "  stored root: {}",
+                    hex::encode(&conflict.consensus_state.root.hash)
+                );
+                println!(
+                    "  counterparty root: {}",
+                    hex::encode(conflict.header.signed_header.header.app_hash.as_bytes())
+                );
+
+                let Some(misbehaviour) = conflict.misbehaviour else {
+                    println!(
+                        "  the header which installed this consensus state was not found, \
+                         so no evidence can be submitted"
+                    );
+                    continue;
+                };
+                if self.alert_only {
+                    continue;
+                }
+
+                println!("submitting evidence of misbehaviour to freeze {client_id}");
+                self.submit(app, IbcRelay::SubmitMisbehavior(misbehaviour))
+                    .await?;
+                // The frozen client won't be updated further, so there is nothing left to monitor.
+                return Ok(());
+            }
+
+            if !self.watch {
+                return Ok(());
+            }
+            tokio::time::sleep(Duration::from_secs(self.interval)).await;
+        }
+    }
+
+    async fn submit(&self, app: &mut App, message: IbcRelay) -> Result<()> {
+        let gas_prices = app
+            .view
+            .as_mut()
+            .context("view service must be initialized")?
+            .gas_prices(GasPricesRequest {})
+            .await?
+            .into_inner()
+            .gas_prices
+            .expect("gas prices must be available")
+            .try_into()?;
+
+        let mut planner = Planner::new(OsRng);
+        planner
+            .set_gas_prices(gas_prices)
+            .set_fee_tier(Default::default())
+            .ibc_action(message);
+        let plan = planner
+            .plan(
+                app.view
+                    .as_mut()
+                    .context("view service must be initialized")?,
+                AddressIndex::new(self.source),
+            )
+            .await
+            .context("can't build misbehaviour transaction")?;
+        app.build_and_submit_transaction(plan).await?;
+
+        Ok(())
+    }
+
+    pub fn offline(&self) -> bool {
+        false
+    }
+}
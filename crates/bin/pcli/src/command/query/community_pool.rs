@@ -1,9 +1,14 @@
 use crate::App;
 use anyhow::{Context, Result};
+use comfy_table::{presets, Table};
 use futures::TryStreamExt;
 use penumbra_asset::Value;
+use penumbra_community_pool::VestingGrant;
+use penumbra_num::Amount;
 use penumbra_proto::{
-    core::component::community_pool::v1::CommunityPoolAssetBalancesRequest,
+    core::component::community_pool::v1::{
+        CommunityPoolAssetBalancesRequest, VestingGrantsRequest,
+    },
     penumbra::core::component::community_pool::v1::query_service_client::QueryServiceClient as CommunityPoolQueryServiceClient,
 };
 use penumbra_view::ViewClient;
@@ -16,12 +21,20 @@ pub enum CommunityPoolCmd {
         /// Get only the balance of the specified asset.
         asset: Option<String>,
     },
+    /// List the vesting grants funded by the Community Pool, or only the specified grants.
+    VestingGrants {
+        /// The IDs of the grants to show.
+        grant_ids: Vec<u64>,
+    },
 }
 
 impl CommunityPoolCmd {
     pub async fn exec(&self, app: &mut App) -> Result<()> {
         match self {
             CommunityPoolCmd::Balance { asset } => self.print_balance(app, asset).await,
+            CommunityPoolCmd::VestingGrants { grant_ids } => {
+                self.print_vesting_grants(app, grant_ids).await
+            }
         }
     }
 
@@ -63,4 +76,70 @@ impl CommunityPoolCmd {
 
         Ok(())
     }
+
+    pub async fn print_vesting_grants(&self, app: &mut App, grant_ids: &[u64]) -> Result<()> {
+        let mut client = CommunityPoolQueryServiceClient::new(app.pd_channel().await?);
+        let responses = client
+            .vesting_grants(VestingGrantsRequest {
+                grant_ids: grant_ids.to_vec(),
+            })
+            .await?
+            .into_inner()
+            .try_collect::<Vec<_>>()
+            .await
+            .context("cannot process vesting grant data")?;
+
+        let asset_cache = app.view().assets().await?;
+        let mut table = Table::new();
+        table.load_preset(presets::NOTHING);
+        table.set_header(vec![
+            "ID",
+            "Recipient",
+            "Total",
+            "Per Epoch",
+            "Cliff Epoch",
+            "Claimed",
+            "Claimable",
+            "Status",
+        ]);
+        for response in responses {
+            let grant: VestingGrant = response
+                .grant
+                .context("grant should always be set")?
+                .try_into()
+                .context("cannot parse vesting grant")?;
+            let claimable: Amount = response
+                .claimable
+                .context("claimable amount should always be set")?
+                .try_into()
+                .context("cannot parse claimable amount")?;
+            let format = |amount: Amount| {
+                Value {
+                    amount,
+                    asset_id: grant.total.asset_id,
+                }
+                .format(&asset_cache)
+            };
+            let status = if grant.cancelled {
+                "cancelled"
+            } else if grant.is_exhausted() {
+                "exhausted"
+            } else {
+                "active"
+            };
+            table.add_row(vec![
+                grant.id.to_string(),
+                grant.recipient.display_short_form(),
+                grant.total.format(&asset_cache),
+                format(grant.release_per_epoch),
+                grant.cliff_epoch_index.to_string(),
+                format(grant.claimed),
+                format(claimable),
+                status.to_string(),
+            ]);
+        }
+        println!("{table}");
+
+        Ok(())
+    }
 }
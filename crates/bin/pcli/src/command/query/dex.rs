@@ -1,23 +1,34 @@
 use anyhow::{Context, Result};
 use comfy_table::{presets, Table};
 use futures::{Stream, StreamExt, TryStreamExt};
-use std::pin::Pin;
+use std::{collections::BTreeMap, pin::Pin};
 
 use penumbra_asset::{asset, asset::Metadata, Value};
 use penumbra_dex::{
     lp::position::{self, Position},
     BatchSwapOutputData, DirectedTradingPair, SwapExecution, TradingPair,
 };
-use penumbra_proto::core::component::{
-    dex::v1::{
-        query_service_client::QueryServiceClient as DexQueryServiceClient,
-        simulation_service_client::SimulationServiceClient, ArbExecutionRequest,
-        BatchSwapOutputDataRequest, LiquidityPositionByIdRequest, LiquidityPositionsByPriceRequest,
-        LiquidityPositionsRequest, SimulateTradeRequest, SwapExecutionRequest,
+use penumbra_num::Amount;
+use penumbra_proto::{
+    core::component::{
+        dex::v1::{
+            position_eviction_risk_request,
+            query_service_client::QueryServiceClient as DexQueryServiceClient,
+            simulation_service_client::SimulationServiceClient, ArbExecutionRequest,
+            ArbExecutionsRequest, BatchSwapOutputDataRequest, LiquidityPositionByIdRequest,
+            LiquidityPositionsByPriceRequest, LiquidityPositionsRequest,
+            PositionEvictionRiskRequest, SimulateTradeRequest, SwapExecutionRequest,
+        },
+        sct::v1::{
+            query_service_client::QueryServiceClient as SctQueryServiceClient, EpochByHeightRequest,
+        },
+        shielded_pool::v1::{
+            query_service_client::QueryServiceClient as ShieldedPoolQueryServiceClient,
+            AssetMetadataByIdRequest,
+        },
     },
-    shielded_pool::v1::{
-        query_service_client::QueryServiceClient as ShieldedPoolQueryServiceClient,
-        AssetMetadataByIdRequest,
+    util::tendermint_proxy::v1::{
+        tendermint_proxy_service_client::TendermintProxyServiceClient, GetStatusRequest,
     },
 };
 use penumbra_view::ViewClient;
@@ -57,6 +68,26 @@ pub enum DexCmd {
         #[clap(long)]
         height: u64,
     },
+    /// Display protocol arbitrage executions over a range of heights.
+    ///
+    /// Reports the surplus extracted by each arb, and aggregates it per epoch
+    /// and per asset.
+    ///
+    /// The liquidity positions filled by each arb are not shown, since the
+    /// chain only reports them in block events, which it does not serve back.
+    /// They are indexed by pindexer instead, in the `dex_arb_position_fill`
+    /// table and the `dex_arb_fills_by_position` view.
+    Arbs {
+        /// The first height to include [default: 1000 blocks before the end height].
+        #[clap(long)]
+        start_height: Option<u64>,
+        /// The last height to include [default: the latest height].
+        #[clap(long)]
+        end_height: Option<u64>,
+        /// Also display the cycles traversed by each arb.
+        #[clap(long)]
+        show_cycles: bool,
+    },
     /// Display information about all liquidity positions known to the chain.
     #[clap(display_order(900))]
     AllPositions {
@@ -72,6 +103,14 @@ pub enum DexCmd {
         #[clap(long)]
         raw: bool,
     },
+    /// Display how close a position is to being evicted from its trading pair.
+    ///
+    /// When a pair has more than `max_positions_per_pair` open positions, the DEX
+    /// closes the positions that are among the least liquid in both directions.
+    EvictionRisk {
+        /// The ID of the position to query.
+        id: position::Id,
+    },
     /// Display open liquidity for a given pair, sorted by effective price.
     Positions {
         /// The trading pair to query, with ordering determining direction of trade (1=>2).
@@ -89,6 +128,11 @@ pub enum DexCmd {
         /// The denomination to swap the input into, e.g. `gm`
         #[clap(long, display_order = 100)]
         into: String,
+        /// Simulate against the DEX state at this height, rather than the latest state.
+        ///
+        /// The node must still retain the state for the requested height.
+        #[clap(long, display_order = 200)]
+        height: Option<u64>,
     },
 }
 
@@ -148,11 +192,160 @@ impl DexCmd {
             .context("cannot parse batch swap output data")
     }
 
+    pub async fn get_arb_executions(
+        &self,
+        app: &mut App,
+        start_height: u64,
+        end_height: u64,
+    ) -> Result<Vec<(u64, SwapExecution)>> {
+        let mut client = DexQueryServiceClient::new(app.pd_channel().await?);
+        client
+            .arb_executions(ArbExecutionsRequest {
+                start_height,
+                end_height,
+            })
+            .await?
+            .into_inner()
+            .map_err(|e| anyhow::anyhow!("error fetching arb executions: {}", e))
+            .and_then(|msg| async move {
+                let swap_execution = msg
+                    .swap_execution
+                    .ok_or_else(|| anyhow::anyhow!("proto response missing arb execution"))?
+                    .try_into()
+                    .context("cannot parse arb execution")?;
+                Ok((msg.height, swap_execution))
+            })
+            .try_collect()
+            .await
+    }
+
+    pub async fn print_arbs(
+        &self,
+        app: &mut App,
+        start_height: Option<u64>,
+        end_height: Option<u64>,
+        show_cycles: bool,
+    ) -> Result<()> {
+        let channel = app.pd_channel().await?;
+        let end_height = match end_height {
+            Some(end_height) => end_height,
+            None => {
+                TendermintProxyServiceClient::new(channel.clone())
+                    .get_status(GetStatusRequest::default())
+                    .await?
+                    .into_inner()
+                    .sync_info
+                    .ok_or_else(|| anyhow::anyhow!("missing sync_info"))?
+                    .latest_block_height
+            }
+        };
+        let start_height = start_height.unwrap_or(end_height.saturating_sub(1000));
+
+        let arbs = self
+            .get_arb_executions(app, start_height, end_height)
+            .await?;
+        let cache = app.view().assets().await?;
+
+        let min_height = arbs.iter().map(|(height, _)| *height).min();
+        let epochs = match min_height {
+            Some(min_height) => {
+                EpochsByHeight::fetch(
+                    &mut SctQueryServiceClient::new(channel),
+                    min_height,
+                    end_height,
+                )
+                .await?
+            }
+            None => EpochsByHeight::default(),
+        };
+
+        let mut by_block = Table::new();
+        by_block.load_preset(presets::NOTHING);
+        by_block.set_header(vec![
+            "Height", "Epoch", "Input", "Output", "Profit", "Cycles",
+        ]);
+        // (epoch, asset) => (number of arbs, total profit)
+        let mut by_epoch: BTreeMap<(u64, asset::Id), (u64, Amount)> = BTreeMap::new();
+        // asset => (number of arbs, total profit)
+        let mut by_asset: BTreeMap<asset::Id, (u64, Amount)> = BTreeMap::new();
+
+        for (height, arb) in &arbs {
+            let epoch = epochs
+                .epoch_at(*height)
+                .with_context(|| format!("failed to find the epoch of height {height}"))?;
+            let profit = Value {
+                asset_id: arb.output.asset_id,
+                amount: arb.output.amount.saturating_sub(&arb.input.amount),
+            };
+
+            by_block.add_row(vec![
+                height.to_string(),
+                epoch.to_string(),
+                arb.input.format(&cache),
+                arb.output.format(&cache),
+                profit.format(&cache),
+                arb.traces.len().to_string(),
+            ]);
+
+            let entry = by_epoch
+                .entry((epoch, profit.asset_id))
+                .or_insert((0, Amount::zero()));
+            *entry = (entry.0 + 1, entry.1 + profit.amount);
+            let entry = by_asset
+                .entry(profit.asset_id)
+                .or_insert((0, Amount::zero()));
+            *entry = (entry.0 + 1, entry.1 + profit.amount);
+        }
+
+        println!(
+            "{} arbs between heights {} and {}:",
+            arbs.len(),
+            start_height,
+            end_height
+        );
+        println!("{}", by_block);
+
+        let mut epoch_table = Table::new();
+        epoch_table.load_preset(presets::NOTHING);
+        epoch_table.set_header(vec!["Epoch", "Arbs", "Profit"]);
+        for ((epoch, asset_id), (count, amount)) in by_epoch {
+            epoch_table.add_row(vec![
+                epoch.to_string(),
+                count.to_string(),
+                Value { asset_id, amount }.format(&cache),
+            ]);
+        }
+        println!("Profit by epoch:");
+        println!("{}", epoch_table);
+
+        let mut asset_table = Table::new();
+        asset_table.load_preset(presets::NOTHING);
+        asset_table.set_header(vec!["Arbs", "Profit"]);
+        for (asset_id, (count, amount)) in by_asset {
+            asset_table.add_row(vec![
+                count.to_string(),
+                Value { asset_id, amount }.format(&cache),
+            ]);
+        }
+        println!("Profit by asset:");
+        println!("{}", asset_table);
+
+        if show_cycles {
+            for (height, arb) in &arbs {
+                println!("Arb at height {}:", height);
+                self.print_swap_execution(app, arb).await?;
+            }
+        }
+
+        Ok(())
+    }
+
     pub async fn get_simulated_execution(
         &self,
         app: &mut App,
         input: Value,
         output: asset::Id,
+        height: Option<u64>,
     ) -> Result<SwapExecution> {
         use penumbra_proto::core::component::dex::v1::simulate_trade_request::{
             routing::Setting, Routing,
@@ -165,6 +358,8 @@ impl DexCmd {
                 routing: Some(Routing {
                     setting: Some(Setting::Default(Default::default())),
                 }),
+                height: height.unwrap_or_default(),
+                ..Default::default()
             })
             .await?
             .into_inner()
@@ -382,16 +577,30 @@ impl DexCmd {
 
                 self.print_swap_execution(app, &swap_execution).await?;
             }
+            DexCmd::Arbs {
+                start_height,
+                end_height,
+                show_cycles,
+            } => {
+                self.print_arbs(app, *start_height, *end_height, *show_cycles)
+                    .await?;
+            }
             DexCmd::ArbExecution { height } => {
                 let swap_execution = self.get_arb_execution(app, height).await?;
 
                 self.print_swap_execution(app, &swap_execution).await?;
             }
-            DexCmd::Simulate { input, into } => {
+            DexCmd::Simulate {
+                input,
+                into,
+                height,
+            } => {
                 let input = input.parse::<Value>()?;
                 let into = asset::REGISTRY.parse_unit(into.as_str()).base();
 
-                let swap_execution = self.get_simulated_execution(app, input, into.id()).await?;
+                let swap_execution = self
+                    .get_simulated_execution(app, input, into.id(), *height)
+                    .await?;
                 self.print_swap_execution(app, &swap_execution).await?;
             }
             DexCmd::AllPositions { include_closed } => {
@@ -420,6 +629,39 @@ impl DexCmd {
                 let asset_cache = app.view().assets().await?;
                 println!("{}", render_positions(&asset_cache, &positions));
             }
+            DexCmd::EvictionRisk { id } => {
+                let mut client = DexQueryServiceClient::new(app.pd_channel().await?);
+                let risk = client
+                    .position_eviction_risk(PositionEvictionRiskRequest {
+                        position: Some(position_eviction_risk_request::Position::PositionId(
+                            (*id).into(),
+                        )),
+                    })
+                    .await?
+                    .into_inner();
+
+                let overhead_size = risk
+                    .position_count
+                    .saturating_sub(risk.max_positions_per_pair);
+                let mut table = Table::new();
+                table.load_preset(presets::NOTHING);
+                table.add_row(vec!["ID".to_string(), id.to_string()]);
+                table.add_row(vec![
+                    "Positions".to_string(),
+                    format!(
+                        "{} (max {})",
+                        risk.position_count, risk.max_positions_per_pair
+                    ),
+                ]);
+                table.add_row(vec!["Excess".to_string(), overhead_size.to_string()]);
+                table.add_row(vec!["Rank 1=>2".to_string(), risk.rank_1.to_string()]);
+                table.add_row(vec!["Rank 2=>1".to_string(), risk.rank_2.to_string()]);
+                table.add_row(vec![
+                    "At Risk".to_string(),
+                    if risk.at_risk { "yes" } else { "no" }.to_string(),
+                ]);
+                println!("{}", table);
+            }
             DexCmd::Position { id, raw } => {
                 let mut client = DexQueryServiceClient::new(app.pd_channel().await?);
                 let position: Position = client
@@ -478,3 +720,73 @@ impl DexCmd {
         Ok(())
     }
 }
+
+/// The epochs spanning a range of heights, so that the epoch of each height in the range can be
+/// found with one query per epoch rather than one per height.
+#[derive(Debug, Default)]
+struct EpochsByHeight {
+    /// The (start height, index) of each epoch, in ascending order.
+    epochs: Vec<(u64, u64)>,
+}
+
+impl EpochsByHeight {
+    /// Looks up the epochs spanning `start_height..=end_height`, walking back from the epoch of
+    /// `end_height` to the epoch each preceding one started after.
+    async fn fetch(
+        client: &mut SctQueryServiceClient<Channel>,
+        start_height: u64,
+        end_height: u64,
+    ) -> Result<Self> {
+        let mut epochs = Vec::new();
+        let mut height = end_height;
+        loop {
+            let epoch = client
+                .epoch_by_height(EpochByHeightRequest { height })
+                .await?
+                .into_inner()
+                .epoch
+                .context("failed to find EpochByHeight message")?;
+            epochs.push((epoch.start_height, epoch.index));
+            if epoch.start_height <= start_height || epoch.start_height == 0 {
+                break;
+            }
+            height = epoch.start_height - 1;
+        }
+        epochs.reverse();
+        Ok(Self { epochs })
+    }
+
+    /// Returns the index of the epoch containing `height`, if it is in the fetched range.
+    fn epoch_at(&self, height: u64) -> Option<u64> {
+        let following = self
+            .epochs
+            .partition_point(|(start_height, _)| *start_height <= height);
+        following
+            .checked_sub(1)
+            .map(|position| self.epochs[position].1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn epoch_at_finds_the_epoch_containing_a_height() {
+        let epochs = EpochsByHeight {
+            epochs: vec![(100, 1), (200, 2), (250, 3)],
+        };
+        assert_eq!(epochs.epoch_at(99), None);
+        assert_eq!(epochs.epoch_at(100), Some(1));
+        assert_eq!(epochs.epoch_at(199), Some(1));
+        assert_eq!(epochs.epoch_at(200), Some(2));
+        assert_eq!(epochs.epoch_at(249), Some(2));
+        assert_eq!(epochs.epoch_at(250), Some(3));
+        assert_eq!(epochs.epoch_at(10_000), Some(3));
+    }
+
+    #[test]
+    fn epoch_at_is_empty_without_epochs() {
+        assert_eq!(EpochsByHeight::default().epoch_at(1), None);
+    }
+}
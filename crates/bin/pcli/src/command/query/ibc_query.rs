@@ -16,6 +16,19 @@ use ibc_proto::ibc::core::connection::v1::{
 use ibc_types::core::channel::channel::State;
 use ibc_types::lightclients::tendermint::client_state::ClientState as TendermintClientState;
 use ibc_types::lightclients::tendermint::consensus_state::ConsensusState as TendermintConsensusState;
+use penumbra_asset::Value;
+use penumbra_ibc::{
+    Ics20RateLimit, Ics20RateLimitUsage, InterchainAccount, InterchainAccountTx,
+    InterchainAccountTxStatus,
+};
+use penumbra_proto::core::component::ibc::v1::{
+    query_service_client::QueryServiceClient as IbcQueryServiceClient, InterchainAccountTxsRequest,
+    InterchainAccountsRequest,
+};
+use penumbra_proto::core::component::shielded_pool::v1::{
+    query_service_client::QueryServiceClient as ShieldedPoolQueryServiceClient,
+    Ics20RateLimitsRequest,
+};
 
 use crate::App;
 
@@ -50,6 +63,21 @@ pub enum IbcCmd {
     },
     /// Queries for info on all IBC channels.
     Channels {},
+    /// Queries for the ICS-20 transfer rate limits, and their usage in the current window.
+    RateLimits {
+        /// Only show limits on this channel, e.g. "0".
+        #[clap(long)]
+        channel_id: Option<u64>,
+        /// Only show limits on this asset, given as an asset ID or a unit name.
+        #[clap(long)]
+        asset: Option<String>,
+    },
+    /// Queries for the Community Pool's interchain accounts.
+    InterchainAccounts {},
+    /// Queries for the transactions governance has sent to the Community Pool's interchain
+    /// account over a connection, and their outcomes.
+    /// Requires the numeric identifier for the connection, e.g. "0".
+    InterchainAccountTxs { connection_id: u64 },
 }
 
 struct ChannelInfo {
@@ -298,6 +326,126 @@ impl IbcCmd {
                     ]);
                 }
 
+                println!("{table}")
+            }
+            IbcCmd::RateLimits { channel_id, asset } => {
+                let asset_id: Option<penumbra_asset::asset::Id> = asset.as_ref().map(|asset| {
+                    // Try to parse as an asset ID, then if it's not an asset ID, assume it's a unit name
+                    if let Ok(asset_id) = asset.parse() {
+                        asset_id
+                    } else {
+                        penumbra_asset::asset::REGISTRY
+                            .parse_unit(asset.as_str())
+                            .id()
+                    }
+                });
+
+                let mut client = ShieldedPoolQueryServiceClient::new(app.pd_channel().await?);
+                let statuses = client
+                    .ics20_rate_limits(Ics20RateLimitsRequest {
+                        channel_id: channel_id
+                            .map(|id| format!("channel-{id}"))
+                            .unwrap_or_default(),
+                        asset_id: asset_id.map(Into::into),
+                    })
+                    .await?
+                    .into_inner()
+                    .limits;
+
+                let asset_cache = app.view().assets().await?;
+                let format = |amount, asset_id| Value { amount, asset_id }.format(&asset_cache);
+
+                let mut table = Table::new();
+                table.set_header(vec![
+                    "Channel ID",
+                    "Asset",
+                    "Window (blocks)",
+                    "Window Start",
+                    "Net Inflow",
+                    "Max Inflow",
+                    "Net Outflow",
+                    "Max Outflow",
+                ]);
+                for status in statuses {
+                    let limit: Ics20RateLimit = status
+                        .limit
+                        .ok_or_else(|| anyhow::anyhow!("missing rate limit"))?
+                        .try_into()?;
+                    let usage: Ics20RateLimitUsage = status.usage.unwrap_or_default().try_into()?;
+                    let asset_name = asset_cache
+                        .get(&limit.asset_id)
+                        .map(|denom| denom.to_string())
+                        .unwrap_or_else(|| limit.asset_id.to_string());
+                    table.add_row(vec![
+                        limit.channel_id.to_string(),
+                        asset_name,
+                        limit.window_blocks.to_string(),
+                        usage.window_start_height.to_string(),
+                        format(usage.net_inflow(), limit.asset_id),
+                        format(limit.max_inflow, limit.asset_id),
+                        format(usage.net_outflow(), limit.asset_id),
+                        format(limit.max_outflow, limit.asset_id),
+                    ]);
+                }
+
+                println!("{table}")
+            }
+            IbcCmd::InterchainAccounts {} => {
+                let mut client = IbcQueryServiceClient::new(app.pd_channel().await?);
+                let accounts = client
+                    .interchain_accounts(InterchainAccountsRequest {})
+                    .await?
+                    .into_inner()
+                    .accounts
+                    .into_iter()
+                    .map(InterchainAccount::try_from)
+                    .collect::<Result<Vec<_>>>()?;
+
+                let mut table = Table::new();
+                table.set_header(vec!["Connection ID", "Port ID", "Channel ID", "Address"]);
+                for account in accounts {
+                    table.add_row(vec![
+                        account.connection_id.to_string(),
+                        account.port_id.to_string(),
+                        account.channel_id.to_string(),
+                        account
+                            .address
+                            .unwrap_or_else(|| "(awaiting handshake)".to_string()),
+                    ]);
+                }
+
+                println!("{table}")
+            }
+            IbcCmd::InterchainAccountTxs { connection_id } => {
+                let mut client = IbcQueryServiceClient::new(app.pd_channel().await?);
+                let txs = client
+                    .interchain_account_txs(InterchainAccountTxsRequest {
+                        connection_id: format!("connection-{connection_id}"),
+                    })
+                    .await?
+                    .into_inner()
+                    .txs
+                    .into_iter()
+                    .map(InterchainAccountTx::try_from)
+                    .collect::<Result<Vec<_>>>()?;
+
+                let mut table = Table::new();
+                table.set_header(vec!["Proposal ID", "Channel ID", "Sequence", "Status"]);
+                for tx in txs {
+                    let status = match tx.status {
+                        InterchainAccountTxStatus::Pending => "pending".to_string(),
+                        InterchainAccountTxStatus::Succeeded { .. } => "succeeded".to_string(),
+                        InterchainAccountTxStatus::Failed { error } => format!("failed: {error}"),
+                        InterchainAccountTxStatus::TimedOut => "timed out".to_string(),
+                    };
+                    table.add_row(vec![
+                        tx.proposal_id.to_string(),
+                        tx.channel_id.to_string(),
+                        tx.sequence.to_string(),
+                        status,
+                    ]);
+                }
+
                 println!("{table}")
             }
         }
@@ -18,8 +18,8 @@ use penumbra_proto::{
         },
         component::stake::v1::{
             query_service_client::QueryServiceClient as StakeQueryServiceClient,
-            GetValidatorInfoRequest, GetValidatorInfoResponse, ValidatorInfoRequest,
-            ValidatorStatusRequest, ValidatorUptimeRequest,
+            GetValidatorInfoRequest, GetValidatorInfoResponse, ValidatorCommissionScheduleRequest,
+            ValidatorInfoRequest, ValidatorStatusRequest, ValidatorUptimeRequest,
         },
     },
     DomainType,
@@ -27,11 +27,15 @@ use penumbra_proto::{
 use penumbra_stake::{
     rate::RateData,
     validator::{self, Info, Status, Validator, ValidatorToml},
-    IdentityKey, Uptime, BPS_SQUARED_SCALING_FACTOR,
+    IdentityKey, PendingFundingStreams, Uptime, BPS_SQUARED_SCALING_FACTOR,
 };
 
 use crate::App;
 
+use self::health::HealthCmd;
+
+mod health;
+
 // TODO: replace this with something more standard for the `query` subcommand
 #[derive(Debug, clap::Subcommand)]
 pub enum ValidatorCmd {
@@ -62,6 +66,8 @@ pub enum ValidatorCmd {
         /// The identity key of the validator to fetch.
         identity_key: String,
     },
+    /// Report on the operational health of a validator, alerting when thresholds are crossed.
+    Health(HealthCmd),
 }
 
 impl ValidatorCmd {
@@ -210,6 +216,17 @@ impl ValidatorCmd {
                     .map(ValidatorToml::from)
                     .and_then(serialize)?;
 
+                // The definition only includes the funding streams currently in effect, so
+                // uploading it again would cancel a pending commission increase.
+                let identity_key = identity_key.parse::<IdentityKey>()?;
+                if let Some(pending) = pending_funding_streams(app, &identity_key).await? {
+                    eprintln!(
+                        "warning: a commission increase to {}bps takes effect at the start of epoch {}, and is not included in this definition",
+                        pending.funding_streams.total_rate_bps(),
+                        pending.effective_epoch_index,
+                    );
+                }
+
                 // Write to a file if an output file was specified, otherwise print to stdout.
                 if let Some(file) = file {
                     File::create(file)
@@ -390,13 +407,39 @@ impl ValidatorCmd {
                     ])
                     .add_row(StatusRow::new(info));
                 println!("{table}");
+
+                let identity_key = identity_key.parse::<IdentityKey>()?;
+                if let Some(pending) = pending_funding_streams(app, &identity_key).await? {
+                    println!(
+                        "Commission increases to {}bps at the start of epoch {}",
+                        pending.funding_streams.total_rate_bps(),
+                        pending.effective_epoch_index,
+                    );
+                }
             }
+            ValidatorCmd::Health(health_cmd) => health_cmd.exec(app).await?,
         }
 
         Ok(())
     }
 }
 
+/// Fetches the announced commission increase of a validator that has not yet taken effect.
+async fn pending_funding_streams(
+    app: &App,
+    identity_key: &IdentityKey,
+) -> Result<Option<PendingFundingStreams>> {
+    StakeQueryServiceClient::new(app.pd_channel().await?)
+        .validator_commission_schedule(ValidatorCommissionScheduleRequest {
+            identity_key: Some(identity_key.to_proto()),
+        })
+        .await?
+        .into_inner()
+        .pending
+        .map(TryInto::try_into)
+        .transpose()
+}
+
 /// A row within the `status` command's table output.
 struct StatusRow {
     power: f64,
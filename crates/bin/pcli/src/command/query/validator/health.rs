@@ -0,0 +1,541 @@
+use std::{collections::BTreeSet, process::Stdio, time::Duration};
+
+use anyhow::{anyhow, Context, Result};
+use penumbra_app::params::AppParameters;
+use penumbra_proto::core::{
+    app::v1::{
+        query_service_client::QueryServiceClient as AppQueryServiceClient, AppParametersRequest,
+    },
+    component::stake::v1::{
+        query_service_client::QueryServiceClient as StakeQueryServiceClient,
+        CurrentConsensusKeysRequest, GetValidatorInfoRequest, ValidatorUptimeRequest,
+    },
+};
+use penumbra_stake::{
+    validator::{self, BondingState},
+    CurrentConsensusKeys, IdentityKey, Uptime,
+};
+use serde::Serialize;
+use tokio::io::AsyncWriteExt as _;
+
+use crate::App;
+
+/// Report on the operational health of a validator.
+///
+/// Missed blocks are read from the validator's uptime record, which tracks the same blocks
+/// for which the chain emits `EventValidatorMissedBlock`.
+#[derive(Debug, clap::Args)]
+pub struct HealthCmd {
+    /// The identity key of the validator to report on.
+    identity_key: IdentityKey,
+    /// Print the report as JSON.
+    #[clap(long)]
+    json: bool,
+    /// Keep checking the validator's health, every given number of seconds.
+    ///
+    /// Failed checks and alert commands are reported, and retried at the next check.
+    #[clap(long)]
+    watch: Option<u64>,
+    /// A shell command to run whenever the set of alerts changes.
+    ///
+    /// The command receives the JSON report on stdin, and the names of the raised alerts,
+    /// separated by commas, in the `PCLI_VALIDATOR_ALERTS` environment variable.
+    #[clap(long)]
+    alert_command: Option<String>,
+    /// Alert when the share of signed blocks in the uptime window falls below this percentage.
+    #[clap(long, default_value = "95")]
+    alert_min_uptime: f64,
+    /// Alert when the validator has missed at least this many consecutive blocks.
+    #[clap(long, default_value = "10")]
+    alert_missed_streak: u64,
+    /// Alert when the validator could be jailed within this many blocks.
+    #[clap(long, default_value = "720")]
+    alert_jail_within: u64,
+}
+
+/// The status of a validator's consensus key, relative to the keys known to CometBFT.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum KeyRotationStatus {
+    /// CometBFT is using the consensus key in the validator's definition.
+    Current,
+    /// The validator uploaded a new consensus key, which CometBFT will use from the next epoch.
+    Pending,
+    /// The validator is not part of the consensus set.
+    Inactive,
+}
+
+/// A condition that warrants the operator's attention.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Alert {
+    LowUptime,
+    MissedBlockStreak,
+    JailRisk,
+    Jailed,
+    Tombstoned,
+}
+
+impl std::fmt::Display for Alert {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Alert::LowUptime => "low_uptime",
+            Alert::MissedBlockStreak => "missed_block_streak",
+            Alert::JailRisk => "jail_risk",
+            Alert::Jailed => "jailed",
+            Alert::Tombstoned => "tombstoned",
+        })
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct HealthReport {
+    pub identity_key: String,
+    pub name: String,
+    pub state: String,
+    pub bonding_state: String,
+    pub as_of_height: u64,
+    pub window_len: u64,
+    pub missed_blocks: u64,
+    pub missed_blocks_maximum: u64,
+    pub uptime_percent: f64,
+    /// The share of the missed-blocks allowance used up by the missed blocks in the window.
+    pub window_fill_percent: f64,
+    pub current_missed_streak: u64,
+    pub longest_missed_streak: u64,
+    /// The fewest blocks after which the validator is jailed, if it misses every one of them.
+    pub blocks_until_jail: Option<u64>,
+    /// The average time between the blocks in the window, if it could be measured.
+    pub block_time_seconds: Option<f64>,
+    pub estimated_seconds_until_jail: Option<u64>,
+    pub key_rotation: KeyRotationStatus,
+    /// The height at which the validator's delegation pool finishes unbonding, if it is unbonding.
+    pub unbonds_at_height: Option<u64>,
+    pub unbonding_delay: u64,
+    /// The validator's voting power, in units of the staking token's base denom.
+    pub voting_power: u128,
+    /// The stake that would be slashed if the validator were jailed for downtime.
+    pub downtime_slashing_exposure: u128,
+    /// The stake that would be slashed if the validator were tombstoned for misbehavior.
+    pub misbehavior_slashing_exposure: u128,
+    pub alerts: Vec<Alert>,
+}
+
+impl HealthCmd {
+    pub async fn exec(&self, app: &mut App) -> Result<()> {
+        let mut previous_alerts = BTreeSet::new();
+        loop {
+            let report = match (self.report(app).await, self.watch) {
+                (Ok(report), _) => report,
+                (Err(error), Some(seconds)) => {
+                    eprintln!(
+                        "failed to check validator health, retrying in {seconds}s: {error:#}"
+                    );
+                    tokio::time::sleep(Duration::from_secs(seconds)).await;
+                    continue;
+                }
+                (Err(error), None) => return Err(error),
+            };
+
+            if self.json {
+                println!("{}", serde_json::to_string_pretty(&report)?);
+            } else {
+                print_report(&report);
+            }
+
+            let alerts = report.alerts.iter().copied().collect::<BTreeSet<_>>();
+            if alerts != previous_alerts {
+                match &self.alert_command {
+                    Some(command) => match run_alert_command(command, &report).await {
+                        Ok(()) => previous_alerts = alerts,
+                        // Keep watching, and run the command again at the next check.
+                        Err(error) if self.watch.is_some() => {
+                            eprintln!("failed to run alert command: {error:#}")
+                        }
+                        Err(error) => return Err(error),
+                    },
+                    None => previous_alerts = alerts,
+                }
+            }
+
+            match self.watch {
+                Some(seconds) => tokio::time::sleep(Duration::from_secs(seconds)).await,
+                None => return Ok(()),
+            }
+        }
+    }
+
+    async fn report(&self, app: &mut App) -> Result<HealthReport> {
+        let mut client = StakeQueryServiceClient::new(app.pd_channel().await?);
+
+        let info: validator::Info = client
+            .get_validator_info(GetValidatorInfoRequest {
+                identity_key: Some(self.identity_key.into()),
+            })
+            .await?
+            .into_inner()
+            .validator_info
+            .ok_or_else(|| anyhow!("response did not include validator info"))?
+            .try_into()
+            .context("parsing validator info")?;
+
+        let uptime: Uptime = client
+            .validator_uptime(ValidatorUptimeRequest {
+                identity_key: Some(self.identity_key.into()),
+            })
+            .await?
+            .into_inner()
+            .uptime
+            .ok_or_else(|| anyhow!("uptime must be present in response"))?
+            .try_into()?;
+
+        let current_consensus_keys: CurrentConsensusKeys = client
+            .current_consensus_keys(CurrentConsensusKeysRequest {})
+            .await?
+            .into_inner()
+            .current_consensus_keys
+            .ok_or_else(|| anyhow!("current consensus keys must be present in response"))?
+            .try_into()?;
+
+        let params: AppParameters = AppQueryServiceClient::new(app.pd_channel().await?)
+            .app_parameters(AppParametersRequest {})
+            .await?
+            .into_inner()
+            .app_parameters
+            .ok_or_else(|| anyhow!("empty AppParametersResponse message"))?
+            .try_into()?;
+        let stake_params = params.stake_params;
+
+        let window_len = uptime.missed_blocks_window() as u64;
+        let missed_blocks = uptime.num_missed_blocks() as u64;
+        let as_of_height = uptime.as_of_height();
+        let (current_missed_streak, longest_missed_streak) = missed_streaks(&uptime);
+        let block_time =
+            penumbra_view::observed_block_time(app.pd_channel().await?, as_of_height, window_len)
+                .await
+                .map_err(|error| tracing::warn!(?error, "can't estimate block time"))
+                .ok();
+
+        let state = info.status.state;
+        let active = matches!(state, validator::State::Active);
+        let blocks_until_jail = active.then(|| {
+            stake_params
+                .missed_blocks_maximum
+                .saturating_sub(missed_blocks)
+        });
+
+        let key_rotation = if current_consensus_keys
+            .consensus_keys
+            .contains(&info.validator.consensus_key)
+        {
+            KeyRotationStatus::Current
+        } else if active {
+            KeyRotationStatus::Pending
+        } else {
+            KeyRotationStatus::Inactive
+        };
+
+        let unbonds_at_height = match info.status.bonding_state {
+            BondingState::Unbonding { unbonds_at_height } => Some(unbonds_at_height),
+            _ => None,
+        };
+
+        // Penalties are expressed in basis points squared.
+        let voting_power = info.status.voting_power.value();
+        let exposure = |penalty: u64| voting_power * u128::from(penalty) / 1_0000_0000;
+
+        let uptime_percent = 100.0 * (window_len - missed_blocks) as f64 / window_len.max(1) as f64;
+
+        let thresholds = AlertThresholds {
+            min_uptime_percent: self.alert_min_uptime,
+            missed_streak: self.alert_missed_streak,
+            jail_within: self.alert_jail_within,
+        };
+        let alerts = thresholds.alerts(
+            state,
+            uptime_percent,
+            current_missed_streak,
+            blocks_until_jail,
+        );
+
+        Ok(HealthReport {
+            identity_key: self.identity_key.to_string(),
+            name: info.validator.name,
+            state: state.to_string(),
+            bonding_state: info.status.bonding_state.to_string(),
+            as_of_height,
+            window_len,
+            missed_blocks,
+            missed_blocks_maximum: stake_params.missed_blocks_maximum,
+            uptime_percent,
+            window_fill_percent: 100.0 * missed_blocks as f64
+                / stake_params.missed_blocks_maximum.max(1) as f64,
+            current_missed_streak,
+            longest_missed_streak,
+            blocks_until_jail,
+            block_time_seconds: block_time.map(|block_time| block_time.as_secs_f64()),
+            estimated_seconds_until_jail: blocks_until_jail
+                .zip(block_time)
+                .map(|(blocks, block_time)| (block_time.as_secs_f64() * blocks as f64) as u64),
+            key_rotation,
+            unbonds_at_height,
+            unbonding_delay: stake_params.unbonding_delay,
+            voting_power,
+            downtime_slashing_exposure: exposure(stake_params.slashing_penalty_downtime),
+            misbehavior_slashing_exposure: exposure(stake_params.slashing_penalty_misbehavior),
+            alerts,
+        })
+    }
+}
+
+/// The thresholds at which a [`HealthReport`] raises alerts.
+#[derive(Debug, Clone, Copy)]
+struct AlertThresholds {
+    min_uptime_percent: f64,
+    missed_streak: u64,
+    jail_within: u64,
+}
+
+impl AlertThresholds {
+    /// Returns the alerts raised by a validator in the given `state`, sorted.
+    fn alerts(
+        &self,
+        state: validator::State,
+        uptime_percent: f64,
+        current_missed_streak: u64,
+        blocks_until_jail: Option<u64>,
+    ) -> Vec<Alert> {
+        let mut alerts = Vec::new();
+        match state {
+            validator::State::Jailed => alerts.push(Alert::Jailed),
+            validator::State::Tombstoned => alerts.push(Alert::Tombstoned),
+            _ => {}
+        }
+        if matches!(state, validator::State::Active) && uptime_percent < self.min_uptime_percent {
+            alerts.push(Alert::LowUptime);
+        }
+        if current_missed_streak >= self.missed_streak {
+            alerts.push(Alert::MissedBlockStreak);
+        }
+        if blocks_until_jail.is_some_and(|blocks| blocks <= self.jail_within) {
+            alerts.push(Alert::JailRisk);
+        }
+        alerts.sort();
+        alerts
+    }
+}
+
+/// Returns the length of the run of missed blocks ending at the latest recorded height, and of
+/// the longest run of missed blocks in the window.
+fn missed_streaks(uptime: &Uptime) -> (u64, u64) {
+    let mut current: Option<(u64, u64)> = None;
+    let mut longest = 0;
+    for height in uptime.missed_blocks() {
+        current = match current {
+            Some((end, len)) if end + 1 == height => Some((height, len + 1)),
+            _ => Some((height, 1)),
+        };
+        longest = longest.max(current.map(|(_, len)| len).unwrap_or_default());
+    }
+    let current = current
+        .filter(|(end, _)| *end == uptime.as_of_height())
+        .map(|(_, len)| len)
+        .unwrap_or_default();
+    (current, longest)
+}
+
+fn print_report(report: &HealthReport) {
+    println!(
+        "{} ({}): {} validator, {}, as of block {}",
+        report.name, report.identity_key, report.state, report.bonding_state, report.as_of_height
+    );
+    println!(
+        "Uptime:           {:>6.2}% of the last {} blocks signed",
+        report.uptime_percent, report.window_len
+    );
+    println!(
+        "Missed blocks:    {}/{} allowed ({:.2}% of the allowance used)",
+        report.missed_blocks, report.missed_blocks_maximum, report.window_fill_percent
+    );
+    println!(
+        "Missed streak:    {} current, {} longest in window",
+        report.current_missed_streak, report.longest_missed_streak
+    );
+    if let Some(blocks) = report.blocks_until_jail {
+        match report.estimated_seconds_until_jail {
+            Some(seconds) => println!(
+                "Time to jail:     {} blocks ~ {} at minimum, if every block is missed",
+                blocks,
+                humantime::Duration::from(Duration::from_secs(seconds))
+            ),
+            None => println!(
+                "Time to jail:     {} blocks at minimum, if every block is missed",
+                blocks
+            ),
+        }
+    }
+    println!(
+        "Consensus key:    {}",
+        match report.key_rotation {
+            KeyRotationStatus::Current => "in use by CometBFT",
+            KeyRotationStatus::Pending => "rotation pending until the next epoch",
+            KeyRotationStatus::Inactive => "not in the consensus set",
+        }
+    );
+    if let Some(height) = report.unbonds_at_height {
+        println!(
+            "Unbonding:        delegation pool unbonds at block {}, and is slashable until then",
+            height
+        );
+    }
+    println!(
+        "Slashing exposure: {:.6} for downtime, {:.6} for misbehavior (voting power {:.6})",
+        report.downtime_slashing_exposure as f64 * 1e-6,
+        report.misbehavior_slashing_exposure as f64 * 1e-6,
+        report.voting_power as f64 * 1e-6,
+    );
+    if report.alerts.is_empty() {
+        println!("Alerts:           none");
+    } else {
+        println!(
+            "Alerts:           {}",
+            report
+                .alerts
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>()
+                .join(", ")
+        );
+    }
+}
+
+/// Runs the operator's alert command, passing it the report.
+async fn run_alert_command(command: &str, report: &HealthReport) -> Result<()> {
+    let alerts = report
+        .alerts
+        .iter()
+        .map(ToString::to_string)
+        .collect::<Vec<_>>()
+        .join(",");
+
+    let mut child = tokio::process::Command::new("sh")
+        .arg("-c")
+        .arg(command)
+        .env("PCLI_VALIDATOR_ALERTS", alerts)
+        .stdin(Stdio::piped())
+        .spawn()
+        .with_context(|| format!("could not run alert command {command:?}"))?;
+    let mut stdin = child.stdin.take().expect("stdin is piped");
+    // The command may exit without reading the report, which is fine.
+    if let Err(error) = stdin.write_all(&serde_json::to_vec(report)?).await {
+        if error.kind() != std::io::ErrorKind::BrokenPipe {
+            return Err(error.into());
+        }
+    }
+    // Close stdin, so that the command sees the end of the report.
+    drop(stdin);
+
+    let status = child.wait().await?;
+    if !status.success() {
+        tracing::warn!(%status, "alert command failed");
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const THRESHOLDS: AlertThresholds = AlertThresholds {
+        min_uptime_percent: 95.0,
+        missed_streak: 3,
+        jail_within: 10,
+    };
+
+    /// Records the blocks after `start`, missing those in `missed`.
+    fn uptime(start: u64, window_len: usize, end: u64, missed: &[u64]) -> Uptime {
+        let mut uptime = Uptime::new(start, window_len);
+        for height in start + 1..=end {
+            uptime
+                .mark_height_as_signed(height, !missed.contains(&height))
+                .expect("heights are recorded in order");
+        }
+        uptime
+    }
+
+    #[test]
+    fn no_missed_blocks_make_no_streaks() {
+        assert_eq!(missed_streaks(&uptime(0, 10, 20, &[])), (0, 0));
+    }
+
+    #[test]
+    fn streak_ending_at_the_latest_block_is_current() {
+        assert_eq!(
+            missed_streaks(&uptime(0, 10, 20, &[12, 13, 15, 18, 19, 20])),
+            (3, 3)
+        );
+        assert_eq!(
+            missed_streaks(&uptime(0, 10, 20, &[13, 14, 15, 16, 19, 20])),
+            (2, 4)
+        );
+    }
+
+    #[test]
+    fn streak_before_the_latest_block_is_not_current() {
+        assert_eq!(
+            missed_streaks(&uptime(0, 10, 20, &[15, 16, 17, 19])),
+            (0, 3)
+        );
+    }
+
+    #[test]
+    fn streaks_are_limited_to_the_window() {
+        // Blocks 5 through 14 were missed, but only 11 through 14 are still in the window.
+        let missed = (5..=14).collect::<Vec<_>>();
+        assert_eq!(missed_streaks(&uptime(0, 10, 20, &missed)), (0, 4));
+    }
+
+    #[test]
+    fn healthy_validator_raises_no_alerts() {
+        assert_eq!(
+            THRESHOLDS.alerts(validator::State::Active, 99.0, 2, Some(11)),
+            vec![]
+        );
+    }
+
+    #[test]
+    fn alerts_are_raised_at_their_thresholds() {
+        assert_eq!(
+            THRESHOLDS.alerts(validator::State::Active, 94.9, 0, Some(100)),
+            vec![Alert::LowUptime]
+        );
+        assert_eq!(
+            THRESHOLDS.alerts(validator::State::Active, 100.0, 3, Some(100)),
+            vec![Alert::MissedBlockStreak]
+        );
+        assert_eq!(
+            THRESHOLDS.alerts(validator::State::Active, 100.0, 0, Some(10)),
+            vec![Alert::JailRisk]
+        );
+        assert_eq!(
+            THRESHOLDS.alerts(validator::State::Active, 50.0, 5, Some(0)),
+            vec![Alert::LowUptime, Alert::MissedBlockStreak, Alert::JailRisk]
+        );
+    }
+
+    #[test]
+    fn inactive_validators_are_not_alerted_for_uptime() {
+        assert_eq!(
+            THRESHOLDS.alerts(validator::State::Inactive, 0.0, 0, None),
+            vec![]
+        );
+        assert_eq!(
+            THRESHOLDS.alerts(validator::State::Jailed, 0.0, 0, None),
+            vec![Alert::Jailed]
+        );
+        assert_eq!(
+            THRESHOLDS.alerts(validator::State::Tombstoned, 0.0, 0, None),
+            vec![Alert::Tombstoned]
+        );
+    }
+}
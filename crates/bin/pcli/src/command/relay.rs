@@ -0,0 +1,667 @@
+use std::{
+    path::{Path, PathBuf},
+    str::FromStr as _,
+};
+
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+use ibc_types::{
+    core::{
+        channel::{ChannelId, PortId},
+        client::{ClientId, Height},
+        connection::ChainId,
+    },
+    lightclients::tendermint::header::Header as TendermintHeader,
+};
+use k256::ecdsa::{signature::Signer as _, Signature, SigningKey};
+use penumbra_ibc::{
+    monitor::ClientEventSource,
+    relay::{ChannelRelayer, HeaderSource, PacketEventSource},
+    IbcRelay,
+};
+use penumbra_keys::keys::AddressIndex;
+use penumbra_proto::{
+    core::component::ibc::v1 as pb_ibc,
+    cosmos::{
+        auth::v1beta1::{
+            query_client::QueryClient as AuthQueryClient, BaseAccount, QueryAccountRequest,
+        },
+        base::{abci::v1beta1::TxResponse, v1beta1::Coin},
+        crypto::secp256k1::PubKey,
+        tx::{
+            signing::v1beta1::SignMode,
+            v1beta1::{
+                mode_info::{Single, Sum},
+                service_client::ServiceClient as CosmosServiceClient,
+                AuthInfo as CosmosAuthInfo, BroadcastMode,
+                BroadcastTxRequest as CosmosBroadcastTxRequest, Fee as CosmosFee, ModeInfo,
+                SignDoc, SignerInfo as CosmosSignerInfo, TxBody as CosmosTxBody, TxRaw,
+            },
+        },
+    },
+    serializers::bech32str,
+    util::tendermint_proxy::v1::{
+        tendermint_proxy_service_client::TendermintProxyServiceClient, GetBlockByHeightRequest,
+        GetStatusRequest, GetValidatorSetRequest, SearchTxEventsRequest,
+    },
+    view::v1::GasPricesRequest,
+    Message, Name as _,
+};
+use penumbra_view::ViewClient;
+use penumbra_wallet::plan::Planner;
+use rand_core::OsRng;
+use ripemd::Ripemd160;
+use sha2::{Digest as _, Sha256};
+use tendermint::{abci::Event, block::signed_header::SignedHeader, validator::Set};
+use tendermint_rpc::{query::Query, Client, HttpClient, Order, Paging};
+use tonic::transport::{Channel, ClientTlsConfig};
+use url::Url;
+
+use crate::App;
+
+/// Relay the packets pending on an IBC channel, in both directions.
+///
+/// Towards Penumbra, this updates Penumbra's client for the counterparty chain, and delivers the
+/// packets the counterparty sent over the channel, along with the acknowledgements and timeouts
+/// of the packets Penumbra sent, in a single transaction paid for by the wallet.
+///
+/// Towards the counterparty, it does the same for the counterparty's client for Penumbra and the
+/// packets Penumbra sent, in a single transaction signed with `--counterparty-key-file`. Without
+/// a key, the messages for the counterparty are only printed.
+///
+/// Penumbra is read through the tendermint proxy of the node `pcli` is connected to. The
+/// counterparty's gRPC endpoint must serve proofs alongside its IBC query responses, as
+/// Penumbra's does, and both chains' CometBFT nodes must index transactions.
+#[derive(Debug, clap::Parser)]
+pub struct RelayCmd {
+    /// The channel to relay, as identified on Penumbra.
+    /// You can search for channels via e.g. `pcli query ibc channels`.
+    #[clap(long)]
+    pub channel: u64,
+    /// The port of Penumbra's end of the channel.
+    #[clap(long, default_value = "transfer")]
+    pub port: String,
+    /// The URL of the counterparty chain's gRPC endpoint.
+    #[clap(long)]
+    pub counterparty_grpc_url: Url,
+    /// The URL of the counterparty chain's CometBFT RPC endpoint.
+    #[clap(long)]
+    pub counterparty_rpc_url: Url,
+    /// A file holding the hex-encoded secp256k1 key paying for the relay transaction on the
+    /// counterparty chain.
+    #[clap(long)]
+    pub counterparty_key_file: Option<PathBuf>,
+    /// The Bech32 prefix of the counterparty chain's account addresses, e.g. `osmo`.
+    #[clap(long, default_value = "cosmos")]
+    pub counterparty_address_prefix: String,
+    /// The fee paid for the relay transaction on the counterparty chain, e.g. `5000uosmo`.
+    #[clap(long)]
+    pub counterparty_fee: Option<String>,
+    /// The gas limit of the relay transaction on the counterparty chain.
+    #[clap(long, default_value = "1000000")]
+    pub counterparty_gas_limit: u64,
+    /// Only print the messages that would be relayed, without submitting them.
+    #[clap(long)]
+    pub dry_run: bool,
+    /// The account paying for the relay transaction on Penumbra.
+    #[clap(long, default_value = "0")]
+    pub source: u32,
+}
+
+impl RelayCmd {
+    pub async fn exec(&self, app: &mut App) -> Result<()> {
+        let status = app
+            .tendermint_proxy_client()
+            .await?
+            .get_status(GetStatusRequest {})
+            .await?
+            .into_inner();
+        let chain_id = status
+            .node_info
+            .context("node info must be available")?
+            .network;
+        let sync_info = status.sync_info.context("sync info must be available")?;
+        let latest_block_time = sync_info
+            .latest_block_time
+            .context("latest block time must be available")?;
+        let penumbra_height = Height {
+            revision_number: ChainId::from_string(&chain_id).version(),
+            revision_height: sync_info.latest_block_height,
+        };
+        let penumbra_time = tendermint::Time::from_unix_timestamp(
+            latest_block_time.seconds,
+            latest_block_time.nanos.try_into()?,
+        )?;
+
+        let counterparty_rpc = CometBft::new(&self.counterparty_rpc_url)?;
+        let counterparty_status = counterparty_rpc.0.status().await?;
+        let counterparty_chain_id = counterparty_status.node_info.network.to_string();
+        let counterparty_height = Height {
+            revision_number: ChainId::from_string(&counterparty_chain_id).version(),
+            revision_height: counterparty_status.sync_info.latest_block_height.value(),
+        };
+        let counterparty_time = counterparty_status.sync_info.latest_block_time;
+
+        let counterparty_key = self
+            .counterparty_key_file
+            .as_ref()
+            .map(|path| CounterpartyKey::load(path, &self.counterparty_address_prefix))
+            .transpose()?;
+
+        let counterparty = connect(&self.counterparty_grpc_url).await?;
+        let mut relayer = ChannelRelayer {
+            penumbra: app.pd_channel().await?,
+            counterparty: counterparty.clone(),
+            port_id: PortId::from_str(&self.port)?,
+            channel_id: ChannelId::new(self.channel),
+            signer: app
+                .config
+                .full_viewing_key
+                .incoming()
+                .payment_address(AddressIndex::new(self.source))
+                .0
+                .to_string(),
+            counterparty_signer: counterparty_key
+                .as_ref()
+                .map(|key| key.address.clone())
+                .unwrap_or_default(),
+            counterparty_headers: CometBft::new(&self.counterparty_rpc_url)?,
+            counterparty_events: counterparty_rpc,
+            penumbra_headers: Proxy(app.tendermint_proxy_client().await?),
+            penumbra_events: Proxy(app.tendermint_proxy_client().await?),
+        };
+        let messages = relayer
+            .pending_messages(penumbra_height, penumbra_time)
+            .await?;
+        let counterparty_messages = relayer
+            .pending_counterparty_messages(counterparty_height, counterparty_time)
+            .await?;
+
+        if messages.is_empty() && counterparty_messages.is_empty() {
+            println!("nothing to relay on {}/channel-{}", self.port, self.channel);
+            return Ok(());
+        }
+        for message in &messages {
+            println!("to Penumbra: {message:?}");
+        }
+        for message in &counterparty_messages {
+            println!("to {counterparty_chain_id}: {message:?}");
+        }
+        if self.dry_run {
+            return Ok(());
+        }
+
+        if !messages.is_empty() {
+            let gas_prices = app
+                .view
+                .as_mut()
+                .context("view service must be initialized")?
+                .gas_prices(GasPricesRequest {})
+                .await?
+                .into_inner()
+                .gas_prices
+                .expect("gas prices must be available")
+                .try_into()?;
+
+            let mut planner = Planner::new(OsRng);
+            planner
+                .set_gas_prices(gas_prices)
+                .set_fee_tier(Default::default());
+            for message in messages {
+                planner.ibc_action(message);
+            }
+            let plan = planner
+                .plan(
+                    app.view
+                        .as_mut()
+                        .context("view service must be initialized")?,
+                    AddressIndex::new(self.source),
+                )
+                .await
+                .context("can't build relay transaction")?;
+            app.build_and_submit_transaction(plan).await?;
+        }
+
+        if !counterparty_messages.is_empty() {
+            let Some(key) = counterparty_key else {
+                println!(
+                    "not relaying to {counterparty_chain_id}: pass --counterparty-key-file to pay for it"
+                );
+                return Ok(());
+            };
+            let fee = self
+                .counterparty_fee
+                .as_deref()
+                .map(parse_coin)
+                .transpose()?
+                .into_iter()
+                .collect();
+            let response = key
+                .submit(
+                    counterparty,
+                    &counterparty_chain_id,
+                    counterparty_messages,
+                    fee,
+                    self.counterparty_gas_limit,
+                )
+                .await?;
+            if response.code != 0 {
+                anyhow::bail!(
+                    "relay transaction {} failed on {counterparty_chain_id}: {}",
+                    response.txhash,
+                    response.raw_log
+                );
+            }
+            println!(
+                "submitted relay transaction {} to {counterparty_chain_id}",
+                response.txhash
+            );
+        }
+
+        Ok(())
+    }
+
+    pub fn offline(&self) -> bool {
+        false
+    }
+}
+
+pub(super) async fn connect(url: &Url) -> Result<Channel> {
+    match url.scheme() {
+        "http" => Ok(Channel::from_shared(url.to_string())?.connect().await?),
+        "https" => Ok(Channel::from_shared(url.to_string())?
+            .tls_config(ClientTlsConfig::new())?
+            .connect()
+            .await?),
+        other => Err(anyhow::anyhow!("unknown url scheme {other}"))
+            .with_context(|| format!("could not connect to {url}")),
+    }
+}
+
+/// Parses a Cosmos SDK coin, such as `5000uosmo`.
+fn parse_coin(coin: &str) -> Result<Coin> {
+    let split = coin
+        .find(|c: char| !c.is_ascii_digit())
+        .filter(|&split| split > 0)
+        .with_context(|| format!("invalid coin {coin}, expected e.g. 5000uosmo"))?;
+    let (amount, denom) = coin.split_at(split);
+    Ok(Coin {
+        denom: denom.to_string(),
+        amount: amount.to_string(),
+    })
+}
+
+/// The query for the `kind` events of the packet with the given `sequence`, whose `end` ("src"
+/// or "dst") is `port_id`/`channel_id`.
+fn packet_query(
+    kind: &str,
+    end: &str,
+    port_id: &PortId,
+    channel_id: &ChannelId,
+    sequence: u64,
+) -> Query {
+    Query::eq(format!("{kind}.packet_{end}_port"), port_id.to_string())
+        .and_eq(
+            format!("{kind}.packet_{end}_channel"),
+            channel_id.to_string(),
+        )
+        .and_eq(format!("{kind}.packet_sequence"), sequence.to_string())
+}
+
+/// A secp256k1 key paying for relay transactions on the counterparty chain.
+struct CounterpartyKey {
+    signing_key: SigningKey,
+    /// The key's account address on the counterparty chain.
+    address: String,
+}
+
+impl CounterpartyKey {
+    /// Loads a hex-encoded key from `path`, deriving its address with the Bech32 `prefix`.
+    fn load(path: &Path, prefix: &str) -> Result<Self> {
+        let key = std::fs::read_to_string(path)
+            .with_context(|| format!("could not read {}", path.display()))?;
+        let signing_key = SigningKey::from_slice(&hex::decode(key.trim())?)?;
+        let address = Ripemd160::digest(Sha256::digest(
+            signing_key
+                .verifying_key()
+                .to_encoded_point(true)
+                .as_bytes(),
+        ));
+        Ok(Self {
+            signing_key,
+            address: bech32str::encode(&address, prefix, bech32str::Bech32),
+        })
+    }
+
+    /// Signs and broadcasts a transaction carrying `messages` to the counterparty chain `chain_id`
+    /// at `node`, returning the node's response.
+    async fn submit(
+        &self,
+        node: Channel,
+        chain_id: &str,
+        messages: Vec<IbcRelay>,
+        fee: Vec<Coin>,
+        gas_limit: u64,
+    ) -> Result<TxResponse> {
+        let account = AuthQueryClient::new(node.clone())
+            .account(QueryAccountRequest {
+                address: self.address.clone(),
+            })
+            .await?
+            .into_inner()
+            .account
+            .with_context(|| format!("account {} not found", self.address))?;
+        if account.type_url != BaseAccount::type_url() {
+            anyhow::bail!("unsupported account type {}", account.type_url);
+        }
+        let account = BaseAccount::decode(account.value)?;
+
+        let body = CosmosTxBody {
+            messages: messages
+                .into_iter()
+                .map(|message| {
+                    pb_ibc::IbcRelay::from(message)
+                        .raw_action
+                        .context("relayed message must be encodable")
+                })
+                .collect::<Result<_>>()?,
+            memo: String::new(),
+            timeout_height: 0,
+            extension_options: vec![],
+            non_critical_extension_options: vec![],
+        };
+        let auth_info = CosmosAuthInfo {
+            signer_infos: vec![CosmosSignerInfo {
+                public_key: Some(pbjson_types::Any {
+                    type_url: PubKey::type_url(),
+                    value: PubKey {
+                        key: self
+                            .signing_key
+                            .verifying_key()
+                            .to_encoded_point(true)
+                            .as_bytes()
+                            .to_vec(),
+                    }
+                    .encode_to_vec()
+                    .into(),
+                }),
+                mode_info: Some(ModeInfo {
+                    sum: Some(Sum::Single(Single {
+                        mode: SignMode::Direct as i32,
+                    })),
+                }),
+                sequence: account.sequence,
+            }],
+            fee: Some(CosmosFee {
+                amount: fee,
+                gas_limit,
+                payer: String::new(),
+                granter: String::new(),
+            }),
+            tip: None,
+        };
+        let sign_doc = SignDoc {
+            body_bytes: body.encode_to_vec(),
+            auth_info_bytes: auth_info.encode_to_vec(),
+            chain_id: chain_id.to_string(),
+            account_number: account.account_number,
+        };
+        let signature: Signature = self.signing_key.sign(&sign_doc.encode_to_vec());
+        let tx = TxRaw {
+            body_bytes: sign_doc.body_bytes,
+            auth_info_bytes: sign_doc.auth_info_bytes,
+            signatures: vec![signature.to_bytes().to_vec()],
+        };
+
+        CosmosServiceClient::new(node)
+            .broadcast_tx(CosmosBroadcastTxRequest {
+                tx_bytes: tx.encode_to_vec().into(),
+                mode: BroadcastMode::Sync as i32,
+            })
+            .await?
+            .into_inner()
+            .tx_response
+            .context("missing tx response")
+    }
+}
+
+/// Penumbra's tendermint proxy, serving its headers and packet events.
+struct Proxy(TendermintProxyServiceClient<Channel>);
+
+impl Proxy {
+    async fn block(&mut self, height: u64) -> Result<penumbra_proto::tendermint::types::Block> {
+        self.0
+            .get_block_by_height(GetBlockByHeightRequest {
+                height: height.try_into()?,
+            })
+            .await?
+            .into_inner()
+            .block
+            .with_context(|| format!("block {height} not found"))
+    }
+
+    async fn validators(&mut self, height: u64) -> Result<Vec<tendermint::validator::Info>> {
+        self.0
+            .get_validator_set(GetValidatorSetRequest {
+                height: height.try_into()?,
+            })
+            .await?
+            .into_inner()
+            .validators
+            .into_iter()
+            .map(TryInto::try_into)
+            .collect()
+    }
+
+    async fn packet_events(
+        &mut self,
+        kind: &str,
+        end: &str,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        sequences: &[u64],
+    ) -> Result<Vec<Event>> {
+        let mut events = Vec::new();
+        for sequence in sequences {
+            let query = packet_query(kind, end, port_id, channel_id, *sequence);
+            let response = self
+                .0
+                .search_tx_events(SearchTxEventsRequest {
+                    query: query.to_string(),
+                })
+                .await?
+                .into_inner();
+            events.extend(response.events.into_iter().map(Event::from));
+        }
+        Ok(events)
+    }
+}
+
+#[async_trait]
+impl HeaderSource for Proxy {
+    async fn latest_height(&mut self) -> Result<u64> {
+        // A block's commit is only served with the next block, so the latest block can't be
+        // relayed yet.
+        let height = self
+            .0
+            .get_status(GetStatusRequest {})
+            .await?
+            .into_inner()
+            .sync_info
+            .context("sync info must be available")?
+            .latest_block_height;
+        Ok(height.saturating_sub(1))
+    }
+
+    async fn header(&mut self, height: Height, trusted_height: Height) -> Result<TendermintHeader> {
+        let header: tendermint::block::Header = self
+            .block(height.revision_height)
+            .await?
+            .header
+            .context("block must have a header")?
+            .try_into()?;
+        let commit: tendermint::block::Commit = self
+            .block(height.revision_height + 1)
+            .await?
+            .last_commit
+            .context("block must have a last commit")?
+            .try_into()?;
+        let validators = self.validators(height.revision_height).await?;
+        // The client verifies the header against the validators it trusts to sign the block
+        // after its trusted height.
+        let trusted_validators = self.validators(trusted_height.revision_height + 1).await?;
+
+        Ok(TendermintHeader {
+            validator_set: Set::with_proposer(validators, header.proposer_address)?,
+            signed_header: SignedHeader::new(header, commit)?,
+            trusted_height,
+            trusted_validator_set: Set::without_proposer(trusted_validators),
+        })
+    }
+}
+
+#[async_trait]
+impl PacketEventSource for Proxy {
+    async fn send_packet_events(
+        &mut self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        sequences: &[u64],
+    ) -> Result<Vec<Event>> {
+        self.packet_events("send_packet", "src", port_id, channel_id, sequences)
+            .await
+    }
+
+    async fn write_acknowledgement_events(
+        &mut self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        sequences: &[u64],
+    ) -> Result<Vec<Event>> {
+        self.packet_events(
+            "write_acknowledgement",
+            "dst",
+            port_id,
+            channel_id,
+            sequences,
+        )
+        .await
+    }
+}
+
+/// A chain's CometBFT RPC endpoint, serving its headers and packet and client events.
+pub(super) struct CometBft(HttpClient);
+
+impl CometBft {
+    pub(super) fn new(url: &Url) -> Result<Self> {
+        Ok(Self(HttpClient::new(url.as_str())?))
+    }
+
+    /// Searches for the `kind` events of the packets with the given `sequences`, whose
+    /// `end` ("src" or "dst") is `port_id`/`channel_id`.
+    async fn packet_events(
+        &self,
+        kind: &str,
+        end: &str,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        sequences: &[u64],
+    ) -> Result<Vec<Event>> {
+        let mut events = Vec::new();
+        for sequence in sequences {
+            let query = packet_query(kind, end, port_id, channel_id, *sequence);
+            let response = self
+                .0
+                .tx_search(query, false, 1, 100, Order::Descending)
+                .await?;
+            events.extend(response.txs.into_iter().flat_map(|tx| tx.tx_result.events));
+        }
+        Ok(events)
+    }
+}
+
+#[async_trait]
+impl HeaderSource for CometBft {
+    async fn latest_height(&mut self) -> Result<u64> {
+        Ok(self.0.status().await?.sync_info.latest_block_height.value())
+    }
+
+    async fn header(&mut self, height: Height, trusted_height: Height) -> Result<TendermintHeader> {
+        let block_height = tendermint::block::Height::try_from(height.revision_height)?;
+        let signed_header = self.0.commit(block_height).await?.signed_header;
+        let validators = self
+            .0
+            .validators(block_height, Paging::All)
+            .await?
+            .validators;
+        // The client verifies the header against the validators it trusts to sign the block
+        // after its trusted height.
+        let trusted_validators = self
+            .0
+            .validators(
+                tendermint::block::Height::try_from(trusted_height.revision_height + 1)?,
+                Paging::All,
+            )
+            .await?
+            .validators;
+
+        Ok(TendermintHeader {
+            validator_set: Set::with_proposer(validators, signed_header.header.proposer_address)?,
+            signed_header,
+            trusted_height,
+            trusted_validator_set: Set::without_proposer(trusted_validators),
+        })
+    }
+}
+
+#[async_trait]
+impl PacketEventSource for CometBft {
+    async fn send_packet_events(
+        &mut self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        sequences: &[u64],
+    ) -> Result<Vec<Event>> {
+        self.packet_events("send_packet", "src", port_id, channel_id, sequences)
+            .await
+    }
+
+    async fn write_acknowledgement_events(
+        &mut self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        sequences: &[u64],
+    ) -> Result<Vec<Event>> {
+        self.packet_events(
+            "write_acknowledgement",
+            "dst",
+            port_id,
+            channel_id,
+            sequences,
+        )
+        .await
+    }
+}
+
+#[async_trait]
+impl ClientEventSource for CometBft {
+    async fn update_client_events(
+        &mut self,
+        client_id: &ClientId,
+        consensus_height: Height,
+    ) -> Result<Vec<Event>> {
+        let query = Query::eq("update_client.client_id", client_id.to_string()).and_eq(
+            "update_client.consensus_height",
+            consensus_height.to_string(),
+        );
+        let response = self
+            .0
+            .tx_search(query, false, 1, 100, Order::Descending)
+            .await?;
+        Ok(response
+            .txs
+            .into_iter()
+            .flat_map(|tx| tx.tx_result.events)
+            .collect())
+    }
+}
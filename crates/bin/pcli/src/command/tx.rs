@@ -1,14 +1,16 @@
 use std::{
-    collections::BTreeMap,
+    collections::{BTreeMap, BTreeSet},
     fs::{self, File},
-    io::{Read, Write},
+    io::Write,
     path::PathBuf,
     str::FromStr,
     time::{SystemTime, UNIX_EPOCH},
 };
 
 use anyhow::{ensure, Context, Result};
+use comfy_table::{presets, Table};
 use decaf377::{Fq, Fr};
+use futures::TryStreamExt;
 use ibc_proto::ibc::core::client::v1::{
     query_client::QueryClient as IbcClientQueryClient, QueryClientStateRequest,
 };
@@ -22,6 +24,7 @@ use ibc_types::core::{
     client::Height as IbcHeight,
 };
 use ibc_types::lightclients::tendermint::client_state::ClientState as TendermintClientState;
+use penumbra_app::params::AppParameters;
 use rand_core::OsRng;
 use regex::Regex;
 
@@ -29,11 +32,20 @@ use liquidity_position::PositionCmd;
 use penumbra_asset::{asset, asset::Metadata, Value, STAKING_TOKEN_ASSET_ID};
 use penumbra_dex::{lp::position, swap_claim::SwapClaimPlan};
 use penumbra_fee::FeeTier;
-use penumbra_governance::{proposal::ProposalToml, proposal_state::State as ProposalState, Vote};
+use penumbra_governance::{
+    proposal::ProposalToml, proposal_state::State as ProposalState, ProxyVote, ProxyVoteBody, Vote,
+};
 use penumbra_keys::{keys::AddressIndex, Address};
 use penumbra_num::Amount;
 use penumbra_proto::{
+    core::app::v1::{
+        query_service_client::QueryServiceClient as AppQueryServiceClient, SimulateProposalRequest,
+    },
     core::component::{
+        community_pool::v1::{
+            query_service_client::QueryServiceClient as CommunityPoolQueryServiceClient,
+            CommunityPoolAssetBalancesRequest, VestingGrantsRequest,
+        },
         dex::v1::{
             query_service_client::QueryServiceClient as DexQueryServiceClient,
             LiquidityPositionByIdRequest, PositionId,
@@ -51,25 +63,18 @@ use penumbra_proto::{
             ValidatorPenaltyRequest,
         },
     },
-    cosmos::tx::v1beta1::{
-        mode_info::{Single, Sum},
-        service_client::ServiceClient as CosmosServiceClient,
-        AuthInfo as CosmosAuthInfo, BroadcastTxRequest as CosmosBroadcastTxRequest,
-        Fee as CosmosFee, ModeInfo, SignerInfo as CosmosSignerInfo, Tx as CosmosTx,
-        TxBody as CosmosTxBody,
-    },
-    noble::forwarding::v1::{ForwardingPubKey, MsgRegisterAccount},
     view::v1::GasPricesRequest,
-    Message, Name as _,
 };
 use penumbra_shielded_pool::Ics20Withdrawal;
 use penumbra_stake::rate::RateData;
-use penumbra_stake::{DelegationToken, IdentityKey, Penalty, UnbondingToken, UndelegateClaimPlan};
+use penumbra_stake::{
+    DelegationToken, GovernanceKey, IdentityKey, Penalty, UnbondingToken, UndelegateClaimPlan,
+};
 use penumbra_transaction::{gas::swap_claim_gas_cost, Transaction};
-use penumbra_view::{SpendableNoteRecord, ViewClient};
+use penumbra_view::{register_forwarding_account, SpendableNoteRecord, ViewClient};
 use penumbra_wallet::plan::{self, Planner};
 use proposal::ProposalCmd;
-use tonic::transport::{Channel, ClientTlsConfig};
+use schedule::ScheduleCmd;
 use url::Url;
 
 use crate::command::tx::auction::AuctionCmd;
@@ -80,6 +85,7 @@ mod auction;
 mod liquidity_position;
 mod proposal;
 mod replicate;
+mod schedule;
 
 /// The planner can fail to build a large transaction, so
 /// pcli splits apart the number of positions to close/withdraw
@@ -157,6 +163,21 @@ pub enum TxCmd {
         #[clap(short, long, default_value_t)]
         fee_tier: FeeTier,
     },
+    /// Move stake from one validator's delegation pool to another's, without unbonding.
+    #[clap(display_order = 200)]
+    Redelegate {
+        /// The amount of delegation tokens to redelegate.
+        amount: String,
+        /// The identity key of the validator to redelegate to.
+        #[clap(long, display_order = 100)]
+        to: String,
+        /// Only spend funds originally received by the given account.
+        #[clap(long, default_value = "0", display_order = 300)]
+        source: u32,
+        /// The selected fee tier to multiply the fee amount by.
+        #[clap(short, long, default_value_t)]
+        fee_tier: FeeTier,
+    },
     /// Claim any undelegations that have finished unbonding.
     #[clap(display_order = 200)]
     UndelegateClaim {
@@ -195,6 +216,28 @@ pub enum TxCmd {
         source: u32,
         #[clap(subcommand)]
         vote: VoteCmd,
+        /// Delegate the voting weight to this proxy for the proposal. The vote given here only
+        /// counts if the proxy does not vote.
+        #[clap(long, global = true, display_order = 400)]
+        proxy: Option<GovernanceKey>,
+        /// Record the choice of proxy on chain, so that it also applies to proposals started
+        /// later, until the voting notes are spent. Without `--proxy`, this clears any standing
+        /// delegation of the voting notes.
+        #[clap(long, global = true, display_order = 500)]
+        standing: bool,
+        /// The selected fee tier to multiply the fee amount by.
+        #[clap(short, long, default_value_t)]
+        fee_tier: FeeTier,
+    },
+    /// Vote on a governance proposal as a proxy, on behalf of the delegators who delegated their
+    /// voting weight to you (see also: `pcli view proxy-key`).
+    #[clap(display_order = 450)]
+    ProxyVote {
+        /// Only spend funds originally received by the given account.
+        #[clap(long, default_value = "0", global = true, display_order = 300)]
+        source: u32,
+        #[clap(subcommand)]
+        vote: VoteCmd,
         /// The selected fee tier to multiply the fee amount by.
         #[clap(short, long, default_value_t)]
         fee_tier: FeeTier,
@@ -214,9 +257,34 @@ pub enum TxCmd {
         #[clap(short, long, default_value_t)]
         fee_tier: FeeTier,
     },
+    /// Claim vested funds from a Community Pool vesting grant.
+    ///
+    /// The funds are always paid to the grant's recipient, no matter who submits the claim.
+    #[clap(display_order = 600)]
+    CommunityPoolVestingClaim {
+        /// The ID of the grant, which is the ID of the proposal that created it.
+        grant_id: u64,
+        /// The amount to claim. If not specified, all the funds that have vested so far and have
+        /// not been claimed yet are claimed.
+        #[clap(long)]
+        amount: Option<String>,
+        /// Only spend funds originally received by the given account.
+        #[clap(long, default_value = "0", display_order = 300)]
+        source: u32,
+        /// The selected fee tier to multiply the fee amount by.
+        #[clap(short, long, default_value_t)]
+        fee_tier: FeeTier,
+    },
     /// Manage liquidity positions.
     #[clap(display_order = 500, subcommand, visible_alias = "lp")]
     Position(PositionCmd),
+    /// Schedule transactions to be executed later, once, at a fixed interval, or every epoch.
+    ///
+    /// Scheduled transactions are stored by the view service, but only executed by `pclientd`
+    /// running in custody mode: neither `pcli` nor `pclientd` in view-only mode submit them. To
+    /// manage the schedules of a `pclientd`, set `view_url` in the `pcli` config to its address.
+    #[clap(display_order = 700, subcommand)]
+    Schedule(ScheduleCmd),
     /// Consolidate many small notes into a few larger notes.
     ///
     /// Since Penumbra transactions reveal their arity (how many spends,
@@ -272,10 +340,16 @@ pub enum TxCmd {
     },
     #[clap(display_order = 970)]
     /// Register a Noble forwarding account.
+    ///
+    /// Forwarding accounts for address indices are also added to the view service's registry,
+    /// listed by `pcli view forwarding-accounts`.
     RegisterForwardingAccount {
         /// The Noble node to submit the registration transaction to.
         #[clap(long)]
         noble_node: Url,
+        /// The chain ID of the Noble chain.
+        #[clap(long, default_value = "noble-1")]
+        chain_id: String,
         /// The Noble IBC channel to use for forwarding.
         #[clap(long)]
         channel: String,
@@ -285,6 +359,10 @@ pub enum TxCmd {
         /// Whether or not to use an ephemeral address.
         #[clap(long)]
         ephemeral: bool,
+        /// Only add the forwarding account to the view service's registry, which registers it
+        /// once deposits arrive in it.
+        #[clap(long)]
+        when_funded: bool,
     },
     /// Broadcast a saved transaction to the network
     #[clap(display_order = 1000)]
@@ -339,11 +417,15 @@ impl TxCmd {
             TxCmd::Swap { .. } => false,
             TxCmd::Delegate { .. } => false,
             TxCmd::Undelegate { .. } => false,
+            TxCmd::Redelegate { .. } => false,
             TxCmd::UndelegateClaim { .. } => false,
             TxCmd::Vote { .. } => false,
+            TxCmd::ProxyVote { .. } => false,
             TxCmd::Proposal(proposal_cmd) => proposal_cmd.offline(),
             TxCmd::CommunityPoolDeposit { .. } => false,
+            TxCmd::CommunityPoolVestingClaim { .. } => false,
             TxCmd::Position(lp_cmd) => lp_cmd.offline(),
+            TxCmd::Schedule(schedule_cmd) => schedule_cmd.offline(),
             TxCmd::Withdraw { .. } => false,
             TxCmd::Auction(_) => false,
             TxCmd::Broadcast { .. } => false,
@@ -432,6 +514,68 @@ impl TxCmd {
                     .await?;
                 app.build_and_submit_transaction(plan).await?;
             }
+            TxCmd::CommunityPoolVestingClaim {
+                grant_id,
+                amount,
+                source,
+                fee_tier,
+            } => {
+                let mut client = CommunityPoolQueryServiceClient::new(app.pd_channel().await?);
+                let response = client
+                    .vesting_grants(VestingGrantsRequest {
+                        grant_ids: vec![*grant_id],
+                    })
+                    .await?
+                    .into_inner()
+                    .message()
+                    .await?
+                    .ok_or_else(|| anyhow::anyhow!("vesting grant {grant_id} not found"))?;
+                let grant: penumbra_community_pool::VestingGrant = response
+                    .grant
+                    .ok_or_else(|| anyhow::anyhow!("missing vesting grant"))?
+                    .try_into()?;
+                let claimable: Amount = response
+                    .claimable
+                    .ok_or_else(|| anyhow::anyhow!("missing claimable amount"))?
+                    .try_into()?;
+
+                let amount = match amount {
+                    Some(amount) => {
+                        let value: Value = amount.parse()?;
+                        ensure!(
+                            value.asset_id == grant.total.asset_id,
+                            "vesting grant {grant_id} is not denominated in that asset"
+                        );
+                        value.amount
+                    }
+                    None => claimable,
+                };
+                ensure!(
+                    amount > Amount::zero() && amount <= claimable,
+                    "can only claim up to {} from vesting grant {grant_id}",
+                    Value {
+                        amount: claimable,
+                        asset_id: grant.total.asset_id,
+                    }
+                    .format(&app.view().assets().await?)
+                );
+
+                let mut planner = Planner::new(OsRng);
+                planner
+                    .set_gas_prices(gas_prices)
+                    .set_fee_tier((*fee_tier).into());
+                planner.community_pool_vesting_claim(*grant_id, amount);
+                let plan = planner
+                    .plan(
+                        app.view
+                            .as_mut()
+                            .context("view service must be initialized")?,
+                        AddressIndex::new(*source),
+                    )
+                    .await
+                    .context("can't build vesting claim transaction")?;
+                app.build_and_submit_transaction(plan).await?;
+            }
             TxCmd::Sweep => loop {
                 let plans = plan::sweep(
                     app.view
@@ -660,6 +804,75 @@ impl TxCmd {
 
                 app.build_and_submit_transaction(plan).await?;
             }
+            TxCmd::Redelegate {
+                amount,
+                to,
+                source,
+                fee_tier,
+            } => {
+                let delegation_value @ Value {
+                    amount: _,
+                    asset_id,
+                } = amount.parse::<Value>()?;
+
+                let delegation_token: DelegationToken = app
+                    .view()
+                    .assets()
+                    .await?
+                    .get(&asset_id)
+                    .ok_or_else(|| anyhow::anyhow!("unknown asset id {}", asset_id))?
+                    .clone()
+                    .try_into()
+                    .context("could not parse supplied denomination as a delegation token")?;
+
+                let from = delegation_token.validator();
+                let to = to.parse::<IdentityKey>()?;
+                if from == to {
+                    anyhow::bail!("cannot redelegate to the same validator");
+                }
+
+                let mut stake_client = StakeQueryServiceClient::new(app.pd_channel().await?);
+                let source_rate_data: RateData = stake_client
+                    .current_validator_rate(tonic::Request::new(from.into()))
+                    .await?
+                    .into_inner()
+                    .try_into()?;
+                let destination_rate_data: RateData = stake_client
+                    .current_validator_rate(tonic::Request::new(to.into()))
+                    .await?
+                    .into_inner()
+                    .try_into()?;
+
+                let mut sct_client = SctQueryServiceClient::new(app.pd_channel().await?);
+                let latest_sync_height = app.view().status().await?.full_sync_height;
+                let epoch = sct_client
+                    .epoch_by_height(EpochByHeightRequest {
+                        height: latest_sync_height,
+                    })
+                    .await?
+                    .into_inner()
+                    .epoch
+                    .expect("epoch must be available")
+                    .into();
+
+                let mut planner = Planner::new(OsRng);
+                planner
+                    .set_gas_prices(gas_prices)
+                    .set_fee_tier((*fee_tier).into());
+
+                let plan = planner
+                    .redelegate(
+                        epoch,
+                        delegation_value.amount,
+                        source_rate_data,
+                        destination_rate_data,
+                    )
+                    .plan(app.view(), AddressIndex::new(*source))
+                    .await
+                    .context("can't build redelegate plan")?;
+
+                app.build_and_submit_transaction(plan).await?;
+            }
             TxCmd::UndelegateClaim { fee_tier } => {
                 let channel = app.pd_channel().await?;
                 let view: &mut dyn ViewClient = app
@@ -781,16 +994,7 @@ impl TxCmd {
                 deposit_amount,
                 fee_tier,
             }) => {
-                let mut proposal_file = File::open(file).context("can't open proposal file")?;
-                let mut proposal_string = String::new();
-                proposal_file
-                    .read_to_string(&mut proposal_string)
-                    .context("can't read proposal file")?;
-                let proposal_toml: ProposalToml =
-                    toml::from_str(&proposal_string).context("can't parse proposal file")?;
-                let proposal = proposal_toml
-                    .try_into()
-                    .context("can't parse proposal file")?;
+                let proposal = proposal::read_proposal(file)?;
 
                 let deposit_amount: Value = deposit_amount.parse()?;
                 ensure!(
@@ -813,6 +1017,106 @@ impl TxCmd {
                     .await?;
                 app.build_and_submit_transaction(plan).await?;
             }
+            TxCmd::Proposal(ProposalCmd::Simulate { file }) => {
+                let proposal = proposal::read_proposal(file)?;
+                let current_app_params = app.view().app_params().await?;
+                let current_pool_balance: BTreeMap<asset::Id, Amount> =
+                    CommunityPoolQueryServiceClient::new(app.pd_channel().await?)
+                        .community_pool_asset_balances(CommunityPoolAssetBalancesRequest {
+                            asset_ids: vec![],
+                        })
+                        .await?
+                        .into_inner()
+                        .map_err(anyhow::Error::from)
+                        .and_then(|response| async move {
+                            let balance: Value = response
+                                .balance
+                                .context("balance should always be set")?
+                                .try_into()?;
+                            anyhow::Ok((balance.asset_id, balance.amount))
+                        })
+                        .try_collect()
+                        .await?;
+
+                let simulation = AppQueryServiceClient::new(app.pd_channel().await?)
+                    .simulate_proposal(SimulateProposalRequest {
+                        proposal: Some(proposal.into()),
+                    })
+                    .await?
+                    .into_inner();
+
+                if !simulation.submission_error.is_empty() {
+                    println!(
+                        "The proposal would be rejected when submitted: {}",
+                        simulation.submission_error
+                    );
+                    return Ok(());
+                }
+                println!("The proposal would be accepted for voting.");
+                if simulation.enactment_error.is_empty() {
+                    println!("If it passed, it would be enacted successfully.");
+                } else {
+                    println!(
+                        "If it passed, it would fail to enact: {}",
+                        simulation.enactment_error
+                    );
+                }
+                if !simulation.community_pool_transaction_error.is_empty() {
+                    println!(
+                        "Its Community Pool transaction would fail: {}",
+                        simulation.community_pool_transaction_error
+                    );
+                }
+
+                if let Some(new_app_params) = simulation.new_app_parameters {
+                    let new_app_params: AppParameters = new_app_params.try_into()?;
+                    let changes =
+                        proposal::parameter_changes(&current_app_params, &new_app_params)?;
+                    let mut table = Table::new();
+                    table.load_preset(presets::NOTHING);
+                    table.set_header(vec!["Parameter", "Current", "Proposed"]);
+                    for (parameter, current, proposed) in changes {
+                        table.add_row(vec![parameter, current, proposed]);
+                    }
+                    println!("\nParameter changes:\n{table}");
+                }
+
+                let asset_cache = app.view().assets().await?;
+                let mut table = Table::new();
+                table.load_preset(presets::NOTHING);
+                table.set_header(vec!["Community Pool", "Current", "After Enactment"]);
+                let mut pool_changed = false;
+                let mut new_pool_balance = BTreeMap::new();
+                for value in simulation.community_pool_balance {
+                    let value: Value = value.try_into()?;
+                    new_pool_balance.insert(value.asset_id, value.amount);
+                }
+                let asset_ids = current_pool_balance
+                    .keys()
+                    .chain(new_pool_balance.keys())
+                    .collect::<BTreeSet<_>>();
+                for asset_id in asset_ids {
+                    let current = current_pool_balance
+                        .get(asset_id)
+                        .copied()
+                        .unwrap_or_default();
+                    let new = new_pool_balance.get(asset_id).copied().unwrap_or_default();
+                    if current != new {
+                        let format = |amount| {
+                            Value {
+                                amount,
+                                asset_id: *asset_id,
+                            }
+                            .format(&asset_cache)
+                        };
+                        table.add_row(vec![String::new(), format(current), format(new)]);
+                        pool_changed = true;
+                    }
+                }
+                if pool_changed {
+                    println!("\n{table}");
+                }
+            }
             TxCmd::Proposal(ProposalCmd::Withdraw {
                 proposal_id,
                 reason,
@@ -917,9 +1221,25 @@ impl TxCmd {
             TxCmd::Vote {
                 vote,
                 source,
+                proxy,
+                standing,
                 fee_tier,
             } => {
                 let (proposal_id, vote): (u64, Vote) = (*vote).into();
+                match (proxy, *standing) {
+                    (Some(proxy), false) => {
+                        println!(
+                            "delegating voting weight on proposal {proposal_id} to proxy {proxy}"
+                        );
+                    }
+                    (Some(proxy), true) => {
+                        println!("delegating voting weight on proposal {proposal_id} and later proposals to proxy {proxy}");
+                    }
+                    (None, true) => {
+                        println!("clearing any standing delegation of voting weight");
+                    }
+                    (None, false) => {}
+                }
 
                 // Before we vote on the proposal, we have to gather some information about it so
                 // that we can prepare our vote:
@@ -968,6 +1288,8 @@ impl TxCmd {
                         start_block_height,
                         start_position,
                         start_rate_data,
+                        *proxy,
+                        *standing,
                     )
                     .await?
                     .plan(
@@ -980,6 +1302,34 @@ impl TxCmd {
 
                 app.build_and_submit_transaction(plan).await?;
             }
+            TxCmd::ProxyVote {
+                vote,
+                source,
+                fee_tier,
+            } => {
+                let (proposal_id, vote): (u64, Vote) = (*vote).into();
+
+                let body = ProxyVoteBody {
+                    proposal: proposal_id,
+                    vote,
+                    proxy: app.config.proxy_key(),
+                };
+                let auth_sig = app.sign_proxy_vote(body.clone()).await?;
+
+                let plan = Planner::new(OsRng)
+                    .set_gas_prices(gas_prices)
+                    .set_fee_tier((*fee_tier).into())
+                    .proxy_vote(ProxyVote { body, auth_sig })
+                    .plan(
+                        app.view
+                            .as_mut()
+                            .context("view service must be initialized")?,
+                        AddressIndex::new(*source),
+                    )
+                    .await?;
+
+                app.build_and_submit_transaction(plan).await?;
+            }
             TxCmd::Position(PositionCmd::Order(order)) => {
                 let asset_cache = app.view().assets().await?;
 
@@ -1370,15 +1720,20 @@ impl TxCmd {
             TxCmd::Auction(AuctionCmd::Dutch(auction_cmd)) => {
                 auction_cmd.exec(app).await?;
             }
+            TxCmd::Schedule(schedule_cmd) => {
+                schedule_cmd.exec(app).await?;
+            }
             TxCmd::Broadcast { transaction } => {
                 let transaction: Transaction = serde_json::from_slice(&fs::read(transaction)?)?;
                 app.submit_transaction(transaction).await?;
             }
             TxCmd::RegisterForwardingAccount {
                 noble_node,
+                chain_id,
                 channel,
                 address_or_index,
                 ephemeral,
+                when_funded,
             } => {
                 let index: Result<u32, _> = address_or_index.parse();
                 let fvk = app.config.full_viewing_key.clone();
@@ -1402,76 +1757,38 @@ impl TxCmd {
 
                 let noble_address = address.noble_forwarding_address(channel);
 
+                // Forwarding accounts for indexed addresses are remembered in the view service,
+                // which registers them once deposits arrive if they aren't registered yet.
+                if let (Ok(index), false) = (index, *ephemeral) {
+                    app.view()
+                        .add_forwarding_account(
+                            chain_id.clone(),
+                            noble_node.clone(),
+                            channel.clone(),
+                            index.into(),
+                            false,
+                        )
+                        .await?;
+                } else if *when_funded {
+                    anyhow::bail!(
+                        "only forwarding accounts for address indices can be registered when funded"
+                    );
+                }
+
+                if *when_funded {
+                    println!(
+                        "Noble forwarding account {} will be registered once it receives deposits, to forward them to Penumbra address {}",
+                        noble_address, address
+                    );
+                    return Ok(());
+                }
+
                 println!(
                     "registering Noble forwarding account with address {} to forward to Penumbra address {}...",
                     noble_address, address
                 );
 
-                let mut noble_client = CosmosServiceClient::new(
-                    Channel::from_shared(noble_node.to_string())?
-                        .tls_config(ClientTlsConfig::new())?
-                        .connect()
-                        .await?,
-                );
-
-                let tx = CosmosTx {
-                    body: Some(CosmosTxBody {
-                        messages: vec![pbjson_types::Any {
-                            type_url: MsgRegisterAccount::type_url(),
-                            value: MsgRegisterAccount {
-                                signer: noble_address.to_string(),
-                                recipient: address.to_string(),
-                                channel: channel.to_string(),
-                            }
-                            .encode_to_vec()
-                            .into(),
-                        }],
-                        memo: "".to_string(),
-                        timeout_height: 0,
-                        extension_options: vec![],
-                        non_critical_extension_options: vec![],
-                    }),
-                    auth_info: Some(CosmosAuthInfo {
-                        signer_infos: vec![CosmosSignerInfo {
-                            public_key: Some(pbjson_types::Any {
-                                type_url: ForwardingPubKey::type_url(),
-                                value: ForwardingPubKey {
-                                    key: noble_address.bytes(),
-                                }
-                                .encode_to_vec()
-                                .into(),
-                            }),
-                            mode_info: Some(ModeInfo {
-                                // SIGN_MODE_DIRECT
-                                sum: Some(Sum::Single(Single { mode: 1 })),
-                            }),
-                            sequence: 0,
-                        }],
-                        fee: Some(CosmosFee {
-                            amount: vec![],
-                            gas_limit: 200000u64,
-                            payer: "".to_string(),
-                            granter: "".to_string(),
-                        }),
-                        tip: None,
-                    }),
-                    signatures: vec![vec![]],
-                };
-                let r = noble_client
-                    .broadcast_tx(CosmosBroadcastTxRequest {
-                        tx_bytes: tx.encode_to_vec().into(),
-                        // sync
-                        mode: 2,
-                    })
-                    .await?;
-
-                // let r = noble_client
-                //     .register_account(MsgRegisterAccount {
-                //         signer: noble_address,
-                //         recipient: address.to_string(),
-                //         channel: channel.to_string(),
-                //     })
-                //     .await?;
+                let r = register_forwarding_account(noble_node, channel, &address).await?;
 
                 println!("Noble response: {:?}", r);
             }
@@ -1,7 +1,13 @@
+use std::{collections::BTreeMap, fs::File, io::Read};
+
 use anyhow::{Context, Result};
 
 use penumbra_app::params::AppParameters;
-use penumbra_governance::{change::ParameterChange, Proposal, ProposalPayload};
+use penumbra_asset::Value;
+use penumbra_governance::{
+    change::ParameterChange, proposal::ProposalToml, Proposal, ProposalPayload,
+};
+use penumbra_keys::Address;
 use penumbra_proto::DomainType;
 use penumbra_transaction::TransactionPlan;
 
@@ -14,7 +20,7 @@ pub enum ProposalCmd {
         /// The file to output the template to.
         #[clap(long, global = true)]
         file: Option<camino::Utf8PathBuf>,
-        /// The kind of the proposal to template [one of: signaling, emergency, parameter-change, or community-pool-spend].
+        /// The kind of the proposal to template [one of: signaling, emergency, parameter-change, community-pool-spend, upgrade-plan, community-pool-vesting-grant, cancel-community-pool-vesting-grant, register-interchain-account, interchain-account-tx, or batch].
         #[clap(subcommand)]
         kind: ProposalKindCmd,
     },
@@ -33,6 +39,15 @@ pub enum ProposalCmd {
         #[clap(short, long, default_value_t)]
         fee_tier: FeeTier,
     },
+    /// Simulate a governance proposal against the latest chain state, without submitting it.
+    ///
+    /// This reports whether the proposal would be accepted, and what it would change or why it
+    /// would fail if it passed. The node must be running with expensive RPCs enabled.
+    Simulate {
+        /// The proposal to simulate, in TOML format.
+        #[clap(long)]
+        file: camino::Utf8PathBuf,
+    },
     /// Withdraw a governance proposal that you previously submitted.
     Withdraw {
         /// The proposal id to withdraw.
@@ -84,6 +99,74 @@ pub enum ProposalKindCmd {
     },
     /// Generate a template for an upgrade proposal,
     UpgradePlan,
+    /// Generate a template for a Community Pool vesting grant proposal.
+    CommunityPoolVestingGrant {
+        /// The address that vested funds are paid to.
+        #[clap(long)]
+        recipient: Address,
+        /// The total value of the grant, set aside from the Community Pool if the proposal passes.
+        #[clap(long)]
+        total: Value,
+        /// The number of epochs after the proposal passes before any funds vest.
+        #[clap(long, default_value = "0")]
+        cliff_epochs: u64,
+        /// The value that vests in each epoch, in the same asset as the total.
+        #[clap(long)]
+        release_per_epoch: Value,
+    },
+    /// Generate a template for a proposal cancelling a Community Pool vesting grant.
+    CancelCommunityPoolVestingGrant {
+        /// The ID of the grant to cancel, which is the ID of the proposal that created it.
+        #[clap(long)]
+        grant_id: u64,
+    },
+    /// Generate a template for a proposal registering a Community Pool interchain account.
+    RegisterInterchainAccount {
+        /// The IBC connection to the host chain, e.g. "connection-0".
+        #[clap(long)]
+        connection_id: String,
+    },
+    /// Generate a template for a proposal sending a transaction from the Community Pool's
+    /// interchain account.
+    ///
+    /// The template contains no messages; add the host chain messages to execute before
+    /// submission.
+    InterchainAccountTx {
+        /// The IBC connection to the host chain, e.g. "connection-0".
+        #[clap(long)]
+        connection_id: String,
+        /// How long after enactment the host chain may still execute the transaction.
+        #[clap(long, default_value = "3600")]
+        timeout_seconds: u64,
+    },
+    /// Generate a template for a batch proposal, which enacts several payloads atomically.
+    Batch {
+        /// The kind of each payload to include in the batch, in the order they are enacted.
+        #[clap(long = "kind", value_enum, required = true)]
+        kinds: Vec<BatchPayloadKind>,
+    },
+}
+
+/// The kinds of payload which can be templated as part of a batch proposal.
+#[derive(Clone, Copy, Debug, clap::ValueEnum)]
+pub enum BatchPayloadKind {
+    Signaling,
+    ParameterChange,
+    CommunityPoolSpend,
+    UpgradePlan,
+}
+
+impl From<BatchPayloadKind> for ProposalKindCmd {
+    fn from(kind: BatchPayloadKind) -> Self {
+        match kind {
+            BatchPayloadKind::Signaling => ProposalKindCmd::Signaling,
+            BatchPayloadKind::ParameterChange => ProposalKindCmd::ParameterChange,
+            BatchPayloadKind::CommunityPoolSpend => ProposalKindCmd::CommunityPoolSpend {
+                transaction_plan: None,
+            },
+            BatchPayloadKind::UpgradePlan => ProposalKindCmd::UpgradePlan,
+        }
+    }
 }
 
 impl ProposalKindCmd {
@@ -118,6 +201,57 @@ impl ProposalKindCmd {
                 }
             }
             ProposalKindCmd::UpgradePlan { .. } => ProposalPayload::UpgradePlan { height: 0 },
+            ProposalKindCmd::CommunityPoolVestingGrant {
+                recipient,
+                total,
+                cliff_epochs,
+                release_per_epoch,
+            } => {
+                anyhow::ensure!(
+                    total.asset_id == release_per_epoch.asset_id,
+                    "the release per epoch must be in the same asset as the total"
+                );
+                ProposalPayload::CommunityPoolVestingGrant {
+                    recipient: recipient.clone(),
+                    total: *total,
+                    cliff_epochs: *cliff_epochs,
+                    release_per_epoch: release_per_epoch.amount,
+                }
+            }
+            ProposalKindCmd::CancelCommunityPoolVestingGrant { grant_id } => {
+                ProposalPayload::CancelCommunityPoolVestingGrant {
+                    grant_id: *grant_id,
+                }
+            }
+            ProposalKindCmd::RegisterInterchainAccount { connection_id } => {
+                ProposalPayload::RegisterInterchainAccount {
+                    connection_id: connection_id.clone(),
+                }
+            }
+            ProposalKindCmd::InterchainAccountTx {
+                connection_id,
+                timeout_seconds,
+            } => ProposalPayload::InterchainAccountTx {
+                connection_id: connection_id.clone(),
+                messages: Vec::new(),
+                memo: String::new(),
+                timeout_seconds: *timeout_seconds,
+            },
+            ProposalKindCmd::Batch { kinds } => {
+                let payloads = kinds
+                    .iter()
+                    .map(|kind| {
+                        Ok(ProposalKindCmd::from(*kind)
+                            .template_proposal(app_params, id)?
+                            .payload)
+                    })
+                    .collect::<Result<Vec<_>>>()?;
+                ProposalPayload::validate_batch(&payloads)?;
+                ProposalPayload::Batch {
+                    payloads,
+                    conditions: Vec::new(),
+                }
+            }
         };
 
         Ok(Proposal {
@@ -134,8 +268,66 @@ impl ProposalCmd {
         match self {
             ProposalCmd::Template { .. } => false,
             ProposalCmd::Submit { .. } => false,
+            ProposalCmd::Simulate { .. } => false,
             ProposalCmd::Withdraw { .. } => false,
             ProposalCmd::DepositClaim { .. } => false,
         }
     }
 }
+
+/// Reads a proposal from a TOML file.
+pub fn read_proposal(file: &camino::Utf8Path) -> Result<Proposal> {
+    let mut proposal_file = File::open(file).context("can't open proposal file")?;
+    let mut proposal_string = String::new();
+    proposal_file
+        .read_to_string(&mut proposal_string)
+        .context("can't read proposal file")?;
+    let proposal_toml: ProposalToml =
+        toml::from_str(&proposal_string).context("can't parse proposal file")?;
+    proposal_toml
+        .try_into()
+        .context("can't parse proposal file")
+}
+
+/// Lists the app parameters which differ between `old` and `new`, as their path within the
+/// parameters, followed by their old and new values.
+pub fn parameter_changes(
+    old: &AppParameters,
+    new: &AppParameters,
+) -> Result<Vec<(String, String, String)>> {
+    fn flatten(path: String, value: serde_json::Value, out: &mut BTreeMap<String, String>) {
+        match value {
+            serde_json::Value::Object(fields) => {
+                for (key, value) in fields {
+                    let path = if path.is_empty() {
+                        key
+                    } else {
+                        format!("{path}.{key}")
+                    };
+                    flatten(path, value, out);
+                }
+            }
+            value => {
+                out.insert(path, value.to_string());
+            }
+        }
+    }
+
+    let mut old_values = BTreeMap::new();
+    flatten(String::new(), serde_json::to_value(old)?, &mut old_values);
+    let mut new_values = BTreeMap::new();
+    flatten(String::new(), serde_json::to_value(new)?, &mut new_values);
+
+    let mut changes = Vec::new();
+    for (path, new_value) in new_values {
+        let old_value = old_values.remove(&path).unwrap_or_default();
+        if old_value != new_value {
+            changes.push((path, old_value, new_value));
+        }
+    }
+    // Parameters which were removed entirely, e.g. by unsetting them.
+    for (path, old_value) in old_values {
+        changes.push((path, old_value, String::new()));
+    }
+    Ok(changes)
+}
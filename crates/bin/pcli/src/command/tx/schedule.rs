@@ -0,0 +1,300 @@
+use anyhow::{Context, Result};
+use comfy_table::{presets, Table};
+
+use penumbra_asset::{asset, Value};
+use penumbra_keys::{keys::AddressIndex, Address};
+use penumbra_proto::view::v1::{
+    transaction_planner_request::{Output, Swap},
+    GasPricesRequest, TransactionPlannerRequest,
+};
+use penumbra_transaction::gas::swap_claim_gas_cost;
+use penumbra_view::{ScheduleStatus, ViewClient};
+
+use crate::App;
+
+/// The schedule of a scheduled transaction.
+#[derive(Debug, clap::Args)]
+pub struct ScheduleArgs {
+    /// Execute the transaction every N blocks.
+    #[clap(long, conflicts_with = "every_epoch", display_order = 100)]
+    every: Option<u64>,
+    /// Execute the transaction at the start of every epoch, beginning with the next one.
+    #[clap(long, display_order = 100)]
+    every_epoch: bool,
+    /// The height of the first execution. Defaults to the next block.
+    #[clap(long, conflicts_with = "every_epoch", display_order = 200)]
+    start: Option<u64>,
+    /// Stop after this many executions. Defaults to no limit for recurring
+    /// transactions.
+    #[clap(long, display_order = 200)]
+    count: Option<u64>,
+    /// Only spend funds originally received by the given account.
+    #[clap(long, default_value = "0", display_order = 300)]
+    source: u32,
+}
+
+#[derive(Debug, clap::Subcommand)]
+pub enum ScheduleCmd {
+    /// Schedule sending funds to an address, once or at a fixed interval.
+    Send {
+        /// The destination address to send funds to.
+        #[clap(long)]
+        to: String,
+        /// The amounts to send, written as typed values 1.87penumbra, 12cubes, etc.
+        values: Vec<String>,
+        #[clap(flatten)]
+        schedule: ScheduleArgs,
+    },
+    /// Schedule a swap, once or at a fixed interval, claiming its outputs once executed.
+    Swap {
+        /// The input amount to swap, written as a typed value 1.87penumbra, 12cubes, etc.
+        input: String,
+        /// The denomination to swap the input into, e.g. `gm`
+        #[clap(long)]
+        into: String,
+        #[clap(flatten)]
+        schedule: ScheduleArgs,
+    },
+    /// List the scheduled transactions and the outcome of their executions.
+    List {
+        /// Also list cancelled and completed schedules.
+        #[clap(long)]
+        all: bool,
+    },
+    /// Pause a scheduled transaction, skipping its executions until it is resumed.
+    Pause {
+        /// The ID of the schedule to pause.
+        id: u64,
+    },
+    /// Resume a paused scheduled transaction.
+    Resume {
+        /// The ID of the schedule to resume.
+        id: u64,
+    },
+    /// Cancel a scheduled transaction.
+    Cancel {
+        /// The ID of the schedule to cancel.
+        id: u64,
+    },
+}
+
+impl ScheduleCmd {
+    pub fn offline(&self) -> bool {
+        // Schedules are stored by the view service, so every command needs it.
+        false
+    }
+
+    pub async fn exec(&self, app: &mut App) -> Result<()> {
+        match self {
+            ScheduleCmd::Send {
+                to,
+                values,
+                schedule,
+            } => {
+                let to = to
+                    .parse::<Address>()
+                    .map_err(|_| anyhow::anyhow!("address is invalid"))?;
+                let outputs = values
+                    .iter()
+                    .map(|v| {
+                        let value: Value = v.parse()?;
+                        anyhow::Ok(Output {
+                            value: Some(value.into()),
+                            address: Some(to.clone().into()),
+                        })
+                    })
+                    .collect::<Result<Vec<_>>>()?;
+
+                let request = TransactionPlannerRequest {
+                    outputs,
+                    source: Some(AddressIndex::new(schedule.source).into()),
+                    ..Default::default()
+                };
+                schedule.create(app, request).await
+            }
+            ScheduleCmd::Swap {
+                input,
+                into,
+                schedule,
+            } => {
+                let input = input.parse::<Value>()?;
+                let into = asset::REGISTRY.parse_unit(into.as_str()).base();
+
+                let (claim_address, _dtk_d) = app
+                    .config
+                    .full_viewing_key
+                    .incoming()
+                    .payment_address(AddressIndex::new(schedule.source));
+
+                // The claim fee is prepaid when the swap is planned, so we estimate it
+                // from the current gas prices, which may drift over a long schedule.
+                let gas_prices: penumbra_fee::GasPrices = app
+                    .view
+                    .as_mut()
+                    .context("view service must be initialized")?
+                    .gas_prices(GasPricesRequest {})
+                    .await?
+                    .into_inner()
+                    .gas_prices
+                    .expect("gas prices must be available")
+                    .try_into()?;
+                let estimated_claim_fee = gas_prices.fee(&swap_claim_gas_cost());
+
+                let request = TransactionPlannerRequest {
+                    swaps: vec![Swap {
+                        value: Some(input.into()),
+                        target_asset: Some(into.id().into()),
+                        fee: Some(estimated_claim_fee.into()),
+                        claim_address: Some(claim_address.into()),
+                    }],
+                    source: Some(AddressIndex::new(schedule.source).into()),
+                    ..Default::default()
+                };
+                schedule.create(app, request).await
+            }
+            ScheduleCmd::List { all } => {
+                let schedules = app.view().schedules(vec![]).await?;
+                let asset_cache = app.view().assets().await?;
+
+                let mut table = Table::new();
+                table.load_preset(presets::NOTHING);
+                table.set_header(vec![
+                    "ID",
+                    "Status",
+                    "Transaction",
+                    "Interval",
+                    "Next Height",
+                    "Executions",
+                    "Last Outcome",
+                ]);
+
+                for (schedule, executions) in schedules {
+                    if !all && schedule.status.is_terminal() {
+                        continue;
+                    }
+
+                    let request = &schedule.plan_request;
+                    let mut description = Vec::new();
+                    for output in &request.outputs {
+                        let value: Value = output.value.clone().unwrap_or_default().try_into()?;
+                        let address: Address =
+                            output.address.clone().unwrap_or_default().try_into()?;
+                        description.push(format!(
+                            "send {} to {}",
+                            value.format(&asset_cache),
+                            address.display_short_form()
+                        ));
+                    }
+                    for swap in &request.swaps {
+                        let value: Value = swap.value.clone().unwrap_or_default().try_into()?;
+                        let target: asset::Id =
+                            swap.target_asset.clone().unwrap_or_default().try_into()?;
+                        let target = asset_cache
+                            .get(&target)
+                            .map(|m| m.to_string())
+                            .unwrap_or_else(|| target.to_string());
+                        description.push(format!(
+                            "swap {} into {}",
+                            value.format(&asset_cache),
+                            target
+                        ));
+                    }
+
+                    let interval = if schedule.interval == 0 {
+                        "once".to_string()
+                    } else if schedule.every_epoch {
+                        "every epoch".to_string()
+                    } else {
+                        format!("{} blocks", schedule.interval)
+                    };
+                    let executions_count = if schedule.max_occurrences == 0 {
+                        format!("{}", schedule.occurrences)
+                    } else {
+                        format!("{}/{}", schedule.occurrences, schedule.max_occurrences)
+                    };
+                    let last_outcome = match executions.last() {
+                        None => String::new(),
+                        Some(execution) => match (&execution.transaction_id, &execution.error) {
+                            (Some(id), None) => format!("@{}: {}", execution.height, id),
+                            (Some(id), Some(error)) => {
+                                format!("@{}: {}, then failed: {}", execution.height, id, error)
+                            }
+                            (None, error) => format!(
+                                "@{}: failed: {}",
+                                execution.height,
+                                error.as_deref().unwrap_or_default()
+                            ),
+                        },
+                    };
+
+                    table.add_row(vec![
+                        schedule.id.to_string(),
+                        schedule.status.to_string(),
+                        description.join(", "),
+                        interval,
+                        if schedule.status.is_terminal() {
+                            String::new()
+                        } else {
+                            schedule.next_height.to_string()
+                        },
+                        executions_count,
+                        last_outcome,
+                    ]);
+                }
+
+                println!("{table}");
+                Ok(())
+            }
+            ScheduleCmd::Pause { id } => {
+                app.view()
+                    .update_schedule_status(*id, ScheduleStatus::Paused)
+                    .await?;
+                println!("paused schedule {id}");
+                Ok(())
+            }
+            ScheduleCmd::Resume { id } => {
+                app.view()
+                    .update_schedule_status(*id, ScheduleStatus::Active)
+                    .await?;
+                println!("resumed schedule {id}");
+                Ok(())
+            }
+            ScheduleCmd::Cancel { id } => {
+                app.view()
+                    .update_schedule_status(*id, ScheduleStatus::Cancelled)
+                    .await?;
+                println!("cancelled schedule {id}");
+                Ok(())
+            }
+        }
+    }
+}
+
+impl ScheduleArgs {
+    async fn create(&self, app: &mut App, request: TransactionPlannerRequest) -> Result<()> {
+        let recurring = self.every.is_some() || self.every_epoch;
+        let max_occurrences = match (recurring, self.count) {
+            // A one-off transaction is executed exactly once.
+            (false, _) => 1,
+            (true, count) => count.unwrap_or(0),
+        };
+
+        let id = app
+            .view()
+            .create_schedule(
+                request,
+                self.start.unwrap_or_default(),
+                self.every.unwrap_or_default(),
+                max_occurrences,
+                self.every_epoch,
+            )
+            .await?;
+
+        println!("created schedule {id}");
+        println!(
+            "note: scheduled transactions are only executed by `pclientd` running in custody mode; \
+             schedules created in pcli's own view service, or in a view-only `pclientd`, are never submitted"
+        );
+        Ok(())
+    }
+}
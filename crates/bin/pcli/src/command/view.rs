@@ -2,9 +2,14 @@ use anyhow::Result;
 
 use address::AddressCmd;
 use balance::BalanceCmd;
+use forwarding_accounts::ForwardingAccountsCmd;
+use ibc_transfers::IbcTransfersCmd;
 use lps::LiquidityPositionsCmd;
 use noble_address::NobleAddressCmd;
+use proposals::ProposalsCmd;
+use proxy_key::ProxyKeyCmd;
 use staked::StakedCmd;
+use staking_rewards::StakingRewardsCmd;
 use transaction_hashes::TransactionHashesCmd;
 use tx::TxCmd;
 use wallet_id::WalletIdCmd;
@@ -16,9 +21,14 @@ use self::auction::AuctionCmd;
 mod address;
 mod auction;
 mod balance;
+mod forwarding_accounts;
+mod ibc_transfers;
 mod lps;
 mod noble_address;
+mod proposals;
+mod proxy_key;
 mod staked;
+mod staking_rewards;
 mod wallet_id;
 
 pub mod transaction_hashes;
@@ -34,11 +44,22 @@ pub enum ViewCmd {
     Address(AddressCmd),
     /// View the Noble forwarding address associated with one of your addresses, either by numerical index, or a random ephemeral one.
     NobleAddress(NobleAddressCmd),
+    /// View the forwarding accounts on other chains that forward deposits to your addresses.
+    ForwardingAccounts(ForwardingAccountsCmd),
+    /// View the proxy key that delegators can delegate their governance voting weight to.
+    ProxyKey(ProxyKeyCmd),
     /// View your account balances.
     Balance(BalanceCmd),
     /// View your staked delegation tokens.
     Staked(StakedCmd),
-    /// Deletes all scanned data and local state, while leaving keys untouched.
+    /// View the rewards earned by your delegations, and their projected APR.
+    StakingRewards(StakingRewardsCmd),
+    /// View the governance proposals you can vote on, and the proposal deposits you can claim.
+    Proposals(ProposalsCmd),
+    /// View the IBC transfers you sent to other chains, and whether they were received or refunded.
+    IbcTransfers(IbcTransfersCmd),
+    /// Deletes all scanned data and local state, while leaving keys, forwarding accounts and
+    /// scheduled transactions untouched.
     Reset(Reset),
     /// Synchronizes the client, privately scanning the chain state.
     ///
@@ -62,8 +83,13 @@ impl ViewCmd {
             ViewCmd::WalletId(wallet_id_cmd) => wallet_id_cmd.offline(),
             ViewCmd::Address(address_cmd) => address_cmd.offline(),
             ViewCmd::NobleAddress(address_cmd) => address_cmd.offline(),
+            ViewCmd::ForwardingAccounts(cmd) => cmd.offline(),
+            ViewCmd::ProxyKey(proxy_key_cmd) => proxy_key_cmd.offline(),
             ViewCmd::Balance(balance_cmd) => balance_cmd.offline(),
             ViewCmd::Staked(staked_cmd) => staked_cmd.offline(),
+            ViewCmd::StakingRewards(cmd) => cmd.offline(),
+            ViewCmd::Proposals(cmd) => cmd.offline(),
+            ViewCmd::IbcTransfers(cmd) => cmd.offline(),
             ViewCmd::Reset(_) => true,
             ViewCmd::Sync => false,
             ViewCmd::ListTransactionHashes(transactions_cmd) => transactions_cmd.offline(),
@@ -94,7 +120,11 @@ impl ViewCmd {
             }
             ViewCmd::Sync => {
                 // We set needs_sync() -> true, so by this point, we have
-                // already synchronized the wallet above, so we can just return.
+                // already synchronized the wallet above, so we only need to
+                // remind the user of any governance actions they can take.
+                if let Err(e) = proposals::print_reminder(app).await {
+                    tracing::warn!(?e, "could not check for governance proposals");
+                }
             }
             ViewCmd::Reset(_reset) => {
                 // The wallet has already been reset by a short-circuiting path.
@@ -105,6 +135,9 @@ impl ViewCmd {
             ViewCmd::NobleAddress(noble_address_cmd) => {
                 noble_address_cmd.exec(&full_viewing_key)?;
             }
+            ViewCmd::ProxyKey(proxy_key_cmd) => {
+                proxy_key_cmd.exec(&full_viewing_key)?;
+            }
             ViewCmd::Balance(balance_cmd) => {
                 let view_client = app.view();
                 balance_cmd.exec(view_client).await?;
@@ -116,6 +149,10 @@ impl ViewCmd {
                     .exec(&full_viewing_key, view_client, channel)
                     .await?;
             }
+            ViewCmd::StakingRewards(cmd) => cmd.exec(app).await?,
+            ViewCmd::Proposals(cmd) => cmd.exec(app).await?,
+            ViewCmd::ForwardingAccounts(cmd) => cmd.exec(app).await?,
+            ViewCmd::IbcTransfers(cmd) => cmd.exec(app).await?,
             ViewCmd::LiquidityPositions(cmd) => cmd.exec(app).await?,
         }
 
@@ -133,6 +170,10 @@ impl Reset {
         if view_path.is_file() {
             std::fs::remove_file(&view_path)?;
             println!("Deleted view data at {view_path}");
+            let registry_path = penumbra_view::Storage::registry_path(&view_path);
+            if registry_path.is_file() {
+                println!("Kept forwarding accounts and scheduled transactions at {registry_path}");
+            }
         } else if view_path.exists() {
             anyhow::bail!(
                 "Expected view data at {} but found something that is not a file; refusing to delete it",
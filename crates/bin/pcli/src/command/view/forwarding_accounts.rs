@@ -0,0 +1,71 @@
+use anyhow::Result;
+use comfy_table::{presets, Table};
+use penumbra_view::ViewClient;
+
+use crate::App;
+
+#[derive(Debug, clap::Args)]
+pub struct ForwardingAccountsCmd {
+    /// Only display the forwarding accounts on this counterparty chain.
+    #[clap(long)]
+    chain_id: Option<String>,
+    /// Query the counterparty chains for the deposits yet to be forwarded from each account.
+    #[clap(long)]
+    pending: bool,
+}
+
+impl ForwardingAccountsCmd {
+    pub fn offline(&self) -> bool {
+        false
+    }
+
+    pub async fn exec(&self, app: &mut App) -> Result<()> {
+        let accounts = app
+            .view()
+            .forwarding_accounts(self.chain_id.clone(), self.pending)
+            .await?;
+
+        if accounts.is_empty() {
+            println!("No forwarding accounts found.");
+            return Ok(());
+        }
+
+        let mut table = Table::new();
+        table.load_preset(presets::NOTHING);
+        let mut header = vec![
+            "Chain",
+            "Channel",
+            "Index",
+            "Forwarding Address",
+            "Registered",
+        ];
+        if self.pending {
+            header.push("Pending Deposits");
+        }
+        table.set_header(header);
+
+        for (account, pending_deposits) in accounts {
+            let mut row = vec![
+                account.chain_id,
+                account.channel,
+                account.address_index.account.to_string(),
+                account.forwarding_address,
+                if account.registered { "yes" } else { "no" }.to_string(),
+            ];
+            if self.pending {
+                row.push(
+                    pending_deposits
+                        .iter()
+                        .map(|deposit| format!("{}{}", deposit.amount, deposit.denom))
+                        .collect::<Vec<_>>()
+                        .join(", "),
+                );
+            }
+            table.add_row(row);
+        }
+
+        println!("{table}");
+
+        Ok(())
+    }
+}
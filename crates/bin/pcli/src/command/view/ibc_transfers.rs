@@ -0,0 +1,67 @@
+use anyhow::Result;
+use comfy_table::{presets, Table};
+use penumbra_asset::asset;
+use penumbra_view::{Ics20WithdrawalRecord, ViewClient};
+
+use crate::App;
+
+#[derive(Debug, clap::Args)]
+pub struct IbcTransfersCmd {
+    /// Only display the transfers still in flight, omitting those that were acknowledged by the
+    /// counterparty chain or refunded.
+    #[clap(long)]
+    pending: bool,
+}
+
+impl IbcTransfersCmd {
+    pub fn offline(&self) -> bool {
+        false
+    }
+
+    pub async fn exec(&self, app: &mut App) -> Result<()> {
+        let withdrawals = app.view().ics20_withdrawals(None, !self.pending).await?;
+        let asset_cache = app.view().assets().await?;
+
+        if withdrawals.is_empty() {
+            println!("No IBC transfers found.");
+            return Ok(());
+        }
+
+        println!("{}", withdrawals_table(&withdrawals, &asset_cache));
+
+        Ok(())
+    }
+}
+
+/// Renders a table of ICS-20 withdrawals and their statuses.
+pub(super) fn withdrawals_table(
+    withdrawals: &[Ics20WithdrawalRecord],
+    asset_cache: &asset::Cache,
+) -> Table {
+    let mut table = Table::new();
+    table.load_preset(presets::NOTHING);
+    table.set_header(vec![
+        "Channel",
+        "Sequence",
+        "Amount",
+        "Destination",
+        "Status",
+        "Sent",
+        "Settled",
+    ]);
+
+    for record in withdrawals {
+        let optional = |value: Option<u64>| value.map(|v| v.to_string()).unwrap_or_default();
+        table.add_row(vec![
+            record.withdrawal.source_channel.to_string(),
+            optional(record.sequence),
+            record.withdrawal.value().format(asset_cache),
+            record.withdrawal.destination_chain_address.clone(),
+            record.status.to_string(),
+            record.height_sent.to_string(),
+            optional(record.height_settled),
+        ]);
+    }
+
+    table
+}
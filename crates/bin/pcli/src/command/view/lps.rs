@@ -1,16 +1,29 @@
 use anyhow::{anyhow, Result};
+use comfy_table::{presets, Table};
 use futures::stream::TryStreamExt;
-use penumbra_dex::lp::position::{Position, State};
+use penumbra_asset::{asset, Value};
+use penumbra_dex::{
+    lp::position::{Position, State},
+    TradingPair,
+};
+use penumbra_num::Amount;
 use penumbra_proto::core::component::dex::v1::{
     query_service_client::QueryServiceClient as DexQueryServiceClient,
-    LiquidityPositionsByIdRequest,
+    LiquidityPositionsByIdRequest, SpreadRequest,
 };
-use penumbra_view::ViewClient;
+use penumbra_view::{PositionPerformance, ViewClient};
+use tonic::transport::Channel;
 
 use crate::{command::utils, App};
 
 #[derive(Debug, clap::Args)]
-pub struct LiquidityPositionsCmd {}
+pub struct LiquidityPositionsCmd {
+    /// Display the performance of all of your positions, including closed and withdrawn ones:
+    /// the fills and fees earned since opening, and the profit relative to holding the initial
+    /// reserves, valued in the second asset of the pair at the current mid-market price.
+    #[clap(long)]
+    performance: bool,
+}
 
 impl LiquidityPositionsCmd {
     pub fn offline(&self) -> bool {
@@ -18,6 +31,10 @@ impl LiquidityPositionsCmd {
     }
 
     pub async fn exec(&self, app: &mut App) -> Result<()> {
+        if self.performance {
+            return self.print_performance(app).await;
+        }
+
         let my_position_ids = app
             .view()
             .owned_position_ids(Some(State::Opened), None)
@@ -45,4 +62,143 @@ impl LiquidityPositionsCmd {
 
         Ok(())
     }
+
+    async fn print_performance(&self, app: &mut App) -> Result<()> {
+        let performances = app.view().position_performance(Vec::new()).await?;
+        let asset_cache = app.view().assets().await?;
+        let mut dex_client = DexQueryServiceClient::new(app.pd_channel().await?);
+
+        let mut table = Table::new();
+        table.load_preset(presets::NOTHING);
+        table.set_header(vec![
+            "ID",
+            "State",
+            "Opened",
+            "Initial Reserves",
+            "Reserves",
+            "Executions",
+            "Filled In",
+            "Filled Out",
+            "Fees",
+            "PnL vs Hold",
+            "Impermanent Loss",
+        ]);
+
+        for performance in performances {
+            let pair = performance.opened.phi.pair;
+            let price = mid_price(&mut dex_client, &performance).await?;
+            let value = |r1: Amount, r2: Amount| r1.value() as f64 * price + r2.value() as f64;
+
+            let held = value(
+                performance.opened.reserves.r1,
+                performance.opened.reserves.r2,
+            );
+            let current = value(performance.reserves.r1, performance.reserves.r2);
+            let fees = value(performance.fees_1, performance.fees_2);
+            let pnl = current - held;
+            let impermanent_loss = pnl - fees;
+
+            let state = match (performance.height_withdrawn, performance.height_evicted) {
+                (Some(height), _) => format!("{} @ {}", performance.state, height),
+                (None, Some(height)) => format!("{} (evicted @ {})", performance.state, height),
+                (None, None) => performance.state.to_string(),
+            };
+
+            table.add_row(vec![
+                performance.position_id.to_string(),
+                state,
+                performance.height_opened.to_string(),
+                format_reserves(
+                    &asset_cache,
+                    pair,
+                    performance.opened.reserves.r1,
+                    performance.opened.reserves.r2,
+                ),
+                format_reserves(
+                    &asset_cache,
+                    pair,
+                    performance.reserves.r1,
+                    performance.reserves.r2,
+                ),
+                performance.executions.to_string(),
+                format_reserves(
+                    &asset_cache,
+                    pair,
+                    performance.filled_in_1,
+                    performance.filled_in_2,
+                ),
+                format_reserves(
+                    &asset_cache,
+                    pair,
+                    performance.filled_out_1,
+                    performance.filled_out_2,
+                ),
+                format_reserves(&asset_cache, pair, performance.fees_1, performance.fees_2),
+                format_signed(&asset_cache, pair.asset_2(), pnl),
+                format_signed(&asset_cache, pair.asset_2(), impermanent_loss),
+            ]);
+        }
+
+        println!("{}", table);
+
+        Ok(())
+    }
+}
+
+/// Returns the price of asset 1 in terms of asset 2, in base units, at the midpoint of
+/// the current spread. Falls back to the position's own price if there is no liquidity
+/// on either side of the book.
+async fn mid_price(
+    dex_client: &mut DexQueryServiceClient<Channel>,
+    performance: &PositionPerformance,
+) -> Result<f64> {
+    let phi = &performance.opened.phi;
+    let spread = dex_client
+        .spread(SpreadRequest {
+            trading_pair: Some(phi.pair.into()),
+        })
+        .await?
+        .into_inner();
+
+    // Trading 1 => 2, the effective price is in units of asset 1 per unit of asset 2.
+    let bid = (spread.approx_effective_price_1_to_2 > 0.0)
+        .then_some(1.0 / spread.approx_effective_price_1_to_2);
+    // Trading 2 => 1, the effective price is in units of asset 2 per unit of asset 1.
+    let ask = (spread.approx_effective_price_2_to_1 > 0.0)
+        .then_some(spread.approx_effective_price_2_to_1);
+
+    Ok(match (bid, ask) {
+        (Some(bid), Some(ask)) => (bid + ask) / 2.0,
+        (Some(price), None) | (None, Some(price)) => price,
+        (None, None) => phi.component.p.value() as f64 / phi.component.q.value() as f64,
+    })
+}
+
+fn format_reserves(cache: &asset::Cache, pair: TradingPair, r1: Amount, r2: Amount) -> String {
+    format!(
+        "{}, {}",
+        Value {
+            amount: r1,
+            asset_id: pair.asset_1(),
+        }
+        .format(cache),
+        Value {
+            amount: r2,
+            asset_id: pair.asset_2(),
+        }
+        .format(cache),
+    )
+}
+
+fn format_signed(cache: &asset::Cache, asset_id: asset::Id, amount: f64) -> String {
+    let formatted = Value {
+        amount: (amount.abs().round() as u128).into(),
+        asset_id,
+    }
+    .format(cache);
+    if amount < 0.0 {
+        format!("-{formatted}")
+    } else {
+        formatted
+    }
 }
@@ -0,0 +1,130 @@
+use anyhow::Result;
+use comfy_table::{presets, Table};
+
+use penumbra_asset::{Value, STAKING_TOKEN_ASSET_ID};
+use penumbra_governance::proposal_state::State as ProposalState;
+use penumbra_view::{GovernanceFeedItem, ViewClient};
+
+use crate::App;
+
+#[derive(Debug, clap::Parser)]
+pub struct ProposalsCmd {
+    /// Also list proposals that are no longer voting.
+    #[clap(long)]
+    inactive: bool,
+    /// Only list proposals you can act on: those you can vote on, and those whose deposit you can
+    /// claim.
+    #[clap(long)]
+    actionable: bool,
+}
+
+impl ProposalsCmd {
+    pub fn offline(&self) -> bool {
+        false
+    }
+
+    pub async fn exec(&self, app: &mut App) -> Result<()> {
+        let items = app
+            .view()
+            .governance_feed(self.inactive, self.actionable, true)
+            .await?;
+        let height = app.view().status().await?.full_sync_height;
+        let asset_cache = app.view().assets().await?;
+        let staked = |amount| {
+            Value {
+                amount,
+                asset_id: *STAKING_TOKEN_ASSET_ID,
+            }
+            .format(&asset_cache)
+        };
+
+        let mut table = Table::new();
+        table.load_preset(presets::NOTHING);
+        table.set_header(vec![
+            "",
+            "ID",
+            "Title",
+            "Kind",
+            "State",
+            "Voting Ends",
+            "Votable Power",
+            "Your Vote",
+            "Claimable Deposit",
+        ]);
+        for item in &items {
+            let voting_ends = if item.state.is_voting() {
+                format!(
+                    "{} (in {} blocks)",
+                    item.end_block_height,
+                    item.end_block_height.saturating_sub(height)
+                )
+            } else {
+                item.end_block_height.to_string()
+            };
+            let mut votes: Vec<String> =
+                item.delegator_votes.iter().map(|v| v.to_string()).collect();
+            if let Some(vote) = item.proxy_vote {
+                votes.push(format!("{vote} (as proxy)"));
+            }
+            table.add_row(vec![
+                if item.state_changed { "*" } else { "" }.to_string(),
+                item.proposal.id.to_string(),
+                item.proposal.title.clone(),
+                format!("{:?}", item.proposal.kind()),
+                state_label(&item.state).to_string(),
+                voting_ends,
+                if item.can_vote() {
+                    format!(
+                        "{} ({} notes)",
+                        staked(item.votable_power),
+                        item.votable_notes
+                    )
+                } else {
+                    String::new()
+                },
+                votes.join(", "),
+                item.claimable_deposit.map(staked).unwrap_or_default(),
+            ]);
+        }
+        println!("{table}");
+        println!("* the proposal is new, or its state changed since the last time it was listed");
+
+        if items.iter().any(GovernanceFeedItem::can_vote) {
+            println!("vote on a proposal with `pcli tx vote <yes|no|abstain> --on <ID>`");
+        }
+        if items.iter().any(|item| item.claimable_deposit.is_some()) {
+            println!("claim a proposal deposit with `pcli tx proposal deposit-claim <ID>`");
+        }
+
+        Ok(())
+    }
+}
+
+/// Prints a reminder of the proposals the wallet can act on, if there are any.
+pub async fn print_reminder(app: &mut App) -> Result<()> {
+    let items = app.view().governance_feed(false, true, false).await?;
+
+    let votable = items.iter().filter(|item| item.can_vote()).count();
+    let claimable = items
+        .iter()
+        .filter(|item| item.claimable_deposit.is_some())
+        .count();
+    if votable > 0 || claimable > 0 {
+        println!(
+            "governance: {votable} proposal(s) you can vote on, {claimable} deposit(s) you can claim; see `pcli view proposals`"
+        );
+    }
+
+    Ok(())
+}
+
+fn state_label(state: &ProposalState) -> &'static str {
+    match state {
+        ProposalState::Voting => "voting",
+        ProposalState::Withdrawn { .. } => "withdrawn",
+        ProposalState::Finished { .. } if state.is_passed() => "passed",
+        ProposalState::Finished { .. } if state.is_slashed() => "slashed",
+        ProposalState::Finished { .. } => "failed",
+        ProposalState::Claimed { .. } => "claimed",
+    }
+}
@@ -0,0 +1,21 @@
+use anyhow::Result;
+
+use penumbra_keys::FullViewingKey;
+use penumbra_stake::GovernanceKey;
+
+#[derive(Debug, clap::Parser)]
+pub struct ProxyKeyCmd {}
+
+impl ProxyKeyCmd {
+    /// Determine if this command requires a network sync before it executes.
+    pub fn offline(&self) -> bool {
+        true
+    }
+
+    pub fn exec(&self, fvk: &FullViewingKey) -> Result<()> {
+        let proxy_key = GovernanceKey(*fvk.spend_verification_key());
+        println!("{proxy_key}");
+
+        Ok(())
+    }
+}
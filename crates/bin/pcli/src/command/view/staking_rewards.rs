@@ -0,0 +1,137 @@
+use anyhow::Result;
+use comfy_table::{presets, Table};
+use futures::TryStreamExt;
+
+use penumbra_asset::{Value, STAKING_TOKEN_ASSET_ID};
+use penumbra_num::Amount;
+use penumbra_proto::core::component::stake::v1::{
+    query_service_client::QueryServiceClient as StakeQueryServiceClient, ValidatorInfoRequest,
+};
+use penumbra_stake::{validator, DelegationToken, IdentityKey};
+use penumbra_view::{StakingRewardsEventKind, ViewClient};
+
+use crate::App;
+
+#[derive(Debug, clap::Parser)]
+pub struct StakingRewardsCmd {
+    /// Only report on delegations to these validators.
+    #[clap(long = "validator")]
+    validators: Vec<IdentityKey>,
+    /// Also display each delegation and undelegation contributing to the rewards.
+    #[clap(long)]
+    history: bool,
+}
+
+impl StakingRewardsCmd {
+    pub fn offline(&self) -> bool {
+        false
+    }
+
+    pub async fn exec(&self, app: &mut App) -> Result<()> {
+        let rewards = app.view().staking_rewards(self.validators.clone()).await?;
+        let asset_cache = app.view().assets().await?;
+
+        let mut client = StakeQueryServiceClient::new(app.pd_channel().await?);
+        let validators = client
+            .validator_info(ValidatorInfoRequest {
+                show_inactive: true,
+                ..Default::default()
+            })
+            .await?
+            .into_inner()
+            .try_collect::<Vec<_>>()
+            .await?
+            .into_iter()
+            .map(TryInto::try_into)
+            .collect::<Result<Vec<validator::Info>, _>>()?;
+        let name = |identity_key: &IdentityKey| {
+            validators
+                .iter()
+                .find(|v| &v.validator.identity_key == identity_key)
+                .map(|v| v.validator.name.clone())
+                .unwrap_or_else(|| identity_key.to_string())
+        };
+        let staked = |amount: Amount| {
+            Value {
+                amount,
+                asset_id: *STAKING_TOKEN_ASSET_ID,
+            }
+            .format(&asset_cache)
+        };
+
+        let mut table = Table::new();
+        table.load_preset(presets::NOTHING);
+        table.set_header(vec![
+            "Name",
+            "Tokens",
+            "Value",
+            "Cost Basis",
+            "Realized",
+            "Unrealized",
+            "Proj. APR",
+        ]);
+
+        let mut realized = Amount::zero();
+        let mut unrealized = Amount::zero();
+        for r in &rewards {
+            let delegation = Value {
+                amount: r.delegation_tokens,
+                asset_id: DelegationToken::new(r.identity_key).id(),
+            };
+            table.add_row(vec![
+                name(&r.identity_key),
+                delegation.format(&asset_cache),
+                staked(r.current_value),
+                staked(r.cost_basis),
+                staked(r.realized_rewards),
+                staked(r.unrealized_rewards),
+                format!("{:.2}%", r.projected_apr_bps as f64 / 100.0),
+            ]);
+            realized += r.realized_rewards;
+            unrealized += r.unrealized_rewards;
+        }
+        table.add_row(vec![
+            "Total".to_string(),
+            String::new(),
+            String::new(),
+            String::new(),
+            staked(realized),
+            staked(unrealized),
+            String::new(),
+        ]);
+        println!("{table}");
+
+        if self.history {
+            let mut table = Table::new();
+            table.load_preset(presets::NOTHING);
+            table.set_header(vec![
+                "Name", "Height", "Epoch", "Kind", "Tokens", "Value", "Realized",
+            ]);
+            for r in &rewards {
+                let delegation_token = DelegationToken::new(r.identity_key);
+                for event in &r.history {
+                    let kind = match event.kind {
+                        StakingRewardsEventKind::Delegation => "Delegate",
+                        StakingRewardsEventKind::Undelegation => "Undelegate",
+                    };
+                    table.add_row(vec![
+                        name(&r.identity_key),
+                        event.height.to_string(),
+                        event.epoch_index.to_string(),
+                        kind.to_string(),
+                        Value {
+                            amount: event.delegation_amount,
+                            asset_id: delegation_token.id(),
+                        }
+                        .format(&asset_cache),
+                        staked(event.unbonded_amount),
+                        staked(event.realized_rewards),
+                    ]);
+                }
+            }
+            println!("{table}");
+        }
+
+        Ok(())
+    }
+}
@@ -68,6 +68,17 @@ impl TxCmd {
         } else {
             use crate::transaction_view_ext::TransactionViewExt;
             tx_info.view.render_terminal();
+
+            // Report on the progress of any IBC transfers the wallet sent in the transaction.
+            let withdrawals = app.view().ics20_withdrawals(Some(hash), true).await?;
+            if !withdrawals.is_empty() {
+                let asset_cache = app.view().assets().await?;
+                println!("\nIBC transfers:");
+                println!(
+                    "{}",
+                    super::ibc_transfers::withdrawals_table(&withdrawals, &asset_cache)
+                );
+            }
         }
 
         Ok(())
@@ -58,6 +58,11 @@ impl PcliConfig {
         };
         GovernanceKey(fvk.spend_verification_key().clone())
     }
+
+    /// The key identifying this wallet when it acts as a proxy for other delegators' votes.
+    pub fn proxy_key(&self) -> GovernanceKey {
+        GovernanceKey(*self.full_viewing_key.spend_verification_key())
+    }
 }
 
 /// The custody backend to use.
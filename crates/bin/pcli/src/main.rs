@@ -65,6 +65,8 @@ async fn main() -> Result<()> {
         Command::Query(cmd) => cmd.exec(&mut app).await?,
         Command::Threshold(cmd) => cmd.exec(&mut app).await?,
         Command::Migrate(cmd) => cmd.exec(&mut app).await?,
+        Command::Relay(cmd) => cmd.exec(&mut app).await?,
+        Command::MonitorClient(cmd) => cmd.exec(&mut app).await?,
     }
 
     Ok(())
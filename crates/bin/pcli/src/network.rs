@@ -1,9 +1,12 @@
 use anyhow::Context;
 use decaf377_rdsa::{Signature, SpendAuth};
 use futures::{FutureExt, TryStreamExt};
-use penumbra_governance::ValidatorVoteBody;
+use penumbra_governance::{ProxyVoteBody, ValidatorVoteBody};
 use penumbra_proto::{
-    custody::v1::{AuthorizeValidatorDefinitionRequest, AuthorizeValidatorVoteRequest},
+    custody::v1::{
+        AuthorizeProxyVoteRequest, AuthorizeValidatorDefinitionRequest,
+        AuthorizeValidatorVoteRequest,
+    },
     util::tendermint_proxy::v1::tendermint_proxy_service_client::TendermintProxyServiceClient,
     view::v1::broadcast_transaction_response::Status as BroadcastStatus,
     DomainType,
@@ -100,6 +103,25 @@ impl App {
             .try_into()
     }
 
+    pub async fn sign_proxy_vote(
+        &mut self,
+        proxy_vote: ProxyVoteBody,
+    ) -> anyhow::Result<Signature<SpendAuth>> {
+        let request = AuthorizeProxyVoteRequest {
+            proxy_vote: Some(proxy_vote.into()),
+            pre_authorizations: vec![],
+        };
+        // Proxy votes are signed by the wallet's own spend authorization key, so unlike validator
+        // votes they use the main custody service.
+        self.custody
+            .authorize_proxy_vote(request)
+            .await?
+            .into_inner()
+            .proxy_vote_auth
+            .ok_or_else(|| anyhow::anyhow!("missing proxy vote auth"))?
+            .try_into()
+    }
+
     /// Submits a transaction to the network.
     pub async fn submit_transaction(
         &mut self,
@@ -404,6 +404,10 @@ impl TransactionViewExt for TransactionView {
                     ["Upload Validator Definition", ""]
                 }
                 penumbra_transaction::ActionView::ValidatorVote(_) => ["Validator Vote", ""],
+                penumbra_transaction::ActionView::ProxyVote(x) => {
+                    action = format!("{} on proposal {}", x.body.vote, x.body.proposal);
+                    ["Proxy Vote", &action]
+                }
                 penumbra_transaction::ActionView::CommunityPoolDeposit(_) => {
                     ["Community Pool Deposit", ""]
                 }
@@ -413,8 +417,13 @@ impl TransactionViewExt for TransactionView {
                 penumbra_transaction::ActionView::CommunityPoolOutput(_) => {
                     ["Community Pool Output", ""]
                 }
+                penumbra_transaction::ActionView::CommunityPoolVestingClaim(x) => {
+                    action = format!("claim {} from grant {}", x.amount, x.grant_id);
+                    ["Community Pool Vesting Claim", &action]
+                }
                 penumbra_transaction::ActionView::Delegate(_) => ["Delegation", ""],
                 penumbra_transaction::ActionView::Undelegate(_) => ["Undelegation", ""],
+                penumbra_transaction::ActionView::Redelegate(_) => ["Redelegation", ""],
                 penumbra_transaction::ActionView::UndelegateClaim(_) => ["Undelegation Claim", ""],
                 penumbra_transaction::ActionView::ActionDutchAuctionSchedule(x) => {
                     let description = &x.action.description;
@@ -31,6 +31,7 @@ use tonic::transport::Server;
 use url::Url;
 
 mod proxy;
+mod scheduler;
 pub use proxy::{
     AppQueryProxy, ChainQueryProxy, CompactBlockQueryProxy, DexQueryProxy, DexSimulationProxy,
     GovernanceQueryProxy, SctQueryProxy, ShieldedPoolQueryProxy, StakeQueryProxy,
@@ -38,6 +39,7 @@ pub use proxy::{
 };
 
 use crate::proxy::FeeQueryProxy;
+pub use crate::scheduler::Scheduler;
 
 #[serde_as]
 #[derive(Serialize, Deserialize, Clone, Debug)]
@@ -176,6 +178,13 @@ impl Opt {
                 if opt.sqlite_path().exists() {
                     fs::remove_file(opt.sqlite_path())?;
                     println!("Deleted local storage at: {:?}", opt.sqlite_path());
+                    let registry_path = Storage::registry_path(opt.sqlite_path());
+                    if registry_path.exists() {
+                        println!(
+                            "Kept forwarding accounts and scheduled transactions at: {:?}",
+                            registry_path
+                        );
+                    }
                 } else {
                     println!("No local storage at: {:?} (have you started pclientd, so it would have data to store?)", opt.sqlite_path());
                 }
@@ -316,12 +325,28 @@ impl Opt {
                 let compact_block_query_proxy = CompactBlockQueryProxy(proxy_channel.clone());
                 let tendermint_proxy_proxy = TendermintProxyProxy(proxy_channel.clone());
 
-                let view_service =
-                    ViewServiceServer::new(ViewServer::new(storage, config.grpc_url).await?);
+                let view_server = ViewServer::new(storage.clone(), config.grpc_url).await?;
                 let custody_service = config.kms_config.as_ref().map(|kms_config| {
                     CustodyServiceServer::new(SoftKms::new(kms_config.spend_key.clone().into()))
                 });
 
+                // In custody mode, we can also execute the transactions scheduled in the view service.
+                if let Some(kms_config) = config.kms_config.as_ref() {
+                    let scheduler = Scheduler::new(
+                        storage,
+                        config.full_viewing_key.clone(),
+                        view_server.clone(),
+                        SoftKms::new(kms_config.spend_key.clone().into()),
+                    );
+                    tokio::spawn(async move {
+                        if let Err(error) = scheduler.run().await {
+                            tracing::error!(?error, "transaction scheduler failed");
+                        }
+                    });
+                }
+
+                let view_service = ViewServiceServer::new(view_server);
+
                 let server = Server::builder()
                     .accept_http1(true)
                     .add_service(tonic_web::enable(view_service))
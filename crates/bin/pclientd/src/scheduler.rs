@@ -0,0 +1,231 @@
+use std::time::Duration;
+
+use anyhow::{Context, Result};
+use futures::TryStreamExt;
+use penumbra_custody::soft_kms::SoftKms;
+use penumbra_keys::FullViewingKey;
+use penumbra_proto::{
+    box_grpc_svc::{self, BoxGrpcService},
+    custody::v1::{
+        custody_service_client::CustodyServiceClient, custody_service_server::CustodyServiceServer,
+    },
+    view::v1::{
+        broadcast_transaction_response::Status as BroadcastStatus,
+        transaction_planner_request::SwapClaim, view_service_client::ViewServiceClient,
+        view_service_server::ViewServiceServer, TransactionPlannerRequest,
+    },
+};
+use penumbra_transaction::{txhash::TransactionId, Transaction, TransactionPlan};
+use penumbra_view::{ScheduledTransaction, Storage, ViewClient, ViewServer};
+
+/// How often to check for scheduled transactions that have come due.
+const POLL_INTERVAL: Duration = Duration::from_secs(2);
+
+/// Executes the transactions scheduled in the view service.
+///
+/// Each occurrence is planned from the schedule's planner request, authorized by
+/// the custody service, then broadcast, and its outcome recorded in the view
+/// service's storage. Occurrences are executed one at a time, waiting for each
+/// transaction to be detected, so that they never try to spend the same notes.
+///
+/// The transaction of each occurrence is recorded before it is broadcast, so that
+/// an occurrence interrupted by a restart is reconciled with the chain when the
+/// scheduler starts again, rather than executed a second time.
+pub struct Scheduler {
+    storage: Storage,
+    fvk: FullViewingKey,
+    view: ViewServiceClient<BoxGrpcService>,
+    custody: CustodyServiceClient<BoxGrpcService>,
+}
+
+/// The outcome of an occurrence: the ID of its transaction, if it was submitted,
+/// and the error that prevented it from completing, if any.
+type Outcome = (Option<TransactionId>, Option<String>);
+
+impl Scheduler {
+    pub fn new(storage: Storage, fvk: FullViewingKey, view: ViewServer, kms: SoftKms) -> Self {
+        Self {
+            storage,
+            fvk,
+            view: ViewServiceClient::new(box_grpc_svc::local(ViewServiceServer::new(view))),
+            custody: CustodyServiceClient::new(box_grpc_svc::local(CustodyServiceServer::new(kms))),
+        }
+    }
+
+    pub async fn run(mut self) -> Result<()> {
+        for (schedule, height, transaction) in self.storage.schedules_in_flight().await? {
+            tracing::info!(
+                id = schedule.id,
+                height,
+                "reconciling interrupted scheduled transaction"
+            );
+            let (id, error) = self.reconcile(&schedule, transaction).await;
+            self.record(&schedule, height, id, error).await?;
+        }
+
+        let mut interval = tokio::time::interval(POLL_INTERVAL);
+        interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+
+        loop {
+            interval.tick().await;
+
+            let Some(height) = self.storage.last_sync_height().await? else {
+                continue;
+            };
+
+            for schedule in self.storage.due_schedules(height).await? {
+                tracing::info!(id = schedule.id, height, "executing scheduled transaction");
+                let (id, error) = self.execute(&schedule, height).await;
+                self.record(&schedule, height, id, error).await?;
+            }
+        }
+    }
+
+    /// Records the outcome of an occurrence, and advances its schedule.
+    async fn record(
+        &self,
+        schedule: &ScheduledTransaction,
+        height: u64,
+        id: Option<TransactionId>,
+        error: Option<String>,
+    ) -> Result<()> {
+        if let Some(error) = &error {
+            tracing::warn!(id = schedule.id, ?error, "scheduled transaction failed");
+        }
+        self.storage
+            .record_schedule_execution(schedule, height, id, error)
+            .await
+    }
+
+    /// Plans, builds and submits one occurrence of a scheduled transaction at `height`.
+    async fn execute(&mut self, schedule: &ScheduledTransaction, height: u64) -> Outcome {
+        let transaction = match self.prepare(schedule, height).await {
+            Ok(transaction) => transaction,
+            Err(e) => return (None, Some(format!("{:#}", e))),
+        };
+        let id = match self.submit(transaction.clone()).await {
+            Ok(id) => id,
+            Err(e) => return (None, Some(format!("{:#}", e))),
+        };
+
+        (Some(id), self.claim_swaps(schedule, &transaction).await)
+    }
+
+    /// Plans and builds the transaction of an occurrence, and records it as in flight.
+    async fn prepare(
+        &mut self,
+        schedule: &ScheduledTransaction,
+        height: u64,
+    ) -> Result<Transaction> {
+        let plan = self.plan(schedule.plan_request.clone()).await?;
+        let transaction = self.build(plan).await?;
+        self.storage
+            .record_schedule_in_flight(schedule.id, height, &transaction)
+            .await?;
+        Ok(transaction)
+    }
+
+    /// Reconciles an occurrence interrupted before its outcome was recorded, whose transaction
+    /// may or may not have been broadcast.
+    ///
+    /// The same transaction is submitted again unless it was already detected: it can only be
+    /// confirmed once, so the occurrence is never executed twice.
+    async fn reconcile(
+        &mut self,
+        schedule: &ScheduledTransaction,
+        transaction: Transaction,
+    ) -> Outcome {
+        let id = transaction.id();
+        if !self.detected(&id).await {
+            if let Err(e) = self.submit(transaction.clone()).await {
+                // The transaction can't be submitted again if it was confirmed in the meantime.
+                if !self.detected(&id).await {
+                    return (
+                        None,
+                        Some(format!(
+                            "interrupted, and could not resubmit {}: {:#}",
+                            id, e
+                        )),
+                    );
+                }
+            }
+        }
+
+        (Some(id), self.claim_swaps(schedule, &transaction).await)
+    }
+
+    async fn detected(&self, id: &TransactionId) -> bool {
+        matches!(self.storage.transaction_by_hash(&id.0).await, Ok(Some(_)))
+    }
+
+    /// Claims the outputs of the swaps in a confirmed transaction, returning the error if they
+    /// could not be claimed.
+    ///
+    /// Swaps are only useful once claimed, so we claim their outputs as soon as the transaction
+    /// has been detected.
+    async fn claim_swaps(
+        &mut self,
+        schedule: &ScheduledTransaction,
+        transaction: &Transaction,
+    ) -> Option<String> {
+        let swap_claims: Vec<_> = transaction
+            .swaps()
+            .map(|swap| SwapClaim {
+                swap_commitment: Some(swap.body.payload.commitment.into()),
+            })
+            .collect();
+        if swap_claims.is_empty() {
+            return None;
+        }
+
+        let result = async {
+            let claim_plan = self
+                .plan(TransactionPlannerRequest {
+                    swap_claims,
+                    source: schedule.plan_request.source.clone(),
+                    ..Default::default()
+                })
+                .await
+                .context("can't plan swap claim")?;
+            let claim = self
+                .build(claim_plan)
+                .await
+                .context("can't build swap claim")?;
+            self.submit(claim).await.context("can't submit swap claim")
+        }
+        .await;
+
+        result.err().map(|e| format!("{:#}", e))
+    }
+
+    async fn plan(&mut self, request: TransactionPlannerRequest) -> Result<TransactionPlan> {
+        ViewServiceClient::transaction_planner(&mut self.view, request)
+            .await?
+            .into_inner()
+            .plan
+            .ok_or_else(|| anyhow::anyhow!("empty TransactionPlannerResponse message"))?
+            .try_into()
+    }
+
+    async fn build(&mut self, plan: TransactionPlan) -> Result<Transaction> {
+        penumbra_wallet::build_transaction(&self.fvk, &mut self.view, &mut self.custody, plan).await
+    }
+
+    async fn submit(&mut self, transaction: Transaction) -> Result<TransactionId> {
+        let mut rsp = ViewClient::broadcast_transaction(&mut self.view, transaction, true).await?;
+        while let Some(rsp) = rsp.try_next().await? {
+            match rsp.status {
+                Some(BroadcastStatus::BroadcastSuccess(_)) => {}
+                Some(BroadcastStatus::Confirmed(c)) => {
+                    return c
+                        .id
+                        .ok_or_else(|| anyhow::anyhow!("detected transaction missing id"))?
+                        .try_into();
+                }
+                None => anyhow::bail!("empty BroadcastTransactionResponse message"),
+            }
+        }
+
+        anyhow::bail!("view server closed stream without reporting transaction confirmation")
+    }
+}
@@ -86,7 +86,7 @@ pub enum RootCommand {
             alias = "tendermint-addr",
         )]
         cometbft_addr: Url,
-        /// Enable expensive RPCs, currently a no-op.
+        /// Enable expensive RPCs, such as simulating governance proposals.
         #[clap(short, long, display_order = 500)]
         enable_expensive_rpc: bool,
     },
@@ -144,6 +144,33 @@ pub enum RootCommand {
         #[clap(long, display_order = 1000)]
         ready_to_start: bool,
     },
+
+    /// Replay recorded batch swaps through the DEX router, reporting how they
+    /// would have executed against a counterfactual set of liquidity positions.
+    ///
+    /// The node must not be running. Each replayed batch is printed as a line of JSON.
+    DexReplay {
+        /// The home directory of the full node whose liquidity is replayed against.
+        #[clap(long, env = "PENUMBRA_PD_HOME", display_order = 100)]
+        home: PathBuf,
+        /// The home directory of a full node to read the recorded swap flows from.
+        /// Defaults to `--home`.
+        ///
+        /// This makes it possible to replay flows against an export of the
+        /// node state taken at an earlier height, e.g. via `pd export`.
+        #[clap(long, display_order = 200)]
+        flows_home: Option<PathBuf>,
+        /// The first block height whose batch swaps are replayed.
+        #[clap(long, display_order = 300)]
+        start_height: u64,
+        /// The last block height whose batch swaps are replayed.
+        #[clap(long, display_order = 301)]
+        end_height: u64,
+        /// A JSON file containing a list of hypothetical liquidity positions,
+        /// which are opened before any batch swap is replayed.
+        #[clap(long, display_order = 400)]
+        positions: Option<PathBuf>,
+    },
 }
 
 #[derive(Debug, Subcommand)]
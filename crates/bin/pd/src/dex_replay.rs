@@ -0,0 +1,133 @@
+//! Offline replay of recorded DEX batch swaps against counterfactual liquidity.
+
+use std::{path::PathBuf, sync::Arc};
+
+use anyhow::Context;
+use cnidarium::{StateDelta, Storage};
+use penumbra_app::SUBSTORE_PREFIXES;
+use penumbra_dex::{
+    component::{
+        replay::{ReplayBatchSwaps, ReplayedBatch},
+        StateReadExt as _,
+    },
+    lp::position::Position,
+};
+use serde::Serialize;
+
+/// A single replayed batch, as reported to the user.
+#[derive(Debug, Serialize)]
+struct ReplayReport {
+    height: u64,
+    trading_pair: String,
+    delta_1: u128,
+    delta_2: u128,
+    recorded_lambda_1: u128,
+    recorded_lambda_2: u128,
+    simulated_lambda_1: u128,
+    simulated_lambda_2: u128,
+    recorded_unfilled_1: u128,
+    recorded_unfilled_2: u128,
+    simulated_unfilled_1: u128,
+    simulated_unfilled_2: u128,
+}
+
+impl From<ReplayedBatch> for ReplayReport {
+    fn from(batch: ReplayedBatch) -> Self {
+        Self {
+            height: batch.recorded.height,
+            trading_pair: batch.recorded.trading_pair.to_string(),
+            delta_1: batch.recorded.delta_1.value(),
+            delta_2: batch.recorded.delta_2.value(),
+            recorded_lambda_1: batch.recorded.lambda_1.value(),
+            recorded_lambda_2: batch.recorded.lambda_2.value(),
+            simulated_lambda_1: batch.simulated.lambda_1.value(),
+            simulated_lambda_2: batch.simulated.lambda_2.value(),
+            recorded_unfilled_1: batch.recorded.unfilled_1.value(),
+            recorded_unfilled_2: batch.recorded.unfilled_2.value(),
+            simulated_unfilled_1: batch.simulated.unfilled_1.value(),
+            simulated_unfilled_2: batch.simulated.unfilled_2.value(),
+        }
+    }
+}
+
+/// Replay the batch swaps recorded between `start_height` and `end_height` (inclusive)
+/// against the liquidity stored in `home`, after opening the hypothetical positions
+/// listed in `positions_file`.
+///
+/// Swap flows are read from `flows_home`, which defaults to `home`. Each replayed
+/// batch is printed to stdout as a JSON object.
+pub async fn replay(
+    home: PathBuf,
+    flows_home: Option<PathBuf>,
+    start_height: u64,
+    end_height: u64,
+    positions_file: Option<PathBuf>,
+) -> anyhow::Result<()> {
+    anyhow::ensure!(
+        start_height <= end_height,
+        "start height {start_height} is greater than end height {end_height}"
+    );
+
+    let positions: Vec<Position> = match positions_file {
+        Some(path) => {
+            let file = std::fs::File::open(&path)
+                .with_context(|| format!("failed to open positions file {}", path.display()))?;
+            serde_json::from_reader(file).context("failed to parse positions file")?
+        }
+        None => Vec::new(),
+    };
+
+    let storage = Storage::load(home.join("rocksdb"), SUBSTORE_PREFIXES.to_vec())
+        .await
+        .context("error loading node state")?;
+
+    // Collect the recorded batch swaps up front, so that the flow storage can be
+    // released before we start replaying.
+    let recorded = {
+        let flow_storage = match &flows_home {
+            Some(flows_home) => Some(
+                Storage::load(flows_home.join("rocksdb"), SUBSTORE_PREFIXES.to_vec())
+                    .await
+                    .context("error loading swap flow state")?,
+            ),
+            None => None,
+        };
+        let flow_snapshot = flow_storage.as_ref().unwrap_or(&storage).latest_snapshot();
+
+        let mut recorded = Vec::new();
+        for height in start_height..=end_height {
+            recorded.extend(flow_snapshot.output_data_at_height(height).await?);
+        }
+
+        drop(flow_snapshot);
+        if let Some(flow_storage) = flow_storage {
+            flow_storage.release().await;
+        }
+        recorded
+    };
+
+    tracing::info!(
+        n_batches = recorded.len(),
+        n_positions = positions.len(),
+        start_height,
+        end_height,
+        "replaying batch swaps"
+    );
+
+    let mut state = Arc::new(StateDelta::new(storage.latest_snapshot()));
+    state
+        .open_hypothetical_positions(positions)
+        .await
+        .context("failed to open hypothetical positions")?;
+
+    for output_data in recorded {
+        let replayed = state.replay_batch_swap(output_data).await?;
+        println!("{}", serde_json::to_string(&ReplayReport::from(replayed))?);
+    }
+
+    // Discard the counterfactual state before releasing the storage.
+    drop(state);
+    storage.release().await;
+
+    Ok(())
+}
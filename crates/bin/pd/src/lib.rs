@@ -8,6 +8,7 @@
 pub mod metrics;
 
 pub mod cli;
+pub mod dex_replay;
 pub mod migrate;
 pub mod network;
 pub mod zipserve;
@@ -12,7 +12,7 @@ use cnidarium::Storage;
 use metrics_exporter_prometheus::PrometheusBuilder;
 use pd::{
     cli::{NetworkCommand, Opt, RootCommand},
-    migrate::Migration::{Mainnet2, ReadyToStart},
+    migrate::Migration::{Mainnet3, ReadyToStart},
     network::{
         config::{get_network_dir, parse_tm_address, url_has_necessary_parts},
         generate::NetworkConfig,
@@ -473,12 +473,21 @@ async fn main() -> anyhow::Result<()> {
 
             let genesis_start = pd::migrate::last_block_timestamp(pd_home.clone()).await?;
             tracing::info!(?genesis_start, "last block timestamp");
-            Mainnet2
+            Mainnet3
                 .migrate(pd_home.clone(), comet_home, Some(genesis_start), force)
                 .instrument(pd_migrate_span)
                 .await
                 .context("failed to upgrade state")?;
         }
+        RootCommand::DexReplay {
+            home,
+            flows_home,
+            start_height,
+            end_height,
+            positions,
+        } => {
+            pd::dex_replay::replay(home, flows_home, start_height, end_height, positions).await?;
+        }
     }
     Ok(())
 }
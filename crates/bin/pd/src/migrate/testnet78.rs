@@ -343,6 +343,12 @@ async fn truncate_proposal_fields(delta: &mut StateDelta<Snapshot>) -> anyhow::R
                     },
                 ));
             }
+            // These kinds of proposal postdate this migration, so there is nothing to truncate.
+            pb_governance::proposal::Payload::CommunityPoolVestingGrant(_)
+            | pb_governance::proposal::Payload::CancelCommunityPoolVestingGrant(_)
+            | pb_governance::proposal::Payload::Batch(_)
+            | pb_governance::proposal::Payload::RegisterInterchainAccount(_)
+            | pb_governance::proposal::Payload::InterchainAccountTx(_) => {}
         };
 
         // Store the truncated proposal data
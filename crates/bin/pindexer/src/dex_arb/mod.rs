@@ -0,0 +1,127 @@
+use cometindex::{async_trait, index::EventBatch, AppView, PgTransaction};
+use penumbra_dex::event::{EventArbExecution, EventPositionExecution};
+use penumbra_proto::{
+    core::component::sct::v1 as sct_pb,
+    event::{EventDomainType as _, ProtoEvent as _},
+};
+
+async fn init_db(dbtx: &mut PgTransaction<'_>) -> anyhow::Result<()> {
+    for statement in include_str!("schema.sql").split(";") {
+        sqlx::query(statement).execute(dbtx.as_mut()).await?;
+    }
+    Ok(())
+}
+
+async fn record_arb(dbtx: &mut PgTransaction<'_>, event: EventArbExecution) -> anyhow::Result<()> {
+    let height = i64::try_from(event.height)?;
+    let execution = event.swap_execution;
+    let profit = execution
+        .output
+        .amount
+        .checked_sub(&execution.input.amount)
+        .ok_or_else(|| anyhow::anyhow!("arb at height {height} has negative profit"))?;
+
+    sqlx::query(
+        "INSERT INTO dex_arb VALUES ($1, NULL, $2, $3::NUMERIC(39, 0), $4::NUMERIC(39, 0), $5::NUMERIC(39, 0), $6)",
+    )
+    .bind(height)
+    .bind(execution.input.asset_id.to_bytes())
+    .bind(execution.input.amount.to_string())
+    .bind(execution.output.amount.to_string())
+    .bind(profit.to_string())
+    .bind(i32::try_from(execution.traces.len())?)
+    .execute(dbtx.as_mut())
+    .await?;
+
+    for (cycle, trace) in execution.traces.iter().enumerate() {
+        for (hop, value) in trace.iter().enumerate() {
+            sqlx::query(
+                "INSERT INTO dex_arb_cycle_hop VALUES ($1, $2, $3, $4, $5::NUMERIC(39, 0))",
+            )
+            .bind(height)
+            .bind(i32::try_from(cycle)?)
+            .bind(i32::try_from(hop)?)
+            .bind(value.asset_id.to_bytes())
+            .bind(value.amount.to_string())
+            .execute(dbtx.as_mut())
+            .await?;
+        }
+    }
+
+    Ok(())
+}
+
+async fn record_position_fill(
+    dbtx: &mut PgTransaction<'_>,
+    height: u64,
+    event: EventPositionExecution,
+) -> anyhow::Result<()> {
+    sqlx::query(
+        "INSERT INTO dex_arb_position_fill VALUES (DEFAULT, $1, $2, $3, $4, $5::NUMERIC(40, 0) - $6::NUMERIC(40, 0), $7::NUMERIC(40, 0) - $8::NUMERIC(40, 0))",
+    )
+    .bind(i64::try_from(height)?)
+    .bind(event.position_id.0)
+    .bind(event.trading_pair.asset_1().to_bytes())
+    .bind(event.trading_pair.asset_2().to_bytes())
+    .bind(event.reserves_1.to_string())
+    .bind(event.prev_reserves_1.to_string())
+    .bind(event.reserves_2.to_string())
+    .bind(event.prev_reserves_2.to_string())
+    .execute(dbtx.as_mut())
+    .await?;
+    Ok(())
+}
+
+/// Assign the epoch that just ended to all of the arbs that happened during it.
+async fn end_epoch(dbtx: &mut PgTransaction<'_>, index: u64) -> anyhow::Result<()> {
+    sqlx::query("UPDATE dex_arb SET epoch = $1 WHERE epoch IS NULL")
+        .bind(i64::try_from(index)?)
+        .execute(dbtx.as_mut())
+        .await?;
+    Ok(())
+}
+
+/// Indexes protocol arbitrage executions, and the liquidity positions they filled.
+#[derive(Debug)]
+pub struct Component {}
+
+impl Component {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+#[async_trait]
+impl AppView for Component {
+    fn name(&self) -> String {
+        "dex_arb".to_string()
+    }
+
+    async fn init_chain(
+        &self,
+        dbtx: &mut PgTransaction,
+        _app_state: &serde_json::Value,
+    ) -> anyhow::Result<()> {
+        init_db(dbtx).await
+    }
+
+    async fn index_batch(
+        &self,
+        dbtx: &mut PgTransaction,
+        batch: EventBatch,
+    ) -> Result<(), anyhow::Error> {
+        for event in batch.events() {
+            if let Ok(e) = EventArbExecution::try_from_event(&event.event) {
+                record_arb(dbtx, e).await?;
+            } else if let Ok(e) = EventPositionExecution::try_from_event(&event.event) {
+                // Arbs are the only executions routing from an asset back to itself.
+                if e.context.start == e.context.end {
+                    record_position_fill(dbtx, event.block_height, e).await?;
+                }
+            } else if let Ok(e) = sct_pb::EventEpochRoot::from_event(&event.event) {
+                end_epoch(dbtx, e.index).await?;
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,69 @@
+-- Each protocol arbitrage execution, one per block at most.
+CREATE TABLE IF NOT EXISTS dex_arb (
+  -- The height of the block the arb was executed in.
+  height BIGINT PRIMARY KEY,
+  -- The index of the epoch containing this block.
+  --
+  -- This is only known once the epoch has ended, and is NULL until then.
+  epoch BIGINT,
+  -- The asset that was arbitraged, i.e. both the input and the output of the cycles.
+  asset BYTEA NOT NULL,
+  -- The amount of the asset consumed by the arb.
+  input NUMERIC(39, 0) NOT NULL,
+  -- The amount of the asset produced by the arb.
+  output NUMERIC(39, 0) NOT NULL,
+  -- The surplus extracted by the arb, i.e. output - input.
+  profit NUMERIC(39, 0) NOT NULL,
+  -- The number of cycles traversed by the arb.
+  cycles INT NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS dex_arb_epoch_idx ON dex_arb (epoch);
+
+-- Each hop of each cycle traversed by an arb.
+CREATE TABLE IF NOT EXISTS dex_arb_cycle_hop (
+  height BIGINT NOT NULL REFERENCES dex_arb (height),
+  -- The index of the cycle within the arb execution.
+  cycle INT NOT NULL,
+  -- The index of the hop within the cycle, starting from the input.
+  hop INT NOT NULL,
+  asset BYTEA NOT NULL,
+  amount NUMERIC(39, 0) NOT NULL,
+  PRIMARY KEY (height, cycle, hop)
+);
+
+-- Each liquidity position filled by an arb, with the change in its reserves.
+--
+-- A positive delta means the position received that asset, a negative
+-- delta means the arb took that asset out of the position.
+CREATE TABLE IF NOT EXISTS dex_arb_position_fill (
+  id SERIAL PRIMARY KEY,
+  height BIGINT NOT NULL,
+  position_id BYTEA NOT NULL,
+  asset_1 BYTEA NOT NULL,
+  asset_2 BYTEA NOT NULL,
+  delta_1 NUMERIC(40, 0) NOT NULL,
+  delta_2 NUMERIC(40, 0) NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS dex_arb_position_fill_height_idx ON dex_arb_position_fill (height);
+CREATE INDEX IF NOT EXISTS dex_arb_position_fill_position_idx ON dex_arb_position_fill (position_id);
+
+-- Total arb profit extracted, per asset.
+CREATE OR REPLACE VIEW dex_arb_profit_by_asset AS
+  SELECT asset, COUNT(*) AS arbs, SUM(profit) AS profit
+  FROM dex_arb
+  GROUP BY asset;
+
+-- Total arb profit extracted, per epoch and asset.
+CREATE OR REPLACE VIEW dex_arb_profit_by_epoch AS
+  SELECT epoch, asset, COUNT(*) AS arbs, SUM(profit) AS profit
+  FROM dex_arb
+  WHERE epoch IS NOT NULL
+  GROUP BY epoch, asset;
+
+-- Total value moved in and out of each position by arbs.
+CREATE OR REPLACE VIEW dex_arb_fills_by_position AS
+  SELECT position_id, asset_1, asset_2, COUNT(*) AS fills, SUM(delta_1) AS delta_1, SUM(delta_2) AS delta_2
+  FROM dex_arb_position_fill
+  GROUP BY position_id, asset_1, asset_2;
@@ -0,0 +1,122 @@
+use cometindex::{async_trait, index::EventBatch, AppView, PgTransaction};
+use penumbra_asset::asset;
+use penumbra_dex::event::{
+    CircuitBreakerTrip, EventCircuitBreakerTrip, EventValueCircuitBreakerCredit,
+    EventValueCircuitBreakerDebit,
+};
+use penumbra_num::Amount;
+use penumbra_proto::event::EventDomainType as _;
+
+async fn init_db(dbtx: &mut PgTransaction<'_>) -> anyhow::Result<()> {
+    for statement in include_str!("schema.sql").split(";") {
+        sqlx::query(statement).execute(dbtx.as_mut()).await?;
+    }
+    Ok(())
+}
+
+async fn set_value_balance(
+    dbtx: &mut PgTransaction<'_>,
+    height: u64,
+    asset_id: asset::Id,
+    balance: Amount,
+) -> anyhow::Result<()> {
+    sqlx::query(
+        "INSERT INTO dex_value_balance VALUES ($1, $2::NUMERIC(39, 0), $3)
+         ON CONFLICT (asset) DO UPDATE SET balance = EXCLUDED.balance, height = EXCLUDED.height",
+    )
+    .bind(asset_id.to_bytes())
+    .bind(balance.to_string())
+    .bind(i64::try_from(height)?)
+    .execute(dbtx.as_mut())
+    .await?;
+    Ok(())
+}
+
+async fn record_trip(
+    dbtx: &mut PgTransaction<'_>,
+    event: EventCircuitBreakerTrip,
+) -> anyhow::Result<()> {
+    let height = i64::try_from(event.height)?;
+    match event.trip {
+        CircuitBreakerTrip::Value {
+            asset_id,
+            balance,
+            debit,
+            transaction_id,
+        } => {
+            sqlx::query(
+                "INSERT INTO dex_circuit_breaker_trip (height, kind, asset, balance, debit, tx_hash)
+                 VALUES ($1, 'value', $2, $3::NUMERIC(39, 0), $4::NUMERIC(39, 0), $5)",
+            )
+            .bind(height)
+            .bind(asset_id.to_bytes())
+            .bind(balance.to_string())
+            .bind(debit.to_string())
+            .bind(transaction_id.map(|id| id.0))
+            .execute(dbtx.as_mut())
+            .await?;
+        }
+        CircuitBreakerTrip::Execution {
+            trading_pair,
+            input,
+            unfilled,
+            execution_budget,
+        } => {
+            sqlx::query(
+                "INSERT INTO dex_circuit_breaker_trip (height, kind, asset_start, asset_end, input, unfilled, execution_budget)
+                 VALUES ($1, 'execution', $2, $3, $4::NUMERIC(39, 0), $5::NUMERIC(39, 0), $6)",
+            )
+            .bind(height)
+            .bind(trading_pair.start.to_bytes())
+            .bind(trading_pair.end.to_bytes())
+            .bind(input.to_string())
+            .bind(unfilled.to_string())
+            .bind(i32::try_from(execution_budget)?)
+            .execute(dbtx.as_mut())
+            .await?;
+        }
+    }
+    Ok(())
+}
+
+/// Indexes the DEX value balances and the trips of its circuit breakers.
+#[derive(Debug)]
+pub struct Component {}
+
+impl Component {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+#[async_trait]
+impl AppView for Component {
+    fn name(&self) -> String {
+        "dex_circuit_breaker".to_string()
+    }
+
+    async fn init_chain(
+        &self,
+        dbtx: &mut PgTransaction,
+        _app_state: &serde_json::Value,
+    ) -> anyhow::Result<()> {
+        init_db(dbtx).await
+    }
+
+    async fn index_batch(
+        &self,
+        dbtx: &mut PgTransaction,
+        batch: EventBatch,
+    ) -> Result<(), anyhow::Error> {
+        for event in batch.events() {
+            if let Ok(e) = EventValueCircuitBreakerCredit::try_from_event(&event.event) {
+                set_value_balance(dbtx, event.block_height, e.asset_id, e.new_balance).await?;
+            } else if let Ok(e) = EventValueCircuitBreakerDebit::try_from_event(&event.event) {
+                set_value_balance(dbtx, event.block_height, e.asset_id, e.new_balance).await?;
+            } else if let Ok(e) = EventCircuitBreakerTrip::try_from_event(&event.event) {
+                record_trip(dbtx, e).await?;
+            }
+        }
+        Ok(())
+    }
+}
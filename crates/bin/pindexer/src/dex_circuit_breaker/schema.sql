@@ -0,0 +1,36 @@
+-- The value held by the DEX for each asset, as tracked by the value circuit breaker.
+CREATE TABLE IF NOT EXISTS dex_value_balance (
+  asset BYTEA PRIMARY KEY,
+  -- The amount of the asset the DEX can pay out.
+  balance NUMERIC(39, 0) NOT NULL,
+  -- The height at which the balance last changed.
+  height BIGINT NOT NULL
+);
+
+-- Each trip of a DEX circuit breaker.
+CREATE TABLE IF NOT EXISTS dex_circuit_breaker_trip (
+  id SERIAL PRIMARY KEY,
+  height BIGINT NOT NULL,
+  -- Either 'value' or 'execution'.
+  kind TEXT NOT NULL,
+  -- For value trips, the asset whose balance would have underflowed.
+  asset BYTEA,
+  -- For value trips, the balance of the asset at the time of the trip.
+  balance NUMERIC(39, 0),
+  -- For value trips, the amount that was requested.
+  debit NUMERIC(39, 0),
+  -- For value trips, the hash of the rejected transaction.
+  tx_hash BYTEA,
+  -- For execution trips, the direction of the route-and-fill.
+  asset_start BYTEA,
+  asset_end BYTEA,
+  -- For execution trips, the input to the route-and-fill.
+  input NUMERIC(39, 0),
+  -- For execution trips, the input left unfilled once the budget was exhausted.
+  unfilled NUMERIC(39, 0),
+  -- For execution trips, the execution budget that was exhausted.
+  execution_budget INT
+);
+
+CREATE INDEX IF NOT EXISTS dex_circuit_breaker_trip_height_idx ON dex_circuit_breaker_trip (height);
+CREATE INDEX IF NOT EXISTS dex_circuit_breaker_trip_tx_hash_idx ON dex_circuit_breaker_trip (tx_hash);
@@ -20,6 +20,8 @@ impl IndexerExt for cometindex::Indexer {
                 .expect("should be able to parse passet"),
                 1000.0 * 1_000_000.0,
             )))
+            .with_index(Box::new(crate::dex_arb::Component::new()))
+            .with_index(Box::new(crate::dex_circuit_breaker::Component::new()))
             .with_index(Box::new(crate::supply::Component::new()))
             .with_index(Box::new(crate::ibc::Component::new()))
             .with_index(Box::new(crate::insights::Component::new(
@@ -17,7 +17,7 @@ use penumbra_shielded_pool::event::{
     EventOutboundFungibleTokenTransfer,
 };
 use penumbra_stake::{
-    event::{EventDelegate, EventRateDataChange, EventUndelegate},
+    event::{EventDelegate, EventRateDataChange, EventRedelegate, EventUndelegate},
     validator::Validator,
     IdentityKey,
 };
@@ -344,6 +344,47 @@ impl Component {
                 }),
             )
             .await?;
+        } else if let Ok(e) = EventRedelegate::try_from_event(&event.event) {
+            let amount = u64::try_from(e.amount.value())?;
+            let source_delta = modify_validator_supply(
+                dbtx,
+                height,
+                e.source_identity_key,
+                Box::new(move |supply| {
+                    Ok(ValidatorSupply {
+                        um: supply.um.saturating_sub(amount),
+                        ..supply
+                    })
+                }),
+            )
+            .await?;
+            let destination_delta = modify_validator_supply(
+                dbtx,
+                height,
+                e.destination_identity_key,
+                Box::new(move |supply| {
+                    Ok(ValidatorSupply {
+                        um: supply.um + amount,
+                        ..supply
+                    })
+                }),
+            )
+            .await?;
+            modify_supply(
+                dbtx,
+                height,
+                self.price_numeraire,
+                Box::new(move |supply| {
+                    // The stake moved between validators, so the amount staked should be unchanged.
+                    Ok(Supply {
+                        staked: u64::try_from(
+                            i64::try_from(supply.staked)? + source_delta + destination_delta,
+                        )?,
+                        ..supply
+                    })
+                }),
+            )
+            .await?;
         } else if let Ok(e) = EventRateDataChange::try_from_event(&event.event) {
             let delta = modify_validator_supply(
                 dbtx,
@@ -3,6 +3,8 @@ pub use cometindex::{opt::Options, AppView, ContextualizedEvent, Indexer, PgPool
 mod indexer_ext;
 pub use indexer_ext::IndexerExt;
 pub mod block;
+pub mod dex_arb;
+pub mod dex_circuit_breaker;
 pub mod dex_ex;
 pub mod ibc;
 pub mod insights;
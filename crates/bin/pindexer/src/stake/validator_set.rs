@@ -60,6 +60,23 @@ impl ValidatorSet {
                 )?;
                 handle_undelegate(dbtx, ik, amount).await?;
             }
+            "penumbra.core.component.stake.v1.EventRedelegate" => {
+                let pe = pb::EventRedelegate::from_event(event.as_ref())?;
+                let source_ik = IdentityKey::try_from(
+                    pe.source_identity_key
+                        .ok_or_else(|| anyhow!("missing source ik in event"))?,
+                )?;
+                let destination_ik = IdentityKey::try_from(
+                    pe.destination_identity_key
+                        .ok_or_else(|| anyhow!("missing destination ik in event"))?,
+                )?;
+                let amount = Amount::try_from(
+                    pe.amount
+                        .ok_or_else(|| anyhow!("missing amount in event"))?,
+                )?;
+                handle_undelegate(dbtx, source_ik, amount).await?;
+                handle_delegate(dbtx, destination_ik, amount).await?;
+            }
             "penumbra.core.component.stake.v1.EventValidatorVotingPowerChange" => {
                 let pe = pb::EventValidatorVotingPowerChange::from_event(event.as_ref())?;
                 let ik = IdentityKey::try_from(
@@ -375,6 +375,13 @@ enum Event {
         identity_key: IdentityKey,
         amount: Amount,
     },
+    /// A parsed version of [pb::EventRedelegate]
+    Redelegate {
+        height: u64,
+        source_identity_key: IdentityKey,
+        destination_identity_key: IdentityKey,
+        amount: Amount,
+    },
     /// A parsed version of [pb::EventFundingStreamReward]
     FundingStreamReward { height: u64, reward_amount: Amount },
     /// A parsed version of EventRateDataChange
@@ -422,7 +429,7 @@ enum Event {
 }
 
 impl Event {
-    const NAMES: [&'static str; 10] = [
+    const NAMES: [&'static str; 11] = [
         "penumbra.core.component.stake.v1.EventUndelegate",
         "penumbra.core.component.stake.v1.EventDelegate",
         "penumbra.core.component.funding.v1.EventFundingStreamReward",
@@ -433,6 +440,7 @@ impl Event {
         "penumbra.core.component.dex.v1.EventValueCircuitBreakerDebit",
         "penumbra.core.component.dex.v1.EventArbExecution",
         "penumbra.core.component.fee.v1.EventBlockFees",
+        "penumbra.core.component.stake.v1.EventRedelegate",
     ];
 
     async fn index<'d>(&self, dbtx: &mut Transaction<'d, Postgres>) -> anyhow::Result<()> {
@@ -481,6 +489,28 @@ impl Event {
                 })
                 .await
             }
+            Event::Redelegate {
+                height,
+                source_identity_key,
+                destination_identity_key,
+                amount,
+            } => {
+                // The stake moves between delegation pools, without ever becoming unstaked.
+                let amount = i64::try_from(amount.value())?;
+
+                let source = delegated_supply::define_validator(dbtx, source_identity_key).await?;
+                delegated_supply::modify(dbtx, source, *height, |current| {
+                    current.unwrap_or_default().add_um(-amount)
+                })
+                .await?;
+
+                let destination =
+                    delegated_supply::define_validator(dbtx, destination_identity_key).await?;
+                delegated_supply::modify(dbtx, destination, *height, |current| {
+                    current.unwrap_or_default().add_um(amount)
+                })
+                .await
+            }
             Event::FundingStreamReward {
                 height,
                 reward_amount,
@@ -830,6 +860,32 @@ impl<'a> TryFrom<&'a ContextualizedEvent> for Event {
                     total,
                 })
             }
+            // redelegation
+            x if x == Event::NAMES[10] => {
+                let pe = pb_stake::EventRedelegate::from_event(event.as_ref())?;
+                let source_identity_key = pe
+                    .source_identity_key
+                    .ok_or(anyhow!(
+                        "EventRedelegate should contain source identity key"
+                    ))?
+                    .try_into()?;
+                let destination_identity_key = pe
+                    .destination_identity_key
+                    .ok_or(anyhow!(
+                        "EventRedelegate should contain destination identity key"
+                    ))?
+                    .try_into()?;
+                let amount = pe
+                    .amount
+                    .ok_or(anyhow!("EventRedelegate should contain amount"))?
+                    .try_into()?;
+                Ok(Self::Redelegate {
+                    height: event.block_height,
+                    source_identity_key,
+                    destination_identity_key,
+                    amount,
+                })
+            }
             x => Err(anyhow!(format!("unrecognized event kind: {x}"))),
         }
     }
@@ -7,6 +7,8 @@ use cnidarium::{StateRead, StateWrite};
 mod actions;
 mod transaction;
 
+pub(crate) use actions::{build_community_pool_transaction, enact_batch_proposal};
+
 /// Stub: to be replaced with impls of cnidarium_component::ActionHandler
 ///
 /// This trait should move to that crate, but the orphan rules make it tricky to
@@ -9,6 +9,8 @@ use penumbra_txhash::TransactionContext;
 
 mod submit;
 
+pub(crate) use submit::{build_community_pool_transaction, enact_batch_proposal};
+
 use crate::PenumbraHost;
 
 use super::AppActionHandler;
@@ -25,9 +27,11 @@ impl AppActionHandler for Action {
             Action::DelegatorVote(action) => action.check_stateless(context).await,
             Action::Delegate(action) => action.check_stateless(()).await,
             Action::Undelegate(action) => action.check_stateless(()).await,
+            Action::Redelegate(action) => action.check_stateless(()).await,
             Action::UndelegateClaim(action) => action.check_stateless(()).await,
             Action::ValidatorDefinition(action) => action.check_stateless(()).await,
             Action::ValidatorVote(action) => action.check_stateless(()).await,
+            Action::ProxyVote(action) => action.check_stateless(()).await,
             Action::PositionClose(action) => action.check_stateless(()).await,
             Action::PositionOpen(action) => action.check_stateless(()).await,
             Action::PositionWithdraw(action) => action.check_stateless(()).await,
@@ -52,6 +56,7 @@ impl AppActionHandler for Action {
             }
             Action::CommunityPoolSpend(action) => action.check_stateless(()).await,
             Action::CommunityPoolOutput(action) => action.check_stateless(()).await,
+            Action::CommunityPoolVestingClaim(action) => action.check_stateless(()).await,
             Action::CommunityPoolDeposit(action) => action.check_stateless(()).await,
             Action::ActionDutchAuctionSchedule(action) => action.check_stateless(()).await,
             Action::ActionDutchAuctionEnd(action) => action.check_stateless(()).await,
@@ -63,10 +68,12 @@ impl AppActionHandler for Action {
         match self {
             Action::Delegate(action) => action.check_historical(state).await,
             Action::Undelegate(action) => action.check_historical(state).await,
+            Action::Redelegate(action) => action.check_historical(state).await,
             Action::UndelegateClaim(action) => action.check_historical(state).await,
             Action::ValidatorDefinition(action) => action.check_historical(state).await,
             Action::DelegatorVote(action) => action.check_historical(state).await,
             Action::ValidatorVote(action) => action.check_historical(state).await,
+            Action::ProxyVote(action) => action.check_historical(state).await,
             Action::PositionClose(action) => action.check_historical(state).await,
             Action::PositionOpen(action) => action.check_historical(state).await,
             Action::PositionWithdraw(action) => action.check_historical(state).await,
@@ -93,6 +100,7 @@ impl AppActionHandler for Action {
             }
             Action::CommunityPoolSpend(action) => action.check_historical(state).await,
             Action::CommunityPoolOutput(action) => action.check_historical(state).await,
+            Action::CommunityPoolVestingClaim(action) => action.check_historical(state).await,
             Action::CommunityPoolDeposit(action) => action.check_historical(state).await,
             Action::ActionDutchAuctionSchedule(action) => action.check_historical(state).await,
             Action::ActionDutchAuctionEnd(action) => action.check_historical(state).await,
@@ -104,10 +112,12 @@ impl AppActionHandler for Action {
         match self {
             Action::Delegate(action) => action.check_and_execute(state).await,
             Action::Undelegate(action) => action.check_and_execute(state).await,
+            Action::Redelegate(action) => action.check_and_execute(state).await,
             Action::UndelegateClaim(action) => action.check_and_execute(state).await,
             Action::ValidatorDefinition(action) => action.check_and_execute(state).await,
             Action::DelegatorVote(action) => action.check_and_execute(state).await,
             Action::ValidatorVote(action) => action.check_and_execute(state).await,
+            Action::ProxyVote(action) => action.check_and_execute(state).await,
             Action::PositionClose(action) => action.check_and_execute(state).await,
             Action::PositionOpen(action) => action.check_and_execute(state).await,
             Action::PositionWithdraw(action) => action.check_and_execute(state).await,
@@ -134,6 +144,7 @@ impl AppActionHandler for Action {
             }
             Action::CommunityPoolSpend(action) => action.check_and_execute(state).await,
             Action::CommunityPoolOutput(action) => action.check_and_execute(state).await,
+            Action::CommunityPoolVestingClaim(action) => action.check_and_execute(state).await,
             Action::CommunityPoolDeposit(action) => action.check_and_execute(state).await,
             Action::ActionDutchAuctionSchedule(action) => action.check_and_execute(state).await,
             Action::ActionDutchAuctionEnd(action) => action.check_and_execute(state).await,
@@ -1,31 +1,32 @@
-use std::str::FromStr;
+use std::{str::FromStr, sync::Arc};
 
 use anyhow::{Context, Result};
 use async_trait::async_trait;
 use decaf377::Fq;
 use decaf377_rdsa::{VerificationKey, VerificationKeyBytes};
-use ibc_types::core::client::ClientId;
+use ibc_types::core::{client::ClientId, connection::ConnectionId};
 use once_cell::sync::Lazy;
 
-use cnidarium::StateWrite;
+use cnidarium::{StateDelta, StateRead, StateWrite};
 use penumbra_asset::STAKING_TOKEN_DENOM;
 use penumbra_community_pool::component::StateReadExt as _;
 use penumbra_governance::{
     component::{StateReadExt as _, StateWriteExt as _},
     event,
-    proposal::{Proposal, ProposalPayload},
+    proposal::{BatchCondition, Proposal, ProposalPayload},
     proposal_state::State as ProposalState,
-    ProposalNft, ProposalSubmit, VotingReceiptToken,
+    state_key, ProposalNft, ProposalSubmit, VotingReceiptToken,
 };
-use penumbra_ibc::component::ClientStateReadExt;
+use penumbra_ibc::component::{ClientStateReadExt, ConnectionStateReadExt as _};
 use penumbra_keys::keys::{FullViewingKey, NullifierKey};
-use penumbra_proto::{DomainType, StateWriteProto as _};
+use penumbra_num::Amount;
+use penumbra_proto::{DomainType, StateReadProto as _, StateWriteProto as _};
 use penumbra_sct::component::clock::EpochRead;
 use penumbra_sct::component::tree::SctRead;
 use penumbra_shielded_pool::component::AssetRegistry;
 use penumbra_transaction::{AuthorizationData, Transaction, TransactionPlan, WitnessData};
 
-use crate::app::StateReadExt;
+use crate::app::{StateReadExt, StateWriteExt as _};
 use crate::community_pool_ext::CommunityPoolStateWriteExt;
 use crate::{action_handler::AppActionHandler, params::change::ParameterChangeExt as _};
 
@@ -51,7 +52,7 @@ impl AppActionHandler for ProposalSubmit {
             deposit_amount: _, // we don't check the deposit amount because it's defined by state
         } = self;
         let Proposal {
-            id: _, // we can't check the ID statelessly because it's defined by state
+            id, // we can't check the ID statelessly because it's defined by state
             title,
             description,
             payload,
@@ -68,54 +69,120 @@ impl AppActionHandler for ProposalSubmit {
         }
 
         use penumbra_governance::ProposalPayload::*;
-        match payload {
-            Signaling { commit: _ } => { /* all signaling proposals are valid */ }
-            Emergency { halt_chain: _ } => { /* all emergency proposals are valid */ }
-            ParameterChange(_change) => { /* no stateless checks -- see check-and-execute below */ }
-            CommunityPoolSpend { transaction_plan } => {
-                // Check to make sure that the transaction plan contains only valid actions for the
-                // Community Pool (none of them should require proving to build):
-                use penumbra_transaction::plan::ActionPlan::*;
-
-                let parsed_transaction_plan = TransactionPlan::decode(&transaction_plan[..])
-                    .context("transaction plan was malformed")?;
-
-                for action in &parsed_transaction_plan.actions {
-                    match action {
-                        Spend(_) | Output(_) | Swap(_) | SwapClaim(_) | DelegatorVote(_)
-                        | UndelegateClaim(_) => {
-                            anyhow::bail!("invalid action in Community Pool spend proposal (would require proving)")
-                        }
-                        Delegate(_) | Undelegate(_) => {
-                            anyhow::bail!("invalid action in Community Pool spend proposal (can't claim outputs of undelegation)")
-                        }
-                        ProposalSubmit(_) | ProposalWithdraw(_) | ProposalDepositClaim(_) => {
-                            anyhow::bail!("invalid action in Community Pool spend proposal (not allowed to manipulate proposals from within proposals)")
+        if let Batch {
+            payloads,
+            conditions,
+        } = payload
+        {
+            ProposalPayload::validate_batch(payloads)?;
+            for condition in conditions {
+                // The batch's own ID is checked against the state below, so this ensures the
+                // proposals it depends on were submitted before it.
+                if let BatchCondition::ProposalPassed { proposal_id } = condition {
+                    anyhow::ensure!(
+                        proposal_id < id,
+                        "batch proposal {id} can't depend on later proposal {proposal_id}"
+                    );
+                }
+            }
+        }
+        // A batch is checked by checking each of the payloads it contains.
+        for payload in payload.enacted_payloads() {
+            match payload {
+                Signaling { commit: _ } => { /* all signaling proposals are valid */ }
+                Emergency { halt_chain: _ } => { /* all emergency proposals are valid */ }
+                ParameterChange(_change) => {
+                    // no stateless checks -- see check-and-execute below
+                }
+                CommunityPoolSpend { transaction_plan } => {
+                    // Check to make sure that the transaction plan contains only valid actions for the
+                    // Community Pool (none of them should require proving to build):
+                    use penumbra_transaction::plan::ActionPlan::*;
+
+                    let parsed_transaction_plan = TransactionPlan::decode(&transaction_plan[..])
+                        .context("transaction plan was malformed")?;
+
+                    for action in &parsed_transaction_plan.actions {
+                        match action {
+                            Spend(_) | Output(_) | Swap(_) | SwapClaim(_) | DelegatorVote(_)
+                            | UndelegateClaim(_) => {
+                                anyhow::bail!("invalid action in Community Pool spend proposal (would require proving)")
+                            }
+                            Delegate(_) | Undelegate(_) | Redelegate(_) => {
+                                anyhow::bail!("invalid action in Community Pool spend proposal (can't claim outputs of undelegation)")
+                            }
+                            ProposalSubmit(_) | ProposalWithdraw(_) | ProposalDepositClaim(_) => {
+                                anyhow::bail!("invalid action in Community Pool spend proposal (not allowed to manipulate proposals from within proposals)")
+                            }
+                            ProxyVote(_) => {
+                                anyhow::bail!("invalid action in Community Pool spend proposal (would require a proxy's signature)")
+                            }
+                            CommunityPoolVestingClaim(_) => {
+                                anyhow::bail!("invalid action in Community Pool spend proposal (vesting grants pay out of the Community Pool)")
+                            }
+                            ValidatorDefinition(_)
+                            | IbcAction(_)
+                            | ValidatorVote(_)
+                            | PositionOpen(_)
+                            | PositionClose(_)
+                            | PositionWithdraw(_)
+                            | CommunityPoolSpend(_)
+                            | CommunityPoolOutput(_)
+                            | Ics20Withdrawal(_)
+                            | CommunityPoolDeposit(_)
+                            | ActionDutchAuctionSchedule(_)
+                            | ActionDutchAuctionEnd(_)
+                            | ActionDutchAuctionWithdraw(_) => {}
                         }
-                        ValidatorDefinition(_)
-                        | IbcAction(_)
-                        | ValidatorVote(_)
-                        | PositionOpen(_)
-                        | PositionClose(_)
-                        | PositionWithdraw(_)
-                        | CommunityPoolSpend(_)
-                        | CommunityPoolOutput(_)
-                        | Ics20Withdrawal(_)
-                        | CommunityPoolDeposit(_)
-                        | ActionDutchAuctionSchedule(_)
-                        | ActionDutchAuctionEnd(_)
-                        | ActionDutchAuctionWithdraw(_) => {}
                     }
                 }
-            }
-            UpgradePlan { .. } => {}
-            FreezeIbcClient { client_id } => {
-                let _ = &ClientId::from_str(client_id)
-                    .context("can't decode client id from IBC proposal")?;
-            }
-            UnfreezeIbcClient { client_id } => {
-                let _ = &ClientId::from_str(client_id)
-                    .context("can't decode client id from IBC proposal")?;
+                UpgradePlan { .. } => {}
+                FreezeIbcClient { client_id } => {
+                    let _ = &ClientId::from_str(client_id)
+                        .context("can't decode client id from IBC proposal")?;
+                }
+                UnfreezeIbcClient { client_id } => {
+                    let _ = &ClientId::from_str(client_id)
+                        .context("can't decode client id from IBC proposal")?;
+                }
+                CommunityPoolVestingGrant {
+                    recipient: _,
+                    total,
+                    cliff_epochs: _,
+                    release_per_epoch,
+                } => {
+                    anyhow::ensure!(
+                        total.amount > Amount::zero(),
+                        "vesting grant total must be nonzero"
+                    );
+                    anyhow::ensure!(
+                        *release_per_epoch > Amount::zero(),
+                        "vesting grant must release a nonzero amount per epoch"
+                    );
+                }
+                CancelCommunityPoolVestingGrant { grant_id: _ } => {}
+                RegisterInterchainAccount { connection_id } => {
+                    let _ = &ConnectionId::from_str(connection_id)
+                        .context("can't decode connection id from interchain account proposal")?;
+                }
+                InterchainAccountTx {
+                    connection_id,
+                    messages,
+                    memo: _,
+                    timeout_seconds,
+                } => {
+                    let _ = &ConnectionId::from_str(connection_id)
+                        .context("can't decode connection id from interchain account proposal")?;
+                    anyhow::ensure!(
+                        !messages.is_empty(),
+                        "interchain account transaction must contain at least one message"
+                    );
+                    anyhow::ensure!(
+                        *timeout_seconds > 0,
+                        "interchain account transaction timeout must be nonzero"
+                    );
+                }
+                Batch { .. } => anyhow::bail!("batch proposals cannot be nested"),
             }
         }
 
@@ -154,73 +221,124 @@ impl AppActionHandler for ProposalSubmit {
             );
         }
 
-        match &proposal.payload {
-            ProposalPayload::Signaling { .. } => { /* no stateful checks for signaling */ }
-            ProposalPayload::Emergency { .. } => { /* no stateful checks for emergency */ }
-            ProposalPayload::ParameterChange(change) => {
-                // Check that the parameter change is valid and could be applied to the current
-                // parameters. This doesn't guarantee that it will be valid when/if it passes but
-                // ensures that clearly malformed proposals are rejected upfront.
-                let current_parameters = state.get_app_params().await?;
-                change
-                    .apply_changes(current_parameters)
-                    .context("proposed parameter changes do not apply to current parameters")?;
-            }
-            ProposalPayload::CommunityPoolSpend { transaction_plan } => {
-                // If Community Pool spend proposals aren't enabled, then we can't allow them to be submitted
-                let community_pool_parameters = state.get_community_pool_params().await?;
-                anyhow::ensure!(
-                    community_pool_parameters.community_pool_spend_proposals_enabled,
-                    "Community Pool spend proposals are not enabled",
-                );
-
-                // Check that the transaction plan can be built without any witness or auth data and
-                // it passes stateless and stateful checks, and can be executed successfully in the
-                // current chain state. This doesn't guarantee that it will execute successfully at
-                // the time when the proposal passes, but we don't want to allow proposals that are
-                // obviously going to fail to execute.
-                //
-                // NOTE: we do not do stateful checks, see below
-                let parsed_transaction_plan = TransactionPlan::decode(&transaction_plan[..])
-                    .context("transaction plan was malformed")?;
-                let tx = build_community_pool_transaction(parsed_transaction_plan.clone())
-                    .await
-                    .context("failed to build submitted Community Pool spend transaction plan")?;
-                tx.check_stateless(()).await.context(
-                    "submitted Community Pool spend transaction failed stateless checks",
-                )?;
-                /*
-                // We skip stateful checks rather than doing them in simulation. Partly this is
-                // because it's easier to not check, but also it avoids having to reason about whether
-                // there are any cases where a transaction could be invalid when submitted but become
-                // valid when voting finishes (e.g., an undelegation?)
-
-                tx.check_historical(state.clone())
-                    .await
-                    .context("submitted Community Pool spend transaction failed stateful checks")?;
-                tx.check_and_execute(StateDelta::new(state)).await.context(
-                    "submitted Community Pool spend transaction failed to execute in current chain state",
-                )?;
-                 */
-            }
-            ProposalPayload::UpgradePlan { .. } => {
-                // TODO(erwan): no stateful checks for upgrade plan.
-            }
-            ProposalPayload::FreezeIbcClient { client_id } => {
-                // Check that the client ID is valid and that there is a corresponding
-                // client state. If the client state is already frozen, then freezing it
-                // is a no-op.
-                let client_id = &ClientId::from_str(client_id)
-                    .map_err(|e| tonic::Status::aborted(format!("invalid client id: {e}")))?;
-                let _ = state.get_client_state(client_id).await?;
-            }
-            ProposalPayload::UnfreezeIbcClient { client_id } => {
-                // Check that the client ID is valid and that there is a corresponding
-                // client state. If the client state is not frozen, then unfreezing it
-                // is a no-op.
-                let client_id = &ClientId::from_str(client_id)
-                    .map_err(|e| tonic::Status::aborted(format!("invalid client id: {e}")))?;
-                let _ = state.get_client_state(client_id).await?;
+        for payload in proposal.payload.enacted_payloads() {
+            match payload {
+                ProposalPayload::Signaling { .. } => { /* no stateful checks for signaling */ }
+                ProposalPayload::Emergency { .. } => { /* no stateful checks for emergency */ }
+                ProposalPayload::ParameterChange(change) => {
+                    // Check that the parameter change is valid and could be applied to the current
+                    // parameters. This doesn't guarantee that it will be valid when/if it passes but
+                    // ensures that clearly malformed proposals are rejected upfront.
+                    let current_parameters = state.get_app_params().await?;
+                    change
+                        .apply_changes(current_parameters)
+                        .context("proposed parameter changes do not apply to current parameters")?;
+                }
+                ProposalPayload::CommunityPoolSpend { transaction_plan } => {
+                    // If Community Pool spend proposals aren't enabled, then we can't allow them to be submitted
+                    let community_pool_parameters = state.get_community_pool_params().await?;
+                    anyhow::ensure!(
+                        community_pool_parameters.community_pool_spend_proposals_enabled,
+                        "Community Pool spend proposals are not enabled",
+                    );
+
+                    // Check that the transaction plan can be built without any witness or auth data and
+                    // it passes stateless and stateful checks, and can be executed successfully in the
+                    // current chain state. This doesn't guarantee that it will execute successfully at
+                    // the time when the proposal passes, but we don't want to allow proposals that are
+                    // obviously going to fail to execute.
+                    //
+                    // NOTE: we do not do stateful checks, see below
+                    let parsed_transaction_plan = TransactionPlan::decode(&transaction_plan[..])
+                        .context("transaction plan was malformed")?;
+                    let tx = build_community_pool_transaction(parsed_transaction_plan.clone())
+                        .await
+                        .context(
+                            "failed to build submitted Community Pool spend transaction plan",
+                        )?;
+                    tx.check_stateless(()).await.context(
+                        "submitted Community Pool spend transaction failed stateless checks",
+                    )?;
+                    /*
+                    // We skip stateful checks rather than doing them in simulation. Partly this is
+                    // because it's easier to not check, but also it avoids having to reason about whether
+                    // there are any cases where a transaction could be invalid when submitted but become
+                    // valid when voting finishes (e.g., an undelegation?)
+
+                    tx.check_historical(state.clone())
+                        .await
+                        .context("submitted Community Pool spend transaction failed stateful checks")?;
+                    tx.check_and_execute(StateDelta::new(state)).await.context(
+                        "submitted Community Pool spend transaction failed to execute in current chain state",
+                    )?;
+                     */
+                }
+                ProposalPayload::UpgradePlan { .. } => {
+                    // TODO(erwan): no stateful checks for upgrade plan.
+                }
+                ProposalPayload::FreezeIbcClient { client_id } => {
+                    // Check that the client ID is valid and that there is a corresponding
+                    // client state. If the client state is already frozen, then freezing it
+                    // is a no-op.
+                    let client_id = &ClientId::from_str(client_id)
+                        .map_err(|e| tonic::Status::aborted(format!("invalid client id: {e}")))?;
+                    let _ = state.get_client_state(client_id).await?;
+                }
+                ProposalPayload::UnfreezeIbcClient { client_id } => {
+                    // Check that the client ID is valid and that there is a corresponding
+                    // client state. If the client state is not frozen, then unfreezing it
+                    // is a no-op.
+                    let client_id = &ClientId::from_str(client_id)
+                        .map_err(|e| tonic::Status::aborted(format!("invalid client id: {e}")))?;
+                    let _ = state.get_client_state(client_id).await?;
+                }
+                ProposalPayload::CommunityPoolVestingGrant { total, .. } => {
+                    // Vesting grants are funded by the Community Pool, so they're subject to the
+                    // same parameter as Community Pool spend proposals.
+                    let community_pool_parameters = state.get_community_pool_params().await?;
+                    anyhow::ensure!(
+                        community_pool_parameters.community_pool_spend_proposals_enabled,
+                        "Community Pool spend proposals are not enabled",
+                    );
+
+                    // Check that the Community Pool could fund the grant now. This doesn't guarantee
+                    // that it can when the proposal passes, in which case the proposal fails to enact.
+                    let balance = state.community_pool_asset_balance(total.asset_id).await?;
+                    anyhow::ensure!(
+                        balance >= total.amount,
+                        "Community Pool balance of {} is insufficient to fund a vesting grant of {}",
+                        balance,
+                        total.amount,
+                    );
+                }
+                ProposalPayload::CancelCommunityPoolVestingGrant { grant_id } => {
+                    let grant = state.vesting_grant(*grant_id).await?.ok_or_else(|| {
+                        anyhow::anyhow!("vesting grant {grant_id} does not exist")
+                    })?;
+                    anyhow::ensure!(
+                        !grant.cancelled,
+                        "vesting grant {grant_id} was already cancelled"
+                    );
+                }
+                ProposalPayload::RegisterInterchainAccount { connection_id }
+                | ProposalPayload::InterchainAccountTx { connection_id, .. } => {
+                    // Interchain accounts act on behalf of the Community Pool, so they're subject
+                    // to the same parameter as Community Pool spend proposals.
+                    let community_pool_parameters = state.get_community_pool_params().await?;
+                    anyhow::ensure!(
+                        community_pool_parameters.community_pool_spend_proposals_enabled,
+                        "Community Pool spend proposals are not enabled",
+                    );
+
+                    // Check that the connection exists. Whether the account can be registered or
+                    // used depends on the state of its channel when the proposal passes.
+                    let connection_id = &ConnectionId::from_str(connection_id)
+                        .context("can't decode connection id from interchain account proposal")?;
+                    state.get_connection(connection_id).await?.ok_or_else(|| {
+                        anyhow::anyhow!("connection {connection_id} does not exist")
+                    })?;
+                }
+                ProposalPayload::Batch { .. } => anyhow::bail!("batch proposals cannot be nested"),
             }
         }
 
@@ -231,9 +349,12 @@ impl AppActionHandler for ProposalSubmit {
             deposit_amount,
         } = self;
 
-        // If the proposal is a Community Pool spend proposal, we've already built it, but we need to build it
-        // again because we can't remember anything from `check_tx_stateful` to `execute`:
-        if let ProposalPayload::CommunityPoolSpend { transaction_plan } = &proposal.payload {
+        // If the proposal is (or batches) a Community Pool spend proposal, we've already built it, but we
+        // need to build it again because we can't remember anything from `check_tx_stateful` to `execute`:
+        for payload in proposal.payload.enacted_payloads() {
+            let ProposalPayload::CommunityPoolSpend { transaction_plan } = payload else {
+                continue;
+            };
             // Build the transaction again (this time we know it will succeed because it built and
             // passed all checks in `check_tx_stateful`):
             let parsed_transaction_plan = TransactionPlan::decode(&transaction_plan[..])
@@ -281,6 +402,10 @@ impl AppActionHandler for ProposalSubmit {
         let voting_end = current_block + governance_params.proposal_voting_blocks;
         state.put_proposal_voting_start(proposal_id, current_block);
         state.put_proposal_voting_end(proposal_id, voting_end);
+        state
+            .snapshot_standing_proxy_delegations(proposal_id)
+            .await
+            .context("can record standing proxy delegations")?;
 
         // Compute the effective starting TCT position for the proposal, by rounding the current
         // position down to the start of the block.
@@ -342,7 +467,7 @@ static COMMUNITY_POOL_FULL_VIEWING_KEY: Lazy<FullViewingKey> = Lazy::new(|| {
     FullViewingKey::from_components(ak, nk)
 });
 
-async fn build_community_pool_transaction(
+pub(crate) async fn build_community_pool_transaction(
     transaction_plan: TransactionPlan,
 ) -> Result<Transaction> {
     let effect_hash = transaction_plan.effect_hash(&COMMUNITY_POOL_FULL_VIEWING_KEY)?;
@@ -360,6 +485,96 @@ async fn build_community_pool_transaction(
     )
 }
 
+/// Enacts the `payloads` of a passed batch proposal in the `state`, if its `conditions` hold.
+///
+/// A lone parameter change or Community Pool spend only takes effect in the block after its
+/// proposal passes, so the batch's take effect here, together with its other payloads. The inner
+/// error reports why the batch failed to enact, in which case the returned state must be
+/// discarded.
+pub(crate) async fn enact_batch_proposal<S: StateRead + 'static>(
+    mut state: StateDelta<S>,
+    proposal_id: u64,
+    payloads: &[ProposalPayload],
+    conditions: &[BatchCondition],
+) -> Result<(StateDelta<S>, Result<()>)> {
+    if let Err(error) = state.check_batch_conditions(conditions).await {
+        return Ok((state, Err(error.context("batch conditions do not hold"))));
+    }
+
+    for (index, payload) in payloads.iter().enumerate() {
+        let enacted = match payload {
+            ProposalPayload::ParameterChange(change) => change
+                .apply_changes(state.get_app_params().await?)
+                .map(|new_params| state.put_app_params(new_params)),
+            ProposalPayload::CommunityPoolSpend { .. } => {
+                // The transaction was built and cached when the batch was submitted.
+                let transaction: Transaction = state
+                    .get(&state_key::community_pool_transaction(proposal_id))
+                    .await?
+                    .ok_or_else(|| {
+                        anyhow::anyhow!("no transaction found for proposal {proposal_id}")
+                    })?;
+                let (new_state, executed) =
+                    execute_community_pool_transaction(state, transaction).await?;
+                state = new_state;
+                executed
+            }
+            payload => state.enact_proposal(proposal_id, payload).await?,
+        };
+        if let Err(error) = enacted {
+            return Ok((
+                state,
+                Err(error.context(format!(
+                    "batched payload {index} ({:?}) failed to enact",
+                    payload.kind()
+                ))),
+            ));
+        }
+    }
+
+    Ok((state, Ok(())))
+}
+
+/// Checks and executes a Community Pool `transaction` in the `state`, as when delivering it.
+///
+/// The inner error reports why the transaction failed, in which case the returned state must be
+/// discarded.
+async fn execute_community_pool_transaction<S: StateRead + 'static>(
+    state: StateDelta<S>,
+    transaction: Transaction,
+) -> Result<(StateDelta<S>, Result<()>)> {
+    if let Err(error) = transaction.check_stateless(()).await {
+        return Ok((
+            state,
+            Err(error.context("transaction failed stateless checks")),
+        ));
+    }
+
+    let shared_state = Arc::new(state);
+    let checked = transaction.check_historical(shared_state.clone()).await;
+    let mut state = Arc::try_unwrap(shared_state)
+        .map_err(|_| anyhow::anyhow!("state Arc should be unique after checks"))?;
+    if let Err(error) = checked {
+        return Ok((
+            state,
+            Err(error.context("transaction failed stateful checks")),
+        ));
+    }
+
+    // Index the transaction, as it's delivered in this block.
+    let height = state.get_block_height().await?;
+    state
+        .put_block_transaction(height, transaction.clone().into())
+        .await
+        .context("storing transactions")?;
+
+    let executed = transaction
+        .check_and_execute(&mut state)
+        .await
+        .context("transaction failed to execute");
+    Ok((state, executed))
+}
+
 #[cfg(test)]
 mod test {
     /// Ensure that the Community Pool full viewing key can be constructed and does not panic when referenced.
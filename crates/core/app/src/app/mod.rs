@@ -13,12 +13,16 @@ use penumbra_community_pool::component::{CommunityPool, StateWriteExt as _};
 use penumbra_community_pool::StateReadExt as _;
 use penumbra_compact_block::component::CompactBlockManager;
 use penumbra_dex::component::StateReadExt as _;
-use penumbra_dex::component::{Dex, StateWriteExt as _};
+use penumbra_dex::component::{
+    CircuitBreakerTripRecorder as _, Dex, StateWriteExt as _, ValueCircuitBreakerTripped,
+};
+use penumbra_dex::event::CircuitBreakerTrip;
 use penumbra_distributions::component::{Distributions, StateReadExt as _, StateWriteExt as _};
 use penumbra_fee::component::{FeeComponent, StateReadExt as _, StateWriteExt as _};
 use penumbra_funding::component::Funding;
 use penumbra_funding::component::{StateReadExt as _, StateWriteExt as _};
 use penumbra_governance::component::{Governance, StateReadExt as _, StateWriteExt as _};
+use penumbra_governance::ProposalPayload;
 use penumbra_ibc::component::{Ibc, StateWriteExt as _};
 use penumbra_ibc::StateReadExt as _;
 use penumbra_proto::core::app::v1::TransactionsByHeightResponse;
@@ -40,7 +44,7 @@ use tendermint::validator::Update;
 use tokio::time::sleep;
 use tracing::{instrument, Instrument};
 
-use crate::action_handler::AppActionHandler;
+use crate::action_handler::{enact_batch_proposal, AppActionHandler};
 use crate::genesis::AppState;
 use crate::params::change::ParameterChangeExt as _;
 use crate::params::AppParameters;
@@ -380,6 +384,38 @@ impl App {
             }
         }
 
+        // Enact the batch proposals which passed in the last block. Each is enacted in a fork of
+        // the state, which is only applied if its conditions hold and all of its payloads succeed.
+        let pending_batches = self
+            .state
+            .pending_batch_proposals()
+            .await
+            .expect("batch proposals should always be readable");
+        for proposal_id in pending_batches {
+            let Some(ProposalPayload::Batch {
+                payloads,
+                conditions,
+            }) = self
+                .state
+                .proposal_payload(proposal_id)
+                .await
+                .expect("proposal payloads should always be readable")
+            else {
+                tracing::warn!(proposal_id, "scheduled batch proposal not found");
+                continue;
+            };
+            let fork = StateDelta::new(self.state.clone());
+            match enact_batch_proposal(fork, proposal_id, &payloads, &conditions).await {
+                Ok((fork, Ok(()))) => {
+                    tracing::info!(proposal_id, "enacted batch proposal");
+                    events.extend(self.apply(fork));
+                }
+                Ok((_, Err(error))) | Err(error) => {
+                    tracing::warn!(proposal_id, ?error, "failed to enact batch proposal");
+                }
+            }
+        }
+
         events
     }
 
@@ -452,9 +488,31 @@ impl App {
             .await
             .context("storing transactions")?;
 
-        tx.check_and_execute(&mut state_tx)
-            .await
-            .context("executing transaction")?;
+        if let Err(e) = tx.check_and_execute(&mut state_tx).await {
+            // The transaction's changes are discarded, but if it was rejected by the
+            // DEX value circuit breaker, we still want to keep a record of the trip.
+            drop(state_tx);
+            if let Some(tripped) = e
+                .chain()
+                .find_map(|cause| cause.downcast_ref::<ValueCircuitBreakerTripped>())
+            {
+                let mut trip_tx = self
+                    .state
+                    .try_begin_transaction()
+                    .context("state Arc should be present and unique")?;
+                trip_tx
+                    .record_circuit_breaker_trip(CircuitBreakerTrip::Value {
+                        asset_id: tripped.debit.asset_id,
+                        balance: tripped.balance,
+                        debit: tripped.debit.amount,
+                        transaction_id: Some(tx.id()),
+                    })
+                    .await
+                    .context("recording circuit breaker trip")?;
+                trip_tx.apply();
+            }
+            return Err(e).context("executing transaction");
+        }
 
         // At this point, we've completed execution successfully with no errors,
         // so we can apply the transaction to the State. Otherwise, we'd have
@@ -1,6 +1,6 @@
 /// Representation of the Penumbra application version. Notably, this is distinct
 /// from the crate version(s). This number should only ever be incremented.
-pub const APP_VERSION: u64 = 9;
+pub const APP_VERSION: u64 = 10;
 
 cfg_if::cfg_if! {
     if #[cfg(feature="component")] {
@@ -17,6 +17,7 @@ fn version_to_software_version(version: u64) -> &'static str {
         7 => "v0.79.x",
         8 => "v0.80.x",
         9 => "v0.81.x",
+        10 => "v0.82.x",
         _ => "unknown",
     }
 }
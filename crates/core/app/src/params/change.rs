@@ -88,6 +88,7 @@ impl AppParameters {
                     ibc_enabled: _,
                     inbound_ics20_transfers_enabled: _,
                     outbound_ics20_transfers_enabled: _,
+                    ics20_rate_limits: _,
                 },
             sct_params: SctParameters { epoch_duration },
             shielded_pool_params: ShieldedPoolParameters { fmd_meta_params: _ },
@@ -100,6 +101,8 @@ impl AppParameters {
                     missed_blocks_maximum: _,
                     min_validator_stake: _,
                     unbonding_delay: _,
+                    max_commission_increase_bps: _,
+                    commission_increase_notice_epochs: _,
                 },
             dex_params:
                 DexParameters {
@@ -183,6 +186,7 @@ impl AppParameters {
                     ibc_enabled,
                     inbound_ics20_transfers_enabled,
                     outbound_ics20_transfers_enabled,
+                    ics20_rate_limits,
                 },
             sct_params: SctParameters { epoch_duration },
             shielded_pool_params: ShieldedPoolParameters { fmd_meta_params: _ },
@@ -195,6 +199,8 @@ impl AppParameters {
                     missed_blocks_maximum,
                     min_validator_stake,
                     unbonding_delay,
+                    max_commission_increase_bps,
+                    commission_increase_notice_epochs: _,
                 },
             dex_params:
                 DexParameters {
@@ -250,6 +256,20 @@ impl AppParameters {
                     || *ibc_enabled,
                 "IBC must be enabled if either inbound or outbound ICS20 transfers are enabled",
             ),
+            (
+                ics20_rate_limits
+                    .iter()
+                    .all(|limit| limit.window_blocks >= 1),
+                "ICS20 rate limit windows must be at least 1 block",
+            ),
+            (
+                ics20_rate_limits.iter().enumerate().all(|(i, limit)| {
+                    !ics20_rate_limits[..i]
+                        .iter()
+                        .any(|other| other.applies_to(&limit.channel_id, &limit.asset_id))
+                }),
+                "ICS20 rate limits must not repeat a channel and asset",
+            ),
             (
                 *proposal_voting_blocks >= 1,
                 "proposal voting blocks must be at least 1",
@@ -274,6 +294,10 @@ impl AppParameters {
                 *min_validator_stake >= 1_000_000u128.into(),
                 "the minimum validator stake must be at least 1penumbra",
             ),
+            (
+                *max_commission_increase_bps <= 10_000,
+                "the maximum commission increase must be at most 10,000 basis points",
+            ),
         ])
     }
 }
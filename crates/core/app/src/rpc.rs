@@ -22,6 +22,7 @@ use {
         connection::v1::query_server::QueryServer as ConnectionQueryServer,
     },
     penumbra_auction::component::rpc::Server as AuctionServer,
+    penumbra_community_pool::component::rpc::Server as CommunityPoolServer,
     penumbra_compact_block::component::rpc::Server as CompactBlockServer,
     penumbra_dex::component::rpc::Server as DexServer,
     penumbra_fee::component::rpc::Server as FeeServer,
@@ -31,6 +32,7 @@ use {
             app::v1::query_service_server::QueryServiceServer as AppQueryServiceServer,
             component::{
                 auction::v1::query_service_server::QueryServiceServer as AuctionQueryServiceServer,
+                community_pool::v1::query_service_server::QueryServiceServer as CommunityPoolQueryServiceServer,
                 compact_block::v1::query_service_server::QueryServiceServer as CompactBlockQueryServiceServer,
                 dex::v1::{
                     query_service_server::QueryServiceServer as DexQueryServiceServer,
@@ -38,6 +40,7 @@ use {
                 },
                 fee::v1::query_service_server::QueryServiceServer as FeeQueryServiceServer,
                 governance::v1::query_service_server::QueryServiceServer as GovernanceQueryServiceServer,
+                ibc::v1::query_service_server::QueryServiceServer as IbcQueryServiceServer,
                 sct::v1::query_service_server::QueryServiceServer as SctQueryServiceServer,
                 shielded_pool::v1::query_service_server::QueryServiceServer as ShieldedPoolQueryServiceServer,
                 stake::v1::query_service_server::QueryServiceServer as StakeQueryServiceServer,
@@ -57,7 +60,7 @@ use {
 pub fn router(
     storage: &cnidarium::Storage,
     tm_proxy: impl TendermintProxyService,
-    _enable_expensive_rpc: bool,
+    enable_expensive_rpc: bool,
 ) -> anyhow::Result<tonic::transport::server::Router> {
     let ibc = penumbra_ibc::component::rpc::IbcQuery::<PenumbraHost>::new(storage.clone());
     let grpc_server = tonic::transport::server::Server::builder()
@@ -88,7 +91,11 @@ pub fn router(
         ))))
         .add_service(we(AppQueryServiceServer::new(AppQueryServer::new(
             storage.clone(),
+            enable_expensive_rpc,
         ))))
+        .add_service(we(CommunityPoolQueryServiceServer::new(
+            CommunityPoolServer::new(storage.clone()),
+        )))
         .add_service(we(CompactBlockQueryServiceServer::new(
             CompactBlockServer::new(storage.clone()),
         )))
@@ -119,6 +126,7 @@ pub fn router(
         .add_service(we(ClientQueryServer::new(ibc.clone())))
         .add_service(we(ChannelQueryServer::new(ibc.clone())))
         .add_service(we(ConnectionQueryServer::new(ibc.clone())))
+        .add_service(we(IbcQueryServiceServer::new(ibc.clone())))
         .add_service(we(TendermintProxyServiceServer::new(tm_proxy)))
         .add_service(we(SimulationServiceServer::new(DexServer::new(
             storage.clone(),
@@ -1,21 +1,45 @@
 use {
-    crate::app::StateReadExt as _,
-    cnidarium::Storage,
-    penumbra_proto::core::app::v1::{
-        query_service_server::QueryService, AppParametersRequest, AppParametersResponse,
-        TransactionsByHeightRequest, TransactionsByHeightResponse,
+    crate::{
+        action_handler::{
+            build_community_pool_transaction, enact_batch_proposal, AppActionHandler,
+        },
+        app::StateReadExt as _,
+        params::change::ParameterChangeExt as _,
     },
+    anyhow::Context as _,
+    cnidarium::{Snapshot, StateDelta, Storage},
+    penumbra_asset::Value,
+    penumbra_community_pool::component::StateReadExt as _,
+    penumbra_governance::{
+        component::{StateReadExt as _, StateWriteExt as _},
+        Proposal, ProposalPayload, ProposalSubmit,
+    },
+    penumbra_proto::{
+        core::app::v1::{
+            query_service_server::QueryService, AppParametersRequest, AppParametersResponse,
+            SimulateProposalRequest, SimulateProposalResponse, TransactionsByHeightRequest,
+            TransactionsByHeightResponse,
+        },
+        DomainType,
+    },
+    penumbra_transaction::TransactionPlan,
+    std::sync::Arc,
     tonic::Status,
     tracing::instrument,
 };
 
 pub(super) struct AppQueryServer {
     storage: Storage,
+    /// Whether to serve [`QueryService::simulate_proposal`], which executes arbitrary proposals.
+    enable_expensive_rpc: bool,
 }
 
 impl AppQueryServer {
-    pub fn new(storage: Storage) -> Self {
-        Self { storage }
+    pub fn new(storage: Storage, enable_expensive_rpc: bool) -> Self {
+        Self {
+            storage,
+            enable_expensive_rpc,
+        }
     }
 }
 
@@ -55,4 +79,133 @@ impl QueryService for AppQueryServer {
             app_parameters: Some(app_parameters.into()),
         }))
     }
+
+    #[instrument(skip(self, request))]
+    async fn simulate_proposal(
+        &self,
+        request: tonic::Request<SimulateProposalRequest>,
+    ) -> Result<tonic::Response<SimulateProposalResponse>, Status> {
+        if !self.enable_expensive_rpc {
+            return Err(Status::unimplemented(
+                "QueryService::simulate_proposal() is not enabled on this node. \
+                 Run pd with `--enable-expensive-rpc` to use this RPC.",
+            ));
+        }
+
+        let proposal: Proposal = request
+            .into_inner()
+            .proposal
+            .ok_or_else(|| Status::invalid_argument("missing proposal"))?
+            .try_into()
+            .map_err(|e| Status::invalid_argument(format!("invalid proposal: {e:#}")))?;
+
+        let response = simulate_proposal(self.storage.latest_snapshot(), proposal)
+            .await
+            .map_err(|e| Status::internal(format!("error simulating proposal: {e:#}")))?;
+
+        Ok(tonic::Response::new(response))
+    }
+}
+
+/// Submits and enacts the `proposal` in a fork of the `snapshot`, which is discarded afterwards.
+///
+/// Errors in the proposal are reported in the response, while errors reading the state are
+/// returned.
+async fn simulate_proposal(
+    snapshot: Snapshot,
+    proposal: Proposal,
+) -> anyhow::Result<SimulateProposalResponse> {
+    let mut state = StateDelta::new(snapshot);
+    let mut response = SimulateProposalResponse::default();
+
+    // Submit the proposal, with the deposit the chain currently requires.
+    let submit = ProposalSubmit {
+        deposit_amount: state.get_governance_params().await?.proposal_deposit_amount,
+        proposal: proposal.clone(),
+    };
+    let submitted = async {
+        submit.check_stateless(()).await?;
+        submit.check_and_execute(&mut state).await
+    }
+    .await;
+    if let Err(error) = submitted {
+        response.submission_error = format!("{error:#}");
+        return Ok(response);
+    }
+
+    // Enact it as if it had passed. A batch takes effect in full when it's enacted.
+    if let ProposalPayload::Batch {
+        payloads,
+        conditions,
+    } = &proposal.payload
+    {
+        let (batch_state, enacted) =
+            enact_batch_proposal(StateDelta::new(state), proposal.id, payloads, conditions).await?;
+        state = match enacted {
+            Ok(()) => batch_state.apply().0,
+            Err(error) => {
+                response.enactment_error = format!("{error:#}");
+                batch_state.flatten().0
+            }
+        };
+        if response.enactment_error.is_empty()
+            && payloads.iter().any(ProposalPayload::is_parameter_change)
+        {
+            response.new_app_parameters = Some(state.get_app_params().await?.into());
+        }
+    } else {
+        // Other proposals may only schedule their effects when enacted, so apply those effects
+        // here, unless enactment failed.
+        let enacted = state.enact_proposal(proposal.id, &proposal.payload).await?;
+        match (&proposal.payload, enacted) {
+            (_, Err(error)) => response.enactment_error = format!("{error:#}"),
+            (ProposalPayload::ParameterChange(change), Ok(())) => {
+                match change.apply_changes(state.get_app_params().await?) {
+                    Ok(new_app_parameters) => {
+                        response.new_app_parameters = Some(new_app_parameters.into())
+                    }
+                    Err(error) => response.enactment_error = format!("{error:#}"),
+                }
+            }
+            (ProposalPayload::CommunityPoolSpend { transaction_plan }, Ok(())) => {
+                // Submitting the proposal already checked that the transaction builds and passes
+                // stateless checks, so it's the stateful checks and execution that could fail.
+                let transaction_plan = TransactionPlan::decode(&transaction_plan[..])?;
+                let transaction = build_community_pool_transaction(transaction_plan).await?;
+
+                let shared_state = Arc::new(state);
+                let checked = transaction.check_historical(shared_state.clone()).await;
+                state = Arc::try_unwrap(shared_state)
+                    .map_err(|_| anyhow::anyhow!("state Arc should be unique after checks"))?;
+
+                // As when delivering the transaction, its changes are discarded if it fails.
+                let mut tx_state = StateDelta::new(&mut state);
+                let executed = match checked {
+                    Ok(()) => transaction
+                        .check_and_execute(&mut tx_state)
+                        .await
+                        .context("transaction failed to execute"),
+                    Err(error) => Err(error.context("transaction failed stateful checks")),
+                };
+                match executed {
+                    Ok(()) => {
+                        tx_state.apply();
+                    }
+                    Err(error) => {
+                        response.community_pool_transaction_error = format!("{error:#}");
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    response.community_pool_balance = state
+        .community_pool_balance()
+        .await?
+        .into_iter()
+        .map(|(asset_id, amount)| Value { asset_id, amount }.into())
+        .collect();
+
+    Ok(response)
 }
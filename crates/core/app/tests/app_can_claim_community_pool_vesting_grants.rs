@@ -0,0 +1,358 @@
+use {
+    self::common::TestNodeExt as _,
+    anyhow::anyhow,
+    cnidarium::TempStorage,
+    common::TempStorageExt as _,
+    decaf377_rdsa::VerificationKey,
+    penumbra_app::{
+        genesis::{AppState, Content},
+        server::consensus::Consensus,
+    },
+    penumbra_community_pool::{CommunityPoolDeposit, CommunityPoolVestingClaim, StateReadExt as _},
+    penumbra_governance::{
+        Proposal, ProposalPayload, ProposalSubmit, StateReadExt as _, ValidatorVote,
+        ValidatorVoteBody, ValidatorVoteReason,
+    },
+    penumbra_keys::{
+        keys::{SpendKey, SpendKeyBytes},
+        test_keys,
+    },
+    penumbra_mock_client::MockClient,
+    penumbra_mock_consensus::TestNode,
+    penumbra_num::Amount,
+    penumbra_proto::{
+        core::keys::v1::{GovernanceKey, IdentityKey},
+        penumbra::core::component::stake::v1::Validator as PenumbraValidator,
+        DomainType,
+    },
+    penumbra_sct::component::clock::EpochRead as _,
+    penumbra_shielded_pool::{genesis::Allocation, OutputPlan, SpendPlan},
+    penumbra_stake::DelegationToken,
+    penumbra_transaction::{ActionPlan, TransactionParameters, TransactionPlan},
+    rand::Rng,
+    rand_core::OsRng,
+    std::ops::Deref,
+    tap::{Tap, TapFallible},
+    tracing::{error_span, info, Instrument},
+};
+
+mod common;
+
+const PROPOSAL_VOTING_BLOCKS: u64 = 3;
+
+/// The length of the [`penumbra_sct`] epoch.
+///
+/// Grants vest per epoch, so we will work with a shorter epoch duration.
+const EPOCH_DURATION: u64 = 8;
+
+/// Exercises that passed proposals can create and cancel vesting grants funded by the community
+/// pool, and that vested funds can be claimed.
+#[tokio::test]
+async fn app_can_claim_community_pool_vesting_grants() -> anyhow::Result<()> {
+    // Install a test logger, and acquire some temporary storage.
+    let guard = common::set_tracing_subscriber();
+    let storage = TempStorage::new_with_penumbra_prefixes().await?;
+
+    // Define helpers to get the current community pool balance, and the state of a grant.
+    let pool_balance = || async { storage.latest_snapshot().community_pool_balance().await };
+    let grant = |id| {
+        let snapshot = storage.latest_snapshot();
+        async move {
+            snapshot
+                .vesting_grant(id)
+                .await?
+                .ok_or_else(|| anyhow!("vesting grant {id} should exist"))
+        }
+    };
+
+    // Generate a set of consensus keys.
+    let consensus_sk = ed25519_consensus::SigningKey::new(OsRng);
+    let consensus_vk = consensus_sk.verification_key();
+
+    // Generate a set of identity keys, which are also used for governance.
+    let spend_key: SpendKey = SpendKeyBytes(OsRng.gen()).into();
+    let (identity_sk, identity_vk) = {
+        let sk = spend_key.spend_auth_key();
+        let vk = VerificationKey::from(sk);
+        (sk, vk)
+    };
+    let (governance_sk, governance_vk) = (identity_sk, identity_vk);
+
+    // Define a validator and an associated genesis allocation.
+    let (validator, allocation) = {
+        let v = PenumbraValidator {
+            identity_key: Some(IdentityKey {
+                ik: identity_vk.to_bytes().to_vec(),
+            }),
+            governance_key: Some(GovernanceKey {
+                gk: identity_vk.to_bytes().to_vec(),
+            }),
+            consensus_key: consensus_vk.as_bytes().to_vec(),
+            enabled: true,
+            sequence_number: 0,
+            name: String::default(),
+            website: String::default(),
+            description: String::default(),
+            funding_streams: Vec::default(),
+        };
+
+        let (address, _) = spend_key
+            .full_viewing_key()
+            .incoming()
+            .payment_address(0u32.into());
+
+        let ik = penumbra_stake::IdentityKey(identity_vk.into());
+        let delegation_denom = DelegationToken::from(ik).denom();
+
+        let allocation = Allocation {
+            raw_amount: 1000u128.into(),
+            raw_denom: delegation_denom.to_string(),
+            address,
+        };
+
+        (v, allocation)
+    };
+
+    // Define our application state, and start the test node.
+    let mut test_node = {
+        let mut content = Content {
+            governance_content: penumbra_governance::genesis::Content {
+                governance_params: penumbra_governance::params::GovernanceParameters {
+                    proposal_deposit_amount: 0_u32.into(),
+                    proposal_voting_blocks: PROPOSAL_VOTING_BLOCKS,
+                    ..Default::default()
+                },
+            },
+            ..Default::default()
+        }
+        .with_chain_id(TestNode::<()>::CHAIN_ID.to_string())
+        .with_epoch_duration(EPOCH_DURATION);
+        content.stake_content.validators.push(validator);
+        content.shielded_pool_content.allocations.push(allocation);
+        let app_state = AppState::Content(content);
+        let app_state = serde_json::to_vec(&app_state).unwrap();
+        let consensus = Consensus::new(storage.as_ref().clone());
+        TestNode::builder()
+            .single_validator()
+            .app_state(app_state)
+            .init_chain(consensus)
+            .await
+            .tap_ok(|e| tracing::info!(hash = %e.last_app_hash_hex(), "finished init chain"))?
+    };
+
+    // Sync the mock client, using the test wallet's spend key, to the latest snapshot.
+    let client = MockClient::new(test_keys::SPEND_KEY.clone())
+        .with_sync_to_storage(&storage)
+        .await?
+        .tap(|c| info!(client.notes = %c.notes.len(), "mock client synced to test storage"));
+
+    // Build a transaction containing the given actions.
+    let build = |actions: Vec<ActionPlan>| {
+        let mut plan = TransactionPlan {
+            actions,
+            memo: None,
+            detection_data: None,
+            transaction_parameters: TransactionParameters {
+                chain_id: TestNode::<()>::CHAIN_ID.to_string(),
+                ..Default::default()
+            },
+        };
+        plan.populate_detection_data(OsRng, Default::default());
+        let client = &client;
+        async move { client.witness_auth_build(&plan).await }
+    };
+
+    // Take one of the test wallet's notes, and deposit it in the community pool.
+    let note = client
+        .notes
+        .values()
+        .cloned()
+        .next()
+        .ok_or_else(|| anyhow!("mock client had no note"))?;
+    let value = note.value();
+    let tx = build(vec![
+        SpendPlan::new(
+            &mut OsRng,
+            note.clone(),
+            client
+                .position(note.commit())
+                .ok_or_else(|| anyhow!("input note commitment was unknown to mock client"))?,
+        )
+        .into(),
+        CommunityPoolDeposit { value }.into(),
+    ])
+    .await?;
+    test_node
+        .block()
+        .with_data(vec![tx.encode_to_vec()])
+        .execute()
+        .instrument(error_span!("executing block with community pool deposit"))
+        .await?;
+
+    // Build the transactions submitting a proposal, and voting for it.
+    let proposal_txs = |id: u64, payload: ProposalPayload| {
+        let proposal_submit = ProposalSubmit {
+            proposal: Proposal {
+                id,
+                title: "vesting grant".to_owned(),
+                description: "a proposal about a community pool vesting grant".to_owned(),
+                payload,
+            },
+            deposit_amount: 0_u32.into(),
+        };
+        let proposal_nft_value = proposal_submit.proposal_nft_value();
+        let body = ValidatorVoteBody {
+            proposal: id,
+            vote: penumbra_governance::Vote::Yes,
+            identity_key: penumbra_stake::IdentityKey(identity_vk.to_bytes().into()),
+            governance_key: penumbra_stake::GovernanceKey(governance_vk),
+            reason: ValidatorVoteReason("test reason".to_owned()),
+        };
+        let auth_sig = governance_sk.sign(OsRng, body.encode_to_vec().as_slice());
+        let build = &build;
+        async move {
+            let submit = build(vec![
+                ActionPlan::ProposalSubmit(proposal_submit),
+                OutputPlan::new(
+                    &mut OsRng,
+                    proposal_nft_value,
+                    test_keys::ADDRESS_0.deref().clone(),
+                )
+                .into(),
+            ])
+            .await?;
+            let vote = build(vec![ValidatorVote { body, auth_sig }.into()]).await?;
+            anyhow::Ok([submit, vote])
+        }
+    };
+
+    // Propose a grant of the deposited funds, a quarter of which vest in each epoch after a one
+    // epoch cliff.
+    let release_per_epoch = Amount::from(value.amount.value() / 4);
+    for tx in proposal_txs(
+        0,
+        ProposalPayload::CommunityPoolVestingGrant {
+            recipient: test_keys::ADDRESS_1.deref().clone(),
+            total: value,
+            cliff_epochs: 1,
+            release_per_epoch,
+        },
+    )
+    .await?
+    {
+        test_node
+            .block()
+            .with_data(vec![tx.encode_to_vec()])
+            .execute()
+            .instrument(error_span!("executing block with grant proposal"))
+            .await?;
+    }
+    test_node.fast_forward(PROPOSAL_VOTING_BLOCKS).await?;
+    assert_eq!(
+        storage.latest_snapshot().proposal_state(0).await?,
+        Some(penumbra_governance::proposal_state::State::Finished {
+            outcome: penumbra_governance::proposal_state::Outcome::Passed,
+        }),
+        "the grant proposal should pass"
+    );
+    assert_eq!(
+        pool_balance().await?.get(&value.asset_id).copied(),
+        Some(Amount::zero()),
+        "the grant should be set aside from the community pool"
+    );
+    let created = grant(0).await?;
+    assert_eq!(created.total, value);
+    assert_eq!(created.cliff_epoch_index, created.start_epoch_index + 1);
+    assert_eq!(created.claimed, Amount::zero());
+
+    // Nothing vests before the cliff, so a claim is rejected.
+    let claim = |amount| CommunityPoolVestingClaim {
+        grant_id: 0,
+        amount,
+    };
+    let tx = build(vec![claim(release_per_epoch).into()]).await?;
+    test_node
+        .block()
+        .with_data(vec![tx.encode_to_vec()])
+        .execute()
+        .instrument(error_span!("executing block with early vesting claim"))
+        .await?;
+    assert_eq!(
+        grant(0).await?.claimed,
+        Amount::zero(),
+        "funds cannot be claimed before the cliff"
+    );
+
+    // Once the cliff has passed, the first release can be claimed, but no more.
+    test_node.fast_forward_to_next_epoch(&storage).await?;
+    assert_eq!(
+        storage.latest_snapshot().get_current_epoch().await?.index,
+        created.cliff_epoch_index
+    );
+    let tx = build(vec![claim(release_per_epoch + Amount::from(1u64)).into()]).await?;
+    test_node
+        .block()
+        .with_data(vec![tx.encode_to_vec()])
+        .execute()
+        .instrument(error_span!("executing block with excessive vesting claim"))
+        .await?;
+    assert_eq!(grant(0).await?.claimed, Amount::zero());
+    let tx = build(vec![claim(release_per_epoch).into()]).await?;
+    test_node
+        .block()
+        .with_data(vec![tx.encode_to_vec()])
+        .execute()
+        .instrument(error_span!("executing block with vesting claim"))
+        .await?;
+    assert_eq!(
+        grant(0).await?.claimed,
+        release_per_epoch,
+        "vested funds can be claimed after the cliff"
+    );
+
+    // Cancel the grant before the rest vests, which returns it to the community pool.
+    for tx in proposal_txs(
+        1,
+        ProposalPayload::CancelCommunityPoolVestingGrant { grant_id: 0 },
+    )
+    .await?
+    {
+        test_node
+            .block()
+            .with_data(vec![tx.encode_to_vec()])
+            .execute()
+            .instrument(error_span!("executing block with cancellation proposal"))
+            .await?;
+    }
+    test_node.fast_forward(PROPOSAL_VOTING_BLOCKS).await?;
+    let cancelled = grant(0).await?;
+    assert!(cancelled.cancelled, "the grant should be cancelled");
+    assert!(
+        cancelled.total.amount >= release_per_epoch && cancelled.total.amount < value.amount,
+        "a cancelled grant keeps only the funds which had vested"
+    );
+    assert_eq!(
+        pool_balance().await?.get(&value.asset_id).copied(),
+        Some(value.amount - cancelled.total.amount),
+        "the unvested funds should be returned to the community pool"
+    );
+
+    // The funds which had vested remain claimable after the cancellation.
+    let remaining = cancelled.claimable(0);
+    if remaining > Amount::zero() {
+        let tx = build(vec![claim(remaining).into()]).await?;
+        test_node
+            .block()
+            .with_data(vec![tx.encode_to_vec()])
+            .execute()
+            .instrument(error_span!("executing block with claim after cancellation"))
+            .await?;
+    }
+    assert!(grant(0).await?.is_exhausted());
+
+    // Free our temporary storage.
+    Ok(())
+        .tap(|_| drop(test_node))
+        .tap(|_| drop(storage))
+        .tap(|_| drop(guard))
+}
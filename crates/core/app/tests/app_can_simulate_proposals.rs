@@ -0,0 +1,191 @@
+use {
+    anyhow::Context as _,
+    cnidarium::TempStorage,
+    common::TempStorageExt as _,
+    penumbra_app::{
+        genesis::{AppState, Content},
+        params::AppParameters,
+        server::consensus::Consensus,
+    },
+    penumbra_asset::{Value, STAKING_TOKEN_ASSET_ID},
+    penumbra_community_pool::{CommunityPoolOutput, CommunityPoolSpend},
+    penumbra_governance::{
+        change::{EncodedParameter, ParameterChange},
+        Proposal, ProposalPayload, StateReadExt as _,
+    },
+    penumbra_keys::test_keys,
+    penumbra_mock_consensus::TestNode,
+    penumbra_proto::{
+        core::app::v1::{
+            query_service_client::QueryServiceClient as AppQueryServiceClient,
+            SimulateProposalRequest,
+        },
+        DomainType as _,
+    },
+    penumbra_transaction::{TransactionParameters, TransactionPlan},
+    std::ops::Deref,
+    tap::{Tap, TapFallible},
+    tokio::time,
+    tonic::transport::Channel,
+};
+
+mod common;
+
+/// Exercises that proposals can be simulated against the latest state, reporting why they would
+/// fail without committing any of their changes.
+#[tokio::test]
+async fn app_can_simulate_proposals() -> anyhow::Result<()> {
+    // Install a test logger, and acquire some temporary storage.
+    let guard = common::set_tracing_subscriber();
+    let storage = TempStorage::new_with_penumbra_prefixes().await?;
+
+    // Instantiate a mock tendermint proxy, which the rpc server requires.
+    let proxy = penumbra_mock_tendermint_proxy::TestNodeProxy::new::<Consensus>();
+
+    // Start the test node, with an empty community pool.
+    let mut test_node = {
+        let content = Content::default().with_chain_id(TestNode::<()>::CHAIN_ID.to_string());
+        let app_state = serde_json::to_vec(&AppState::Content(content))?;
+        let consensus = Consensus::new(storage.as_ref().clone());
+        TestNode::builder()
+            .single_validator()
+            .app_state(app_state)
+            .init_chain(consensus)
+            .await
+            .tap_ok(|e| tracing::info!(hash = %e.last_app_hash_hex(), "finished init chain"))?
+    };
+    test_node.block().execute().await?;
+
+    // Spawn the node's RPC server, with expensive RPCs enabled.
+    let grpc_url = "http://127.0.0.1:8082" // see #4517
+        .parse::<url::Url>()?
+        .tap(|url| tracing::debug!(%url, "parsed grpc url"));
+    let _rpc_server = {
+        let make_svc =
+            penumbra_app::rpc::router(&storage, proxy, true /*enable_expensive_rpc*/)?
+                .into_router()
+                .into_make_service();
+        let [addr] = grpc_url
+            .socket_addrs(|| None)?
+            .try_into()
+            .expect("grpc url can be turned into a socket address");
+        let server = axum_server::bind(addr).serve(make_svc);
+        tokio::spawn(async { server.await.expect("grpc server returned an error") })
+    };
+    time::sleep(time::Duration::from_secs(1)).await;
+    let channel = Channel::from_shared(grpc_url.to_string())
+        .context("could not parse node URI")?
+        .connect()
+        .await
+        .context("could not connect to grpc server")?;
+    let client = AppQueryServiceClient::new(channel);
+
+    let voting_blocks = storage
+        .latest_snapshot()
+        .get_governance_params()
+        .await?
+        .proposal_voting_blocks;
+    let proposal = |id: u64, payload: ProposalPayload| Proposal {
+        id,
+        title: "simulated".to_owned(),
+        description: "a proposal in a test of proposal simulation".to_owned(),
+        payload,
+    };
+    let simulate = |proposal: Proposal| {
+        let request = SimulateProposalRequest {
+            proposal: Some(proposal.into()),
+        };
+        let mut client = client.clone();
+        async move {
+            let response = client.simulate_proposal(request).await?.into_inner();
+            tracing::info!(?response, "simulated proposal");
+            anyhow::Ok(response)
+        }
+    };
+
+    // A parameter change is reported with the parameters it would put in effect.
+    let response = simulate(proposal(
+        0,
+        ProposalPayload::ParameterChange(ParameterChange {
+            changes: vec![EncodedParameter {
+                component: "governanceParams".to_owned(),
+                key: "proposalVotingBlocks".to_owned(),
+                value: format!(r#""{}""#, voting_blocks + 1),
+            }],
+            preconditions: vec![],
+        }),
+    ))
+    .await?;
+    assert_eq!(response.submission_error, "");
+    assert_eq!(response.enactment_error, "");
+    let new_app_parameters = AppParameters::try_from(
+        response
+            .new_app_parameters
+            .context("parameter changes report the new parameters")?,
+    )?;
+    assert_eq!(
+        new_app_parameters.governance_params.proposal_voting_blocks,
+        voting_blocks + 1
+    );
+
+    // A proposal that would be rejected on submission is reported as such.
+    let response = simulate(proposal(1, ProposalPayload::Signaling { commit: None })).await?;
+    assert!(
+        response
+            .submission_error
+            .contains("does not match expected proposal ID"),
+        "proposals must have the next proposal id"
+    );
+
+    // A community pool spend that the pool can't fund is accepted, but its transaction fails.
+    let value = Value {
+        amount: 1_000u128.into(),
+        asset_id: *STAKING_TOKEN_ASSET_ID,
+    };
+    let transaction_plan = TransactionPlan {
+        actions: vec![
+            CommunityPoolSpend { value }.into(),
+            CommunityPoolOutput {
+                value,
+                address: test_keys::ADDRESS_0.deref().clone(),
+            }
+            .into(),
+        ],
+        memo: None,
+        detection_data: None,
+        transaction_parameters: TransactionParameters {
+            chain_id: TestNode::<()>::CHAIN_ID.to_string(),
+            ..Default::default()
+        },
+    };
+    let response = simulate(proposal(
+        0,
+        ProposalPayload::CommunityPoolSpend {
+            transaction_plan: transaction_plan.encode_to_vec(),
+        },
+    ))
+    .await?;
+    assert_eq!(response.submission_error, "");
+    assert_ne!(
+        response.community_pool_transaction_error, "",
+        "the community pool is empty, so the spend must fail"
+    );
+    assert!(response.new_app_parameters.is_none());
+
+    // None of the simulations changed the chain's state.
+    let snapshot = storage.latest_snapshot();
+    assert_eq!(snapshot.next_proposal_id().await?, 0);
+    assert_eq!(
+        snapshot
+            .get_governance_params()
+            .await?
+            .proposal_voting_blocks,
+        voting_blocks
+    );
+
+    // Free our temporary storage.
+    Ok(())
+        .tap(|_| drop(test_node))
+        .tap(|_| drop(storage))
+        .tap(|_| drop(guard))
+}
@@ -0,0 +1,204 @@
+use {
+    self::common::{BuilderExt, TestNodeExt},
+    cnidarium::TempStorage,
+    common::TempStorageExt as _,
+    decaf377_rdsa::{SigningKey, SpendAuth, VerificationKey},
+    penumbra_app::{
+        genesis::{self, AppState},
+        server::consensus::Consensus,
+    },
+    penumbra_keys::test_keys,
+    penumbra_mock_client::MockClient,
+    penumbra_mock_consensus::TestNode,
+    penumbra_proto::DomainType,
+    penumbra_sct::component::clock::EpochRead as _,
+    penumbra_stake::{
+        component::validator_handler::ValidatorDataRead as _, validator::Validator, FundingStream,
+        FundingStreams, GovernanceKey, IdentityKey,
+    },
+    rand_core::OsRng,
+    tap::Tap,
+    tracing::{error_span, info, Instrument},
+};
+
+mod common;
+
+/// The length of the [`penumbra_sct`] epoch.
+///
+/// This test relies on a few epochs turning over, so we will work with a shorter epoch duration.
+const EPOCH_DURATION: u64 = 8;
+
+/// Show that increases of a validator's commission are limited, and only take effect once the
+/// notice period has elapsed.
+#[tokio::test]
+async fn app_defers_validator_commission_increases() -> anyhow::Result<()> {
+    // Install a test logger, acquire some temporary storage, and start the test node.
+    let guard = common::set_tracing_subscriber();
+    let storage = TempStorage::new_with_penumbra_prefixes().await?;
+
+    // Configure an AppState with slightly shorter epochs than usual.
+    let app_state = AppState::Content(
+        genesis::Content::default()
+            .with_epoch_duration(EPOCH_DURATION)
+            .with_chain_id(TestNode::<()>::CHAIN_ID.to_string()),
+    );
+
+    // Start the test node.
+    let mut node = {
+        let consensus = Consensus::new(storage.as_ref().clone());
+        TestNode::builder()
+            .single_validator()
+            .with_penumbra_auto_app_state(app_state)?
+            .init_chain(consensus)
+            .await
+    }?;
+
+    // Sync the mock client, using the test wallet's spend key, to the latest snapshot.
+    let client = MockClient::new(test_keys::SPEND_KEY.clone())
+        .with_sync_to_storage(&storage)
+        .await?
+        .tap(|c| info!(client.notes = %c.notes.len(), "mock client synced to test storage"));
+
+    // Define the keys of a new validator, which starts without any commission.
+    let new_validator_id_sk = SigningKey::<SpendAuth>::new(OsRng);
+    let new_validator_id = IdentityKey(VerificationKey::from(&new_validator_id_sk).into());
+    let new_validator_consensus_sk = ed25519_consensus::SigningKey::new(OsRng);
+    let new_validator_consensus = new_validator_consensus_sk.verification_key();
+    let new_validator = Validator {
+        identity_key: new_validator_id.clone(),
+        consensus_key: tendermint::PublicKey::from_raw_ed25519(&new_validator_consensus.to_bytes())
+            .expect("consensus key is valid"),
+        governance_key: GovernanceKey(new_validator_id_sk.into()),
+        enabled: true,
+        sequence_number: 0,
+        name: "test validator".to_string(),
+        website: String::default(),
+        description: String::default(),
+        funding_streams: FundingStreams::default(),
+    };
+    let with_commission = |sequence_number, rate_bps| Validator {
+        sequence_number,
+        funding_streams: vec![FundingStream::ToCommunityPool { rate_bps }]
+            .try_into()
+            .expect("funding streams are valid"),
+        ..new_validator.clone()
+    };
+
+    // Plan a transaction that uploads the given validator definition.
+    let definition_plan = |validator: Validator| {
+        use {
+            penumbra_stake::validator,
+            penumbra_transaction::{ActionPlan, TransactionParameters, TransactionPlan},
+        };
+        let bytes = validator.encode_to_vec();
+        let auth_sig = new_validator_id_sk.sign(OsRng, &bytes);
+        let action = ActionPlan::ValidatorDefinition(validator::Definition {
+            validator,
+            auth_sig,
+        });
+        TransactionPlan {
+            actions: vec![action.into()],
+            memo: None,
+            detection_data: None,
+            transaction_parameters: TransactionParameters {
+                chain_id: TestNode::<()>::CHAIN_ID.to_string(),
+                ..Default::default()
+            },
+        }
+        .with_populated_detection_data(OsRng, Default::default())
+    };
+    let commission = || async {
+        let snapshot = storage.latest_snapshot();
+        let definition = snapshot
+            .get_validator_definition(&new_validator_id)
+            .await?
+            .expect("validator is defined");
+        let pending = snapshot
+            .get_pending_funding_streams(&new_validator_id)
+            .await?;
+        anyhow::Ok((
+            definition.funding_streams.total_rate_bps(),
+            pending.map(|p| (p.funding_streams.total_rate_bps(), p.effective_epoch_index)),
+        ))
+    };
+
+    // Define the validator, in the first epoch.
+    let tx = client
+        .witness_auth_build(&definition_plan(new_validator.clone()))
+        .await?;
+    node.block()
+        .add_tx(tx.encode_to_vec())
+        .execute()
+        .instrument(error_span!("executing block with validator definition"))
+        .await?;
+    assert_eq!(commission().await?, (0, None));
+
+    // Increase its commission by 3%, which is scheduled to take effect after one full epoch.
+    let tx = client
+        .witness_auth_build(&definition_plan(with_commission(1, 300)))
+        .await?;
+    node.block()
+        .add_tx(tx.encode_to_vec())
+        .execute()
+        .instrument(error_span!("executing block with commission increase"))
+        .await?;
+    assert_eq!(
+        commission().await?,
+        (0, Some((300, 2))),
+        "commission increases should not take effect immediately"
+    );
+
+    // An increase of 10% exceeds the default limit, and is rejected.
+    let tx = client
+        .witness_auth_build(&definition_plan(with_commission(2, 1000)))
+        .await?;
+    node.block()
+        .add_tx(tx.encode_to_vec())
+        .execute()
+        .instrument(error_span!(
+            "executing block with excessive commission increase"
+        ))
+        .await?;
+    assert_eq!(
+        commission().await?,
+        (0, Some((300, 2))),
+        "commission increases above the limit should be rejected"
+    );
+
+    // The increase has not taken effect in the next epoch...
+    node.fast_forward_to_next_epoch(&storage).await?;
+    assert_eq!(
+        storage.latest_snapshot().get_current_epoch().await?.index,
+        1
+    );
+    assert_eq!(commission().await?, (0, Some((300, 2))));
+
+    // ...but it has in the one after that.
+    node.fast_forward_to_next_epoch(&storage).await?;
+    assert_eq!(
+        storage.latest_snapshot().get_current_epoch().await?.index,
+        2
+    );
+    assert_eq!(
+        commission().await?,
+        (300, None),
+        "commission increases should take effect once the notice period has elapsed"
+    );
+
+    // Decreases take effect immediately.
+    let tx = client
+        .witness_auth_build(&definition_plan(with_commission(3, 100)))
+        .await?;
+    node.block()
+        .add_tx(tx.encode_to_vec())
+        .execute()
+        .instrument(error_span!("executing block with commission decrease"))
+        .await?;
+    assert_eq!(commission().await?, (100, None));
+
+    // The test passed. Free our temporary storage and drop our tracing subscriber.
+    Ok(())
+        .tap(|_| drop(node))
+        .tap(|_| drop(storage))
+        .tap(|_| drop(guard))
+}
@@ -0,0 +1,450 @@
+use {
+    self::common::TestNodeExt as _,
+    anyhow::anyhow,
+    cnidarium::TempStorage,
+    common::TempStorageExt as _,
+    decaf377_rdsa::VerificationKey,
+    penumbra_app::{
+        genesis::{AppState, Content},
+        server::consensus::Consensus,
+    },
+    penumbra_asset::Value,
+    penumbra_community_pool::{
+        CommunityPoolDeposit, CommunityPoolOutput, CommunityPoolSpend, StateReadExt as _,
+    },
+    penumbra_governance::{
+        change::{EncodedParameter, ParameterChange},
+        BatchCondition, Proposal, ProposalPayload, ProposalSubmit, StateReadExt as _,
+        ValidatorVote, ValidatorVoteBody, ValidatorVoteReason,
+    },
+    penumbra_keys::{
+        keys::{SpendKey, SpendKeyBytes},
+        test_keys,
+    },
+    penumbra_mock_client::MockClient,
+    penumbra_mock_consensus::TestNode,
+    penumbra_num::Amount,
+    penumbra_proto::{
+        core::keys::v1::{GovernanceKey, IdentityKey},
+        penumbra::core::component::stake::v1::Validator as PenumbraValidator,
+        DomainType,
+    },
+    penumbra_shielded_pool::{genesis::Allocation, OutputPlan, SpendPlan},
+    penumbra_stake::DelegationToken,
+    penumbra_transaction::{ActionPlan, TransactionParameters, TransactionPlan},
+    rand::Rng,
+    rand_core::OsRng,
+    std::ops::Deref,
+    tap::{Tap, TapFallible},
+    tracing::{error_span, info, Instrument},
+};
+
+mod common;
+
+const PROPOSAL_VOTING_BLOCKS: u64 = 3;
+
+/// Exercises that a batch proposal enacts all of its payloads when they succeed and its
+/// conditions hold, and none of them otherwise, including when its Community Pool spend fails.
+#[tokio::test]
+async fn app_enacts_batch_proposals_atomically() -> anyhow::Result<()> {
+    // Install a test logger, and acquire some temporary storage.
+    let guard = common::set_tracing_subscriber();
+    let storage = TempStorage::new_with_penumbra_prefixes().await?;
+
+    // Define helpers to get the current proposal voting period, the state of a grant, and the
+    // community pool's balance of an asset.
+    let voting_blocks = || async {
+        anyhow::Ok(
+            storage
+                .latest_snapshot()
+                .get_governance_params()
+                .await?
+                .proposal_voting_blocks,
+        )
+    };
+    let grant = |id| {
+        let snapshot = storage.latest_snapshot();
+        async move { snapshot.vesting_grant(id).await }
+    };
+    let pool_balance = |value: Value| {
+        let snapshot = storage.latest_snapshot();
+        async move { snapshot.community_pool_asset_balance(value.asset_id).await }
+    };
+
+    // Generate a set of consensus keys.
+    let consensus_sk = ed25519_consensus::SigningKey::new(OsRng);
+    let consensus_vk = consensus_sk.verification_key();
+
+    // Generate a set of identity keys, which are also used for governance.
+    let spend_key: SpendKey = SpendKeyBytes(OsRng.gen()).into();
+    let (identity_sk, identity_vk) = {
+        let sk = spend_key.spend_auth_key();
+        let vk = VerificationKey::from(sk);
+        (sk, vk)
+    };
+    let (governance_sk, governance_vk) = (identity_sk, identity_vk);
+
+    // Define a validator and an associated genesis allocation.
+    let (validator, allocation) = {
+        let v = PenumbraValidator {
+            identity_key: Some(IdentityKey {
+                ik: identity_vk.to_bytes().to_vec(),
+            }),
+            governance_key: Some(GovernanceKey {
+                gk: identity_vk.to_bytes().to_vec(),
+            }),
+            consensus_key: consensus_vk.as_bytes().to_vec(),
+            enabled: true,
+            sequence_number: 0,
+            name: String::default(),
+            website: String::default(),
+            description: String::default(),
+            funding_streams: Vec::default(),
+        };
+
+        let (address, _) = spend_key
+            .full_viewing_key()
+            .incoming()
+            .payment_address(0u32.into());
+
+        let ik = penumbra_stake::IdentityKey(identity_vk.into());
+        let delegation_denom = DelegationToken::from(ik).denom();
+
+        let allocation = Allocation {
+            raw_amount: 1000u128.into(),
+            raw_denom: delegation_denom.to_string(),
+            address,
+        };
+
+        (v, allocation)
+    };
+
+    // Define our application state, and start the test node.
+    let mut test_node = {
+        let mut content = Content {
+            governance_content: penumbra_governance::genesis::Content {
+                governance_params: penumbra_governance::params::GovernanceParameters {
+                    proposal_deposit_amount: 0_u32.into(),
+                    proposal_voting_blocks: PROPOSAL_VOTING_BLOCKS,
+                    ..Default::default()
+                },
+            },
+            ..Default::default()
+        }
+        .with_chain_id(TestNode::<()>::CHAIN_ID.to_string());
+        content.stake_content.validators.push(validator);
+        content.shielded_pool_content.allocations.push(allocation);
+        let app_state = AppState::Content(content);
+        let app_state = serde_json::to_vec(&app_state).unwrap();
+        let consensus = Consensus::new(storage.as_ref().clone());
+        TestNode::builder()
+            .single_validator()
+            .app_state(app_state)
+            .init_chain(consensus)
+            .await
+            .tap_ok(|e| tracing::info!(hash = %e.last_app_hash_hex(), "finished init chain"))?
+    };
+
+    // Sync the mock client, using the test wallet's spend key, to the latest snapshot.
+    let client = MockClient::new(test_keys::SPEND_KEY.clone())
+        .with_sync_to_storage(&storage)
+        .await?
+        .tap(|c| info!(client.notes = %c.notes.len(), "mock client synced to test storage"));
+
+    // Build a transaction containing the given actions.
+    let build = |actions: Vec<ActionPlan>| {
+        let mut plan = TransactionPlan {
+            actions,
+            memo: None,
+            detection_data: None,
+            transaction_parameters: TransactionParameters {
+                chain_id: TestNode::<()>::CHAIN_ID.to_string(),
+                ..Default::default()
+            },
+        };
+        plan.populate_detection_data(OsRng, Default::default());
+        let client = &client;
+        async move { client.witness_auth_build(&plan).await }
+    };
+
+    // Take one of the test wallet's notes, and deposit it in the community pool.
+    let note = client
+        .notes
+        .values()
+        .cloned()
+        .next()
+        .ok_or_else(|| anyhow!("mock client had no note"))?;
+    let value = note.value();
+    let tx = build(vec![
+        SpendPlan::new(
+            &mut OsRng,
+            note.clone(),
+            client
+                .position(note.commit())
+                .ok_or_else(|| anyhow!("input note commitment was unknown to mock client"))?,
+        )
+        .into(),
+        CommunityPoolDeposit { value }.into(),
+    ])
+    .await?;
+    test_node
+        .block()
+        .with_data(vec![tx.encode_to_vec()])
+        .execute()
+        .instrument(error_span!("executing block with community pool deposit"))
+        .await?;
+
+    // Build the transactions submitting a proposal, and voting for it.
+    let proposal_txs = |id: u64, payload: ProposalPayload| {
+        let proposal_submit = ProposalSubmit {
+            proposal: Proposal {
+                id,
+                title: "batch".to_owned(),
+                description: "a proposal in a test of batch proposals".to_owned(),
+                payload,
+            },
+            deposit_amount: 0_u32.into(),
+        };
+        let proposal_nft_value = proposal_submit.proposal_nft_value();
+        let body = ValidatorVoteBody {
+            proposal: id,
+            vote: penumbra_governance::Vote::Yes,
+            identity_key: penumbra_stake::IdentityKey(identity_vk.to_bytes().into()),
+            governance_key: penumbra_stake::GovernanceKey(governance_vk),
+            reason: ValidatorVoteReason("test reason".to_owned()),
+        };
+        let auth_sig = governance_sk.sign(OsRng, body.encode_to_vec().as_slice());
+        let build = &build;
+        async move {
+            let submit = build(vec![
+                ActionPlan::ProposalSubmit(proposal_submit),
+                OutputPlan::new(
+                    &mut OsRng,
+                    proposal_nft_value,
+                    test_keys::ADDRESS_0.deref().clone(),
+                )
+                .into(),
+            ])
+            .await?;
+            let vote = build(vec![ValidatorVote { body, auth_sig }.into()]).await?;
+            anyhow::Ok([submit, vote])
+        }
+    };
+
+    // Parameter changes to the voting period, which are easy to observe once applied.
+    let set_voting = |blocks: u64| {
+        ProposalPayload::ParameterChange(ParameterChange {
+            changes: vec![EncodedParameter {
+                component: "governanceParams".to_owned(),
+                key: "proposalVotingBlocks".to_owned(),
+                value: format!(r#""{blocks}""#),
+            }],
+            preconditions: vec![],
+        })
+    };
+    let shorten_voting = set_voting(5);
+    // A community pool spend, paying the given value to the test wallet.
+    let spend = |value: Value| {
+        let transaction_plan = TransactionPlan {
+            actions: vec![
+                CommunityPoolSpend { value }.into(),
+                CommunityPoolOutput {
+                    value,
+                    address: test_keys::ADDRESS_0.deref().clone(),
+                }
+                .into(),
+            ],
+            memo: None,
+            detection_data: None,
+            transaction_parameters: TransactionParameters {
+                chain_id: TestNode::<()>::CHAIN_ID.to_string(),
+                ..Default::default()
+            },
+        };
+        ProposalPayload::CommunityPoolSpend {
+            transaction_plan: transaction_plan.encode_to_vec(),
+        }
+    };
+    let grant_everything = ProposalPayload::CommunityPoolVestingGrant {
+        recipient: test_keys::ADDRESS_1.deref().clone(),
+        total: value,
+        cliff_epochs: 10,
+        release_per_epoch: value.amount,
+    };
+
+    // Propose a grant of the whole community pool, alongside a batch which changes a parameter
+    // and makes the same grant. Both can fund their grant when submitted, but the batch is
+    // enacted second, once the community pool has been emptied.
+    let [submit_grant, vote_grant] = proposal_txs(0, grant_everything.clone()).await?;
+    let [submit_batch, vote_batch] = proposal_txs(
+        1,
+        ProposalPayload::Batch {
+            payloads: vec![shorten_voting.clone(), grant_everything],
+            conditions: vec![],
+        },
+    )
+    .await?;
+    test_node
+        .block()
+        .with_data(vec![
+            submit_grant.encode_to_vec(),
+            submit_batch.encode_to_vec(),
+        ])
+        .execute()
+        .instrument(error_span!("executing block with proposal submissions"))
+        .await?;
+    test_node
+        .block()
+        .with_data(vec![vote_grant.encode_to_vec(), vote_batch.encode_to_vec()])
+        .execute()
+        .instrument(error_span!("executing block with validator votes"))
+        .await?;
+    // Batches are enacted in the block after they pass.
+    test_node.fast_forward(PROPOSAL_VOTING_BLOCKS + 2).await?;
+
+    // Both proposals pass, but the batch fails to enact, so its parameter change is discarded.
+    for id in [0, 1] {
+        assert_eq!(
+            storage.latest_snapshot().proposal_state(id).await?,
+            Some(penumbra_governance::proposal_state::State::Finished {
+                outcome: penumbra_governance::proposal_state::Outcome::Passed,
+            }),
+            "proposal {id} should pass"
+        );
+    }
+    assert!(
+        grant(0).await?.is_some(),
+        "the grant proposal should be enacted"
+    );
+    assert!(
+        grant(1).await?.is_none(),
+        "the batched grant cannot be funded, so it fails to enact"
+    );
+    assert_eq!(
+        voting_blocks().await?,
+        PROPOSAL_VOTING_BLOCKS,
+        "the batched parameter change should be rolled back"
+    );
+
+    // A batch whose payloads all succeed is enacted in full.
+    for tx in proposal_txs(
+        2,
+        ProposalPayload::Batch {
+            payloads: vec![
+                shorten_voting,
+                ProposalPayload::CancelCommunityPoolVestingGrant { grant_id: 0 },
+            ],
+            conditions: vec![],
+        },
+    )
+    .await?
+    {
+        test_node
+            .block()
+            .with_data(vec![tx.encode_to_vec()])
+            .execute()
+            .instrument(error_span!("executing block with batch proposal"))
+            .await?;
+    }
+    test_node.fast_forward(PROPOSAL_VOTING_BLOCKS + 2).await?;
+    assert!(
+        grant(0)
+            .await?
+            .ok_or_else(|| anyhow!("vesting grant 0 should exist"))?
+            .cancelled,
+        "the batched cancellation should be enacted"
+    );
+    assert_eq!(
+        voting_blocks().await?,
+        5,
+        "the batched parameter change should be applied"
+    );
+    assert_eq!(
+        pool_balance(value).await?,
+        value.amount,
+        "the cancelled grant should return its funds to the community pool"
+    );
+
+    // Propose three batches, which pass together and are enacted in order in the same block:
+    // - one whose community pool spend overdraws the pool, so that its transaction fails;
+    // - one which depends on the first passing and the pool holding enough to fund its spend;
+    // - one which depends on the pool holding funds which the second batch has spent.
+    let overdraw = Value {
+        amount: value.amount + Amount::from(1u64),
+        asset_id: value.asset_id,
+    };
+    let batches = [
+        (
+            3,
+            ProposalPayload::Batch {
+                payloads: vec![set_voting(7), spend(overdraw)],
+                conditions: vec![],
+            },
+        ),
+        (
+            4,
+            ProposalPayload::Batch {
+                payloads: vec![set_voting(7), spend(value)],
+                conditions: vec![
+                    BatchCondition::ProposalPassed { proposal_id: 3 },
+                    BatchCondition::CommunityPoolBalanceAtLeast { value },
+                ],
+            },
+        ),
+        (
+            5,
+            ProposalPayload::Batch {
+                payloads: vec![set_voting(9)],
+                conditions: vec![BatchCondition::CommunityPoolBalanceAtLeast { value }],
+            },
+        ),
+    ];
+    let (mut submits, mut votes) = (Vec::new(), Vec::new());
+    for (id, payload) in batches {
+        let [submit, vote] = proposal_txs(id, payload).await?;
+        submits.push(submit.encode_to_vec());
+        votes.push(vote.encode_to_vec());
+    }
+    test_node
+        .block()
+        .with_data(submits)
+        .execute()
+        .instrument(error_span!(
+            "executing block with conditional batch proposals"
+        ))
+        .await?;
+    test_node
+        .block()
+        .with_data(votes)
+        .execute()
+        .instrument(error_span!("executing block with validator votes"))
+        .await?;
+    test_node.fast_forward(voting_blocks().await? + 2).await?;
+
+    for id in [3, 4, 5] {
+        assert!(
+            storage
+                .latest_snapshot()
+                .proposal_state(id)
+                .await?
+                .is_some_and(|state| state.is_passed()),
+            "proposal {id} should pass"
+        );
+    }
+    assert_eq!(
+        voting_blocks().await?,
+        7,
+        "only the batch whose conditions hold and whose spend succeeds should be enacted"
+    );
+    assert_eq!(
+        pool_balance(value).await?,
+        Amount::zero(),
+        "the community pool should be spent by the conditional batch alone"
+    );
+
+    // Free our temporary storage.
+    Ok(())
+        .tap(|_| drop(test_node))
+        .tap(|_| drop(storage))
+        .tap(|_| drop(guard))
+}
@@ -0,0 +1,434 @@
+use {
+    self::common::{BuilderExt, TestNodeExt, ValidatorDataReadExt},
+    anyhow::anyhow,
+    cnidarium::TempStorage,
+    common::TempStorageExt as _,
+    decaf377_rdsa::{SigningKey, SpendAuth, VerificationKey},
+    penumbra_app::{
+        genesis::{self, AppState},
+        server::consensus::Consensus,
+    },
+    penumbra_asset::Value,
+    penumbra_keys::test_keys,
+    penumbra_mock_client::MockClient,
+    penumbra_mock_consensus::TestNode,
+    penumbra_num::Amount,
+    penumbra_proto::DomainType,
+    penumbra_sct::component::clock::EpochRead as _,
+    penumbra_shielded_pool::{Note, OutputPlan, SpendPlan},
+    penumbra_stake::{
+        component::{validator_handler::ValidatorDataRead as _, SlashingData as _},
+        validator::{self, BondingState, Validator},
+        DelegationToken, FundingStreams, GovernanceKey, IdentityKey, Penalty,
+    },
+    penumbra_transaction::{
+        memo::MemoPlaintext, plan::MemoPlan, TransactionParameters, TransactionPlan,
+    },
+    rand_core::OsRng,
+    std::ops::Deref,
+    tap::Tap,
+    tracing::{error_span, info, Instrument},
+};
+
+mod common;
+
+/// The length of the [`penumbra_sct`] epoch.
+const EPOCH_DURATION: u64 = 8;
+
+/// The number of blocks a validator may miss within its signing window before being jailed.
+///
+/// This is small enough that a validator which never signs is jailed early in its first epoch
+/// in the active set.
+const MISSED_BLOCKS_MAXIMUM: u64 = 4;
+
+/// Builds a transaction plan spending `note`, which holds delegation tokens of the validator
+/// described by `source`, and redelegating `amount` of them to the `destination` validator.
+async fn redelegate_plan(
+    storage: &TempStorage,
+    client: &MockClient,
+    note: Note,
+    source: &IdentityKey,
+    destination: &IdentityKey,
+    amount: Amount,
+) -> anyhow::Result<TransactionPlan> {
+    let snapshot = storage.latest_snapshot();
+    let source_rate = snapshot
+        .get_validator_rate(source)
+        .await?
+        .ok_or(anyhow!("source validator has a rate"))?;
+    let destination_rate = snapshot
+        .get_validator_rate(destination)
+        .await?
+        .ok_or(anyhow!("destination validator has a rate"))?;
+    let redelegate = source_rate.build_redelegate(
+        &destination_rate,
+        snapshot.get_current_epoch().await?,
+        amount,
+    );
+
+    let spend = SpendPlan::new(
+        &mut OsRng,
+        note.clone(),
+        client
+            .position(note.commit())
+            .expect("note should be in mock client's tree"),
+    );
+    let output = OutputPlan::new(
+        &mut OsRng,
+        redelegate.destination_delegation_value(),
+        test_keys::ADDRESS_1.deref().clone(),
+    );
+    let change = OutputPlan::new(
+        &mut OsRng,
+        Value {
+            amount: note.amount() - amount,
+            asset_id: note.asset_id(),
+        },
+        test_keys::ADDRESS_1.deref().clone(),
+    );
+
+    Ok(TransactionPlan {
+        actions: vec![
+            spend.into(),
+            output.into(),
+            change.into(),
+            redelegate.into(),
+        ],
+        memo: Some(MemoPlan::new(
+            &mut OsRng,
+            MemoPlaintext::blank_memo(test_keys::ADDRESS_0.deref().clone()),
+        )),
+        detection_data: None, // We'll set this automatically below
+        transaction_parameters: TransactionParameters {
+            chain_id: TestNode::<()>::CHAIN_ID.to_string(),
+            ..Default::default()
+        },
+    }
+    .with_populated_detection_data(OsRng, Default::default()))
+}
+
+/// Redelegates stake away from a validator which is then jailed, checking that the penalty
+/// recorded after the redelegation is paid by the destination validator's pool, that no more
+/// stake can be redelegated away from the jailed validator, and that both delegation pools
+/// account for the redelegation at the epoch boundary.
+#[tokio::test]
+async fn app_redelegations_remain_liable_for_source_penalties() -> anyhow::Result<()> {
+    // Install a test logger, acquire some temporary storage, and start the test node.
+    let guard = common::set_tracing_subscriber();
+    let storage = TempStorage::new_with_penumbra_prefixes().await?;
+
+    // Configure an AppState with short epochs, which quickly jails validators missing blocks.
+    let app_state = {
+        let content = genesis::Content::default()
+            .with_epoch_duration(EPOCH_DURATION)
+            .with_chain_id(TestNode::<()>::CHAIN_ID.to_string());
+        AppState::Content(genesis::Content {
+            stake_content: penumbra_stake::genesis::Content {
+                stake_params: penumbra_stake::params::StakeParameters {
+                    signed_blocks_window_len: 2 * MISSED_BLOCKS_MAXIMUM,
+                    missed_blocks_maximum: MISSED_BLOCKS_MAXIMUM,
+                    ..content.stake_content.stake_params.clone()
+                },
+                ..content.stake_content.clone()
+            },
+            ..content
+        })
+    };
+
+    // Start the test node.
+    let mut node = {
+        let consensus = Consensus::new(storage.as_ref().clone());
+        TestNode::builder()
+            .single_validator()
+            .with_penumbra_auto_app_state(app_state)?
+            .init_chain(consensus)
+            .await
+    }?;
+
+    // Sync the mock client, using the test wallet's spend key, to the latest snapshot.
+    let mut client = MockClient::new(test_keys::SPEND_KEY.clone())
+        .with_sync_to_storage(&storage)
+        .await?
+        .tap(|c| info!(client.notes = %c.notes.len(), "mock client synced to test storage"));
+
+    // The genesis validator will receive the redelegated stake.
+    let [destination_id] = storage
+        .latest_snapshot()
+        .validator_identity_keys()
+        .await?
+        .try_into()
+        .map_err(|keys| anyhow::anyhow!("expected one key, got: {keys:?}"))?;
+
+    // Define a new validator, whose consensus key is NOT inserted into the keyring: it will
+    // never sign a block, and will be jailed shortly after becoming active.
+    let source_id_sk = SigningKey::<SpendAuth>::new(OsRng);
+    let source_id = IdentityKey(VerificationKey::from(&source_id_sk).into());
+    let source_consensus = ed25519_consensus::SigningKey::new(OsRng).verification_key();
+    let source = Validator {
+        identity_key: source_id.clone(),
+        consensus_key: tendermint::PublicKey::from_raw_ed25519(&source_consensus.to_bytes())
+            .expect("consensus key is valid"),
+        governance_key: GovernanceKey(source_id_sk.into()),
+        enabled: true,
+        sequence_number: 0,
+        name: "test validator".to_string(),
+        website: String::default(),
+        description: String::default(),
+        funding_streams: FundingStreams::default(),
+    };
+    let plan = {
+        use penumbra_transaction::ActionPlan;
+        let bytes = source.encode_to_vec();
+        let auth_sig = source_id_sk.sign(OsRng, &bytes);
+        let action = ActionPlan::ValidatorDefinition(validator::Definition {
+            validator: source.clone(),
+            auth_sig,
+        });
+        TransactionPlan {
+            actions: vec![action.into()],
+            memo: None,
+            detection_data: None, // We'll set this automatically below
+            transaction_parameters: TransactionParameters {
+                chain_id: TestNode::<()>::CHAIN_ID.to_string(),
+                ..Default::default()
+            },
+        }
+        .with_populated_detection_data(OsRng, Default::default())
+    };
+    let tx = client.witness_auth_build(&plan).await?;
+    node.block()
+        .add_tx(tx.encode_to_vec())
+        .execute()
+        .instrument(error_span!(
+            "executing block with validator definition transaction"
+        ))
+        .await?;
+
+    // Delegate to the new validator, so that it becomes active in the next epoch.
+    let plan = {
+        use penumbra_asset::STAKING_TOKEN_ASSET_ID;
+        let snapshot = storage.latest_snapshot();
+        client.sync_to_latest(snapshot.clone()).await?;
+        let rate = snapshot
+            .get_validator_rate(&source_id)
+            .await?
+            .ok_or(anyhow!("new validator has a rate"))?;
+        let note = client
+            .spendable_notes_by_asset(*STAKING_TOKEN_ASSET_ID)
+            .next()
+            .cloned()
+            .expect("the test account should have one staking token note");
+        let spend = SpendPlan::new(
+            &mut OsRng,
+            note.clone(),
+            client
+                .position(note.commit())
+                .expect("note should be in mock client's tree"),
+        );
+        let delegate = rate.build_delegate(snapshot.get_current_epoch().await?, note.amount());
+        let output = OutputPlan::new(
+            &mut OsRng,
+            delegate.delegation_value(),
+            test_keys::ADDRESS_1.deref().clone(),
+        );
+        TransactionPlan {
+            actions: vec![spend.into(), output.into(), delegate.into()],
+            memo: Some(MemoPlan::new(
+                &mut OsRng,
+                MemoPlaintext::blank_memo(test_keys::ADDRESS_0.deref().clone()),
+            )),
+            detection_data: None, // We'll set this automatically below
+            transaction_parameters: TransactionParameters {
+                chain_id: TestNode::<()>::CHAIN_ID.to_string(),
+                ..Default::default()
+            },
+        }
+        .with_populated_detection_data(OsRng, Default::default())
+    };
+    let tx = client.witness_auth_build(&plan).await?;
+    node.block()
+        .add_tx(tx.encode_to_vec())
+        .execute()
+        .instrument(error_span!("executing block with delegation transaction"))
+        .await?;
+    node.fast_forward_to_next_epoch(&storage).await?;
+    assert_eq!(
+        storage
+            .latest_snapshot()
+            .get_validator_state(&source_id)
+            .await?,
+        Some(validator::State::Active),
+        "the new validator should be active after the delegation"
+    );
+
+    // Redelegate half of the stake delegated to the new validator, before it is jailed.
+    let delegation_id = DelegationToken::new(source_id.clone()).id();
+    client.sync_to_latest(storage.latest_snapshot()).await?;
+    let note = client
+        .spendable_notes_by_asset(delegation_id)
+        .next()
+        .cloned()
+        .expect("the test account should have one delegation token note");
+    let redelegated_amount = Amount::from(note.amount().value() / 2);
+    let plan = redelegate_plan(
+        &storage,
+        &client,
+        note,
+        &source_id,
+        &destination_id,
+        redelegated_amount,
+    )
+    .await?;
+    let redelegate = plan
+        .redelegations()
+        .next()
+        .cloned()
+        .expect("plan contains a redelegation");
+    let tx = client.witness_auth_build(&plan).await?;
+    node.block()
+        .add_tx(tx.encode_to_vec())
+        .execute()
+        .instrument(error_span!("executing block with redelegation transaction"))
+        .await?;
+
+    // The redelegated stake is liable for the new validator's penalties until it would have
+    // finished unbonding.
+    {
+        let liabilities = storage
+            .latest_snapshot()
+            .redelegation_liabilities(&source_id)
+            .await?;
+        let [(_, destination, amount)] = liabilities
+            .try_into()
+            .map_err(|l| anyhow!("expected one liability, got: {l:?}"))?;
+        assert_eq!(destination, destination_id);
+        assert_eq!(amount, redelegate.unbonded_amount);
+    }
+
+    // Keep going until the new validator is jailed for missing blocks.
+    let jailed_epoch = storage.latest_snapshot().get_current_epoch().await?;
+    loop {
+        let snapshot = storage.latest_snapshot();
+        if snapshot.get_validator_state(&source_id).await? == Some(validator::State::Jailed) {
+            break;
+        }
+        assert_eq!(
+            snapshot.get_current_epoch().await?,
+            jailed_epoch,
+            "the new validator should be jailed within its first active epoch"
+        );
+        node.block().execute().await?;
+    }
+    let penalty = storage
+        .latest_snapshot()
+        .get_penalty_in_epoch(&source_id, jailed_epoch.index)
+        .await
+        .expect("the jailed validator was penalized");
+    assert!(matches!(
+        storage
+            .latest_snapshot()
+            .get_validator_bonding_state(&source_id)
+            .await,
+        Some(BondingState::Unbonding { .. })
+    ));
+
+    // The rest of the stake can no longer be redelegated away from the jailed validator.
+    client.sync_to_latest(storage.latest_snapshot()).await?;
+    let note = client
+        .spendable_notes_by_asset(delegation_id)
+        .next()
+        .cloned()
+        .expect("the test account should have kept some delegation tokens");
+    let remaining_amount = note.amount();
+    let plan = redelegate_plan(
+        &storage,
+        &client,
+        note.clone(),
+        &source_id,
+        &destination_id,
+        remaining_amount,
+    )
+    .await?;
+    let tx = client.witness_auth_build(&plan).await?;
+    node.block()
+        .add_tx(tx.encode_to_vec())
+        .execute()
+        .instrument(error_span!(
+            "executing block with redelegation from a jailed validator"
+        ))
+        .await?;
+    client.sync_to_latest(storage.latest_snapshot()).await?;
+    assert!(
+        !client.spent_note(&note.commit()),
+        "redelegations from a jailed validator should be rejected"
+    );
+
+    // Record the state of both pools before the epoch boundary.
+    let pre_boundary_snapshot = storage.latest_snapshot();
+    let source_pool = pre_boundary_snapshot
+        .get_validator_pool_size(&source_id)
+        .await
+        .expect("the new validator has a pool");
+    let destination_pool = pre_boundary_snapshot
+        .get_validator_pool_size(&destination_id)
+        .await
+        .expect("the genesis validator has a pool");
+    let destination_rate = pre_boundary_snapshot
+        .get_validator_rate(&destination_id)
+        .await?
+        .ok_or(anyhow!("the genesis validator has a rate"))?;
+
+    node.fast_forward_to_next_epoch(&storage).await?;
+    let post_boundary_snapshot = storage.latest_snapshot();
+
+    // Only the first redelegation moved delegation tokens between the pools.
+    assert_eq!(
+        post_boundary_snapshot
+            .get_validator_pool_size(&source_id)
+            .await
+            .expect("the new validator has a pool"),
+        source_pool - redelegate.source_delegation_amount,
+    );
+    assert_eq!(
+        post_boundary_snapshot
+            .get_validator_pool_size(&destination_id)
+            .await
+            .expect("the genesis validator has a pool"),
+        destination_pool + redelegate.destination_delegation_amount,
+    );
+
+    // The genesis validator's pool, including the redelegated tokens, covers the penalty owed
+    // by the redelegated stake, so its rate for the ended epoch was slashed accordingly.
+    let loss = redelegate.unbonded_amount - penalty.apply_to_amount(redelegate.unbonded_amount);
+    assert!(loss > Amount::zero());
+    let liable_stake = destination_rate
+        .unbonded_amount(destination_pool + redelegate.destination_delegation_amount);
+    let slashed_rate = post_boundary_snapshot
+        .get_prev_validator_rate(&destination_id)
+        .await
+        .ok_or(anyhow!("the genesis validator has a previous rate"))?;
+    assert_eq!(
+        slashed_rate,
+        destination_rate.slash(Penalty::covering_loss(loss, liable_stake))
+    );
+    assert!(slashed_rate.validator_exchange_rate < destination_rate.validator_exchange_rate);
+
+    // The liability was reduced by the penalty it paid, and remains for the rest of the
+    // unbonding delay.
+    {
+        let liabilities = post_boundary_snapshot
+            .redelegation_liabilities(&source_id)
+            .await?;
+        let [(_, destination, amount)] = liabilities
+            .try_into()
+            .map_err(|l| anyhow!("expected one liability, got: {l:?}"))?;
+        assert_eq!(destination, destination_id);
+        assert_eq!(amount, redelegate.unbonded_amount - loss);
+    }
+
+    // The test passed. Free our temporary storage and drop our tracing subscriber.
+    Ok(())
+        .tap(|_| drop(node))
+        .tap(|_| drop(storage))
+        .tap(|_| drop(guard))
+}
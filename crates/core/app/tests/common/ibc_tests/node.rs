@@ -31,7 +31,10 @@ use {
     penumbra_proto::util::tendermint_proxy::v1::{
         tendermint_proxy_service_client::TendermintProxyServiceClient, GetStatusRequest,
     },
-    std::error::Error,
+    std::{
+        error::Error,
+        sync::atomic::{AtomicU16, Ordering},
+    },
     tap::{Tap, TapFallible},
     tendermint::{
         v0_37::abci::{ConsensusRequest, ConsensusResponse},
@@ -46,6 +49,9 @@ use {
 
 // Contains some data from a single IBC connection + client for test usage.
 // This might be better off as an extension trait or additional impl on the TestNode struct.
+/// The port the next node's gRPC server binds to.
+static NEXT_GRPC_PORT: AtomicU16 = AtomicU16::new(9990);
+
 #[allow(unused)]
 pub struct TestNodeWithIBC {
     pub connection_id: ConnectionId,
@@ -65,6 +71,7 @@ pub struct TestNodeWithIBC {
     pub ibc_connection_query_client: IbcConnectionQueryClient<Channel>,
     pub ibc_channel_query_client: IbcChannelQueryClient<Channel>,
     pub tendermint_proxy_service_client: TendermintProxyServiceClient<Channel>,
+    pub grpc_channel: Channel,
 }
 
 #[allow(unused)]
@@ -75,6 +82,17 @@ impl TestNodeWithIBC {
         suffix: &str,
         start_time: Time,
         keys: (SigningKey, VerificationKey),
+    ) -> Result<Self, anyhow::Error> {
+        Self::new_with_genesis_content(suffix, start_time, keys, genesis::Content::default()).await
+    }
+
+    /// Like [`TestNodeWithIBC::new`], but starting the chain from the given genesis content,
+    /// e.g. to configure its IBC parameters.
+    pub async fn new_with_genesis_content(
+        suffix: &str,
+        start_time: Time,
+        keys: (SigningKey, VerificationKey),
+        content: genesis::Content,
     ) -> Result<Self, anyhow::Error> {
         let chain_id = format!("{}-{}", TestNode::<()>::CHAIN_ID, suffix);
         // Use the correct substores
@@ -83,8 +101,7 @@ impl TestNodeWithIBC {
         let proxy = penumbra_mock_tendermint_proxy::TestNodeProxy::new::<Consensus>();
 
         let node = {
-            let app_state =
-                AppState::Content(genesis::Content::default().with_chain_id(chain_id.clone()));
+            let app_state = AppState::Content(content.with_chain_id(chain_id.clone()));
             let consensus = Consensus::new(storage.as_ref().clone());
             TestNode::builder()
                 .with_keys(vec![keys])
@@ -97,15 +114,12 @@ impl TestNodeWithIBC {
                 .tap_ok(|e| tracing::info!(hash = %e.last_app_hash_hex(), "finished init chain"))?
         };
 
-        // to select a port number just index on the suffix for now
-        let index = match suffix {
-            "a" => 0,
-            "b" => 1,
-            _ => unreachable!("update this hack"),
-        };
         // We use a non-standard port range, to avoid conflicting with other
-        // integration tests that bind to the more typical 8080/8081 ports.
-        let grpc_url = format!("http://127.0.0.1:999{}", index) // see #4517
+        // integration tests that bind to the more typical 8080/8081 ports,
+        // and give each node its own port, so that tests in the same binary
+        // can run concurrently.
+        let port = NEXT_GRPC_PORT.fetch_add(1, Ordering::Relaxed);
+        let grpc_url = format!("http://127.0.0.1:{port}") // see #4517
             .parse::<url::Url>()?
             .tap(|url| tracing::debug!(%url, "parsed grpc url"));
 
@@ -180,6 +194,7 @@ impl TestNodeWithIBC {
             ibc_channel_query_client,
             ibc_client_query_client,
             tendermint_proxy_service_client,
+            grpc_channel: channel,
         })
     }
 
@@ -1400,6 +1400,75 @@ impl MockRelayer {
     /// Currently hardcoded to send 50% of the first note's value
     /// on chain A.
     pub async fn transfer_from_a_to_b(&mut self) -> Result<()> {
+        let destination_chain_address = self
+            .chain_b_ibc
+            .client()
+            .await?
+            .fvk
+            .payment_address(AddressIndex::new(0))
+            .0;
+        let send_events = self
+            .send_transfer_from_a(destination_chain_address.to_string(), String::new())
+            .await?;
+        let recv_events = self.relay_send_packets(send_events).await?;
+        self.relay_acknowledgements(recv_events).await?;
+
+        self.chain_a_ibc.node.block().execute().await?;
+        self.chain_b_ibc.node.block().execute().await?;
+        self._sync_chains().await?;
+
+        Ok(())
+    }
+
+    /// Swaps the roles of the two chains, so that packets can be relayed from chain B to chain A.
+    pub fn reverse(&mut self) {
+        std::mem::swap(&mut self.chain_a_ibc, &mut self.chain_b_ibc);
+    }
+
+    /// Submits an ICS-20 withdrawal on chain A to `destination_chain_address` over channel-0,
+    /// carrying `ics20_memo`, and returns the events emitted by its transaction.
+    ///
+    /// Currently hardcoded to send 50% of the first unspent note's value
+    /// on chain A.
+    pub async fn send_transfer_from_a(
+        &mut self,
+        destination_chain_address: String,
+        ics20_memo: String,
+    ) -> Result<Vec<Event>> {
+        // For this sunny path test, we'll set the timeouts very far in the future
+        let timeout_height = Height {
+            revision_height: 1_000_000,
+            revision_number: 0,
+        };
+        // get the current time on the local machine
+        let current_time_ns = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("Time went backwards")
+            .as_nanos() as u64;
+
+        // add 2 days to current time
+        let mut timeout_time = current_time_ns + 1.728e14 as u64;
+
+        // round to the nearest 10 minutes
+        timeout_time += 600_000_000_000 - (timeout_time % 600_000_000_000);
+
+        self.send_transfer_from_a_with_timeout(
+            destination_chain_address,
+            ics20_memo,
+            timeout_height,
+            timeout_time,
+        )
+        .await
+    }
+
+    /// Like [`Self::send_transfer_from_a`], but with the given timeouts on chain B.
+    pub async fn send_transfer_from_a_with_timeout(
+        &mut self,
+        destination_chain_address: String,
+        ics20_memo: String,
+        timeout_height: Height,
+        timeout_time: u64,
+    ) -> Result<Vec<Event>> {
         // Ensure chain A has balance to transfer
         let chain_a_client = self.chain_a_ibc.client().await?;
         let chain_b_client = self.chain_b_ibc.client().await?;
@@ -1407,9 +1476,9 @@ impl MockRelayer {
         let chain_a_note = chain_a_client
             .notes
             .values()
+            .find(|note| !chain_a_client.spent_note(&note.commit()))
             .cloned()
-            .next()
-            .ok_or_else(|| anyhow!("mock client had no note"))?;
+            .ok_or_else(|| anyhow!("mock client had no unspent note"))?;
 
         // Get the balance of that asset on chain A
         let pretransfer_balance_a: Amount = chain_a_client
@@ -1442,29 +1511,11 @@ impl MockRelayer {
         };
 
         // Prepare and perform the transfer from chain A to chain B
-        let destination_chain_address = chain_b_client.fvk.payment_address(AddressIndex::new(0)).0;
         let denom = asset_cache
             .get(&transfer_value.asset_id)
             .expect("asset ID should exist in asset cache")
             .clone();
         let amount = transfer_value.amount;
-        // TODO: test timeouts
-        // For this sunny path test, we'll set the timeouts very far in the future
-        let timeout_height = Height {
-            revision_height: 1_000_000,
-            revision_number: 0,
-        };
-        // get the current time on the local machine
-        let current_time_ns = SystemTime::now()
-            .duration_since(UNIX_EPOCH)
-            .expect("Time went backwards")
-            .as_nanos() as u64;
-
-        // add 2 days to current time
-        let mut timeout_time = current_time_ns + 1.728e14 as u64;
-
-        // round to the nearest 10 minutes
-        timeout_time += 600_000_000_000 - (timeout_time % 600_000_000_000);
 
         let return_address = chain_a_client
             .fvk
@@ -1474,7 +1525,7 @@ impl MockRelayer {
             )
             .0;
         let withdrawal = Ics20Withdrawal {
-            destination_chain_address: destination_chain_address.to_string(),
+            destination_chain_address,
             denom,
             amount,
             timeout_height,
@@ -1486,7 +1537,7 @@ impl MockRelayer {
             // Penumbra <-> Penumbra so false
             use_compat_address: false,
             use_transparent_address: false,
-            ics20_memo: "".to_string(),
+            ics20_memo,
         };
         // There will need to be `Spend` and `Output` actions
         // within the transaction in order for it to balance
@@ -1543,6 +1594,13 @@ impl MockRelayer {
             .await?;
         self._sync_chains().await?;
 
+        Ok(deliver_tx_events.0)
+    }
+
+    /// Relays the packets sent in `events` on chain A to chain B, returning the events emitted by
+    /// chain B while receiving them, including those emitted at the end of the block, such as the
+    /// acknowledgements of swapped transfers.
+    pub async fn relay_send_packets(&mut self, events: Vec<Event>) -> Result<Vec<Event>> {
         // Since multiple send_packet events can occur in a single deliver tx response,
         // we accumulate all the events and process them in a loop.
         let mut recv_tx_deliver_tx_events: Vec<Event> = Vec::new();
@@ -1551,7 +1609,7 @@ impl MockRelayer {
         // MsgRecvPacket to chain B.
         //
         // The relayer needs to extract the event that chain A emitted:
-        for event in deliver_tx_events.iter() {
+        for event in events.iter() {
             if event.kind == "send_packet" {
                 let mut packet_data_hex = None;
                 let mut sequence = None;
@@ -1646,7 +1704,7 @@ impl MockRelayer {
                     .witness_auth_build(&plan)
                     .await?;
 
-                let (_end_block_events, dtx_events) = self
+                let (end_block_events, dtx_events) = self
                     .chain_b_ibc
                     .node
                     .block()
@@ -1654,14 +1712,23 @@ impl MockRelayer {
                     .execute()
                     .await?;
                 recv_tx_deliver_tx_events.extend(dtx_events.0.into_iter());
+                recv_tx_deliver_tx_events.extend(end_block_events.0.into_iter());
             }
         }
 
         self._sync_chains().await?;
 
+        Ok(recv_tx_deliver_tx_events)
+    }
+
+    /// Relays the acknowledgements written in `events` on chain B back to chain A, returning the
+    /// events emitted by chain A while processing them.
+    pub async fn relay_acknowledgements(&mut self, events: Vec<Event>) -> Result<Vec<Event>> {
+        let mut ack_tx_deliver_tx_events: Vec<Event> = Vec::new();
+
         // Now that the transfer packet has been processed by chain B,
         // the relayer tells chain A to process the acknowledgement.
-        for event in recv_tx_deliver_tx_events.iter() {
+        for event in events.iter() {
             if event.kind == "write_acknowledgement" {
                 let mut packet_data_hex = None;
                 let mut sequence = None;
@@ -1764,20 +1831,20 @@ impl MockRelayer {
                     .witness_auth_build(&plan)
                     .await?;
 
-                self.chain_a_ibc
+                let (_end_block_events, dtx_events) = self
+                    .chain_a_ibc
                     .node
                     .block()
                     .with_data(vec![tx.encode_to_vec()])
                     .execute()
                     .await?;
+                ack_tx_deliver_tx_events.extend(dtx_events.0.into_iter());
             }
         }
 
-        self.chain_a_ibc.node.block().execute().await?;
-        self.chain_b_ibc.node.block().execute().await?;
         self._sync_chains().await?;
 
-        Ok(())
+        Ok(ack_tx_deliver_tx_events)
     }
 }
 
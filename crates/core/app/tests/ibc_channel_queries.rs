@@ -0,0 +1,207 @@
+use {
+    common::ibc_tests::{MockRelayer, TestNodeWithIBC, ValidatorKeys},
+    ibc_proto::ibc::core::channel::v1::{
+        QueryChannelsRequest, QueryPacketAcknowledgementsRequest, QueryPacketCommitmentsRequest,
+        QueryUnreceivedAcksRequest, QueryUnreceivedPacketsRequest,
+    },
+    penumbra_keys::keys::AddressIndex,
+    std::time::Duration,
+    tap::Tap as _,
+};
+
+mod common;
+
+/// Exercises the channel query service's packet queries over the lifetime of a transfer,
+/// checking that a relayer can find the packets and acknowledgements it has to relay.
+#[tokio::test]
+async fn channel_queries_track_packets_in_flight() -> anyhow::Result<()> {
+    // Install a test logger, and acquire some temporary storage.
+    let guard = common::set_tracing_subscriber();
+
+    let block_duration = Duration::from_secs(5);
+    // Fixed start times (both chains start at the same time to avoid unintended timeouts):
+    let start_time_a = tendermint::Time::parse_from_rfc3339("2022-02-11T17:30:50.425417198Z")?;
+
+    // But chain B will be 39 blocks ahead of chain A, so offset chain A's
+    // start time so they match:
+    let start_time_b = start_time_a.checked_sub(39 * block_duration).unwrap();
+
+    // Hardcoded keys for each chain for test reproducibility:
+    let vkeys_a = ValidatorKeys::from_seed([0u8; 32]);
+    let vkeys_b = ValidatorKeys::from_seed([1u8; 32]);
+    let sk_a = vkeys_a.validator_cons_sk.ed25519_signing_key().unwrap();
+    let sk_b = vkeys_b.validator_cons_sk.ed25519_signing_key().unwrap();
+
+    let ska = ed25519_consensus::SigningKey::try_from(sk_a.as_bytes())?;
+    let skb = ed25519_consensus::SigningKey::try_from(sk_b.as_bytes())?;
+    let keys_a = (ska.clone(), ska.verification_key());
+    let keys_b = (skb.clone(), skb.verification_key());
+
+    // Set up some configuration for the two different chains we'll need to keep around.
+    let mut chain_a_ibc = TestNodeWithIBC::new("a", start_time_a, keys_a).await?;
+    let mut chain_b_ibc = TestNodeWithIBC::new("b", start_time_b, keys_b).await?;
+
+    // The two chains can't IBC handshake during the first block, let's fast forward
+    // them both a few.
+    for _ in 0..3 {
+        chain_a_ibc.node.block().execute().await?;
+    }
+    // Do them each a different # of blocks to make sure the heights don't get confused.
+    for _ in 0..42 {
+        chain_b_ibc.node.block().execute().await?;
+    }
+
+    let mut relayer = MockRelayer {
+        chain_a_ibc,
+        chain_b_ibc,
+    };
+    relayer.handshake().await?;
+
+    let port_id = relayer.chain_a_ibc.port_id.to_string();
+    let channel_id = relayer.chain_a_ibc.channel_id.to_string();
+    let port_id_b = relayer.chain_b_ibc.port_id.to_string();
+    let channel_id_b = relayer.chain_b_ibc.channel_id.to_string();
+
+    // Chain A lists the transfer channel.
+    let channels = relayer
+        .chain_a_ibc
+        .ibc_channel_query_client
+        .channels(QueryChannelsRequest { pagination: None })
+        .await?
+        .into_inner()
+        .channels;
+    assert_eq!(channels.len(), 1);
+    assert_eq!(channels[0].port_id, port_id);
+    assert_eq!(channels[0].channel_id, channel_id);
+
+    // Send a transfer from chain A, without relaying it yet.
+    let receiver = relayer
+        .chain_b_ibc
+        .client()
+        .await?
+        .fvk
+        .payment_address(AddressIndex::new(0))
+        .0;
+    let send_events = relayer
+        .send_transfer_from_a(receiver.to_string(), String::new())
+        .await?;
+
+    // Chain A has committed to the packet, which chain B has yet to receive.
+    let commitments = relayer
+        .chain_a_ibc
+        .ibc_channel_query_client
+        .packet_commitments(QueryPacketCommitmentsRequest {
+            port_id: port_id.clone(),
+            channel_id: channel_id.clone(),
+            pagination: None,
+        })
+        .await?
+        .into_inner()
+        .commitments;
+    let sequences: Vec<u64> = commitments.iter().map(|c| c.sequence).collect();
+    assert_eq!(sequences, vec![1]);
+
+    let unreceived = relayer
+        .chain_b_ibc
+        .ibc_channel_query_client
+        .unreceived_packets(QueryUnreceivedPacketsRequest {
+            port_id: port_id_b.clone(),
+            channel_id: channel_id_b.clone(),
+            packet_commitment_sequences: sequences.clone(),
+        })
+        .await?
+        .into_inner()
+        .sequences;
+    assert_eq!(unreceived, vec![1]);
+
+    // Once chain B receives the packet, it acknowledges it, and chain A has yet to receive the
+    // acknowledgement.
+    let recv_events = relayer.relay_send_packets(send_events).await?;
+
+    let unreceived = relayer
+        .chain_b_ibc
+        .ibc_channel_query_client
+        .unreceived_packets(QueryUnreceivedPacketsRequest {
+            port_id: port_id_b.clone(),
+            channel_id: channel_id_b.clone(),
+            packet_commitment_sequences: sequences.clone(),
+        })
+        .await?
+        .into_inner()
+        .sequences;
+    assert!(unreceived.is_empty());
+
+    let acks = relayer
+        .chain_b_ibc
+        .ibc_channel_query_client
+        .packet_acknowledgements(QueryPacketAcknowledgementsRequest {
+            port_id: port_id_b.clone(),
+            channel_id: channel_id_b.clone(),
+            pagination: None,
+            packet_commitment_sequences: vec![],
+        })
+        .await?
+        .into_inner()
+        .acknowledgements;
+    let ack_sequences: Vec<u64> = acks.iter().map(|a| a.sequence).collect();
+    assert_eq!(ack_sequences, vec![1]);
+
+    // Acknowledgements of other packets are filtered out when specific packets are asked for.
+    let acks = relayer
+        .chain_b_ibc
+        .ibc_channel_query_client
+        .packet_acknowledgements(QueryPacketAcknowledgementsRequest {
+            port_id: port_id_b.clone(),
+            channel_id: channel_id_b.clone(),
+            pagination: None,
+            packet_commitment_sequences: vec![2],
+        })
+        .await?
+        .into_inner()
+        .acknowledgements;
+    assert!(acks.is_empty());
+
+    let unreceived_acks = relayer
+        .chain_a_ibc
+        .ibc_channel_query_client
+        .unreceived_acks(QueryUnreceivedAcksRequest {
+            port_id: port_id.clone(),
+            channel_id: channel_id.clone(),
+            packet_ack_sequences: ack_sequences.clone(),
+        })
+        .await?
+        .into_inner()
+        .sequences;
+    assert_eq!(unreceived_acks, vec![1]);
+
+    // Once chain A processes the acknowledgement, nothing is left to relay.
+    relayer.relay_acknowledgements(recv_events).await?;
+
+    let commitments = relayer
+        .chain_a_ibc
+        .ibc_channel_query_client
+        .packet_commitments(QueryPacketCommitmentsRequest {
+            port_id: port_id.clone(),
+            channel_id: channel_id.clone(),
+            pagination: None,
+        })
+        .await?
+        .into_inner()
+        .commitments;
+    assert!(commitments.is_empty());
+
+    let unreceived_acks = relayer
+        .chain_a_ibc
+        .ibc_channel_query_client
+        .unreceived_acks(QueryUnreceivedAcksRequest {
+            port_id,
+            channel_id,
+            packet_ack_sequences: ack_sequences,
+        })
+        .await?
+        .into_inner()
+        .sequences;
+    assert!(unreceived_acks.is_empty());
+
+    Ok(()).tap(|_| drop(relayer)).tap(|_| drop(guard))
+}
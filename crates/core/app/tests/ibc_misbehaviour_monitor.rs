@@ -0,0 +1,307 @@
+use {
+    anyhow::Result,
+    async_trait::async_trait,
+    common::ibc_tests::{MockRelayer, TestNodeWithIBC, ValidatorKeys},
+    ibc_proto::ibc::core::client::v1::QueryClientStateRequest,
+    ibc_types::{
+        core::client::{msgs::MsgUpdateClient, ClientId, Height},
+        lightclients::tendermint::{
+            client_state::ClientState as TendermintClientState, header::Header as TendermintHeader,
+            misbehaviour::Misbehaviour as TendermintMisbehaviour,
+        },
+    },
+    penumbra_ibc::{
+        monitor::{ClientEventSource, ClientMonitor},
+        relay::HeaderSource,
+        IbcRelay,
+    },
+    penumbra_proto::util::tendermint_proxy::v1::GetBlockByHeightRequest,
+    penumbra_transaction::{TransactionParameters, TransactionPlan},
+    prost::Message as _,
+    std::time::Duration,
+    tap::Tap as _,
+    tendermint::{
+        abci::Event,
+        block::CommitSig,
+        vote::{self, CanonicalVote},
+    },
+    tendermint_proto::{v0_37::types::CanonicalVote as RawCanonicalVote, Protobuf},
+};
+
+mod common;
+
+/// Serves chain B's headers from the test node itself.
+struct MockHeaders<'a>(&'a mut TestNodeWithIBC);
+
+#[async_trait]
+impl HeaderSource for MockHeaders<'_> {
+    async fn latest_height(&mut self) -> Result<u64> {
+        Ok(self.0.get_latest_height().await?.revision_height)
+    }
+
+    async fn header(&mut self, height: Height, trusted_height: Height) -> Result<TendermintHeader> {
+        let block = self
+            .0
+            .tendermint_proxy_service_client
+            .get_block_by_height(GetBlockByHeightRequest {
+                height: height.revision_height.try_into()?,
+            })
+            .await?
+            .into_inner();
+        self.0.create_tendermint_header(Some(trusted_height), block)
+    }
+}
+
+/// Serves headers from a fork of chain B, whose blocks have different app hashes, signed by chain
+/// B's validator.
+struct ForkedHeaders<'a> {
+    headers: MockHeaders<'a>,
+    validator_key: ed25519_consensus::SigningKey,
+}
+
+#[async_trait]
+impl HeaderSource for ForkedHeaders<'_> {
+    async fn latest_height(&mut self) -> Result<u64> {
+        self.headers.latest_height().await
+    }
+
+    async fn header(&mut self, height: Height, trusted_height: Height) -> Result<TendermintHeader> {
+        let mut header = self.headers.header(height, trusted_height).await?;
+        let signed_header = &mut header.signed_header;
+        signed_header.header.app_hash = tendermint::AppHash::try_from(vec![0xff; 32])?;
+        signed_header.commit.block_id.hash = signed_header.header.hash();
+
+        // Sign the forked block in place of chain B's, as a validator equivocating would.
+        for commit_sig in &mut signed_header.commit.signatures {
+            let CommitSig::BlockIdFlagCommit {
+                timestamp,
+                signature,
+                ..
+            } = commit_sig
+            else {
+                continue;
+            };
+            let vote = CanonicalVote {
+                vote_type: vote::Type::Precommit,
+                height: signed_header.header.height,
+                round: signed_header.commit.round,
+                block_id: Some(signed_header.commit.block_id),
+                timestamp: Some(*timestamp),
+                chain_id: signed_header.header.chain_id.clone(),
+            };
+            let sign_bytes = Protobuf::<RawCanonicalVote>::encode_length_delimited_vec(vote);
+            *signature = Some(
+                self.validator_key
+                    .sign(&sign_bytes)
+                    .try_into()
+                    .expect("signature is valid"),
+            );
+        }
+
+        Ok(header)
+    }
+}
+
+/// Serves the client events a test recorded while submitting transactions.
+struct RecordedEvents(Vec<Event>);
+
+#[async_trait]
+impl ClientEventSource for RecordedEvents {
+    async fn update_client_events(
+        &mut self,
+        _client_id: &ClientId,
+        _consensus_height: Height,
+    ) -> Result<Vec<Event>> {
+        Ok(self.0.clone())
+    }
+}
+
+/// Returns the state of the client `client_id` on `chain`.
+async fn client_state(
+    chain: &mut TestNodeWithIBC,
+    client_id: &ClientId,
+) -> Result<TendermintClientState> {
+    Ok(TendermintClientState::try_from(
+        chain
+            .ibc_client_query_client
+            .client_state(QueryClientStateRequest {
+                client_id: client_id.to_string(),
+            })
+            .await?
+            .into_inner()
+            .client_state
+            .expect("client state must be present"),
+    )?)
+}
+
+/// Submits `action` to `chain` in a transaction of its own, returning the transaction's events.
+async fn submit(chain: &mut TestNodeWithIBC, action: IbcRelay) -> Result<Vec<Event>> {
+    let plan = TransactionPlan {
+        actions: vec![action.into()],
+        memo: None,
+        detection_data: None,
+        transaction_parameters: TransactionParameters {
+            chain_id: chain.chain_id.clone(),
+            ..Default::default()
+        },
+    };
+    let tx = chain.client().await?.witness_auth_build(&plan).await?;
+    let (_end_block_events, deliver_tx_events) = chain
+        .node
+        .block()
+        .with_data(vec![tx.encode_to_vec()])
+        .execute()
+        .await?;
+    Ok(deliver_tx_events.0)
+}
+
+/// Exercises the client monitor against chain A's client for chain B, checking that it finds no
+/// evidence of misbehaviour while chain B's headers match the client's consensus states, and
+/// that the evidence it builds from the header that updated the client, once they conflict with a
+/// fork, freezes the client.
+#[tokio::test]
+async fn client_monitor_detects_conflicting_consensus_states() -> anyhow::Result<()> {
+    // Install a test logger, and acquire some temporary storage.
+    let guard = common::set_tracing_subscriber();
+
+    let block_duration = Duration::from_secs(5);
+    let start_time_a = tendermint::Time::parse_from_rfc3339("2022-02-11T17:30:50.425417198Z")?;
+    let start_time_b = start_time_a.checked_sub(39 * block_duration).unwrap();
+
+    // Hardcoded keys for each chain for test reproducibility:
+    let vkeys_a = ValidatorKeys::from_seed([0u8; 32]);
+    let vkeys_b = ValidatorKeys::from_seed([1u8; 32]);
+    let sk_a = vkeys_a.validator_cons_sk.ed25519_signing_key().unwrap();
+    let sk_b = vkeys_b.validator_cons_sk.ed25519_signing_key().unwrap();
+
+    let ska = ed25519_consensus::SigningKey::try_from(sk_a.as_bytes())?;
+    let skb = ed25519_consensus::SigningKey::try_from(sk_b.as_bytes())?;
+    let keys_a = (ska.clone(), ska.verification_key());
+    let keys_b = (skb.clone(), skb.verification_key());
+
+    let mut chain_a_ibc = TestNodeWithIBC::new("a", start_time_a, keys_a).await?;
+    let mut chain_b_ibc = TestNodeWithIBC::new("b", start_time_b, keys_b).await?;
+
+    // The two chains can't IBC handshake during the first block, let's fast forward
+    // them both a few.
+    for _ in 0..3 {
+        chain_a_ibc.node.block().execute().await?;
+    }
+    for _ in 0..42 {
+        chain_b_ibc.node.block().execute().await?;
+    }
+
+    let mut relayer = MockRelayer {
+        chain_a_ibc,
+        chain_b_ibc,
+    };
+    relayer.handshake().await?;
+
+    // Update chain A's client for chain B to chain B's latest block, recording the events.
+    for _ in 0..5 {
+        relayer.chain_b_ibc.node.block().execute().await?;
+    }
+    let client_id = relayer.chain_a_ibc.client_id.clone();
+    let signer = relayer.chain_a_ibc.signer.clone();
+    let trusted_height = client_state(&mut relayer.chain_a_ibc, &client_id)
+        .await?
+        .latest_height;
+    let update_height = relayer.chain_b_ibc.get_latest_height().await?;
+    let update_header = MockHeaders(&mut relayer.chain_b_ibc)
+        .header(update_height, trusted_height)
+        .await?;
+    let update_events = submit(
+        &mut relayer.chain_a_ibc,
+        IbcRelay::UpdateClient(MsgUpdateClient {
+            signer: signer.clone(),
+            client_id: client_id.clone(),
+            client_message: update_header.clone().into(),
+        }),
+    )
+    .await?;
+    assert!(
+        update_events
+            .iter()
+            .any(|event| event.kind == "update_client"),
+        "missing update_client event"
+    );
+
+    // Against chain B's own headers, the monitor finds no evidence of misbehaviour. The mock
+    // relayer creates the client with a consensus state that isn't derived from one of chain B's
+    // headers, so only that consensus state may be reported, without evidence.
+    let penumbra = relayer.chain_a_ibc.grpc_channel.clone();
+    let mut monitor = ClientMonitor::new(
+        penumbra.clone(),
+        client_id.clone(),
+        signer.clone(),
+        MockHeaders(&mut relayer.chain_b_ibc),
+        RecordedEvents(update_events.clone()),
+    );
+    let conflicts = monitor.check().await?;
+    assert!(
+        conflicts
+            .iter()
+            .all(|conflict| conflict.misbehaviour.is_none()),
+        "unexpected conflicts: {conflicts:?}"
+    );
+    assert!(conflicts
+        .iter()
+        .all(|conflict| conflict.height != update_height));
+    drop(monitor);
+
+    // Against a fork of chain B, the consensus state installed by the update conflicts, and the
+    // monitor builds evidence of misbehaviour from the header that installed it.
+    let mut monitor = ClientMonitor::new(
+        penumbra,
+        client_id.clone(),
+        signer.clone(),
+        ForkedHeaders {
+            headers: MockHeaders(&mut relayer.chain_b_ibc),
+            validator_key: skb,
+        },
+        RecordedEvents(update_events),
+    );
+    let conflicts = monitor.check().await?;
+    let conflict = conflicts
+        .iter()
+        .find(|conflict| conflict.height == update_height)
+        .expect("the updated consensus state conflicts with the fork");
+    let msg = conflict
+        .misbehaviour
+        .clone()
+        .expect("evidence can be built from the update");
+    assert_eq!(msg.client_id, client_id);
+    assert_eq!(msg.signer, signer);
+
+    let misbehaviour = TendermintMisbehaviour::try_from(msg.misbehaviour.clone())?;
+    assert_eq!(
+        misbehaviour.header1.signed_header,
+        update_header.signed_header
+    );
+    assert_eq!(misbehaviour.header1.height(), misbehaviour.header2.height());
+    assert_ne!(
+        misbehaviour.header1.signed_header.commit.block_id.hash,
+        misbehaviour.header2.signed_header.commit.block_id.hash
+    );
+    assert_eq!(misbehaviour.header2.trusted_height, trusted_height);
+    assert_eq!(
+        misbehaviour.header2.trusted_validator_set,
+        update_header.trusted_validator_set
+    );
+
+    // Consensus states are only checked once.
+    assert!(monitor.check().await?.is_empty());
+    drop(monitor);
+
+    // Both headers verify against the client's trusted consensus state, so the evidence freezes
+    // the client.
+    submit(&mut relayer.chain_a_ibc, IbcRelay::SubmitMisbehavior(msg)).await?;
+    assert!(
+        client_state(&mut relayer.chain_a_ibc, &client_id)
+            .await?
+            .is_frozen(),
+        "client must be frozen"
+    );
+
+    Ok(()).tap(|_| drop(relayer)).tap(|_| drop(guard))
+}
@@ -0,0 +1,375 @@
+use {
+    anyhow::Result,
+    async_trait::async_trait,
+    common::ibc_tests::{MockRelayer, TestNodeWithIBC, ValidatorKeys},
+    ibc_proto::ibc::core::channel::v1::{
+        QueryPacketCommitmentsRequest, QueryUnreceivedPacketsRequest,
+    },
+    ibc_types::{
+        core::{
+            channel::{ChannelId, PortId},
+            client::Height,
+        },
+        lightclients::tendermint::header::Header as TendermintHeader,
+    },
+    penumbra_ibc::{
+        relay::{ChannelRelayer, HeaderSource, PacketEventSource},
+        IbcRelay,
+    },
+    penumbra_keys::keys::AddressIndex,
+    penumbra_proto::util::tendermint_proxy::v1::GetBlockByHeightRequest,
+    penumbra_transaction::{TransactionParameters, TransactionPlan},
+    prost::Message as _,
+    std::time::Duration,
+    tap::Tap as _,
+    tendermint::abci::Event,
+};
+
+mod common;
+
+/// Serves a chain's headers from the test node itself.
+struct MockHeaders<'a>(&'a mut TestNodeWithIBC);
+
+#[async_trait]
+impl HeaderSource for MockHeaders<'_> {
+    async fn latest_height(&mut self) -> Result<u64> {
+        Ok(self.0.get_latest_height().await?.revision_height)
+    }
+
+    async fn header(&mut self, height: Height, trusted_height: Height) -> Result<TendermintHeader> {
+        let block = self
+            .0
+            .tendermint_proxy_service_client
+            .get_block_by_height(GetBlockByHeightRequest {
+                height: height.revision_height.try_into()?,
+            })
+            .await?
+            .into_inner();
+        self.0.create_tendermint_header(Some(trusted_height), block)
+    }
+}
+
+/// Serves the packet events a test recorded while submitting transactions.
+struct RecordedEvents(Vec<Event>);
+
+#[async_trait]
+impl PacketEventSource for RecordedEvents {
+    async fn send_packet_events(
+        &mut self,
+        _port_id: &PortId,
+        _channel_id: &ChannelId,
+        _sequences: &[u64],
+    ) -> Result<Vec<Event>> {
+        Ok(self.0.clone())
+    }
+
+    async fn write_acknowledgement_events(
+        &mut self,
+        _port_id: &PortId,
+        _channel_id: &ChannelId,
+        _sequences: &[u64],
+    ) -> Result<Vec<Event>> {
+        Ok(self.0.clone())
+    }
+}
+
+/// Returns a relayer for chain A's transfer channel, with chain B as its counterparty.
+fn channel_relayer<'a>(
+    relayer: &'a mut MockRelayer,
+    counterparty_events: &[Event],
+    penumbra_events: &[Event],
+) -> ChannelRelayer<MockHeaders<'a>, RecordedEvents, MockHeaders<'a>, RecordedEvents> {
+    ChannelRelayer {
+        penumbra: relayer.chain_a_ibc.grpc_channel.clone(),
+        counterparty: relayer.chain_b_ibc.grpc_channel.clone(),
+        port_id: relayer.chain_a_ibc.port_id.clone(),
+        channel_id: relayer.chain_a_ibc.channel_id.clone(),
+        signer: relayer.chain_a_ibc.signer.clone(),
+        counterparty_signer: relayer.chain_b_ibc.signer.clone(),
+        counterparty_headers: MockHeaders(&mut relayer.chain_b_ibc),
+        counterparty_events: RecordedEvents(counterparty_events.to_vec()),
+        penumbra_headers: MockHeaders(&mut relayer.chain_a_ibc),
+        penumbra_events: RecordedEvents(penumbra_events.to_vec()),
+    }
+}
+
+/// Returns the messages relaying everything pending on chain A's transfer channel from chain B.
+async fn pending_messages(
+    relayer: &mut MockRelayer,
+    counterparty_events: &[Event],
+    penumbra_events: &[Event],
+) -> Result<Vec<IbcRelay>> {
+    let penumbra_height = relayer.chain_a_ibc.get_latest_height().await?;
+    let penumbra_time = *relayer.chain_a_ibc.node.timestamp();
+    channel_relayer(relayer, counterparty_events, penumbra_events)
+        .pending_messages(penumbra_height, penumbra_time)
+        .await
+}
+
+/// Returns the messages relaying everything pending on chain A's transfer channel to chain B.
+async fn pending_counterparty_messages(
+    relayer: &mut MockRelayer,
+    counterparty_events: &[Event],
+    penumbra_events: &[Event],
+) -> Result<Vec<IbcRelay>> {
+    let counterparty_height = relayer.chain_b_ibc.get_latest_height().await?;
+    let counterparty_time = *relayer.chain_b_ibc.node.timestamp();
+    channel_relayer(relayer, counterparty_events, penumbra_events)
+        .pending_counterparty_messages(counterparty_height, counterparty_time)
+        .await
+}
+
+/// Submits `messages` in a single transaction on `chain`, returning its events.
+async fn submit(chain: &mut TestNodeWithIBC, messages: Vec<IbcRelay>) -> Result<Vec<Event>> {
+    let plan = TransactionPlan {
+        actions: messages
+            .into_iter()
+            .map(penumbra_transaction::ActionPlan::IbcAction)
+            .collect(),
+        memo: None,
+        detection_data: None,
+        transaction_parameters: TransactionParameters {
+            chain_id: chain.chain_id.clone(),
+            ..Default::default()
+        },
+    };
+    let tx = chain.client().await?.witness_auth_build(&plan).await?;
+    let (_end_block_events, deliver_tx_events) = chain
+        .node
+        .block()
+        .with_data(vec![tx.encode_to_vec()])
+        .execute()
+        .await?;
+    Ok(deliver_tx_events.0)
+}
+
+/// Sets up two chains with an open transfer channel between them.
+async fn two_chains() -> Result<MockRelayer> {
+    let block_duration = Duration::from_secs(5);
+    // Fixed start times (both chains start at the same time to avoid unintended timeouts):
+    let start_time_a = tendermint::Time::parse_from_rfc3339("2022-02-11T17:30:50.425417198Z")?;
+
+    // But chain B will be 39 blocks ahead of chain A, so offset chain A's
+    // start time so they match:
+    let start_time_b = start_time_a.checked_sub(39 * block_duration).unwrap();
+
+    // Hardcoded keys for each chain for test reproducibility:
+    let vkeys_a = ValidatorKeys::from_seed([0u8; 32]);
+    let vkeys_b = ValidatorKeys::from_seed([1u8; 32]);
+    let sk_a = vkeys_a.validator_cons_sk.ed25519_signing_key().unwrap();
+    let sk_b = vkeys_b.validator_cons_sk.ed25519_signing_key().unwrap();
+
+    let ska = ed25519_consensus::SigningKey::try_from(sk_a.as_bytes())?;
+    let skb = ed25519_consensus::SigningKey::try_from(sk_b.as_bytes())?;
+    let keys_a = (ska.clone(), ska.verification_key());
+    let keys_b = (skb.clone(), skb.verification_key());
+
+    // Set up some configuration for the two different chains we'll need to keep around.
+    let mut chain_a_ibc = TestNodeWithIBC::new("a", start_time_a, keys_a).await?;
+    let mut chain_b_ibc = TestNodeWithIBC::new("b", start_time_b, keys_b).await?;
+
+    // The two chains can't IBC handshake during the first block, let's fast forward
+    // them both a few.
+    for _ in 0..3 {
+        chain_a_ibc.node.block().execute().await?;
+    }
+    // Do them each a different # of blocks to make sure the heights don't get confused.
+    for _ in 0..42 {
+        chain_b_ibc.node.block().execute().await?;
+    }
+
+    let mut relayer = MockRelayer {
+        chain_a_ibc,
+        chain_b_ibc,
+    };
+    relayer.handshake().await?;
+    Ok(relayer)
+}
+
+/// Exercises the channel relayer against two chains, checking that it updates chain A's client
+/// for chain B and relays a packet sent from chain B, the acknowledgement of a packet chain B
+/// received, and the timeout of a packet chain B never received, all in a single transaction.
+#[tokio::test]
+async fn channel_relayer_relays_pending_packets() -> anyhow::Result<()> {
+    // Install a test logger, and acquire some temporary storage.
+    let guard = common::set_tracing_subscriber();
+    let mut relayer = two_chains().await?;
+
+    let receiver_a = relayer
+        .chain_a_ibc
+        .client()
+        .await?
+        .fvk
+        .payment_address(AddressIndex::new(0))
+        .0;
+    let receiver_b = relayer
+        .chain_b_ibc
+        .client()
+        .await?
+        .fvk
+        .payment_address(AddressIndex::new(0))
+        .0;
+
+    // Chain A sends a packet, which chain B receives and acknowledges.
+    let acked_send_events = relayer
+        .send_transfer_from_a(receiver_b.to_string(), String::new())
+        .await?;
+    let recv_events = relayer
+        .relay_send_packets(acked_send_events.clone())
+        .await?;
+
+    // Chain A sends a packet that times out two minutes from now on chain B, which never
+    // receives it.
+    let now = relayer.chain_b_ibc.node.timestamp().unix_timestamp_nanos() as u64;
+    let timeout_time = now - now % 60_000_000_000 + 120_000_000_000;
+    let timed_out_send_events = relayer
+        .send_transfer_from_a_with_timeout(
+            receiver_b.to_string(),
+            String::new(),
+            Height {
+                revision_number: 0,
+                revision_height: 1_000_000,
+            },
+            timeout_time,
+        )
+        .await?;
+
+    // Chain B sends a packet, which chain A has yet to receive.
+    relayer.reverse();
+    let send_events_b = relayer
+        .send_transfer_from_a(receiver_a.to_string(), String::new())
+        .await?;
+    relayer.reverse();
+
+    // Let the second packet time out.
+    for _ in 0..30 {
+        relayer.chain_a_ibc.node.block().execute().await?;
+        relayer.chain_b_ibc.node.block().execute().await?;
+    }
+    relayer._sync_chains().await?;
+
+    let counterparty_events: Vec<Event> = send_events_b.into_iter().chain(recv_events).collect();
+    let penumbra_events: Vec<Event> = acked_send_events
+        .into_iter()
+        .chain(timed_out_send_events)
+        .collect();
+    let messages = pending_messages(&mut relayer, &counterparty_events, &penumbra_events).await?;
+    assert_eq!(messages.len(), 4, "unexpected messages: {messages:?}");
+    assert!(matches!(messages[0], IbcRelay::UpdateClient(_)));
+    assert!(matches!(messages[1], IbcRelay::RecvPacket(_)));
+    assert!(matches!(messages[2], IbcRelay::Acknowledgement(_)));
+    assert!(matches!(messages[3], IbcRelay::Timeout(_)));
+
+    // Submit them all in a single transaction on chain A.
+    let events = submit(&mut relayer.chain_a_ibc, messages).await?;
+    for kind in ["recv_packet", "acknowledge_packet", "timeout_packet"] {
+        assert!(
+            events.iter().any(|event| event.kind == kind),
+            "missing {kind} event"
+        );
+    }
+
+    // Chain A has settled both of its packets, and received chain B's.
+    let commitments = relayer
+        .chain_a_ibc
+        .ibc_channel_query_client
+        .packet_commitments(QueryPacketCommitmentsRequest {
+            port_id: relayer.chain_a_ibc.port_id.to_string(),
+            channel_id: relayer.chain_a_ibc.channel_id.to_string(),
+            pagination: None,
+        })
+        .await?
+        .into_inner()
+        .commitments;
+    assert!(commitments.is_empty());
+
+    let unreceived = relayer
+        .chain_a_ibc
+        .ibc_channel_query_client
+        .unreceived_packets(QueryUnreceivedPacketsRequest {
+            port_id: relayer.chain_a_ibc.port_id.to_string(),
+            channel_id: relayer.chain_a_ibc.channel_id.to_string(),
+            packet_commitment_sequences: vec![1],
+        })
+        .await?
+        .into_inner()
+        .sequences;
+    assert!(unreceived.is_empty());
+
+    // Nothing is left to relay.
+    let messages = pending_messages(&mut relayer, &counterparty_events, &penumbra_events).await?;
+    assert!(messages.is_empty(), "unexpected messages: {messages:?}");
+
+    Ok(()).tap(|_| drop(relayer)).tap(|_| drop(guard))
+}
+
+/// Exercises the channel relayer in the other direction, checking that it delivers a packet sent
+/// from chain A to chain B, and then relays chain B's acknowledgement of it back to chain A.
+#[tokio::test]
+async fn channel_relayer_relays_to_counterparty() -> anyhow::Result<()> {
+    // Install a test logger, and acquire some temporary storage.
+    let guard = common::set_tracing_subscriber();
+    let mut relayer = two_chains().await?;
+
+    let receiver_b = relayer
+        .chain_b_ibc
+        .client()
+        .await?
+        .fvk
+        .payment_address(AddressIndex::new(0))
+        .0;
+
+    // Chain A sends a packet, which nobody has relayed yet.
+    let penumbra_events = relayer
+        .send_transfer_from_a(receiver_b.to_string(), String::new())
+        .await?;
+    // The packet commitment is proven against the next block's header.
+    relayer.chain_a_ibc.node.block().execute().await?;
+    relayer._sync_chains().await?;
+
+    // The relayer updates chain B's client for chain A and delivers the packet.
+    let messages = pending_counterparty_messages(&mut relayer, &[], &penumbra_events).await?;
+    assert_eq!(messages.len(), 2, "unexpected messages: {messages:?}");
+    assert!(matches!(messages[0], IbcRelay::UpdateClient(_)));
+    assert!(matches!(messages[1], IbcRelay::RecvPacket(_)));
+    let counterparty_events = submit(&mut relayer.chain_b_ibc, messages).await?;
+    assert!(
+        counterparty_events
+            .iter()
+            .any(|event| event.kind == "write_acknowledgement"),
+        "chain B did not acknowledge the packet"
+    );
+
+    // Nothing else is pending towards chain B.
+    let messages =
+        pending_counterparty_messages(&mut relayer, &counterparty_events, &penumbra_events).await?;
+    assert!(messages.is_empty(), "unexpected messages: {messages:?}");
+
+    relayer.chain_b_ibc.node.block().execute().await?;
+    relayer._sync_chains().await?;
+
+    // The acknowledgement chain B wrote is relayed back to chain A, which settles the packet.
+    let messages = pending_messages(&mut relayer, &counterparty_events, &penumbra_events).await?;
+    assert_eq!(messages.len(), 2, "unexpected messages: {messages:?}");
+    assert!(matches!(messages[0], IbcRelay::UpdateClient(_)));
+    assert!(matches!(messages[1], IbcRelay::Acknowledgement(_)));
+    let events = submit(&mut relayer.chain_a_ibc, messages).await?;
+    assert!(events
+        .iter()
+        .any(|event| event.kind == "acknowledge_packet"));
+
+    let commitments = relayer
+        .chain_a_ibc
+        .ibc_channel_query_client
+        .packet_commitments(QueryPacketCommitmentsRequest {
+            port_id: relayer.chain_a_ibc.port_id.to_string(),
+            channel_id: relayer.chain_a_ibc.channel_id.to_string(),
+            pagination: None,
+        })
+        .await?
+        .into_inner()
+        .commitments;
+    assert!(commitments.is_empty());
+
+    Ok(()).tap(|_| drop(relayer)).tap(|_| drop(guard))
+}
@@ -0,0 +1,147 @@
+use {
+    anyhow::anyhow,
+    common::ibc_tests::{MockRelayer, TestNodeWithIBC, ValidatorKeys},
+    penumbra_asset::asset::Cache,
+    penumbra_ibc::IbcToken,
+    penumbra_keys::keys::AddressIndex,
+    penumbra_num::Amount,
+    penumbra_shielded_pool::Ics20ForwardMemo,
+    std::time::Duration,
+    tap::Tap as _,
+};
+
+mod common;
+
+/// Exercises that a transfer can be forwarded through a chain acting as an intermediate hop,
+/// by sending a transfer from chain A to chain B that asks chain B to forward it back to chain A,
+/// and that the acknowledgement of the forwarded packet is propagated back to chain A.
+#[tokio::test]
+async fn ics20_transfer_is_forwarded_through_an_intermediate_chain() -> anyhow::Result<()> {
+    // Install a test logger, and acquire some temporary storage.
+    let guard = common::set_tracing_subscriber();
+
+    let block_duration = Duration::from_secs(5);
+    // Fixed start times (both chains start at the same time to avoid unintended timeouts):
+    let start_time_a = tendermint::Time::parse_from_rfc3339("2022-02-11T17:30:50.425417198Z")?;
+
+    // But chain B will be 39 blocks ahead of chain A, so offset chain A's
+    // start time so they match:
+    let start_time_b = start_time_a.checked_sub(39 * block_duration).unwrap();
+
+    // Hardcoded keys for each chain for test reproducibility:
+    let vkeys_a = ValidatorKeys::from_seed([0u8; 32]);
+    let vkeys_b = ValidatorKeys::from_seed([1u8; 32]);
+    let sk_a = vkeys_a.validator_cons_sk.ed25519_signing_key().unwrap();
+    let sk_b = vkeys_b.validator_cons_sk.ed25519_signing_key().unwrap();
+
+    let ska = ed25519_consensus::SigningKey::try_from(sk_a.as_bytes())?;
+    let skb = ed25519_consensus::SigningKey::try_from(sk_b.as_bytes())?;
+    let keys_a = (ska.clone(), ska.verification_key());
+    let keys_b = (skb.clone(), skb.verification_key());
+
+    // Set up some configuration for the two different chains we'll need to keep around.
+    let mut chain_a_ibc = TestNodeWithIBC::new("a", start_time_a, keys_a).await?;
+    let mut chain_b_ibc = TestNodeWithIBC::new("b", start_time_b, keys_b).await?;
+
+    // The two chains can't IBC handshake during the first block, let's fast forward
+    // them both a few.
+    for _ in 0..3 {
+        chain_a_ibc.node.block().execute().await?;
+    }
+    // Do them each a different # of blocks to make sure the heights don't get confused.
+    for _ in 0..42 {
+        chain_b_ibc.node.block().execute().await?;
+    }
+
+    let mut relayer = MockRelayer {
+        chain_a_ibc,
+        chain_b_ibc,
+    };
+    relayer.handshake().await?;
+
+    // Grab the note that will be spent during the transfer, and the balance of its asset on
+    // chain A.
+    let chain_a_client = relayer.chain_a_ibc.client().await?;
+    let chain_a_note = chain_a_client
+        .notes
+        .values()
+        .cloned()
+        .next()
+        .ok_or_else(|| anyhow!("mock client had no note"))?;
+    let pretransfer_balance_a: Amount = chain_a_client
+        .spendable_notes_by_asset(chain_a_note.asset_id())
+        .map(|n| n.value().amount)
+        .sum();
+
+    // The voucher chain B would mint for the transferred asset, were the transfer not forwarded.
+    let denom = Cache::with_known_assets()
+        .get(&chain_a_note.asset_id())
+        .expect("asset ID should exist in asset cache")
+        .clone();
+    let ibc_token = IbcToken::new(
+        &relayer.chain_b_ibc.channel_id,
+        &relayer.chain_b_ibc.port_id,
+        &denom.to_string(),
+    );
+
+    // Send the transfer to chain B, asking chain B to forward it back to chain A.
+    let intermediate_receiver = relayer
+        .chain_b_ibc
+        .client()
+        .await?
+        .fvk
+        .payment_address(AddressIndex::new(0))
+        .0;
+    let final_receiver = chain_a_client.fvk.payment_address(AddressIndex::new(0)).0;
+    let forward = Ics20ForwardMemo {
+        receiver: final_receiver.to_string(),
+        port: relayer.chain_b_ibc.port_id.to_string(),
+        channel: relayer.chain_b_ibc.channel_id.to_string(),
+        timeout: None,
+        next: None,
+    };
+    let send_events = relayer
+        .send_transfer_from_a(intermediate_receiver.to_string(), forward.to_memo())
+        .await?;
+
+    // Chain B receives the transfer and forwards it, without acknowledging it yet.
+    let recv_events_b = relayer.relay_send_packets(send_events).await?;
+    assert!(recv_events_b
+        .iter()
+        .any(|event| event.kind == "send_packet"));
+    assert!(!recv_events_b
+        .iter()
+        .any(|event| event.kind == "write_acknowledgement"));
+
+    // Relay the forwarded packet from chain B back to chain A.
+    relayer.reverse();
+    let recv_events_a = relayer.relay_send_packets(recv_events_b).await?;
+
+    // Relay chain A's acknowledgement of the forwarded packet to chain B, which in turn
+    // acknowledges the original packet.
+    let ack_events_b = relayer.relay_acknowledgements(recv_events_a).await?;
+    assert!(ack_events_b
+        .iter()
+        .any(|event| event.kind == "write_acknowledgement"));
+
+    // Relay that acknowledgement back to chain A.
+    relayer.reverse();
+    relayer.relay_acknowledgements(ack_events_b).await?;
+
+    // The tokens made their way back to chain A, and were never minted on chain B.
+    let chain_a_client = relayer.chain_a_ibc.client().await?;
+    let posttransfer_balance_a: Amount = chain_a_client
+        .spendable_notes_by_asset(chain_a_note.asset_id())
+        .map(|n| n.value().amount)
+        .sum();
+    assert_eq!(posttransfer_balance_a, pretransfer_balance_a);
+
+    let chain_b_client = relayer.chain_b_ibc.client().await?;
+    let posttransfer_balance_b: Amount = chain_b_client
+        .spendable_notes_by_asset(ibc_token.id())
+        .map(|n| n.value().amount)
+        .sum();
+    assert_eq!(posttransfer_balance_b, Amount::zero());
+
+    Ok(()).tap(|_| drop(relayer)).tap(|_| drop(guard))
+}
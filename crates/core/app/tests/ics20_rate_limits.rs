@@ -0,0 +1,179 @@
+use {
+    anyhow::anyhow,
+    common::ibc_tests::{MockRelayer, TestNodeWithIBC, ValidatorKeys},
+    ibc_types::core::{channel::ChannelId, host::identifier::PortId},
+    penumbra_app::genesis::Content,
+    penumbra_asset::{STAKING_TOKEN_ASSET_ID, STAKING_TOKEN_DENOM},
+    penumbra_ibc::{IbcToken, Ics20RateLimit},
+    penumbra_keys::{keys::AddressIndex, test_keys},
+    penumbra_num::Amount,
+    penumbra_shielded_pool::genesis::Allocation,
+    std::time::Duration,
+    tap::Tap as _,
+    tendermint::abci::Event,
+};
+
+mod common;
+
+/// Returns whether `events` contain an acknowledgement, and whether it is an error.
+fn acknowledgement(events: &[Event]) -> anyhow::Result<Option<bool>> {
+    let Some(event) = events
+        .iter()
+        .find(|event| event.kind == "write_acknowledgement")
+    else {
+        return Ok(None);
+    };
+    let ack = event
+        .attributes
+        .iter()
+        .find(|attr| attr.key == "packet_ack_hex")
+        .ok_or_else(|| anyhow!("acknowledgement event had no packet_ack_hex"))?;
+    let ack = String::from_utf8(hex::decode(&ack.value)?)?;
+    Ok(Some(ack.contains("error")))
+}
+
+/// Genesis content limiting transfers of `asset_id` over channel-0, in which the test wallet
+/// holds a single note of 1000 of the staking token, so that the transfers the relayer sends
+/// from it are of known amounts.
+fn content_with_rate_limit(
+    asset_id: penumbra_asset::asset::Id,
+    max_inflow: Amount,
+    max_outflow: Amount,
+) -> Content {
+    let mut content = Content {
+        shielded_pool_content: penumbra_shielded_pool::genesis::Content {
+            allocations: vec![Allocation {
+                raw_amount: 1000u128.into(),
+                raw_denom: STAKING_TOKEN_DENOM.base_denom().denom,
+                address: test_keys::ADDRESS_0.to_owned(),
+            }],
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    content.ibc_content.ibc_params.ics20_rate_limits = vec![Ics20RateLimit {
+        channel_id: ChannelId::new(0),
+        asset_id,
+        max_inflow,
+        max_outflow,
+        window_blocks: 1000,
+    }];
+    content
+}
+
+/// Exercises the ICS-20 rate limits through the transfer handlers: an inbound transfer over the
+/// limit is acknowledged with an error, an outbound transfer over the limit is rejected, and the
+/// refund of a transfer releases its share of the limit.
+#[tokio::test]
+async fn ics20_transfers_are_rate_limited() -> anyhow::Result<()> {
+    // Install a test logger, and acquire some temporary storage.
+    let guard = common::set_tracing_subscriber();
+
+    let block_duration = Duration::from_secs(5);
+    // Fixed start times (both chains start at the same time to avoid unintended timeouts):
+    let start_time_a = tendermint::Time::parse_from_rfc3339("2022-02-11T17:30:50.425417198Z")?;
+
+    // But chain B will be 39 blocks ahead of chain A, so offset chain A's
+    // start time so they match:
+    let start_time_b = start_time_a.checked_sub(39 * block_duration).unwrap();
+
+    // Hardcoded keys for each chain for test reproducibility:
+    let vkeys_a = ValidatorKeys::from_seed([0u8; 32]);
+    let vkeys_b = ValidatorKeys::from_seed([1u8; 32]);
+    let sk_a = vkeys_a.validator_cons_sk.ed25519_signing_key().unwrap();
+    let sk_b = vkeys_b.validator_cons_sk.ed25519_signing_key().unwrap();
+
+    let ska = ed25519_consensus::SigningKey::try_from(sk_a.as_bytes())?;
+    let skb = ed25519_consensus::SigningKey::try_from(sk_b.as_bytes())?;
+    let keys_a = (ska.clone(), ska.verification_key());
+    let keys_b = (skb.clone(), skb.verification_key());
+
+    // Chain A may send out at most 500 of the staking token, which is as much as its first
+    // transfer sends, and chain B may receive at most 250 of the voucher for it.
+    let voucher = IbcToken::new(
+        &ChannelId::new(0),
+        &PortId::transfer(),
+        &STAKING_TOKEN_DENOM.to_string(),
+    );
+    let content_a = content_with_rate_limit(*STAKING_TOKEN_ASSET_ID, Amount::zero(), 500u64.into());
+    let content_b = content_with_rate_limit(voucher.id(), 250u64.into(), Amount::zero());
+
+    // Set up some configuration for the two different chains we'll need to keep around.
+    let mut chain_a_ibc =
+        TestNodeWithIBC::new_with_genesis_content("a", start_time_a, keys_a, content_a).await?;
+    let mut chain_b_ibc =
+        TestNodeWithIBC::new_with_genesis_content("b", start_time_b, keys_b, content_b).await?;
+
+    // The two chains can't IBC handshake during the first block, let's fast forward
+    // them both a few.
+    for _ in 0..3 {
+        chain_a_ibc.node.block().execute().await?;
+    }
+    // Do them each a different # of blocks to make sure the heights don't get confused.
+    for _ in 0..42 {
+        chain_b_ibc.node.block().execute().await?;
+    }
+
+    let mut relayer = MockRelayer {
+        chain_a_ibc,
+        chain_b_ibc,
+    };
+    relayer.handshake().await?;
+
+    let receiver = relayer
+        .chain_b_ibc
+        .client()
+        .await?
+        .fvk
+        .payment_address(AddressIndex::new(0))
+        .0;
+    let sends_packet = |events: &[Event]| events.iter().any(|event| event.kind == "send_packet");
+    let staking_balance_a = |client: &penumbra_mock_client::MockClient| -> Amount {
+        client
+            .spendable_notes_by_asset(*STAKING_TOKEN_ASSET_ID)
+            .map(|n| n.value().amount)
+            .sum()
+    };
+    let pretransfer_balance_a = staking_balance_a(&relayer.chain_a_ibc.client().await?);
+
+    // Chain A's first transfer fits in its outbound limit, but not in chain B's inbound limit,
+    // so chain B acknowledges it with an error.
+    let send_events = relayer
+        .send_transfer_from_a(receiver.to_string(), String::new())
+        .await?;
+    assert!(sends_packet(&send_events));
+    let recv_events = relayer.relay_send_packets(send_events).await?;
+    assert_eq!(acknowledgement(&recv_events)?, Some(true));
+
+    // Until that transfer is refunded, another one would exceed chain A's outbound limit.
+    let send_events = relayer
+        .send_transfer_from_a(receiver.to_string(), String::new())
+        .await?;
+    assert!(!sends_packet(&send_events));
+
+    // The refund releases the first transfer's share of the outbound limit, so the same
+    // transfer then goes through, and fits in chain B's inbound limit.
+    relayer.relay_acknowledgements(recv_events).await?;
+    assert_eq!(
+        staking_balance_a(&relayer.chain_a_ibc.client().await?),
+        pretransfer_balance_a
+    );
+    let send_events = relayer
+        .send_transfer_from_a(receiver.to_string(), String::new())
+        .await?;
+    assert!(sends_packet(&send_events));
+    let recv_events = relayer.relay_send_packets(send_events).await?;
+    assert_eq!(acknowledgement(&recv_events)?, Some(false));
+    relayer.relay_acknowledgements(recv_events).await?;
+
+    let voucher_balance_b: Amount = relayer
+        .chain_b_ibc
+        .client()
+        .await?
+        .spendable_notes_by_asset(voucher.id())
+        .map(|n| n.value().amount)
+        .sum();
+    assert_eq!(voucher_balance_b, 250u64.into());
+
+    Ok(()).tap(|_| drop(relayer)).tap(|_| drop(guard))
+}
@@ -0,0 +1,269 @@
+use {
+    anyhow::anyhow,
+    common::ibc_tests::{MockRelayer, TestNodeWithIBC, ValidatorKeys},
+    penumbra_asset::{asset::Cache, Value, STAKING_TOKEN_ASSET_ID},
+    penumbra_dex::{
+        lp::{
+            position::{Position, State},
+            LpNft, Reserves,
+        },
+        DirectedTradingPair, PositionOpen,
+    },
+    penumbra_ibc::IbcToken,
+    penumbra_keys::keys::AddressIndex,
+    penumbra_num::Amount,
+    penumbra_shielded_pool::{Ics20SwapMemo, OutputPlan, SpendPlan},
+    penumbra_transaction::{
+        memo::MemoPlaintext, plan::MemoPlan, TransactionParameters, TransactionPlan,
+    },
+    prost::Message as _,
+    rand_core::SeedableRng as _,
+    std::time::Duration,
+    tap::Tap as _,
+    tendermint::abci::Event,
+};
+
+mod common;
+
+/// Returns whether `events` contain an acknowledgement, and whether it is an error.
+fn acknowledgement(events: &[Event]) -> anyhow::Result<Option<bool>> {
+    let Some(event) = events
+        .iter()
+        .find(|event| event.kind == "write_acknowledgement")
+    else {
+        return Ok(None);
+    };
+    let ack = event
+        .attributes
+        .iter()
+        .find(|attr| attr.key == "packet_ack_hex")
+        .ok_or_else(|| anyhow!("acknowledgement event had no packet_ack_hex"))?;
+    let ack = String::from_utf8(hex::decode(&ack.value)?)?;
+    Ok(Some(ack.contains("error")))
+}
+
+/// The asset chain A's next transfer sends, and the voucher chain B mints for it.
+async fn next_transfer_asset(
+    relayer: &mut MockRelayer,
+) -> anyhow::Result<(penumbra_asset::asset::Id, IbcToken)> {
+    let chain_a_client = relayer.chain_a_ibc.client().await?;
+    let note = chain_a_client
+        .notes
+        .values()
+        .find(|note| !chain_a_client.spent_note(&note.commit()))
+        .cloned()
+        .ok_or_else(|| anyhow!("mock client had no unspent note"))?;
+    let denom = Cache::with_known_assets()
+        .get(&note.asset_id())
+        .expect("asset ID should exist in asset cache")
+        .clone();
+    let ibc_token = IbcToken::new(
+        &relayer.chain_b_ibc.channel_id,
+        &relayer.chain_b_ibc.port_id,
+        &denom.to_string(),
+    );
+    Ok((note.asset_id(), ibc_token))
+}
+
+/// Exercises that inbound transfers asking to be swapped on receipt are swapped when the batch
+/// meets their minimum output, and otherwise acknowledged with an error, which refunds the sender.
+#[tokio::test]
+async fn ics20_swaps_are_admitted_or_refunded() -> anyhow::Result<()> {
+    // Install a test logger, and acquire some temporary storage.
+    let guard = common::set_tracing_subscriber();
+
+    let block_duration = Duration::from_secs(5);
+    // Fixed start times (both chains start at the same time to avoid unintended timeouts):
+    let start_time_a = tendermint::Time::parse_from_rfc3339("2022-02-11T17:30:50.425417198Z")?;
+
+    // But chain B will be 39 blocks ahead of chain A, so offset chain A's
+    // start time so they match:
+    let start_time_b = start_time_a.checked_sub(39 * block_duration).unwrap();
+
+    // Hardcoded keys for each chain for test reproducibility:
+    let vkeys_a = ValidatorKeys::from_seed([0u8; 32]);
+    let vkeys_b = ValidatorKeys::from_seed([1u8; 32]);
+    let sk_a = vkeys_a.validator_cons_sk.ed25519_signing_key().unwrap();
+    let sk_b = vkeys_b.validator_cons_sk.ed25519_signing_key().unwrap();
+
+    let ska = ed25519_consensus::SigningKey::try_from(sk_a.as_bytes())?;
+    let skb = ed25519_consensus::SigningKey::try_from(sk_b.as_bytes())?;
+    let keys_a = (ska.clone(), ska.verification_key());
+    let keys_b = (skb.clone(), skb.verification_key());
+
+    // Set up some configuration for the two different chains we'll need to keep around.
+    let mut chain_a_ibc = TestNodeWithIBC::new("a", start_time_a, keys_a).await?;
+    let mut chain_b_ibc = TestNodeWithIBC::new("b", start_time_b, keys_b).await?;
+
+    // The two chains can't IBC handshake during the first block, let's fast forward
+    // them both a few.
+    for _ in 0..3 {
+        chain_a_ibc.node.block().execute().await?;
+    }
+    // Do them each a different # of blocks to make sure the heights don't get confused.
+    for _ in 0..42 {
+        chain_b_ibc.node.block().execute().await?;
+    }
+
+    let mut relayer = MockRelayer {
+        chain_a_ibc,
+        chain_b_ibc,
+    };
+    relayer.handshake().await?;
+
+    // Open a position on chain B that sells the staking token for the voucher of the asset
+    // chain A's first transfer sends, one for one.
+    let (_, ibc_token) = next_transfer_asset(&mut relayer).await?;
+    let chain_b_client = relayer.chain_b_ibc.client().await?;
+    let staking_note = chain_b_client
+        .spendable_notes_by_asset(*STAKING_TOKEN_ASSET_ID)
+        .next()
+        .cloned()
+        .ok_or_else(|| anyhow!("mock client had no staking token note"))?;
+    let reserves = Amount::from(staking_note.amount().value() / 2);
+    let position = Position::new(
+        rand_chacha::ChaChaRng::seed_from_u64(1312),
+        DirectedTradingPair::new(*STAKING_TOKEN_ASSET_ID, ibc_token.id()),
+        0,
+        1u64.into(),
+        1u64.into(),
+        Reserves {
+            r1: reserves,
+            r2: Amount::zero(),
+        },
+    );
+    let address_b = chain_b_client.fvk.payment_address(AddressIndex::new(0)).0;
+    let plan = TransactionPlan {
+        actions: vec![
+            SpendPlan::new(
+                &mut rand_chacha::ChaChaRng::seed_from_u64(1312),
+                staking_note.clone(),
+                chain_b_client
+                    .position(staking_note.commit())
+                    .expect("note should be in mock client's tree"),
+            )
+            .into(),
+            PositionOpen {
+                position: position.clone(),
+            }
+            .into(),
+            OutputPlan::new(
+                &mut rand_chacha::ChaChaRng::seed_from_u64(1312),
+                Value {
+                    amount: staking_note.amount() - reserves,
+                    asset_id: *STAKING_TOKEN_ASSET_ID,
+                },
+                address_b.clone(),
+            )
+            .into(),
+            OutputPlan::new(
+                &mut rand_chacha::ChaChaRng::seed_from_u64(1312),
+                Value {
+                    amount: 1u64.into(),
+                    asset_id: LpNft::new(position.id(), State::Opened).asset_id(),
+                },
+                address_b.clone(),
+            )
+            .into(),
+        ],
+        memo: Some(MemoPlan::new(
+            &mut rand_chacha::ChaChaRng::seed_from_u64(1312),
+            MemoPlaintext::blank_memo(address_b),
+        )),
+        detection_data: None, // We'll set this automatically below
+        transaction_parameters: TransactionParameters {
+            chain_id: relayer.chain_b_ibc.chain_id.clone(),
+            ..Default::default()
+        },
+    }
+    .with_populated_detection_data(
+        rand_chacha::ChaChaRng::seed_from_u64(1312),
+        Default::default(),
+    );
+    let tx = chain_b_client.witness_auth_build(&plan).await?;
+    relayer
+        .chain_b_ibc
+        .node
+        .block()
+        .with_data(vec![tx.encode_to_vec()])
+        .execute()
+        .await?;
+
+    let staking_balance_b = |client: &penumbra_mock_client::MockClient| -> Amount {
+        client
+            .spendable_notes_by_asset(*STAKING_TOKEN_ASSET_ID)
+            .map(|n| n.value().amount)
+            .sum()
+    };
+    let pretransfer_staking_b = staking_balance_b(&relayer.chain_b_ibc.client().await?);
+
+    // A transfer the position can fill is swapped, and acknowledged once it has been.
+    let receiver = relayer
+        .chain_b_ibc
+        .client()
+        .await?
+        .fvk
+        .payment_address(AddressIndex::new(0))
+        .0;
+    let swap = Ics20SwapMemo {
+        asset_out: STAKING_TOKEN_ASSET_ID.to_string(),
+        min_output: "1".to_string(),
+    };
+    let send_events = relayer
+        .send_transfer_from_a(receiver.to_string(), swap.to_memo())
+        .await?;
+    let recv_events = relayer.relay_send_packets(send_events).await?;
+    assert_eq!(acknowledgement(&recv_events)?, Some(false));
+    relayer.relay_acknowledgements(recv_events).await?;
+
+    let chain_b_client = relayer.chain_b_ibc.client().await?;
+    assert!(staking_balance_b(&chain_b_client) > pretransfer_staking_b);
+    let filled = staking_balance_b(&chain_b_client) - pretransfer_staking_b;
+    assert!(filled <= reserves);
+
+    // A transfer whose minimum output no batch could meet is refunded to its sender.
+    let (asset_a, ibc_token) = next_transfer_asset(&mut relayer).await?;
+    let pretransfer_balance_a: Amount = relayer
+        .chain_a_ibc
+        .client()
+        .await?
+        .spendable_notes_by_asset(asset_a)
+        .map(|n| n.value().amount)
+        .sum();
+    let pretransfer_voucher_b: Amount = chain_b_client
+        .spendable_notes_by_asset(ibc_token.id())
+        .map(|n| n.value().amount)
+        .sum();
+    let swap = Ics20SwapMemo {
+        asset_out: STAKING_TOKEN_ASSET_ID.to_string(),
+        min_output: u64::MAX.to_string(),
+    };
+    let send_events = relayer
+        .send_transfer_from_a(receiver.to_string(), swap.to_memo())
+        .await?;
+    let recv_events = relayer.relay_send_packets(send_events).await?;
+    assert_eq!(acknowledgement(&recv_events)?, Some(true));
+    relayer.relay_acknowledgements(recv_events).await?;
+
+    let posttransfer_balance_a: Amount = relayer
+        .chain_a_ibc
+        .client()
+        .await?
+        .spendable_notes_by_asset(asset_a)
+        .map(|n| n.value().amount)
+        .sum();
+    assert_eq!(posttransfer_balance_a, pretransfer_balance_a);
+
+    let chain_b_client = relayer.chain_b_ibc.client().await?;
+    let posttransfer_voucher_b: Amount = chain_b_client
+        .spendable_notes_by_asset(ibc_token.id())
+        .map(|n| n.value().amount)
+        .sum();
+    assert_eq!(posttransfer_voucher_b, pretransfer_voucher_b);
+    assert_eq!(
+        staking_balance_b(&chain_b_client),
+        pretransfer_staking_b + filled
+    );
+
+    Ok(()).tap(|_| drop(relayer)).tap(|_| drop(guard))
+}
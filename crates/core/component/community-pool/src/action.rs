@@ -1,7 +1,9 @@
 mod community_pool_deposit;
 mod community_pool_output;
 mod community_pool_spend;
+mod community_pool_vesting_claim;
 
 pub use community_pool_deposit::CommunityPoolDeposit;
 pub use community_pool_output::CommunityPoolOutput;
 pub use community_pool_spend::CommunityPoolSpend;
+pub use community_pool_vesting_claim::CommunityPoolVestingClaim;
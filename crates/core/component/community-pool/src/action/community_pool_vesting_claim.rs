@@ -0,0 +1,66 @@
+use anyhow::Error;
+use serde::{Deserialize, Serialize};
+use std::convert::{TryFrom, TryInto};
+
+use penumbra_asset::Balance;
+use penumbra_num::Amount;
+use penumbra_proto::{penumbra::core::component::governance::v1 as pb, DomainType};
+use penumbra_txhash::{EffectHash, EffectingData};
+
+/// Claims funds which have vested from a Community Pool vesting grant.
+///
+/// The claimed funds are always paid to the recipient of the grant, so anyone may submit a claim.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(
+    try_from = "pb::CommunityPoolVestingClaim",
+    into = "pb::CommunityPoolVestingClaim"
+)]
+pub struct CommunityPoolVestingClaim {
+    /// The ID of the grant to claim from.
+    pub grant_id: u64,
+    /// The amount to claim, of the grant's asset.
+    pub amount: Amount,
+}
+
+impl CommunityPoolVestingClaim {
+    pub fn balance(&self) -> Balance {
+        // The claimed funds are minted directly to the recipient, so they don't
+        // contribute to the transaction's balance.
+        Balance::zero()
+    }
+}
+
+impl EffectingData for CommunityPoolVestingClaim {
+    fn effect_hash(&self) -> EffectHash {
+        EffectHash::from_proto_effecting_data(&self.to_proto())
+    }
+}
+
+impl DomainType for CommunityPoolVestingClaim {
+    type Proto = pb::CommunityPoolVestingClaim;
+}
+
+impl From<CommunityPoolVestingClaim> for pb::CommunityPoolVestingClaim {
+    fn from(msg: CommunityPoolVestingClaim) -> Self {
+        pb::CommunityPoolVestingClaim {
+            grant_id: msg.grant_id,
+            amount: Some(msg.amount.into()),
+        }
+    }
+}
+
+impl TryFrom<pb::CommunityPoolVestingClaim> for CommunityPoolVestingClaim {
+    type Error = Error;
+
+    fn try_from(proto: pb::CommunityPoolVestingClaim) -> anyhow::Result<Self, Self::Error> {
+        let amount = proto
+            .amount
+            .ok_or_else(|| anyhow::anyhow!("missing amount"))?
+            .try_into()?;
+
+        Ok(CommunityPoolVestingClaim {
+            grant_id: proto.grant_id,
+            amount,
+        })
+    }
+}
@@ -4,6 +4,7 @@
 pub mod state_key;
 
 mod action_handler;
+pub mod rpc;
 mod view;
 
 use std::sync::Arc;
@@ -1,3 +1,4 @@
 mod community_pool_deposit;
 mod community_pool_output;
 mod community_pool_spend;
+mod community_pool_vesting_claim;
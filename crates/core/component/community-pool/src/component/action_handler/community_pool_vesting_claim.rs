@@ -0,0 +1,55 @@
+use anyhow::Result;
+use async_trait::async_trait;
+use cnidarium::StateWrite;
+use cnidarium_component::ActionHandler;
+use penumbra_asset::Value;
+use penumbra_num::Amount;
+use penumbra_sct::{component::clock::EpochRead, CommitmentSource};
+use penumbra_shielded_pool::component::NoteManager;
+
+use crate::{
+    component::{StateReadExt as _, StateWriteExt as _},
+    CommunityPoolVestingClaim,
+};
+
+#[async_trait]
+impl ActionHandler for CommunityPoolVestingClaim {
+    type CheckStatelessContext = ();
+    async fn check_stateless(&self, _context: ()) -> Result<()> {
+        anyhow::ensure!(
+            self.amount > Amount::zero(),
+            "vesting claims must claim a nonzero amount"
+        );
+        Ok(())
+    }
+
+    async fn check_and_execute<S: StateWrite>(&self, mut state: S) -> Result<()> {
+        let Some(mut grant) = state.vesting_grant(self.grant_id).await? else {
+            anyhow::bail!("vesting grant {} does not exist", self.grant_id);
+        };
+
+        let current_epoch = state.get_current_epoch().await?;
+        let claimable = grant.claimable(current_epoch.index);
+        anyhow::ensure!(
+            self.amount <= claimable,
+            "cannot claim {} from vesting grant {}, only {} is claimable",
+            self.amount,
+            self.grant_id,
+            claimable,
+        );
+
+        grant.claimed += self.amount;
+        let value = Value {
+            amount: self.amount,
+            asset_id: grant.total.asset_id,
+        };
+        let recipient = grant.recipient.clone();
+        state.put_vesting_grant(grant);
+
+        // The funds were set aside from the Community Pool when the grant was created, so
+        // claiming them is just minting a note to the recipient of the grant.
+        state
+            .mint_note(value, &recipient, CommitmentSource::CommunityPoolOutput)
+            .await
+    }
+}
@@ -0,0 +1,104 @@
+use std::pin::Pin;
+
+use async_trait::async_trait;
+use cnidarium::Storage;
+use futures::StreamExt;
+use penumbra_asset::{asset, Value};
+use penumbra_proto::core::component::community_pool::v1::{
+    self as pb, query_service_server::QueryService,
+};
+use penumbra_sct::component::clock::EpochRead;
+
+use super::StateReadExt;
+
+// TODO: Hide this and only expose a Router?
+pub struct Server {
+    storage: Storage,
+}
+
+impl Server {
+    pub fn new(storage: Storage) -> Self {
+        Self { storage }
+    }
+}
+
+#[async_trait]
+impl QueryService for Server {
+    type CommunityPoolAssetBalancesStream = Pin<
+        Box<
+            dyn futures::Stream<
+                    Item = Result<pb::CommunityPoolAssetBalancesResponse, tonic::Status>,
+                > + Send,
+        >,
+    >;
+
+    async fn community_pool_asset_balances(
+        &self,
+        request: tonic::Request<pb::CommunityPoolAssetBalancesRequest>,
+    ) -> Result<tonic::Response<Self::CommunityPoolAssetBalancesStream>, tonic::Status> {
+        let state = self.storage.latest_snapshot();
+
+        let asset_ids = request
+            .into_inner()
+            .asset_ids
+            .into_iter()
+            .map(asset::Id::try_from)
+            .collect::<anyhow::Result<Vec<_>>>()
+            .map_err(|e| tonic::Status::invalid_argument(format!("invalid asset id: {e}")))?;
+
+        let balances = state
+            .community_pool_balance()
+            .await
+            .map_err(|e| tonic::Status::internal(e.to_string()))?;
+
+        let responses = balances
+            .into_iter()
+            .filter(|(asset_id, _)| asset_ids.is_empty() || asset_ids.contains(asset_id))
+            .map(|(asset_id, amount)| {
+                Ok(pb::CommunityPoolAssetBalancesResponse {
+                    balance: Some(Value { amount, asset_id }.into()),
+                })
+            })
+            .collect::<Vec<_>>();
+
+        Ok(tonic::Response::new(
+            futures::stream::iter(responses).boxed(),
+        ))
+    }
+
+    type VestingGrantsStream = Pin<
+        Box<dyn futures::Stream<Item = Result<pb::VestingGrantsResponse, tonic::Status>> + Send>,
+    >;
+
+    async fn vesting_grants(
+        &self,
+        request: tonic::Request<pb::VestingGrantsRequest>,
+    ) -> Result<tonic::Response<Self::VestingGrantsStream>, tonic::Status> {
+        let state = self.storage.latest_snapshot();
+        let grant_ids = request.into_inner().grant_ids;
+
+        let current_epoch = state
+            .get_current_epoch()
+            .await
+            .map_err(|e| tonic::Status::internal(e.to_string()))?;
+        let grants = state
+            .vesting_grants()
+            .await
+            .map_err(|e| tonic::Status::internal(e.to_string()))?;
+
+        let responses = grants
+            .into_iter()
+            .filter(|grant| grant_ids.is_empty() || grant_ids.contains(&grant.id))
+            .map(|grant| {
+                Ok(pb::VestingGrantsResponse {
+                    claimable: Some(grant.claimable(current_epoch.index).into()),
+                    grant: Some(grant.into()),
+                })
+            })
+            .collect::<Vec<_>>();
+
+        Ok(tonic::Response::new(
+            futures::stream::iter(responses).boxed(),
+        ))
+    }
+}
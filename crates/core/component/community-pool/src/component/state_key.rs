@@ -12,3 +12,12 @@ pub fn all_assets_balance() -> &'static str {
     // note: this must be the prefix of the above.
     "community_pool/asset/"
 }
+
+pub fn vesting_grant(grant_id: u64) -> String {
+    format!("community_pool/vesting_grant/{grant_id:020}")
+}
+
+pub fn all_vesting_grants() -> &'static str {
+    // note: this must be the prefix of the above.
+    "community_pool/vesting_grant/"
+}
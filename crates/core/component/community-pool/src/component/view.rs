@@ -6,10 +6,11 @@ use async_trait::async_trait;
 use cnidarium::{StateRead, StateWrite};
 use futures::{StreamExt, TryStreamExt};
 use penumbra_asset::{asset, Value};
+use penumbra_keys::Address;
 use penumbra_num::Amount;
 use penumbra_proto::{StateReadProto, StateWriteProto};
 
-use crate::params::CommunityPoolParameters;
+use crate::{params::CommunityPoolParameters, VestingGrant};
 
 use super::state_key;
 
@@ -41,6 +42,19 @@ pub trait StateReadExt: StateRead {
             .try_collect()
             .await
     }
+
+    /// Gets the vesting grant with the given ID, if it exists.
+    async fn vesting_grant(&self, grant_id: u64) -> Result<Option<VestingGrant>> {
+        self.get(&state_key::vesting_grant(grant_id)).await
+    }
+
+    /// Gets all of the vesting grants, including cancelled and fully claimed ones, ordered by ID.
+    async fn vesting_grants(&self) -> Result<Vec<VestingGrant>> {
+        self.prefix(state_key::all_vesting_grants())
+            .map(|result| Ok(result?.1))
+            .try_collect()
+            .await
+    }
 }
 
 impl<T> StateReadExt for T where T: StateRead + ?Sized {}
@@ -80,6 +94,70 @@ pub trait StateWriteExt: StateWrite {
         }
         Ok(())
     }
+
+    fn put_vesting_grant(&mut self, grant: VestingGrant) {
+        self.put(state_key::vesting_grant(grant.id), grant)
+    }
+
+    /// Creates a vesting grant, setting aside its total value from the Community Pool.
+    ///
+    /// This fails if the Community Pool can't fund the grant.
+    async fn create_vesting_grant(
+        &mut self,
+        grant_id: u64,
+        recipient: Address,
+        total: Value,
+        cliff_epochs: u64,
+        release_per_epoch: Amount,
+        current_epoch_index: u64,
+    ) -> Result<()> {
+        if self.vesting_grant(grant_id).await?.is_some() {
+            anyhow::bail!("vesting grant {grant_id} already exists");
+        }
+        self.community_pool_withdraw(total).await?;
+        self.put_vesting_grant(VestingGrant {
+            id: grant_id,
+            recipient,
+            total,
+            release_per_epoch,
+            start_epoch_index: current_epoch_index,
+            cliff_epoch_index: current_epoch_index.saturating_add(cliff_epochs),
+            claimed: Amount::zero(),
+            cancelled: false,
+        });
+        Ok(())
+    }
+
+    /// Cancels a vesting grant, returning the funds which have not vested to the Community Pool.
+    ///
+    /// The funds which have vested remain claimable by the recipient.
+    async fn cancel_vesting_grant(
+        &mut self,
+        grant_id: u64,
+        current_epoch_index: u64,
+    ) -> Result<()> {
+        let Some(mut grant) = self.vesting_grant(grant_id).await? else {
+            anyhow::bail!("vesting grant {grant_id} does not exist");
+        };
+        if grant.cancelled {
+            anyhow::bail!("vesting grant {grant_id} was already cancelled");
+        }
+
+        let vested = grant.vested(current_epoch_index);
+        let unvested = grant.total.amount.saturating_sub(&vested);
+        if unvested > Amount::zero() {
+            self.community_pool_deposit(Value {
+                amount: unvested,
+                asset_id: grant.total.asset_id,
+            })
+            .await;
+        }
+
+        grant.total.amount = vested;
+        grant.cancelled = true;
+        self.put_vesting_grant(grant);
+        Ok(())
+    }
 }
 
 impl<T> StateWriteExt for T where T: StateWrite + ?Sized {}
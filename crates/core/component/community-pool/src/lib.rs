@@ -6,7 +6,12 @@ pub mod component;
 pub mod event;
 
 mod action;
-pub use action::{CommunityPoolDeposit, CommunityPoolOutput, CommunityPoolSpend};
+pub use action::{
+    CommunityPoolDeposit, CommunityPoolOutput, CommunityPoolSpend, CommunityPoolVestingClaim,
+};
+
+mod vesting_grant;
+pub use vesting_grant::VestingGrant;
 
 pub mod genesis;
 pub mod params;
@@ -0,0 +1,113 @@
+use anyhow::Context;
+use penumbra_asset::Value;
+use penumbra_keys::Address;
+use penumbra_num::Amount;
+use penumbra_proto::{core::component::community_pool::v1 as pb, DomainType};
+use serde::{Deserialize, Serialize};
+
+/// A vesting schedule releasing funds set aside from the Community Pool to a recipient.
+///
+/// Grants are created by passed `CommunityPoolVestingGrant` proposals, and are identified by the
+/// ID of the proposal that created them.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(try_from = "pb::VestingGrant", into = "pb::VestingGrant")]
+pub struct VestingGrant {
+    /// The ID of the grant, which is the ID of the proposal that created it.
+    pub id: u64,
+    /// The address that vested funds are paid to.
+    pub recipient: Address,
+    /// The total value of the grant.
+    ///
+    /// If the grant was cancelled, this is the value which had vested at the time.
+    pub total: Value,
+    /// The amount that vests in each epoch since the start epoch, once the cliff has passed.
+    pub release_per_epoch: Amount,
+    /// The epoch in which the grant was created.
+    pub start_epoch_index: u64,
+    /// The first epoch in which funds vest.
+    pub cliff_epoch_index: u64,
+    /// The amount claimed by the recipient so far.
+    pub claimed: Amount,
+    /// Whether the grant was cancelled by a later proposal.
+    pub cancelled: bool,
+}
+
+impl VestingGrant {
+    /// The amount of the grant which has vested as of the given epoch.
+    pub fn vested(&self, epoch_index: u64) -> Amount {
+        if self.cancelled {
+            // Cancelling a grant reduces its total to the amount which had vested.
+            return self.total.amount;
+        }
+        if epoch_index < self.cliff_epoch_index {
+            return Amount::zero();
+        }
+
+        let epochs = epoch_index.saturating_sub(self.start_epoch_index);
+        let released = self
+            .release_per_epoch
+            .value()
+            .saturating_mul(u128::from(epochs));
+        self.total.amount.min(released.into())
+    }
+
+    /// The amount of the grant which has vested as of the given epoch, but has not been claimed.
+    pub fn claimable(&self, epoch_index: u64) -> Amount {
+        self.vested(epoch_index).saturating_sub(&self.claimed)
+    }
+
+    /// Whether every part of the grant has been claimed.
+    pub fn is_exhausted(&self) -> bool {
+        self.claimed >= self.total.amount
+    }
+}
+
+impl DomainType for VestingGrant {
+    type Proto = pb::VestingGrant;
+}
+
+impl From<VestingGrant> for pb::VestingGrant {
+    fn from(grant: VestingGrant) -> Self {
+        pb::VestingGrant {
+            id: grant.id,
+            recipient: Some(grant.recipient.into()),
+            total: Some(grant.total.into()),
+            release_per_epoch: Some(grant.release_per_epoch.into()),
+            start_epoch_index: grant.start_epoch_index,
+            cliff_epoch_index: grant.cliff_epoch_index,
+            claimed: Some(grant.claimed.into()),
+            cancelled: grant.cancelled,
+        }
+    }
+}
+
+impl TryFrom<pb::VestingGrant> for VestingGrant {
+    type Error = anyhow::Error;
+
+    fn try_from(msg: pb::VestingGrant) -> anyhow::Result<Self> {
+        Ok(VestingGrant {
+            id: msg.id,
+            recipient: msg
+                .recipient
+                .ok_or_else(|| anyhow::anyhow!("missing recipient"))?
+                .try_into()
+                .context("malformed recipient")?,
+            total: msg
+                .total
+                .ok_or_else(|| anyhow::anyhow!("missing total"))?
+                .try_into()
+                .context("malformed total")?,
+            release_per_epoch: msg
+                .release_per_epoch
+                .ok_or_else(|| anyhow::anyhow!("missing release per epoch"))?
+                .try_into()?,
+            start_epoch_index: msg.start_epoch_index,
+            cliff_epoch_index: msg.cliff_epoch_index,
+            claimed: msg
+                .claimed
+                .ok_or_else(|| anyhow::anyhow!("missing claimed amount"))?
+                .try_into()?,
+            cancelled: msg.cancelled,
+        })
+    }
+}
@@ -1,7 +1,9 @@
 use std::{collections::BTreeMap, convert::TryFrom};
 
 use anyhow::Result;
-use penumbra_dex::{BatchSwapOutputData, TradingPair};
+use penumbra_dex::{
+    event::EventPositionEvicted, lp::PositionExecutionSummary, BatchSwapOutputData, TradingPair,
+};
 use penumbra_fee::GasPrices;
 use penumbra_proto::{
     core::component::compact_block::v1::CompactBlockRangeResponse,
@@ -42,6 +44,10 @@ pub struct CompactBlock {
     pub alt_gas_prices: Vec<GasPrices>,
     // The epoch index
     pub epoch_index: u64,
+    /// Executions against liquidity positions in this block, summarized per position.
+    pub position_executions: Vec<PositionExecutionSummary>,
+    /// Positions evicted by the DEX at the end of this block.
+    pub evicted_positions: Vec<EventPositionEvicted>,
     // **IMPORTANT NOTE FOR FUTURE HUMANS**: if you want to add new fields to the `CompactBlock`,
     // you must update `CompactBlock::requires_scanning` to check for the emptiness of those fields,
     // because the client will skip processing any compact block that is marked as not requiring
@@ -63,6 +69,8 @@ impl Default for CompactBlock {
             gas_prices: None,
             alt_gas_prices: Vec::new(),
             epoch_index: 0,
+            position_executions: Vec::new(),
+            evicted_positions: Vec::new(),
         }
     }
 }
@@ -77,6 +85,8 @@ impl CompactBlock {
             || self.app_parameters_updated // need to save latest app parameters
             || self.gas_prices.is_some() // need to save latest gas prices
             || !self.alt_gas_prices.is_empty() // need to save latest alt gas prices
+            || !self.position_executions.is_empty() // need to record executions of owned positions
+            || !self.evicted_positions.is_empty() // need to close evicted owned positions
     }
 }
 
@@ -104,6 +114,8 @@ impl From<CompactBlock> for pb::CompactBlock {
             gas_prices: cb.gas_prices.map(Into::into),
             alt_gas_prices: cb.alt_gas_prices.into_iter().map(Into::into).collect(),
             epoch_index: cb.epoch_index,
+            position_executions: cb.position_executions.into_iter().map(Into::into).collect(),
+            evicted_positions: cb.evicted_positions.into_iter().map(Into::into).collect(),
         }
     }
 }
@@ -147,6 +159,16 @@ impl TryFrom<pb::CompactBlock> for CompactBlock {
                 .map(GasPrices::try_from)
                 .collect::<Result<Vec<GasPrices>>>()?,
             epoch_index: value.epoch_index,
+            position_executions: value
+                .position_executions
+                .into_iter()
+                .map(PositionExecutionSummary::try_from)
+                .collect::<Result<Vec<PositionExecutionSummary>>>()?,
+            evicted_positions: value
+                .evicted_positions
+                .into_iter()
+                .map(EventPositionEvicted::try_from)
+                .collect::<Result<Vec<EventPositionEvicted>>>()?,
         })
     }
 }
@@ -2,7 +2,7 @@ use anyhow::{Context, Result};
 use async_trait::async_trait;
 use cnidarium::StateWrite;
 #[cfg(feature = "component")]
-use penumbra_dex::component::SwapDataRead;
+use penumbra_dex::component::{PositionRead, SwapDataRead};
 use penumbra_fee::component::StateReadExt as _;
 use penumbra_governance::StateReadExt as _;
 use penumbra_proto::DomainType;
@@ -117,6 +117,16 @@ trait Inner: StateWrite {
         // Gather the swap outputs
         let swap_outputs = self.pending_batch_swap_outputs().into_iter().collect();
 
+        // Gather the summarized executions against liquidity positions
+        let position_executions = self
+            .pending_position_executions()
+            .into_iter()
+            .map(|(_, execution)| execution)
+            .collect();
+
+        // Gather the positions evicted by the DEX
+        let evicted_positions = self.pending_evicted_positions().into_iter().collect();
+
         // Add all the pending nullifiers to the compact block
         let nullifiers = self.pending_nullifiers().into_iter().collect();
 
@@ -140,6 +150,8 @@ trait Inner: StateWrite {
             gas_prices,
             alt_gas_prices,
             epoch_index,
+            position_executions,
+            evicted_positions,
         };
 
         self.nonverifiable_put_raw(
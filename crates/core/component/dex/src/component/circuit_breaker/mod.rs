@@ -1,6 +1,8 @@
 pub(crate) mod execution;
+pub(crate) mod trip;
 pub(crate) mod value;
 
 pub(crate) use execution::ExecutionCircuitBreaker;
+pub use trip::{CircuitBreakerTripRead, CircuitBreakerTripRecorder, ExecutionBudgetUsage};
 pub(crate) use value::ValueCircuitBreaker;
-pub use value::ValueCircuitBreakerRead;
+pub use value::{ValueCircuitBreakerRead, ValueCircuitBreakerTripped};
@@ -0,0 +1,262 @@
+use anyhow::{anyhow, Context as _, Result};
+use cnidarium::{StateRead, StateWrite};
+use futures::{StreamExt, TryStreamExt};
+use penumbra_proto::{core::component::dex::v1 as pb, DomainType, StateReadProto, StateWriteProto};
+use penumbra_sct::component::clock::EpochRead as _;
+use tonic::async_trait;
+
+use crate::{
+    event::{CircuitBreakerTrip, EventCircuitBreakerTrip},
+    state_key, DirectedTradingPair,
+};
+
+/// The execution budget consumed by a route-and-fill in a given direction.
+#[derive(Clone, Debug)]
+pub struct ExecutionBudgetUsage {
+    pub trading_pair: DirectedTradingPair,
+    /// The height at which the route-and-fill was performed.
+    pub height: u64,
+    /// The number of path searches and executions performed.
+    pub used: u32,
+}
+
+impl DomainType for ExecutionBudgetUsage {
+    type Proto = pb::circuit_breaker_status_response::ExecutionBudgetUsage;
+}
+
+impl From<ExecutionBudgetUsage> for pb::circuit_breaker_status_response::ExecutionBudgetUsage {
+    fn from(value: ExecutionBudgetUsage) -> Self {
+        Self {
+            trading_pair: Some(value.trading_pair.into()),
+            height: value.height,
+            used: value.used,
+        }
+    }
+}
+
+impl TryFrom<pb::circuit_breaker_status_response::ExecutionBudgetUsage> for ExecutionBudgetUsage {
+    type Error = anyhow::Error;
+
+    fn try_from(
+        value: pb::circuit_breaker_status_response::ExecutionBudgetUsage,
+    ) -> Result<Self, Self::Error> {
+        Ok(Self {
+            trading_pair: value
+                .trading_pair
+                .ok_or_else(|| anyhow!("missing trading pair"))?
+                .try_into()?,
+            height: value.height,
+            used: value.used,
+        })
+    }
+}
+
+#[async_trait]
+pub trait CircuitBreakerTripRead: StateRead {
+    /// Fetch up to `limit` circuit breaker trips recorded at or after `start_height`.
+    async fn circuit_breaker_trips(
+        &self,
+        start_height: u64,
+        limit: usize,
+    ) -> Result<Vec<EventCircuitBreakerTrip>> {
+        let prefix = state_key::circuit_breaker::trips();
+        let start_height_key = format!("{:020}", start_height).as_bytes().to_vec();
+
+        let range = self
+            .nonverifiable_range_raw(Some(prefix.as_bytes()), start_height_key..)
+            .context("error forming range query")?;
+
+        range
+            .take(limit)
+            .and_then(|(_k, v)| async move {
+                EventCircuitBreakerTrip::decode(v.as_ref())
+                    .context("error deserializing circuit breaker trip")
+            })
+            .try_collect()
+            .await
+    }
+
+    /// Fetch the execution budget used by the most recent route-and-fill in each direction.
+    async fn execution_budget_usage(&self) -> Result<Vec<ExecutionBudgetUsage>> {
+        self.nonverifiable_prefix_raw(
+            state_key::circuit_breaker::execution_budget_usage().as_bytes(),
+        )
+        .and_then(|(_k, v)| async move {
+            ExecutionBudgetUsage::decode(v.as_ref())
+                .context("error deserializing execution budget usage")
+        })
+        .try_collect()
+        .await
+    }
+
+    /// Fetch the circuit breaker trips recorded during this block.
+    fn pending_circuit_breaker_trips(&self) -> im::Vector<EventCircuitBreakerTrip> {
+        self.object_get(state_key::pending_circuit_breaker_trips())
+            .unwrap_or_default()
+    }
+}
+
+impl<T: StateRead + ?Sized> CircuitBreakerTripRead for T {}
+
+/// Records circuit breaker activity, so that it can be observed by clients.
+#[async_trait]
+pub trait CircuitBreakerTripRecorder: StateWrite {
+    /// Records a circuit breaker trip at the current height.
+    ///
+    /// The trip is indexed in the nonverifiable storage, and emitted as an
+    /// event at the end of the block.
+    async fn record_circuit_breaker_trip(&mut self, trip: CircuitBreakerTrip) -> Result<()> {
+        let height = self.get_block_height().await?;
+        let mut pending = self.pending_circuit_breaker_trips();
+        let event = EventCircuitBreakerTrip { height, trip };
+
+        tracing::debug!(?event, "circuit breaker tripped");
+        self.nonverifiable_put(
+            state_key::circuit_breaker::trip(height, pending.len()).into_bytes(),
+            event.clone(),
+        );
+        pending.push_back(event);
+        self.object_put(state_key::pending_circuit_breaker_trips(), pending);
+        Ok(())
+    }
+
+    /// Records the execution budget used by a route-and-fill at the current height.
+    async fn record_execution_budget_usage(
+        &mut self,
+        trading_pair: DirectedTradingPair,
+        used: u32,
+    ) -> Result<()> {
+        let height = self.get_block_height().await?;
+        self.nonverifiable_put(
+            state_key::circuit_breaker::execution_budget_usage_by_pair(&trading_pair).into_bytes(),
+            ExecutionBudgetUsage {
+                trading_pair,
+                height,
+                used,
+            },
+        );
+        Ok(())
+    }
+
+    /// Emits an event for each circuit breaker trip recorded during this block.
+    fn emit_circuit_breaker_trips(&mut self) {
+        for trip in self.pending_circuit_breaker_trips() {
+            self.record_proto(trip.to_proto());
+        }
+    }
+}
+
+impl<T: StateWrite + ?Sized> CircuitBreakerTripRecorder for T {}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use cnidarium::{ArcStateDeltaExt as _, StateDelta, TempStorage};
+    use penumbra_asset::asset;
+    use penumbra_sct::component::clock::EpochManager as _;
+
+    use super::*;
+    use crate::component::tests::TempStorageExt as _;
+
+    fn value_trip(debit: u64) -> CircuitBreakerTrip {
+        CircuitBreakerTrip::Value {
+            asset_id: asset::Cache::with_known_assets()
+                .get_unit("gm")
+                .unwrap()
+                .id(),
+            balance: 10u64.into(),
+            debit: debit.into(),
+            transaction_id: None,
+        }
+    }
+
+    fn debit(event: &EventCircuitBreakerTrip) -> u64 {
+        match event.trip {
+            CircuitBreakerTrip::Value { debit, .. } => debit.value() as u64,
+            _ => panic!("expected a value circuit breaker trip"),
+        }
+    }
+
+    #[tokio::test]
+    async fn trips_are_recorded_by_height() -> anyhow::Result<()> {
+        let _ = tracing_subscriber::fmt::try_init();
+        let storage = TempStorage::new().await?.apply_minimal_genesis().await?;
+
+        // Record two trips in a block at height 3, and one at height 5.
+        for (height, debits) in [(3, vec![11, 12]), (5, vec![13])] {
+            let mut state = Arc::new(StateDelta::new(storage.latest_snapshot()));
+            let mut state_tx = state.try_begin_transaction().unwrap();
+            state_tx.put_block_height(height);
+            for debit in debits.iter() {
+                state_tx
+                    .record_circuit_breaker_trip(value_trip(*debit))
+                    .await?;
+            }
+
+            // The trips are pending until they are emitted at the end of the block.
+            let pending = state_tx.pending_circuit_breaker_trips();
+            assert_eq!(pending.len(), debits.len());
+            assert!(pending.iter().all(|event| event.height == height));
+            state_tx.emit_circuit_breaker_trips();
+            let (_, events) = state_tx.apply();
+            assert_eq!(events.len(), debits.len());
+
+            storage.commit(Arc::try_unwrap(state).unwrap()).await?;
+        }
+
+        let state = storage.latest_snapshot();
+        let trips = state.circuit_breaker_trips(0, 10).await?;
+        assert_eq!(
+            trips
+                .iter()
+                .map(|event| (event.height, debit(event)))
+                .collect::<Vec<_>>(),
+            vec![(3, 11), (3, 12), (5, 13)]
+        );
+
+        // Trips can be fetched from a later height, and are limited in number.
+        let trips = state.circuit_breaker_trips(4, 10).await?;
+        assert_eq!(trips.len(), 1);
+        assert_eq!(trips[0].height, 5);
+        assert_eq!(state.circuit_breaker_trips(0, 2).await?.len(), 2);
+
+        // Nothing is pending in a new block.
+        let state = StateDelta::new(storage.latest_snapshot());
+        assert!(state.pending_circuit_breaker_trips().is_empty());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn execution_budget_usage_is_recorded_per_direction() -> anyhow::Result<()> {
+        let _ = tracing_subscriber::fmt::try_init();
+        let storage = TempStorage::new().await?.apply_minimal_genesis().await?;
+        let mut state = StateDelta::new(storage.latest_snapshot());
+
+        let gm = asset::Cache::with_known_assets().get_unit("gm").unwrap();
+        let gn = asset::Cache::with_known_assets().get_unit("gn").unwrap();
+        let gm_for_gn = DirectedTradingPair::new(gm.id(), gn.id());
+        let gn_for_gm = DirectedTradingPair::new(gn.id(), gm.id());
+
+        state.put_block_height(2);
+        state.record_execution_budget_usage(gm_for_gn, 3).await?;
+        state.record_execution_budget_usage(gn_for_gm, 4).await?;
+        // A later route-and-fill in the same direction replaces the earlier usage.
+        state.put_block_height(3);
+        state.record_execution_budget_usage(gm_for_gn, 5).await?;
+        storage.commit(state).await?;
+
+        let mut usage = storage
+            .latest_snapshot()
+            .execution_budget_usage()
+            .await?
+            .into_iter()
+            .map(|usage| (usage.trading_pair, usage.height, usage.used))
+            .collect::<Vec<_>>();
+        usage.sort_by_key(|(_, _, used)| *used);
+        assert_eq!(usage, vec![(gn_for_gm, 2, 4), (gm_for_gn, 3, 5)]);
+
+        Ok(())
+    }
+}
@@ -1,5 +1,6 @@
 use anyhow::{anyhow, Result};
 use cnidarium::{StateRead, StateWrite};
+use futures::{StreamExt, TryStreamExt};
 use penumbra_asset::{asset, Value};
 use penumbra_num::Amount;
 use penumbra_proto::{DomainType, StateReadProto, StateWriteProto};
@@ -8,12 +9,40 @@ use tracing::instrument;
 
 use crate::{event, state_key};
 
+/// The error returned when a debit exceeds the value balance held by the DEX.
+///
+/// This is surfaced as a distinct type so that the rejection of a transaction
+/// by the circuit breaker can be told apart from other execution failures.
+#[derive(Debug, Clone, thiserror::Error)]
+#[error("underflowed balance while debiting value circuit breaker (prev balance={balance:?}, debit={debit:?})")]
+pub struct ValueCircuitBreakerTripped {
+    /// The balance of the asset held by the DEX.
+    pub balance: Amount,
+    /// The value that was requested.
+    pub debit: Value,
+}
+
 #[async_trait]
 pub trait ValueCircuitBreakerRead: StateRead {
     /// Fetch the DEX VCB balance for a specified asset id.
     async fn get_dex_vcb_for_asset(&self, id: &asset::Id) -> Result<Option<Amount>> {
         Ok(self.get(&state_key::value_balance(&id)).await?)
     }
+
+    /// Fetch the DEX VCB balances of every asset held by the DEX.
+    async fn get_dex_vcb_balances(&self) -> Result<Vec<(asset::Id, Amount)>> {
+        self.prefix::<Amount>(state_key::value_balances())
+            .map(|entry| {
+                let (key, balance) = entry?;
+                let asset_id = key
+                    .strip_prefix(state_key::value_balances())
+                    .ok_or_else(|| anyhow!("unexpected value balance key {key}"))?
+                    .parse()?;
+                Ok((asset_id, balance))
+            })
+            .try_collect()
+            .await
+    }
 }
 
 impl<T: StateRead + ?Sized> ValueCircuitBreakerRead for T {}
@@ -61,9 +90,13 @@ pub(crate) trait ValueCircuitBreaker: StateWrite {
             .get_dex_vcb_for_asset(&value.asset_id)
             .await?
             .unwrap_or_default();
-        let new_balance = prev_balance
-            .checked_sub(&value.amount)
-            .ok_or_else(|| anyhow!("underflowed balance while debiting value circuit breaker (prev balance={prev_balance:?}, debit={value:?}"))?;
+        let new_balance =
+            prev_balance
+                .checked_sub(&value.amount)
+                .ok_or(ValueCircuitBreakerTripped {
+                    balance: prev_balance,
+                    debit: value,
+                })?;
 
         tracing::debug!(?prev_balance, ?new_balance, "crediting the dex VCB");
         self.put(state_key::value_balance(&value.asset_id), new_balance);
@@ -130,8 +163,12 @@ mod tests {
         // Debit 100 gn.
         state_tx.dex_vcb_debit(gn.value(100u64.into())).await?;
 
-        // Debiting an additional gm should fail.
-        assert!(state_tx.dex_vcb_debit(gm.value(1u64.into())).await.is_err());
+        // Debiting an additional gm should fail, tripping the circuit breaker.
+        let err = state_tx
+            .dex_vcb_debit(gm.value(1u64.into()))
+            .await
+            .unwrap_err();
+        assert!(err.downcast_ref::<ValueCircuitBreakerTripped>().is_some());
 
         // Debiting an asset that hasn't been credited should also fail.
         assert!(state_tx
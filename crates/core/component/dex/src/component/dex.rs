@@ -5,12 +5,14 @@ use anyhow::Result;
 use async_trait::async_trait;
 use cnidarium::{StateRead, StateWrite};
 use cnidarium_component::Component;
+use futures::TryStreamExt;
 use penumbra_asset::asset;
 use penumbra_asset::{Value, STAKING_TOKEN_ASSET_ID};
 use penumbra_fee::component::StateWriteExt as _;
 use penumbra_fee::Fee;
 use penumbra_num::Amount;
 use penumbra_proto::{DomainType as _, StateReadProto, StateWriteProto};
+use penumbra_shielded_pool::{component::Ics20SwapQueue as _, Ics20Swap};
 use tendermint::v0_37::abci;
 use tracing::instrument;
 
@@ -21,10 +23,12 @@ use crate::{
 };
 
 use super::eviction_manager::EvictionManager;
+use super::ics20_swap::{ics20_swap_pair, Ics20SwapExecutor as _};
 use super::{
     chandelier::Chandelier,
     router::{HandleBatchSwaps, RoutingParams},
-    Arbitrage, PositionManager, PositionRead as _, ValueCircuitBreaker,
+    Arbitrage, CircuitBreakerTripRecorder as _, PositionManager, PositionRead as _,
+    ValueCircuitBreaker,
 };
 
 pub struct Dex {}
@@ -99,41 +103,97 @@ impl Component for Dex {
             base_fees_and_tips
         };
 
+        // I.0. Take the inbound ICS-20 transfers that asked to be swapped on receipt, grouped by
+        // trading pair. If the DEX is disabled, there's no swapping them, so refund them instead.
+        let dex_params = state.get_dex_params().await.expect("dex params are set");
+        let mut ics20_swaps: BTreeMap<TradingPair, Vec<Ics20Swap>> = BTreeMap::new();
+        {
+            let state_ref = Arc::get_mut(state).expect("should have unique ref before batch swaps");
+            let swaps = state_ref.take_pending_ics20_swaps();
+            if dex_params.is_enabled {
+                for swap in swaps {
+                    ics20_swaps
+                        .entry(ics20_swap_pair(&swap))
+                        .or_default()
+                        .push(swap);
+                }
+            } else {
+                state_ref
+                    .refund_ics20_swaps(swaps.into_iter().collect(), "the dex is disabled")
+                    .await;
+            }
+        }
+
         // 1. Add all newly opened positions to the DEX.
         // This has already happened in the action handlers for each `PositionOpen` action.
 
         // 2. For each batch swap during the block, calculate clearing prices and set in the JMT.
         let routing_params = state.routing_params().await.expect("dex params are set");
-        let execution_budget = state
-            .get_dex_params()
-            .await
-            .expect("dex params are set")
-            .max_execution_budget;
+        let execution_budget = dex_params.max_execution_budget;
+        let block_height: u64 = end_block
+            .height
+            .try_into()
+            .expect("height is part of the end block data");
 
         // Local cache of BSODs used for claiming fee swaps.
         let mut bsods = BTreeMap::new();
 
-        for (trading_pair, swap_flows) in state.swap_flows() {
+        let mut swap_flows = state.swap_flows();
+        for trading_pair in ics20_swaps.keys() {
+            if !swap_flows.contains_key(trading_pair) {
+                swap_flows.insert(*trading_pair, Default::default());
+            }
+        }
+
+        for (trading_pair, swap_flows) in swap_flows {
             let batch_start = std::time::Instant::now();
-            let bsod = state
-                .handle_batch_swaps(
-                    trading_pair,
-                    swap_flows,
-                    end_block
-                        .height
-                        .try_into()
-                        .expect("height is part of the end block data"),
-                    // Always include both ends of the target pair as fixed candidates.
-                    routing_params
-                        .clone()
-                        .with_extra_candidates([trading_pair.asset_1(), trading_pair.asset_2()]),
-                    execution_budget,
-                )
-                .await
-                .expect("handling batch swaps is infaillible");
+            // Always include both ends of the target pair as fixed candidates.
+            let batch_routing_params = routing_params
+                .clone()
+                .with_extra_candidates([trading_pair.asset_1(), trading_pair.asset_2()]);
+
+            // I.1. Execute the batch with the ICS-20 swaps on this pair that it fills well enough.
+            let executed = match ics20_swaps.remove(&trading_pair) {
+                Some(swaps) => {
+                    state
+                        .execute_batch_with_ics20_swaps(
+                            trading_pair,
+                            swap_flows,
+                            swaps,
+                            block_height,
+                            batch_routing_params.clone(),
+                            execution_budget,
+                        )
+                        .await
+                }
+                None => None,
+            };
+
+            let (bsod, executed_ics20_swaps) = match executed {
+                Some(executed) => executed,
+                None => {
+                    let bsod = state
+                        .handle_batch_swaps(
+                            trading_pair,
+                            swap_flows,
+                            block_height,
+                            batch_routing_params,
+                            execution_budget,
+                        )
+                        .await
+                        .expect("handling batch swaps is infaillible");
+                    (bsod, Vec::new())
+                }
+            };
             metrics::histogram!(crate::component::metrics::DEX_BATCH_DURATION)
                 .record(batch_start.elapsed());
 
+            // I.2. Mint the outputs of the executed ICS-20 swaps to their receivers.
+            Arc::get_mut(state)
+                .expect("should have unique ref after batch swap")
+                .claim_ics20_swaps(&bsod, executed_ics20_swaps)
+                .await;
+
             bsods.insert(trading_pair, bsod);
         }
 
@@ -248,6 +308,11 @@ impl Component for Dex {
             .finalize_block_candlesticks()
             .await
             .expect("finalizing block candlesticks should not fail");
+
+        // 6. Emit the circuit breaker trips that occurred during the block.
+        Arc::get_mut(state)
+            .expect("state should be uniquely referenced after batch swaps complete")
+            .emit_circuit_breaker_trips();
     }
 
     #[instrument(name = "dex", skip(_state))]
@@ -280,6 +345,14 @@ pub trait StateReadExt: StateRead {
             .await
     }
 
+    /// Returns the batch swap output data of every trading pair executed at `height`.
+    async fn output_data_at_height(&self, height: u64) -> Result<Vec<BatchSwapOutputData>> {
+        self.prefix(&state_key::output_data_at_height(height))
+            .map_ok(|(_, output_data)| output_data)
+            .try_collect()
+            .await
+    }
+
     async fn swap_execution(
         &self,
         height: u64,
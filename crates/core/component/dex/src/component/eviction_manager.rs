@@ -1,14 +1,20 @@
 use crate::component::StateReadExt;
 use crate::{component::position_manager::counter::PositionCounterRead, lp::position};
 use futures::{StreamExt as _, TryStreamExt};
+use penumbra_num::Amount;
+use penumbra_proto::{DomainType as _, StateWriteProto as _};
 use std::collections::BTreeSet;
 
-use crate::state_key::eviction_queue;
+use crate::state_key::{self, eviction_queue};
 use anyhow::Result;
-use cnidarium::StateWrite;
+use async_trait::async_trait;
+use cnidarium::{StateRead, StateWrite};
 use tracing::instrument;
 
-use crate::{component::PositionManager, DirectedTradingPair, TradingPair};
+use crate::{
+    component::{PositionManager, PositionRead},
+    event, DirectedTradingPair, TradingPair,
+};
 
 pub(crate) trait EvictionManager: StateWrite {
     /// Evict liquidity positions that are in excess of the trading pair limit.
@@ -102,7 +108,19 @@ pub(crate) trait EvictionManager: StateWrite {
             let overlap = overhead_ab.intersection(&overhead_ba);
 
             for id in overlap {
+                let position = self
+                    .position_by_id(id)
+                    .await?
+                    .ok_or_else(|| anyhow::anyhow!("could not find position {} to evict", id))?;
                 self.close_position_by_id(id).await?;
+
+                // Let the owner know that the position was closed by the DEX,
+                // both through an event and through the compact block.
+                let evicted = event::EventPositionEvicted::in_context(*id, &position);
+                self.record_proto(evicted.clone().to_proto());
+                let mut pending = self.pending_evicted_positions();
+                pending.push_back(evicted);
+                self.object_put(state_key::pending_evicted_positions(), pending);
             }
         }
         Ok(())
@@ -110,3 +128,174 @@ pub(crate) trait EvictionManager: StateWrite {
 }
 
 impl<T: StateWrite + ?Sized> EvictionManager for T {}
+
+#[async_trait]
+pub(crate) trait EvictionQueueRead: StateRead {
+    /// Returns the number of open positions on the directed pair holding less inventory
+    /// than a position with the supplied `inventory` and `id`, i.e. its rank from the
+    /// bottom of the eviction queue.
+    ///
+    /// The position itself need not be indexed, so this can be used to rank a
+    /// hypothetical position before it is opened.
+    #[instrument(skip(self), level = "trace")]
+    async fn eviction_rank(
+        &self,
+        pair: &DirectedTradingPair,
+        inventory: Amount,
+        id: &position::Id,
+    ) -> Result<u64> {
+        let prefix = eviction_queue::inventory_index::by_trading_pair(pair);
+        let key = eviction_queue::inventory_index::key(pair, inventory, id);
+        // The range is relative to the prefix, so we only bound it by the
+        // inventory and the position id.
+        let end = key[prefix.len()..].to_vec();
+
+        self.nonverifiable_range_raw(Some(&prefix), ..end)?
+            .try_fold(0u64, |rank, _| async move { anyhow::Ok(rank + 1) })
+            .await
+    }
+}
+
+impl<T: StateRead + ?Sized> EvictionQueueRead for T {}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use cnidarium::{ArcStateDeltaExt as _, StateDelta, TempStorage};
+    use penumbra_asset::asset;
+    use penumbra_proto::{core::component::dex::v1 as pb, event::ProtoEvent as _, Name as _};
+    use rand_core::OsRng;
+
+    use super::*;
+    use crate::{
+        component::{tests::TempStorageExt as _, StateWriteExt as _},
+        lp::{position::Position, Reserves},
+        DexParameters,
+    };
+
+    /// Creates a position on `pair` holding `inventory` of each asset.
+    fn position_with_inventory(pair: DirectedTradingPair, inventory: u64) -> Position {
+        Position::new(
+            OsRng,
+            pair,
+            0u32,
+            1u64.into(),
+            1u64.into(),
+            Reserves {
+                r1: inventory.into(),
+                r2: inventory.into(),
+            },
+        )
+    }
+
+    #[tokio::test]
+    async fn least_liquid_positions_are_evicted() -> anyhow::Result<()> {
+        let _ = tracing_subscriber::fmt::try_init();
+        let storage = TempStorage::new().await?.apply_minimal_genesis().await?;
+        let mut state = Arc::new(StateDelta::new(storage.latest_snapshot()));
+        let mut state_tx = state.try_begin_transaction().unwrap();
+        state_tx.put_dex_params(DexParameters {
+            max_positions_per_pair: 2,
+            ..Default::default()
+        });
+
+        let gm = asset::Cache::with_known_assets().get_unit("gm").unwrap();
+        let gn = asset::Cache::with_known_assets().get_unit("gn").unwrap();
+        let pair = DirectedTradingPair::new(gm.id(), gn.id());
+        let pair_ab =
+            DirectedTradingPair::new(pair.to_canonical().asset_1(), pair.to_canonical().asset_2());
+
+        let positions = (1..=4)
+            .map(|inventory| position_with_inventory(pair, inventory * 100))
+            .collect::<Vec<_>>();
+        for position in positions.iter() {
+            state_tx.open_position(position.clone()).await?;
+        }
+
+        // A position ranks above the positions holding less inventory, in both directions, and
+        // hypothetical positions can be ranked without being opened.
+        let third = &positions[2];
+        assert_eq!(
+            state_tx
+                .eviction_rank(&pair_ab, 300u64.into(), &third.id())
+                .await?,
+            2
+        );
+        assert_eq!(
+            state_tx
+                .eviction_rank(&pair_ab.flip(), 300u64.into(), &third.id())
+                .await?,
+            2
+        );
+        let hypothetical = position_with_inventory(pair, 50);
+        assert_eq!(
+            state_tx
+                .eviction_rank(&pair_ab, 50u64.into(), &hypothetical.id())
+                .await?,
+            0
+        );
+        let hypothetical = position_with_inventory(pair, 1000);
+        assert_eq!(
+            state_tx
+                .eviction_rank(&pair_ab, 1000u64.into(), &hypothetical.id())
+                .await?,
+            4
+        );
+
+        // With a limit of two positions, the two least liquid positions are evicted.
+        state_tx.evict_positions().await?;
+        let evicted = state_tx.pending_evicted_positions();
+        assert_eq!(evicted.len(), 2);
+        for evicted in evicted.iter() {
+            let position = positions
+                .iter()
+                .find(|position| position.id() == evicted.position_id)
+                .expect("an opened position was evicted");
+            assert_eq!(evicted.trading_pair, pair.to_canonical());
+            assert_eq!(evicted.reserves_1, position.reserves.r1);
+            assert_eq!(evicted.reserves_2, position.reserves.r2);
+        }
+        let mut evicted_ids = evicted
+            .iter()
+            .map(|evicted| evicted.position_id)
+            .collect::<Vec<_>>();
+        evicted_ids.sort();
+        let mut expected_ids = vec![positions[0].id(), positions[1].id()];
+        expected_ids.sort();
+        assert_eq!(evicted_ids, expected_ids);
+
+        for (i, position) in positions.iter().enumerate() {
+            let state = state_tx
+                .position_by_id(&position.id())
+                .await?
+                .expect("position exists")
+                .state;
+            let expected = if i < 2 {
+                position::State::Closed
+            } else {
+                position::State::Opened
+            };
+            assert_eq!(state, expected);
+        }
+
+        // The evictions are also emitted as events.
+        let (_, events) = state_tx.apply();
+        let mut event_ids = events
+            .iter()
+            .filter(|event| event.kind == pb::EventPositionEvicted::full_name())
+            .map(|event| {
+                anyhow::Ok(
+                    event::EventPositionEvicted::try_from(pb::EventPositionEvicted::from_event(
+                        event,
+                    )?)?
+                    .position_id,
+                )
+            })
+            .collect::<Result<Vec<_>>>()?;
+        event_ids.sort();
+        assert_eq!(event_ids, expected_ids);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,204 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use cnidarium::{StateDelta, StateWrite};
+use penumbra_asset::Value;
+use penumbra_num::Amount;
+use penumbra_shielded_pool::{
+    component::{Ics20SwapQueue, NoteManager},
+    Ics20Swap,
+};
+use tracing::instrument;
+
+use crate::{
+    component::{flow::SwapFlow, router::HandleBatchSwaps, router::RoutingParams, SwapDataWrite},
+    BatchSwapOutputData, TradingPair,
+};
+
+/// The trading pair an ICS-20 swap trades on.
+pub(crate) fn ics20_swap_pair(swap: &Ics20Swap) -> TradingPair {
+    TradingPair::new(swap.input.asset_id, swap.asset_out)
+}
+
+/// The swap flow contributed by a set of ICS-20 swaps on `trading_pair`.
+fn ics20_swap_flow(trading_pair: &TradingPair, swaps: &[Ics20Swap]) -> SwapFlow {
+    let mut flow = SwapFlow::default();
+    for swap in swaps {
+        if swap.input.asset_id == trading_pair.asset_1() {
+            flow.0 += swap.input.amount;
+        } else {
+            flow.1 += swap.input.amount;
+        }
+    }
+    flow
+}
+
+/// The outputs of an ICS-20 swap in a batch, as `(output, unfilled_input)`.
+fn ics20_swap_outputs(bsod: &BatchSwapOutputData, swap: &Ics20Swap) -> (Amount, Amount) {
+    if swap.input.asset_id == bsod.trading_pair.asset_1() {
+        let (unfilled_input, output) = bsod.pro_rata_outputs((swap.input.amount, 0u64.into()));
+        (output, unfilled_input)
+    } else {
+        let (output, unfilled_input) = bsod.pro_rata_outputs((0u64.into(), swap.input.amount));
+        (output, unfilled_input)
+    }
+}
+
+/// Executes the inbound ICS-20 transfers that asked to be swapped on receipt, as chain-submitted
+/// swaps in the batch for their trading pair.
+#[async_trait]
+pub(crate) trait Ics20SwapExecutor: StateWrite + Sized {
+    /// Executes the batch on `trading_pair` with the ICS-20 swaps on it, refunding the swaps whose
+    /// minimum output the batch doesn't meet.
+    ///
+    /// The batch is executed in a fork of the state, with every swap not yet refunded, and the swaps
+    /// it fills below their minimum output are refunded. Dropping them changes the batch, and can
+    /// fill the remaining swaps worse, so the batch is executed again until it meets the minimum
+    /// output of every swap it includes. Only that execution is applied to the state.
+    ///
+    /// Returns the output data of the batch along with the swaps it executed, or `None` if every
+    /// swap was refunded, in which case the batch is left to be executed without them. If the
+    /// batch can't be executed with the swaps, or their inputs can't be credited, every swap is
+    /// refunded.
+    #[instrument(skip(self, batch_flow, swaps, params))]
+    async fn execute_batch_with_ics20_swaps(
+        self: &mut Arc<Self>,
+        trading_pair: TradingPair,
+        batch_flow: SwapFlow,
+        mut swaps: Vec<Ics20Swap>,
+        block_height: u64,
+        params: RoutingParams,
+        execution_budget: u32,
+    ) -> Option<(BatchSwapOutputData, Vec<Ics20Swap>)>
+    where
+        Self: 'static,
+    {
+        while !swaps.is_empty() {
+            let ics20_flow = ics20_swap_flow(&trading_pair, &swaps);
+            let flow = (batch_flow.0 + ics20_flow.0, batch_flow.1 + ics20_flow.1).into();
+            let mut fork = Arc::new(StateDelta::new(self.clone()));
+            let executed = async {
+                Arc::get_mut(&mut fork)
+                    .expect("fork is uniquely referenced")
+                    .accumulate_swap_flow(&trading_pair, ics20_flow)
+                    .await?;
+                fork.handle_batch_swaps(
+                    trading_pair,
+                    flow,
+                    block_height,
+                    params.clone(),
+                    execution_budget,
+                )
+                .await
+            }
+            .await;
+
+            let bsod = match executed {
+                Ok(bsod) => bsod,
+                Err(e) => {
+                    std::mem::drop(fork);
+                    tracing::error!(?e, "could not execute batch with ics20 swaps");
+                    Arc::get_mut(self)
+                        .expect("state is uniquely referenced")
+                        .refund_ics20_swaps(swaps, "swap could not be executed")
+                        .await;
+                    return None;
+                }
+            };
+
+            let (met, unmet): (Vec<_>, Vec<_>) = swaps
+                .into_iter()
+                .partition(|swap| ics20_swap_outputs(&bsod, swap).0 >= swap.min_output);
+            swaps = met;
+            if unmet.is_empty() {
+                let (forked, changes) = Arc::into_inner(fork)
+                    .expect("fork is uniquely referenced")
+                    .flatten();
+                std::mem::drop(forked);
+                changes.apply_to(Arc::get_mut(self).expect("state is uniquely referenced"));
+                return Some((bsod, swaps));
+            }
+
+            std::mem::drop(fork);
+            tracing::debug!(?unmet, "ics20 swaps did not meet their minimum output");
+            Arc::get_mut(self)
+                .expect("state is uniquely referenced")
+                .refund_ics20_swaps(unmet, "swap did not meet its minimum output")
+                .await;
+        }
+
+        None
+    }
+
+    /// Mints the outputs of the executed ICS-20 swaps to their receivers, and acknowledges their
+    /// inbound transfers.
+    ///
+    /// A swap whose outputs can't be minted is refunded instead, so that the chain it came from
+    /// refunds the sender. Its input has already been traded, and its outputs are left unclaimed
+    /// in the DEX.
+    async fn claim_ics20_swaps(&mut self, bsod: &BatchSwapOutputData, swaps: Vec<Ics20Swap>) {
+        for swap in swaps {
+            let (output, unfilled_input) = ics20_swap_outputs(bsod, &swap);
+            tracing::debug!(?swap, ?output, ?unfilled_input, "claiming ics20 swap");
+            let mut fork = StateDelta::new(&mut *self);
+            let minted = async {
+                for value in [
+                    Value {
+                        amount: output,
+                        asset_id: swap.asset_out,
+                    },
+                    Value {
+                        amount: unfilled_input,
+                        asset_id: swap.input.asset_id,
+                    },
+                ] {
+                    if value.amount > Amount::zero() {
+                        fork.mint_note(value, &swap.receiver, swap.source.clone())
+                            .await?;
+                    }
+                }
+                anyhow::Ok(())
+            }
+            .await;
+            match minted {
+                Ok(()) => {
+                    apply_fork(fork);
+                    if let Err(e) = self.acknowledge_ics20_swap(&swap).await {
+                        tracing::error!(?swap, ?e, "could not acknowledge ics20 swap");
+                    }
+                }
+                Err(e) => {
+                    std::mem::drop(fork);
+                    tracing::error!(?swap, ?e, "could not mint outputs of ics20 swap");
+                    self.refund_ics20_swaps(vec![swap], "swap outputs could not be minted")
+                        .await;
+                }
+            }
+        }
+    }
+
+    /// Refunds ICS-20 swaps that were not executed, acknowledging their inbound transfers with
+    /// `error` so that the chains they came from refund their senders.
+    async fn refund_ics20_swaps(&mut self, swaps: Vec<Ics20Swap>, error: &str) {
+        for swap in swaps {
+            let mut fork = StateDelta::new(&mut *self);
+            match fork.refund_ics20_swap(&swap, error).await {
+                Ok(()) => apply_fork(fork),
+                Err(e) => {
+                    std::mem::drop(fork);
+                    tracing::error!(?swap, ?e, "could not refund ics20 swap");
+                }
+            }
+        }
+    }
+}
+
+/// Applies a fork of the state, along with the events recorded in it.
+fn apply_fork<S: StateWrite>(fork: StateDelta<S>) {
+    let (mut state, events) = fork.apply();
+    for event in events {
+        state.record(event);
+    }
+}
+
+impl<T: StateWrite> Ics20SwapExecutor for T {}
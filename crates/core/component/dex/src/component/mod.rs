@@ -2,6 +2,7 @@
 //! supplies based on liquidity provider interactions.
 
 pub mod metrics;
+pub mod replay;
 pub mod rpc;
 
 pub mod router;
@@ -13,6 +14,7 @@ pub(crate) mod circuit_breaker;
 mod dex;
 mod eviction_manager;
 mod flow;
+mod ics20_swap;
 mod position_manager;
 mod swap_manager;
 
@@ -27,6 +29,10 @@ pub(crate) use arb::Arbitrage;
 pub(crate) use circuit_breaker::ExecutionCircuitBreaker;
 pub(crate) use circuit_breaker::ValueCircuitBreaker;
 pub use circuit_breaker::ValueCircuitBreakerRead;
+pub use circuit_breaker::ValueCircuitBreakerTripped;
+pub use circuit_breaker::{
+    CircuitBreakerTripRead, CircuitBreakerTripRecorder, ExecutionBudgetUsage,
+};
 pub(crate) use dex::InternalDexWrite;
 pub(crate) use swap_manager::SwapDataWrite;
 pub(crate) use swap_manager::SwapManager;
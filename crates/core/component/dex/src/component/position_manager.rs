@@ -21,7 +21,7 @@ use crate::component::{
         price_index::PositionByPriceIndex,
     },
 };
-use crate::lp::Reserves;
+use crate::lp::{PositionExecutionSummary, Reserves};
 use crate::{
     component::position_manager::counter::PositionCounter,
     component::ValueCircuitBreaker,
@@ -154,6 +154,18 @@ pub trait PositionRead: StateRead {
         self.object_get(state_key::recently_accessed_assets())
             .unwrap_or_default()
     }
+
+    /// Fetch the executions against positions during this block, summarized per position.
+    fn pending_position_executions(&self) -> im::OrdMap<position::Id, PositionExecutionSummary> {
+        self.object_get(state_key::pending_position_executions())
+            .unwrap_or_default()
+    }
+
+    /// Fetch the positions evicted during this block.
+    fn pending_evicted_positions(&self) -> im::Vector<event::EventPositionEvicted> {
+        self.object_get(state_key::pending_evicted_positions())
+            .unwrap_or_default()
+    }
 }
 impl<T: StateRead + ?Sized> PositionRead for T {}
 
@@ -380,6 +392,24 @@ pub trait PositionManager: StateWrite + PositionRead {
             event::EventPositionExecution::in_context(&prev_state, &new_state, context).to_proto(),
         );
 
+        // ... and also add it to the summary in the compact block to be pushed out to clients,
+        // along with any earlier execution of the same position in this block.
+        let mut executions = self.pending_position_executions();
+        match executions.get_mut(&position_id) {
+            Some(summary) => summary.record(&prev_state.reserves, &new_state.reserves),
+            None => {
+                executions.insert(
+                    position_id,
+                    PositionExecutionSummary::new(
+                        position_id,
+                        &prev_state.reserves,
+                        &new_state.reserves,
+                    ),
+                );
+            }
+        }
+        self.object_put(state_key::pending_position_executions(), executions);
+
         // Handle "close-on-fill": automatically flip the position state to "closed" if
         // either of the reserves are zero.
         if new_state.close_on_fill {
@@ -0,0 +1,108 @@
+use std::sync::Arc;
+
+use anyhow::Result;
+use async_trait::async_trait;
+use penumbra_asset::Value;
+use penumbra_sct::component::clock::EpochRead;
+use tracing::instrument;
+
+use crate::{
+    component::{router::HandleBatchSwaps, PositionManager, StateReadExt, ValueCircuitBreaker},
+    lp::position::Position,
+    BatchSwapOutputData,
+};
+
+/// The outcome of replaying a recorded batch swap against a counterfactual DEX state.
+#[derive(Clone, Debug)]
+pub struct ReplayedBatch {
+    /// The batch swap as it was recorded on-chain.
+    pub recorded: BatchSwapOutputData,
+    /// The batch swap as it would have cleared against the counterfactual state.
+    ///
+    /// The height and SCT position prefix of the simulated output refer to the
+    /// state the replay was performed against, not to the recorded block.
+    pub simulated: BatchSwapOutputData,
+}
+
+/// Replays recorded batch swap flows through the router, to compute counterfactual
+/// executions (e.g. "what would this block have cleared at, had my position been open?").
+///
+/// This is intended to be used on a fork of the chain state, e.g. a `StateDelta`
+/// over a retained snapshot, whose changes are discarded afterwards.
+#[async_trait]
+pub trait ReplayBatchSwaps: HandleBatchSwaps + PositionManager {
+    /// Opens a set of hypothetical liquidity positions in the forked state.
+    #[instrument(skip_all)]
+    async fn open_hypothetical_positions(
+        self: &mut Arc<Self>,
+        positions: Vec<Position>,
+    ) -> Result<()>
+    where
+        Self: 'static,
+    {
+        let state = Arc::get_mut(self).expect("expected state to have no other refs");
+        for position in positions {
+            state.open_position(position).await?;
+        }
+        Ok(())
+    }
+
+    /// Replays the swap flows of a recorded batch swap against the current state.
+    ///
+    /// Executions are applied to the state, so replaying successive batches against
+    /// the same state accumulates their effects on the liquidity positions. The
+    /// arbitrage and end-of-block position closures that follow batch execution
+    /// on-chain are not replayed.
+    #[instrument(skip(self, recorded), fields(height = recorded.height, pair = ?recorded.trading_pair))]
+    async fn replay_batch_swap(
+        self: &mut Arc<Self>,
+        recorded: BatchSwapOutputData,
+    ) -> Result<ReplayedBatch>
+    where
+        Self: 'static,
+    {
+        let trading_pair = recorded.trading_pair;
+
+        // The batch swap will debit its inputs from the DEX value balance, so we
+        // first need to credit them, exactly as the swap actions would have.
+        {
+            let state = Arc::get_mut(self).expect("expected state to have no other refs");
+            state
+                .dex_vcb_credit(Value {
+                    amount: recorded.delta_1,
+                    asset_id: trading_pair.asset_1(),
+                })
+                .await?;
+            state
+                .dex_vcb_credit(Value {
+                    amount: recorded.delta_2,
+                    asset_id: trading_pair.asset_2(),
+                })
+                .await?;
+        }
+
+        let routing_params = self.routing_params().await?;
+        let execution_budget = self.get_dex_params().await?.max_execution_budget;
+        // The batch output data is positioned relative to the current epoch,
+        // so we execute at the height of the state we are replaying against.
+        let height = self.get_block_height().await?;
+
+        let simulated = self
+            .handle_batch_swaps(
+                trading_pair,
+                (recorded.delta_1, recorded.delta_2).into(),
+                height,
+                routing_params
+                    .with_extra_candidates([trading_pair.asset_1(), trading_pair.asset_2()]),
+                execution_budget,
+            )
+            .await?;
+
+        Ok(ReplayedBatch {
+            recorded,
+            simulated,
+        })
+    }
+}
+
+impl<T: HandleBatchSwaps + PositionManager> ReplayBatchSwaps for T {}
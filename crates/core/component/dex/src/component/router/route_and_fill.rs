@@ -13,10 +13,11 @@ use crate::{
         chandelier::Chandelier,
         flow::SwapFlow,
         router::{FillRoute, PathSearch, RoutingParams},
-        ExecutionCircuitBreaker, InternalDexWrite, PositionManager,
+        CircuitBreakerTripRecorder, ExecutionCircuitBreaker, InternalDexWrite, PositionManager,
     },
+    event::CircuitBreakerTrip,
     lp::position::MAX_RESERVE_AMOUNT,
-    BatchSwapOutputData, SwapExecution, TradingPair,
+    BatchSwapOutputData, DirectedTradingPair, SwapExecution, TradingPair,
 };
 
 use super::fill_route::FillError;
@@ -184,6 +185,15 @@ pub trait RouteAndFill: StateWrite + Sized {
             // Check if we have exceeded the execution circuit breaker limits.
             if execution_circuit_breaker.exceeded_limits() {
                 tracing::debug!("execution circuit breaker triggered, exiting route_and_fill");
+                Arc::get_mut(self)
+                    .expect("expected state to have no other refs")
+                    .record_circuit_breaker_trip(CircuitBreakerTrip::Execution {
+                        trading_pair: DirectedTradingPair::new(asset_1, asset_2),
+                        input,
+                        unfilled: total_unfilled_1,
+                        execution_budget: execution_circuit_breaker.max,
+                    })
+                    .await?;
                 break;
             } else {
                 // This should be done ahead of doing any path search or execution, so that we never
@@ -289,6 +299,15 @@ pub trait RouteAndFill: StateWrite + Sized {
             }
         }
 
+        // Keep track of the execution budget consumed in this direction.
+        Arc::get_mut(self)
+            .expect("expected state to have no other refs")
+            .record_execution_budget_usage(
+                DirectedTradingPair::new(asset_1, asset_2),
+                execution_circuit_breaker.counter,
+            )
+            .await?;
+
         // If we didn't execute against any position at all, there are no execution records to return.
         if traces.is_empty() {
             return Ok(None);
@@ -16,8 +16,9 @@ use crate::{
     component::{
         router::{FillRoute, HandleBatchSwaps, Path},
         tests::TempStorageExt,
-        PositionManager, PositionRead, StateReadExt, StateWriteExt,
+        CircuitBreakerTripRead as _, PositionManager, PositionRead, StateReadExt, StateWriteExt,
     },
+    event::CircuitBreakerTrip,
     lp::{
         position::{self, Position},
         Reserves,
@@ -1052,6 +1053,61 @@ async fn best_position_route_and_fill() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[tokio::test]
+/// Checks that a route-and-fill which exhausts its execution budget records a circuit breaker trip.
+async fn exhausted_execution_budget_is_recorded() -> anyhow::Result<()> {
+    let _ = tracing_subscriber::fmt::try_init();
+    let storage = TempStorage::new().await?.apply_minimal_genesis().await?;
+    let mut state = Arc::new(StateDelta::new(storage.latest_snapshot()));
+    let mut state_tx = state.try_begin_transaction().unwrap();
+
+    let gn = asset::Cache::with_known_assets().get_unit("gn").unwrap();
+    let penumbra = asset::Cache::with_known_assets()
+        .get_unit("penumbra")
+        .unwrap();
+
+    let pair_1 = DirectedUnitPair::new(gn.clone(), penumbra.clone());
+    let buy_1 = create_buy(pair_1.clone(), 1u64.into(), 1u64.into());
+    state_tx.open_position(buy_1).await.unwrap();
+    state_tx.apply();
+
+    let trading_pair = pair_1.into_directed_trading_pair().into();
+    let mut swap_flow = state.swap_flow(&trading_pair);
+    swap_flow.1 += 1u32.into();
+    let routing_params = state.routing_params().await.unwrap();
+    // With no execution budget, the swap can't be filled at all.
+    state
+        .handle_batch_swaps(trading_pair, swap_flow, 0u32.into(), routing_params, 0)
+        .await
+        .expect("unable to process batch swaps");
+
+    let output_data = state.output_data(0, trading_pair).await?.unwrap();
+    assert_eq!(output_data.unfilled_2, 1u64.into());
+
+    // Only the direction with input trips the circuit breaker.
+    let trips = state.pending_circuit_breaker_trips();
+    assert_eq!(trips.len(), 1);
+    match &trips[0].trip {
+        CircuitBreakerTrip::Execution {
+            trading_pair,
+            input,
+            unfilled,
+            execution_budget,
+        } => {
+            assert_eq!(
+                *trading_pair,
+                DirectedTradingPair::new(gn.id(), penumbra.id())
+            );
+            assert_eq!(*input, 1u64.into());
+            assert_eq!(*unfilled, 1u64.into());
+            assert_eq!(*execution_budget, 0);
+        }
+        trip => panic!("unexpected circuit breaker trip {trip:?}"),
+    }
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn multi_hop_route_and_fill() -> anyhow::Result<()> {
     let _ = tracing_subscriber::fmt::try_init();
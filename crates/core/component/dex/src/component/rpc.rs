@@ -11,6 +11,7 @@ use cnidarium::{StateDelta, Storage};
 use penumbra_asset::{asset, Value};
 use penumbra_proto::{
     core::component::dex::v1::{
+        circuit_breaker_status_response, position_eviction_risk_request,
         query_service_server::QueryService,
         simulate_trade_request::{
             routing::{self, Setting},
@@ -20,11 +21,13 @@ use penumbra_proto::{
         ArbExecutionRequest, ArbExecutionResponse, ArbExecutionsRequest, ArbExecutionsResponse,
         BatchSwapOutputDataRequest, BatchSwapOutputDataResponse, CandlestickDataRequest,
         CandlestickDataResponse, CandlestickDataStreamRequest, CandlestickDataStreamResponse,
-        LiquidityPositionByIdRequest, LiquidityPositionByIdResponse, LiquidityPositionsByIdRequest,
+        CircuitBreakerStatusRequest, CircuitBreakerStatusResponse, LiquidityPositionByIdRequest,
+        LiquidityPositionByIdResponse, LiquidityPositionsByIdRequest,
         LiquidityPositionsByIdResponse, LiquidityPositionsByPriceRequest,
         LiquidityPositionsByPriceResponse, LiquidityPositionsRequest, LiquidityPositionsResponse,
-        SimulateTradeRequest, SimulateTradeResponse, SpreadRequest, SpreadResponse,
-        SwapExecutionRequest, SwapExecutionResponse, SwapExecutionsRequest, SwapExecutionsResponse,
+        PositionEvictionRiskRequest, PositionEvictionRiskResponse, SimulateTradeRequest,
+        SimulateTradeResponse, SpreadRequest, SpreadResponse, SwapExecutionRequest,
+        SwapExecutionResponse, SwapExecutionsRequest, SwapExecutionsResponse,
     },
     DomainType, StateReadProto,
 };
@@ -36,7 +39,11 @@ use crate::{
     state_key, CandlestickData, DirectedTradingPair, SwapExecution, TradingPair,
 };
 
-use super::{chandelier::CandlestickRead, router::RouteAndFill, PositionRead, StateReadExt};
+use super::{
+    chandelier::CandlestickRead, eviction_manager::EvictionQueueRead,
+    position_manager::counter::PositionCounterRead, router::RouteAndFill, CircuitBreakerTripRead,
+    PositionManager, PositionRead, StateReadExt, ValueCircuitBreakerRead,
+};
 
 pub mod stub;
 
@@ -207,6 +214,141 @@ impl QueryService for Server {
         }
     }
 
+    #[instrument(skip(self, request))]
+    async fn position_eviction_risk(
+        &self,
+        request: tonic::Request<PositionEvictionRiskRequest>,
+    ) -> Result<tonic::Response<PositionEvictionRiskResponse>, Status> {
+        let state = self.storage.latest_snapshot();
+
+        // Resolve the position to rank, counting a hypothetical position
+        // as if it had been opened.
+        let (id, position, extra) = match request
+            .into_inner()
+            .position
+            .ok_or_else(|| Status::invalid_argument("missing position"))?
+        {
+            position_eviction_risk_request::Position::PositionId(id) => {
+                let id: position::Id = id.try_into().map_err(|e: anyhow::Error| {
+                    Status::invalid_argument(format!("error converting position_id: {e}"))
+                })?;
+                let position = state
+                    .position_by_id(&id)
+                    .await
+                    .map_err(|e| {
+                        Status::unavailable(format!("error fetching position from storage: {e}"))
+                    })?
+                    .ok_or_else(|| Status::not_found("position not found"))?;
+                (id, position, 0)
+            }
+            position_eviction_risk_request::Position::Hypothetical(position) => {
+                let position: Position = position.try_into().map_err(|e: anyhow::Error| {
+                    Status::invalid_argument(format!("error converting position: {e}"))
+                })?;
+                (position.id(), position, 1)
+            }
+        };
+
+        let pair = position.phi.pair;
+        let pair_12 = DirectedTradingPair::new(pair.asset_1(), pair.asset_2());
+        let pair_21 = pair_12.flip();
+
+        let position_count = u64::from(state.get_position_count(&pair).await) + extra;
+        let max_positions_per_pair = u64::from(
+            state
+                .get_dex_params()
+                .await
+                .map_err(|e| Status::internal(e.to_string()))?
+                .max_positions_per_pair,
+        );
+
+        let rank_1 = state
+            .eviction_rank(&pair_12, position.reserves_1().amount, &id)
+            .await
+            .map_err(|e| Status::internal(e.to_string()))?;
+        let rank_2 = state
+            .eviction_rank(&pair_21, position.reserves_2().amount, &id)
+            .await
+            .map_err(|e| Status::internal(e.to_string()))?;
+
+        // Eviction closes the positions that are among the least liquid in both directions.
+        let overhead_size = position_count.saturating_sub(max_positions_per_pair);
+        let at_risk = matches!(position.state, position::State::Opened)
+            && rank_1 < overhead_size
+            && rank_2 < overhead_size;
+
+        Ok(tonic::Response::new(PositionEvictionRiskResponse {
+            position_count,
+            max_positions_per_pair,
+            rank_1,
+            rank_2,
+            at_risk,
+        }))
+    }
+
+    #[instrument(skip(self, request))]
+    async fn circuit_breaker_status(
+        &self,
+        request: tonic::Request<CircuitBreakerStatusRequest>,
+    ) -> Result<tonic::Response<CircuitBreakerStatusResponse>, Status> {
+        let state = self.storage.latest_snapshot();
+        let request = request.into_inner();
+
+        let value_balances = if request.asset_ids.is_empty() {
+            state
+                .get_dex_vcb_balances()
+                .await
+                .map_err(|e| tonic::Status::internal(e.to_string()))?
+        } else {
+            let mut value_balances = Vec::with_capacity(request.asset_ids.len());
+            for asset_id in request.asset_ids {
+                let asset_id: asset::Id = asset_id
+                    .try_into()
+                    .map_err(|_| Status::invalid_argument("invalid asset_id"))?;
+                let balance = state
+                    .get_dex_vcb_for_asset(&asset_id)
+                    .await
+                    .map_err(|e| tonic::Status::internal(e.to_string()))?
+                    .unwrap_or_default();
+                value_balances.push((asset_id, balance));
+            }
+            value_balances
+        };
+
+        let max_execution_budget = state
+            .get_dex_params()
+            .await
+            .map_err(|e| tonic::Status::internal(e.to_string()))?
+            .max_execution_budget;
+
+        let execution_budget_usage = state
+            .execution_budget_usage()
+            .await
+            .map_err(|e| tonic::Status::internal(e.to_string()))?;
+
+        // Limit the number of trips returned, to prevent the server from being
+        // overwhelmed by a single request.
+        let trips = state
+            .circuit_breaker_trips(request.start_height, 10_000)
+            .await
+            .map_err(|e| tonic::Status::internal(e.to_string()))?;
+
+        Ok(tonic::Response::new(CircuitBreakerStatusResponse {
+            value_balances: value_balances
+                .into_iter()
+                .map(
+                    |(asset_id, balance)| circuit_breaker_status_response::ValueBalance {
+                        asset_id: Some(asset_id.into()),
+                        balance: Some(balance.into()),
+                    },
+                )
+                .collect(),
+            max_execution_budget,
+            execution_budget_usage: execution_budget_usage.into_iter().map(Into::into).collect(),
+            trips: trips.into_iter().map(Into::into).collect(),
+        }))
+    }
+
     #[instrument(skip(self, request))]
     async fn candlestick_data(
         &self,
@@ -621,8 +763,34 @@ impl SimulationService for Server {
                 tonic::Status::invalid_argument(format!("error parsing output id: {:#}", e))
             })?;
 
+        let open_positions = request
+            .open_positions
+            .into_iter()
+            .map(Position::try_from)
+            .collect::<anyhow::Result<Vec<_>>>()
+            .map_err(|e| {
+                tonic::Status::invalid_argument(format!("error parsing open positions: {:#}", e))
+            })?;
+
+        let close_positions = request
+            .close_positions
+            .into_iter()
+            .map(position::Id::try_from)
+            .collect::<anyhow::Result<Vec<_>>>()
+            .map_err(|e| {
+                tonic::Status::invalid_argument(format!("error parsing close positions: {:#}", e))
+            })?;
+
         let start_time = std::time::Instant::now();
-        let state = self.storage.latest_snapshot();
+        let state = match request.height {
+            0 => self.storage.latest_snapshot(),
+            height => self.storage.snapshot(height).ok_or_else(|| {
+                tonic::Status::not_found(format!(
+                    "state at height {height} is not retained by this node"
+                ))
+            })?,
+        };
+        let height = state.version();
 
         let mut routing_params = state
             .routing_params()
@@ -644,6 +812,29 @@ impl SimulationService for Server {
             .max_execution_budget;
 
         let mut state_tx = Arc::new(StateDelta::new(state));
+
+        // Apply the hypothetical liquidity changes to the forked state before routing.
+        {
+            let state_tx = Arc::get_mut(&mut state_tx).expect("state fork is uniquely referenced");
+            for id in close_positions {
+                state_tx.close_position_by_id(&id).await.map_err(|e| {
+                    tonic::Status::failed_precondition(format!(
+                        "error closing position {id}: {:#}",
+                        e
+                    ))
+                })?;
+            }
+            for position in open_positions {
+                let id = position.id();
+                state_tx.open_position(position).await.map_err(|e| {
+                    tonic::Status::failed_precondition(format!(
+                        "error opening position {id}: {:#}",
+                        e
+                    ))
+                })?;
+            }
+        }
+
         let execution_circuit_breaker = ExecutionCircuitBreaker::new(execution_budget);
 
         let swap_execution = match state_tx
@@ -687,6 +878,7 @@ impl SimulationService for Server {
         let rsp = tonic::Response::new(SimulateTradeResponse {
             unfilled: Some(unfilled.into()),
             output: Some(swap_execution.into()),
+            height,
         });
 
         let duration = start_time.elapsed();
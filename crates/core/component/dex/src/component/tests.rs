@@ -1144,3 +1144,50 @@ async fn check_routable_asset_ordering() -> anyhow::Result<()> {
 
     Ok(())
 }
+
+#[tokio::test]
+/// Test that replaying a recorded batch swap against hypothetical liquidity
+/// reports the counterfactual execution.
+async fn replay_batch_swap_against_hypothetical_position() -> anyhow::Result<()> {
+    use crate::component::replay::ReplayBatchSwaps;
+
+    let _ = tracing_subscriber::fmt::try_init();
+    let storage = TempStorage::new().await?.apply_minimal_genesis().await?;
+    let mut state = Arc::new(StateDelta::new(storage.latest_snapshot()));
+
+    let penumbra = asset::Cache::with_known_assets()
+        .get_unit("penumbra")
+        .unwrap();
+    let gn = asset::Cache::with_known_assets().get_unit("gn").unwrap();
+
+    let pair_gn_penumbra = DirectedUnitPair::new(gn.clone(), penumbra.clone());
+    let trading_pair = pair_gn_penumbra.into_directed_trading_pair().into();
+
+    // A batch that was recorded with no liquidity available, so its input went unfilled.
+    let recorded = BatchSwapOutputData {
+        delta_1: 0u64.into(),
+        delta_2: gn.value(1u32.into()).amount,
+        lambda_1: 0u64.into(),
+        lambda_2: 0u64.into(),
+        unfilled_1: 0u64.into(),
+        unfilled_2: gn.value(1u32.into()).amount,
+        height: 1,
+        trading_pair,
+        sct_position_prefix: Default::default(),
+    };
+
+    // Had a 1:1 gn:penumbra position been open, the batch would have been filled.
+    let buy_1 = create_buy(pair_gn_penumbra.clone(), 1u64.into(), 1u64.into());
+    state.open_hypothetical_positions(vec![buy_1]).await?;
+
+    let replayed = state.replay_batch_swap(recorded).await?;
+
+    assert_eq!(replayed.recorded, recorded);
+    assert_eq!(replayed.simulated.unfilled_2, Amount::zero());
+    assert_eq!(
+        replayed.simulated.lambda_1,
+        penumbra.value(1u32.into()).amount
+    );
+
+    Ok(())
+}
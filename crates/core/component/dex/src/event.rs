@@ -10,6 +10,7 @@ use penumbra_num::Amount;
 use penumbra_proto::{penumbra::core::component::dex::v1 as pb, DomainType};
 use penumbra_sct::Nullifier;
 use penumbra_tct::StateCommitment;
+use penumbra_txhash::TransactionId;
 use prost::Name as _;
 
 #[derive(Clone, Debug)]
@@ -254,6 +255,68 @@ impl DomainType for EventPositionClose {
     type Proto = pb::EventPositionClose;
 }
 
+#[derive(Clone, Debug)]
+pub struct EventPositionEvicted {
+    pub position_id: position::Id,
+    pub trading_pair: TradingPair,
+    pub reserves_1: Amount,
+    pub reserves_2: Amount,
+}
+
+impl EventPositionEvicted {
+    pub fn in_context(position_id: position::Id, position: &Position) -> Self {
+        Self {
+            position_id,
+            trading_pair: position.phi.pair,
+            reserves_1: position.reserves.r1,
+            reserves_2: position.reserves.r2,
+        }
+    }
+}
+
+impl TryFrom<pb::EventPositionEvicted> for EventPositionEvicted {
+    type Error = anyhow::Error;
+
+    fn try_from(value: pb::EventPositionEvicted) -> Result<Self, Self::Error> {
+        fn inner(value: pb::EventPositionEvicted) -> anyhow::Result<EventPositionEvicted> {
+            Ok(EventPositionEvicted {
+                position_id: value
+                    .position_id
+                    .ok_or(anyhow!("missing `position_id`"))?
+                    .try_into()?,
+                trading_pair: value
+                    .trading_pair
+                    .ok_or(anyhow!("missing `trading_pair`"))?
+                    .try_into()?,
+                reserves_1: value
+                    .reserves_1
+                    .ok_or(anyhow!("missing `reserves_1`"))?
+                    .try_into()?,
+                reserves_2: value
+                    .reserves_2
+                    .ok_or(anyhow!("missing `reserves_2`"))?
+                    .try_into()?,
+            })
+        }
+        inner(value).context(format!("parsing {}", pb::EventPositionEvicted::NAME))
+    }
+}
+
+impl From<EventPositionEvicted> for pb::EventPositionEvicted {
+    fn from(value: EventPositionEvicted) -> Self {
+        Self {
+            position_id: Some(value.position_id.into()),
+            trading_pair: Some(value.trading_pair.into()),
+            reserves_1: Some(value.reserves_1.into()),
+            reserves_2: Some(value.reserves_2.into()),
+        }
+    }
+}
+
+impl DomainType for EventPositionEvicted {
+    type Proto = pb::EventPositionEvicted;
+}
+
 #[derive(Clone, Debug)]
 pub struct EventQueuePositionClose {
     pub position_id: position::Id,
@@ -661,3 +724,107 @@ impl From<EventCandlestickData> for pb::EventCandlestickData {
 impl DomainType for EventCandlestickData {
     type Proto = pb::EventCandlestickData;
 }
+
+/// A circuit breaker trip, recorded at the end of the block in which it happened.
+#[derive(Clone, Debug)]
+pub struct EventCircuitBreakerTrip {
+    pub height: u64,
+    pub trip: CircuitBreakerTrip,
+}
+
+#[derive(Clone, Debug)]
+pub enum CircuitBreakerTrip {
+    /// A debit exceeded the value balance held by the DEX, rejecting the transaction.
+    Value {
+        asset_id: asset::Id,
+        balance: Amount,
+        debit: Amount,
+        transaction_id: Option<TransactionId>,
+    },
+    /// A route-and-fill exhausted its execution budget with input left unfilled.
+    Execution {
+        trading_pair: DirectedTradingPair,
+        input: Amount,
+        unfilled: Amount,
+        execution_budget: u32,
+    },
+}
+
+impl TryFrom<pb::EventCircuitBreakerTrip> for EventCircuitBreakerTrip {
+    type Error = anyhow::Error;
+
+    fn try_from(value: pb::EventCircuitBreakerTrip) -> Result<Self, Self::Error> {
+        fn inner(value: pb::EventCircuitBreakerTrip) -> anyhow::Result<EventCircuitBreakerTrip> {
+            let trip = match value.trip.ok_or(anyhow!("missing `trip`"))? {
+                pb::event_circuit_breaker_trip::Trip::Value(trip) => CircuitBreakerTrip::Value {
+                    asset_id: trip
+                        .asset_id
+                        .ok_or(anyhow!("missing `asset_id`"))?
+                        .try_into()?,
+                    balance: trip
+                        .balance
+                        .ok_or(anyhow!("missing `balance`"))?
+                        .try_into()?,
+                    debit: trip.debit.ok_or(anyhow!("missing `debit`"))?.try_into()?,
+                    transaction_id: trip.transaction_id.map(TryInto::try_into).transpose()?,
+                },
+                pb::event_circuit_breaker_trip::Trip::Execution(trip) => {
+                    CircuitBreakerTrip::Execution {
+                        trading_pair: trip
+                            .trading_pair
+                            .ok_or(anyhow!("missing `trading_pair`"))?
+                            .try_into()?,
+                        input: trip.input.ok_or(anyhow!("missing `input`"))?.try_into()?,
+                        unfilled: trip
+                            .unfilled
+                            .ok_or(anyhow!("missing `unfilled`"))?
+                            .try_into()?,
+                        execution_budget: trip.execution_budget,
+                    }
+                }
+            };
+            Ok(EventCircuitBreakerTrip {
+                height: value.height,
+                trip,
+            })
+        }
+        inner(value).context(format!("parsing {}", pb::EventCircuitBreakerTrip::NAME))
+    }
+}
+
+impl From<EventCircuitBreakerTrip> for pb::EventCircuitBreakerTrip {
+    fn from(value: EventCircuitBreakerTrip) -> Self {
+        let trip = match value.trip {
+            CircuitBreakerTrip::Value {
+                asset_id,
+                balance,
+                debit,
+                transaction_id,
+            } => pb::event_circuit_breaker_trip::Trip::Value(pb::ValueCircuitBreakerTrip {
+                asset_id: Some(asset_id.into()),
+                balance: Some(balance.into()),
+                debit: Some(debit.into()),
+                transaction_id: transaction_id.map(Into::into),
+            }),
+            CircuitBreakerTrip::Execution {
+                trading_pair,
+                input,
+                unfilled,
+                execution_budget,
+            } => pb::event_circuit_breaker_trip::Trip::Execution(pb::ExecutionCircuitBreakerTrip {
+                trading_pair: Some(trading_pair.into()),
+                input: Some(input.into()),
+                unfilled: Some(unfilled.into()),
+                execution_budget,
+            }),
+        };
+        Self {
+            height: value.height,
+            trip: Some(trip),
+        }
+    }
+}
+
+impl DomainType for EventCircuitBreakerTrip {
+    type Proto = pb::EventCircuitBreakerTrip;
+}
@@ -0,0 +1,150 @@
+use penumbra_proto::{penumbra::core::component::dex::v1 as pb, DomainType};
+
+use super::{position, Reserves};
+
+/// The executions against a liquidity position during a single block, netted into the change of
+/// its reserves and the total inflow of each asset.
+///
+/// A position may be executed against several times in a block, in both directions, so its
+/// fills and fees can't be recovered from the change of its reserves alone: the inflows are
+/// summed across the executions, and the outflows follow from them.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PositionExecutionSummary {
+    pub position_id: position::Id,
+    /// The reserves before the first execution in the block.
+    pub prev_reserves: Reserves,
+    /// The reserves after the last execution in the block.
+    pub reserves: Reserves,
+    /// The total amount of each asset traded into the position in the block.
+    pub inflows: Reserves,
+}
+
+impl PositionExecutionSummary {
+    /// Summarizes a single execution moving the reserves of a position from `prev` to `new`.
+    pub fn new(position_id: position::Id, prev: &Reserves, new: &Reserves) -> Self {
+        Self {
+            position_id,
+            prev_reserves: prev.clone(),
+            reserves: new.clone(),
+            inflows: inflows(prev, new),
+        }
+    }
+
+    /// Adds a later execution moving the reserves of the position from `prev` to `new`.
+    pub fn record(&mut self, prev: &Reserves, new: &Reserves) {
+        let inflows = inflows(prev, new);
+        self.inflows.r1 += inflows.r1;
+        self.inflows.r2 += inflows.r2;
+        self.reserves = new.clone();
+    }
+
+    /// The total amount of each asset traded out of the position in the block.
+    pub fn outflows(&self) -> Reserves {
+        Reserves {
+            r1: (self.prev_reserves.r1 + self.inflows.r1).saturating_sub(&self.reserves.r1),
+            r2: (self.prev_reserves.r2 + self.inflows.r2).saturating_sub(&self.reserves.r2),
+        }
+    }
+}
+
+/// The amount of each asset traded into a position by an execution from `prev` to `new`.
+fn inflows(prev: &Reserves, new: &Reserves) -> Reserves {
+    Reserves {
+        r1: new.r1.saturating_sub(&prev.r1),
+        r2: new.r2.saturating_sub(&prev.r2),
+    }
+}
+
+impl DomainType for PositionExecutionSummary {
+    type Proto = pb::PositionExecutionSummary;
+}
+
+impl TryFrom<pb::PositionExecutionSummary> for PositionExecutionSummary {
+    type Error = anyhow::Error;
+
+    fn try_from(value: pb::PositionExecutionSummary) -> Result<Self, Self::Error> {
+        Ok(Self {
+            position_id: value
+                .position_id
+                .ok_or_else(|| anyhow::anyhow!("missing position_id"))?
+                .try_into()?,
+            prev_reserves: value
+                .prev_reserves
+                .ok_or_else(|| anyhow::anyhow!("missing prev_reserves"))?
+                .try_into()?,
+            reserves: value
+                .reserves
+                .ok_or_else(|| anyhow::anyhow!("missing reserves"))?
+                .try_into()?,
+            inflows: value
+                .inflows
+                .ok_or_else(|| anyhow::anyhow!("missing inflows"))?
+                .try_into()?,
+        })
+    }
+}
+
+impl From<PositionExecutionSummary> for pb::PositionExecutionSummary {
+    fn from(value: PositionExecutionSummary) -> Self {
+        Self {
+            position_id: Some(value.position_id.into()),
+            prev_reserves: Some(value.prev_reserves.into()),
+            reserves: Some(value.reserves.into()),
+            inflows: Some(value.inflows.into()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn reserves(r1: u64, r2: u64) -> Reserves {
+        Reserves {
+            r1: r1.into(),
+            r2: r2.into(),
+        }
+    }
+
+    #[test]
+    fn single_execution_is_summarized() {
+        let summary = PositionExecutionSummary::new(
+            position::Id([0; 32]),
+            &reserves(100, 0),
+            &reserves(40, 61),
+        );
+        assert_eq!(summary.inflows, reserves(0, 61));
+        assert_eq!(summary.outflows(), reserves(60, 0));
+    }
+
+    #[test]
+    fn executions_in_both_directions_keep_their_gross_flows() {
+        let mut summary = PositionExecutionSummary::new(
+            position::Id([0; 32]),
+            &reserves(100, 0),
+            &reserves(40, 61),
+        );
+        // The position is filled back the other way, ending with a gain of 1 of asset 1.
+        summary.record(&reserves(40, 61), &reserves(101, 0));
+
+        assert_eq!(summary.prev_reserves, reserves(100, 0));
+        assert_eq!(summary.reserves, reserves(101, 0));
+        // Netting the reserves alone would only show an inflow of 1 of asset 1.
+        assert_eq!(summary.inflows, reserves(61, 61));
+        assert_eq!(summary.outflows(), reserves(60, 61));
+    }
+
+    #[test]
+    fn summary_roundtrips_through_proto() {
+        let mut summary = PositionExecutionSummary::new(
+            position::Id([1; 32]),
+            &reserves(10, 20),
+            &reserves(5, 26),
+        );
+        summary.record(&reserves(5, 26), &reserves(0, 32));
+        let roundtripped =
+            PositionExecutionSummary::try_from(pb::PositionExecutionSummary::from(summary.clone()))
+                .expect("summary roundtrips");
+        assert_eq!(roundtripped, summary);
+    }
+}
@@ -11,7 +11,11 @@ pub mod config {
 }
 
 pub fn value_balance(asset_id: &asset::Id) -> String {
-    format!("dex/value_balance/{asset_id}")
+    format!("{}{asset_id}", value_balances())
+}
+
+pub fn value_balances() -> &'static str {
+    "dex/value_balance/"
 }
 
 pub fn positions(trading_pair: &TradingPair, position_id: &str) -> String {
@@ -78,6 +82,10 @@ pub fn output_data(height: u64, trading_pair: TradingPair) -> String {
     )
 }
 
+pub fn output_data_at_height(height: u64) -> String {
+    format!("dex/output/{:020}/", height)
+}
+
 pub fn swap_execution(height: u64, trading_pair: DirectedTradingPair) -> String {
     format!(
         "dex/swap_execution/{:020}/{}/{}",
@@ -117,6 +125,38 @@ pub fn pending_outputs() -> &'static str {
     "dex/pending_outputs"
 }
 
+pub fn pending_position_executions() -> &'static str {
+    "dex/pending_position_executions"
+}
+
+pub fn pending_evicted_positions() -> &'static str {
+    "dex/pending_evicted_positions"
+}
+
+pub fn pending_circuit_breaker_trips() -> &'static str {
+    "dex/pending_circuit_breaker_trips"
+}
+
+pub mod circuit_breaker {
+    use crate::DirectedTradingPair;
+
+    pub fn trips() -> &'static str {
+        "dex/circuit_breaker/trip/"
+    }
+
+    pub fn trip(height: u64, index: usize) -> String {
+        format!("{}{height:020}/{index:04}", trips())
+    }
+
+    pub fn execution_budget_usage() -> &'static str {
+        "dex/circuit_breaker/execution_budget/"
+    }
+
+    pub fn execution_budget_usage_by_pair(pair: &DirectedTradingPair) -> String {
+        format!("{}{}/{}", execution_budget_usage(), &pair.start, &pair.end)
+    }
+}
+
 pub fn aggregate_value() -> &'static str {
     "dex/aggregate_value"
 }
@@ -2,6 +2,7 @@ use cnidarium_component::ActionHandler;
 
 pub mod delegator_vote;
 pub mod deposit_claim;
+pub mod proxy_vote;
 pub mod validator_vote;
 pub mod withdraw;
 
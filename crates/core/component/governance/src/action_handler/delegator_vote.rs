@@ -5,10 +5,11 @@ use cnidarium::StateWrite;
 use decaf377::Fr;
 use penumbra_proof_params::DELEGATOR_VOTE_PROOF_VERIFICATION_KEY;
 use penumbra_proto::StateWriteProto as _;
+use penumbra_sct::component::clock::EpochRead as _;
 use penumbra_txhash::TransactionContext;
 
 use crate::{
-    event, DelegatorVote, DelegatorVoteBody, DelegatorVoteProofPublic,
+    event, DelegatorVote, DelegatorVoteBody, DelegatorVoteProofPublic, StandingProxyDelegation,
     {component::StateWriteExt, StateReadExt},
 };
 use cnidarium_component::ActionHandler;
@@ -31,6 +32,8 @@ impl ActionHandler for DelegatorVote {
                     unbonded_amount: _,
                     vote: _,     // Only used when executing the vote
                     proposal: _, // Checked against the current open proposals statefully
+                    proxy: _,    // Only used when executing the vote
+                    standing_proxy: _,
                 },
         } = self;
 
@@ -64,6 +67,8 @@ impl ActionHandler for DelegatorVote {
                     unbonded_amount,
                     nullifier,
                     rk: _, // We already used this to check the auth sig in stateless verification
+                    proxy,
+                    standing_proxy,
                 },
             auth_sig: _, // We already checked this in stateless verification
             proof: _,    // We already checked this in stateless verification
@@ -87,9 +92,40 @@ impl ActionHandler for DelegatorVote {
             .mark_nullifier_voted_on_proposal(*proposal, nullifier)
             .await;
         let identity_key = state.validator_by_delegation_asset(value.asset_id).await?;
-        state
-            .cast_delegator_vote(*proposal, identity_key, *vote, nullifier, *unbonded_amount)
-            .await?;
+        if let Some(proxy) = proxy {
+            state
+                .cast_proxied_delegator_vote(
+                    *proposal,
+                    identity_key,
+                    *proxy,
+                    *vote,
+                    *unbonded_amount,
+                )
+                .await?;
+        } else {
+            state
+                .cast_delegator_vote(*proposal, identity_key, *vote, nullifier, *unbonded_amount)
+                .await?;
+        }
+
+        if *standing_proxy {
+            // The standing delegation applies to proposals started after this block, until the
+            // note is spent.
+            if let Some(proxy) = proxy {
+                let height = state.get_block_height().await?;
+                state.put_standing_proxy_delegation(
+                    nullifier,
+                    StandingProxyDelegation {
+                        proxy: *proxy,
+                        identity_key,
+                        value: *value,
+                        height,
+                    },
+                );
+            } else {
+                state.clear_standing_proxy_delegation(nullifier);
+            }
+        }
 
         state.record_proto(event::delegator_vote(self, &identity_key));
 
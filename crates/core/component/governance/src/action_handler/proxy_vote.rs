@@ -0,0 +1,48 @@
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+use cnidarium::StateWrite;
+use penumbra_proto::{DomainType, StateWriteProto as _};
+
+use crate::component::StateWriteExt;
+use crate::event;
+use crate::{action_handler::ActionHandler, StateReadExt};
+use crate::{ProxyVote, ProxyVoteBody};
+
+#[async_trait]
+impl ActionHandler for ProxyVote {
+    type CheckStatelessContext = ();
+    async fn check_stateless(&self, _context: ()) -> Result<()> {
+        let ProxyVote { body, auth_sig } = self;
+
+        // Check the signature using the proxy's governance key:
+        let body_bytes = body.encode_to_vec();
+        body.proxy
+            .0
+            .verify(&body_bytes, auth_sig)
+            .context("proxy vote signature failed to verify")?;
+
+        Ok(())
+    }
+
+    async fn check_and_execute<S: StateWrite>(&self, mut state: S) -> Result<()> {
+        let ProxyVote {
+            auth_sig: _,
+            body:
+                ProxyVoteBody {
+                    proposal,
+                    vote,
+                    proxy,
+                },
+        } = self;
+
+        state.check_proposal_votable(*proposal).await?;
+        state.check_proxy_has_not_voted(*proposal, proxy).await?;
+
+        tracing::debug!(%proxy, proposal = %proposal, "cast proxy vote");
+        state.cast_proxy_vote(*proposal, *proxy, *vote);
+
+        state.record_proto(event::proxy_vote(self));
+
+        Ok(())
+    }
+}
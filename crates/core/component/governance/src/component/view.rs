@@ -1,16 +1,19 @@
 use std::{
     collections::{BTreeMap, BTreeSet},
     str::FromStr,
+    time::Duration,
 };
 
 use anyhow::{Context, Result};
 use async_trait::async_trait;
 use cnidarium::{StateRead, StateWrite};
-use futures::StreamExt;
-use ibc_types::core::client::ClientId;
+use futures::{StreamExt, TryStreamExt};
+use ibc_types::core::{client::ClientId, connection::ConnectionId};
 use penumbra_asset::{asset, Value, STAKING_TOKEN_DENOM};
+use penumbra_community_pool::component::{StateReadExt as _, StateWriteExt as _};
 use penumbra_ibc::component::ClientStateReadExt as _;
 use penumbra_ibc::component::ClientStateWriteExt as _;
+use penumbra_ibc::component::Ics27StateWriteExt as _;
 use penumbra_num::Amount;
 use penumbra_proto::{StateReadProto, StateWriteProto};
 use penumbra_sct::{
@@ -31,14 +34,18 @@ use penumbra_stake::{rate::RateData, validator};
 use crate::{
     change::ParameterChange,
     params::GovernanceParameters,
-    proposal::{Proposal, ProposalPayload},
+    proposal::{BatchCondition, Proposal, ProposalPayload},
     proposal_state::State as ProposalState,
+    proxy_vote::StandingProxyDelegation,
     state_key::persistent_flags,
     validator_vote::action::ValidatorVoteReason,
     vote::Vote,
 };
 use crate::{state_key, tally::Tally};
 
+#[cfg(test)]
+mod tests;
+
 #[async_trait]
 pub trait StateReadExt: StateRead + penumbra_stake::StateReadExt {
     /// Returns true if the next height is an upgrade height.
@@ -117,6 +124,40 @@ pub trait StateReadExt: StateRead + penumbra_stake::StateReadExt {
         Ok(proposals)
     }
 
+    /// Get the ids of the batch proposals to be enacted in this block (scheduled in last block).
+    async fn pending_batch_proposals(&self) -> Result<Vec<u64>> {
+        let prefix = state_key::deliver_batch_proposals_at_height(self.get_block_height().await?);
+        self.prefix_proto::<u64>(&prefix)
+            .map(|result| anyhow::Ok(result?.1))
+            .try_collect()
+            .await
+    }
+
+    /// Check that the conditions of a batch proposal hold in the current state.
+    async fn check_batch_conditions(&self, conditions: &[BatchCondition]) -> Result<()> {
+        for condition in conditions {
+            match condition {
+                BatchCondition::ProposalPassed { proposal_id } => {
+                    let passed = self
+                        .proposal_state(*proposal_id)
+                        .await?
+                        .is_some_and(|state| state.is_passed());
+                    anyhow::ensure!(passed, "proposal {proposal_id} has not passed");
+                }
+                BatchCondition::CommunityPoolBalanceAtLeast { value } => {
+                    let balance = self.community_pool_asset_balance(value.asset_id).await?;
+                    anyhow::ensure!(
+                        balance >= value.amount,
+                        "Community Pool balance of {} is less than {}",
+                        balance,
+                        value.amount,
+                    );
+                }
+            }
+        }
+        Ok(())
+    }
+
     /// Get the vote of a validator on a particular proposal.
     async fn validator_vote(
         &self,
@@ -128,6 +169,13 @@ pub trait StateReadExt: StateRead + penumbra_stake::StateReadExt {
             .await?)
     }
 
+    /// Get the vote of a proxy on a particular proposal.
+    async fn proxy_vote(&self, proposal_id: u64, proxy: &GovernanceKey) -> Result<Option<Vote>> {
+        Ok(self
+            .get::<Vote>(&state_key::proxy_vote(proposal_id, proxy))
+            .await?)
+    }
+
     /// Get the proposal voting start block for a given proposal.
     async fn proposal_voting_start(&self, proposal_id: u64) -> Result<Option<u64>> {
         Ok(self
@@ -348,6 +396,23 @@ pub trait StateReadExt: StateRead + penumbra_stake::StateReadExt {
         Ok(())
     }
 
+    /// Check that the proxy has not voted on the proposal.
+    async fn check_proxy_has_not_voted(
+        &self,
+        proposal_id: u64,
+        proxy: &GovernanceKey,
+    ) -> Result<()> {
+        if let Some(_vote) = self.proxy_vote(proposal_id, proxy).await? {
+            anyhow::bail!(
+                "proxy {} has already voted on proposal {}",
+                proxy,
+                proposal_id
+            );
+        }
+
+        Ok(())
+    }
+
     /// Check that the governance key matches the validator's identity key.
     async fn check_governance_key_matches_validator(
         &self,
@@ -528,6 +593,101 @@ pub trait StateReadExt: StateRead + penumbra_stake::StateReadExt {
         Ok(tallies)
     }
 
+    /// Get the voting power delegated to proxies on the proposal, for each validator, counted
+    /// toward each proxy's vote if it has cast one.
+    async fn proxied_delegator_votes(
+        &self,
+        proposal_id: u64,
+    ) -> Result<BTreeMap<IdentityKey, Tally>> {
+        let mut tallies: BTreeMap<IdentityKey, Tally> = BTreeMap::new();
+        let mut proxy_votes: BTreeMap<GovernanceKey, Option<Vote>> = BTreeMap::new();
+
+        let prefix = state_key::all_proxied_delegator_votes_for_proposal(proposal_id);
+        let mut stream = self.prefix::<Tally>(&prefix);
+
+        while let Some((key, tally)) = stream.next().await.transpose()? {
+            let mut reverse_path_elements = key.rsplit('/');
+            let identity_key: IdentityKey = reverse_path_elements
+                .next()
+                .ok_or_else(|| anyhow::anyhow!("incorrect key format for proxied delegator vote"))?
+                .parse()?;
+            let proxy: GovernanceKey = reverse_path_elements
+                .next()
+                .ok_or_else(|| anyhow::anyhow!("incorrect key format for proxied delegator vote"))?
+                .parse()?;
+
+            let proxy_vote = match proxy_votes.get(&proxy) {
+                Some(vote) => *vote,
+                None => {
+                    let vote = self.proxy_vote(proposal_id, &proxy).await?;
+                    proxy_votes.insert(proxy, vote);
+                    vote
+                }
+            };
+            *tallies.entry(identity_key).or_default() += tally.proxied(proxy_vote);
+        }
+
+        Ok(tallies)
+    }
+
+    /// Get the voting power of standing proxy delegations on the proposal, for each validator,
+    /// counted toward each proxy's vote if it has cast one.
+    ///
+    /// Only the standing delegations recorded for the proposal when it started count, and only
+    /// for notes that have not voted on the proposal themselves.
+    async fn standing_proxied_votes(
+        &self,
+        proposal_id: u64,
+    ) -> Result<BTreeMap<IdentityKey, Tally>> {
+        let mut tallies: BTreeMap<IdentityKey, Tally> = BTreeMap::new();
+        let mut proxy_votes: BTreeMap<GovernanceKey, Option<Vote>> = BTreeMap::new();
+
+        let prefix = state_key::all_standing_proxy_delegations_for_proposal(proposal_id);
+        let mut stream = self.prefix::<StandingProxyDelegation>(&prefix);
+
+        while let Some((key, delegation)) = stream.next().await.transpose()? {
+            let nullifier = Nullifier::parse_hex(key.rsplit('/').next().ok_or_else(|| {
+                anyhow::anyhow!("incorrect key format for standing proxy delegation")
+            })?)?;
+
+            // A note that voted on the proposal itself overrides its standing delegation.
+            if self
+                .get_proto::<u64>(&state_key::voted_nullifier_lookup_for_proposal(
+                    proposal_id,
+                    &nullifier,
+                ))
+                .await?
+                .is_some()
+            {
+                continue;
+            }
+
+            let proxy_vote = match proxy_votes.get(&delegation.proxy) {
+                Some(vote) => *vote,
+                None => {
+                    let vote = self.proxy_vote(proposal_id, &delegation.proxy).await?;
+                    proxy_votes.insert(delegation.proxy, vote);
+                    vote
+                }
+            };
+            let Some(vote) = proxy_vote else {
+                continue;
+            };
+
+            let Some(rate_data) = self
+                .rate_data_at_proposal_start(proposal_id, delegation.identity_key)
+                .await?
+            else {
+                continue;
+            };
+
+            let power = rate_data.unbonded_amount(delegation.value.amount).value() as u64;
+            *tallies.entry(delegation.identity_key).or_default() += (vote, power).into();
+        }
+
+        Ok(tallies)
+    }
+
     /// Add up all the currently tallied votes (without tallying any cast votes that haven't been
     /// tallied yet).
     async fn current_tally(&self, proposal_id: u64) -> Result<Tally> {
@@ -537,6 +697,15 @@ pub trait StateReadExt: StateRead + penumbra_stake::StateReadExt {
         let mut validator_votes = self.validator_votes(proposal_id).await?;
         let mut delegator_tallies = self.tallied_delegator_votes(proposal_id).await?;
 
+        // Voting power delegated to proxies overrides the validators' votes just like direct
+        // delegator votes do.
+        for (validator, tally) in self.proxied_delegator_votes(proposal_id).await? {
+            *delegator_tallies.entry(validator).or_default() += tally;
+        }
+        for (validator, tally) in self.standing_proxied_votes(proposal_id).await? {
+            *delegator_tallies.entry(validator).or_default() += tally;
+        }
+
         // For each validator, tally their own vote, overriding it with any tallied delegator votes
         let mut tally = Tally::default();
         for (validator, power) in validator_powers.into_iter() {
@@ -780,6 +949,84 @@ pub trait StateWriteExt: StateWrite + penumbra_ibc::component::ConnectionStateWr
         Ok(())
     }
 
+    /// Record a delegator vote on a proposal which delegates its voting power to a proxy.
+    ///
+    /// The delegator's own vote is counted if the proxy does not vote on the proposal.
+    async fn cast_proxied_delegator_vote(
+        &mut self,
+        proposal_id: u64,
+        identity_key: IdentityKey,
+        proxy: GovernanceKey,
+        vote: Vote,
+        unbonded_amount: Amount,
+    ) -> Result<()> {
+        // Convert the unbonded amount into voting power
+        let power = unbonded_amount.value() as u64;
+
+        // Add the vote into the aggregate for this proxy and validator
+        let key = state_key::proxied_delegator_votes(proposal_id, &proxy, identity_key);
+        let mut tally = self.get::<Tally>(&key).await?.unwrap_or_default();
+        tally += (vote, power).into();
+        self.put(key, tally);
+
+        Ok(())
+    }
+
+    /// Record a proxy's vote on a proposal.
+    fn cast_proxy_vote(&mut self, proposal_id: u64, proxy: GovernanceKey, vote: Vote) {
+        self.put(state_key::proxy_vote(proposal_id, &proxy), vote);
+    }
+
+    /// Record a standing delegation of a note's voting weight to a proxy, replacing any previous
+    /// one for the same note.
+    fn put_standing_proxy_delegation(
+        &mut self,
+        nullifier: &Nullifier,
+        delegation: StandingProxyDelegation,
+    ) {
+        self.put(state_key::standing_proxy_delegation(nullifier), delegation);
+    }
+
+    /// Clear the standing delegation of a note's voting weight, if any.
+    fn clear_standing_proxy_delegation(&mut self, nullifier: &Nullifier) {
+        self.delete(state_key::standing_proxy_delegation(nullifier));
+    }
+
+    /// Record the standing delegations that apply to a proposal as it starts, so that tallying
+    /// it reads only those, and prune the standing delegations of notes that have been spent.
+    ///
+    /// A standing delegation applies to the proposal if it was recorded before the block in which
+    /// the proposal started, and its note is unspent.
+    async fn snapshot_standing_proxy_delegations(&mut self, proposal_id: u64) -> Result<()> {
+        let Some(start_height) = self.proposal_voting_start(proposal_id).await? else {
+            anyhow::bail!("proposal {} does not exist", proposal_id);
+        };
+
+        let delegations = self
+            .prefix::<StandingProxyDelegation>(state_key::all_standing_proxy_delegations())
+            .and_then(|(key, delegation)| async move {
+                let nullifier = Nullifier::parse_hex(key.rsplit('/').next().ok_or_else(|| {
+                    anyhow::anyhow!("incorrect key format for standing proxy delegation")
+                })?)?;
+                Ok((nullifier, delegation))
+            })
+            .try_collect::<Vec<_>>()
+            .await?;
+
+        for (nullifier, delegation) in delegations {
+            if self.spend_info(nullifier).await?.is_some() {
+                self.clear_standing_proxy_delegation(&nullifier);
+            } else if delegation.height < start_height {
+                self.put(
+                    state_key::standing_proxy_delegation_for_proposal(proposal_id, &nullifier),
+                    delegation,
+                );
+            }
+        }
+
+        Ok(())
+    }
+
     /// Tally delegator votes by sweeping them into the aggregate for each validator, for each proposal.
     #[instrument(skip(self))]
     async fn tally_delegator_votes(&mut self, just_for_proposal: Option<u64>) -> Result<()> {
@@ -869,7 +1116,10 @@ pub trait StateWriteExt: StateWrite + penumbra_ibc::component::ConnectionStateWr
         &mut self,
         proposal_id: u64,
         payload: &ProposalPayload,
-    ) -> Result<Result<()>> // inner error from proposal execution
+    ) -> Result<Result<()>>
+    // inner error from proposal execution
+    where
+        Self: Sized,
     {
         match payload {
             ProposalPayload::Signaling { .. } => {
@@ -933,6 +1183,87 @@ pub trait StateWriteExt: StateWrite + penumbra_ibc::component::ConnectionStateWr
                 let unfrozen_client = client_state.unfrozen();
                 self.put_client(client_id, unfrozen_client);
             }
+            ProposalPayload::CommunityPoolVestingGrant {
+                recipient,
+                total,
+                cliff_epochs,
+                release_per_epoch,
+            } => {
+                let current_epoch = self.get_current_epoch().await?;
+                // The grant's funds are set aside now, so this fails if the Community Pool
+                // can't fund it.
+                if let Err(error) = self
+                    .create_vesting_grant(
+                        proposal_id,
+                        recipient.clone(),
+                        *total,
+                        *cliff_epochs,
+                        *release_per_epoch,
+                        current_epoch.index,
+                    )
+                    .await
+                {
+                    return Ok(Err(error));
+                }
+                tracing::info!(
+                    grant_id = proposal_id,
+                    "created Community Pool vesting grant"
+                );
+            }
+            ProposalPayload::CancelCommunityPoolVestingGrant { grant_id } => {
+                let current_epoch = self.get_current_epoch().await?;
+                if let Err(error) = self
+                    .cancel_vesting_grant(*grant_id, current_epoch.index)
+                    .await
+                {
+                    return Ok(Err(error));
+                }
+                tracing::info!(grant_id, "cancelled Community Pool vesting grant");
+            }
+            ProposalPayload::RegisterInterchainAccount { connection_id } => {
+                let connection_id = &ConnectionId::from_str(connection_id)
+                    .map_err(|e| tonic::Status::aborted(format!("invalid connection id: {e}")))?;
+                // The connection may have closed since the proposal was submitted, or the account
+                // may have been registered by another proposal, so this can fail.
+                if let Err(error) = self.register_interchain_account(connection_id).await {
+                    return Ok(Err(error));
+                }
+                tracing::info!(%connection_id, "registering Community Pool interchain account");
+            }
+            ProposalPayload::InterchainAccountTx {
+                connection_id,
+                messages,
+                memo,
+                timeout_seconds,
+            } => {
+                let connection_id = &ConnectionId::from_str(connection_id)
+                    .map_err(|e| tonic::Status::aborted(format!("invalid connection id: {e}")))?;
+                let now = self.get_current_block_timestamp().await?;
+                // The account's channel may not be open, e.g. if an earlier transaction timed out
+                // and closed it, so this can fail.
+                if let Err(error) = self
+                    .send_interchain_account_tx(
+                        proposal_id,
+                        connection_id,
+                        messages.clone(),
+                        memo.clone(),
+                        Duration::from_secs(*timeout_seconds),
+                        now,
+                    )
+                    .await
+                {
+                    return Ok(Err(error));
+                }
+                tracing::info!(
+                    %connection_id,
+                    "sent transaction to Community Pool interchain account"
+                );
+            }
+            ProposalPayload::Batch { .. } => {
+                // A batch's Community Pool spend must execute together with its other payloads,
+                // which the `App` does at the start of the next block, so it's scheduled then.
+                self.deliver_batch_proposal(proposal_id).await?;
+            }
         }
         Ok(Ok(()))
     }
@@ -953,6 +1284,19 @@ pub trait StateWriteExt: StateWrite + penumbra_ibc::component::ConnectionStateWr
         Ok(())
     }
 
+    async fn deliver_batch_proposal(&mut self, proposal: u64) -> Result<()> {
+        // Schedule for beginning of next block
+        let delivery_height = self.get_block_height().await? + 1;
+
+        tracing::info!(%proposal, %delivery_height, "scheduling batch proposal for enactment at next block");
+
+        self.put_proto(
+            state_key::deliver_single_batch_proposal_at_height(delivery_height, proposal),
+            proposal,
+        );
+        Ok(())
+    }
+
     /// Records the next upgrade height.
     /// After commititng the height, the chain should halt and wait for an upgrade.
     /// It re-uses the same mechanism as emergency halting that prevents the chain from
@@ -0,0 +1,159 @@
+use cnidarium::{Snapshot, StateDelta, TempStorage};
+use decaf377::Fq;
+use decaf377_rdsa::{SigningKey, SpendAuth, VerificationKey};
+use penumbra_asset::Value;
+use penumbra_num::Amount;
+use penumbra_proto::{StateReadProto as _, StateWriteProto as _};
+use penumbra_sct::{
+    component::{clock::EpochManager as _, tree::SctManager as _},
+    CommitmentSource, Nullifier,
+};
+use penumbra_stake::{rate::RateData, DelegationToken, GovernanceKey, IdentityKey};
+use rand_core::OsRng;
+
+use super::{StateReadExt as _, StateWriteExt as _};
+use crate::{
+    proxy_vote::StandingProxyDelegation, state_key, tally::Tally,
+    validator_vote::action::ValidatorVoteReason, vote::Vote,
+};
+
+const PROPOSAL: u64 = 0;
+const START_HEIGHT: u64 = 5;
+const VALIDATOR_POWER: u64 = 100;
+
+/// Set up a proposal started at `START_HEIGHT`, with a single active validator at a 1:1 exchange
+/// rate, which votes yes.
+fn setup_proposal(state: &mut StateDelta<Snapshot>) -> IdentityKey {
+    let identity_key =
+        IdentityKey(VerificationKey::from(SigningKey::<SpendAuth>::new(OsRng)).into());
+
+    state.put_block_height(START_HEIGHT);
+    state.put_proposal_voting_start(PROPOSAL, START_HEIGHT);
+    state.put(
+        state_key::rate_data_at_proposal_start(PROPOSAL, identity_key),
+        RateData {
+            identity_key,
+            validator_reward_rate: Amount::zero(),
+            validator_exchange_rate: Amount::from(100_000_000u64),
+        },
+    );
+    state.put_proto(
+        state_key::voting_power_at_proposal_start(PROPOSAL, identity_key),
+        VALIDATOR_POWER,
+    );
+    state.cast_validator_vote(
+        PROPOSAL,
+        identity_key,
+        Vote::Yes,
+        ValidatorVoteReason("test".to_string()),
+    );
+
+    identity_key
+}
+
+fn proxy_key() -> GovernanceKey {
+    GovernanceKey(VerificationKey::from(SigningKey::<SpendAuth>::new(OsRng)))
+}
+
+fn standing_delegation(
+    proxy: GovernanceKey,
+    identity_key: IdentityKey,
+    amount: u64,
+    height: u64,
+) -> StandingProxyDelegation {
+    StandingProxyDelegation {
+        proxy,
+        identity_key,
+        value: Value {
+            amount: amount.into(),
+            asset_id: DelegationToken::new(identity_key).id(),
+        },
+        height,
+    }
+}
+
+fn tally(yes: u64, no: u64, abstain: u64) -> Tally {
+    Tally::from((Vote::Yes, yes))
+        + Tally::from((Vote::No, no))
+        + Tally::from((Vote::Abstain, abstain))
+}
+
+#[tokio::test]
+/// Voting power delegated to a proxy counts toward the proxy's vote, and otherwise toward the
+/// delegator's own vote.
+async fn proxied_delegator_votes_follow_proxy_vote() -> anyhow::Result<()> {
+    let storage = TempStorage::new().await?;
+    let mut state = StateDelta::new(storage.latest_snapshot());
+    let identity_key = setup_proposal(&mut state);
+    let proxy = proxy_key();
+
+    state
+        .cast_proxied_delegator_vote(PROPOSAL, identity_key, proxy, Vote::Abstain, 30u64.into())
+        .await?;
+
+    // Without a proxy vote, the delegator's own vote counts, replacing the validator's.
+    assert_eq!(state.current_tally(PROPOSAL).await?, tally(70, 0, 30));
+
+    state.cast_proxy_vote(PROPOSAL, proxy, Vote::No);
+    assert_eq!(state.current_tally(PROPOSAL).await?, tally(70, 30, 0));
+
+    Ok(())
+}
+
+#[tokio::test]
+/// A standing delegation counts toward the proxy's vote for notes which did not vote, and is
+/// overridden by a note's own vote without being counted twice. Only the delegations recorded
+/// when the proposal started count, and those of spent notes are pruned.
+async fn standing_proxy_delegations_count_once() -> anyhow::Result<()> {
+    let storage = TempStorage::new().await?;
+    let mut state = StateDelta::new(storage.latest_snapshot());
+    let identity_key = setup_proposal(&mut state);
+    let proxy = proxy_key();
+
+    let silent = Nullifier(Fq::from(1u64));
+    let voted = Nullifier(Fq::from(2u64));
+    let late = Nullifier(Fq::from(3u64));
+    let spent = Nullifier(Fq::from(4u64));
+    state.put_standing_proxy_delegation(&silent, standing_delegation(proxy, identity_key, 10, 1));
+    state.put_standing_proxy_delegation(&voted, standing_delegation(proxy, identity_key, 20, 1));
+    // Recorded once the proposal had already started, so it does not apply to it.
+    state.put_standing_proxy_delegation(
+        &late,
+        standing_delegation(proxy, identity_key, 40, START_HEIGHT),
+    );
+    state.put_standing_proxy_delegation(&spent, standing_delegation(proxy, identity_key, 80, 1));
+    state
+        .nullify(spent, CommitmentSource::Transaction { id: Some([0; 32]) })
+        .await;
+    state.snapshot_standing_proxy_delegations(PROPOSAL).await?;
+    assert!(state
+        .get::<StandingProxyDelegation>(&state_key::standing_proxy_delegation(&spent))
+        .await?
+        .is_none());
+
+    // The note with a standing delegation votes directly on the proposal.
+    state
+        .mark_nullifier_voted_on_proposal(PROPOSAL, &voted)
+        .await;
+    state
+        .cast_delegator_vote(PROPOSAL, identity_key, Vote::Yes, &voted, 20u64.into())
+        .await?;
+    state.tally_delegator_votes(Some(PROPOSAL)).await?;
+
+    // Until the proxy votes, the silent note's power stays with the validator.
+    assert!(state.standing_proxied_votes(PROPOSAL).await?.is_empty());
+    assert_eq!(state.current_tally(PROPOSAL).await?, tally(100, 0, 0));
+
+    state.cast_proxy_vote(PROPOSAL, proxy, Vote::No);
+    assert_eq!(
+        state.standing_proxied_votes(PROPOSAL).await?,
+        [(identity_key, tally(0, 10, 0))].into_iter().collect()
+    );
+    assert_eq!(state.current_tally(PROPOSAL).await?, tally(90, 10, 0));
+
+    // Clearing the standing delegation once the proposal has started does not affect it.
+    state.clear_standing_proxy_delegation(&silent);
+    assert_eq!(state.current_tally(PROPOSAL).await?, tally(90, 10, 0));
+
+    Ok(())
+}
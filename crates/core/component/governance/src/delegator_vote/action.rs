@@ -6,6 +6,7 @@ use penumbra_asset::Value;
 use penumbra_num::Amount;
 use penumbra_proto::{core::component::governance::v1 as pb, DomainType};
 use penumbra_sct::Nullifier;
+use penumbra_stake::GovernanceKey;
 use penumbra_tct as tct;
 use penumbra_txhash::{EffectHash, EffectingData};
 
@@ -39,6 +40,12 @@ pub struct DelegatorVoteBody {
     pub nullifier: Nullifier,
     /// The randomized validating key for the spend authorization signature.
     pub rk: VerificationKey<SpendAuth>,
+    /// The proxy the voting weight is delegated to, if any, whose vote replaces `vote` if it
+    /// casts one.
+    pub proxy: Option<GovernanceKey>,
+    /// Whether `proxy` is recorded as a standing delegation for the note, which also applies to
+    /// proposals started later; if `proxy` is `None`, any standing delegation is cleared.
+    pub standing_proxy: bool,
 }
 
 impl EffectingData for DelegatorVoteBody {
@@ -57,6 +64,8 @@ impl From<DelegatorVoteBody> for pb::DelegatorVoteBody {
             unbonded_amount: Some(value.unbonded_amount.into()),
             nullifier: Some(value.nullifier.into()),
             rk: Some(value.rk.into()),
+            proxy: value.proxy.map(Into::into),
+            standing_proxy: value.standing_proxy,
         }
     }
 }
@@ -93,6 +102,12 @@ impl TryFrom<pb::DelegatorVoteBody> for DelegatorVoteBody {
                 .ok_or_else(|| anyhow::anyhow!("missing rk in `DelegatorVote`"))?
                 .try_into()
                 .context("invalid rk in `DelegatorVote`")?,
+            proxy: msg
+                .proxy
+                .map(TryInto::try_into)
+                .transpose()
+                .context("invalid proxy in `DelegatorVote`")?,
+            standing_proxy: msg.standing_proxy,
         })
     }
 }
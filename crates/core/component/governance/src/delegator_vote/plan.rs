@@ -8,6 +8,7 @@ use penumbra_proof_params::DELEGATOR_VOTE_PROOF_PROVING_KEY;
 use penumbra_proto::{core::component::governance::v1 as pb, DomainType};
 use penumbra_sct::Nullifier;
 use penumbra_shielded_pool::Note;
+use penumbra_stake::GovernanceKey;
 use penumbra_tct as tct;
 use rand::{CryptoRng, RngCore};
 use serde::{Deserialize, Serialize};
@@ -40,6 +41,10 @@ pub struct DelegatorVotePlan {
     pub proof_blinding_r: Fq,
     /// The second blinding factor used for generating the ZK proof.
     pub proof_blinding_s: Fq,
+    /// The proxy to delegate the voting weight to, if any.
+    pub proxy: Option<GovernanceKey>,
+    /// Whether to record the proxy choice as a standing delegation for the staked note.
+    pub standing_proxy: bool,
 }
 
 impl DelegatorVotePlan {
@@ -53,6 +58,8 @@ impl DelegatorVotePlan {
         staked_note: Note,
         position: tct::Position,
         unbonded_amount: Amount,
+        proxy: Option<GovernanceKey>,
+        standing_proxy: bool,
     ) -> DelegatorVotePlan {
         DelegatorVotePlan {
             proposal,
@@ -64,6 +71,8 @@ impl DelegatorVotePlan {
             randomizer: Fr::rand(rng),
             proof_blinding_r: Fq::rand(rng),
             proof_blinding_s: Fq::rand(rng),
+            proxy,
+            standing_proxy,
         }
     }
 
@@ -95,6 +104,8 @@ impl DelegatorVotePlan {
                 &self.staked_note.commit(),
             ),
             rk: fvk.spend_verification_key().randomize(&self.randomizer),
+            proxy: self.proxy,
+            standing_proxy: self.standing_proxy,
         }
     }
 
@@ -164,6 +175,8 @@ impl From<DelegatorVotePlan> for pb::DelegatorVotePlan {
             randomizer: inner.randomizer.to_bytes().to_vec(),
             proof_blinding_r: inner.proof_blinding_r.to_bytes().to_vec(),
             proof_blinding_s: inner.proof_blinding_s.to_bytes().to_vec(),
+            proxy: inner.proxy.map(Into::into),
+            standing_proxy: inner.standing_proxy,
         }
     }
 }
@@ -209,6 +222,8 @@ impl TryFrom<pb::DelegatorVotePlan> for DelegatorVotePlan {
                 .expect("proof_blinding_r malformed"),
             proof_blinding_s: Fq::from_bytes_checked(&proof_blinding_s_bytes)
                 .expect("proof_blinding_s malformed"),
+            proxy: value.proxy.map(TryInto::try_into).transpose()?,
+            standing_proxy: value.standing_proxy,
         })
     }
 }
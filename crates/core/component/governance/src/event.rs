@@ -2,7 +2,8 @@ use penumbra_proto::penumbra::core::component::governance::v1 as pb;
 use penumbra_stake::IdentityKey;
 
 use crate::{
-    DelegatorVote, Proposal, ProposalDepositClaim, ProposalSubmit, ProposalWithdraw, ValidatorVote,
+    DelegatorVote, Proposal, ProposalDepositClaim, ProposalSubmit, ProposalWithdraw, ProxyVote,
+    ValidatorVote,
 };
 
 pub fn delegator_vote(
@@ -30,6 +31,12 @@ pub fn validator_vote(validator_vote: &ValidatorVote, voting_power: u64) -> pb::
     }
 }
 
+pub fn proxy_vote(proxy_vote: &ProxyVote) -> pb::EventProxyVote {
+    pb::EventProxyVote {
+        vote: Some(pb::ProxyVote::from(proxy_vote.clone())),
+    }
+}
+
 pub fn proposal_withdraw(withdraw: &ProposalWithdraw) -> pb::EventProposalWithdraw {
     pb::EventProposalWithdraw {
         withdraw: Some(pb::ProposalWithdraw::from(withdraw.clone())),
@@ -17,6 +17,9 @@ pub use validator_vote::{
     ValidatorVote, ValidatorVoteBody, ValidatorVoteReason, MAX_VALIDATOR_VOTE_REASON_LENGTH,
 };
 
+pub mod proxy_vote;
+pub use proxy_vote::{ProxyVote, ProxyVoteBody, StandingProxyDelegation};
+
 pub mod proposal_submit;
 pub use proposal_submit::ProposalSubmit;
 
@@ -24,7 +27,7 @@ pub mod proposal_withdraw;
 pub use proposal_withdraw::ProposalWithdraw;
 
 pub mod proposal;
-pub use proposal::{Proposal, ProposalKind, ProposalPayload};
+pub use proposal::{BatchCondition, Proposal, ProposalKind, ProposalPayload};
 
 pub mod proposal_nft;
 pub mod proposal_state;
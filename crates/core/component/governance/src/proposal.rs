@@ -5,6 +5,10 @@ use serde::{Deserialize, Serialize};
 use std::str::FromStr;
 
 use crate::change::ParameterChange;
+use penumbra_asset::{asset::Cache, Value};
+use penumbra_ibc::InterchainAccountMessage;
+use penumbra_keys::Address;
+use penumbra_num::Amount;
 use penumbra_proto::{penumbra::core::component::governance::v1 as pb, DomainType};
 
 /// A governance proposal.
@@ -29,53 +33,12 @@ pub const TRANSACTION_PLAN_TYPE_URL: &str = "/penumbra.core.transaction.v1.Trans
 
 impl From<Proposal> for pb::Proposal {
     fn from(inner: Proposal) -> pb::Proposal {
-        let mut proposal = pb::Proposal {
+        pb::Proposal {
             id: inner.id,
             title: inner.title,
             description: inner.description,
-            ..Default::default() // We're about to fill in precisely one of the fields for the payload
-        };
-        use pb::proposal::Payload;
-        let payload = match inner.payload {
-            ProposalPayload::Signaling { commit } => {
-                Some(Payload::Signaling(pb::proposal::Signaling {
-                    commit: if let Some(c) = commit {
-                        c
-                    } else {
-                        String::default()
-                    },
-                }))
-            }
-            ProposalPayload::Emergency { halt_chain } => {
-                Some(Payload::Emergency(pb::proposal::Emergency { halt_chain }))
-            }
-            ProposalPayload::ParameterChange(change) => {
-                Some(Payload::ParameterChange(change.into()))
-            }
-            ProposalPayload::CommunityPoolSpend { transaction_plan } => Some(
-                Payload::CommunityPoolSpend(pb::proposal::CommunityPoolSpend {
-                    transaction_plan: Some(pbjson_types::Any {
-                        type_url: TRANSACTION_PLAN_TYPE_URL.to_owned(),
-                        value: transaction_plan.into(),
-                    }),
-                }),
-            ),
-            ProposalPayload::UpgradePlan { height } => {
-                Some(Payload::UpgradePlan(pb::proposal::UpgradePlan { height }))
-            }
-            ProposalPayload::FreezeIbcClient { client_id } => {
-                Some(Payload::FreezeIbcClient(pb::proposal::FreezeIbcClient {
-                    client_id: client_id.into(),
-                }))
-            }
-            ProposalPayload::UnfreezeIbcClient { client_id } => Some(Payload::UnfreezeIbcClient(
-                pb::proposal::UnfreezeIbcClient {
-                    client_id: client_id.into(),
-                },
-            )),
-        };
-        proposal.payload = payload;
-        proposal
+            payload: Some(inner.payload.into()),
+        }
     }
 }
 
@@ -94,81 +57,320 @@ impl TryFrom<pb::Proposal> for Proposal {
             anyhow::bail!("proposal description must be less than 10,000 characters");
         }
 
-        use pb::proposal::Payload;
         Ok(Proposal {
             id: inner.id,
             title: inner.title,
             description: inner.description,
-            payload: match inner
+            payload: inner
                 .payload
                 .ok_or_else(|| anyhow::anyhow!("missing proposal payload"))?
-            {
-                Payload::Signaling(signaling) => ProposalPayload::Signaling {
-                    commit: if signaling.commit.is_empty() {
-                        None
-                    } else {
-                        // Commit hash has max length of 255 bytes:
-                        if signaling.commit.len() > 255 {
-                            anyhow::bail!("proposal commit hash must be less than 255 bytes");
-                        }
+                .try_into()?,
+        })
+    }
+}
 
-                        Some(signaling.commit)
-                    },
+impl From<ProposalPayload> for pb::proposal::Payload {
+    fn from(payload: ProposalPayload) -> Self {
+        use pb::proposal::Payload;
+        match payload {
+            ProposalPayload::Signaling { commit } => Payload::Signaling(pb::proposal::Signaling {
+                commit: if let Some(c) = commit {
+                    c
+                } else {
+                    String::default()
                 },
-                Payload::Emergency(emergency) => ProposalPayload::Emergency {
-                    halt_chain: emergency.halt_chain,
+            }),
+            ProposalPayload::Emergency { halt_chain } => {
+                Payload::Emergency(pb::proposal::Emergency { halt_chain })
+            }
+            ProposalPayload::ParameterChange(change) => Payload::ParameterChange(change.into()),
+            ProposalPayload::CommunityPoolSpend { transaction_plan } => {
+                Payload::CommunityPoolSpend(pb::proposal::CommunityPoolSpend {
+                    transaction_plan: Some(pbjson_types::Any {
+                        type_url: TRANSACTION_PLAN_TYPE_URL.to_owned(),
+                        value: transaction_plan.into(),
+                    }),
+                })
+            }
+            ProposalPayload::UpgradePlan { height } => {
+                Payload::UpgradePlan(pb::proposal::UpgradePlan { height })
+            }
+            ProposalPayload::FreezeIbcClient { client_id } => {
+                Payload::FreezeIbcClient(pb::proposal::FreezeIbcClient {
+                    client_id: client_id.into(),
+                })
+            }
+            ProposalPayload::UnfreezeIbcClient { client_id } => {
+                Payload::UnfreezeIbcClient(pb::proposal::UnfreezeIbcClient {
+                    client_id: client_id.into(),
+                })
+            }
+            ProposalPayload::CommunityPoolVestingGrant {
+                recipient,
+                total,
+                cliff_epochs,
+                release_per_epoch,
+            } => Payload::CommunityPoolVestingGrant(pb::proposal::CommunityPoolVestingGrant {
+                recipient: Some(recipient.into()),
+                total: Some(total.into()),
+                cliff_epochs,
+                release_per_epoch: Some(release_per_epoch.into()),
+            }),
+            ProposalPayload::CancelCommunityPoolVestingGrant { grant_id } => {
+                Payload::CancelCommunityPoolVestingGrant(
+                    pb::proposal::CancelCommunityPoolVestingGrant { grant_id },
+                )
+            }
+            ProposalPayload::RegisterInterchainAccount { connection_id } => {
+                Payload::RegisterInterchainAccount(pb::proposal::RegisterInterchainAccount {
+                    connection_id,
+                })
+            }
+            ProposalPayload::InterchainAccountTx {
+                connection_id,
+                messages,
+                memo,
+                timeout_seconds,
+            } => Payload::InterchainAccountTx(pb::proposal::InterchainAccountTx {
+                connection_id,
+                messages: messages.into_iter().map(Into::into).collect(),
+                memo,
+                timeout_seconds,
+            }),
+            ProposalPayload::Batch {
+                payloads,
+                conditions,
+            } => Payload::Batch(pb::proposal::Batch {
+                payloads: payloads.into_iter().map(Into::into).collect(),
+                conditions: conditions.into_iter().map(Into::into).collect(),
+            }),
+        }
+    }
+}
+
+impl TryFrom<pb::proposal::Payload> for ProposalPayload {
+    type Error = anyhow::Error;
+
+    fn try_from(payload: pb::proposal::Payload) -> Result<Self, Self::Error> {
+        use pb::proposal::Payload;
+        Ok(match payload {
+            Payload::Signaling(signaling) => ProposalPayload::Signaling {
+                commit: if signaling.commit.is_empty() {
+                    None
+                } else {
+                    // Commit hash has max length of 255 bytes:
+                    if signaling.commit.len() > 255 {
+                        anyhow::bail!("proposal commit hash must be less than 255 bytes");
+                    }
+
+                    Some(signaling.commit)
                 },
-                Payload::ParameterChange(change) => {
-                    ProposalPayload::ParameterChange(change.try_into()?)
+            },
+            Payload::Emergency(emergency) => ProposalPayload::Emergency {
+                halt_chain: emergency.halt_chain,
+            },
+            Payload::ParameterChange(change) => {
+                ProposalPayload::ParameterChange(change.try_into()?)
+            }
+            Payload::CommunityPoolSpend(community_pool_spend) => {
+                ProposalPayload::CommunityPoolSpend {
+                    transaction_plan: {
+                        let transaction_plan = community_pool_spend
+                            .transaction_plan
+                            .ok_or_else(|| anyhow::anyhow!("missing transaction plan"))?;
+                        if transaction_plan.type_url != TRANSACTION_PLAN_TYPE_URL {
+                            anyhow::bail!(
+                                "unknown transaction plan type url: {}",
+                                transaction_plan.type_url
+                            );
+                        }
+                        transaction_plan.value.to_vec()
+                    },
                 }
-                Payload::CommunityPoolSpend(community_pool_spend) => {
-                    ProposalPayload::CommunityPoolSpend {
-                        transaction_plan: {
-                            let transaction_plan = community_pool_spend
-                                .transaction_plan
-                                .ok_or_else(|| anyhow::anyhow!("missing transaction plan"))?;
-                            if transaction_plan.type_url != TRANSACTION_PLAN_TYPE_URL {
-                                anyhow::bail!(
-                                    "unknown transaction plan type url: {}",
-                                    transaction_plan.type_url
-                                );
-                            }
-                            transaction_plan.value.to_vec()
-                        },
-                    }
+            }
+            Payload::UpgradePlan(upgrade_plan) => ProposalPayload::UpgradePlan {
+                height: upgrade_plan.height,
+            },
+            Payload::FreezeIbcClient(freeze_ibc_client) => {
+                // Validation: client ID has a max length of 128 bytes
+                if freeze_ibc_client.client_id.len() > 128 {
+                    anyhow::bail!("client ID must be less than 128 bytes");
                 }
-                Payload::UpgradePlan(upgrade_plan) => ProposalPayload::UpgradePlan {
-                    height: upgrade_plan.height,
-                },
-                Payload::FreezeIbcClient(freeze_ibc_client) => {
-                    // Validation: client ID has a max length of 128 bytes
-                    if freeze_ibc_client.client_id.len() > 128 {
-                        anyhow::bail!("client ID must be less than 128 bytes");
-                    }
-                    // Validation: Check the client ID is valid using the validation inside `ClientId::from_str`.
-                    ClientId::from_str(&freeze_ibc_client.client_id)
-                        .map_err(|e| anyhow::anyhow!("invalid client id: {e}"))?;
-                    ProposalPayload::FreezeIbcClient {
-                        client_id: freeze_ibc_client.client_id,
-                    }
+                // Validation: Check the client ID is valid using the validation inside `ClientId::from_str`.
+                ClientId::from_str(&freeze_ibc_client.client_id)
+                    .map_err(|e| anyhow::anyhow!("invalid client id: {e}"))?;
+                ProposalPayload::FreezeIbcClient {
+                    client_id: freeze_ibc_client.client_id,
                 }
-                Payload::UnfreezeIbcClient(unfreeze_ibc_client) => {
-                    // Validation: client ID has a max length of 128 bytes
-                    if unfreeze_ibc_client.client_id.len() > 128 {
-                        anyhow::bail!("client ID must be less than 128 bytes");
-                    }
-                    // Validation: Check the client ID is valid using the validation inside `ClientId::from_str`.
-                    ClientId::from_str(&unfreeze_ibc_client.client_id)
-                        .map_err(|e| anyhow::anyhow!("invalid client id: {e}"))?;
-                    ProposalPayload::UnfreezeIbcClient {
-                        client_id: unfreeze_ibc_client.client_id,
-                    }
+            }
+            Payload::UnfreezeIbcClient(unfreeze_ibc_client) => {
+                // Validation: client ID has a max length of 128 bytes
+                if unfreeze_ibc_client.client_id.len() > 128 {
+                    anyhow::bail!("client ID must be less than 128 bytes");
+                }
+                // Validation: Check the client ID is valid using the validation inside `ClientId::from_str`.
+                ClientId::from_str(&unfreeze_ibc_client.client_id)
+                    .map_err(|e| anyhow::anyhow!("invalid client id: {e}"))?;
+                ProposalPayload::UnfreezeIbcClient {
+                    client_id: unfreeze_ibc_client.client_id,
+                }
+            }
+            Payload::CommunityPoolVestingGrant(grant) => {
+                ProposalPayload::CommunityPoolVestingGrant {
+                    recipient: grant
+                        .recipient
+                        .ok_or_else(|| anyhow::anyhow!("missing vesting grant recipient"))?
+                        .try_into()
+                        .context("malformed vesting grant recipient")?,
+                    total: grant
+                        .total
+                        .ok_or_else(|| anyhow::anyhow!("missing vesting grant total"))?
+                        .try_into()
+                        .context("malformed vesting grant total")?,
+                    cliff_epochs: grant.cliff_epochs,
+                    release_per_epoch: grant
+                        .release_per_epoch
+                        .ok_or_else(|| anyhow::anyhow!("missing vesting grant release per epoch"))?
+                        .try_into()?,
+                }
+            }
+            Payload::CancelCommunityPoolVestingGrant(cancel) => {
+                ProposalPayload::CancelCommunityPoolVestingGrant {
+                    grant_id: cancel.grant_id,
                 }
+            }
+            Payload::RegisterInterchainAccount(register) => {
+                ProposalPayload::RegisterInterchainAccount {
+                    connection_id: register.connection_id,
+                }
+            }
+            Payload::InterchainAccountTx(tx) => ProposalPayload::InterchainAccountTx {
+                connection_id: tx.connection_id,
+                messages: tx.messages.into_iter().map(Into::into).collect(),
+                memo: tx.memo,
+                timeout_seconds: tx.timeout_seconds,
             },
+            Payload::Batch(batch) => {
+                let payloads = batch
+                    .payloads
+                    .into_iter()
+                    .enumerate()
+                    .map(|(index, payload)| {
+                        ProposalPayload::try_from(payload)
+                            .with_context(|| format!("malformed batched payload {index}"))
+                    })
+                    .collect::<anyhow::Result<Vec<_>>>()?;
+                let conditions = batch
+                    .conditions
+                    .into_iter()
+                    .map(TryInto::try_into)
+                    .collect::<anyhow::Result<Vec<_>>>()?;
+                ProposalPayload::validate_batch(&payloads)?;
+                ProposalPayload::Batch {
+                    payloads,
+                    conditions,
+                }
+            }
         })
     }
 }
 
+impl From<ProposalPayload> for pb::proposal::BatchPayload {
+    fn from(payload: ProposalPayload) -> Self {
+        use pb::proposal::{batch_payload::Payload as Batched, Payload};
+        let payload = match Payload::from(payload) {
+            Payload::Signaling(signaling) => Some(Batched::Signaling(signaling)),
+            Payload::ParameterChange(change) => Some(Batched::ParameterChange(change)),
+            Payload::CommunityPoolSpend(spend) => Some(Batched::CommunityPoolSpend(spend)),
+            Payload::UpgradePlan(plan) => Some(Batched::UpgradePlan(plan)),
+            Payload::FreezeIbcClient(freeze) => Some(Batched::FreezeIbcClient(freeze)),
+            Payload::UnfreezeIbcClient(unfreeze) => Some(Batched::UnfreezeIbcClient(unfreeze)),
+            Payload::CommunityPoolVestingGrant(grant) => {
+                Some(Batched::CommunityPoolVestingGrant(grant))
+            }
+            Payload::CancelCommunityPoolVestingGrant(cancel) => {
+                Some(Batched::CancelCommunityPoolVestingGrant(cancel))
+            }
+            Payload::RegisterInterchainAccount(register) => {
+                Some(Batched::RegisterInterchainAccount(register))
+            }
+            Payload::InterchainAccountTx(tx) => Some(Batched::InterchainAccountTx(tx)),
+            // These can't be batched, so they are left out, and the batch fails to decode.
+            Payload::Emergency(_) | Payload::Batch(_) => None,
+        };
+        Self { payload }
+    }
+}
+
+impl TryFrom<pb::proposal::BatchPayload> for ProposalPayload {
+    type Error = anyhow::Error;
+
+    fn try_from(msg: pb::proposal::BatchPayload) -> Result<Self, Self::Error> {
+        use pb::proposal::{batch_payload::Payload as Batched, Payload};
+        let payload = match msg
+            .payload
+            .ok_or_else(|| anyhow::anyhow!("missing batched payload"))?
+        {
+            Batched::Signaling(signaling) => Payload::Signaling(signaling),
+            Batched::ParameterChange(change) => Payload::ParameterChange(change),
+            Batched::CommunityPoolSpend(spend) => Payload::CommunityPoolSpend(spend),
+            Batched::UpgradePlan(plan) => Payload::UpgradePlan(plan),
+            Batched::FreezeIbcClient(freeze) => Payload::FreezeIbcClient(freeze),
+            Batched::UnfreezeIbcClient(unfreeze) => Payload::UnfreezeIbcClient(unfreeze),
+            Batched::CommunityPoolVestingGrant(grant) => Payload::CommunityPoolVestingGrant(grant),
+            Batched::CancelCommunityPoolVestingGrant(cancel) => {
+                Payload::CancelCommunityPoolVestingGrant(cancel)
+            }
+            Batched::RegisterInterchainAccount(register) => {
+                Payload::RegisterInterchainAccount(register)
+            }
+            Batched::InterchainAccountTx(tx) => Payload::InterchainAccountTx(tx),
+        };
+        payload.try_into()
+    }
+}
+
+impl From<BatchCondition> for pb::proposal::BatchCondition {
+    fn from(condition: BatchCondition) -> Self {
+        use pb::proposal::batch_condition::Condition;
+        Self {
+            condition: Some(match condition {
+                BatchCondition::ProposalPassed { proposal_id } => {
+                    Condition::ProposalPassed(proposal_id)
+                }
+                BatchCondition::CommunityPoolBalanceAtLeast { value } => {
+                    Condition::CommunityPoolBalanceAtLeast(value.into())
+                }
+            }),
+        }
+    }
+}
+
+impl TryFrom<pb::proposal::BatchCondition> for BatchCondition {
+    type Error = anyhow::Error;
+
+    fn try_from(msg: pb::proposal::BatchCondition) -> Result<Self, Self::Error> {
+        use pb::proposal::batch_condition::Condition;
+        Ok(
+            match msg
+                .condition
+                .ok_or_else(|| anyhow::anyhow!("missing batch condition"))?
+            {
+                Condition::ProposalPassed(proposal_id) => {
+                    BatchCondition::ProposalPassed { proposal_id }
+                }
+                Condition::CommunityPoolBalanceAtLeast(value) => {
+                    BatchCondition::CommunityPoolBalanceAtLeast {
+                        value: value
+                            .try_into()
+                            .context("malformed batch condition balance")?,
+                    }
+                }
+            },
+        )
+    }
+}
+
 impl DomainType for Proposal {
     type Proto = pb::Proposal;
 }
@@ -225,6 +427,16 @@ pub enum ProposalKind {
     FreezeIbcClient,
     /// A proposal to unfreeze an IBC client.
     UnfreezeIbcClient,
+    /// A Community Pool vesting grant proposal.
+    CommunityPoolVestingGrant,
+    /// A proposal to cancel a Community Pool vesting grant.
+    CancelCommunityPoolVestingGrant,
+    /// A proposal to register a Community Pool interchain account.
+    RegisterInterchainAccount,
+    /// A proposal to send a transaction to a Community Pool interchain account.
+    InterchainAccountTx,
+    /// A batch of proposals enacted together.
+    Batch,
 }
 
 impl From<ProposalKind> for pb::ProposalKind {
@@ -237,6 +449,13 @@ impl From<ProposalKind> for pb::ProposalKind {
             ProposalKind::UpgradePlan => pb::ProposalKind::UpgradePlan,
             ProposalKind::FreezeIbcClient => pb::ProposalKind::FreezeIbcClient,
             ProposalKind::UnfreezeIbcClient => pb::ProposalKind::UnfreezeIbcClient,
+            ProposalKind::CommunityPoolVestingGrant => pb::ProposalKind::CommunityPoolVestingGrant,
+            ProposalKind::CancelCommunityPoolVestingGrant => {
+                pb::ProposalKind::CancelCommunityPoolVestingGrant
+            }
+            ProposalKind::RegisterInterchainAccount => pb::ProposalKind::RegisterInterchainAccount,
+            ProposalKind::InterchainAccountTx => pb::ProposalKind::InterchainAccountTx,
+            ProposalKind::Batch => pb::ProposalKind::Batch,
         }
     }
 }
@@ -254,6 +473,13 @@ impl TryFrom<pb::ProposalKind> for ProposalKind {
             pb::ProposalKind::UpgradePlan => ProposalKind::UpgradePlan,
             pb::ProposalKind::FreezeIbcClient => ProposalKind::FreezeIbcClient,
             pb::ProposalKind::UnfreezeIbcClient => ProposalKind::UnfreezeIbcClient,
+            pb::ProposalKind::CommunityPoolVestingGrant => ProposalKind::CommunityPoolVestingGrant,
+            pb::ProposalKind::CancelCommunityPoolVestingGrant => {
+                ProposalKind::CancelCommunityPoolVestingGrant
+            }
+            pb::ProposalKind::RegisterInterchainAccount => ProposalKind::RegisterInterchainAccount,
+            pb::ProposalKind::InterchainAccountTx => ProposalKind::InterchainAccountTx,
+            pb::ProposalKind::Batch => ProposalKind::Batch,
         };
         Ok(kind)
     }
@@ -269,6 +495,13 @@ impl FromStr for ProposalKind {
             "parameter_change" => Ok(ProposalKind::ParameterChange),
             "community_pool_spend" => Ok(ProposalKind::CommunityPoolSpend),
             "upgrade_plan" => Ok(ProposalKind::UpgradePlan),
+            "community_pool_vesting_grant" => Ok(ProposalKind::CommunityPoolVestingGrant),
+            "cancel_community_pool_vesting_grant" => {
+                Ok(ProposalKind::CancelCommunityPoolVestingGrant)
+            }
+            "register_interchain_account" => Ok(ProposalKind::RegisterInterchainAccount),
+            "interchain_account_tx" => Ok(ProposalKind::InterchainAccountTx),
+            "batch" => Ok(ProposalKind::Batch),
             _ => Err(anyhow::anyhow!("invalid proposal kind: {}", s)),
         }
     }
@@ -277,7 +510,14 @@ impl FromStr for ProposalKind {
 impl Proposal {
     /// Get the kind of a proposal.
     pub fn kind(&self) -> ProposalKind {
-        match self.payload {
+        self.payload.kind()
+    }
+}
+
+impl ProposalPayload {
+    /// Get the kind of proposal this payload belongs to.
+    pub fn kind(&self) -> ProposalKind {
+        match self {
             ProposalPayload::Signaling { .. } => ProposalKind::Signaling,
             ProposalPayload::Emergency { .. } => ProposalKind::Emergency,
             ProposalPayload::ParameterChange { .. } => ProposalKind::ParameterChange,
@@ -285,6 +525,17 @@ impl Proposal {
             ProposalPayload::UpgradePlan { .. } => ProposalKind::UpgradePlan,
             ProposalPayload::FreezeIbcClient { .. } => ProposalKind::FreezeIbcClient,
             ProposalPayload::UnfreezeIbcClient { .. } => ProposalKind::UnfreezeIbcClient,
+            ProposalPayload::CommunityPoolVestingGrant { .. } => {
+                ProposalKind::CommunityPoolVestingGrant
+            }
+            ProposalPayload::CancelCommunityPoolVestingGrant { .. } => {
+                ProposalKind::CancelCommunityPoolVestingGrant
+            }
+            ProposalPayload::RegisterInterchainAccount { .. } => {
+                ProposalKind::RegisterInterchainAccount
+            }
+            ProposalPayload::InterchainAccountTx { .. } => ProposalKind::InterchainAccountTx,
+            ProposalPayload::Batch { .. } => ProposalKind::Batch,
         }
     }
 }
@@ -331,19 +582,162 @@ pub enum ProposalPayload {
         /// The identifier of the client to unfreeze.
         client_id: String,
     },
+    /// A Community Pool vesting grant proposal sets aside funds from the Community Pool when it
+    /// passes, which are released to the recipient every epoch once the cliff has passed.
+    CommunityPoolVestingGrant {
+        /// The address that vested funds are paid to.
+        recipient: Address,
+        /// The total value of the grant.
+        total: Value,
+        /// The number of epochs after the proposal passes before any funds vest.
+        cliff_epochs: u64,
+        /// The amount that vests in each epoch since the proposal passed, once the cliff has
+        /// passed.
+        release_per_epoch: Amount,
+    },
+    /// A proposal to cancel a Community Pool vesting grant created by an earlier proposal.
+    CancelCommunityPoolVestingGrant {
+        /// The ID of the grant, which is the ID of the proposal that created it.
+        grant_id: u64,
+    },
+    /// A proposal to register an interchain account for the Community Pool on the chain at the
+    /// other end of an IBC connection, which begins the handshake of the account's channel when
+    /// it passes.
+    RegisterInterchainAccount {
+        /// The identifier of the connection to the chain to host the account.
+        connection_id: String,
+    },
+    /// A proposal to send a transaction to the Community Pool's interchain account on the chain at
+    /// the other end of an IBC connection, when it passes.
+    InterchainAccountTx {
+        /// The identifier of the connection to the chain hosting the account.
+        connection_id: String,
+        /// The messages for the account to execute, in order.
+        messages: Vec<InterchainAccountMessage>,
+        /// A memo to include in the packet carrying the transaction.
+        memo: String,
+        /// How long after the proposal passes the transaction times out, in seconds.
+        timeout_seconds: u64,
+    },
+    /// A batch proposal enacts each of its payloads in order at the start of the block after it
+    /// passes, if its conditions hold then. If any condition doesn't hold or any payload fails
+    /// to enact, including a Community Pool spend's transaction, none of them take effect.
+    Batch {
+        /// The payloads to enact, which may not include another batch, an emergency proposal, or
+        /// more than one payload of the same kind.
+        payloads: Vec<ProposalPayload>,
+        /// The conditions which must all hold for the batch to be enacted.
+        conditions: Vec<BatchCondition>,
+    },
+}
+
+/// A condition on the state of the chain which must hold for a batch proposal to be enacted.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BatchCondition {
+    /// Another proposal, submitted before the batch, must have passed.
+    ProposalPassed { proposal_id: u64 },
+    /// The Community Pool must hold at least this much of an asset.
+    CommunityPoolBalanceAtLeast { value: Value },
 }
 
 /// A TOML-serializable version of `ProposalPayload`, meant for human consumption.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(tag = "kind", rename_all = "snake_case")]
 pub enum ProposalPayloadToml {
-    Signaling { commit: Option<String> },
-    Emergency { halt_chain: bool },
+    Signaling {
+        commit: Option<String>,
+    },
+    Emergency {
+        halt_chain: bool,
+    },
     ParameterChange(ParameterChange),
-    CommunityPoolSpend { transaction: String },
-    UpgradePlan { height: u64 },
-    FreezeIbcClient { client_id: String },
-    UnfreezeIbcClient { client_id: String },
+    CommunityPoolSpend {
+        transaction: String,
+    },
+    UpgradePlan {
+        height: u64,
+    },
+    FreezeIbcClient {
+        client_id: String,
+    },
+    UnfreezeIbcClient {
+        client_id: String,
+    },
+    CommunityPoolVestingGrant {
+        recipient: String,
+        total: String,
+        cliff_epochs: u64,
+        release_per_epoch: String,
+    },
+    CancelCommunityPoolVestingGrant {
+        grant_id: u64,
+    },
+    RegisterInterchainAccount {
+        connection_id: String,
+    },
+    InterchainAccountTx {
+        connection_id: String,
+        messages: Vec<InterchainAccountMessageToml>,
+        #[serde(default)]
+        memo: String,
+        timeout_seconds: u64,
+    },
+    Batch {
+        payloads: Vec<ProposalPayloadToml>,
+        #[serde(default)]
+        conditions: Vec<BatchConditionToml>,
+    },
+}
+
+/// A TOML-serializable version of `BatchCondition`, meant for human consumption.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum BatchConditionToml {
+    ProposalPassed { proposal_id: u64 },
+    CommunityPoolBalanceAtLeast { value: String },
+}
+
+impl TryFrom<BatchConditionToml> for BatchCondition {
+    type Error = anyhow::Error;
+
+    fn try_from(toml: BatchConditionToml) -> Result<Self, Self::Error> {
+        Ok(match toml {
+            BatchConditionToml::ProposalPassed { proposal_id } => {
+                BatchCondition::ProposalPassed { proposal_id }
+            }
+            BatchConditionToml::CommunityPoolBalanceAtLeast { value } => {
+                BatchCondition::CommunityPoolBalanceAtLeast {
+                    value: value
+                        .parse()
+                        .context("couldn't parse batch condition balance")?,
+                }
+            }
+        })
+    }
+}
+
+impl From<BatchCondition> for BatchConditionToml {
+    fn from(condition: BatchCondition) -> Self {
+        match condition {
+            BatchCondition::ProposalPassed { proposal_id } => {
+                BatchConditionToml::ProposalPassed { proposal_id }
+            }
+            BatchCondition::CommunityPoolBalanceAtLeast { value } => {
+                BatchConditionToml::CommunityPoolBalanceAtLeast {
+                    value: value.format(&Cache::with_known_assets()),
+                }
+            }
+        }
+    }
+}
+
+/// A TOML-serializable version of an `InterchainAccountMessage`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct InterchainAccountMessageToml {
+    /// The type URL of the host chain's message, e.g. `/cosmos.staking.v1beta1.MsgDelegate`.
+    pub type_url: String,
+    /// The base64 encoding of the protobuf-encoded message.
+    pub value: String,
 }
 
 impl TryFrom<ProposalPayloadToml> for ProposalPayload {
@@ -377,6 +771,84 @@ impl TryFrom<ProposalPayloadToml> for ProposalPayload {
             ProposalPayloadToml::UnfreezeIbcClient { client_id } => {
                 ProposalPayload::UnfreezeIbcClient { client_id }
             }
+            ProposalPayloadToml::CommunityPoolVestingGrant {
+                recipient,
+                total,
+                cliff_epochs,
+                release_per_epoch,
+            } => {
+                let total: Value = total
+                    .parse()
+                    .context("couldn't parse vesting grant total")?;
+                let release_per_epoch: Value = release_per_epoch
+                    .parse()
+                    .context("couldn't parse vesting grant release per epoch")?;
+                anyhow::ensure!(
+                    release_per_epoch.asset_id == total.asset_id,
+                    "vesting grant must release the same asset as its total"
+                );
+                ProposalPayload::CommunityPoolVestingGrant {
+                    recipient: recipient
+                        .parse()
+                        .context("couldn't parse vesting grant recipient")?,
+                    total,
+                    cliff_epochs,
+                    release_per_epoch: release_per_epoch.amount,
+                }
+            }
+            ProposalPayloadToml::CancelCommunityPoolVestingGrant { grant_id } => {
+                ProposalPayload::CancelCommunityPoolVestingGrant { grant_id }
+            }
+            ProposalPayloadToml::RegisterInterchainAccount { connection_id } => {
+                ProposalPayload::RegisterInterchainAccount { connection_id }
+            }
+            ProposalPayloadToml::InterchainAccountTx {
+                connection_id,
+                messages,
+                memo,
+                timeout_seconds,
+            } => ProposalPayload::InterchainAccountTx {
+                connection_id,
+                messages: messages
+                    .into_iter()
+                    .map(|message| {
+                        Ok(InterchainAccountMessage {
+                            value: base64::Engine::decode(
+                                &base64::engine::general_purpose::STANDARD,
+                                message.value,
+                            )
+                            .with_context(|| {
+                                format!("couldn't decode {} message from base64", message.type_url)
+                            })?,
+                            type_url: message.type_url,
+                        })
+                    })
+                    .collect::<anyhow::Result<Vec<_>>>()?,
+                memo,
+                timeout_seconds,
+            },
+            ProposalPayloadToml::Batch {
+                payloads,
+                conditions,
+            } => {
+                let payloads = payloads
+                    .into_iter()
+                    .enumerate()
+                    .map(|(index, payload)| {
+                        ProposalPayload::try_from(payload)
+                            .with_context(|| format!("invalid batched payload {index}"))
+                    })
+                    .collect::<anyhow::Result<Vec<_>>>()?;
+                let conditions = conditions
+                    .into_iter()
+                    .map(TryInto::try_into)
+                    .collect::<anyhow::Result<Vec<_>>>()?;
+                ProposalPayload::validate_batch(&payloads)?;
+                ProposalPayload::Batch {
+                    payloads,
+                    conditions,
+                }
+            }
         })
     }
 }
@@ -406,6 +878,57 @@ impl From<ProposalPayload> for ProposalPayloadToml {
             ProposalPayload::UnfreezeIbcClient { client_id } => {
                 ProposalPayloadToml::UnfreezeIbcClient { client_id }
             }
+            ProposalPayload::CommunityPoolVestingGrant {
+                recipient,
+                total,
+                cliff_epochs,
+                release_per_epoch,
+            } => {
+                let cache = Cache::with_known_assets();
+                ProposalPayloadToml::CommunityPoolVestingGrant {
+                    recipient: recipient.to_string(),
+                    total: total.format(&cache),
+                    cliff_epochs,
+                    release_per_epoch: Value {
+                        amount: release_per_epoch,
+                        asset_id: total.asset_id,
+                    }
+                    .format(&cache),
+                }
+            }
+            ProposalPayload::CancelCommunityPoolVestingGrant { grant_id } => {
+                ProposalPayloadToml::CancelCommunityPoolVestingGrant { grant_id }
+            }
+            ProposalPayload::RegisterInterchainAccount { connection_id } => {
+                ProposalPayloadToml::RegisterInterchainAccount { connection_id }
+            }
+            ProposalPayload::InterchainAccountTx {
+                connection_id,
+                messages,
+                memo,
+                timeout_seconds,
+            } => ProposalPayloadToml::InterchainAccountTx {
+                connection_id,
+                messages: messages
+                    .into_iter()
+                    .map(|message| InterchainAccountMessageToml {
+                        type_url: message.type_url,
+                        value: base64::Engine::encode(
+                            &base64::engine::general_purpose::STANDARD,
+                            message.value,
+                        ),
+                    })
+                    .collect(),
+                memo,
+                timeout_seconds,
+            },
+            ProposalPayload::Batch {
+                payloads,
+                conditions,
+            } => ProposalPayloadToml::Batch {
+                payloads: payloads.into_iter().map(Into::into).collect(),
+                conditions: conditions.into_iter().map(Into::into).collect(),
+            },
         }
     }
 }
@@ -431,4 +954,96 @@ impl ProposalPayload {
     pub fn is_community_pool_spend(&self) -> bool {
         matches!(self, ProposalPayload::CommunityPoolSpend { .. })
     }
+
+    pub fn is_batch(&self) -> bool {
+        matches!(self, ProposalPayload::Batch { .. })
+    }
+
+    /// Check that a list of payloads can be enacted together as a batch.
+    ///
+    /// Batches can't be nested, and can't contain emergency proposals, since those are passed by
+    /// validators alone. Enacting two payloads of the same kind could clobber each other's
+    /// effects (e.g. a Community Pool spend is stored under the ID of its proposal), so each kind
+    /// may only appear once.
+    pub fn validate_batch(payloads: &[ProposalPayload]) -> anyhow::Result<()> {
+        anyhow::ensure!(
+            !payloads.is_empty(),
+            "batch proposal must contain at least one payload"
+        );
+        let mut kinds = Vec::with_capacity(payloads.len());
+        for payload in payloads {
+            let kind = payload.kind();
+            match kind {
+                ProposalKind::Batch => anyhow::bail!("batch proposals cannot be nested"),
+                ProposalKind::Emergency => {
+                    anyhow::bail!("batch proposals cannot contain an emergency proposal")
+                }
+                _ => {}
+            }
+            if kinds.contains(&kind) {
+                anyhow::bail!("batch proposal contains more than one {kind:?} payload");
+            }
+            kinds.push(kind);
+        }
+        Ok(())
+    }
+
+    /// The payloads enacted by this payload: the contents of a batch, or the payload itself.
+    pub fn enacted_payloads(&self) -> Vec<&ProposalPayload> {
+        match self {
+            ProposalPayload::Batch { payloads, .. } => payloads.iter().collect(),
+            payload => vec![payload],
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use penumbra_asset::STAKING_TOKEN_ASSET_ID;
+
+    fn batch() -> ProposalPayload {
+        ProposalPayload::Batch {
+            payloads: vec![
+                ProposalPayload::Signaling {
+                    commit: Some("abc".to_owned()),
+                },
+                ProposalPayload::CancelCommunityPoolVestingGrant { grant_id: 1 },
+            ],
+            conditions: vec![
+                BatchCondition::ProposalPassed { proposal_id: 0 },
+                BatchCondition::CommunityPoolBalanceAtLeast {
+                    value: Value {
+                        amount: 1_000u64.into(),
+                        asset_id: *STAKING_TOKEN_ASSET_ID,
+                    },
+                },
+            ],
+        }
+    }
+
+    #[test]
+    fn batch_roundtrips_through_proto() {
+        let payload = batch();
+        let roundtripped = ProposalPayload::try_from(pb::proposal::Payload::from(payload.clone()))
+            .expect("batch roundtrips");
+        assert_eq!(roundtripped, payload);
+    }
+
+    #[test]
+    fn batch_roundtrips_through_toml() {
+        let payload = batch();
+        let roundtripped = ProposalPayload::try_from(ProposalPayloadToml::from(payload.clone()))
+            .expect("batch roundtrips");
+        assert_eq!(roundtripped, payload);
+    }
+
+    #[test]
+    fn batched_emergency_fails_to_decode() {
+        let batch = pb::proposal::Payload::Batch(pb::proposal::Batch {
+            payloads: vec![ProposalPayload::Emergency { halt_chain: true }.into()],
+            conditions: vec![],
+        });
+        assert!(ProposalPayload::try_from(batch).is_err());
+    }
 }
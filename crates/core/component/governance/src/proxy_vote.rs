@@ -0,0 +1,5 @@
+pub mod action;
+pub mod standing;
+
+pub use action::{ProxyVote, ProxyVoteBody};
+pub use standing::StandingProxyDelegation;
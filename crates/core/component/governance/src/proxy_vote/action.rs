@@ -0,0 +1,97 @@
+use decaf377_rdsa::{Signature, SpendAuth};
+use penumbra_proto::{penumbra::core::component::governance::v1 as pb, DomainType};
+use penumbra_stake::GovernanceKey;
+use penumbra_txhash::{EffectHash, EffectingData};
+use serde::{Deserialize, Serialize};
+
+use crate::vote::Vote;
+
+/// A vote by a proxy, on behalf of the delegators who delegated their voting weight to it.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(try_from = "pb::ProxyVote", into = "pb::ProxyVote")]
+pub struct ProxyVote {
+    /// The body of the proxy vote.
+    pub body: ProxyVoteBody,
+    /// The signature authorizing the vote (signed with the proxy's governance key over the body).
+    pub auth_sig: Signature<SpendAuth>,
+}
+
+impl EffectingData for ProxyVote {
+    fn effect_hash(&self) -> EffectHash {
+        EffectHash::from_proto_effecting_data(&self.to_proto())
+    }
+}
+
+impl DomainType for ProxyVote {
+    type Proto = pb::ProxyVote;
+}
+
+impl From<ProxyVote> for pb::ProxyVote {
+    fn from(msg: ProxyVote) -> Self {
+        Self {
+            body: Some(msg.body.into()),
+            auth_sig: Some(msg.auth_sig.into()),
+        }
+    }
+}
+
+impl TryFrom<pb::ProxyVote> for ProxyVote {
+    type Error = anyhow::Error;
+
+    fn try_from(msg: pb::ProxyVote) -> Result<Self, Self::Error> {
+        Ok(Self {
+            body: msg
+                .body
+                .ok_or_else(|| anyhow::anyhow!("missing proxy vote body"))?
+                .try_into()?,
+            auth_sig: msg
+                .auth_sig
+                .ok_or_else(|| anyhow::anyhow!("missing proxy auth sig"))?
+                .try_into()?,
+        })
+    }
+}
+
+/// A public vote as a proxy.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(try_from = "pb::ProxyVoteBody", into = "pb::ProxyVoteBody")]
+pub struct ProxyVoteBody {
+    /// The proposal ID to vote on.
+    pub proposal: u64,
+    /// The vote to cast.
+    pub vote: Vote,
+    /// The governance key identifying the proxy who is voting.
+    pub proxy: GovernanceKey,
+}
+
+impl From<ProxyVoteBody> for pb::ProxyVoteBody {
+    fn from(value: ProxyVoteBody) -> Self {
+        pb::ProxyVoteBody {
+            proposal: value.proposal,
+            vote: Some(value.vote.into()),
+            proxy: Some(value.proxy.into()),
+        }
+    }
+}
+
+impl TryFrom<pb::ProxyVoteBody> for ProxyVoteBody {
+    type Error = anyhow::Error;
+
+    fn try_from(msg: pb::ProxyVoteBody) -> Result<Self, Self::Error> {
+        Ok(ProxyVoteBody {
+            proposal: msg.proposal,
+            vote: msg
+                .vote
+                .ok_or_else(|| anyhow::anyhow!("missing vote in `ProxyVote`"))?
+                .try_into()?,
+            proxy: msg
+                .proxy
+                .ok_or_else(|| anyhow::anyhow!("missing proxy governance key in `ProxyVote`"))?
+                .try_into()?,
+        })
+    }
+}
+
+impl DomainType for ProxyVoteBody {
+    type Proto = pb::ProxyVoteBody;
+}
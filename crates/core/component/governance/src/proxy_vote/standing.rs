@@ -0,0 +1,63 @@
+use penumbra_asset::Value;
+use penumbra_proto::{penumbra::core::component::governance::v1 as pb, DomainType};
+use penumbra_stake::{GovernanceKey, IdentityKey};
+use serde::{Deserialize, Serialize};
+
+/// A standing delegation of a delegation note's voting weight to a proxy.
+///
+/// It is recorded by a [`DelegatorVote`](crate::DelegatorVote) with `standing_proxy` set, and
+/// applies to every proposal started after it was recorded, until the note is spent.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(
+    try_from = "pb::StandingProxyDelegation",
+    into = "pb::StandingProxyDelegation"
+)]
+pub struct StandingProxyDelegation {
+    /// The proxy the voting weight is delegated to.
+    pub proxy: GovernanceKey,
+    /// The validator the note is delegated to.
+    pub identity_key: IdentityKey,
+    /// The value of the delegation note.
+    pub value: Value,
+    /// The block height at which the delegation was recorded.
+    pub height: u64,
+}
+
+impl DomainType for StandingProxyDelegation {
+    type Proto = pb::StandingProxyDelegation;
+}
+
+impl From<StandingProxyDelegation> for pb::StandingProxyDelegation {
+    fn from(value: StandingProxyDelegation) -> Self {
+        pb::StandingProxyDelegation {
+            proxy: Some(value.proxy.into()),
+            identity_key: Some(value.identity_key.into()),
+            value: Some(value.value.into()),
+            height: value.height,
+        }
+    }
+}
+
+impl TryFrom<pb::StandingProxyDelegation> for StandingProxyDelegation {
+    type Error = anyhow::Error;
+
+    fn try_from(msg: pb::StandingProxyDelegation) -> Result<Self, Self::Error> {
+        Ok(StandingProxyDelegation {
+            proxy: msg
+                .proxy
+                .ok_or_else(|| anyhow::anyhow!("missing proxy in `StandingProxyDelegation`"))?
+                .try_into()?,
+            identity_key: msg
+                .identity_key
+                .ok_or_else(|| {
+                    anyhow::anyhow!("missing identity key in `StandingProxyDelegation`")
+                })?
+                .try_into()?,
+            value: msg
+                .value
+                .ok_or_else(|| anyhow::anyhow!("missing value in `StandingProxyDelegation`"))?
+                .try_into()?,
+            height: msg.height,
+        })
+    }
+}
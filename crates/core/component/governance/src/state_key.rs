@@ -1,5 +1,5 @@
 use penumbra_sct::Nullifier;
-use penumbra_stake::IdentityKey;
+use penumbra_stake::{GovernanceKey, IdentityKey};
 
 pub fn governance_params() -> &'static str {
     "governance/params"
@@ -108,6 +108,41 @@ pub fn all_untallied_delegator_votes() -> &'static str {
     "governance/untallied_delegator_vote/"
 }
 
+pub fn proxy_vote(proposal_id: u64, proxy: &GovernanceKey) -> String {
+    format!("governance/proxy_vote/{proposal_id:020}/{proxy}")
+}
+
+pub fn proxied_delegator_votes(
+    proposal_id: u64,
+    proxy: &GovernanceKey,
+    identity_key: IdentityKey,
+) -> String {
+    format!("governance/proxied_delegator_votes/{proposal_id:020}/{proxy}/{identity_key}")
+}
+
+pub fn all_proxied_delegator_votes_for_proposal(proposal_id: u64) -> String {
+    // Note: this has to be the prefix of the `proxied_delegator_votes` function above.
+    format!("governance/proxied_delegator_votes/{proposal_id:020}/")
+}
+
+pub fn standing_proxy_delegation(nullifier: &Nullifier) -> String {
+    format!("governance/standing_proxy_delegation/{nullifier}")
+}
+
+pub fn all_standing_proxy_delegations() -> &'static str {
+    // Note: this has to be the prefix of the `standing_proxy_delegation` function above.
+    "governance/standing_proxy_delegation/"
+}
+
+pub fn standing_proxy_delegation_for_proposal(proposal_id: u64, nullifier: &Nullifier) -> String {
+    format!("governance/standing_proxy_delegation_for_proposal/{proposal_id:020}/{nullifier}")
+}
+
+pub fn all_standing_proxy_delegations_for_proposal(proposal_id: u64) -> String {
+    // Note: this has to be the prefix of the `standing_proxy_delegation_for_proposal` function above.
+    format!("governance/standing_proxy_delegation_for_proposal/{proposal_id:020}/")
+}
+
 pub fn deliver_single_community_pool_transaction_at_height(
     block_height: u64,
     proposal_id: u64,
@@ -120,6 +155,15 @@ pub fn deliver_community_pool_transactions_at_height(block_height: u64) -> Strin
     format!("governance/deliver_community_pool_transactions/{block_height}/")
 }
 
+pub fn deliver_single_batch_proposal_at_height(block_height: u64, proposal_id: u64) -> String {
+    format!("governance/deliver_batch_proposals/{block_height}/{proposal_id:020}")
+}
+
+pub fn deliver_batch_proposals_at_height(block_height: u64) -> String {
+    // Note: this has to be the prefix of the `deliver_single_batch_proposal_at_height` function above.
+    format!("governance/deliver_batch_proposals/{block_height}/")
+}
+
 pub fn param_changes_for_height(block_height: u64) -> String {
     format!("governance/param_changes/{block_height}/")
 }
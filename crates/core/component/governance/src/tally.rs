@@ -40,6 +40,19 @@ impl Tally {
     }
 }
 
+impl Tally {
+    /// Re-assign a tally of voting power delegated to a proxy.
+    ///
+    /// If the proxy voted, all of the power counts toward its vote; otherwise, each delegator's
+    /// power counts toward the vote they cast alongside their delegation.
+    pub fn proxied(self, proxy_vote: Option<Vote>) -> Tally {
+        match proxy_vote {
+            Some(vote) => (vote, self.total()).into(),
+            None => self,
+        }
+    }
+}
+
 impl From<Tally> for pb::Tally {
     fn from(tally: Tally) -> Self {
         Self {
@@ -280,3 +293,33 @@ impl From<pb::Ratio> for Ratio {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tally(yes: u64, no: u64, abstain: u64) -> Tally {
+        Tally { yes, no, abstain }
+    }
+
+    #[test]
+    fn proxied_counts_all_power_toward_proxy_vote() {
+        let delegated = tally(10, 20, 5);
+        assert_eq!(delegated.proxied(Some(Vote::No)), tally(0, 35, 0));
+        assert_eq!(delegated.proxied(Some(Vote::Abstain)), tally(0, 0, 35));
+    }
+
+    #[test]
+    fn proxied_falls_back_to_delegator_votes() {
+        let delegated = tally(10, 20, 5);
+        assert_eq!(delegated.proxied(None), delegated);
+    }
+
+    #[test]
+    fn proxied_preserves_total_power() {
+        let delegated = tally(7, 0, 3);
+        for proxy_vote in [None, Some(Vote::Yes), Some(Vote::No), Some(Vote::Abstain)] {
+            assert_eq!(delegated.proxied(proxy_vote).total(), delegated.total());
+        }
+    }
+}
@@ -11,6 +11,7 @@ pub mod rpc;
 
 mod host_interface;
 mod ibc_component;
+mod ics27_controller;
 mod metrics;
 mod msg_handler;
 mod proof_verification;
@@ -31,6 +32,10 @@ pub use client::StateWriteExt as ClientStateWriteExt;
 pub use connection::StateReadExt as ConnectionStateReadExt;
 pub use connection::StateWriteExt as ConnectionStateWriteExt;
 pub use host_interface::HostInterface;
+pub use ics27_controller::Ics27Controller;
+pub use ics27_controller::StateReadExt as Ics27StateReadExt;
+pub use ics27_controller::StateWriteExt as Ics27StateWriteExt;
 pub use view::{StateReadExt, StateWriteExt};
 
 pub use ibc_component::Ibc;
+pub use proof_verification::commit_packet;
@@ -5,7 +5,13 @@
 /// that they have subscribed to, and apply application-specific state transition logic.
 ///
 /// The primary IBC application is the Ics20 transfer application, which allows for interchain
-/// token transfers.
+/// token transfers. Its handler also implements packet-forward middleware semantics: a transfer
+/// whose memo asks to be forwarded is sent on to another chain, and its acknowledgement is
+/// deferred until the forwarded packet is acknowledged or times out.
+///
+/// Packets and channels on ICS-27 controller ports are instead handled by the built-in
+/// [`Ics27Controller`](super::Ics27Controller), through which the Community Pool controls
+/// interchain accounts on other chains.
 use anyhow::Result;
 use async_trait::async_trait;
 use cnidarium::{StateRead, StateWrite};
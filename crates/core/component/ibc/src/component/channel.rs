@@ -4,13 +4,14 @@ use crate::IBC_COMMITMENT_PREFIX;
 
 use anyhow::Result;
 use async_trait::async_trait;
+use futures::{StreamExt, TryStreamExt};
 
 use ibc_types::path::{
     AckPath, ChannelEndPath, CommitmentPath, ReceiptPath, SeqAckPath, SeqRecvPath, SeqSendPath,
 };
 
 use cnidarium::{StateRead, StateWrite};
-use ibc_types::core::channel::{ChannelEnd, ChannelId, Packet, PortId};
+use ibc_types::core::channel::{ChannelEnd, ChannelId, IdentifiedChannelEnd, Packet, PortId};
 use penumbra_proto::{StateReadProto, StateWriteProto};
 
 // Note: many of the methods on this trait need to write raw bytes,
@@ -263,6 +264,94 @@ pub trait StateReadExt: StateRead {
         )
         .await
     }
+
+    /// Returns every channel end on this chain, across all ports, ordered by port and channel.
+    async fn get_channels(&self) -> Result<Vec<IdentifiedChannelEnd>> {
+        let prefix = IBC_COMMITMENT_PREFIX.apply_string("channelEnds/ports/".to_string());
+        let mut channels = self
+            .prefix::<ChannelEnd>(&prefix)
+            .map(|entry| {
+                let (key, channel_end) = entry?;
+                let (port_id, channel_id) = key
+                    .strip_prefix(&prefix)
+                    .and_then(|path| path.split_once("/channels/"))
+                    .ok_or_else(|| anyhow::anyhow!("malformed channel end key {key}"))?;
+                Ok(IdentifiedChannelEnd {
+                    port_id: port_id
+                        .parse()
+                        .map_err(|e| anyhow::anyhow!("invalid port id in key {key}: {e}"))?,
+                    channel_id: channel_id
+                        .parse()
+                        .map_err(|e| anyhow::anyhow!("invalid channel id in key {key}: {e}"))?,
+                    channel_end,
+                })
+            })
+            .try_collect::<Vec<_>>()
+            .await?;
+        // Sort by the length of the channel id first, so that e.g. channel-2 comes before
+        // channel-10.
+        channels.sort_by_key(|channel| {
+            (
+                channel.port_id.to_string(),
+                channel.channel_id.0.len(),
+                channel.channel_id.0.clone(),
+            )
+        });
+        Ok(channels)
+    }
+
+    /// Returns the sequences and commitments of the packets sent over the channel that have
+    /// yet to be acknowledged or timed out, ordered by sequence.
+    async fn get_packet_commitments(
+        &self,
+        channel_id: &ChannelId,
+        port_id: &PortId,
+    ) -> Result<Vec<(u64, Vec<u8>)>> {
+        values_by_sequence(
+            self,
+            format!("commitments/ports/{port_id}/channels/{channel_id}/sequences/"),
+        )
+        .await
+    }
+
+    /// Returns the sequences and acknowledgement commitments of the packets received over the
+    /// channel, ordered by sequence.
+    async fn get_packet_acknowledgements(
+        &self,
+        channel_id: &ChannelId,
+        port_id: &PortId,
+    ) -> Result<Vec<(u64, Vec<u8>)>> {
+        values_by_sequence(
+            self,
+            format!("acks/ports/{port_id}/channels/{channel_id}/sequences/"),
+        )
+        .await
+    }
 }
 
 impl<T: StateRead + ?Sized> StateReadExt for T {}
+
+/// Collects the non-empty values stored under `path_prefix`, whose keys end in a packet
+/// sequence, ordered by sequence.
+async fn values_by_sequence<S: StateRead + ?Sized>(
+    state: &S,
+    path_prefix: String,
+) -> Result<Vec<(u64, Vec<u8>)>> {
+    let prefix = IBC_COMMITMENT_PREFIX.apply_string(path_prefix);
+    let mut values = state
+        .prefix_raw(&prefix)
+        .map(|entry| {
+            let (key, value) = entry?;
+            let sequence = key
+                .strip_prefix(&prefix)
+                .and_then(|sequence| sequence.parse::<u64>().ok())
+                .ok_or_else(|| anyhow::anyhow!("malformed packet key {key}"))?;
+            Ok((sequence, value))
+        })
+        .try_collect::<Vec<_>>()
+        .await?;
+    // As in `get_packet_commitment`, an empty value is treated as absent.
+    values.retain(|(_, value)| !value.is_empty());
+    values.sort_by_key(|(sequence, _)| *sequence);
+    Ok(values)
+}
@@ -627,6 +627,7 @@ mod tests {
             ibc_enabled: true,
             inbound_ics20_transfers_enabled: true,
             outbound_ics20_transfers_enabled: true,
+            ics20_rate_limits: Vec::new(),
         });
         state_tx.put_epoch_by_height(
             1,
@@ -717,6 +718,7 @@ mod tests {
             ibc_enabled: false,
             inbound_ics20_transfers_enabled: true,
             outbound_ics20_transfers_enabled: true,
+            ics20_rate_limits: Vec::new(),
         });
 
         let msg_create_client_stargaze_raw = BASE64_STANDARD
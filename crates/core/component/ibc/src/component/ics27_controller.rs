@@ -0,0 +1,533 @@
+//! The ICS-27 controller application, through which the Community Pool controls accounts on other
+//! chains.
+//!
+//! Interchain accounts are registered and used only by governance proposals, so unlike the ICS-20
+//! transfer application, the controller never accepts a channel handshake started by a relayer:
+//! the handshake is started when a registration proposal passes, and a relayer completes it. Each
+//! transaction sent to an account is recorded in the state along with its outcome, once the host
+//! chain acknowledges it or it times out.
+use std::{str::FromStr, time::Duration};
+
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+use cnidarium::{StateRead, StateWrite};
+use futures::TryStreamExt;
+use ibc_types::core::{
+    channel::{
+        channel::{Order as ChannelOrder, State as ChannelState},
+        events,
+        msgs::{
+            MsgAcknowledgement, MsgChannelCloseConfirm, MsgChannelCloseInit, MsgChannelOpenAck,
+            MsgChannelOpenConfirm, MsgChannelOpenInit, MsgChannelOpenTry, MsgRecvPacket,
+            MsgTimeout,
+        },
+        ChannelEnd, Counterparty, Packet, PortId,
+    },
+    client::Height,
+    connection::{ConnectionId, State as ConnectionState},
+};
+use penumbra_proto::{StateReadProto, StateWriteProto};
+use tendermint::Time;
+
+use crate::{
+    component::{
+        app_handler::{AppHandler, AppHandlerCheck, AppHandlerExecute},
+        channel::{StateReadExt as _, StateWriteExt as _},
+        client::StateReadExt as _,
+        connection::StateReadExt as _,
+        packet::{IBCPacket, SendPacketRead as _, SendPacketWrite as _},
+        state_key,
+    },
+    ics27::{
+        community_pool_controller_port, Ics27Metadata, Ics27PacketData, InterchainAccount,
+        InterchainAccountMessage, InterchainAccountTx, InterchainAccountTxStatus, HOST_PORT,
+    },
+};
+
+/// The number of blocks on the host after which a transaction times out, per second of its
+/// timeout: hosts are assumed to produce blocks at most every 100ms, so that the timeout
+/// timestamp is what times out the transaction.
+const TIMEOUT_BLOCKS_PER_SECOND: u64 = 10;
+
+/// The ICS-27 controller application, routed to for packets and channels on controller ports.
+pub struct Ics27Controller;
+
+#[async_trait]
+impl AppHandlerCheck for Ics27Controller {
+    async fn chan_open_init_check<S: StateRead>(
+        _state: S,
+        _msg: &MsgChannelOpenInit,
+    ) -> Result<()> {
+        anyhow::bail!("interchain accounts can only be registered by governance proposals");
+    }
+
+    async fn chan_open_try_check<S: StateRead>(_state: S, _msg: &MsgChannelOpenTry) -> Result<()> {
+        anyhow::bail!("interchain accounts can't be hosted on Penumbra");
+    }
+
+    async fn chan_open_ack_check<S: StateRead>(state: S, msg: &MsgChannelOpenAck) -> Result<()> {
+        anyhow::ensure!(
+            msg.port_id_on_a == community_pool_controller_port(),
+            "unknown interchain account controller port {}",
+            msg.port_id_on_a
+        );
+        let channel = state
+            .get_channel(&msg.chan_id_on_a, &msg.port_id_on_a)
+            .await?
+            .ok_or_else(|| anyhow::anyhow!("channel not found"))?;
+        let proposed = Ics27Metadata::from_version(&channel.version)?;
+        let accepted = Ics27Metadata::from_version(&msg.version_on_b)?;
+        proposed.check_counterparty(&accepted)
+    }
+
+    async fn chan_open_confirm_check<S: StateRead>(
+        _state: S,
+        _msg: &MsgChannelOpenConfirm,
+    ) -> Result<()> {
+        anyhow::bail!("interchain accounts can't be hosted on Penumbra");
+    }
+
+    async fn chan_close_confirm_check<S: StateRead>(
+        _state: S,
+        _msg: &MsgChannelCloseConfirm,
+    ) -> Result<()> {
+        // the account can be registered again over a new channel.
+        Ok(())
+    }
+
+    async fn chan_close_init_check<S: StateRead>(
+        _state: S,
+        _msg: &MsgChannelCloseInit,
+    ) -> Result<()> {
+        anyhow::bail!("interchain account channels can't be closed");
+    }
+
+    async fn recv_packet_check<S: StateRead>(_state: S, _msg: &MsgRecvPacket) -> Result<()> {
+        anyhow::bail!("interchain account controllers don't receive packets");
+    }
+
+    async fn timeout_packet_check<S: StateRead>(_state: S, _msg: &MsgTimeout) -> Result<()> {
+        Ok(())
+    }
+
+    async fn acknowledge_packet_check<S: StateRead>(
+        _state: S,
+        msg: &MsgAcknowledgement,
+    ) -> Result<()> {
+        InterchainAccountTxStatus::from_acknowledgement(&msg.acknowledgement)?;
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl AppHandlerExecute for Ics27Controller {
+    async fn chan_open_init_execute<S: StateWrite>(_state: S, _msg: &MsgChannelOpenInit) {}
+    async fn chan_open_try_execute<S: StateWrite>(_state: S, _msg: &MsgChannelOpenTry) {}
+
+    async fn chan_open_ack_execute<S: StateWrite>(mut state: S, msg: &MsgChannelOpenAck) {
+        let metadata = Ics27Metadata::from_version(&msg.version_on_b)
+            .expect("metadata was checked in chan_open_ack_check");
+        let connection_id = ConnectionId::from_str(&metadata.controller_connection_id)
+            .expect("metadata was checked against the channel's connection");
+        let account = InterchainAccount {
+            connection_id: connection_id.clone(),
+            port_id: msg.port_id_on_a.clone(),
+            channel_id: msg.chan_id_on_a.clone(),
+            address: Some(metadata.address),
+        };
+        tracing::info!(?account, "registered interchain account");
+        state.put(state_key::ics27::account(&connection_id), account);
+    }
+
+    async fn chan_open_confirm_execute<S: StateWrite>(_state: S, _msg: &MsgChannelOpenConfirm) {}
+    async fn chan_close_confirm_execute<S: StateWrite>(_state: S, _msg: &MsgChannelCloseConfirm) {}
+    async fn chan_close_init_execute<S: StateWrite>(_state: S, _msg: &MsgChannelCloseInit) {}
+
+    async fn recv_packet_execute<S: StateWrite>(_state: S, _msg: &MsgRecvPacket) -> Result<()> {
+        Ok(())
+    }
+
+    async fn timeout_packet_execute<S: StateWrite>(mut state: S, msg: &MsgTimeout) -> Result<()> {
+        // the timeout closes the account's ordered channel, so it must be registered again
+        // before it can be used.
+        record_outcome(&mut state, &msg.packet, InterchainAccountTxStatus::TimedOut).await
+    }
+
+    async fn acknowledge_packet_execute<S: StateWrite>(
+        mut state: S,
+        msg: &MsgAcknowledgement,
+    ) -> Result<()> {
+        let status = InterchainAccountTxStatus::from_acknowledgement(&msg.acknowledgement)?;
+        record_outcome(&mut state, &msg.packet, status).await
+    }
+}
+
+impl AppHandler for Ics27Controller {}
+
+/// Records the outcome of the transaction carried by `packet`.
+async fn record_outcome<S: StateWrite>(
+    state: &mut S,
+    packet: &Packet,
+    status: InterchainAccountTxStatus,
+) -> Result<()> {
+    let channel = state
+        .get_channel(&packet.chan_on_a, &packet.port_on_a)
+        .await?
+        .ok_or_else(|| anyhow::anyhow!("channel not found"))?;
+    let key = state_key::ics27::tx(
+        &channel.connection_hops[0],
+        &packet.chan_on_a,
+        packet.sequence.into(),
+    );
+    let Some(mut tx) = state.get::<InterchainAccountTx>(&key).await? else {
+        tracing::warn!(
+            ?packet,
+            "no interchain account transaction was sent in packet"
+        );
+        return Ok(());
+    };
+    tracing::info!(?tx, ?status, "interchain account transaction completed");
+    tx.status = status;
+    state.put(key, tx);
+    Ok(())
+}
+
+#[async_trait]
+pub trait StateReadExt: StateRead {
+    /// Gets the Community Pool's interchain account on the chain at the other end of a
+    /// connection, if it has been registered.
+    async fn interchain_account(
+        &self,
+        connection_id: &ConnectionId,
+    ) -> Result<Option<InterchainAccount>> {
+        self.get(&state_key::ics27::account(connection_id)).await
+    }
+
+    /// Gets all of the Community Pool's interchain accounts.
+    async fn interchain_accounts(&self) -> Result<Vec<InterchainAccount>> {
+        self.prefix::<InterchainAccount>(state_key::ics27::accounts_prefix())
+            .map_ok(|(_, account)| account)
+            .try_collect()
+            .await
+    }
+
+    /// Gets the transactions sent to the interchain account on the chain at the other end of a
+    /// connection, ordered by the proposals that sent them.
+    async fn interchain_account_txs(
+        &self,
+        connection_id: &ConnectionId,
+    ) -> Result<Vec<InterchainAccountTx>> {
+        let mut txs: Vec<InterchainAccountTx> = self
+            .prefix::<InterchainAccountTx>(&state_key::ics27::txs_by_connection(connection_id))
+            .map_ok(|(_, tx)| tx)
+            .try_collect()
+            .await?;
+        txs.sort_by_key(|tx| (tx.proposal_id, tx.sequence));
+        Ok(txs)
+    }
+}
+
+impl<T: StateRead + ?Sized> StateReadExt for T {}
+
+#[async_trait]
+pub trait StateWriteExt: StateWrite {
+    /// Starts the handshake of the channel of a new interchain account for the Community Pool on
+    /// the chain at the other end of a connection.
+    ///
+    /// An account can only be registered again once its previous channel has been closed.
+    async fn register_interchain_account(&mut self, connection_id: &ConnectionId) -> Result<()> {
+        let connection = self
+            .get_connection(connection_id)
+            .await?
+            .ok_or_else(|| anyhow::anyhow!("connection {connection_id} does not exist"))?;
+        anyhow::ensure!(
+            connection.state == ConnectionState::Open,
+            "connection {connection_id} is not open"
+        );
+        let host_connection_id = connection
+            .counterparty
+            .connection_id
+            .clone()
+            .ok_or_else(|| anyhow::anyhow!("no counterparty connection id for {connection_id}"))?;
+
+        if let Some(account) = self.interchain_account(connection_id).await? {
+            let channel = self
+                .get_channel(&account.channel_id, &account.port_id)
+                .await?
+                .ok_or_else(|| anyhow::anyhow!("channel {} not found", account.channel_id))?;
+            anyhow::ensure!(
+                channel.state_matches(&ChannelState::Closed),
+                "the Community Pool already has an interchain account on connection {connection_id}, over channel {}",
+                account.channel_id
+            );
+        }
+
+        let port_id = community_pool_controller_port();
+        let channel_id = self
+            .next_channel_id()
+            .await
+            .context("unable to get next channel id")?;
+        let channel = ChannelEnd {
+            state: ChannelState::Init,
+            ordering: ChannelOrder::Ordered,
+            remote: Counterparty::new(PortId::from_str(HOST_PORT)?, None),
+            connection_hops: vec![connection_id.clone()],
+            version: Ics27Metadata::new(connection_id, &host_connection_id).to_version(),
+        };
+        self.put_channel(&channel_id, &port_id, channel.clone());
+        self.put_send_sequence(&channel_id, &port_id, 1);
+        self.put_recv_sequence(&channel_id, &port_id, 1);
+        self.put_ack_sequence(&channel_id, &port_id, 1);
+
+        self.record(
+            events::channel::OpenInit {
+                port_id: port_id.clone(),
+                channel_id: channel_id.clone(),
+                counterparty_port_id: channel.counterparty().port_id().clone(),
+                connection_id: connection_id.clone(),
+                version: channel.version.clone(),
+            }
+            .into(),
+        );
+
+        // the account's address is only known once the host accepts the channel.
+        self.put(
+            state_key::ics27::account(connection_id),
+            InterchainAccount {
+                connection_id: connection_id.clone(),
+                port_id,
+                channel_id,
+                address: None,
+            },
+        );
+        Ok(())
+    }
+
+    /// Sends a transaction executing `messages` to the Community Pool's interchain account on the
+    /// chain at the other end of a connection, on behalf of the proposal `proposal_id`.
+    async fn send_interchain_account_tx(
+        &mut self,
+        proposal_id: u64,
+        connection_id: &ConnectionId,
+        messages: Vec<InterchainAccountMessage>,
+        memo: String,
+        timeout: Duration,
+        current_block_time: Time,
+    ) -> Result<()> {
+        let account = self
+            .interchain_account(connection_id)
+            .await?
+            .ok_or_else(|| {
+                anyhow::anyhow!(
+                    "the Community Pool has no interchain account on connection {connection_id}"
+                )
+            })?;
+        let channel = self
+            .get_channel(&account.channel_id, &account.port_id)
+            .await?
+            .ok_or_else(|| anyhow::anyhow!("channel {} not found", account.channel_id))?;
+        anyhow::ensure!(
+            channel.state_matches(&ChannelState::Open),
+            "the channel of the interchain account on connection {connection_id} is not open"
+        );
+
+        let connection = self
+            .get_connection(connection_id)
+            .await?
+            .ok_or_else(|| anyhow::anyhow!("connection {connection_id} does not exist"))?;
+        let latest_height = self
+            .get_client_state(&connection.client_id)
+            .await?
+            .latest_height();
+        let timeout_height = Height {
+            revision_number: latest_height.revision_number,
+            revision_height: latest_height.revision_height
+                + timeout.as_secs().saturating_mul(TIMEOUT_BLOCKS_PER_SECOND),
+        };
+        let timeout_timestamp = (current_block_time.unix_timestamp_nanos() as u64)
+            .checked_add(timeout.as_nanos().try_into()?)
+            .context("interchain account transaction timeout is too long")?;
+
+        let sequence = self
+            .get_send_sequence(&account.channel_id, &account.port_id)
+            .await?;
+        let packet = IBCPacket::new(
+            account.port_id.clone(),
+            account.channel_id.clone(),
+            timeout_height,
+            timeout_timestamp,
+            Ics27PacketData::execute_tx(messages, memo).to_bytes(),
+        );
+        let packet = self.send_packet_check(packet, current_block_time).await?;
+        self.send_packet_execute(packet).await;
+
+        self.put(
+            state_key::ics27::tx(connection_id, &account.channel_id, sequence),
+            InterchainAccountTx {
+                proposal_id,
+                connection_id: connection_id.clone(),
+                channel_id: account.channel_id,
+                sequence,
+                status: InterchainAccountTxStatus::Pending,
+            },
+        );
+        Ok(())
+    }
+}
+
+impl<T: StateWrite + ?Sized> StateWriteExt for T {}
+
+#[cfg(test)]
+mod tests {
+    use cnidarium::StateDelta;
+    use ibc_types::{
+        core::{
+            channel::{
+                msgs::MsgAcknowledgement, packet::Sequence, ChannelId, TimeoutHeight, Version,
+            },
+            client::ClientId,
+            commitment::MerkleProof,
+            connection::{self, ConnectionEnd},
+        },
+        timestamp::Timestamp,
+    };
+
+    use super::*;
+    use crate::{component::connection::StateWriteExt as _, IBC_COMMITMENT_PREFIX};
+
+    async fn open_connection<S: StateWrite>(state: &mut S) -> Result<ConnectionId> {
+        let connection_id = ConnectionId::new(0);
+        let client_id = ClientId::from_str("07-tendermint-0")?;
+        state
+            .put_new_connection(
+                &connection_id,
+                ConnectionEnd {
+                    state: ConnectionState::Open,
+                    client_id: client_id.clone(),
+                    counterparty: connection::Counterparty {
+                        client_id,
+                        connection_id: Some(ConnectionId::new(7)),
+                        prefix: IBC_COMMITMENT_PREFIX.clone(),
+                    },
+                    versions: vec![Default::default()],
+                    delay_period: Duration::ZERO,
+                },
+            )
+            .await?;
+        Ok(connection_id)
+    }
+
+    #[tokio::test]
+    async fn registers_accounts_and_records_outcomes() -> Result<()> {
+        let mut state = StateDelta::new(());
+        let connection_id = open_connection(&mut state).await?;
+
+        // Registering an account starts the handshake of an ordered channel to the host port.
+        state.register_interchain_account(&connection_id).await?;
+        let account = state
+            .interchain_account(&connection_id)
+            .await?
+            .expect("account is registered");
+        assert_eq!(account.port_id, community_pool_controller_port());
+        assert_eq!(account.address, None);
+        let channel = state
+            .get_channel(&account.channel_id, &account.port_id)
+            .await?
+            .expect("channel exists");
+        assert!(channel.state_matches(&ChannelState::Init));
+        assert_eq!(channel.ordering, ChannelOrder::Ordered);
+        assert_eq!(
+            Ics27Metadata::from_version(&channel.version)?,
+            Ics27Metadata::new(&connection_id, &ConnectionId::new(7))
+        );
+
+        // The account can't be registered again while its channel is in use.
+        assert!(state
+            .register_interchain_account(&connection_id)
+            .await
+            .is_err());
+
+        // The host accepts the channel, filling in the account's address.
+        let accepted = Ics27Metadata {
+            address: "osmo1account".to_string(),
+            ..Ics27Metadata::new(&connection_id, &ConnectionId::new(7))
+        };
+        let ack = MsgChannelOpenAck {
+            port_id_on_a: account.port_id.clone(),
+            chan_id_on_a: account.channel_id.clone(),
+            chan_id_on_b: ChannelId::new(3),
+            version_on_b: accepted.to_version(),
+            proof_chan_end_on_b: MerkleProof { proofs: vec![] },
+            proof_height_on_b: Height {
+                revision_number: 0,
+                revision_height: 1,
+            },
+            signer: String::new(),
+        };
+        assert!(Ics27Controller::chan_open_ack_check(
+            &state,
+            &MsgChannelOpenAck {
+                version_on_b: Version::new("ics20-1".to_string()),
+                ..ack.clone()
+            },
+        )
+        .await
+        .is_err());
+        Ics27Controller::chan_open_ack_check(&state, &ack).await?;
+        Ics27Controller::chan_open_ack_execute(&mut state, &ack).await;
+        assert_eq!(
+            state
+                .interchain_account(&connection_id)
+                .await?
+                .expect("account is registered")
+                .address,
+            Some("osmo1account".to_string())
+        );
+
+        // The host's acknowledgement of a transaction is recorded as its outcome.
+        state.put(
+            state_key::ics27::tx(&connection_id, &account.channel_id, 1),
+            InterchainAccountTx {
+                proposal_id: 4,
+                connection_id: connection_id.clone(),
+                channel_id: account.channel_id.clone(),
+                sequence: 1,
+                status: InterchainAccountTxStatus::Pending,
+            },
+        );
+        let packet = Packet {
+            sequence: Sequence::from(1),
+            port_on_a: account.port_id.clone(),
+            chan_on_a: account.channel_id.clone(),
+            port_on_b: PortId::from_str(HOST_PORT)?,
+            chan_on_b: ChannelId::new(3),
+            data: vec![],
+            timeout_height_on_b: TimeoutHeight::Never,
+            timeout_timestamp_on_b: Timestamp::none(),
+        };
+        Ics27Controller::acknowledge_packet_execute(
+            &mut state,
+            &MsgAcknowledgement {
+                packet,
+                acknowledgement: br#"{"error":"out of gas"}"#.to_vec(),
+                proof_acked_on_b: MerkleProof { proofs: vec![] },
+                proof_height_on_b: Height {
+                    revision_number: 0,
+                    revision_height: 1,
+                },
+                signer: String::new(),
+            },
+        )
+        .await?;
+        let txs = state.interchain_account_txs(&connection_id).await?;
+        assert_eq!(txs.len(), 1);
+        assert_eq!(
+            txs[0].status,
+            InterchainAccountTxStatus::Failed {
+                error: "out of gas".to_string()
+            }
+        );
+
+        Ok(())
+    }
+}
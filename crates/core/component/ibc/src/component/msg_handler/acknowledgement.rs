@@ -12,8 +12,9 @@ use crate::component::{
     channel::{StateReadExt as _, StateWriteExt as _},
     connection::StateReadExt as _,
     proof_verification::{commit_packet, PacketProofVerifier},
-    HostInterface, MsgHandler,
+    HostInterface, Ics27Controller, MsgHandler,
 };
+use crate::ics27::is_controller_port;
 
 #[async_trait]
 impl MsgHandler for MsgAcknowledgement {
@@ -90,6 +91,8 @@ impl MsgHandler for MsgAcknowledgement {
         let transfer = PortId::transfer();
         if self.packet.port_on_b == transfer {
             AH::acknowledge_packet_check(&mut state, self).await?;
+        } else if is_controller_port(&self.packet.port_on_a) {
+            Ics27Controller::acknowledge_packet_check(&mut state, self).await?;
         } else {
             anyhow::bail!("invalid port id");
         }
@@ -130,6 +133,8 @@ impl MsgHandler for MsgAcknowledgement {
         let transfer = PortId::transfer();
         if self.packet.port_on_b == transfer {
             AH::acknowledge_packet_execute(state, self).await?;
+        } else if is_controller_port(&self.packet.port_on_a) {
+            Ics27Controller::acknowledge_packet_execute(state, self).await?;
         } else {
             anyhow::bail!("invalid port id");
         }
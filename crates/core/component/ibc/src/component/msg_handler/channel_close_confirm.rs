@@ -14,8 +14,9 @@ use crate::component::{
     channel::{StateReadExt as _, StateWriteExt as _},
     connection::StateReadExt as _,
     proof_verification::ChannelProofVerifier,
-    HostInterface, MsgHandler,
+    HostInterface, Ics27Controller, MsgHandler,
 };
+use crate::ics27::is_controller_port;
 
 #[async_trait]
 impl MsgHandler for MsgChannelCloseConfirm {
@@ -90,6 +91,8 @@ impl MsgHandler for MsgChannelCloseConfirm {
         let transfer = PortId::transfer();
         if self.port_id_on_b == transfer {
             AH::chan_close_confirm_check(&mut state, self).await?;
+        } else if is_controller_port(&self.port_id_on_b) {
+            Ics27Controller::chan_close_confirm_check(&mut state, self).await?;
         } else {
             anyhow::bail!("invalid port id");
         }
@@ -115,6 +118,8 @@ impl MsgHandler for MsgChannelCloseConfirm {
         let transfer = PortId::transfer();
         if self.port_id_on_b == transfer {
             AH::chan_close_confirm_execute(state, self).await;
+        } else if is_controller_port(&self.port_id_on_b) {
+            Ics27Controller::chan_close_confirm_execute(state, self).await;
         } else {
             anyhow::bail!("invalid port id");
         }
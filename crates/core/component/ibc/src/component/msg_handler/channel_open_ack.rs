@@ -12,8 +12,9 @@ use crate::component::{
     channel::{StateReadExt as _, StateWriteExt as _},
     connection::StateReadExt as _,
     proof_verification::ChannelProofVerifier,
-    HostInterface, MsgHandler,
+    HostInterface, Ics27Controller, MsgHandler,
 };
+use crate::ics27::is_controller_port;
 
 #[async_trait]
 impl MsgHandler for MsgChannelOpenAck {
@@ -74,6 +75,8 @@ impl MsgHandler for MsgChannelOpenAck {
         let transfer = PortId::transfer();
         if self.port_id_on_a == transfer {
             AH::chan_open_ack_check(&mut state, self).await?;
+        } else if is_controller_port(&self.port_id_on_a) {
+            Ics27Controller::chan_open_ack_check(&mut state, self).await?;
         } else {
             anyhow::bail!("invalid port id");
         }
@@ -101,6 +104,8 @@ impl MsgHandler for MsgChannelOpenAck {
         let transfer = PortId::transfer();
         if self.port_id_on_a == transfer {
             AH::chan_open_ack_execute(state, self).await;
+        } else if is_controller_port(&self.port_id_on_a) {
+            Ics27Controller::chan_open_ack_execute(state, self).await;
         } else {
             anyhow::bail!("invalid port id");
         }
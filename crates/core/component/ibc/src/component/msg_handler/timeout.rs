@@ -14,8 +14,9 @@ use crate::component::{
     client::StateReadExt,
     connection::StateReadExt as _,
     proof_verification::{commit_packet, PacketProofVerifier},
-    HostInterface, MsgHandler,
+    HostInterface, Ics27Controller, MsgHandler,
 };
+use crate::ics27::is_controller_port;
 
 #[async_trait]
 impl MsgHandler for MsgTimeout {
@@ -112,6 +113,10 @@ impl MsgHandler for MsgTimeout {
             H::timeout_packet_check(&mut state, self)
                 .await
                 .context("failed to execute handler for timeout_packet_check")?;
+        } else if is_controller_port(&self.packet.port_on_a) {
+            Ics27Controller::timeout_packet_check(&mut state, self)
+                .await
+                .context("failed to execute handler for timeout_packet_check")?;
         } else {
             anyhow::bail!("invalid port id");
         }
@@ -149,6 +154,8 @@ impl MsgHandler for MsgTimeout {
         let transfer = PortId::transfer();
         if self.packet.port_on_b == transfer {
             H::timeout_packet_execute(state, self).await?;
+        } else if is_controller_port(&self.packet.port_on_a) {
+            Ics27Controller::timeout_packet_execute(state, self).await?;
         } else {
             anyhow::bail!("invalid port id");
         }
@@ -3,9 +3,10 @@ use tonic::transport::server::Routes;
 
 use super::HostInterface;
 
+mod channel_query;
 mod client_query;
 mod connection_query;
-mod consensus_query;
+mod ics27_query;
 mod utils;
 
 use std::marker::PhantomData;
@@ -1,7 +1,7 @@
 use crate::prefix::MerklePrefixExt;
 use crate::IBC_COMMITMENT_PREFIX;
 use async_trait::async_trait;
-use ibc_proto::ibc::core::channel::v1::query_server::Query as ConsensusQuery;
+use ibc_proto::ibc::core::channel::v1::query_server::Query as ChannelQuery;
 use ibc_proto::ibc::core::channel::v1::{
     Channel, PacketState, QueryChannelClientStateRequest, QueryChannelClientStateResponse,
     QueryChannelConsensusStateRequest, QueryChannelConsensusStateResponse, QueryChannelRequest,
@@ -22,7 +22,7 @@ use ibc_types::path::{
 };
 use ibc_types::DomainType;
 
-use ibc_types::core::channel::{ChannelId, IdentifiedChannelEnd, PortId};
+use ibc_types::core::channel::{channel::Order, ChannelId, PortId};
 
 use ibc_types::core::connection::ConnectionId;
 use prost::Message;
@@ -35,7 +35,7 @@ use super::utils::determine_snapshot_from_metadata;
 use super::IbcQuery;
 
 #[async_trait]
-impl<HI: HostInterface + Send + Sync + 'static> ConsensusQuery for IbcQuery<HI> {
+impl<HI: HostInterface + Send + Sync + 'static> ChannelQuery for IbcQuery<HI> {
     /// Channel queries an IBC Channel.
     #[tracing::instrument(skip(self), err, level = "debug")]
     async fn channel(
@@ -107,32 +107,13 @@ impl<HI: HostInterface + Send + Sync + 'static> ConsensusQuery for IbcQuery<HI>
                 .map_err(|e| tonic::Status::aborted(format!("couldn't decode height: {e}")))?,
         };
 
-        let channel_counter = snapshot
-            .get_channel_counter()
+        let channels = snapshot
+            .get_channels()
             .await
-            .map_err(|e| tonic::Status::aborted(format!("couldn't get channel counter: {e}")))?;
-
-        let mut channels = vec![];
-        for chan_idx in 0..channel_counter {
-            let chan_id = ChannelId(format!("channel-{}", chan_idx));
-            let channel = snapshot
-                .get_channel(&chan_id, &PortId::transfer())
-                .await
-                .map_err(|e| {
-                    tonic::Status::aborted(format!("couldn't get channel {chan_id}: {e}"))
-                })?
-                .ok_or("unable to get channel")
-                .map_err(|e| {
-                    tonic::Status::aborted(format!("couldn't get channel {chan_id}: {e}"))
-                })?;
-
-            let id_chan = IdentifiedChannelEnd {
-                channel_id: chan_id,
-                port_id: PortId::transfer(),
-                channel_end: channel,
-            };
-            channels.push(id_chan.into());
-        }
+            .map_err(|e| tonic::Status::aborted(format!("couldn't get channels: {e}")))?
+            .into_iter()
+            .map(Into::into)
+            .collect();
 
         let res = QueryChannelsResponse {
             channels,
@@ -164,33 +145,14 @@ impl<HI: HostInterface + Send + Sync + 'static> ConsensusQuery for IbcQuery<HI>
             .map_err(|e| tonic::Status::aborted(format!("invalid connection id: {e}")))?;
 
         // look up all of the channels for this connection
-        let channel_counter = snapshot
-            .get_channel_counter()
+        let channels = snapshot
+            .get_channels()
             .await
-            .map_err(|e| tonic::Status::aborted(format!("couldn't get channel counter: {e}")))?;
-
-        let mut channels = vec![];
-        for chan_idx in 0..channel_counter {
-            let chan_id = ChannelId(format!("channel-{}", chan_idx));
-            let channel = snapshot
-                .get_channel(&chan_id, &PortId::transfer())
-                .await
-                .map_err(|e| {
-                    tonic::Status::aborted(format!("couldn't get channel {chan_id}: {e}"))
-                })?
-                .ok_or("unable to get channel")
-                .map_err(|e| {
-                    tonic::Status::aborted(format!("couldn't get channel {chan_id}: {e}"))
-                })?;
-            if channel.connection_hops.contains(&connection_id) {
-                let id_chan = IdentifiedChannelEnd {
-                    channel_id: chan_id,
-                    port_id: PortId::transfer(),
-                    channel_end: channel,
-                };
-                channels.push(id_chan.into());
-            }
-        }
+            .map_err(|e| tonic::Status::aborted(format!("couldn't get channels: {e}")))?
+            .into_iter()
+            .filter(|channel| channel.channel_end.connection_hops.contains(&connection_id))
+            .map(Into::into)
+            .collect();
 
         let res = QueryConnectionChannelsResponse {
             channels,
@@ -458,39 +420,22 @@ impl<HI: HostInterface + Send + Sync + 'static> ConsensusQuery for IbcQuery<HI>
         let port_id: PortId = PortId::from_str(&request.port_id)
             .map_err(|e| tonic::Status::aborted(format!("invalid port id: {e}")))?;
 
-        let mut commitment_states = vec![];
-        let commitment_counter = snapshot
-            .get_send_sequence(&chan_id, &port_id)
+        let commitment_states = snapshot
+            .get_packet_commitments(&chan_id, &port_id)
             .await
             .map_err(|e| {
                 tonic::Status::aborted(format!(
-                    "couldn't get send sequence for channel {chan_id} and port {port_id}: {e}"
+                    "couldn't get packet commitments for channel {chan_id} and port {port_id}: {e}"
                 ))
-            })?;
-
-        // this starts at 1; the first commitment index is 1 (from ibc spec)
-        for commitment_idx in 1..commitment_counter {
-            let commitment = snapshot
-                .get_packet_commitment_by_id(&chan_id, &port_id, commitment_idx)
-                .await.map_err(|e| {
-                    tonic::Status::aborted(format!(
-                        "couldn't get packet commitment for channel {chan_id} and port {port_id} at index {commitment_idx}: {e}"
-                    ))
-                })?;
-            if commitment.is_none() {
-                continue;
-            }
-            let commitment = commitment.expect("commitment existence was checked earlier");
-
-            let commitment_state = PacketState {
+            })?
+            .into_iter()
+            .map(|(sequence, commitment)| PacketState {
                 port_id: request.port_id.clone(),
                 channel_id: request.channel_id.clone(),
-                sequence: commitment_idx,
-                data: commitment.clone(),
-            };
-
-            commitment_states.push(commitment_state);
-        }
+                sequence,
+                data: commitment,
+            })
+            .collect();
 
         let height = Height {
             revision_number: 0,
@@ -622,38 +567,27 @@ impl<HI: HostInterface + Send + Sync + 'static> ConsensusQuery for IbcQuery<HI>
         let port_id: PortId = PortId::from_str(&request.port_id)
             .map_err(|e| tonic::Status::aborted(format!("invalid port id: {e}")))?;
 
-        let ack_counter = snapshot
-            .get_ack_sequence(&chan_id, &port_id)
+        // If the request names packet sequences, only their acknowledgements are returned.
+        let acks = snapshot
+            .get_packet_acknowledgements(&chan_id, &port_id)
             .await
             .map_err(|e| {
                 tonic::Status::aborted(format!(
-                    "couldn't get ack sequence for channel {chan_id} and port {port_id}: {e}"
+                    "couldn't get packet acknowledgements for channel {chan_id} and port {port_id}: {e}"
                 ))
-            })?;
-
-        let mut acks = vec![];
-        for ack_idx in 0..ack_counter {
-            let maybe_ack = snapshot
-                .get_packet_acknowledgement(&port_id, &chan_id, ack_idx)
-                .await.map_err(|e| {
-                    tonic::Status::aborted(format!(
-                        "couldn't get packet acknowledgement for channel {chan_id} and port {port_id} at index {ack_idx}: {e}"
-                    ))
-                })?;
-
-            // Only include the ack if it was found; otherwise, signal lack of
-            // by omitting it from the response.
-            if let Some(ack) = maybe_ack {
-                let ack_state = PacketState {
-                    port_id: request.port_id.clone(),
-                    channel_id: request.channel_id.clone(),
-                    sequence: ack_idx,
-                    data: ack.clone(),
-                };
-
-                acks.push(ack_state);
-            }
-        }
+            })?
+            .into_iter()
+            .filter(|(sequence, _)| {
+                request.packet_commitment_sequences.is_empty()
+                    || request.packet_commitment_sequences.contains(sequence)
+            })
+            .map(|(sequence, ack)| PacketState {
+                port_id: request.port_id.clone(),
+                channel_id: request.channel_id.clone(),
+                sequence,
+                data: ack,
+            })
+            .collect();
 
         let res = QueryPacketAcknowledgementsResponse {
             acknowledgements: acks,
@@ -679,6 +613,35 @@ impl<HI: HostInterface + Send + Sync + 'static> ConsensusQuery for IbcQuery<HI>
         let port_id: PortId = PortId::from_str(&request.port_id)
             .map_err(|e| tonic::Status::aborted(format!("invalid port id: {e}")))?;
 
+        let channel = snapshot
+            .get_channel(&chan_id, &port_id)
+            .await
+            .map_err(|e| {
+                tonic::Status::aborted(format!(
+                    "couldn't get channel {chan_id} for port {port_id}: {e}"
+                ))
+            })?
+            .ok_or_else(|| {
+                tonic::Status::not_found(format!("channel {chan_id} for port {port_id} not found"))
+            })?;
+
+        // Ordered channels don't write packet receipts: every packet below the next receive
+        // sequence has been received.
+        let next_recv_sequence = if channel.ordering == Order::Ordered {
+            Some(
+                snapshot
+                    .get_recv_sequence(&chan_id, &port_id)
+                    .await
+                    .map_err(|e| {
+                        tonic::Status::aborted(format!(
+                            "couldn't get receive sequence for channel {chan_id} and port {port_id}: {e}"
+                        ))
+                    })?,
+            )
+        } else {
+            None
+        };
+
         let mut unreceived_seqs = vec![];
 
         for seq in request.packet_commitment_sequences.clone() {
@@ -689,14 +652,17 @@ impl<HI: HostInterface + Send + Sync + 'static> ConsensusQuery for IbcQuery<HI>
                 )));
             }
 
-            if !snapshot
-                .seen_packet_by_channel(&chan_id, &port_id, seq)
-                .await.map_err(|e| {
-                    tonic::Status::aborted(format!(
-                        "couldn't get packet commitment for channel {chan_id} and port {port_id} at index {seq}: {e}"
-                    ))
-                })?
-            {
+            let received = match next_recv_sequence {
+                Some(next_recv_sequence) => seq < next_recv_sequence,
+                None => snapshot
+                    .seen_packet_by_channel(&chan_id, &port_id, seq)
+                    .await.map_err(|e| {
+                        tonic::Status::aborted(format!(
+                            "couldn't get packet receipt for channel {chan_id} and port {port_id} at index {seq}: {e}"
+                        ))
+                    })?,
+            };
+            if !received {
                 unreceived_seqs.push(seq);
             }
         }
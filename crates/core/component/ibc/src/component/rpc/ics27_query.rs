@@ -0,0 +1,52 @@
+use std::str::FromStr;
+
+use async_trait::async_trait;
+use ibc_types::core::connection::ConnectionId;
+use penumbra_proto::core::component::ibc::v1::{
+    query_service_server::QueryService, InterchainAccountTxsRequest, InterchainAccountTxsResponse,
+    InterchainAccountsRequest, InterchainAccountsResponse,
+};
+use tonic::Status;
+
+use crate::component::{HostInterface, Ics27StateReadExt};
+
+use super::IbcQuery;
+
+#[async_trait]
+impl<HI: HostInterface + Send + Sync + 'static> QueryService for IbcQuery<HI> {
+    #[tracing::instrument(skip(self, _request), level = "debug")]
+    async fn interchain_accounts(
+        &self,
+        _request: tonic::Request<InterchainAccountsRequest>,
+    ) -> Result<tonic::Response<InterchainAccountsResponse>, Status> {
+        let state = self.storage.latest_snapshot();
+
+        let accounts = state
+            .interchain_accounts()
+            .await
+            .map_err(|e| Status::internal(e.to_string()))?;
+
+        Ok(tonic::Response::new(InterchainAccountsResponse {
+            accounts: accounts.into_iter().map(Into::into).collect(),
+        }))
+    }
+
+    #[tracing::instrument(skip(self, request), level = "debug")]
+    async fn interchain_account_txs(
+        &self,
+        request: tonic::Request<InterchainAccountTxsRequest>,
+    ) -> Result<tonic::Response<InterchainAccountTxsResponse>, Status> {
+        let state = self.storage.latest_snapshot();
+
+        let connection_id = ConnectionId::from_str(&request.get_ref().connection_id)
+            .map_err(|e| Status::invalid_argument(format!("invalid connection id: {e}")))?;
+        let txs = state
+            .interchain_account_txs(&connection_id)
+            .await
+            .map_err(|e| Status::internal(e.to_string()))?;
+
+        Ok(tonic::Response::new(InterchainAccountTxsResponse {
+            txs: txs.into_iter().map(Into::into).collect(),
+        }))
+    }
+}
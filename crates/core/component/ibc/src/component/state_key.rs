@@ -30,3 +30,32 @@ pub mod ics20_value_balance {
         format!("ibc/ics20-value-balance/{channel_id}/{asset_id}")
     }
 }
+
+pub mod ics20_rate_limit_usage {
+    use ibc_types::core::channel::ChannelId;
+    use penumbra_asset::asset;
+
+    pub fn by_asset_id(channel_id: &ChannelId, asset_id: &asset::Id) -> String {
+        format!("ibc/ics20-rate-limit-usage/{channel_id}/{asset_id}")
+    }
+}
+
+pub mod ics27 {
+    use ibc_types::core::{channel::ChannelId, connection::ConnectionId};
+
+    pub fn accounts_prefix() -> &'static str {
+        "ibc/ics27/accounts/"
+    }
+
+    pub fn account(connection_id: &ConnectionId) -> String {
+        format!("ibc/ics27/accounts/{connection_id}")
+    }
+
+    pub fn txs_by_connection(connection_id: &ConnectionId) -> String {
+        format!("ibc/ics27/txs/{connection_id}/")
+    }
+
+    pub fn tx(connection_id: &ConnectionId, channel_id: &ChannelId, sequence: u64) -> String {
+        format!("ibc/ics27/txs/{connection_id}/{channel_id}/{sequence:020}")
+    }
+}
@@ -0,0 +1,255 @@
+use std::str::FromStr;
+
+use anyhow::{anyhow, ensure, Context};
+use ibc_types::core::channel::ChannelId;
+use penumbra_asset::asset;
+use penumbra_num::Amount;
+use penumbra_proto::{penumbra::core::component::ibc::v1 as pb, DomainType};
+use serde::{Deserialize, Serialize};
+
+/// A limit on the net flow of one asset over one channel, within a window of blocks.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(try_from = "pb::Ics20RateLimit", into = "pb::Ics20RateLimit")]
+pub struct Ics20RateLimit {
+    /// The channel (on the Penumbra side) the limit applies to.
+    pub channel_id: ChannelId,
+    /// The asset the limit applies to.
+    pub asset_id: asset::Id,
+    /// The maximum net amount that may be transferred in within a window.
+    pub max_inflow: Amount,
+    /// The maximum net amount that may be transferred out within a window.
+    pub max_outflow: Amount,
+    /// The length of a window, in blocks.
+    pub window_blocks: u64,
+}
+
+impl Ics20RateLimit {
+    /// Whether this limit applies to transfers of `asset_id` over `channel_id`.
+    pub fn applies_to(&self, channel_id: &ChannelId, asset_id: &asset::Id) -> bool {
+        &self.channel_id == channel_id && &self.asset_id == asset_id
+    }
+}
+
+impl DomainType for Ics20RateLimit {
+    type Proto = pb::Ics20RateLimit;
+}
+
+impl TryFrom<pb::Ics20RateLimit> for Ics20RateLimit {
+    type Error = anyhow::Error;
+
+    fn try_from(msg: pb::Ics20RateLimit) -> anyhow::Result<Self> {
+        Ok(Ics20RateLimit {
+            channel_id: ChannelId::from_str(&msg.channel_id)
+                .context("invalid channel id in ICS-20 rate limit")?,
+            asset_id: msg
+                .asset_id
+                .ok_or_else(|| anyhow!("missing asset id in ICS-20 rate limit"))?
+                .try_into()?,
+            max_inflow: msg
+                .max_inflow
+                .ok_or_else(|| anyhow!("missing max inflow in ICS-20 rate limit"))?
+                .try_into()?,
+            max_outflow: msg
+                .max_outflow
+                .ok_or_else(|| anyhow!("missing max outflow in ICS-20 rate limit"))?
+                .try_into()?,
+            window_blocks: msg.window_blocks,
+        })
+    }
+}
+
+impl From<Ics20RateLimit> for pb::Ics20RateLimit {
+    fn from(limit: Ics20RateLimit) -> Self {
+        pb::Ics20RateLimit {
+            channel_id: limit.channel_id.to_string(),
+            asset_id: Some(limit.asset_id.into()),
+            max_inflow: Some(limit.max_inflow.into()),
+            max_outflow: Some(limit.max_outflow.into()),
+            window_blocks: limit.window_blocks,
+        }
+    }
+}
+
+/// The flow of one asset over one channel within the current rate limit window.
+#[derive(Clone, Debug, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(try_from = "pb::Ics20RateLimitUsage", into = "pb::Ics20RateLimitUsage")]
+pub struct Ics20RateLimitUsage {
+    /// The height at which the current window started.
+    pub window_start_height: u64,
+    /// The amount transferred in since the window started.
+    pub inflow: Amount,
+    /// The amount transferred out since the window started.
+    pub outflow: Amount,
+}
+
+impl Ics20RateLimitUsage {
+    /// Returns the usage as of `height`, starting a new window if the current one has elapsed.
+    pub fn at_height(self, limit: &Ics20RateLimit, height: u64) -> Self {
+        if height >= self.window_start_height.saturating_add(limit.window_blocks) {
+            Self {
+                window_start_height: height,
+                ..Default::default()
+            }
+        } else {
+            self
+        }
+    }
+
+    /// The net amount transferred in within the window.
+    pub fn net_inflow(&self) -> Amount {
+        self.inflow.saturating_sub(&self.outflow)
+    }
+
+    /// The net amount transferred out within the window.
+    pub fn net_outflow(&self) -> Amount {
+        self.outflow.saturating_sub(&self.inflow)
+    }
+
+    /// Records an inbound transfer of `amount`, failing if it would exceed the limit.
+    pub fn record_inflow(self, limit: &Ics20RateLimit, amount: Amount) -> anyhow::Result<Self> {
+        let usage = Self {
+            inflow: self
+                .inflow
+                .checked_add(&amount)
+                .context("overflow recording ICS-20 inflow")?,
+            ..self
+        };
+        ensure!(
+            usage.net_inflow() <= limit.max_inflow,
+            "inbound transfer of {} exceeds the rate limit for asset {} on channel {}",
+            amount,
+            limit.asset_id,
+            limit.channel_id,
+        );
+        Ok(usage)
+    }
+
+    /// Records an outbound transfer of `amount`, failing if it would exceed the limit.
+    pub fn record_outflow(self, limit: &Ics20RateLimit, amount: Amount) -> anyhow::Result<Self> {
+        let usage = Self {
+            outflow: self
+                .outflow
+                .checked_add(&amount)
+                .context("overflow recording ICS-20 outflow")?,
+            ..self
+        };
+        ensure!(
+            usage.net_outflow() <= limit.max_outflow,
+            "outbound transfer of {} exceeds the rate limit for asset {} on channel {}",
+            amount,
+            limit.asset_id,
+            limit.channel_id,
+        );
+        Ok(usage)
+    }
+
+    /// Records the refund of an outbound transfer of `amount`, releasing its share of the limit.
+    pub fn record_refund(self, amount: Amount) -> Self {
+        Self {
+            outflow: self.outflow.saturating_sub(&amount),
+            ..self
+        }
+    }
+
+    /// Records the refund of an inbound transfer of `amount`, which was acknowledged with an
+    /// error, releasing its share of the limit.
+    pub fn record_inbound_refund(self, amount: Amount) -> Self {
+        Self {
+            inflow: self.inflow.saturating_sub(&amount),
+            ..self
+        }
+    }
+}
+
+impl DomainType for Ics20RateLimitUsage {
+    type Proto = pb::Ics20RateLimitUsage;
+}
+
+impl TryFrom<pb::Ics20RateLimitUsage> for Ics20RateLimitUsage {
+    type Error = anyhow::Error;
+
+    fn try_from(msg: pb::Ics20RateLimitUsage) -> anyhow::Result<Self> {
+        Ok(Ics20RateLimitUsage {
+            window_start_height: msg.window_start_height,
+            inflow: msg.inflow.unwrap_or_default().try_into()?,
+            outflow: msg.outflow.unwrap_or_default().try_into()?,
+        })
+    }
+}
+
+impl From<Ics20RateLimitUsage> for pb::Ics20RateLimitUsage {
+    fn from(usage: Ics20RateLimitUsage) -> Self {
+        pb::Ics20RateLimitUsage {
+            window_start_height: usage.window_start_height,
+            inflow: Some(usage.inflow.into()),
+            outflow: Some(usage.outflow.into()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn limit() -> Ics20RateLimit {
+        Ics20RateLimit {
+            channel_id: ChannelId::new(0),
+            asset_id: *penumbra_asset::STAKING_TOKEN_ASSET_ID,
+            max_inflow: 100u64.into(),
+            max_outflow: 50u64.into(),
+            window_blocks: 10,
+        }
+    }
+
+    #[test]
+    fn limits_net_flow_within_window() {
+        let limit = limit();
+        let usage = Ics20RateLimitUsage::default()
+            .at_height(&limit, 5)
+            .record_outflow(&limit, 50u64.into())
+            .expect("outflow is within the limit");
+        assert!(usage.clone().record_outflow(&limit, 1u64.into()).is_err());
+
+        // Inflows offset outflows, and vice versa.
+        let usage = usage
+            .record_inflow(&limit, 20u64.into())
+            .expect("inflow is within the limit")
+            .record_outflow(&limit, 20u64.into())
+            .expect("outflow is offset by inflow");
+        assert_eq!(usage.net_outflow(), 50u64.into());
+
+        // Refunds release the limit.
+        let usage = usage.record_refund(10u64.into());
+        assert_eq!(usage.net_outflow(), 40u64.into());
+    }
+
+    #[test]
+    fn inbound_refunds_release_the_limit() {
+        let limit = limit();
+        let usage = Ics20RateLimitUsage::default()
+            .at_height(&limit, 5)
+            .record_inflow(&limit, 100u64.into())
+            .expect("inflow is within the limit");
+        assert!(usage.clone().record_inflow(&limit, 1u64.into()).is_err());
+
+        let usage = usage.record_inbound_refund(30u64.into());
+        assert_eq!(usage.net_inflow(), 70u64.into());
+        usage
+            .record_inflow(&limit, 30u64.into())
+            .expect("refunded inflow is available again");
+    }
+
+    #[test]
+    fn resets_after_window() {
+        let limit = limit();
+        let usage = Ics20RateLimitUsage::default()
+            .at_height(&limit, 5)
+            .record_outflow(&limit, 50u64.into())
+            .expect("outflow is within the limit");
+        assert_eq!(usage.clone().at_height(&limit, 14), usage);
+
+        let usage = usage.at_height(&limit, 15);
+        assert_eq!(usage.window_start_height, 15);
+        assert_eq!(usage.net_outflow(), Amount::zero());
+    }
+}
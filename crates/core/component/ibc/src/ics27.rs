@@ -0,0 +1,384 @@
+use std::str::FromStr;
+
+use anyhow::{anyhow, Context};
+use base64::prelude::*;
+use ibc_types::core::{
+    channel::{ChannelId, PortId, Version},
+    connection::ConnectionId,
+};
+use penumbra_proto::{penumbra::core::component::ibc::v1 as pb, DomainType};
+use prost::Message as _;
+use serde::{Deserialize, Serialize};
+
+/// The version of ICS-27 spoken over interchain account channels.
+pub const ICS27_VERSION: &str = "ics27-1";
+
+/// The prefix of every ICS-27 controller port.
+pub const CONTROLLER_PORT_PREFIX: &str = "icacontroller-";
+
+/// The port interchain accounts are hosted on by the counterparty.
+pub const HOST_PORT: &str = "icahost";
+
+/// The controller port of the Community Pool's interchain accounts.
+pub fn community_pool_controller_port() -> PortId {
+    PortId::from_str("icacontroller-community-pool").expect("valid port id")
+}
+
+/// Whether `port_id` is an ICS-27 controller port.
+pub fn is_controller_port(port_id: &PortId) -> bool {
+    port_id.to_string().starts_with(CONTROLLER_PORT_PREFIX)
+}
+
+/// The version metadata negotiated during the handshake of an interchain account channel.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub struct Ics27Metadata {
+    pub version: String,
+    pub controller_connection_id: String,
+    pub host_connection_id: String,
+    /// The address of the account, filled in by the host when it accepts the channel.
+    #[serde(default)]
+    pub address: String,
+    pub encoding: String,
+    pub tx_type: String,
+}
+
+impl Ics27Metadata {
+    /// The metadata proposed when opening an account's channel over the given connections.
+    pub fn new(controller_connection_id: &ConnectionId, host_connection_id: &ConnectionId) -> Self {
+        Self {
+            version: ICS27_VERSION.to_string(),
+            controller_connection_id: controller_connection_id.to_string(),
+            host_connection_id: host_connection_id.to_string(),
+            address: String::new(),
+            encoding: "proto3".to_string(),
+            tx_type: "sdk_multi_msg".to_string(),
+        }
+    }
+
+    /// Parses the metadata from a channel version.
+    pub fn from_version(version: &Version) -> anyhow::Result<Self> {
+        serde_json::from_str(&version.to_string()).context("invalid ICS-27 version metadata")
+    }
+
+    /// The channel version carrying this metadata.
+    pub fn to_version(&self) -> Version {
+        Version::new(serde_json::to_string(self).expect("can serialize ICS-27 metadata"))
+    }
+
+    /// Checks that the metadata a host accepted the channel with matches the metadata proposed
+    /// by the controller, and includes the address of the account.
+    pub fn check_counterparty(&self, counterparty: &Ics27Metadata) -> anyhow::Result<()> {
+        anyhow::ensure!(
+            !counterparty.address.is_empty(),
+            "host did not provide the interchain account address"
+        );
+        anyhow::ensure!(
+            Self {
+                address: String::new(),
+                ..counterparty.clone()
+            } == *self,
+            "host's ICS-27 version metadata {counterparty:?} does not match the proposed {self:?}"
+        );
+        Ok(())
+    }
+}
+
+/// A message to be executed by an interchain account on its host chain.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(
+    from = "pb::InterchainAccountMessage",
+    into = "pb::InterchainAccountMessage"
+)]
+pub struct InterchainAccountMessage {
+    /// The type URL of the host chain's message.
+    pub type_url: String,
+    /// The protobuf encoding of the message.
+    pub value: Vec<u8>,
+}
+
+impl DomainType for InterchainAccountMessage {
+    type Proto = pb::InterchainAccountMessage;
+}
+
+impl From<pb::InterchainAccountMessage> for InterchainAccountMessage {
+    fn from(msg: pb::InterchainAccountMessage) -> Self {
+        InterchainAccountMessage {
+            type_url: msg.type_url,
+            value: msg.value,
+        }
+    }
+}
+
+impl From<InterchainAccountMessage> for pb::InterchainAccountMessage {
+    fn from(msg: InterchainAccountMessage) -> Self {
+        pb::InterchainAccountMessage {
+            type_url: msg.type_url,
+            value: msg.value,
+        }
+    }
+}
+
+/// The transaction carried by an ICS-27 packet, as a `cosmos.tx.v1beta1`-style list of messages.
+#[derive(Clone, PartialEq, prost::Message)]
+struct CosmosTx {
+    // `InterchainAccountMessage` has the same encoding as a `google.protobuf.Any`.
+    #[prost(message, repeated, tag = "1")]
+    messages: Vec<pb::InterchainAccountMessage>,
+}
+
+/// The data of an ICS-27 packet.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub struct Ics27PacketData {
+    #[serde(rename = "type")]
+    pub kind: String,
+    /// The base64 encoding of the transaction.
+    pub data: String,
+    #[serde(default)]
+    pub memo: String,
+}
+
+impl Ics27PacketData {
+    /// A packet asking the host to execute `messages` as a single transaction.
+    pub fn execute_tx(messages: Vec<InterchainAccountMessage>, memo: String) -> Self {
+        let tx = CosmosTx {
+            messages: messages.into_iter().map(Into::into).collect(),
+        };
+        Self {
+            kind: "TYPE_EXECUTE_TX".to_string(),
+            data: BASE64_STANDARD.encode(tx.encode_to_vec()),
+            memo,
+        }
+    }
+
+    /// The JSON encoding of the packet data.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        serde_json::to_vec(self).expect("can serialize ICS-27 packet data")
+    }
+}
+
+/// An interchain account on a counterparty chain, controlled by the Community Pool.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(try_from = "pb::InterchainAccount", into = "pb::InterchainAccount")]
+pub struct InterchainAccount {
+    /// The connection to the chain hosting the account.
+    pub connection_id: ConnectionId,
+    /// The controller port of the account's channel.
+    pub port_id: PortId,
+    /// The account's channel.
+    pub channel_id: ChannelId,
+    /// The address of the account on the host chain, once its channel is open.
+    pub address: Option<String>,
+}
+
+impl DomainType for InterchainAccount {
+    type Proto = pb::InterchainAccount;
+}
+
+impl TryFrom<pb::InterchainAccount> for InterchainAccount {
+    type Error = anyhow::Error;
+
+    fn try_from(msg: pb::InterchainAccount) -> anyhow::Result<Self> {
+        Ok(InterchainAccount {
+            connection_id: ConnectionId::from_str(&msg.connection_id)
+                .context("invalid connection id in interchain account")?,
+            port_id: PortId::from_str(&msg.port_id)
+                .context("invalid port id in interchain account")?,
+            channel_id: ChannelId::from_str(&msg.channel_id)
+                .context("invalid channel id in interchain account")?,
+            address: (!msg.address.is_empty()).then_some(msg.address),
+        })
+    }
+}
+
+impl From<InterchainAccount> for pb::InterchainAccount {
+    fn from(account: InterchainAccount) -> Self {
+        pb::InterchainAccount {
+            connection_id: account.connection_id.to_string(),
+            port_id: account.port_id.to_string(),
+            channel_id: account.channel_id.to_string(),
+            address: account.address.unwrap_or_default(),
+        }
+    }
+}
+
+/// The outcome of a transaction sent to an interchain account.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum InterchainAccountTxStatus {
+    /// The transaction has yet to be acknowledged by the host chain.
+    Pending,
+    /// The host chain executed the transaction, returning `result`.
+    Succeeded { result: Vec<u8> },
+    /// The host chain failed to execute the transaction.
+    Failed { error: String },
+    /// The transaction timed out before the host chain received it.
+    TimedOut,
+}
+
+impl InterchainAccountTxStatus {
+    /// Parses the outcome of a transaction from the host chain's acknowledgement.
+    pub fn from_acknowledgement(ack: &[u8]) -> anyhow::Result<Self> {
+        #[derive(Deserialize)]
+        #[serde(rename_all = "snake_case")]
+        enum Acknowledgement {
+            Result(String),
+            Error(String),
+        }
+
+        let ack: Acknowledgement =
+            serde_json::from_slice(ack).context("invalid ICS-27 acknowledgement")?;
+        Ok(match ack {
+            Acknowledgement::Result(result) => InterchainAccountTxStatus::Succeeded {
+                result: BASE64_STANDARD
+                    .decode(result)
+                    .context("invalid ICS-27 acknowledgement result")?,
+            },
+            Acknowledgement::Error(error) => InterchainAccountTxStatus::Failed { error },
+        })
+    }
+}
+
+/// A transaction sent to an interchain account by a governance proposal.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(try_from = "pb::InterchainAccountTx", into = "pb::InterchainAccountTx")]
+pub struct InterchainAccountTx {
+    /// The ID of the proposal that sent the transaction.
+    pub proposal_id: u64,
+    /// The connection to the chain hosting the account.
+    pub connection_id: ConnectionId,
+    /// The channel the transaction was sent over.
+    pub channel_id: ChannelId,
+    /// The sequence number of the packet carrying the transaction.
+    pub sequence: u64,
+    /// The outcome of the transaction.
+    pub status: InterchainAccountTxStatus,
+}
+
+impl DomainType for InterchainAccountTx {
+    type Proto = pb::InterchainAccountTx;
+}
+
+impl TryFrom<pb::InterchainAccountTx> for InterchainAccountTx {
+    type Error = anyhow::Error;
+
+    fn try_from(msg: pb::InterchainAccountTx) -> anyhow::Result<Self> {
+        use pb::interchain_account_tx::Status;
+        let status = match Status::try_from(msg.status)
+            .map_err(|_| anyhow!("invalid interchain account transaction status"))?
+        {
+            Status::Unspecified => {
+                anyhow::bail!("missing interchain account transaction status")
+            }
+            Status::Pending => InterchainAccountTxStatus::Pending,
+            Status::Succeeded => InterchainAccountTxStatus::Succeeded { result: msg.result },
+            Status::Failed => InterchainAccountTxStatus::Failed { error: msg.error },
+            Status::TimedOut => InterchainAccountTxStatus::TimedOut,
+        };
+        Ok(InterchainAccountTx {
+            proposal_id: msg.proposal_id,
+            connection_id: ConnectionId::from_str(&msg.connection_id)
+                .context("invalid connection id in interchain account transaction")?,
+            channel_id: ChannelId::from_str(&msg.channel_id)
+                .context("invalid channel id in interchain account transaction")?,
+            sequence: msg.sequence,
+            status,
+        })
+    }
+}
+
+impl From<InterchainAccountTx> for pb::InterchainAccountTx {
+    fn from(tx: InterchainAccountTx) -> Self {
+        use pb::interchain_account_tx::Status;
+        let (status, result, error) = match tx.status {
+            InterchainAccountTxStatus::Pending => (Status::Pending, Vec::new(), String::new()),
+            InterchainAccountTxStatus::Succeeded { result } => {
+                (Status::Succeeded, result, String::new())
+            }
+            InterchainAccountTxStatus::Failed { error } => (Status::Failed, Vec::new(), error),
+            InterchainAccountTxStatus::TimedOut => (Status::TimedOut, Vec::new(), String::new()),
+        };
+        pb::InterchainAccountTx {
+            proposal_id: tx.proposal_id,
+            connection_id: tx.connection_id.to_string(),
+            channel_id: tx.channel_id.to_string(),
+            sequence: tx.sequence,
+            status: status as i32,
+            result,
+            error,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn checks_host_metadata() {
+        let proposed = Ics27Metadata::new(&ConnectionId::new(0), &ConnectionId::new(7));
+        let version = proposed.to_version();
+        assert_eq!(
+            Ics27Metadata::from_version(&version).expect("valid metadata"),
+            proposed
+        );
+
+        // The host fills in the address of the account, and nothing else.
+        let accepted = Version::new(
+            r#"{"version":"ics27-1","controller_connection_id":"connection-0","host_connection_id":"connection-7","address":"osmo1account","encoding":"proto3","tx_type":"sdk_multi_msg"}"#
+                .to_string(),
+        );
+        let accepted = Ics27Metadata::from_version(&accepted).expect("valid metadata");
+        proposed
+            .check_counterparty(&accepted)
+            .expect("host accepted the proposed metadata");
+
+        let without_address = Ics27Metadata {
+            address: String::new(),
+            ..accepted.clone()
+        };
+        assert!(proposed.check_counterparty(&without_address).is_err());
+
+        let other_encoding = Ics27Metadata {
+            encoding: "proto3json".to_string(),
+            ..accepted
+        };
+        assert!(proposed.check_counterparty(&other_encoding).is_err());
+    }
+
+    #[test]
+    fn encodes_execute_tx_packets() {
+        let message = InterchainAccountMessage {
+            type_url: "/cosmos.bank.v1beta1.MsgSend".to_string(),
+            value: vec![1, 2, 3],
+        };
+        let data = Ics27PacketData::execute_tx(vec![message.clone()], "memo".to_string());
+        let json: serde_json::Value = serde_json::from_slice(&data.to_bytes()).expect("valid json");
+        assert_eq!(json["type"], "TYPE_EXECUTE_TX");
+        assert_eq!(json["memo"], "memo");
+
+        let tx = CosmosTx::decode(
+            BASE64_STANDARD
+                .decode(json["data"].as_str().expect("data is a string"))
+                .expect("data is base64")
+                .as_slice(),
+        )
+        .expect("data is a transaction");
+        assert_eq!(tx.messages, vec![message.into()]);
+    }
+
+    #[test]
+    fn parses_acknowledgements() {
+        assert_eq!(
+            InterchainAccountTxStatus::from_acknowledgement(br#"{"result":"AQI="}"#)
+                .expect("valid acknowledgement"),
+            InterchainAccountTxStatus::Succeeded { result: vec![1, 2] }
+        );
+        assert_eq!(
+            InterchainAccountTxStatus::from_acknowledgement(br#"{"error":"out of gas"}"#)
+                .expect("valid acknowledgement"),
+            InterchainAccountTxStatus::Failed {
+                error: "out of gas".to_string()
+            }
+        );
+        assert!(InterchainAccountTxStatus::from_acknowledgement(b"not json").is_err());
+    }
+}
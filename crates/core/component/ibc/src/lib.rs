@@ -14,7 +14,13 @@ pub use component::ibc_action_with_handler::IbcRelayWithHandlers;
 pub mod genesis;
 mod ibc_action;
 mod ibc_token;
+mod ics20_rate_limit;
+pub mod ics27;
+#[cfg(feature = "rpc")]
+pub mod monitor;
 pub mod params;
+#[cfg(feature = "rpc")]
+pub mod relay;
 mod version;
 
 mod prefix;
@@ -22,6 +28,10 @@ pub use prefix::{MerklePrefixExt, IBC_COMMITMENT_PREFIX, IBC_PROOF_SPECS, IBC_SU
 
 pub use ibc_action::IbcRelay;
 pub use ibc_token::IbcToken;
+pub use ics20_rate_limit::{Ics20RateLimit, Ics20RateLimitUsage};
+pub use ics27::{
+    InterchainAccount, InterchainAccountMessage, InterchainAccountTx, InterchainAccountTxStatus,
+};
 
 #[cfg(feature = "component")]
 pub use component::{StateReadExt, StateWriteExt};
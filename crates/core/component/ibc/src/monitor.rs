@@ -0,0 +1,236 @@
+//! A monitor for misbehaviour by the counterparty chain of one of Penumbra's IBC clients.
+//!
+//! Penumbra's clients accept any header signed by enough of the validators they trust, so
+//! validators signing conflicting blocks can get a client to store a consensus state that isn't
+//! on the counterparty's canonical chain. The [`ClientMonitor`] compares the consensus states a
+//! client stores against the counterparty's headers, fetched from an endpoint the operator trusts,
+//! and on a conflict builds a [`MsgSubmitMisbehaviour`] which freezes the client.
+//!
+//! The evidence pairs the header that installed the conflicting consensus state, recovered from
+//! its `update_client` event, with the canonical header at the same height. Consensus states
+//! installed by creating or upgrading the client have no such header, so their conflicts can only
+//! be reported.
+
+use std::collections::BTreeSet;
+
+use anyhow::{anyhow, Result};
+use async_trait::async_trait;
+use ibc_proto::{
+    google::protobuf::Any,
+    ibc::{
+        core::client::v1::{
+            query_client::QueryClient as ClientQueryClient, QueryClientStateRequest,
+            QueryConsensusStatesRequest,
+        },
+        lightclients::tendermint::v1::Header as RawTendermintHeader,
+    },
+};
+use ibc_types::{
+    core::client::{msgs::MsgSubmitMisbehaviour, ClientId, Height},
+    lightclients::tendermint::{
+        client_state::ClientState as TendermintClientState,
+        consensus_state::ConsensusState as TendermintConsensusState,
+        header::Header as TendermintHeader, misbehaviour::Misbehaviour as TendermintMisbehaviour,
+    },
+};
+use tendermint::abci::Event;
+use tonic::transport::Channel;
+
+use crate::relay::{attribute, HeaderSource};
+
+/// A source of the client events emitted by Penumbra.
+#[async_trait]
+pub trait ClientEventSource: Send {
+    /// Returns the `update_client` events of the updates of `client_id` to `consensus_height`.
+    async fn update_client_events(
+        &mut self,
+        client_id: &ClientId,
+        consensus_height: Height,
+    ) -> Result<Vec<Event>>;
+}
+
+/// Reconstructs the header submitted in the update described by an `update_client` event.
+pub fn header_from_event(event: &Event) -> Result<TendermintHeader> {
+    let header = hex::decode(attribute(event, "header")?)?;
+    <TendermintHeader as ibc_proto::Protobuf<RawTendermintHeader>>::decode_vec(&header)
+        .map_err(|e| anyhow!("invalid header in update_client event: {e}"))
+}
+
+/// A consensus state stored by a client which conflicts with the counterparty's header at the
+/// same height.
+#[derive(Debug, Clone)]
+pub struct Conflict {
+    pub height: Height,
+    /// The consensus state stored by the client.
+    pub consensus_state: TendermintConsensusState,
+    /// The counterparty's header at the consensus state's height.
+    pub header: TendermintHeader,
+    /// The evidence of the counterparty's misbehaviour, if the header that installed the
+    /// conflicting consensus state could be found.
+    pub misbehaviour: Option<MsgSubmitMisbehaviour>,
+}
+
+/// Monitors one of Penumbra's clients for misbehaviour by its counterparty chain.
+pub struct ClientMonitor<H, E> {
+    penumbra: Channel,
+    client_id: ClientId,
+    signer: String,
+    counterparty_headers: H,
+    penumbra_events: E,
+    /// The heights of the consensus states that have already been checked.
+    checked: BTreeSet<Height>,
+}
+
+impl<H: HeaderSource, E: ClientEventSource> ClientMonitor<H, E> {
+    /// Creates a monitor for the client `client_id` on `penumbra`, whose evidence of misbehaviour
+    /// records `signer` as its submitter.
+    pub fn new(
+        penumbra: Channel,
+        client_id: ClientId,
+        signer: String,
+        counterparty_headers: H,
+        penumbra_events: E,
+    ) -> Self {
+        Self {
+            penumbra,
+            client_id,
+            signer,
+            counterparty_headers,
+            penumbra_events,
+            checked: BTreeSet::new(),
+        }
+    }
+
+    /// Checks the consensus states stored by the client since the last check, returning those
+    /// which conflict with the counterparty's headers.
+    ///
+    /// A consensus state which can't be checked, for instance because the counterparty's node has
+    /// pruned its height, is logged and skipped, to be checked again by the next check.
+    ///
+    /// Frozen clients can't be misled any further, so they aren't checked.
+    pub async fn check(&mut self) -> Result<Vec<Conflict>> {
+        let mut clients = ClientQueryClient::new(self.penumbra.clone());
+
+        let client_state = clients
+            .client_state(QueryClientStateRequest {
+                client_id: self.client_id.to_string(),
+            })
+            .await?
+            .into_inner()
+            .client_state
+            .ok_or_else(|| anyhow!("client {} not found", self.client_id))?;
+        if TendermintClientState::try_from(client_state)?.is_frozen() {
+            tracing::debug!(client_id = %self.client_id, "client is frozen, skipping check");
+            return Ok(Vec::new());
+        }
+
+        let consensus_states = clients
+            .consensus_states(QueryConsensusStatesRequest {
+                client_id: self.client_id.to_string(),
+                pagination: None,
+            })
+            .await?
+            .into_inner()
+            .consensus_states;
+
+        let mut conflicts = Vec::new();
+        for consensus_state in consensus_states {
+            let height = Height::try_from(
+                consensus_state
+                    .height
+                    .ok_or_else(|| anyhow!("consensus state is missing its height"))?,
+            )?;
+            if self.checked.contains(&height) {
+                continue;
+            }
+            match self
+                .check_consensus_state(height, consensus_state.consensus_state)
+                .await
+            {
+                Ok(conflict) => {
+                    conflicts.extend(conflict);
+                    self.checked.insert(height);
+                }
+                Err(error) => tracing::warn!(
+                    client_id = %self.client_id,
+                    %height,
+                    ?error,
+                    "could not check consensus state, skipping it until the next check"
+                ),
+            }
+        }
+
+        Ok(conflicts)
+    }
+
+    /// Checks the consensus state stored by the client at `height` against the counterparty's
+    /// header at that height.
+    async fn check_consensus_state(
+        &mut self,
+        height: Height,
+        consensus_state: Option<Any>,
+    ) -> Result<Option<Conflict>> {
+        let consensus_state = TendermintConsensusState::try_from(
+            consensus_state.ok_or_else(|| anyhow!("consensus state at {height} not found"))?,
+        )?;
+
+        // Only the signed header is compared, so the header is fetched trusting its own height.
+        let header = self.counterparty_headers.header(height, height).await?;
+        if TendermintConsensusState::from(header.clone()) == consensus_state {
+            return Ok(None);
+        }
+
+        tracing::warn!(
+            client_id = %self.client_id,
+            %height,
+            "found conflicting consensus state"
+        );
+        let misbehaviour = self.misbehaviour(height, &header).await?;
+        Ok(Some(Conflict {
+            height,
+            consensus_state,
+            header,
+            misbehaviour,
+        }))
+    }
+
+    /// Builds the evidence of misbehaviour for a conflict with the counterparty's `header` at
+    /// `height`, if the header that installed the conflicting consensus state can be found.
+    async fn misbehaviour(
+        &mut self,
+        height: Height,
+        header: &TendermintHeader,
+    ) -> Result<Option<MsgSubmitMisbehaviour>> {
+        let events = self
+            .penumbra_events
+            .update_client_events(&self.client_id, height)
+            .await?;
+        let Some(conflicting_header) = events
+            .iter()
+            .filter(|event| event.kind == "update_client")
+            .filter_map(|event| header_from_event(event).ok())
+            .find(|conflicting_header| conflicting_header.height() == height)
+        else {
+            return Ok(None);
+        };
+
+        // Both headers must verify against the same consensus state trusted by the client, so the
+        // canonical header reuses the validators the conflicting header was trusted with.
+        let canonical_header = TendermintHeader {
+            trusted_height: conflicting_header.trusted_height,
+            trusted_validator_set: conflicting_header.trusted_validator_set.clone(),
+            ..header.clone()
+        };
+
+        Ok(Some(MsgSubmitMisbehaviour {
+            client_id: self.client_id.clone(),
+            misbehaviour: TendermintMisbehaviour {
+                client_id: self.client_id.clone(),
+                header1: conflicting_header,
+                header2: canonical_header,
+            }
+            .into(),
+            signer: self.signer.clone(),
+        }))
+    }
+}
@@ -2,6 +2,8 @@ use penumbra_proto::core::component::ibc::v1 as pb;
 use penumbra_proto::DomainType;
 use serde::{Deserialize, Serialize};
 
+use crate::Ics20RateLimit;
+
 #[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
 #[serde(try_from = "pb::IbcParameters", into = "pb::IbcParameters")]
 pub struct IBCParameters {
@@ -11,6 +13,8 @@ pub struct IBCParameters {
     pub inbound_ics20_transfers_enabled: bool,
     /// Whether outbound ICS-20 transfers are enabled
     pub outbound_ics20_transfers_enabled: bool,
+    /// Rate limits on ICS-20 transfers of particular assets over particular channels.
+    pub ics20_rate_limits: Vec<Ics20RateLimit>,
 }
 
 impl DomainType for IBCParameters {
@@ -25,6 +29,11 @@ impl TryFrom<pb::IbcParameters> for IBCParameters {
             ibc_enabled: msg.ibc_enabled,
             inbound_ics20_transfers_enabled: msg.inbound_ics20_transfers_enabled,
             outbound_ics20_transfers_enabled: msg.outbound_ics20_transfers_enabled,
+            ics20_rate_limits: msg
+                .ics20_rate_limits
+                .into_iter()
+                .map(TryInto::try_into)
+                .collect::<anyhow::Result<_>>()?,
         })
     }
 }
@@ -35,6 +44,11 @@ impl From<IBCParameters> for pb::IbcParameters {
             ibc_enabled: params.ibc_enabled,
             inbound_ics20_transfers_enabled: params.inbound_ics20_transfers_enabled,
             outbound_ics20_transfers_enabled: params.outbound_ics20_transfers_enabled,
+            ics20_rate_limits: params
+                .ics20_rate_limits
+                .into_iter()
+                .map(Into::into)
+                .collect(),
         }
     }
 }
@@ -45,6 +59,7 @@ impl Default for IBCParameters {
             ibc_enabled: true,
             inbound_ics20_transfers_enabled: true,
             outbound_ics20_transfers_enabled: true,
+            ics20_rate_limits: Vec::new(),
         }
     }
 }
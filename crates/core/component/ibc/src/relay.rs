@@ -0,0 +1,553 @@
+//! A lightweight relayer for the packets pending on a single channel.
+//!
+//! The [`ChannelRelayer`] relays a channel in both directions. Towards either end, it updates that
+//! end's client for the other chain, and delivers the packets the other chain sent over the
+//! channel, the acknowledgements the other chain wrote for packets this end sent, and the timeouts
+//! of packets this end sent that the other chain never received. The messages for Penumbra are
+//! submitted as `IbcRelay` actions, and those for the counterparty in a transaction on the
+//! counterparty chain.
+//!
+//! The counterparty's IBC query services must return proofs alongside their responses, and honor
+//! the `"height"` request header, as Penumbra's do.
+
+use std::str::FromStr as _;
+
+use anyhow::{anyhow, Result};
+use async_trait::async_trait;
+use ibc_proto::ibc::core::{
+    channel::v1::{
+        query_client::QueryClient as ChannelQueryClient, Order as RawOrder, QueryChannelRequest,
+        QueryNextSequenceReceiveRequest, QueryPacketAcknowledgementRequest,
+        QueryPacketAcknowledgementsRequest, QueryPacketCommitmentRequest,
+        QueryPacketCommitmentsRequest, QueryPacketReceiptRequest, QueryUnreceivedAcksRequest,
+        QueryUnreceivedPacketsRequest,
+    },
+    client::v1::{
+        query_client::QueryClient as ClientQueryClient, QueryClientStateRequest,
+        QueryConsensusStateRequest,
+    },
+    connection::v1::{query_client::QueryClient as ConnectionQueryClient, QueryConnectionRequest},
+};
+use ibc_types::{
+    core::{
+        channel::{
+            msgs::{MsgAcknowledgement, MsgRecvPacket, MsgTimeout},
+            packet::Sequence,
+            ChannelId, Packet, PortId, TimeoutHeight,
+        },
+        client::{msgs::MsgUpdateClient, ClientId, Height},
+        commitment::MerkleProof,
+    },
+    lightclients::tendermint::{
+        client_state::ClientState as TendermintClientState,
+        consensus_state::ConsensusState as TendermintConsensusState,
+        header::Header as TendermintHeader,
+    },
+    timestamp::Timestamp,
+    DomainType as _,
+};
+use tendermint::abci::Event;
+use tonic::transport::Channel;
+
+use crate::IbcRelay;
+
+/// A source of a chain's headers, used to update the other end's client for it.
+#[async_trait]
+pub trait HeaderSource: Send {
+    /// Returns the height of the chain's latest block.
+    async fn latest_height(&mut self) -> Result<u64>;
+
+    /// Returns the header of the chain's block at `height`, for a client that trusts the chain's
+    /// block at `trusted_height`.
+    async fn header(&mut self, height: Height, trusted_height: Height) -> Result<TendermintHeader>;
+}
+
+/// A source of the packet events emitted by a chain.
+#[async_trait]
+pub trait PacketEventSource: Send {
+    /// Returns the `send_packet` events of the packets with the given `sequences`, sent from
+    /// `port_id`/`channel_id`.
+    async fn send_packet_events(
+        &mut self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        sequences: &[u64],
+    ) -> Result<Vec<Event>>;
+
+    /// Returns the `write_acknowledgement` events of the packets with the given `sequences`,
+    /// received on `port_id`/`channel_id`.
+    async fn write_acknowledgement_events(
+        &mut self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        sequences: &[u64],
+    ) -> Result<Vec<Event>>;
+}
+
+pub(crate) fn attribute<'a>(event: &'a Event, key: &str) -> Result<&'a str> {
+    event
+        .attributes
+        .iter()
+        .find(|attr| attr.key == key)
+        .map(|attr| attr.value.as_str())
+        .ok_or_else(|| anyhow!("{} event is missing the {key} attribute", event.kind))
+}
+
+/// Reconstructs the packet described by a `send_packet`, `recv_packet` or `write_acknowledgement`
+/// event.
+pub fn packet_from_event(event: &Event) -> Result<Packet> {
+    Ok(Packet {
+        sequence: Sequence::from_str(attribute(event, "packet_sequence")?)?,
+        port_on_a: PortId::from_str(attribute(event, "packet_src_port")?)?,
+        chan_on_a: ChannelId::from_str(attribute(event, "packet_src_channel")?)?,
+        port_on_b: PortId::from_str(attribute(event, "packet_dst_port")?)?,
+        chan_on_b: ChannelId::from_str(attribute(event, "packet_dst_channel")?)?,
+        data: hex::decode(attribute(event, "packet_data_hex")?)?,
+        timeout_height_on_b: TimeoutHeight::from_str(attribute(event, "packet_timeout_height")?)?,
+        timeout_timestamp_on_b: Timestamp::from_str(attribute(event, "packet_timeout_timestamp")?)?,
+    })
+}
+
+/// Reconstructs the packet and acknowledgement described by a `write_acknowledgement` event.
+pub fn acknowledgement_from_event(event: &Event) -> Result<(Packet, Vec<u8>)> {
+    Ok((
+        packet_from_event(event)?,
+        hex::decode(attribute(event, "packet_ack_hex")?)?,
+    ))
+}
+
+/// Builds a request for the state whose proofs verify against the header at `proof_height`,
+/// which commits to the state as of the block before it.
+fn at_proof_height<T>(message: T, proof_height: Height) -> Result<tonic::Request<T>> {
+    let mut request = tonic::Request::new(message);
+    request.metadata_mut().insert(
+        "height",
+        format!(
+            "{}-{}",
+            proof_height.revision_number,
+            proof_height.revision_height - 1
+        )
+        .parse()?,
+    );
+    Ok(request)
+}
+
+/// Relays the packets pending on a channel, in both directions.
+pub struct ChannelRelayer<CH, CE, PH, PE> {
+    /// Penumbra's gRPC endpoint.
+    pub penumbra: Channel,
+    /// The counterparty's gRPC endpoint.
+    pub counterparty: Channel,
+    /// The port of Penumbra's end of the channel.
+    pub port_id: PortId,
+    /// The channel, as identified on Penumbra.
+    pub channel_id: ChannelId,
+    /// The signer recorded in the messages relayed to Penumbra.
+    pub signer: String,
+    /// The signer recorded in the messages relayed to the counterparty.
+    pub counterparty_signer: String,
+    /// The source of the counterparty's headers.
+    pub counterparty_headers: CH,
+    /// The source of the counterparty's packet events.
+    pub counterparty_events: CE,
+    /// The source of Penumbra's headers.
+    pub penumbra_headers: PH,
+    /// The source of Penumbra's packet events.
+    pub penumbra_events: PE,
+}
+
+impl<CH, CE, PH, PE> ChannelRelayer<CH, CE, PH, PE>
+where
+    CH: HeaderSource,
+    CE: PacketEventSource,
+    PH: HeaderSource,
+    PE: PacketEventSource,
+{
+    /// Returns the messages relaying everything pending on the channel to Penumbra, whose latest
+    /// block is at `penumbra_height` and `penumbra_time`.
+    ///
+    /// Unless Penumbra's client for the counterparty is already up to date, the first message
+    /// updates it to the counterparty's latest block, against which the remaining messages are
+    /// proven, so the messages must be submitted together and in order.
+    pub async fn pending_messages(
+        &mut self,
+        penumbra_height: Height,
+        penumbra_time: tendermint::Time,
+    ) -> Result<Vec<IbcRelay>> {
+        Route {
+            destination: &self.penumbra,
+            port_id: &self.port_id,
+            channel_id: &self.channel_id,
+            destination_events: &mut self.penumbra_events,
+            source: &self.counterparty,
+            source_headers: &mut self.counterparty_headers,
+            source_events: &mut self.counterparty_events,
+            signer: &self.signer,
+        }
+        .pending_messages(penumbra_height, penumbra_time)
+        .await
+    }
+
+    /// Returns the messages relaying everything pending on the channel to the counterparty, whose
+    /// latest block is at `counterparty_height` and `counterparty_time`.
+    ///
+    /// As with [`Self::pending_messages`], the messages must be submitted together and in order,
+    /// in a transaction on the counterparty chain.
+    pub async fn pending_counterparty_messages(
+        &mut self,
+        counterparty_height: Height,
+        counterparty_time: tendermint::Time,
+    ) -> Result<Vec<IbcRelay>> {
+        let counterparty = ChannelQueryClient::new(self.penumbra.clone())
+            .channel(QueryChannelRequest {
+                port_id: self.port_id.to_string(),
+                channel_id: self.channel_id.to_string(),
+            })
+            .await?
+            .into_inner()
+            .channel
+            .and_then(|channel| channel.counterparty)
+            .ok_or_else(|| anyhow!("channel {} has no counterparty", self.channel_id))?;
+        let port_id = PortId::from_str(&counterparty.port_id)?;
+        let channel_id = ChannelId::from_str(&counterparty.channel_id)?;
+
+        Route {
+            destination: &self.counterparty,
+            port_id: &port_id,
+            channel_id: &channel_id,
+            destination_events: &mut self.counterparty_events,
+            source: &self.penumbra,
+            source_headers: &mut self.penumbra_headers,
+            source_events: &mut self.penumbra_events,
+            signer: &self.counterparty_signer,
+        }
+        .pending_messages(counterparty_height, counterparty_time)
+        .await
+    }
+}
+
+/// One direction of a channel: messages are relayed from the source chain to the destination.
+struct Route<'a, H, SE, DE> {
+    /// The destination's gRPC endpoint.
+    destination: &'a Channel,
+    /// The port of the destination's end of the channel.
+    port_id: &'a PortId,
+    /// The channel, as identified on the destination.
+    channel_id: &'a ChannelId,
+    /// The source of the destination's packet events.
+    destination_events: &'a mut DE,
+    /// The source's gRPC endpoint.
+    source: &'a Channel,
+    /// The source of the source's headers.
+    source_headers: &'a mut H,
+    /// The source of the source's packet events.
+    source_events: &'a mut SE,
+    /// The signer recorded in the relayed messages.
+    signer: &'a str,
+}
+
+impl<H: HeaderSource, SE: PacketEventSource, DE: PacketEventSource> Route<'_, H, SE, DE> {
+    /// Returns the messages relaying everything pending on the channel to the destination, whose
+    /// latest block is at `destination_height` and `destination_time`.
+    async fn pending_messages(
+        &mut self,
+        destination_height: Height,
+        destination_time: tendermint::Time,
+    ) -> Result<Vec<IbcRelay>> {
+        let mut destination_channels = ChannelQueryClient::new(self.destination.clone());
+        let mut destination_clients = ClientQueryClient::new(self.destination.clone());
+        let mut source_channels = ChannelQueryClient::new(self.source.clone());
+
+        // Find the source's end of the channel, and the destination's client for the source.
+        let channel = destination_channels
+            .channel(QueryChannelRequest {
+                port_id: self.port_id.to_string(),
+                channel_id: self.channel_id.to_string(),
+            })
+            .await?
+            .into_inner()
+            .channel
+            .ok_or_else(|| anyhow!("channel {} not found", self.channel_id))?;
+        let ordered = channel.ordering == RawOrder::Ordered as i32;
+        let counterparty = channel
+            .counterparty
+            .ok_or_else(|| anyhow!("channel {} has no counterparty", self.channel_id))?;
+        let source_port_id = PortId::from_str(&counterparty.port_id)?;
+        let source_channel_id = ChannelId::from_str(&counterparty.channel_id)?;
+        let connection_id = channel
+            .connection_hops
+            .first()
+            .ok_or_else(|| anyhow!("channel {} has no connection", self.channel_id))?
+            .clone();
+        let client_id = ConnectionQueryClient::new(self.destination.clone())
+            .connection(QueryConnectionRequest {
+                connection_id: connection_id.clone(),
+            })
+            .await?
+            .into_inner()
+            .connection
+            .ok_or_else(|| anyhow!("connection {connection_id} not found"))?
+            .client_id;
+        let client_state = destination_clients
+            .client_state(QueryClientStateRequest {
+                client_id: client_id.clone(),
+            })
+            .await?
+            .into_inner()
+            .client_state
+            .ok_or_else(|| anyhow!("client {client_id} not found"))?;
+        let trusted_height = TendermintClientState::try_from(client_state)?.latest_height();
+
+        let mut messages = Vec::new();
+
+        // Update the client to the source's latest block, if it is behind, and prove
+        // everything else against the block the client is left at.
+        let latest_height = self.source_headers.latest_height().await?;
+        let (proof_height, source_time) = if latest_height > trusted_height.revision_height {
+            let height = Height {
+                revision_number: trusted_height.revision_number,
+                revision_height: latest_height,
+            };
+            let header = self.source_headers.header(height, trusted_height).await?;
+            let time = header.signed_header.header.time;
+            messages.push(IbcRelay::UpdateClient(MsgUpdateClient {
+                signer: self.signer.to_string(),
+                client_id: ClientId::from_str(&client_id)?,
+                client_message: header.into(),
+            }));
+            (height, time)
+        } else {
+            let consensus_state = destination_clients
+                .consensus_state(QueryConsensusStateRequest {
+                    client_id: client_id.clone(),
+                    revision_number: trusted_height.revision_number,
+                    revision_height: trusted_height.revision_height,
+                    latest_height: false,
+                })
+                .await?
+                .into_inner()
+                .consensus_state
+                .ok_or_else(|| anyhow!("consensus state at {trusted_height} not found"))?;
+            (
+                trusted_height,
+                TendermintConsensusState::try_from(consensus_state)?.timestamp,
+            )
+        };
+
+        // Packets the source sent, which the destination has yet to receive.
+        let sent: Vec<u64> = source_channels
+            .packet_commitments(QueryPacketCommitmentsRequest {
+                port_id: source_port_id.to_string(),
+                channel_id: source_channel_id.to_string(),
+                pagination: None,
+            })
+            .await?
+            .into_inner()
+            .commitments
+            .into_iter()
+            .map(|commitment| commitment.sequence)
+            .collect();
+        let unreceived = if sent.is_empty() {
+            Vec::new()
+        } else {
+            destination_channels
+                .unreceived_packets(QueryUnreceivedPacketsRequest {
+                    port_id: self.port_id.to_string(),
+                    channel_id: self.channel_id.to_string(),
+                    packet_commitment_sequences: sent,
+                })
+                .await?
+                .into_inner()
+                .sequences
+        };
+        let events = self
+            .source_events
+            .send_packet_events(&source_port_id, &source_channel_id, &unreceived)
+            .await?;
+        for event in events.iter().filter(|event| event.kind == "send_packet") {
+            let packet = packet_from_event(event)?;
+            if packet.port_on_a != source_port_id
+                || packet.chan_on_a != source_channel_id
+                || !unreceived.contains(&packet.sequence.into())
+            {
+                continue;
+            }
+            // Packets that timed out on the destination can only be timed out on the source.
+            if packet.timed_out(&destination_time.into(), destination_height) {
+                continue;
+            }
+            let request = at_proof_height(
+                QueryPacketCommitmentRequest {
+                    port_id: source_port_id.to_string(),
+                    channel_id: source_channel_id.to_string(),
+                    sequence: packet.sequence.into(),
+                },
+                proof_height,
+            )?;
+            // The commitment may postdate the proof height, in which case the packet is left
+            // for the next run.
+            let proof = match source_channels.packet_commitment(request).await {
+                Ok(response) => response.into_inner().proof,
+                Err(e) => {
+                    tracing::warn!(sequence = %packet.sequence, error = %e, "skipping packet");
+                    continue;
+                }
+            };
+            messages.push(IbcRelay::RecvPacket(MsgRecvPacket {
+                packet,
+                proof_commitment_on_a: MerkleProof::decode(proof.as_slice())?,
+                proof_height_on_a: proof_height,
+                signer: self.signer.to_string(),
+            }));
+        }
+
+        // Packets the destination sent, which are still awaiting an acknowledgement or a timeout.
+        let pending: Vec<u64> = destination_channels
+            .packet_commitments(QueryPacketCommitmentsRequest {
+                port_id: self.port_id.to_string(),
+                channel_id: self.channel_id.to_string(),
+                pagination: None,
+            })
+            .await?
+            .into_inner()
+            .commitments
+            .into_iter()
+            .map(|commitment| commitment.sequence)
+            .collect();
+        if pending.is_empty() {
+            return Ok(messages);
+        }
+
+        // Acknowledgements the source wrote for them.
+        let acknowledged: Vec<u64> = source_channels
+            .packet_acknowledgements(QueryPacketAcknowledgementsRequest {
+                port_id: source_port_id.to_string(),
+                channel_id: source_channel_id.to_string(),
+                pagination: None,
+                packet_commitment_sequences: pending.clone(),
+            })
+            .await?
+            .into_inner()
+            .acknowledgements
+            .into_iter()
+            .map(|ack| ack.sequence)
+            .collect();
+        let unreceived_acks = if acknowledged.is_empty() {
+            Vec::new()
+        } else {
+            destination_channels
+                .unreceived_acks(QueryUnreceivedAcksRequest {
+                    port_id: self.port_id.to_string(),
+                    channel_id: self.channel_id.to_string(),
+                    packet_ack_sequences: acknowledged,
+                })
+                .await?
+                .into_inner()
+                .sequences
+        };
+        let events = self
+            .source_events
+            .write_acknowledgement_events(&source_port_id, &source_channel_id, &unreceived_acks)
+            .await?;
+        for event in events
+            .iter()
+            .filter(|event| event.kind == "write_acknowledgement")
+        {
+            let (packet, acknowledgement) = acknowledgement_from_event(event)?;
+            if packet.port_on_a != *self.port_id
+                || packet.chan_on_a != *self.channel_id
+                || !unreceived_acks.contains(&packet.sequence.into())
+            {
+                continue;
+            }
+            let request = at_proof_height(
+                QueryPacketAcknowledgementRequest {
+                    port_id: source_port_id.to_string(),
+                    channel_id: source_channel_id.to_string(),
+                    sequence: packet.sequence.into(),
+                },
+                proof_height,
+            )?;
+            // As with packets, the acknowledgement may postdate the proof height.
+            let proof = match source_channels.packet_acknowledgement(request).await {
+                Ok(response) => response.into_inner().proof,
+                Err(e) => {
+                    tracing::warn!(sequence = %packet.sequence, error = %e, "skipping acknowledgement");
+                    continue;
+                }
+            };
+            messages.push(IbcRelay::Acknowledgement(MsgAcknowledgement {
+                packet,
+                acknowledgement,
+                proof_acked_on_b: MerkleProof::decode(proof.as_slice())?,
+                proof_height_on_b: proof_height,
+                signer: self.signer.to_string(),
+            }));
+        }
+
+        // Packets the source never received, and can no longer receive.
+        let unreceived = source_channels
+            .unreceived_packets(QueryUnreceivedPacketsRequest {
+                port_id: source_port_id.to_string(),
+                channel_id: source_channel_id.to_string(),
+                packet_commitment_sequences: pending,
+            })
+            .await?
+            .into_inner()
+            .sequences;
+        let events = self
+            .destination_events
+            .send_packet_events(self.port_id, self.channel_id, &unreceived)
+            .await?;
+        for event in events.iter().filter(|event| event.kind == "send_packet") {
+            let packet = packet_from_event(event)?;
+            if packet.port_on_a != *self.port_id
+                || packet.chan_on_a != *self.channel_id
+                || !unreceived.contains(&packet.sequence.into())
+                || !packet.timed_out(&source_time.into(), proof_height)
+            {
+                continue;
+            }
+            // Ordered channels prove the source's next receive sequence, and unordered
+            // channels the absence of a receipt for the packet.
+            let (next_seq_recv_on_b, proof) = if ordered {
+                let response = source_channels
+                    .next_sequence_receive(at_proof_height(
+                        QueryNextSequenceReceiveRequest {
+                            port_id: source_port_id.to_string(),
+                            channel_id: source_channel_id.to_string(),
+                        },
+                        proof_height,
+                    )?)
+                    .await?
+                    .into_inner();
+                (response.next_sequence_receive.into(), response.proof)
+            } else {
+                let response = source_channels
+                    .packet_receipt(at_proof_height(
+                        QueryPacketReceiptRequest {
+                            port_id: source_port_id.to_string(),
+                            channel_id: source_channel_id.to_string(),
+                            sequence: packet.sequence.into(),
+                        },
+                        proof_height,
+                    )?)
+                    .await?
+                    .into_inner();
+                if response.received {
+                    continue;
+                }
+                (packet.sequence, response.proof)
+            };
+            messages.push(IbcRelay::Timeout(MsgTimeout {
+                packet,
+                next_seq_recv_on_b,
+                proof_unreceived_on_b: MerkleProof::decode(proof.as_slice())?,
+                proof_height_on_b: proof_height,
+                signer: self.signer.to_string(),
+            }));
+        }
+
+        Ok(messages)
+    }
+}
@@ -0,0 +1,52 @@
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+use cnidarium::StateWrite;
+use ibc_types::transfer::acknowledgement::TokenTransferAcknowledgement;
+use penumbra_ibc::component::packet::WriteAcknowledgement as _;
+
+use crate::{component::transfer::unwind_inbound_transfer, state_key, Ics20Swap};
+
+/// Queues the inbound ICS-20 transfers that asked to be swapped on receipt, for the DEX to execute
+/// in the batch swap at the end of the block, and acknowledges them once it has.
+#[async_trait]
+pub trait Ics20SwapQueue: StateWrite {
+    fn queue_ics20_swap(&mut self, swap: Ics20Swap) {
+        let mut swaps = self.pending_ics20_swaps();
+        swaps.push_back(swap);
+        self.object_put(state_key::pending_ics20_swaps(), swaps);
+    }
+
+    fn pending_ics20_swaps(&self) -> im::Vector<Ics20Swap> {
+        self.object_get(state_key::pending_ics20_swaps())
+            .unwrap_or_default()
+    }
+
+    /// Removes and returns the swaps queued so far in this block.
+    fn take_pending_ics20_swaps(&mut self) -> im::Vector<Ics20Swap> {
+        let swaps = self.pending_ics20_swaps();
+        self.object_delete(state_key::pending_ics20_swaps());
+        swaps
+    }
+
+    /// Acknowledges the inbound transfer of an executed swap, once its outputs have been minted.
+    async fn acknowledge_ics20_swap(&mut self, swap: &Ics20Swap) -> Result<()> {
+        let ack: Vec<u8> = TokenTransferAcknowledgement::success().into();
+        self.write_acknowledgement(&swap.packet, &ack)
+            .await
+            .context("able to write acknowledgement for ics20 swap")
+    }
+
+    /// Refunds a swap that was not executed, by undoing the accounting of its inbound transfer and
+    /// acknowledging it with `error`, so that the chain it came from refunds the sender.
+    async fn refund_ics20_swap(&mut self, swap: &Ics20Swap, error: &str) -> Result<()> {
+        unwind_inbound_transfer(self, &swap.packet, &swap.input.asset_id, swap.input.amount)
+            .await?;
+        let ack: Vec<u8> =
+            TokenTransferAcknowledgement::Error(format!("ics20 swap failed: {error}")).into();
+        self.write_acknowledgement(&swap.packet, &ack)
+            .await
+            .context("able to write acknowledgement for refunded ics20 swap")
+    }
+}
+
+impl<T: StateWrite + ?Sized> Ics20SwapQueue for T {}
@@ -1,11 +1,16 @@
-use std::pin::Pin;
+use std::{pin::Pin, str::FromStr};
 
 use cnidarium::Storage;
+use ibc_types::core::channel::ChannelId;
 use penumbra_asset::asset::{self};
+use penumbra_ibc::{component::state_key as ibc_state_key, Ics20RateLimitUsage, StateReadExt as _};
 use penumbra_proto::core::component::shielded_pool::v1::{
     query_service_server::QueryService, AssetMetadataByIdRequest, AssetMetadataByIdResponse,
-    AssetMetadataByIdsRequest, AssetMetadataByIdsResponse,
+    AssetMetadataByIdsRequest, AssetMetadataByIdsResponse, Ics20RateLimitStatus,
+    Ics20RateLimitsRequest, Ics20RateLimitsResponse,
 };
+use penumbra_proto::StateReadProto as _;
+use penumbra_sct::component::clock::EpochRead as _;
 
 use tonic::Status;
 use tracing::instrument;
@@ -70,4 +75,64 @@ impl QueryService for Server {
     ) -> Result<tonic::Response<Self::AssetMetadataByIdsStream>, tonic::Status> {
         unimplemented!("asset_metadata_by_ids not yet implemented")
     }
+
+    #[instrument(skip(self, request))]
+    async fn ics20_rate_limits(
+        &self,
+        request: tonic::Request<Ics20RateLimitsRequest>,
+    ) -> Result<tonic::Response<Ics20RateLimitsResponse>, Status> {
+        let state = self.storage.latest_snapshot();
+
+        let request = request.into_inner();
+        let channel_id = if request.channel_id.is_empty() {
+            None
+        } else {
+            Some(ChannelId::from_str(&request.channel_id).map_err(|e| {
+                Status::invalid_argument(format!("could not parse channel_id: {e}"))
+            })?)
+        };
+        let asset_id: Option<asset::Id> = request
+            .asset_id
+            .map(TryInto::try_into)
+            .transpose()
+            .map_err(|e| Status::invalid_argument(format!("could not parse asset_id: {e}")))?;
+
+        let limits = state
+            .get_ibc_params()
+            .await
+            .map_err(|e| Status::internal(e.to_string()))?
+            .ics20_rate_limits;
+        let height = state
+            .get_block_height()
+            .await
+            .map_err(|e| Status::internal(e.to_string()))?;
+
+        let mut statuses = Vec::new();
+        for limit in limits {
+            if channel_id
+                .as_ref()
+                .is_some_and(|id| id != &limit.channel_id)
+                || asset_id.as_ref().is_some_and(|id| id != &limit.asset_id)
+            {
+                continue;
+            }
+            let usage: Ics20RateLimitUsage = state
+                .get(&ibc_state_key::ics20_rate_limit_usage::by_asset_id(
+                    &limit.channel_id,
+                    &limit.asset_id,
+                ))
+                .await
+                .map_err(|e| Status::internal(e.to_string()))?
+                .unwrap_or_default();
+            let usage = usage.at_height(&limit, height);
+            statuses.push(Ics20RateLimitStatus {
+                limit: Some(limit.into()),
+                usage: Some(usage.into()),
+            });
+        }
+
+        Ok(tonic::Response::new(Ics20RateLimitsResponse {
+            limits: statuses,
+        }))
+    }
 }
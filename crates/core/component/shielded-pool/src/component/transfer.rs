@@ -1,35 +1,44 @@
 use std::str::FromStr;
 
 use crate::{
-    component::{AssetRegistry, NoteManager},
+    component::{AssetRegistry, Ics20SwapQueue, NoteManager},
     event::{self, FungibleTokenTransferPacketMetadata},
-    Ics20Withdrawal,
+    state_key::ics20_forwards,
+    Ics20ForwardMemo, Ics20Swap, Ics20SwapMemo, Ics20Withdrawal,
 };
 use anyhow::{Context, Result};
 use async_trait::async_trait;
-use cnidarium::{StateRead, StateWrite};
+use cnidarium::{StateDelta, StateRead, StateWrite};
+use ibc_proto::ibc::core::channel::v1::Packet as RawPacket;
 use ibc_types::core::channel::Packet;
 use ibc_types::{
-    core::channel::{
-        channel::Order as ChannelOrder,
-        msgs::{
-            MsgAcknowledgement, MsgChannelCloseConfirm, MsgChannelCloseInit, MsgChannelOpenAck,
-            MsgChannelOpenConfirm, MsgChannelOpenInit, MsgChannelOpenTry, MsgRecvPacket,
-            MsgTimeout,
+    core::{
+        channel::{
+            channel::Order as ChannelOrder,
+            msgs::{
+                MsgAcknowledgement, MsgChannelCloseConfirm, MsgChannelCloseInit, MsgChannelOpenAck,
+                MsgChannelOpenConfirm, MsgChannelOpenInit, MsgChannelOpenTry, MsgRecvPacket,
+                MsgTimeout,
+            },
+            ChannelId, PortId, Version,
         },
-        ChannelId, PortId, Version,
+        client::Height as IbcHeight,
     },
     transfer::acknowledgement::TokenTransferAcknowledgement,
 };
 use penumbra_asset::{asset, asset::Metadata, Value};
-use penumbra_ibc::component::ChannelStateReadExt;
+use penumbra_ibc::component::{
+    ChannelStateReadExt, ClientStateReadExt as _, ConnectionStateReadExt as _,
+};
+use penumbra_ibc::{Ics20RateLimitUsage, StateReadExt as _};
 use penumbra_keys::Address;
 use penumbra_num::Amount;
 use penumbra_proto::{
     penumbra::core::component::ibc::v1::FungibleTokenPacketData, DomainType as _, StateReadProto,
     StateWriteProto,
 };
-use penumbra_sct::CommitmentSource;
+use penumbra_sct::{component::clock::EpochRead as _, CommitmentSource};
+use prost::Message as _;
 
 use penumbra_ibc::component::{
     app_handler::{AppHandler, AppHandlerCheck, AppHandlerExecute},
@@ -67,6 +76,102 @@ fn is_source(
     }
 }
 
+/// The direction of an ICS-20 flow counted against a rate limit.
+enum Flow {
+    Inbound,
+    Outbound,
+    Refund,
+    InboundRefund,
+}
+
+/// The usage of the rate limit on `channel_id` and `asset_id`, if there is one, after a flow of
+/// `amount` of `asset_id` over `channel_id`, failing if the limit would be exceeded.
+async fn rate_limited_usage<S: StateRead + ?Sized>(
+    state: &S,
+    channel_id: &ChannelId,
+    asset_id: &asset::Id,
+    amount: Amount,
+    flow: Flow,
+) -> Result<Option<Ics20RateLimitUsage>> {
+    let Some(limit) = state
+        .get_ibc_params()
+        .await?
+        .ics20_rate_limits
+        .into_iter()
+        .find(|limit| limit.applies_to(channel_id, asset_id))
+    else {
+        return Ok(None);
+    };
+
+    let height = state.get_block_height().await?;
+    let usage: Ics20RateLimitUsage = state
+        .get(&state_key::ics20_rate_limit_usage::by_asset_id(
+            channel_id, asset_id,
+        ))
+        .await?
+        .unwrap_or_default();
+    let usage = usage.at_height(&limit, height);
+    let usage = match flow {
+        Flow::Inbound => usage.record_inflow(&limit, amount)?,
+        Flow::Outbound => usage.record_outflow(&limit, amount)?,
+        Flow::Refund => usage.record_refund(amount),
+        Flow::InboundRefund => usage.record_inbound_refund(amount),
+    };
+
+    Ok(Some(usage))
+}
+
+/// Counts a flow of `amount` of `asset_id` over `channel_id` against the rate limit on that
+/// channel and asset, if there is one, failing if the limit would be exceeded.
+async fn record_rate_limited_flow<S: StateWrite + ?Sized>(
+    state: &mut S,
+    channel_id: &ChannelId,
+    asset_id: &asset::Id,
+    amount: Amount,
+    flow: Flow,
+) -> Result<()> {
+    if let Some(usage) = rate_limited_usage(state, channel_id, asset_id, amount, flow).await? {
+        state.put(
+            state_key::ics20_rate_limit_usage::by_asset_id(channel_id, asset_id),
+            usage,
+        );
+    }
+
+    Ok(())
+}
+
+/// Mints the value of an inbound transfer to the receiver, or, if the transfer asked to be swapped
+/// into `asset_out` with at least `min_output`, queues it for the batch swap at the end of the block.
+async fn mint_or_queue_swap<S: StateWrite>(
+    state: &mut S,
+    packet: &Packet,
+    value: Value,
+    receiver: &Address,
+    swap: Option<(asset::Id, Amount)>,
+    source: CommitmentSource,
+) -> Result<Received> {
+    match swap {
+        Some((asset_out, min_output)) => {
+            state.queue_ics20_swap(Ics20Swap {
+                receiver: receiver.clone(),
+                input: value,
+                asset_out,
+                min_output,
+                source,
+                packet: packet.clone(),
+            });
+            Ok(Received::Queued)
+        }
+        None => {
+            state
+                .mint_note(value, receiver, source)
+                .await
+                .context("unable to mint note when receiving ics20 transfer packet")?;
+            Ok(Received::Delivered)
+        }
+    }
+}
+
 #[derive(Clone)]
 pub struct Ics20Transfer {}
 
@@ -83,6 +188,16 @@ pub trait Ics20TransferReadExt: StateRead {
         // send packet
         self.send_packet_check(packet, current_block_time).await?;
 
+        // check that the withdrawal fits in the rate limit, given the flow recorded so far.
+        rate_limited_usage(
+            self,
+            &withdrawal.source_channel,
+            &withdrawal.denom.id(),
+            withdrawal.amount,
+            Flow::Outbound,
+        )
+        .await?;
+
         Ok(())
     }
 }
@@ -95,6 +210,15 @@ pub trait Ics20TransferWriteExt: StateWrite {
         // create packet, assume it's already checked since the component caller contract calls `check` before `execute`
         let checked_packet = IBCPacket::<Unchecked>::from(withdrawal.clone()).assume_checked();
 
+        record_rate_limited_flow(
+            self,
+            &withdrawal.source_channel,
+            &withdrawal.denom.id(),
+            withdrawal.amount,
+            Flow::Outbound,
+        )
+        .await?;
+
         let prefix = format!("transfer/{}/", &withdrawal.source_channel);
         if !withdrawal.denom.starts_with(&prefix) {
             // we are the source. add the value balance to the escrow channel.
@@ -300,11 +424,23 @@ impl AppHandlerCheck for Ics20Transfer {
     }
 }
 
+/// What became of a received transfer.
+enum Received {
+    /// The transfer was delivered to its receiver on Penumbra.
+    Delivered,
+    /// The transfer was forwarded to another chain, and will be acknowledged once the forwarded
+    /// packet is.
+    Forwarded,
+    /// The transfer was queued to be swapped at the end of the block, and will be acknowledged
+    /// once the swap has been executed or refunded.
+    Queued,
+}
+
 // the main entry point for ICS20 transfer packet handling
 async fn recv_transfer_packet_inner<S: StateWrite>(
     mut state: S,
     msg: &MsgRecvPacket,
-) -> Result<()> {
+) -> Result<Received> {
     // parse if we are source or dest, and mint or burn accordingly
     //
     // see this part of the spec for this logic:
@@ -324,11 +460,19 @@ async fn recv_transfer_packet_inner<S: StateWrite>(
         .try_into()
         .context("couldnt decode amount in ICS20 transfer")?;
     let receiver_address = Address::from_str(&packet_data.receiver)?;
+    // parse the forward or swap requested by the memo, if any, before touching any state.
+    let forward = Ics20ForwardMemo::from_memo(&packet_data.memo)?;
+    let swap = match forward {
+        Some(_) => None,
+        None => Ics20SwapMemo::from_memo(&packet_data.memo)?
+            .map(|memo| anyhow::Ok((memo.asset_out()?, memo.min_output()?)))
+            .transpose()?,
+    };
 
     // NOTE: here we assume we are chain A.
 
     // 2. check if we are the source chain for the denom.
-    if is_source(
+    let (denom, value) = if is_source(
         &msg.packet.port_on_a,
         &msg.packet.chan_on_a,
         &packet_denom,
@@ -372,19 +516,21 @@ async fn recv_transfer_packet_inner<S: StateWrite>(
             anyhow::bail!("transfer coins failed");
         }
 
-        state
-            .mint_note(
-                value,
-                &receiver_address,
-                CommitmentSource::Ics20Transfer {
-                    packet_seq: msg.packet.sequence.0,
-                    // We are chain A
-                    channel_id: msg.packet.chan_on_a.0.clone(),
-                    sender: packet_data.sender.clone(),
-                },
-            )
-            .await
-            .context("unable to mint note when receiving ics20 transfer packet")?;
+        if let Some((asset_out, _)) = swap {
+            anyhow::ensure!(
+                asset_out != denom.id(),
+                "swap memo asks for a swap into the same asset"
+            );
+        }
+
+        record_rate_limited_flow(
+            &mut state,
+            &msg.packet.chan_on_b,
+            &denom.id(),
+            receiver_amount,
+            Flow::Inbound,
+        )
+        .await?;
 
         // update the value balance
         // note: this arithmetic was checked above, but we do it again anyway.
@@ -395,18 +541,8 @@ async fn recv_transfer_packet_inner<S: StateWrite>(
             state_key::ics20_value_balance::by_asset_id(&msg.packet.chan_on_b, &denom.id()),
             new_value_balance,
         );
-        state.record_proto(
-            event::EventInboundFungibleTokenTransfer {
-                value,
-                sender: packet_data.sender.clone(),
-                receiver: receiver_address,
-                meta: FungibleTokenTransferPacketMetadata {
-                    channel: msg.packet.chan_on_a.0.clone(),
-                    sequence: msg.packet.sequence.0,
-                },
-            }
-            .to_proto(),
-        );
+
+        (denom, value)
     } else {
         // create new denom:
         //
@@ -423,6 +559,23 @@ async fn recv_transfer_packet_inner<S: StateWrite>(
             .as_str()
             .try_into()
             .context("unable to parse denom in ics20 transfer as DenomMetadata")?;
+
+        if let Some((asset_out, _)) = swap {
+            anyhow::ensure!(
+                asset_out != denom.id(),
+                "swap memo asks for a swap into the same asset"
+            );
+        }
+
+        record_rate_limited_flow(
+            &mut state,
+            &msg.packet.chan_on_b,
+            &denom.id(),
+            receiver_amount,
+            Flow::Inbound,
+        )
+        .await?;
+
         state.register_denom(&denom).await;
 
         let value = Value {
@@ -430,20 +583,6 @@ async fn recv_transfer_packet_inner<S: StateWrite>(
             asset_id: denom.id(),
         };
 
-        state
-            .mint_note(
-                value,
-                &receiver_address,
-                CommitmentSource::Ics20Transfer {
-                    packet_seq: msg.packet.sequence.0,
-                    // We are chain A
-                    channel_id: msg.packet.chan_on_a.0.clone(),
-                    sender: packet_data.sender.clone(),
-                },
-            )
-            .await
-            .context("failed to mint notes in ibc transfer")?;
-
         // update the value balance
         let value_balance: Amount = state
             .get(&state_key::ics20_value_balance::by_asset_id(
@@ -458,21 +597,52 @@ async fn recv_transfer_packet_inner<S: StateWrite>(
             state_key::ics20_value_balance::by_asset_id(&msg.packet.chan_on_b, &denom.id()),
             new_value_balance,
         );
-        state.record_proto(
-            event::EventInboundFungibleTokenTransfer {
-                value,
-                sender: packet_data.sender.clone(),
-                receiver: receiver_address,
-                meta: FungibleTokenTransferPacketMetadata {
-                    channel: msg.packet.chan_on_a.0.clone(),
-                    sequence: msg.packet.sequence.0,
-                },
-            }
-            .to_proto(),
-        );
+
+        (denom, value)
+    };
+
+    if let Some(forward) = forward {
+        forward_transfer(
+            &mut state,
+            &msg.packet,
+            denom,
+            receiver_amount,
+            receiver_address,
+            &forward,
+        )
+        .await
+        .context("failed to forward ics20 transfer")?;
+        return Ok(Received::Forwarded);
     }
 
-    Ok(())
+    let received = mint_or_queue_swap(
+        &mut state,
+        &msg.packet,
+        value,
+        &receiver_address,
+        swap,
+        CommitmentSource::Ics20Transfer {
+            packet_seq: msg.packet.sequence.0,
+            // We are chain A
+            channel_id: msg.packet.chan_on_a.0.clone(),
+            sender: packet_data.sender.clone(),
+        },
+    )
+    .await?;
+    state.record_proto(
+        event::EventInboundFungibleTokenTransfer {
+            value,
+            sender: packet_data.sender.clone(),
+            receiver: receiver_address,
+            meta: FungibleTokenTransferPacketMetadata {
+                channel: msg.packet.chan_on_a.0.clone(),
+                sequence: msg.packet.sequence.0,
+            },
+        }
+        .to_proto(),
+    );
+
+    Ok(received)
 }
 
 // see: https://github.com/cosmos/ibc/blob/8326e26e7e1188b95c32481ff00348a705b23700/spec/app/ics-020-fungible-token-transfer/README.md?plain=1#L297
@@ -504,6 +674,16 @@ async fn refund_tokens<S: StateWrite>(
         asset_id: denom.id(),
     };
 
+    // the transfer never left, so it shouldn't count against the rate limit.
+    record_rate_limited_flow(
+        &mut state,
+        &packet.chan_on_a,
+        &denom.id(),
+        amount,
+        Flow::Refund,
+    )
+    .await?;
+
     if is_source(&packet.port_on_a, &packet.chan_on_a, &denom, true) {
         // sender was source chain, unescrow tokens back to sender
         let value_balance: Amount = state
@@ -601,6 +781,218 @@ async fn refund_tokens<S: StateWrite>(
     Ok(())
 }
 
+/// The number of blocks on the counterparty after which a forwarded transfer times out, on top of
+/// the timeout given by its memo: 2 days of blocks, at 10 seconds per block.
+const FORWARD_TIMEOUT_BLOCKS: u64 = ((24 * 60 * 60) / 10) * 2;
+
+/// Forwards an inbound transfer of `amount` of `denom`, which has been accounted for but not
+/// minted, to another chain, as requested by its memo.
+async fn forward_transfer<S: StateWrite>(
+    state: &mut S,
+    packet: &Packet,
+    denom: Metadata,
+    amount: Amount,
+    receiver: Address,
+    forward: &Ics20ForwardMemo,
+) -> Result<()> {
+    let port = forward.port()?;
+    anyhow::ensure!(
+        port == PortId::transfer(),
+        "can only forward transfers over the transfer port"
+    );
+    let channel = forward.channel()?;
+
+    // time out on the counterparty's height well after its timestamp, which is what the memo
+    // asks for.
+    let channel_end = state
+        .get_channel(&channel, &port)
+        .await?
+        .ok_or_else(|| anyhow::anyhow!("channel {channel} on port {port} does not exist"))?;
+    let connection = state
+        .get_connection(&channel_end.connection_hops[0])
+        .await?
+        .ok_or_else(|| {
+            anyhow::anyhow!(
+                "connection {} does not exist",
+                channel_end.connection_hops[0]
+            )
+        })?;
+    let latest_height = state
+        .get_client_state(&connection.client_id)
+        .await?
+        .latest_height();
+    let timeout_height = IbcHeight {
+        revision_number: latest_height.revision_number,
+        revision_height: latest_height.revision_height + FORWARD_TIMEOUT_BLOCKS,
+    };
+
+    let now = state.get_current_block_timestamp().await?;
+    let timeout_time = (now.unix_timestamp_nanos() as u64)
+        .checked_add(forward.timeout()?.as_nanos().try_into()?)
+        .context("forward timeout is too long")?;
+
+    // the receiver on Penumbra never holds the tokens, but is the sender of the forwarded
+    // transfer, so that it is the one refunded if the forwarded packet fails on the counterparty.
+    let withdrawal = Ics20Withdrawal {
+        amount,
+        denom,
+        destination_chain_address: forward.receiver.clone(),
+        return_address: receiver,
+        timeout_height,
+        timeout_time,
+        source_channel: channel.clone(),
+        use_compat_address: false,
+        ics20_memo: forward.next_memo(),
+        use_transparent_address: false,
+    };
+    let sequence = state.get_send_sequence(&channel, &port).await?;
+    state.withdrawal_check(&withdrawal, now).await?;
+    state.withdrawal_execute(&withdrawal).await?;
+
+    state.put_raw(
+        ics20_forwards::by_packet(&channel, sequence),
+        RawPacket::from(packet.clone()).encode_to_vec(),
+    );
+
+    Ok(())
+}
+
+/// Takes the inbound packet that `forwarded` was forwarded from, if it was.
+async fn take_forwarded_packet<S: StateWrite>(
+    state: &mut S,
+    forwarded: &Packet,
+) -> Result<Option<Packet>> {
+    let key = ics20_forwards::by_packet(&forwarded.chan_on_a, forwarded.sequence.0);
+    let Some(bytes) = state.get_raw(&key).await? else {
+        return Ok(None);
+    };
+    state.delete(key);
+
+    let packet = RawPacket::decode(bytes.as_slice())?;
+    Packet::try_from(packet)
+        .map(Some)
+        .map_err(|e| anyhow::anyhow!("invalid forwarded packet: {e:?}"))
+}
+
+/// Acknowledges the inbound packet that `forwarded` was forwarded from, once `forwarded` has been
+/// acknowledged or has timed out, with `error` if it failed.
+///
+/// If the forwarded packet failed, the forwarded transfer is unwound, so that the error
+/// acknowledgement refunds the tokens on the chain the transfer came from.
+async fn complete_forward<S: StateWrite>(
+    state: &mut S,
+    forwarded: &Packet,
+    inbound: Packet,
+    error: Option<String>,
+) -> Result<()> {
+    let ack: Vec<u8> = match error {
+        None => TokenTransferAcknowledgement::success().into(),
+        Some(error) => {
+            unwind_forward(state, forwarded, &inbound).await?;
+            TokenTransferAcknowledgement::Error(format!("forwarded transfer failed: {error}"))
+                .into()
+        }
+    };
+
+    state
+        .write_acknowledgement(&inbound, &ack)
+        .await
+        .context("able to write acknowledgement for forwarded packet")?;
+
+    Ok(())
+}
+
+/// Undoes the accounting of a forwarded transfer whose forwarded packet failed: the outbound
+/// transfer, as `refund_tokens` would but without minting the tokens, and then the inbound one.
+async fn unwind_forward<S: StateWrite>(
+    state: &mut S,
+    forwarded: &Packet,
+    inbound: &Packet,
+) -> Result<()> {
+    let packet_data: FungibleTokenPacketData = serde_json::from_slice(forwarded.data.as_slice())?;
+    let denom: asset::Metadata = packet_data
+        .denom
+        .as_str()
+        .try_into()
+        .context("couldn't decode denom of forwarded packet")?;
+    let amount: Amount = packet_data
+        .amount
+        .try_into()
+        .context("couldn't decode amount of forwarded packet")?;
+
+    record_rate_limited_flow(
+        state,
+        &forwarded.chan_on_a,
+        &denom.id(),
+        amount,
+        Flow::Refund,
+    )
+    .await?;
+
+    let outbound_key =
+        state_key::ics20_value_balance::by_asset_id(&forwarded.chan_on_a, &denom.id());
+    let value_balance: Amount = state.get(&outbound_key).await?.unwrap_or_else(Amount::zero);
+    let value_balance = if is_source(&forwarded.port_on_a, &forwarded.chan_on_a, &denom, true) {
+        // we escrowed the tokens when forwarding them, so unescrow them again
+        value_balance
+            .checked_sub(&amount)
+            .context("couldn't unwind forwarded transfer: not enough value balance")?
+    } else {
+        // we burned the vouchers when forwarding them, so restore them
+        value_balance.saturating_add(&amount)
+    };
+    state.put(outbound_key, value_balance);
+
+    // the forwarded transfer carries the denom as it was known on Penumbra, so its asset ID is
+    // the one the inbound transfer was accounted for under.
+    unwind_inbound_transfer(state, inbound, &denom.id(), amount).await
+}
+
+/// Undoes the accounting of an inbound transfer of `amount` of `asset_id`, the asset it was
+/// accounted for under on Penumbra, before it is acknowledged with an error, so that the chain it
+/// came from can refund it. The transfer no longer counts against the inbound rate limit.
+pub(crate) async fn unwind_inbound_transfer<S: StateWrite + ?Sized>(
+    state: &mut S,
+    inbound: &Packet,
+    asset_id: &asset::Id,
+    amount: Amount,
+) -> Result<()> {
+    let inbound_data: FungibleTokenPacketData = serde_json::from_slice(inbound.data.as_slice())?;
+    let inbound_denom: asset::Metadata = inbound_data
+        .denom
+        .as_str()
+        .try_into()
+        .context("couldn't decode denom of inbound packet")?;
+    let inbound_key = state_key::ics20_value_balance::by_asset_id(&inbound.chan_on_b, asset_id);
+    let value_balance: Amount = state.get(&inbound_key).await?.unwrap_or_else(Amount::zero);
+    let value_balance = if is_source(
+        &inbound.port_on_a,
+        &inbound.chan_on_a,
+        &inbound_denom,
+        false,
+    ) {
+        // we unescrowed the tokens on receipt, so escrow them again
+        value_balance
+            .checked_add(&amount)
+            .context("overflow unwinding inbound transfer")?
+    } else {
+        // we minted vouchers on receipt, so burn them again
+        value_balance
+            .checked_sub(&amount)
+            .context("couldn't unwind inbound transfer: not enough value balance")?
+    };
+    state.put(inbound_key, value_balance);
+
+    record_rate_limited_flow(
+        state,
+        &inbound.chan_on_b,
+        asset_id,
+        amount,
+        Flow::InboundRefund,
+    )
+    .await
+}
+
 // NOTE: should these be fallible, now that our enclosing state machine is fallible in execution?
 #[async_trait]
 impl AppHandlerExecute for Ics20Transfer {
@@ -612,12 +1004,26 @@ impl AppHandlerExecute for Ics20Transfer {
     async fn chan_close_init_execute<S: StateWrite>(_state: S, _msg: &MsgChannelCloseInit) {}
     async fn recv_packet_execute<S: StateWrite>(mut state: S, msg: &MsgRecvPacket) -> Result<()> {
         // recv packet should never fail a transaction, but it should record a failure acknowledgement.
-        let ack: Vec<u8> = match recv_transfer_packet_inner(&mut state, msg).await {
-            Ok(_) => {
-                // record packet acknowledgement without error
-                TokenTransferAcknowledgement::success().into()
+        //
+        // the transfer is executed in a fork of the state, so that a failed transfer leaves no
+        // partial state changes behind.
+        let mut fork = StateDelta::new(&mut state);
+        let ack: Vec<u8> = match recv_transfer_packet_inner(&mut fork, msg).await {
+            Ok(received) => {
+                let (state, events) = fork.apply();
+                for event in events {
+                    state.record(event);
+                }
+                match received {
+                    // record packet acknowledgement without error
+                    Received::Delivered => TokenTransferAcknowledgement::success().into(),
+                    // the packet is acknowledged once the forwarded packet is, or once the swap
+                    // has been executed
+                    Received::Forwarded | Received::Queued => return Ok(()),
+                }
             }
             Err(e) => {
+                std::mem::drop(fork);
                 tracing::debug!("couldnt execute transfer: {:#}", e);
                 // record packet acknowledgement with error
                 TokenTransferAcknowledgement::Error(e.to_string()).into()
@@ -633,6 +1039,17 @@ impl AppHandlerExecute for Ics20Transfer {
     }
 
     async fn timeout_packet_execute<S: StateWrite>(mut state: S, msg: &MsgTimeout) -> Result<()> {
+        if let Some(inbound) = take_forwarded_packet(&mut state, &msg.packet).await? {
+            return complete_forward(
+                &mut state,
+                &msg.packet,
+                inbound,
+                Some("packet timed out".to_string()),
+            )
+            .await
+            .context("able to time out forwarded packet");
+        }
+
         // timeouts may fail due to counterparty chains sending transfers of u128-1
         refund_tokens(
             &mut state,
@@ -651,6 +1068,16 @@ impl AppHandlerExecute for Ics20Transfer {
     ) -> Result<()> {
         let ack: TokenTransferAcknowledgement =
             serde_json::from_slice(msg.acknowledgement.as_slice())?;
+        if let Some(inbound) = take_forwarded_packet(&mut state, &msg.packet).await? {
+            // the packet was forwarded on behalf of another chain, so pass its acknowledgement on.
+            let error = match ack {
+                TokenTransferAcknowledgement::Error(error) => Some(error),
+                _ => None,
+            };
+            return complete_forward(&mut state, &msg.packet, inbound, error)
+                .await
+                .context("unable to acknowledge forwarded packet");
+        }
         if !ack.is_successful() {
             // in the case where a counterparty chain acknowledges a packet with an error,
             // for example due to a middleware processing issue or other behavior,
@@ -0,0 +1,200 @@
+//! Forwarding of inbound ICS-20 transfers to another chain, as requested by their memo.
+//!
+//! Penumbra can act as an intermediate hop for a transfer from one chain to another, following
+//! the conventions of the packet-forward middleware. A transfer into Penumbra asks to be forwarded
+//! by carrying a memo of the form
+//!
+//! ```json
+//! {"forward": {"receiver": "osmo1...", "port": "transfer", "channel": "channel-1", "timeout": "10m"}}
+//! ```
+//!
+//! Rather than being minted to the receiver on Penumbra, the transferred tokens are sent on over
+//! `channel` to `receiver`, carrying the memo given by `next`, if any. The inbound packet is only
+//! acknowledged once the forwarded packet is: if the forwarded packet fails or times out, the
+//! inbound packet is acknowledged with an error, refunding the tokens on the chain the transfer
+//! came from. Retries are not supported.
+
+use std::{str::FromStr, time::Duration};
+
+use anyhow::{anyhow, bail, Context, Result};
+use ibc_types::core::channel::{ChannelId, PortId};
+use serde::{Deserialize, Serialize};
+
+/// The timeout of a forwarded transfer, if its memo doesn't specify one.
+pub const DEFAULT_FORWARD_TIMEOUT: Duration = Duration::from_secs(10 * 60);
+
+/// The forward requested by an ICS-20 memo.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct Ics20ForwardMemo {
+    /// The address on the destination chain to forward the transfer to.
+    pub receiver: String,
+    /// The port to forward the transfer over.
+    pub port: String,
+    /// The channel to forward the transfer over.
+    pub channel: String,
+    /// The timeout of the forwarded transfer, either as a number of nanoseconds or as a duration
+    /// such as `"10m"`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub timeout: Option<serde_json::Value>,
+    /// The memo to pass on with the forwarded transfer, either as a string or as a JSON object.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub next: Option<serde_json::Value>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct ForwardMemo {
+    forward: Ics20ForwardMemo,
+}
+
+impl Ics20ForwardMemo {
+    /// Parses the forward requested by an ICS-20 memo.
+    ///
+    /// Returns `None` if the memo does not request a forward, and an error if it requests one but
+    /// is malformed.
+    pub fn from_memo(memo: &str) -> Result<Option<Self>> {
+        let Ok(serde_json::Value::Object(fields)) = serde_json::from_str(memo) else {
+            return Ok(None);
+        };
+        if !fields.contains_key("forward") {
+            return Ok(None);
+        }
+        let memo: ForwardMemo = serde_json::from_value(serde_json::Value::Object(fields))
+            .context("malformed forward memo")?;
+        Ok(Some(memo.forward))
+    }
+
+    /// Encodes the forward as an ICS-20 memo.
+    pub fn to_memo(&self) -> String {
+        serde_json::to_string(&ForwardMemo {
+            forward: self.clone(),
+        })
+        .expect("forward memo is serializable")
+    }
+
+    /// The port to forward the transfer over.
+    pub fn port(&self) -> Result<PortId> {
+        PortId::from_str(&self.port).map_err(|e| anyhow!("invalid port in forward memo: {e}"))
+    }
+
+    /// The channel to forward the transfer over.
+    pub fn channel(&self) -> Result<ChannelId> {
+        ChannelId::from_str(&self.channel)
+            .map_err(|e| anyhow!("invalid channel in forward memo: {e}"))
+    }
+
+    /// The timeout of the forwarded transfer.
+    pub fn timeout(&self) -> Result<Duration> {
+        match &self.timeout {
+            None => Ok(DEFAULT_FORWARD_TIMEOUT),
+            Some(serde_json::Value::Number(nanos)) => nanos
+                .as_u64()
+                .map(Duration::from_nanos)
+                .ok_or_else(|| anyhow!("invalid timeout in forward memo: {nanos}")),
+            Some(serde_json::Value::String(duration)) => parse_duration(duration),
+            Some(timeout) => bail!("invalid timeout in forward memo: {timeout}"),
+        }
+    }
+
+    /// The memo to pass on with the forwarded transfer.
+    pub fn next_memo(&self) -> String {
+        match &self.next {
+            None => String::new(),
+            Some(serde_json::Value::String(memo)) => memo.clone(),
+            Some(memo) => memo.to_string(),
+        }
+    }
+}
+
+/// Parses a duration such as `"90s"`, `"10m"` or `"1h"`.
+fn parse_duration(duration: &str) -> Result<Duration> {
+    let split = duration
+        .find(|c: char| !c.is_ascii_digit())
+        .ok_or_else(|| anyhow!("missing unit in forward memo timeout {duration:?}"))?;
+    let (value, unit) = duration.split_at(split);
+    let value: u64 = value
+        .parse()
+        .with_context(|| format!("invalid forward memo timeout {duration:?}"))?;
+    let unit = match unit {
+        "ns" => Duration::from_nanos(1),
+        "ms" => Duration::from_millis(1),
+        "s" => Duration::from_secs(1),
+        "m" => Duration::from_secs(60),
+        "h" => Duration::from_secs(60 * 60),
+        _ => bail!("invalid unit in forward memo timeout {duration:?}"),
+    };
+    unit.checked_mul(
+        value
+            .try_into()
+            .with_context(|| format!("forward memo timeout {duration:?} is too long"))?,
+    )
+    .ok_or_else(|| anyhow!("forward memo timeout {duration:?} is too long"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_forward_memo() {
+        let memo = r#"{"forward": {"receiver": "osmo1abc", "port": "transfer", "channel": "channel-1", "timeout": "2m", "next": {"forward": {"receiver": "cosmos1abc", "port": "transfer", "channel": "channel-7"}}}}"#;
+        let forward = Ics20ForwardMemo::from_memo(memo)
+            .expect("memo is well-formed")
+            .expect("memo requests a forward");
+        assert_eq!(forward.receiver, "osmo1abc");
+        assert_eq!(forward.port().expect("port is valid"), PortId::transfer());
+        assert_eq!(
+            forward.channel().expect("channel is valid"),
+            ChannelId::new(1)
+        );
+        assert_eq!(
+            forward.timeout().expect("timeout is valid"),
+            Duration::from_secs(120)
+        );
+
+        let next = Ics20ForwardMemo::from_memo(&forward.next_memo())
+            .expect("next memo is well-formed")
+            .expect("next memo requests a forward");
+        assert_eq!(next.channel().expect("channel is valid"), ChannelId::new(7));
+        assert_eq!(
+            next.timeout().expect("timeout is valid"),
+            DEFAULT_FORWARD_TIMEOUT
+        );
+        assert_eq!(next.next_memo(), "");
+
+        assert_eq!(
+            Ics20ForwardMemo::from_memo(&forward.to_memo()).expect("memo is well-formed"),
+            Some(forward)
+        );
+    }
+
+    #[test]
+    fn parses_timeouts() {
+        for (timeout, expected) in [
+            ("600000000000", Duration::from_secs(600)),
+            (r#""90s""#, Duration::from_secs(90)),
+            (r#""1h""#, Duration::from_secs(3600)),
+        ] {
+            let memo = format!(
+                r#"{{"forward": {{"receiver": "osmo1abc", "port": "transfer", "channel": "channel-1", "timeout": {timeout}}}}}"#
+            );
+            let forward = Ics20ForwardMemo::from_memo(&memo)
+                .expect("memo is well-formed")
+                .expect("memo requests a forward");
+            assert_eq!(forward.timeout().expect("timeout is valid"), expected);
+        }
+        for timeout in ["10", "10d", "m", "-1s"] {
+            assert!(parse_duration(timeout).is_err());
+        }
+    }
+
+    #[test]
+    fn ignores_other_memos() {
+        for memo in ["", "hello", r#"{"penumbra": {"swap": {}}}"#] {
+            assert_eq!(
+                Ics20ForwardMemo::from_memo(memo).expect("memo is ignored"),
+                None
+            );
+        }
+        assert!(Ics20ForwardMemo::from_memo(r#"{"forward": {}}"#).is_err());
+    }
+}
@@ -0,0 +1,181 @@
+//! Swaps requested by the memo of an inbound ICS-20 transfer.
+//!
+//! A transfer into Penumbra can ask for its tokens to be swapped into another asset on receipt,
+//! by carrying a memo of the form
+//!
+//! ```json
+//! {"penumbra": {"swap": {"asset_out": "upenumbra", "min_output": "1000000"}}}
+//! ```
+//!
+//! where `asset_out` is the base denom or asset ID of the asset to swap into, and `min_output` is
+//! the least amount of it, in base units, that the receiver will accept. Rather than being minted
+//! to the receiver, the transferred tokens are added to the batch swap for their trading pair in
+//! the block the transfer is received in, and the swap's outputs are minted to the receiver at the
+//! end of the block. The transfer is only acknowledged then: if the batch would not produce
+//! `min_output`, or the swap can't be executed, it is acknowledged with an error, so that the
+//! chain it came from refunds the sender.
+
+use std::str::FromStr;
+
+use anyhow::{anyhow, Context, Result};
+use ibc_types::core::channel::Packet;
+use penumbra_asset::{asset, Value};
+use penumbra_keys::Address;
+use penumbra_num::Amount;
+use penumbra_sct::CommitmentSource;
+use serde::{Deserialize, Serialize};
+
+/// The swap requested by an ICS-20 memo.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct Ics20SwapMemo {
+    /// The base denom or asset ID of the asset to swap into.
+    pub asset_out: String,
+    /// The minimum output of the swap, in base units of `asset_out`.
+    pub min_output: String,
+}
+
+#[derive(Serialize, Deserialize)]
+struct PenumbraMemo {
+    penumbra: PenumbraMemoInner,
+}
+
+#[derive(Serialize, Deserialize)]
+struct PenumbraMemoInner {
+    swap: Ics20SwapMemo,
+}
+
+impl Ics20SwapMemo {
+    /// Parses the swap requested by an ICS-20 memo.
+    ///
+    /// Returns `None` if the memo does not request a swap, so that memos meant for other
+    /// middleware pass through untouched, and an error if it requests one but is malformed.
+    pub fn from_memo(memo: &str) -> Result<Option<Self>> {
+        let Ok(serde_json::Value::Object(fields)) = serde_json::from_str(memo) else {
+            return Ok(None);
+        };
+        if !fields.contains_key("penumbra") {
+            return Ok(None);
+        }
+        let memo: PenumbraMemo = serde_json::from_value(serde_json::Value::Object(fields))
+            .context("malformed penumbra swap memo")?;
+        Ok(Some(memo.penumbra.swap))
+    }
+
+    /// Encodes the swap as an ICS-20 memo.
+    pub fn to_memo(&self) -> String {
+        serde_json::to_string(&PenumbraMemo {
+            penumbra: PenumbraMemoInner { swap: self.clone() },
+        })
+        .expect("swap memo is serializable")
+    }
+
+    /// The ID of the asset to swap into.
+    pub fn asset_out(&self) -> Result<asset::Id> {
+        if let Ok(id) = asset::Id::from_str(&self.asset_out) {
+            return Ok(id);
+        }
+        let denom: asset::Metadata = self
+            .asset_out
+            .as_str()
+            .try_into()
+            .context("invalid output asset in swap memo")?;
+        Ok(denom.id())
+    }
+
+    /// The minimum output of the swap.
+    pub fn min_output(&self) -> Result<Amount> {
+        let amount: u128 = self
+            .min_output
+            .parse()
+            .map_err(|e| anyhow!("invalid minimum output in swap memo: {e}"))?;
+        Ok(amount.into())
+    }
+}
+
+/// An inbound ICS-20 transfer waiting to be swapped at the end of the block.
+#[derive(Debug, Clone)]
+pub struct Ics20Swap {
+    /// The address to mint the swap's outputs to.
+    pub receiver: Address,
+    /// The transferred tokens.
+    pub input: Value,
+    /// The asset to swap into.
+    pub asset_out: asset::Id,
+    /// The least amount of `asset_out` the receiver will accept.
+    pub min_output: Amount,
+    /// The source to record for the notes minted to the receiver.
+    pub source: CommitmentSource,
+    /// The inbound packet, acknowledged once the swap has been executed or refunded.
+    pub packet: Packet,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_swap_memo() {
+        let memo = r#"{"penumbra": {"swap": {"asset_out": "upenumbra", "min_output": "1000"}}}"#;
+        let swap = Ics20SwapMemo::from_memo(memo)
+            .expect("memo is well-formed")
+            .expect("memo requests a swap");
+        assert_eq!(
+            swap.asset_out().expect("asset is valid"),
+            *penumbra_asset::STAKING_TOKEN_ASSET_ID
+        );
+        assert_eq!(swap.min_output().expect("amount is valid"), 1000u64.into());
+        assert_eq!(
+            Ics20SwapMemo::from_memo(&swap.to_memo()).expect("memo is well-formed"),
+            Some(swap)
+        );
+    }
+
+    #[test]
+    fn ignores_other_memos() {
+        for memo in ["", "hello", r#"{"forward": {"receiver": "osmo1..."}}"#] {
+            assert_eq!(
+                Ics20SwapMemo::from_memo(memo).expect("memo is ignored"),
+                None
+            );
+        }
+        assert!(Ics20SwapMemo::from_memo(r#"{"penumbra": {"swap": {}}}"#).is_err());
+    }
+
+    #[test]
+    fn parses_asset_id_as_asset_out() {
+        let id = *penumbra_asset::STAKING_TOKEN_ASSET_ID;
+        let swap = Ics20SwapMemo {
+            asset_out: id.to_string(),
+            min_output: "1".to_string(),
+        };
+        let swap = Ics20SwapMemo::from_memo(&swap.to_memo())
+            .expect("memo is well-formed")
+            .expect("memo requests a swap");
+        assert_eq!(swap.asset_out().expect("asset is valid"), id);
+    }
+
+    #[test]
+    fn rejects_invalid_swaps() {
+        for min_output in ["", "-1", "1.5", "lots"] {
+            let swap = Ics20SwapMemo {
+                asset_out: "upenumbra".to_string(),
+                min_output: min_output.to_string(),
+            };
+            assert!(
+                swap.min_output().is_err(),
+                "{min_output:?} is not an amount"
+            );
+        }
+        // display denoms are not base denoms, and any other string is.
+        for asset_out in ["penumbra", "mpenumbra"] {
+            let swap = Ics20SwapMemo {
+                asset_out: asset_out.to_string(),
+                min_output: "1".to_string(),
+            };
+            assert!(
+                swap.asset_out().is_err(),
+                "{asset_out:?} is not a base denom"
+            );
+        }
+    }
+}
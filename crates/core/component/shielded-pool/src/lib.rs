@@ -6,6 +6,12 @@ pub mod component;
 pub mod ics20_withdrawal;
 pub use ics20_withdrawal::Ics20Withdrawal;
 
+pub mod ics20_swap;
+pub use ics20_swap::{Ics20Swap, Ics20SwapMemo};
+
+pub mod ics20_forward;
+pub use ics20_forward::Ics20ForwardMemo;
+
 pub mod event;
 pub mod fmd;
 pub mod genesis;
@@ -21,6 +21,23 @@ pub fn pending_rolled_up_payloads() -> &'static str {
     "shielded_pool/pending_rolled_up_payloads"
 }
 
+// State key used to temporarily store the inbound ICS-20 transfers to be swapped at the end of the
+// block
+pub fn pending_ics20_swaps() -> &'static str {
+    "shielded_pool/pending_ics20_swaps"
+}
+
+// State key used to store the inbound ICS-20 transfers that were forwarded to another chain, by the
+// channel and sequence of the forwarded packet, until it is acknowledged or times out
+pub mod ics20_forwards {
+    use ibc_types::core::channel::ChannelId;
+    use std::string::String;
+
+    pub fn by_packet(channel_id: &ChannelId, sequence: u64) -> String {
+        format!("shielded_pool/ics20_forwards/{channel_id}/{sequence}")
+    }
+}
+
 pub fn shielded_pool_params() -> &'static str {
     "shielded_pool/params"
 }
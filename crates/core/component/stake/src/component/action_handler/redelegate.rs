@@ -0,0 +1,190 @@
+use anyhow::{ensure, Result};
+use async_trait::async_trait;
+use cnidarium::StateWrite;
+use cnidarium_component::ActionHandler;
+use penumbra_num::Amount;
+use penumbra_proto::{DomainType, StateWriteProto};
+use penumbra_sct::component::clock::EpochRead;
+
+use crate::{
+    component::{stake::RateDataWrite as _, validator_handler::ValidatorDataRead, SlashingData},
+    event,
+    validator::{BondingState, State::*},
+    Delegate, Redelegate, StateReadExt as _, StateWriteExt as _, Undelegate,
+};
+
+#[async_trait]
+impl ActionHandler for Redelegate {
+    type CheckStatelessContext = ();
+    async fn check_stateless(&self, _context: ()) -> Result<()> {
+        ensure!(
+            self.source_validator != self.destination_validator,
+            "cannot redelegate from validator {} to itself",
+            self.source_validator,
+        );
+        Ok(())
+    }
+
+    async fn check_and_execute<S: StateWrite>(&self, mut state: S) -> Result<()> {
+        let r = self;
+
+        // We check if the rate data is for the current epoch to provide a helpful
+        // error message if there is a mismatch.
+        let current_epoch = state.get_current_epoch().await?;
+        ensure!(
+            r.epoch_index == current_epoch.index,
+            "redelegation was prepared for epoch {} but the current epoch is {}",
+            r.epoch_index,
+            current_epoch.index
+        );
+
+        // A redelegation is an undelegation from the source validator followed by a
+        // delegation to the destination validator, so we enforce the rounding direction
+        // of each: the unbonded amount is computed from the source delegation amount,
+        // and the destination delegation amount is computed from the unbonded amount.
+        let source_rate = state
+            .get_validator_rate(&r.source_validator)
+            .await?
+            .ok_or_else(|| anyhow::anyhow!("unknown validator identity {}", r.source_validator))?;
+        let expected_unbonded_amount = source_rate.unbonded_amount(r.source_delegation_amount);
+        ensure!(
+            expected_unbonded_amount == r.unbonded_amount,
+            "given {} source delegation tokens, expected {} unbonded stake but description produces {}",
+            r.source_delegation_amount,
+            expected_unbonded_amount,
+            r.unbonded_amount,
+        );
+
+        let destination_rate = state
+            .get_validator_rate(&r.destination_validator)
+            .await?
+            .ok_or_else(|| {
+                anyhow::anyhow!("unknown validator identity {}", r.destination_validator)
+            })?;
+        let expected_delegation_amount = destination_rate.delegation_amount(r.unbonded_amount);
+        ensure!(
+            expected_delegation_amount == r.destination_delegation_amount,
+            "given {} unbonded stake, expected {} destination delegation tokens but description produces {}",
+            r.unbonded_amount,
+            expected_delegation_amount,
+            r.destination_delegation_amount,
+        );
+
+        // Redelegating skips the unbonding delay, so we must make sure the stake
+        // is not escaping a penalty owed to the source validator: the source must
+        // not be jailed, tombstoned, disabled or unbonding, and must not have
+        // been penalized in the current epoch. Penalties recorded later are
+        // covered by the redelegation's liability, see below.
+        let source_state = state
+            .get_validator_state(&r.source_validator)
+            .await?
+            .ok_or_else(|| anyhow::anyhow!("missing state for validator"))?;
+        ensure!(
+            matches!(source_state, Defined | Inactive | Active),
+            "redelegations are only allowed from active or inactive validators, but {} is in state {:?}",
+            r.source_validator,
+            source_state,
+        );
+
+        let source_bonding_state = state
+            .get_validator_bonding_state(&r.source_validator)
+            .await
+            .ok_or_else(|| anyhow::anyhow!("missing bonding state for validator"))?;
+        ensure!(
+            !matches!(source_bonding_state, BondingState::Unbonding { .. }),
+            "redelegations are not allowed from unbonding validators, but {} is {:?}",
+            r.source_validator,
+            source_bonding_state,
+        );
+
+        ensure!(
+            state
+                .get_penalty_in_epoch(&r.source_validator, current_epoch.index)
+                .await
+                .is_none(),
+            "redelegations are not allowed from validator {} while it has a pending penalty",
+            r.source_validator,
+        );
+
+        // The destination validator must accept delegations, with the same rules
+        // as for a regular delegation.
+        let destination = state
+            .get_validator_definition(&r.destination_validator)
+            .await?
+            .ok_or_else(|| anyhow::anyhow!("missing definition for validator"))?;
+        let destination_state = state
+            .get_validator_state(&r.destination_validator)
+            .await?
+            .ok_or_else(|| anyhow::anyhow!("missing state for validator"))?;
+
+        ensure!(
+            destination.enabled,
+            "redelegations are only allowed to enabled validators, but {} is disabled",
+            r.destination_validator,
+        );
+        ensure!(
+            matches!(destination_state, Defined | Inactive | Active),
+            "redelegations are only allowed to active or inactive validators, but {} is in state {:?}",
+            r.destination_validator,
+            destination_state,
+        );
+
+        if destination_state == Defined {
+            // See the `Delegate` action handler: the first delegation to a `Defined`
+            // validator must meet the minimum self-delegation requirement.
+            let min_stake = state.get_stake_params().await?.min_validator_stake;
+            let destination_pool_size = state
+                .get_validator_pool_size(&r.destination_validator)
+                .await
+                .unwrap_or_else(Amount::zero);
+
+            if destination_pool_size == Amount::zero() {
+                ensure!(
+                    r.unbonded_amount >= min_stake,
+                    "first delegation to a `Defined` validator must be at least {min_stake}"
+                );
+            }
+        }
+
+        /* ----- execution ------ */
+
+        // We queue the redelegation as an undelegation from the source validator and
+        // a delegation to the destination validator, so that both delegation pools
+        // are adjusted at the epoch boundary. No unbonding tokens are produced.
+        tracing::debug!(?self, "queuing redelegation for next epoch");
+        state.push_undelegation(Undelegate {
+            validator_identity: r.source_validator,
+            from_epoch: current_epoch,
+            unbonded_amount: r.unbonded_amount,
+            delegation_amount: r.source_delegation_amount,
+        });
+        state.push_delegation(Delegate {
+            validator_identity: r.destination_validator,
+            epoch_index: r.epoch_index,
+            unbonded_amount: r.unbonded_amount,
+            delegation_amount: r.destination_delegation_amount,
+        });
+
+        // The redelegated stake remains liable for the penalties of the source validator
+        // until it would have finished unbonding, and the destination pool pays for them
+        // at the end of each epoch in which the source is penalized. If the source pool
+        // is unbonded, an undelegation could be claimed right away, so there is no liability.
+        let current_height = state.get_block_height().await?;
+        if let Some(unbonds_at_height) = state
+            .compute_unbonding_height(&r.source_validator, current_height)
+            .await?
+        {
+            state
+                .record_redelegation_liability(
+                    &r.source_validator,
+                    &r.destination_validator,
+                    unbonds_at_height,
+                    r.unbonded_amount,
+                )
+                .await;
+        }
+
+        state.record_proto(event::EventRedelegate::from(self).to_proto());
+        Ok(())
+    }
+}
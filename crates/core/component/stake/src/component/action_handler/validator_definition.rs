@@ -1,16 +1,17 @@
 use crate::{
     component::{
         action_handler::ActionHandler, validator_handler::ValidatorDataRead,
-        validator_handler::ValidatorManager,
+        validator_handler::ValidatorDataWrite, validator_handler::ValidatorManager,
     },
     rate::RateData,
-    validator,
+    validator, PendingFundingStreams, StateReadExt as _,
 };
 use anyhow::{ensure, Context, Result};
 use async_trait::async_trait;
 use cnidarium::StateWrite;
 use decaf377_rdsa::VerificationKey;
 use penumbra_proto::DomainType;
+use penumbra_sct::component::clock::EpochRead;
 
 #[async_trait]
 impl ActionHandler for validator::Definition {
@@ -116,12 +117,62 @@ impl ActionHandler for validator::Definition {
             );
         }
 
+        // Check that an increase of the validator's commission stays within the
+        // per-epoch limit. The increase itself is deferred until the notice period
+        // has elapsed, see below.
+        let stake_params = state.get_stake_params().await?;
+        let commission_increase = prev_definition.as_ref().and_then(|prev_validator| {
+            new_validator
+                .funding_streams
+                .total_rate_bps()
+                .checked_sub(prev_validator.funding_streams.total_rate_bps())
+                .filter(|increase| *increase > 0)
+        });
+        if let Some(increase) = commission_increase {
+            let max_increase = stake_params.max_commission_increase_bps;
+            ensure!(
+                max_increase == 0 || increase <= max_increase,
+                "validator commission can increase by at most {}bps per epoch (given an increase of {}bps)",
+                max_increase,
+                increase,
+            );
+        }
+
         /* ------------ execution ----------- */
         // If the validator is already defined, we update the definition.
         // Otherwise, we add the new validator and "prime" its state.
-        if prev_definition.is_some() {
+        if let Some(prev_validator) = &prev_definition {
+            let mut new_validator = new_validator.clone();
+            let id = new_validator.identity_key;
+
+            if commission_increase.is_some() {
+                // Keep the current funding streams for now, and schedule the new ones
+                // so that delegators have time to react. Re-uploading the same increase,
+                // e.g. to update other fields, does not restart the notice period.
+                let pending = match state.get_pending_funding_streams(&id).await? {
+                    Some(pending) if pending.funding_streams == new_validator.funding_streams => {
+                        pending
+                    }
+                    _ => {
+                        let current_epoch = state.get_current_epoch().await?;
+                        PendingFundingStreams {
+                            funding_streams: new_validator.funding_streams.clone(),
+                            effective_epoch_index: current_epoch.index
+                                + stake_params.commission_increase_notice_epochs
+                                + 1,
+                        }
+                    }
+                };
+                tracing::debug!(validator_identity = %id, ?pending, "scheduling commission increase");
+                state.set_pending_funding_streams(&id, pending);
+                new_validator.funding_streams = prev_validator.funding_streams.clone();
+            } else {
+                // Commission decreases take effect immediately, and supersede any pending increase.
+                state.clear_pending_funding_streams(&id);
+            }
+
             state
-                .update_validator_definition(new_validator.clone())
+                .update_validator_definition(new_validator)
                 .await
                 .context(
                     "should be able to update validator during validator definition execution",
@@ -105,13 +105,32 @@ pub trait EpochHandler: StateWriteExt + ConsensusIndexRead {
             .try_collect::<BTreeSet<IdentityKey>>()
             .await?;
 
-        let validators_to_process = validators_with_delegation_changes
+        let mut validators_to_process = validators_with_delegation_changes
             .union(&consensus_set)
+            .cloned()
             .collect::<BTreeSet<_>>();
 
+        // Stake redelegated away from a validator remains liable for its penalties until the
+        // end of the unbonding delay, so we collect the losses owed by the destination pools,
+        // which must be processed as well.
+        let mut redelegation_losses = BTreeMap::<IdentityKey, Amount>::new();
+        for validator_identity in validators_to_process.iter() {
+            for (destination, loss) in self
+                .apply_redelegation_liabilities(validator_identity, epoch_to_end.index, end_height)
+                .await?
+            {
+                let total_loss = redelegation_losses
+                    .entry(destination)
+                    .or_default()
+                    .saturating_add(&loss);
+                redelegation_losses.insert(destination, total_loss);
+            }
+        }
+        validators_to_process.extend(redelegation_losses.keys().cloned());
+
         let mut funding_queue: Vec<(IdentityKey, FundingStreams, Amount)> = Vec::new();
 
-        for validator_identity in validators_to_process {
+        for validator_identity in validators_to_process.iter() {
             let total_delegations = delegations_by_validator
                 .remove(validator_identity)
                 .unwrap_or_else(Amount::zero);
@@ -120,6 +139,10 @@ pub trait EpochHandler: StateWriteExt + ConsensusIndexRead {
                 .remove(validator_identity)
                 .unwrap_or_else(Amount::zero);
 
+            let redelegation_loss = redelegation_losses
+                .remove(validator_identity)
+                .unwrap_or_else(Amount::zero);
+
             if let Some(rewards) = self
                 .process_validator(
                     validator_identity,
@@ -127,6 +150,7 @@ pub trait EpochHandler: StateWriteExt + ConsensusIndexRead {
                     next_base_rate.clone(),
                     total_delegations,
                     total_undelegations,
+                    redelegation_loss,
                 )
                 .await
                 .map_err(|e| {
@@ -148,11 +172,17 @@ pub trait EpochHandler: StateWriteExt + ConsensusIndexRead {
         // This should coincide with a profiling/performance effort on the epoch-handler.
         assert!(delegations_by_validator.is_empty());
         assert!(undelegations_by_validator.is_empty());
+        assert!(redelegation_losses.is_empty());
 
         // We have collected the funding streams for all validators, so we can now
         // record them for the funding component to process.
         self.queue_staking_rewards(funding_queue);
 
+        // Commission increases whose notice period has elapsed take effect for the next
+        // epoch, now that the rewards of the ending epoch were computed with the old ones.
+        self.apply_pending_funding_streams(epoch_to_end.index + 1)
+            .await?;
+
         // Now that the consensus set voting power has been calculated, we can select the
         // top N validators to be active for the next epoch.
         self.set_active_and_inactive_validators().await?;
@@ -166,6 +196,7 @@ pub trait EpochHandler: StateWriteExt + ConsensusIndexRead {
         next_base_rate: BaseRateData,
         total_delegations: Amount,
         total_undelegations: Amount,
+        redelegation_loss: Amount,
     ) -> Result<Option<(IdentityKey, FundingStreams, Amount)>> {
         let validator = self.get_validator_definition(&validator_identity).await?.ok_or_else(|| {
             anyhow::anyhow!("validator (identity={}) is in consensus index but its definition was not found in the JMT", &validator_identity)
@@ -195,6 +226,31 @@ pub trait EpochHandler: StateWriteExt + ConsensusIndexRead {
             .unwrap_or(Penalty::from_percent(0));
         let prev_validator_rate_with_penalty = prev_validator_rate.slash(penalty);
 
+        // Then, make the pool cover the penalties owed by the stake redelegated into it. The
+        // loss is shared by the delegation tokens which remain in the pool, including the ones
+        // delegated during the epoch we are ending.
+        let prev_validator_rate_with_penalty = if redelegation_loss > Amount::zero() {
+            let liable_delegation_tokens = self
+                .get_validator_pool_size(validator_identity)
+                .await
+                .unwrap_or_else(Amount::zero)
+                .saturating_add(&total_delegations)
+                .saturating_sub(&total_undelegations);
+            let liable_stake =
+                prev_validator_rate_with_penalty.unbonded_amount(liable_delegation_tokens);
+            let redelegation_penalty = Penalty::covering_loss(redelegation_loss, liable_stake);
+            tracing::debug!(
+                validator = ?validator.identity_key,
+                ?redelegation_loss,
+                ?liable_stake,
+                ?redelegation_penalty,
+                "applying penalties owed by redelegated stake"
+            );
+            prev_validator_rate_with_penalty.slash(redelegation_penalty)
+        } else {
+            prev_validator_rate_with_penalty
+        };
+
         self.set_prev_validator_rate(
             &validator.identity_key,
             prev_validator_rate_with_penalty.clone(),
@@ -260,6 +316,11 @@ pub trait EpochHandler: StateWriteExt + ConsensusIndexRead {
         // Update the state of the validator within the validator set
         // with the newly starting epoch's calculated voting rate and power.
         self.set_validator_rate_data(&validator.identity_key, next_validator_rate.clone());
+        self.record_validator_rate_history(
+            &validator.identity_key,
+            epoch_to_end.index + 1,
+            next_validator_rate.clone(),
+        );
         self.set_validator_power(&validator.identity_key, voting_power)?;
 
         // The epoch is ending, so we check if this validator was active and if so
@@ -315,6 +376,45 @@ pub trait EpochHandler: StateWriteExt + ConsensusIndexRead {
         Ok(reward_queue_entry)
     }
 
+    /// Applies the penalty recorded against the `source` validator in the epoch we are ending to
+    /// the stake redelegated away from it, returning the loss owed by each destination pool.
+    ///
+    /// Like an undelegation, a redelegation is liable for the penalties of its source over the
+    /// epochs which end before its unbonding height. Liabilities which end with this epoch
+    /// are removed, and the others are reduced by the penalty they just paid.
+    async fn apply_redelegation_liabilities(
+        &mut self,
+        source: &IdentityKey,
+        epoch_index: u64,
+        end_height: u64,
+    ) -> Result<Vec<(IdentityKey, Amount)>> {
+        let penalty = self.get_penalty_in_epoch(source, epoch_index).await;
+        let mut losses = Vec::new();
+
+        for (unbonds_at_height, destination, amount) in
+            self.redelegation_liabilities(source).await?
+        {
+            let key = state_key::redelegation_liability::for_destination(
+                source,
+                unbonds_at_height,
+                &destination,
+            );
+            if unbonds_at_height <= end_height {
+                self.delete(key);
+                continue;
+            }
+
+            let Some(penalty) = penalty else {
+                continue;
+            };
+            let remaining = penalty.apply_to_amount(amount);
+            losses.push((destination, amount.saturating_sub(&remaining)));
+            self.put(key, remaining);
+        }
+
+        Ok(losses)
+    }
+
     /// Compute and return the chain base rate ("L1BOR").
     async fn process_chain_base_rate(&mut self) -> Result<BaseRateData> {
         // We are transitioning to the next epoch, so the "current" base rate in
@@ -1,14 +1,17 @@
 use std::pin::Pin;
 
-use cnidarium::Storage;
+use cnidarium::{StateRead, Storage};
 use futures::StreamExt;
 use penumbra_proto::{
     core::component::stake::v1::{
-        query_service_server::QueryService, CurrentValidatorRateRequest,
+        query_service_server::QueryService, validator_rate_history_response::EpochRateData,
+        CurrentConsensusKeysRequest, CurrentConsensusKeysResponse, CurrentValidatorRateRequest,
         CurrentValidatorRateResponse, GetValidatorInfoRequest, GetValidatorInfoResponse,
+        ValidatorCommissionScheduleRequest, ValidatorCommissionScheduleResponse,
         ValidatorInfoRequest, ValidatorInfoResponse, ValidatorPenaltyRequest,
-        ValidatorPenaltyResponse, ValidatorStatusRequest, ValidatorStatusResponse,
-        ValidatorUptimeRequest, ValidatorUptimeResponse,
+        ValidatorPenaltyResponse, ValidatorRateHistoryRequest, ValidatorRateHistoryResponse,
+        ValidatorStatusRequest, ValidatorStatusResponse, ValidatorUptimeRequest,
+        ValidatorUptimeResponse,
     },
     DomainType,
 };
@@ -17,7 +20,11 @@ use tonic::Status;
 use tracing::{error_span, instrument, Instrument, Span};
 
 use super::{validator_handler::ValidatorDataRead, ConsensusIndexRead, SlashingData};
-use crate::validator::{Info, State};
+use crate::{
+    state_key,
+    validator::{Info, State},
+    CurrentConsensusKeys,
+};
 
 // TODO: Hide this and only expose a Router?
 pub struct Server {
@@ -209,6 +216,42 @@ impl QueryService for Server {
         }
     }
 
+    #[instrument(skip(self, request))]
+    async fn validator_rate_history(
+        &self,
+        request: tonic::Request<ValidatorRateHistoryRequest>,
+    ) -> Result<tonic::Response<ValidatorRateHistoryResponse>, Status> {
+        let state = self.storage.latest_snapshot();
+        let ValidatorRateHistoryRequest {
+            identity_key,
+            start_epoch_index,
+            limit,
+        } = request.into_inner();
+        let identity_key = identity_key
+            .ok_or_else(|| tonic::Status::invalid_argument("empty message"))?
+            .try_into()
+            .map_err(|_| tonic::Status::invalid_argument("invalid identity key"))?;
+        let limit = match limit {
+            0 => usize::MAX,
+            limit => limit.try_into().unwrap_or(usize::MAX),
+        };
+
+        let rate_data = state
+            .get_validator_rate_history(&identity_key, start_epoch_index, limit)
+            .await
+            .map_err(|e| tonic::Status::internal(e.to_string()))?;
+
+        Ok(tonic::Response::new(ValidatorRateHistoryResponse {
+            history: rate_data
+                .into_iter()
+                .map(|(epoch_index, rate_data)| EpochRateData {
+                    epoch_index,
+                    rate_data: Some(rate_data.into()),
+                })
+                .collect(),
+        }))
+    }
+
     #[instrument(skip(self, request))]
     async fn validator_uptime(
         &self,
@@ -234,4 +277,43 @@ impl QueryService for Server {
             None => Err(Status::not_found("validator uptime not found")),
         }
     }
+
+    #[instrument(skip(self, request))]
+    async fn validator_commission_schedule(
+        &self,
+        request: tonic::Request<ValidatorCommissionScheduleRequest>,
+    ) -> Result<tonic::Response<ValidatorCommissionScheduleResponse>, Status> {
+        let state = self.storage.latest_snapshot();
+        let identity_key = request
+            .into_inner()
+            .identity_key
+            .ok_or_else(|| tonic::Status::invalid_argument("empty message"))?
+            .try_into()
+            .map_err(|_| tonic::Status::invalid_argument("invalid identity key"))?;
+
+        let pending = state
+            .get_pending_funding_streams(&identity_key)
+            .await
+            .map_err(|e| tonic::Status::internal(e.to_string()))?;
+
+        Ok(tonic::Response::new(ValidatorCommissionScheduleResponse {
+            pending: pending.map(Into::into),
+        }))
+    }
+
+    #[instrument(skip(self, _request))]
+    async fn current_consensus_keys(
+        &self,
+        _request: tonic::Request<CurrentConsensusKeysRequest>,
+    ) -> Result<tonic::Response<CurrentConsensusKeysResponse>, Status> {
+        let state = self.storage.latest_snapshot();
+        let current_consensus_keys: Option<CurrentConsensusKeys> = state
+            .get(state_key::consensus_update::consensus_keys())
+            .await
+            .map_err(|e| tonic::Status::internal(e.to_string()))?;
+
+        Ok(tonic::Response::new(CurrentConsensusKeysResponse {
+            current_consensus_keys: current_consensus_keys.map(Into::into),
+        }))
+    }
 }
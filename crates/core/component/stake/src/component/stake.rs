@@ -386,6 +386,31 @@ pub trait SlashingData: StateRead {
         let compounded_penalty = Self::compute_compounded_penalty(range);
         Ok(compounded_penalty)
     }
+
+    /// Returns the stake redelegated away from the `source` validator which is still liable
+    /// for its penalties, as `(unbonds_at_height, destination, unbonded_amount)` entries,
+    /// ordered by the height at which their liability ends.
+    async fn redelegation_liabilities(
+        &self,
+        source: &IdentityKey,
+    ) -> Result<Vec<(u64, IdentityKey, Amount)>> {
+        let prefix = state_key::redelegation_liability::prefix(source);
+        let prefix_len = prefix.len();
+        self.prefix::<Amount>(&prefix)
+            .map(|entry| -> Result<(u64, IdentityKey, Amount)> {
+                let (key, amount) = entry?;
+                let (unbonds_at_height, destination) = key[prefix_len..]
+                    .split_once('/')
+                    .ok_or_else(|| anyhow!("malformed redelegation liability key {key}"))?;
+                Ok((
+                    unbonds_at_height.parse()?,
+                    IdentityKey::from_str(destination)?,
+                    amount,
+                ))
+            })
+            .try_collect()
+            .await
+    }
 }
 
 impl<T: StateRead + ?Sized> SlashingData for T {}
@@ -489,6 +514,28 @@ pub(crate) trait RateDataWrite: StateWrite {
         );
     }
 
+    /// Records that `amount` of unbonded stake was redelegated from `source` to `destination`,
+    /// so that it remains liable for the penalties of `source` until `unbonds_at_height`.
+    async fn record_redelegation_liability(
+        &mut self,
+        source: &IdentityKey,
+        destination: &IdentityKey,
+        unbonds_at_height: u64,
+        amount: Amount,
+    ) {
+        let key = state_key::redelegation_liability::for_destination(
+            source,
+            unbonds_at_height,
+            destination,
+        );
+        let liability = self
+            .get::<Amount>(&key)
+            .await
+            .expect("no deserialization error expected")
+            .unwrap_or_else(Amount::zero);
+        self.put(key, liability.saturating_add(&amount));
+    }
+
     #[tracing::instrument(
         level = "trace",
         skip_all,
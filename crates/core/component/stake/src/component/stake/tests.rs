@@ -5,7 +5,11 @@ use rand_core::OsRng;
 use tendermint::PublicKey;
 
 use crate::{
-    component::{stake::address::validator_address, validator_handler::ValidatorDataRead},
+    component::{
+        stake::address::validator_address,
+        validator_handler::{ValidatorDataRead, ValidatorDataWrite},
+    },
+    rate::RateData,
     IdentityKey, StateWriteExt,
 };
 
@@ -103,3 +107,50 @@ async fn test_persistent_identity_by_ck() -> anyhow::Result<()> {
 
     Ok(())
 }
+
+#[tokio::test]
+/// Test that the rate data recorded at each epoch boundary can be read back by epoch, for each
+/// validator separately.
+async fn test_validator_rate_history() -> anyhow::Result<()> {
+    let storage = TempStorage::new().await?;
+    let mut state = StateDelta::new(storage.latest_snapshot());
+
+    let validator = IdentityKey(VerificationKey::from(SigningKey::<SpendAuth>::new(OsRng)).into());
+    let other = IdentityKey(VerificationKey::from(SigningKey::<SpendAuth>::new(OsRng)).into());
+    let rate_data = |identity_key: IdentityKey, exchange_rate: u64| RateData {
+        identity_key,
+        validator_reward_rate: 1_0000u64.into(),
+        validator_exchange_rate: exchange_rate.into(),
+    };
+
+    for epoch_index in 1..=3 {
+        state.record_validator_rate_history(
+            &validator,
+            epoch_index,
+            rate_data(validator, 1_0000_0000 + epoch_index),
+        );
+    }
+    state.record_validator_rate_history(&other, 2, rate_data(other, 1_0000_0000));
+
+    let history = state
+        .get_validator_rate_history(&validator, 0, usize::MAX)
+        .await?;
+    assert_eq!(
+        history,
+        vec![
+            (1, rate_data(validator, 1_0000_0001)),
+            (2, rate_data(validator, 1_0000_0002)),
+            (3, rate_data(validator, 1_0000_0003)),
+        ]
+    );
+
+    let history = state.get_validator_rate_history(&validator, 2, 1).await?;
+    assert_eq!(history, vec![(2, rate_data(validator, 1_0000_0002))]);
+
+    let history = state
+        .get_validator_rate_history(&other, 0, usize::MAX)
+        .await?;
+    assert_eq!(history, vec![(2, rate_data(other, 1_0000_0000))]);
+
+    Ok(())
+}
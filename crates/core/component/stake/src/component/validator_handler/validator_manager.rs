@@ -17,14 +17,15 @@ use {
             State::{self, *},
             Validator,
         },
-        DelegationToken, IdentityKey, Penalty, Uptime,
+        DelegationToken, IdentityKey, Penalty, PendingFundingStreams, Uptime,
     },
     anyhow::{ensure, Result},
     async_trait::async_trait,
     cnidarium::StateWrite,
+    futures::TryStreamExt,
     penumbra_asset::asset,
     penumbra_num::Amount,
-    penumbra_proto::{DomainType as _, StateWriteProto},
+    penumbra_proto::{DomainType as _, StateReadProto, StateWriteProto},
     penumbra_sct::component::{
         clock::{EpochManager, EpochRead},
         StateReadExt as _,
@@ -615,6 +616,46 @@ pub trait ValidatorManager: StateWrite {
         Ok(())
     }
 
+    /// Apply the announced commission increases whose notice period ends at the start
+    /// of the epoch with index `epoch_index`.
+    #[instrument(skip(self))]
+    async fn apply_pending_funding_streams(&mut self, epoch_index: u64) -> Result<()> {
+        let pending: Vec<(String, PendingFundingStreams)> = self
+            .prefix::<PendingFundingStreams>(
+                state_key::validators::pending_funding_streams::prefix(),
+            )
+            .try_collect()
+            .await?;
+
+        for (key, pending) in pending {
+            if pending.effective_epoch_index > epoch_index {
+                continue;
+            }
+
+            let id: IdentityKey = key
+                .strip_prefix(state_key::validators::pending_funding_streams::prefix())
+                .ok_or_else(|| anyhow::anyhow!("unexpected pending funding streams key {key}"))?
+                .parse()?;
+            self.clear_pending_funding_streams(&id);
+
+            let Some(mut validator) = self.get_validator_definition(&id).await? else {
+                tracing::warn!(validator_identity = %id, "pending funding streams for an unknown validator");
+                continue;
+            };
+
+            tracing::debug!(validator_identity = %id, funding_streams = ?pending.funding_streams, "applying pending funding streams");
+            validator.funding_streams = pending.funding_streams;
+            self.put(
+                state_key::validators::definitions::by_id(&id),
+                validator.clone(),
+            );
+            // The effective definition changed, so we track it like an upload.
+            self.record_proto(event::EventValidatorDefinitionUpload { validator }.to_proto());
+        }
+
+        Ok(())
+    }
+
     /// Update the validator pool's bonding state.
     #[instrument(skip(self))]
     async fn process_validator_pool_state(
@@ -4,12 +4,12 @@ use crate::{
     rate::RateData,
     state_key,
     validator::{self, BondingState::*, State, Validator},
-    IdentityKey, Uptime,
+    IdentityKey, PendingFundingStreams, Uptime,
 };
 use anyhow::Result;
 use async_trait::async_trait;
 use cnidarium::{StateRead, StateWrite};
-use futures::{Future, FutureExt};
+use futures::{Future, FutureExt, StreamExt as _, TryStreamExt as _};
 use penumbra_num::Amount;
 use penumbra_proto::{state::future::DomainFuture, DomainType, StateReadProto, StateWriteProto};
 use std::pin::Pin;
@@ -95,6 +95,29 @@ pub trait ValidatorDataRead: StateRead {
             .expect("no deserialization error expected")
     }
 
+    /// Returns the rate data of the validator in each epoch from `start_epoch_index` on, in
+    /// order, up to `limit` epochs, along with the index of each epoch.
+    async fn get_validator_rate_history(
+        &self,
+        identity_key: &IdentityKey,
+        start_epoch_index: u64,
+        limit: usize,
+    ) -> Result<Vec<(u64, RateData)>> {
+        let prefix = state_key::validators::rate::history_by_id(identity_key);
+        let start_key = format!("{start_epoch_index:020}").into_bytes();
+        self.nonverifiable_range_raw(Some(prefix.as_bytes()), start_key..)?
+            .take(limit)
+            .and_then(|(key, value)| {
+                let prefix_len = prefix.len();
+                async move {
+                    let epoch_index: u64 = std::str::from_utf8(&key[prefix_len..])?.parse()?;
+                    anyhow::Ok((epoch_index, RateData::decode(value.as_ref())?))
+                }
+            })
+            .try_collect()
+            .await
+    }
+
     fn get_validator_power(
         &self,
         validator: &IdentityKey,
@@ -121,6 +144,18 @@ pub trait ValidatorDataRead: StateRead {
             .await
     }
 
+    /// Returns the increase of the validator's commission that has been announced
+    /// but has not yet taken effect, if any.
+    async fn get_pending_funding_streams(
+        &self,
+        identity_key: &IdentityKey,
+    ) -> Result<Option<PendingFundingStreams>> {
+        self.get(&state_key::validators::pending_funding_streams::by_id(
+            identity_key,
+        ))
+        .await
+    }
+
     fn get_validator_uptime(
         &self,
         identity_key: &IdentityKey,
@@ -325,6 +360,21 @@ pub(crate) trait ValidatorDataWrite: StateWrite {
         );
     }
 
+    #[instrument(skip(self))]
+    /// Record the validator rate data of the epoch `epoch_index` for clients, outside of
+    /// consensus state.
+    fn record_validator_rate_history(
+        &mut self,
+        identity_key: &IdentityKey,
+        epoch_index: u64,
+        rate_data: RateData,
+    ) {
+        self.nonverifiable_put(
+            state_key::validators::rate::history_by_id_and_epoch(identity_key, epoch_index).into(),
+            rate_data,
+        );
+    }
+
     #[instrument(skip(self))]
     /// Persist the previous validator rate data, inclusive of accumulated penalties.
     fn set_prev_validator_rate(&mut self, identity_key: &IdentityKey, rate_data: RateData) {
@@ -344,6 +394,25 @@ pub(crate) trait ValidatorDataWrite: StateWrite {
             height.to_be_bytes().to_vec(),
         );
     }
+
+    /// Record an increase of the validator's commission that will take effect
+    /// once the notice period has elapsed, replacing any pending one.
+    fn set_pending_funding_streams(
+        &mut self,
+        identity_key: &IdentityKey,
+        pending: PendingFundingStreams,
+    ) {
+        self.put(
+            state_key::validators::pending_funding_streams::by_id(identity_key),
+            pending,
+        );
+    }
+
+    fn clear_pending_funding_streams(&mut self, identity_key: &IdentityKey) {
+        self.delete(state_key::validators::pending_funding_streams::by_id(
+            identity_key,
+        ));
+    }
 }
 
 impl<T: StateWrite + ?Sized> ValidatorDataWrite for T {}
@@ -1,7 +1,7 @@
 use crate::{
     rate::RateData,
     validator::{BondingState, State, Validator},
-    Delegate, IdentityKey, Penalty, Undelegate,
+    Delegate, IdentityKey, Penalty, Redelegate, Undelegate,
 };
 use anyhow::{anyhow, Context as _};
 use penumbra_num::Amount;
@@ -342,6 +342,61 @@ impl DomainType for EventUndelegate {
     type Proto = pb::EventUndelegate;
 }
 
+#[derive(Clone, Debug)]
+pub struct EventRedelegate {
+    pub source_identity_key: IdentityKey,
+    pub destination_identity_key: IdentityKey,
+    pub amount: Amount,
+}
+
+impl From<&Redelegate> for EventRedelegate {
+    fn from(value: &Redelegate) -> Self {
+        Self {
+            source_identity_key: value.source_validator,
+            destination_identity_key: value.destination_validator,
+            amount: value.unbonded_amount,
+        }
+    }
+}
+
+impl TryFrom<pb::EventRedelegate> for EventRedelegate {
+    type Error = anyhow::Error;
+
+    fn try_from(value: pb::EventRedelegate) -> Result<Self, Self::Error> {
+        fn inner(value: pb::EventRedelegate) -> anyhow::Result<EventRedelegate> {
+            Ok(EventRedelegate {
+                source_identity_key: value
+                    .source_identity_key
+                    .ok_or(anyhow!("missing `source_identity_key`"))?
+                    .try_into()?,
+                destination_identity_key: value
+                    .destination_identity_key
+                    .ok_or(anyhow!("missing `destination_identity_key`"))?
+                    .try_into()?,
+                amount: value
+                    .amount
+                    .ok_or(anyhow!("missing `amount`"))?
+                    .try_into()?,
+            })
+        }
+        inner(value).context(format!("parsing {}", pb::EventRedelegate::NAME))
+    }
+}
+
+impl From<EventRedelegate> for pb::EventRedelegate {
+    fn from(value: EventRedelegate) -> Self {
+        Self {
+            source_identity_key: Some(value.source_identity_key.into()),
+            destination_identity_key: Some(value.destination_identity_key.into()),
+            amount: Some(value.amount.into()),
+        }
+    }
+}
+
+impl DomainType for EventRedelegate {
+    type Proto = pb::EventRedelegate;
+}
+
 #[derive(Clone, Debug)]
 pub struct EventTombstoneValidator {
     pub evidence_height: u64,
@@ -193,6 +193,14 @@ impl FundingStreams {
     pub fn len(&self) -> usize {
         self.funding_streams.len()
     }
+
+    /// Returns the total commission of the funding streams, in basis points.
+    pub fn total_rate_bps(&self) -> u64 {
+        self.funding_streams
+            .iter()
+            .map(|fs| fs.rate_bps() as u64)
+            .sum()
+    }
 }
 
 impl TryFrom<Vec<FundingStream>> for FundingStreams {
@@ -240,3 +248,53 @@ impl<'a> IntoIterator for &'a FundingStreams {
         (self.funding_streams).iter()
     }
 }
+
+/// An increase of a validator's commission that has been announced, but has not yet taken
+/// effect.
+///
+/// Decreases of a validator's commission take effect immediately, but increases are subject to
+/// a notice period, so that delegators have time to react to them.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(
+    try_from = "pb::PendingFundingStreams",
+    into = "pb::PendingFundingStreams"
+)]
+pub struct PendingFundingStreams {
+    /// The funding streams that will replace the validator's current funding streams.
+    pub funding_streams: FundingStreams,
+    /// The index of the epoch at the start of which the new funding streams take effect.
+    pub effective_epoch_index: u64,
+}
+
+impl DomainType for PendingFundingStreams {
+    type Proto = pb::PendingFundingStreams;
+}
+
+impl From<PendingFundingStreams> for pb::PendingFundingStreams {
+    fn from(pending: PendingFundingStreams) -> Self {
+        pb::PendingFundingStreams {
+            funding_streams: pending
+                .funding_streams
+                .into_iter()
+                .map(Into::into)
+                .collect(),
+            effective_epoch_index: pending.effective_epoch_index,
+        }
+    }
+}
+
+impl TryFrom<pb::PendingFundingStreams> for PendingFundingStreams {
+    type Error = anyhow::Error;
+
+    fn try_from(pending: pb::PendingFundingStreams) -> Result<Self, Self::Error> {
+        Ok(PendingFundingStreams {
+            funding_streams: pending
+                .funding_streams
+                .into_iter()
+                .map(FundingStream::try_from)
+                .collect::<Result<Vec<_>, _>>()?
+                .try_into()?,
+            effective_epoch_index: pending.effective_epoch_index,
+        })
+    }
+}
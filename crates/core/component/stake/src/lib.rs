@@ -18,6 +18,7 @@ pub mod funding_stream;
 pub mod genesis;
 pub mod params;
 pub mod rate;
+pub mod redelegate;
 pub mod state_key;
 pub mod undelegate;
 pub mod undelegate_claim;
@@ -33,6 +34,7 @@ pub static BPS_SQUARED_SCALING_FACTOR: once_cell::sync::Lazy<penumbra_num::fixpo
     once_cell::sync::Lazy::new(|| 1_0000_0000u128.into());
 
 pub use self::delegate::Delegate;
+pub use self::redelegate::Redelegate;
 pub use self::undelegate::Undelegate;
 pub use self::undelegate_claim::{
     UndelegateClaim, UndelegateClaimBody, UndelegateClaimPlan, UndelegateClaimProof,
@@ -46,5 +48,5 @@ pub use self::unbonding_token::UnbondingToken;
 
 pub use self::changes::DelegationChanges;
 pub use self::current_consensus_keys::CurrentConsensusKeys;
-pub use self::funding_stream::{FundingStream, FundingStreams};
+pub use self::funding_stream::{FundingStream, FundingStreams, PendingFundingStreams};
 pub use self::uptime::Uptime;
@@ -20,6 +20,12 @@ pub struct StakeParameters {
     pub missed_blocks_maximum: u64,
     /// The minimum amount of stake required for a validator to be indexed.
     pub min_validator_stake: Amount,
+    /// The maximum increase of a validator's total commission, in basis points, that can take
+    /// effect in a single epoch. Zero means that increases are not limited.
+    pub max_commission_increase_bps: u64,
+    /// The number of epochs that must elapse before an increase of a validator's commission
+    /// takes effect.
+    pub commission_increase_notice_epochs: u64,
 }
 
 impl DomainType for StakeParameters {
@@ -41,6 +47,8 @@ impl TryFrom<pb::StakeParameters> for StakeParameters {
                 .ok_or_else(|| anyhow::anyhow!("missing min_validator_stake"))?
                 .try_into()?,
             unbonding_delay: msg.unbonding_delay,
+            max_commission_increase_bps: msg.max_commission_increase_bps,
+            commission_increase_notice_epochs: msg.commission_increase_notice_epochs,
         })
     }
 }
@@ -58,6 +66,8 @@ impl From<StakeParameters> for pb::StakeParameters {
             base_reward_rate: 0,
             min_validator_stake: Some(params.min_validator_stake.into()),
             unbonding_delay: params.unbonding_delay,
+            max_commission_increase_bps: params.max_commission_increase_bps,
+            commission_increase_notice_epochs: params.commission_increase_notice_epochs,
         }
     }
 }
@@ -77,6 +87,10 @@ impl Default for StakeParameters {
             slashing_penalty_downtime: 1_0000,
             // 1 penumbra
             min_validator_stake: 1_000_000u128.into(),
+            // 500 basis points = 5%
+            max_commission_increase_bps: 500,
+            // Delegators get at least one full epoch to react to a commission increase.
+            commission_increase_notice_epochs: 1,
         }
     }
 }
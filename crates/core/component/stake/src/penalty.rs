@@ -42,6 +42,21 @@ impl Penalty {
         .one_minus_this()
     }
 
+    /// Create the smallest `Penalty` which takes at least `lost` out of `total`.
+    ///
+    /// The penalty is rounded up to the next basis point squared, and is 100% if
+    /// `lost` is at least `total`.
+    pub fn covering_loss(lost: Amount, total: Amount) -> Self {
+        if lost >= total {
+            return Penalty::from_percent(100);
+        }
+        let bps_squared = lost
+            .value()
+            .checked_mul(1_0000_0000)
+            .map_or(1_0000_0000, |scaled| scaled.div_ceil(total.value()));
+        Penalty::from_bps_squared(bps_squared.min(1_0000_0000) as u64)
+    }
+
     fn one_minus_this(&self) -> Penalty {
         Self(
             (U128x128::from(1u64) - self.0)
@@ -133,3 +148,29 @@ impl TryFrom<pbs::Penalty> for Penalty {
         Ok(Penalty::try_from(v.inner.as_slice())?)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn covering_loss_rounds_up() {
+        // A third of the total can't be represented exactly, so the penalty takes slightly more.
+        let penalty = Penalty::covering_loss(1u64.into(), 3u64.into());
+        assert_eq!(penalty, Penalty::from_bps_squared(3333_3334));
+        assert!(penalty.apply_to_amount(3_000_000u64.into()) <= 2_000_000u64.into());
+
+        assert_eq!(
+            Penalty::covering_loss(0u64.into(), 3u64.into()),
+            Penalty::from_percent(0)
+        );
+        assert_eq!(
+            Penalty::covering_loss(5u64.into(), 3u64.into()),
+            Penalty::from_percent(100)
+        );
+        assert_eq!(
+            Penalty::covering_loss(0u64.into(), 0u64.into()),
+            Penalty::from_percent(100)
+        );
+    }
+}
@@ -8,7 +8,7 @@ use penumbra_sct::epoch::Epoch;
 use serde::{Deserialize, Serialize};
 
 use crate::{validator::State, FundingStream, IdentityKey};
-use crate::{Delegate, Penalty, Undelegate, BPS_SQUARED_SCALING_FACTOR};
+use crate::{Delegate, Penalty, Redelegate, Undelegate, BPS_SQUARED_SCALING_FACTOR};
 
 /// Describes a validator's reward rate and voting power in some epoch.
 #[derive(Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
@@ -251,6 +251,26 @@ impl RateData {
             validator_identity: self.identity_key.clone(),
         }
     }
+
+    /// Uses this `RateData` and the `destination` validator's `RateData` to build a
+    /// `Redelegate` transaction action that moves `delegation_amount` of this
+    /// validator's delegation tokens to the `destination` validator.
+    pub fn build_redelegate(
+        &self,
+        destination: &RateData,
+        epoch: Epoch,
+        delegation_amount: Amount,
+    ) -> Redelegate {
+        let unbonded_amount = self.unbonded_amount(delegation_amount);
+        Redelegate {
+            source_validator: self.identity_key.clone(),
+            destination_validator: destination.identity_key.clone(),
+            epoch_index: epoch.index,
+            source_delegation_amount: delegation_amount,
+            unbonded_amount,
+            destination_delegation_amount: destination.delegation_amount(unbonded_amount),
+        }
+    }
 }
 
 /// Describes the base reward and exchange rates in some epoch.
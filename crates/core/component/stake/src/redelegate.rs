@@ -0,0 +1,118 @@
+use penumbra_asset::{Balance, Value};
+use penumbra_num::Amount;
+use penumbra_proto::{penumbra::core::component::stake::v1 as pb, DomainType};
+use penumbra_txhash::{EffectHash, EffectingData};
+use serde::{Deserialize, Serialize};
+
+use crate::{DelegationToken, IdentityKey};
+
+/// A transaction action moving stake from one validator's delegation pool to another's.
+///
+/// Unlike an undelegation followed by a delegation, the stake does not go through
+/// the unbonding delay, and keeps earning rewards throughout. It remains liable for
+/// the penalties of the source validator until the end of the unbonding delay, which
+/// are paid by the destination validator's delegation pool.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(try_from = "pb::Redelegate", into = "pb::Redelegate")]
+pub struct Redelegate {
+    /// The identity key of the validator to move stake away from.
+    pub source_validator: IdentityKey,
+    /// The identity key of the validator to move stake to.
+    pub destination_validator: IdentityKey,
+    /// The index of the epoch in which this redelegation was performed.
+    /// The redelegation takes effect in the next epoch.
+    pub epoch_index: u64,
+    /// The amount of the source validator's delegation tokens consumed by this action.
+    pub source_delegation_amount: Amount,
+    /// The amount of unbonded stake represented by the source delegation tokens.
+    ///
+    /// This is implied by the source validator's exchange rate in the specified epoch.
+    pub unbonded_amount: Amount,
+    /// The amount of the destination validator's delegation tokens produced by this action.
+    ///
+    /// This is implied by the destination validator's exchange rate in the specified epoch
+    /// (and should be checked in transaction validation!), but including it allows
+    /// stateless verification that the transaction is internally consistent.
+    pub destination_delegation_amount: Amount,
+}
+
+impl EffectingData for Redelegate {
+    fn effect_hash(&self) -> EffectHash {
+        // For redelegations, the entire action is considered effecting data.
+        EffectHash::from_proto_effecting_data(&self.to_proto())
+    }
+}
+
+impl Redelegate {
+    /// Return the balance after consuming the source validator's delegation tokens,
+    /// and producing the destination validator's delegation tokens.
+    pub fn balance(&self) -> Balance {
+        let source: Balance = self.source_delegation_value().into();
+        let destination: Balance = self.destination_delegation_value().into();
+
+        // We consume the source delegation tokens and produce the destination ones.
+        destination - source
+    }
+
+    /// Returns the [`Value`] of the source delegation tokens consumed.
+    pub fn source_delegation_value(&self) -> Value {
+        Value {
+            amount: self.source_delegation_amount,
+            asset_id: DelegationToken::new(self.source_validator.clone()).id(),
+        }
+    }
+
+    /// Returns the [`Value`] of the destination delegation tokens produced.
+    pub fn destination_delegation_value(&self) -> Value {
+        Value {
+            amount: self.destination_delegation_amount,
+            asset_id: DelegationToken::new(self.destination_validator.clone()).id(),
+        }
+    }
+}
+
+impl DomainType for Redelegate {
+    type Proto = pb::Redelegate;
+}
+
+impl From<Redelegate> for pb::Redelegate {
+    fn from(r: Redelegate) -> Self {
+        pb::Redelegate {
+            source_validator: Some(r.source_validator.into()),
+            destination_validator: Some(r.destination_validator.into()),
+            epoch_index: r.epoch_index,
+            source_delegation_amount: Some(r.source_delegation_amount.into()),
+            unbonded_amount: Some(r.unbonded_amount.into()),
+            destination_delegation_amount: Some(r.destination_delegation_amount.into()),
+        }
+    }
+}
+
+impl TryFrom<pb::Redelegate> for Redelegate {
+    type Error = anyhow::Error;
+    fn try_from(r: pb::Redelegate) -> Result<Self, Self::Error> {
+        Ok(Self {
+            source_validator: r
+                .source_validator
+                .ok_or_else(|| anyhow::anyhow!("missing source validator"))?
+                .try_into()?,
+            destination_validator: r
+                .destination_validator
+                .ok_or_else(|| anyhow::anyhow!("missing destination validator"))?
+                .try_into()?,
+            epoch_index: r.epoch_index,
+            source_delegation_amount: r
+                .source_delegation_amount
+                .ok_or_else(|| anyhow::anyhow!("missing source delegation amount"))?
+                .try_into()?,
+            unbonded_amount: r
+                .unbonded_amount
+                .ok_or_else(|| anyhow::anyhow!("missing unbonded amount"))?
+                .try_into()?,
+            destination_delegation_amount: r
+                .destination_delegation_amount
+                .ok_or_else(|| anyhow::anyhow!("missing destination delegation amount"))?
+                .try_into()?,
+        })
+    }
+}
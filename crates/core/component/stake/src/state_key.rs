@@ -38,6 +38,15 @@ pub mod validators {
         }
     }
 
+    pub mod pending_funding_streams {
+        pub fn prefix() -> &'static str {
+            "staking/validators/pending_funding_streams/"
+        }
+        pub fn by_id(id: &crate::IdentityKey) -> String {
+            format!("{}{id}", prefix())
+        }
+    }
+
     pub mod state {
         pub fn by_id(id: &crate::IdentityKey) -> String {
             format!("staking/validators/data/state/{id}")
@@ -52,6 +61,15 @@ pub mod validators {
         pub fn previous_by_id(id: &crate::IdentityKey) -> String {
             format!("staking/validators/data/rate/previous/{id}")
         }
+
+        /// The prefix of the rate data of a validator in past epochs, in nonverifiable storage.
+        pub fn history_by_id(id: &crate::IdentityKey) -> String {
+            format!("staking/validators/data/rate/history/{id}/")
+        }
+
+        pub fn history_by_id_and_epoch(id: &crate::IdentityKey, epoch_index: u64) -> String {
+            format!("{}{epoch_index:020}", history_by_id(id))
+        }
     }
 
     pub mod power {
@@ -138,6 +156,25 @@ pub mod penalty {
     }
 }
 
+pub mod redelegation_liability {
+    use crate::IdentityKey;
+
+    pub fn prefix(source: &IdentityKey) -> String {
+        // As for penalties, the source validator leads the path so that its
+        // liabilities can be iterated over when it is penalized.
+        format!("staking/redelegation_liability/{source}/")
+    }
+    pub fn for_destination(
+        source: &IdentityKey,
+        unbonds_at_height: u64,
+        destination: &IdentityKey,
+    ) -> String {
+        // Load-bearing format string: we need to pad with 0s to ensure that
+        // the lex order agrees with the numeric order on heights.
+        format!("{}{unbonds_at_height:020}/{destination}", prefix(source))
+    }
+}
+
 pub mod consensus_update {
     pub fn consensus_keys() -> &'static str {
         "staking/cometbft_data/consensus_keys"
@@ -26,6 +26,7 @@ pub enum Action {
     ProposalWithdraw(penumbra_governance::ProposalWithdraw),
     DelegatorVote(penumbra_governance::DelegatorVote),
     ValidatorVote(penumbra_governance::ValidatorVote),
+    ProxyVote(penumbra_governance::ProxyVote),
     ProposalDepositClaim(penumbra_governance::ProposalDepositClaim),
 
     PositionOpen(penumbra_dex::lp::action::PositionOpen),
@@ -34,12 +35,14 @@ pub enum Action {
 
     Delegate(penumbra_stake::Delegate),
     Undelegate(penumbra_stake::Undelegate),
+    Redelegate(penumbra_stake::Redelegate),
     UndelegateClaim(penumbra_stake::UndelegateClaim),
 
     Ics20Withdrawal(penumbra_shielded_pool::Ics20Withdrawal),
 
     CommunityPoolSpend(penumbra_community_pool::CommunityPoolSpend),
     CommunityPoolOutput(penumbra_community_pool::CommunityPoolOutput),
+    CommunityPoolVestingClaim(penumbra_community_pool::CommunityPoolVestingClaim),
     CommunityPoolDeposit(penumbra_community_pool::CommunityPoolDeposit),
 
     ActionDutchAuctionSchedule(ActionDutchAuctionSchedule),
@@ -54,12 +57,14 @@ impl EffectingData for Action {
             Action::Spend(spend) => spend.effect_hash(),
             Action::Delegate(delegate) => delegate.effect_hash(),
             Action::Undelegate(undelegate) => undelegate.effect_hash(),
+            Action::Redelegate(redelegate) => redelegate.effect_hash(),
             Action::UndelegateClaim(claim) => claim.effect_hash(),
             Action::ProposalSubmit(submit) => submit.effect_hash(),
             Action::ProposalWithdraw(withdraw) => withdraw.effect_hash(),
             Action::ProposalDepositClaim(claim) => claim.effect_hash(),
             Action::DelegatorVote(vote) => vote.effect_hash(),
             Action::ValidatorVote(vote) => vote.effect_hash(),
+            Action::ProxyVote(vote) => vote.effect_hash(),
             Action::SwapClaim(swap_claim) => swap_claim.effect_hash(),
             Action::Swap(swap) => swap.effect_hash(),
             Action::ValidatorDefinition(defn) => defn.effect_hash(),
@@ -70,6 +75,7 @@ impl EffectingData for Action {
             Action::Ics20Withdrawal(w) => w.effect_hash(),
             Action::CommunityPoolSpend(d) => d.effect_hash(),
             Action::CommunityPoolOutput(d) => d.effect_hash(),
+            Action::CommunityPoolVestingClaim(d) => d.effect_hash(),
             Action::CommunityPoolDeposit(d) => d.effect_hash(),
             Action::ActionDutchAuctionSchedule(a) => a.effect_hash(),
             Action::ActionDutchAuctionEnd(a) => a.effect_hash(),
@@ -103,6 +109,7 @@ impl Action {
             }
             Action::DelegatorVote(_) => tracing::info_span!("DelegatorVote", ?idx),
             Action::ValidatorVote(_) => tracing::info_span!("ValidatorVote", ?idx),
+            Action::ProxyVote(_) => tracing::info_span!("ProxyVote", ?idx),
             Action::ProposalDepositClaim(_) => {
                 tracing::info_span!("ProposalDepositClaim", ?idx)
             }
@@ -113,11 +120,15 @@ impl Action {
             }
             Action::Delegate(_) => tracing::info_span!("Delegate", ?idx),
             Action::Undelegate(_) => tracing::info_span!("Undelegate", ?idx),
+            Action::Redelegate(_) => tracing::info_span!("Redelegate", ?idx),
             Action::UndelegateClaim(_) => tracing::info_span!("UndelegateClaim", ?idx),
             Action::Ics20Withdrawal(_) => tracing::info_span!("Ics20Withdrawal", ?idx),
             Action::CommunityPoolDeposit(_) => tracing::info_span!("CommunityPoolDeposit", ?idx),
             Action::CommunityPoolSpend(_) => tracing::info_span!("CommunityPoolSpend", ?idx),
             Action::CommunityPoolOutput(_) => tracing::info_span!("CommunityPoolOutput", ?idx),
+            Action::CommunityPoolVestingClaim(_) => {
+                tracing::info_span!("CommunityPoolVestingClaim", ?idx)
+            }
             Action::ActionDutchAuctionSchedule(_) => {
                 tracing::info_span!("ActionDutchAuctionSchedule", ?idx)
             }
@@ -141,6 +152,7 @@ impl Action {
             Action::ProposalWithdraw(_) => 19,
             Action::ValidatorVote(_) => 20,
             Action::DelegatorVote(_) => 21,
+            Action::ProxyVote(_) => 57,
             Action::ProposalDepositClaim(_) => 22,
             Action::PositionOpen(_) => 30,
             Action::PositionClose(_) => 31,
@@ -148,9 +160,11 @@ impl Action {
             Action::Delegate(_) => 40,
             Action::Undelegate(_) => 41,
             Action::UndelegateClaim(_) => 42,
+            Action::Redelegate(_) => 43,
             Action::CommunityPoolSpend(_) => 50,
             Action::CommunityPoolOutput(_) => 51,
             Action::CommunityPoolDeposit(_) => 52,
+            Action::CommunityPoolVestingClaim(_) => 56,
             Action::Ics20Withdrawal(_) => 200,
             Action::ActionDutchAuctionSchedule(_) => 53,
             Action::ActionDutchAuctionEnd(_) => 54,
@@ -166,6 +180,7 @@ impl IsAction for Action {
             Action::Spend(spend) => spend.balance_commitment(),
             Action::Delegate(delegate) => delegate.balance_commitment(),
             Action::Undelegate(undelegate) => undelegate.balance_commitment(),
+            Action::Redelegate(redelegate) => redelegate.balance_commitment(),
             Action::UndelegateClaim(undelegate_claim) => undelegate_claim.balance_commitment(),
             Action::Swap(swap) => swap.balance_commitment(),
             Action::SwapClaim(swap_claim) => swap_claim.balance_commitment(),
@@ -173,6 +188,7 @@ impl IsAction for Action {
             Action::ProposalWithdraw(withdraw) => withdraw.balance_commitment(),
             Action::DelegatorVote(delegator_vote) => delegator_vote.balance_commitment(),
             Action::ValidatorVote(validator_vote) => validator_vote.balance_commitment(),
+            Action::ProxyVote(proxy_vote) => proxy_vote.balance_commitment(),
             Action::ProposalDepositClaim(p) => p.balance_commitment(),
             Action::PositionOpen(p) => p.balance_commitment(),
             Action::PositionClose(p) => p.balance_commitment(),
@@ -181,6 +197,7 @@ impl IsAction for Action {
             Action::CommunityPoolDeposit(deposit) => deposit.balance_commitment(),
             Action::CommunityPoolSpend(spend) => spend.balance_commitment(),
             Action::CommunityPoolOutput(output) => output.balance_commitment(),
+            Action::CommunityPoolVestingClaim(claim) => claim.balance_commitment(),
             // These actions just post Protobuf data to the chain, and leave the
             // value balance unchanged.
             Action::IbcRelay(x) => x.balance_commitment(),
@@ -199,11 +216,13 @@ impl IsAction for Action {
             Action::Spend(x) => x.view_from_perspective(txp),
             Action::Delegate(x) => x.view_from_perspective(txp),
             Action::Undelegate(x) => x.view_from_perspective(txp),
+            Action::Redelegate(x) => x.view_from_perspective(txp),
             Action::UndelegateClaim(x) => x.view_from_perspective(txp),
             Action::ProposalSubmit(x) => x.view_from_perspective(txp),
             Action::ProposalWithdraw(x) => x.view_from_perspective(txp),
             Action::DelegatorVote(x) => x.view_from_perspective(txp),
             Action::ValidatorVote(x) => x.view_from_perspective(txp),
+            Action::ProxyVote(x) => x.view_from_perspective(txp),
             Action::ProposalDepositClaim(x) => x.view_from_perspective(txp),
             Action::PositionOpen(x) => x.view_from_perspective(txp),
             Action::PositionClose(x) => x.view_from_perspective(txp),
@@ -211,6 +230,7 @@ impl IsAction for Action {
             Action::Ics20Withdrawal(x) => x.view_from_perspective(txp),
             Action::CommunityPoolSpend(x) => x.view_from_perspective(txp),
             Action::CommunityPoolOutput(x) => x.view_from_perspective(txp),
+            Action::CommunityPoolVestingClaim(x) => x.view_from_perspective(txp),
             Action::CommunityPoolDeposit(x) => x.view_from_perspective(txp),
             Action::ValidatorDefinition(x) => ActionView::ValidatorDefinition(x.to_owned()),
             Action::IbcRelay(x) => ActionView::IbcRelay(x.to_owned()),
@@ -240,6 +260,9 @@ impl From<Action> for pb::Action {
             Action::Undelegate(inner) => pb::Action {
                 action: Some(pb::action::Action::Undelegate(inner.into())),
             },
+            Action::Redelegate(inner) => pb::Action {
+                action: Some(pb::action::Action::Redelegate(inner.into())),
+            },
             Action::UndelegateClaim(inner) => pb::Action {
                 action: Some(pb::action::Action::UndelegateClaim(inner.into())),
             },
@@ -267,6 +290,9 @@ impl From<Action> for pb::Action {
             Action::ValidatorVote(inner) => pb::Action {
                 action: Some(pb::action::Action::ValidatorVote(inner.into())),
             },
+            Action::ProxyVote(inner) => pb::Action {
+                action: Some(pb::action::Action::ProxyVote(inner.into())),
+            },
             Action::ProposalDepositClaim(inner) => pb::Action {
                 action: Some(pb::action::Action::ProposalDepositClaim(inner.into())),
             },
@@ -291,6 +317,9 @@ impl From<Action> for pb::Action {
             Action::CommunityPoolDeposit(inner) => pb::Action {
                 action: Some(pb::action::Action::CommunityPoolDeposit(inner.into())),
             },
+            Action::CommunityPoolVestingClaim(inner) => pb::Action {
+                action: Some(pb::action::Action::CommunityPoolVestingClaim(inner.into())),
+            },
             Action::ActionDutchAuctionSchedule(inner) => pb::Action {
                 action: Some(pb::action::Action::ActionDutchAuctionSchedule(inner.into())),
             },
@@ -318,6 +347,7 @@ impl TryFrom<pb::Action> for Action {
             pb::action::Action::Spend(inner) => Ok(Action::Spend(inner.try_into()?)),
             pb::action::Action::Delegate(inner) => Ok(Action::Delegate(inner.try_into()?)),
             pb::action::Action::Undelegate(inner) => Ok(Action::Undelegate(inner.try_into()?)),
+            pb::action::Action::Redelegate(inner) => Ok(Action::Redelegate(inner.try_into()?)),
             pb::action::Action::UndelegateClaim(inner) => {
                 Ok(Action::UndelegateClaim(inner.try_into()?))
             }
@@ -339,6 +369,7 @@ impl TryFrom<pb::Action> for Action {
             pb::action::Action::ValidatorVote(inner) => {
                 Ok(Action::ValidatorVote(inner.try_into()?))
             }
+            pb::action::Action::ProxyVote(inner) => Ok(Action::ProxyVote(inner.try_into()?)),
             pb::action::Action::ProposalDepositClaim(inner) => {
                 Ok(Action::ProposalDepositClaim(inner.try_into()?))
             }
@@ -365,6 +396,9 @@ impl TryFrom<pb::Action> for Action {
             pb::action::Action::CommunityPoolDeposit(inner) => {
                 Ok(Action::CommunityPoolDeposit(inner.try_into()?))
             }
+            pb::action::Action::CommunityPoolVestingClaim(inner) => {
+                Ok(Action::CommunityPoolVestingClaim(inner.try_into()?))
+            }
             pb::action::Action::ActionDutchAuctionSchedule(inner) => {
                 Ok(Action::ActionDutchAuctionSchedule(inner.try_into()?))
             }
@@ -1,17 +1,19 @@
 use penumbra_auction::auction::dutch::actions::{
     ActionDutchAuctionEnd, ActionDutchAuctionSchedule, ActionDutchAuctionWithdraw,
 };
-use penumbra_community_pool::{CommunityPoolDeposit, CommunityPoolOutput, CommunityPoolSpend};
+use penumbra_community_pool::{
+    CommunityPoolDeposit, CommunityPoolOutput, CommunityPoolSpend, CommunityPoolVestingClaim,
+};
 use penumbra_dex::{PositionClose, PositionOpen, PositionWithdraw, Swap, SwapClaim};
 use penumbra_fee::Gas;
 use penumbra_ibc::IbcRelay;
 use penumbra_shielded_pool::{Ics20Withdrawal, Output, Spend};
 use penumbra_stake::{
-    validator::Definition as ValidatorDefinition, Delegate, Undelegate, UndelegateClaim,
+    validator::Definition as ValidatorDefinition, Delegate, Redelegate, Undelegate, UndelegateClaim,
 };
 
 use penumbra_governance::{
-    DelegatorVote, ProposalDepositClaim, ProposalSubmit, ProposalWithdraw, ValidatorVote,
+    DelegatorVote, ProposalDepositClaim, ProposalSubmit, ProposalWithdraw, ProxyVote, ValidatorVote,
 };
 
 use crate::{
@@ -94,6 +96,23 @@ fn delegate_gas_cost(delegate: &Delegate) -> Gas {
     }
 }
 
+fn redelegate_gas_cost(redelegate: &Redelegate) -> Gas {
+    Gas {
+        // The block space measured as the byte length of the encoded action.
+        block_space: redelegate.encode_to_vec().len() as u64,
+        // The compact block space cost is based on the byte size of the data the [`Action`] adds
+        // to the compact block.
+        // For a Redelegate, nothing is added to the compact block directly. The source delegation
+        // tokens it consumes and the destination delegation tokens it mints are carried by
+        // [`Action::Spend`] and [`Action::Output`] actions, which add their own costs.
+        compact_block_space: 0,
+        // Does not include a zk-SNARK proof, so there's no verification cost.
+        verification: 0,
+        // Execution cost is currently hardcoded at 10 for all Action variants.
+        execution: 10,
+    }
+}
+
 fn undelegate_gas_cost(undelegate: &Undelegate) -> Gas {
     Gas {
         // The block space measured as the byte length of the encoded action.
@@ -327,17 +346,20 @@ impl GasCost for ActionPlan {
 
             ActionPlan::Delegate(d) => d.gas_cost(),
             ActionPlan::Undelegate(u) => u.gas_cost(),
+            ActionPlan::Redelegate(u) => u.gas_cost(),
             ActionPlan::ValidatorDefinition(vd) => vd.gas_cost(),
             ActionPlan::IbcAction(i) => i.gas_cost(),
             ActionPlan::ProposalSubmit(ps) => ps.gas_cost(),
             ActionPlan::ProposalWithdraw(pw) => pw.gas_cost(),
             ActionPlan::ValidatorVote(v) => v.gas_cost(),
+            ActionPlan::ProxyVote(v) => v.gas_cost(),
             ActionPlan::ProposalDepositClaim(pdc) => pdc.gas_cost(),
             ActionPlan::PositionOpen(po) => po.gas_cost(),
             ActionPlan::PositionClose(pc) => pc.gas_cost(),
             ActionPlan::CommunityPoolSpend(ds) => ds.gas_cost(),
             ActionPlan::CommunityPoolOutput(d) => d.gas_cost(),
             ActionPlan::CommunityPoolDeposit(dd) => dd.gas_cost(),
+            ActionPlan::CommunityPoolVestingClaim(claim) => claim.gas_cost(),
             ActionPlan::Ics20Withdrawal(w) => w.gas_cost(),
         }
     }
@@ -350,6 +372,7 @@ impl GasCost for Action {
             Action::Spend(spend) => spend.gas_cost(),
             Action::Delegate(delegate) => delegate.gas_cost(),
             Action::Undelegate(undelegate) => undelegate.gas_cost(),
+            Action::Redelegate(redelegate) => redelegate.gas_cost(),
             Action::UndelegateClaim(undelegate_claim) => undelegate_claim.gas_cost(),
             Action::Swap(swap) => swap.gas_cost(),
             Action::SwapClaim(swap_claim) => swap_claim.gas_cost(),
@@ -357,6 +380,7 @@ impl GasCost for Action {
             Action::ProposalWithdraw(withdraw) => withdraw.gas_cost(),
             Action::DelegatorVote(delegator_vote) => delegator_vote.gas_cost(),
             Action::ValidatorVote(validator_vote) => validator_vote.gas_cost(),
+            Action::ProxyVote(proxy_vote) => proxy_vote.gas_cost(),
             Action::ProposalDepositClaim(p) => p.gas_cost(),
             Action::PositionOpen(p) => p.gas_cost(),
             Action::PositionClose(p) => p.gas_cost(),
@@ -365,6 +389,7 @@ impl GasCost for Action {
             Action::CommunityPoolDeposit(deposit) => deposit.gas_cost(),
             Action::CommunityPoolSpend(spend) => spend.gas_cost(),
             Action::CommunityPoolOutput(output) => output.gas_cost(),
+            Action::CommunityPoolVestingClaim(claim) => claim.gas_cost(),
             Action::IbcRelay(x) => x.gas_cost(),
             Action::ValidatorDefinition(x) => x.gas_cost(),
             Action::ActionDutchAuctionSchedule(action_dutch_auction_schedule) => {
@@ -404,6 +429,12 @@ impl GasCost for Undelegate {
     }
 }
 
+impl GasCost for Redelegate {
+    fn gas_cost(&self) -> Gas {
+        redelegate_gas_cost(&self)
+    }
+}
+
 impl GasCost for UndelegateClaim {
     fn gas_cost(&self) -> Gas {
         undelegate_claim_gas_cost()
@@ -480,6 +511,23 @@ impl GasCost for ValidatorVote {
     }
 }
 
+impl GasCost for ProxyVote {
+    fn gas_cost(&self) -> Gas {
+        Gas {
+            // The block space measured as the byte length of the encoded action.
+            block_space: self.encode_to_vec().len() as u64,
+            // The compact block space cost is based on the byte size of the data the [`Action`] adds
+            // to the compact block.
+            // For a ProxyVote the compact block is not modified.
+            compact_block_space: 0,
+            // Includes a signature verification, so we include a small constant verification cost.
+            verification: 200,
+            // Execution cost is currently hardcoded at 10 for all Action variants.
+            execution: 10,
+        }
+    }
+}
+
 impl GasCost for ProposalDepositClaim {
     fn gas_cost(&self) -> Gas {
         Gas {
@@ -604,6 +652,23 @@ impl GasCost for CommunityPoolOutput {
     }
 }
 
+impl GasCost for CommunityPoolVestingClaim {
+    fn gas_cost(&self) -> Gas {
+        Gas {
+            // The block space measured as the byte length of the encoded action.
+            block_space: self.encode_to_vec().len() as u64,
+            // The compact block space cost is based on the byte size of the data the [`Action`] adds
+            // to the compact block.
+            // For a CommunityPoolVestingClaim this is the note minted to the grant recipient.
+            compact_block_space: NOTEPAYLOAD_SIZE,
+            // Does not include a zk-SNARK proof, so there's no verification cost.
+            verification: 0,
+            // Execution cost is currently hardcoded at 10 for all Action variants.
+            execution: 10,
+        }
+    }
+}
+
 impl GasCost for IbcRelay {
     fn gas_cost(&self) -> Gas {
         Gas {
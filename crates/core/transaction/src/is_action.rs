@@ -5,7 +5,9 @@ use penumbra_auction::auction::dutch::actions::{
     view::{ActionDutchAuctionScheduleView, ActionDutchAuctionWithdrawView},
     ActionDutchAuctionEnd, ActionDutchAuctionSchedule, ActionDutchAuctionWithdraw,
 };
-use penumbra_community_pool::{CommunityPoolDeposit, CommunityPoolOutput, CommunityPoolSpend};
+use penumbra_community_pool::{
+    CommunityPoolDeposit, CommunityPoolOutput, CommunityPoolSpend, CommunityPoolVestingClaim,
+};
 use penumbra_dex::{
     lp::{
         action::{PositionClose, PositionOpen, PositionWithdraw},
@@ -16,11 +18,11 @@ use penumbra_dex::{
 };
 use penumbra_governance::{
     DelegatorVote, DelegatorVoteView, ProposalDepositClaim, ProposalSubmit, ProposalWithdraw,
-    ValidatorVote, VotingReceiptToken,
+    ProxyVote, ValidatorVote, VotingReceiptToken,
 };
 use penumbra_ibc::IbcRelay;
 use penumbra_shielded_pool::{Ics20Withdrawal, Note, Output, OutputView, Spend, SpendView};
-use penumbra_stake::{Delegate, Undelegate, UndelegateClaim};
+use penumbra_stake::{Delegate, Redelegate, Undelegate, UndelegateClaim};
 
 use crate::{Action, ActionView, TransactionPerspective};
 
@@ -105,6 +107,16 @@ impl IsAction for ValidatorVote {
     }
 }
 
+impl IsAction for ProxyVote {
+    fn balance_commitment(&self) -> balance::Commitment {
+        Default::default()
+    }
+
+    fn view_from_perspective(&self, _txp: &TransactionPerspective) -> ActionView {
+        ActionView::ProxyVote(self.to_owned())
+    }
+}
+
 impl IsAction for Output {
     fn balance_commitment(&self) -> balance::Commitment {
         self.body.balance_commitment
@@ -189,6 +201,16 @@ impl IsAction for Undelegate {
     }
 }
 
+impl IsAction for Redelegate {
+    fn balance_commitment(&self) -> balance::Commitment {
+        self.balance().commit(Fr::zero())
+    }
+
+    fn view_from_perspective(&self, _txp: &TransactionPerspective) -> ActionView {
+        ActionView::Redelegate(self.to_owned())
+    }
+}
+
 impl IsAction for UndelegateClaim {
     fn balance_commitment(&self) -> balance::Commitment {
         self.body.balance_commitment
@@ -240,6 +262,17 @@ impl IsAction for CommunityPoolOutput {
     }
 }
 
+impl IsAction for CommunityPoolVestingClaim {
+    fn balance_commitment(&self) -> balance::Commitment {
+        // Vested funds are minted directly to the grant recipient, so claims have no balance.
+        self.balance().commit(Fr::zero())
+    }
+
+    fn view_from_perspective(&self, _txp: &TransactionPerspective) -> ActionView {
+        ActionView::CommunityPoolVestingClaim(self.clone())
+    }
+}
+
 impl IsAction for CommunityPoolSpend {
     fn balance_commitment(&self) -> balance::Commitment {
         self.balance().commit(Fr::zero())
@@ -3,7 +3,9 @@
 
 use anyhow::Result;
 use decaf377_fmd::Precision;
-use penumbra_community_pool::{CommunityPoolDeposit, CommunityPoolOutput, CommunityPoolSpend};
+use penumbra_community_pool::{
+    CommunityPoolDeposit, CommunityPoolOutput, CommunityPoolSpend, CommunityPoolVestingClaim,
+};
 use penumbra_dex::{
     lp::action::{PositionClose, PositionOpen},
     lp::plan::PositionWithdrawPlan,
@@ -11,13 +13,14 @@ use penumbra_dex::{
     swap_claim::SwapClaimPlan,
 };
 use penumbra_governance::{
-    DelegatorVotePlan, ProposalDepositClaim, ProposalSubmit, ProposalWithdraw, ValidatorVote,
+    DelegatorVotePlan, ProposalDepositClaim, ProposalSubmit, ProposalWithdraw, ProxyVote,
+    ValidatorVote,
 };
 use penumbra_ibc::IbcRelay;
 use penumbra_keys::{Address, FullViewingKey, PayloadKey};
 use penumbra_proto::{core::transaction::v1 as pb, DomainType};
 use penumbra_shielded_pool::{Ics20Withdrawal, OutputPlan, SpendPlan};
-use penumbra_stake::{Delegate, Undelegate, UndelegateClaimPlan};
+use penumbra_stake::{Delegate, Redelegate, Undelegate, UndelegateClaimPlan};
 use penumbra_txhash::{EffectHash, EffectingData};
 use rand::{CryptoRng, Rng};
 use serde::{Deserialize, Serialize};
@@ -151,6 +154,16 @@ impl TransactionPlan {
         })
     }
 
+    pub fn redelegations(&self) -> impl Iterator<Item = &Redelegate> {
+        self.actions.iter().filter_map(|action| {
+            if let ActionPlan::Redelegate(d) = action {
+                Some(d)
+            } else {
+                None
+            }
+        })
+    }
+
     pub fn undelegate_claim_plans(&self) -> impl Iterator<Item = &UndelegateClaimPlan> {
         self.actions.iter().filter_map(|action| {
             if let ActionPlan::UndelegateClaim(d) = action {
@@ -223,6 +236,16 @@ impl TransactionPlan {
         })
     }
 
+    pub fn proxy_votes(&self) -> impl Iterator<Item = &ProxyVote> {
+        self.actions.iter().filter_map(|action| {
+            if let ActionPlan::ProxyVote(v) = action {
+                Some(v)
+            } else {
+                None
+            }
+        })
+    }
+
     pub fn proposal_deposit_claims(&self) -> impl Iterator<Item = &ProposalDepositClaim> {
         self.actions.iter().filter_map(|action| {
             if let ActionPlan::ProposalDepositClaim(p) = action {
@@ -283,6 +306,18 @@ impl TransactionPlan {
         })
     }
 
+    pub fn community_pool_vesting_claims(
+        &self,
+    ) -> impl Iterator<Item = &CommunityPoolVestingClaim> {
+        self.actions.iter().filter_map(|action| {
+            if let ActionPlan::CommunityPoolVestingClaim(v) = action {
+                Some(v)
+            } else {
+                None
+            }
+        })
+    }
+
     pub fn position_openings(&self) -> impl Iterator<Item = &PositionOpen> {
         self.actions.iter().filter_map(|action| {
             if let ActionPlan::PositionOpen(v) = action {
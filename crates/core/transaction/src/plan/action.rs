@@ -7,7 +7,9 @@ use penumbra_asset::Balance;
 use penumbra_auction::auction::dutch::actions::ActionDutchAuctionEnd;
 use penumbra_auction::auction::dutch::actions::ActionDutchAuctionSchedule;
 use penumbra_auction::auction::dutch::actions::ActionDutchAuctionWithdrawPlan;
-use penumbra_community_pool::{CommunityPoolDeposit, CommunityPoolOutput, CommunityPoolSpend};
+use penumbra_community_pool::{
+    CommunityPoolDeposit, CommunityPoolOutput, CommunityPoolSpend, CommunityPoolVestingClaim,
+};
 use penumbra_txhash::{EffectHash, EffectingData};
 
 use penumbra_dex::{
@@ -20,14 +22,14 @@ use penumbra_dex::{
 };
 use penumbra_governance::{
     delegator_vote::DelegatorVotePlan, ProposalDepositClaim, ProposalSubmit, ProposalWithdraw,
-    ValidatorVote,
+    ProxyVote, ValidatorVote,
 };
 
 use penumbra_ibc::IbcRelay;
 use penumbra_keys::{symmetric::PayloadKey, FullViewingKey};
 use penumbra_proto::{core::transaction::v1 as pb_t, DomainType};
 use penumbra_shielded_pool::{Ics20Withdrawal, OutputPlan, SpendPlan};
-use penumbra_stake::{Delegate, Undelegate, UndelegateClaimPlan};
+use penumbra_stake::{Delegate, Redelegate, Undelegate, UndelegateClaimPlan};
 use serde::{Deserialize, Serialize};
 
 /// A declaration of a planned [`Action`], for use in transaction creation.
@@ -49,6 +51,8 @@ pub enum ActionPlan {
     /// We don't need any extra information (yet) to understand undelegations,
     /// because we don't yet use flow encryption.
     Undelegate(Undelegate),
+    /// Like undelegations, redelegations need no extra information to be understood.
+    Redelegate(Redelegate),
     UndelegateClaim(UndelegateClaimPlan),
     ValidatorDefinition(penumbra_stake::validator::Definition),
     /// Describes a proposed swap.
@@ -64,6 +68,8 @@ pub enum ActionPlan {
     DelegatorVote(DelegatorVotePlan),
     /// Vote on a proposal as a validator.
     ValidatorVote(ValidatorVote),
+    /// Vote on a proposal as a proxy for delegators.
+    ProxyVote(ProxyVote),
     /// Claim the deposit for a finished proposal.
     ProposalDepositClaim(ProposalDepositClaim),
 
@@ -76,6 +82,7 @@ pub enum ActionPlan {
     CommunityPoolSpend(CommunityPoolSpend),
     CommunityPoolOutput(CommunityPoolOutput),
     CommunityPoolDeposit(CommunityPoolDeposit),
+    CommunityPoolVestingClaim(CommunityPoolVestingClaim),
 
     Ics20Withdrawal(Ics20Withdrawal),
 
@@ -137,6 +144,7 @@ impl ActionPlan {
             }
             Delegate(plan) => Action::Delegate(plan.clone()),
             Undelegate(plan) => Action::Undelegate(plan.clone()),
+            Redelegate(plan) => Action::Redelegate(plan.clone()),
             UndelegateClaim(plan) => Action::UndelegateClaim(plan.undelegate_claim()),
             ValidatorDefinition(plan) => Action::ValidatorDefinition(plan.clone()),
             // Fixme: action name
@@ -152,6 +160,7 @@ impl ActionPlan {
                 Action::DelegatorVote(plan.delegator_vote(fvk, [0; 64].into(), auth_path.clone()))
             }
             ValidatorVote(plan) => Action::ValidatorVote(plan.clone()),
+            ProxyVote(plan) => Action::ProxyVote(plan.clone()),
             ProposalDepositClaim(plan) => Action::ProposalDepositClaim(plan.clone()),
             PositionOpen(plan) => Action::PositionOpen(plan.clone()),
             PositionClose(plan) => Action::PositionClose(plan.clone()),
@@ -159,6 +168,7 @@ impl ActionPlan {
             CommunityPoolSpend(plan) => Action::CommunityPoolSpend(plan.clone()),
             CommunityPoolOutput(plan) => Action::CommunityPoolOutput(plan.clone()),
             CommunityPoolDeposit(plan) => Action::CommunityPoolDeposit(plan.clone()),
+            CommunityPoolVestingClaim(plan) => Action::CommunityPoolVestingClaim(plan.clone()),
             Ics20Withdrawal(plan) => Action::Ics20Withdrawal(plan.clone()),
             ActionDutchAuctionSchedule(plan) => Action::ActionDutchAuctionSchedule(plan.clone()),
             ActionDutchAuctionEnd(plan) => Action::ActionDutchAuctionEnd(plan.clone()),
@@ -181,6 +191,7 @@ impl ActionPlan {
             ActionPlan::ProposalWithdraw(_) => 19,
             ActionPlan::ValidatorVote(_) => 20,
             ActionPlan::DelegatorVote(_) => 21,
+            ActionPlan::ProxyVote(_) => 57,
             ActionPlan::ProposalDepositClaim(_) => 22,
             ActionPlan::PositionOpen(_) => 30,
             ActionPlan::PositionClose(_) => 31,
@@ -188,9 +199,11 @@ impl ActionPlan {
             ActionPlan::Delegate(_) => 40,
             ActionPlan::Undelegate(_) => 41,
             ActionPlan::UndelegateClaim(_) => 42,
+            ActionPlan::Redelegate(_) => 43,
             ActionPlan::CommunityPoolSpend(_) => 50,
             ActionPlan::CommunityPoolOutput(_) => 51,
             ActionPlan::CommunityPoolDeposit(_) => 52,
+            ActionPlan::CommunityPoolVestingClaim(_) => 56,
             ActionPlan::Ics20Withdrawal(_) => 200,
             ActionPlan::ActionDutchAuctionSchedule(_) => 53,
             ActionPlan::ActionDutchAuctionEnd(_) => 54,
@@ -206,6 +219,7 @@ impl ActionPlan {
             Output(output) => output.balance(),
             Delegate(delegate) => delegate.balance(),
             Undelegate(undelegate) => undelegate.balance(),
+            Redelegate(redelegate) => redelegate.balance(),
             UndelegateClaim(undelegate_claim) => undelegate_claim.balance(),
             Swap(swap) => swap.balance(),
             SwapClaim(swap_claim) => swap_claim.balance(),
@@ -216,6 +230,7 @@ impl ActionPlan {
             CommunityPoolSpend(community_pool_spend) => community_pool_spend.balance(),
             CommunityPoolOutput(community_pool_output) => community_pool_output.balance(),
             CommunityPoolDeposit(community_pool_deposit) => community_pool_deposit.balance(),
+            CommunityPoolVestingClaim(claim) => claim.balance(),
             PositionOpen(position_open) => position_open.balance(),
             PositionClose(position_close) => position_close.balance(),
             PositionWithdraw(position_withdraw) => position_withdraw.balance(),
@@ -225,7 +240,9 @@ impl ActionPlan {
             ActionDutchAuctionWithdraw(action) => action.balance(),
 
             // None of these contribute to transaction balance:
-            IbcAction(_) | ValidatorDefinition(_) | ValidatorVote(_) => Balance::default(),
+            IbcAction(_) | ValidatorDefinition(_) | ValidatorVote(_) | ProxyVote(_) => {
+                Balance::default()
+            }
         }
     }
 
@@ -237,6 +254,7 @@ impl ActionPlan {
             Output(output) => output.value_blinding,
             Delegate(_) => Fr::zero(),
             Undelegate(_) => Fr::zero(),
+            Redelegate(_) => Fr::zero(),
             UndelegateClaim(undelegate_claim) => undelegate_claim.balance_blinding,
             ValidatorDefinition(_) => Fr::zero(),
             Swap(swap) => swap.fee_blinding,
@@ -246,6 +264,7 @@ impl ActionPlan {
             ProposalWithdraw(_) => Fr::zero(),
             DelegatorVote(_) => Fr::zero(),
             ValidatorVote(_) => Fr::zero(),
+            ProxyVote(_) => Fr::zero(),
             ProposalDepositClaim(_) => Fr::zero(),
             PositionOpen(_) => Fr::zero(),
             PositionClose(_) => Fr::zero(),
@@ -253,6 +272,7 @@ impl ActionPlan {
             CommunityPoolSpend(_) => Fr::zero(),
             CommunityPoolOutput(_) => Fr::zero(),
             CommunityPoolDeposit(_) => Fr::zero(),
+            CommunityPoolVestingClaim(_) => Fr::zero(),
             Ics20Withdrawal(_) => Fr::zero(),
             ActionDutchAuctionSchedule(_) => Fr::zero(),
             ActionDutchAuctionEnd(_) => Fr::zero(),
@@ -269,6 +289,7 @@ impl ActionPlan {
             Output(plan) => plan.output_body(fvk.outgoing(), memo_key).effect_hash(),
             Delegate(plan) => plan.effect_hash(),
             Undelegate(plan) => plan.effect_hash(),
+            Redelegate(plan) => plan.effect_hash(),
             UndelegateClaim(plan) => plan.undelegate_claim_body().effect_hash(),
             ValidatorDefinition(plan) => plan.effect_hash(),
             Swap(plan) => plan.swap_body(fvk).effect_hash(),
@@ -278,6 +299,7 @@ impl ActionPlan {
             ProposalWithdraw(plan) => plan.effect_hash(),
             DelegatorVote(plan) => plan.delegator_vote_body(fvk).effect_hash(),
             ValidatorVote(plan) => plan.effect_hash(),
+            ProxyVote(plan) => plan.effect_hash(),
             ProposalDepositClaim(plan) => plan.effect_hash(),
             PositionOpen(plan) => plan.effect_hash(),
             PositionClose(plan) => plan.effect_hash(),
@@ -285,6 +307,7 @@ impl ActionPlan {
             CommunityPoolSpend(plan) => plan.effect_hash(),
             CommunityPoolOutput(plan) => plan.effect_hash(),
             CommunityPoolDeposit(plan) => plan.effect_hash(),
+            CommunityPoolVestingClaim(plan) => plan.effect_hash(),
             Ics20Withdrawal(plan) => plan.effect_hash(),
             ActionDutchAuctionSchedule(plan) => plan.effect_hash(),
             ActionDutchAuctionEnd(plan) => plan.effect_hash(),
@@ -331,6 +354,12 @@ impl From<Undelegate> for ActionPlan {
     }
 }
 
+impl From<Redelegate> for ActionPlan {
+    fn from(inner: Redelegate) -> ActionPlan {
+        ActionPlan::Redelegate(inner)
+    }
+}
+
 impl From<UndelegateClaimPlan> for ActionPlan {
     fn from(inner: UndelegateClaimPlan) -> ActionPlan {
         ActionPlan::UndelegateClaim(inner)
@@ -367,6 +396,12 @@ impl From<ValidatorVote> for ActionPlan {
     }
 }
 
+impl From<ProxyVote> for ActionPlan {
+    fn from(inner: ProxyVote) -> ActionPlan {
+        ActionPlan::ProxyVote(inner)
+    }
+}
+
 impl From<PositionOpen> for ActionPlan {
     fn from(inner: PositionOpen) -> ActionPlan {
         ActionPlan::PositionOpen(inner)
@@ -403,6 +438,12 @@ impl From<CommunityPoolDeposit> for ActionPlan {
     }
 }
 
+impl From<CommunityPoolVestingClaim> for ActionPlan {
+    fn from(inner: CommunityPoolVestingClaim) -> ActionPlan {
+        ActionPlan::CommunityPoolVestingClaim(inner)
+    }
+}
+
 impl From<Ics20Withdrawal> for ActionPlan {
     fn from(inner: Ics20Withdrawal) -> ActionPlan {
         ActionPlan::Ics20Withdrawal(inner)
@@ -458,6 +499,9 @@ impl From<ActionPlan> for pb_t::ActionPlan {
             ActionPlan::Undelegate(inner) => pb_t::ActionPlan {
                 action: Some(pb_t::action_plan::Action::Undelegate(inner.into())),
             },
+            ActionPlan::Redelegate(inner) => pb_t::ActionPlan {
+                action: Some(pb_t::action_plan::Action::Redelegate(inner.into())),
+            },
             ActionPlan::UndelegateClaim(inner) => pb_t::ActionPlan {
                 action: Some(pb_t::action_plan::Action::UndelegateClaim(inner.into())),
             },
@@ -485,6 +529,9 @@ impl From<ActionPlan> for pb_t::ActionPlan {
             ActionPlan::ValidatorVote(inner) => pb_t::ActionPlan {
                 action: Some(pb_t::action_plan::Action::ValidatorVote(inner.into())),
             },
+            ActionPlan::ProxyVote(inner) => pb_t::ActionPlan {
+                action: Some(pb_t::action_plan::Action::ProxyVote(inner.into())),
+            },
             ActionPlan::ProposalDepositClaim(inner) => pb_t::ActionPlan {
                 action: Some(pb_t::action_plan::Action::ProposalDepositClaim(
                     inner.into(),
@@ -514,6 +561,11 @@ impl From<ActionPlan> for pb_t::ActionPlan {
             ActionPlan::CommunityPoolOutput(inner) => pb_t::ActionPlan {
                 action: Some(pb_t::action_plan::Action::CommunityPoolOutput(inner.into())),
             },
+            ActionPlan::CommunityPoolVestingClaim(inner) => pb_t::ActionPlan {
+                action: Some(pb_t::action_plan::Action::CommunityPoolVestingClaim(
+                    inner.into(),
+                )),
+            },
             ActionPlan::Ics20Withdrawal(inner) => pb_t::ActionPlan {
                 action: Some(pb_t::action_plan::Action::Ics20Withdrawal(inner.into())),
             },
@@ -556,6 +608,9 @@ impl TryFrom<pb_t::ActionPlan> for ActionPlan {
             pb_t::action_plan::Action::Undelegate(inner) => {
                 Ok(ActionPlan::Undelegate(inner.try_into()?))
             }
+            pb_t::action_plan::Action::Redelegate(inner) => {
+                Ok(ActionPlan::Redelegate(inner.try_into()?))
+            }
             pb_t::action_plan::Action::UndelegateClaim(inner) => {
                 Ok(ActionPlan::UndelegateClaim(inner.try_into()?))
             }
@@ -578,6 +633,9 @@ impl TryFrom<pb_t::ActionPlan> for ActionPlan {
             pb_t::action_plan::Action::ValidatorVote(inner) => {
                 Ok(ActionPlan::ValidatorVote(inner.try_into()?))
             }
+            pb_t::action_plan::Action::ProxyVote(inner) => {
+                Ok(ActionPlan::ProxyVote(inner.try_into()?))
+            }
             pb_t::action_plan::Action::DelegatorVote(inner) => {
                 Ok(ActionPlan::DelegatorVote(inner.try_into()?))
             }
@@ -605,6 +663,9 @@ impl TryFrom<pb_t::ActionPlan> for ActionPlan {
             pb_t::action_plan::Action::CommunityPoolOutput(inner) => {
                 Ok(ActionPlan::CommunityPoolOutput(inner.try_into()?))
             }
+            pb_t::action_plan::Action::CommunityPoolVestingClaim(inner) => {
+                Ok(ActionPlan::CommunityPoolVestingClaim(inner.try_into()?))
+            }
             pb_t::action_plan::Action::ActionDutchAuctionSchedule(inner) => {
                 Ok(ActionPlan::ActionDutchAuctionSchedule(inner.try_into()?))
             }
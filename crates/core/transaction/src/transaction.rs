@@ -8,12 +8,16 @@ use ark_ff::Zero;
 use decaf377::Fr;
 use decaf377_rdsa::{Binding, Signature, VerificationKey, VerificationKeyBytes};
 use penumbra_asset::Balance;
-use penumbra_community_pool::{CommunityPoolDeposit, CommunityPoolOutput, CommunityPoolSpend};
+use penumbra_community_pool::{
+    CommunityPoolDeposit, CommunityPoolOutput, CommunityPoolSpend, CommunityPoolVestingClaim,
+};
 use penumbra_dex::{
     lp::action::{PositionClose, PositionOpen},
     swap::Swap,
 };
-use penumbra_governance::{DelegatorVote, ProposalSubmit, ProposalWithdraw, ValidatorVote};
+use penumbra_governance::{
+    DelegatorVote, ProposalSubmit, ProposalWithdraw, ProxyVote, ValidatorVote,
+};
 use penumbra_ibc::IbcRelay;
 use penumbra_keys::{AddressView, FullViewingKey, PayloadKey};
 use penumbra_proto::{
@@ -22,7 +26,7 @@ use penumbra_proto::{
 };
 use penumbra_sct::Nullifier;
 use penumbra_shielded_pool::{Note, Output, Spend};
-use penumbra_stake::{Delegate, Undelegate, UndelegateClaim};
+use penumbra_stake::{Delegate, Redelegate, Undelegate, UndelegateClaim};
 use penumbra_tct as tct;
 use penumbra_tct::StateCommitment;
 use penumbra_txhash::{
@@ -258,12 +262,14 @@ impl Transaction {
                 | Action::Spend(_)
                 | Action::Delegate(_)
                 | Action::Undelegate(_)
+                | Action::Redelegate(_)
                 | Action::UndelegateClaim(_)
                 | Action::ValidatorDefinition(_)
                 | Action::IbcRelay(_)
                 | Action::ProposalSubmit(_)
                 | Action::ProposalWithdraw(_)
                 | Action::ValidatorVote(_)
+                | Action::ProxyVote(_)
                 | Action::DelegatorVote(_)
                 | Action::ProposalDepositClaim(_)
                 | Action::PositionOpen(_)
@@ -272,6 +278,7 @@ impl Transaction {
                 | Action::Ics20Withdrawal(_)
                 | Action::CommunityPoolSpend(_)
                 | Action::CommunityPoolOutput(_)
+                | Action::CommunityPoolVestingClaim(_)
                 | Action::CommunityPoolDeposit(_) => {}
                 Action::ActionDutchAuctionSchedule(_) => {}
                 Action::ActionDutchAuctionEnd(_) => {}
@@ -375,6 +382,16 @@ impl Transaction {
         })
     }
 
+    pub fn redelegations(&self) -> impl Iterator<Item = &Redelegate> {
+        self.actions().filter_map(|action| {
+            if let Action::Redelegate(d) = action {
+                Some(d)
+            } else {
+                None
+            }
+        })
+    }
+
     pub fn undelegate_claims(&self) -> impl Iterator<Item = &UndelegateClaim> {
         self.actions().filter_map(|action| {
             if let Action::UndelegateClaim(d) = action {
@@ -415,6 +432,16 @@ impl Transaction {
         })
     }
 
+    pub fn proxy_votes(&self) -> impl Iterator<Item = &ProxyVote> {
+        self.actions().filter_map(|action| {
+            if let Action::ProxyVote(v) = action {
+                Some(v)
+            } else {
+                None
+            }
+        })
+    }
+
     pub fn delegator_votes(&self) -> impl Iterator<Item = &DelegatorVote> {
         self.actions().filter_map(|action| {
             if let Action::DelegatorVote(v) = action {
@@ -539,6 +566,18 @@ impl Transaction {
         })
     }
 
+    pub fn community_pool_vesting_claims(
+        &self,
+    ) -> impl Iterator<Item = &CommunityPoolVestingClaim> {
+        self.actions().filter_map(|action| {
+            if let Action::CommunityPoolVestingClaim(c) = action {
+                Some(c)
+            } else {
+                None
+            }
+        })
+    }
+
     pub fn position_openings(&self) -> impl Iterator<Item = &PositionOpen> {
         self.actions().filter_map(|action| {
             if let Action::PositionOpen(d) = action {
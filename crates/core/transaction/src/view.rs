@@ -693,16 +693,19 @@ mod test {
             ActionPlan::ProposalWithdraw(_) => None,
             ActionPlan::DelegatorVote(_) => None,
             ActionPlan::ValidatorVote(_) => None,
+            ActionPlan::ProxyVote(_) => None,
             ActionPlan::ProposalDepositClaim(_) => None,
             ActionPlan::PositionOpen(_) => None,
             ActionPlan::PositionClose(_) => None,
             ActionPlan::PositionWithdraw(_) => None,
             ActionPlan::Delegate(_) => None,
             ActionPlan::Undelegate(_) => None,
+            ActionPlan::Redelegate(_) => None,
             ActionPlan::UndelegateClaim(_) => None,
             ActionPlan::Ics20Withdrawal(_) => None,
             ActionPlan::CommunityPoolSpend(_) => None,
             ActionPlan::CommunityPoolOutput(_) => None,
+            ActionPlan::CommunityPoolVestingClaim(_) => None,
             ActionPlan::CommunityPoolDeposit(_) => None,
             ActionPlan::ActionDutchAuctionSchedule(_) => None,
             ActionPlan::ActionDutchAuctionEnd(_) => None,
@@ -2,17 +2,21 @@ use penumbra_auction::auction::dutch::{
     actions::view::{ActionDutchAuctionScheduleView, ActionDutchAuctionWithdrawView},
     ActionDutchAuctionEnd,
 };
-use penumbra_community_pool::{CommunityPoolDeposit, CommunityPoolOutput, CommunityPoolSpend};
+use penumbra_community_pool::{
+    CommunityPoolDeposit, CommunityPoolOutput, CommunityPoolSpend, CommunityPoolVestingClaim,
+};
 use penumbra_dex::{
     lp::action::{PositionClose, PositionOpen, PositionWithdraw},
     swap::SwapView,
     swap_claim::SwapClaimView,
 };
-use penumbra_governance::{ProposalDepositClaim, ProposalSubmit, ProposalWithdraw, ValidatorVote};
+use penumbra_governance::{
+    ProposalDepositClaim, ProposalSubmit, ProposalWithdraw, ProxyVote, ValidatorVote,
+};
 use penumbra_ibc::IbcRelay;
 use penumbra_proto::{core::transaction::v1 as pbt, DomainType};
 use penumbra_shielded_pool::Ics20Withdrawal;
-use penumbra_stake::{Delegate, Undelegate, UndelegateClaim};
+use penumbra_stake::{Delegate, Redelegate, Undelegate, UndelegateClaim};
 use serde::{Deserialize, Serialize};
 
 pub use penumbra_governance::DelegatorVoteView;
@@ -37,17 +41,20 @@ pub enum ActionView {
     ProposalSubmit(ProposalSubmit),
     ProposalWithdraw(ProposalWithdraw),
     ValidatorVote(ValidatorVote),
+    ProxyVote(ProxyVote),
     ProposalDepositClaim(ProposalDepositClaim),
     PositionOpen(PositionOpen),
     PositionClose(PositionClose),
     PositionWithdraw(PositionWithdraw),
     Delegate(Delegate),
     Undelegate(Undelegate),
+    Redelegate(Redelegate),
     UndelegateClaim(UndelegateClaim),
     Ics20Withdrawal(Ics20Withdrawal),
     CommunityPoolDeposit(CommunityPoolDeposit),
     CommunityPoolSpend(CommunityPoolSpend),
     CommunityPoolOutput(CommunityPoolOutput),
+    CommunityPoolVestingClaim(CommunityPoolVestingClaim),
     ActionDutchAuctionSchedule(ActionDutchAuctionScheduleView),
     ActionDutchAuctionEnd(ActionDutchAuctionEnd),
     ActionDutchAuctionWithdraw(ActionDutchAuctionWithdrawView),
@@ -71,6 +78,7 @@ impl TryFrom<pbt::ActionView> for ActionView {
                 AV::Spend(x) => ActionView::Spend(x.try_into()?),
                 AV::Output(x) => ActionView::Output(x.try_into()?),
                 AV::Undelegate(x) => ActionView::Undelegate(x.try_into()?),
+                AV::Redelegate(x) => ActionView::Redelegate(x.try_into()?),
                 AV::UndelegateClaim(x) => ActionView::UndelegateClaim(x.try_into()?),
                 AV::Swap(x) => ActionView::Swap(x.try_into()?),
                 AV::SwapClaim(x) => ActionView::SwapClaim(x.try_into()?),
@@ -80,6 +88,7 @@ impl TryFrom<pbt::ActionView> for ActionView {
                 AV::ProposalWithdraw(x) => ActionView::ProposalWithdraw(x.try_into()?),
                 AV::ProposalDepositClaim(x) => ActionView::ProposalDepositClaim(x.try_into()?),
                 AV::ValidatorVote(x) => ActionView::ValidatorVote(x.try_into()?),
+                AV::ProxyVote(x) => ActionView::ProxyVote(x.try_into()?),
                 AV::DelegatorVote(x) => ActionView::DelegatorVote(x.try_into()?),
                 AV::PositionOpen(x) => ActionView::PositionOpen(x.try_into()?),
                 AV::PositionClose(x) => ActionView::PositionClose(x.try_into()?),
@@ -93,6 +102,9 @@ impl TryFrom<pbt::ActionView> for ActionView {
                 AV::CommunityPoolDeposit(x) => ActionView::CommunityPoolDeposit(x.try_into()?),
                 AV::CommunityPoolSpend(x) => ActionView::CommunityPoolSpend(x.try_into()?),
                 AV::CommunityPoolOutput(x) => ActionView::CommunityPoolOutput(x.try_into()?),
+                AV::CommunityPoolVestingClaim(x) => {
+                    ActionView::CommunityPoolVestingClaim(x.try_into()?)
+                }
                 AV::ActionDutchAuctionSchedule(x) => {
                     ActionView::ActionDutchAuctionSchedule(x.try_into()?)
                 }
@@ -116,12 +128,14 @@ impl From<ActionView> for pbt::ActionView {
                 ActionView::Spend(x) => AV::Spend(x.into()),
                 ActionView::Delegate(x) => AV::Delegate(x.into()),
                 ActionView::Undelegate(x) => AV::Undelegate(x.into()),
+                ActionView::Redelegate(x) => AV::Redelegate(x.into()),
                 ActionView::UndelegateClaim(x) => AV::UndelegateClaim(x.into()),
                 ActionView::ValidatorDefinition(x) => AV::ValidatorDefinition(x.into()),
                 ActionView::IbcRelay(x) => AV::IbcRelayAction(x.into()),
                 ActionView::ProposalSubmit(x) => AV::ProposalSubmit(x.into()),
                 ActionView::ProposalWithdraw(x) => AV::ProposalWithdraw(x.into()),
                 ActionView::ValidatorVote(x) => AV::ValidatorVote(x.into()),
+                ActionView::ProxyVote(x) => AV::ProxyVote(x.into()),
                 ActionView::DelegatorVote(x) => AV::DelegatorVote(x.into()),
                 ActionView::ProposalDepositClaim(x) => AV::ProposalDepositClaim(x.into()),
                 ActionView::PositionOpen(x) => AV::PositionOpen(x.into()),
@@ -131,6 +145,7 @@ impl From<ActionView> for pbt::ActionView {
                 ActionView::CommunityPoolDeposit(x) => AV::CommunityPoolDeposit(x.into()),
                 ActionView::CommunityPoolSpend(x) => AV::CommunityPoolSpend(x.into()),
                 ActionView::CommunityPoolOutput(x) => AV::CommunityPoolOutput(x.into()),
+                ActionView::CommunityPoolVestingClaim(x) => AV::CommunityPoolVestingClaim(x.into()),
                 ActionView::ActionDutchAuctionSchedule(x) => {
                     AV::ActionDutchAuctionSchedule(x.into())
                 }
@@ -152,12 +167,14 @@ impl From<ActionView> for Action {
             ActionView::Spend(x) => Action::Spend(x.into()),
             ActionView::Delegate(x) => Action::Delegate(x),
             ActionView::Undelegate(x) => Action::Undelegate(x),
+            ActionView::Redelegate(x) => Action::Redelegate(x),
             ActionView::UndelegateClaim(x) => Action::UndelegateClaim(x),
             ActionView::ValidatorDefinition(x) => Action::ValidatorDefinition(x),
             ActionView::IbcRelay(x) => Action::IbcRelay(x),
             ActionView::ProposalSubmit(x) => Action::ProposalSubmit(x),
             ActionView::ProposalWithdraw(x) => Action::ProposalWithdraw(x),
             ActionView::ValidatorVote(x) => Action::ValidatorVote(x),
+            ActionView::ProxyVote(x) => Action::ProxyVote(x),
             ActionView::DelegatorVote(x) => Action::DelegatorVote(x.into()),
             ActionView::ProposalDepositClaim(x) => Action::ProposalDepositClaim(x),
             ActionView::PositionOpen(x) => Action::PositionOpen(x),
@@ -167,6 +184,7 @@ impl From<ActionView> for Action {
             ActionView::CommunityPoolDeposit(x) => Action::CommunityPoolDeposit(x),
             ActionView::CommunityPoolSpend(x) => Action::CommunityPoolSpend(x),
             ActionView::CommunityPoolOutput(x) => Action::CommunityPoolOutput(x),
+            ActionView::CommunityPoolVestingClaim(x) => Action::CommunityPoolVestingClaim(x),
             ActionView::ActionDutchAuctionSchedule(x) => {
                 Action::ActionDutchAuctionSchedule(x.into())
             }
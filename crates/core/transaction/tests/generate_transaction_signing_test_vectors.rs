@@ -404,6 +404,8 @@ fn delegator_vote_strategy() -> impl Strategy<Value = DelegatorVotePlan> {
                 start_position: penumbra_tct::Position::from(0u64),
                 staked_note,
                 unbonded_amount,
+                proxy: None,
+                standing_proxy: false,
                 position: penumbra_tct::Position::from(0u64),
                 randomizer: Fr::rand(&mut OsRng),
                 proof_blinding_r: Fq::rand(&mut OsRng),
@@ -259,6 +259,13 @@ impl<T: Terminal + Clone + Send + Sync + 'static> pb::custody_service_server::Cu
             .await
     }
 
+    async fn authorize_proxy_vote(
+        &self,
+        request: Request<pb::AuthorizeProxyVoteRequest>,
+    ) -> Result<Response<pb::AuthorizeProxyVoteResponse>, Status> {
+        self.get_inner().await?.authorize_proxy_vote(request).await
+    }
+
     async fn export_full_viewing_key(
         &self,
         request: Request<pb::ExportFullViewingKeyRequest>,
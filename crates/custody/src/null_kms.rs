@@ -39,6 +39,15 @@ impl pb::custody_service_server::CustodyService for NullKms {
         ))
     }
 
+    async fn authorize_proxy_vote(
+        &self,
+        _request: Request<pb::AuthorizeProxyVoteRequest>,
+    ) -> Result<Response<pb::AuthorizeProxyVoteResponse>, Status> {
+        Err(tonic::Status::failed_precondition(
+            "Got authorization request in view-only mode to null KMS.",
+        ))
+    }
+
     async fn export_full_viewing_key(
         &self,
         _request: Request<pb::ExportFullViewingKeyRequest>,
@@ -6,7 +6,9 @@ use penumbra_keys::Address;
 use penumbra_proto::{
     core::{
         component::{
-            governance::v1::ValidatorVoteBody as ProtoValidatorVoteBody,
+            governance::v1::{
+                ProxyVoteBody as ProtoProxyVoteBody, ValidatorVoteBody as ProtoValidatorVoteBody,
+            },
             stake::v1::Validator as ProtoValidator,
         },
         transaction::v1::TransactionPlan as ProtoTransactionPlan,
@@ -17,8 +19,8 @@ use penumbra_transaction::plan::ActionPlan;
 use serde::{Deserialize, Serialize};
 
 use crate::{
-    AuthorizeRequest, AuthorizeValidatorDefinitionRequest, AuthorizeValidatorVoteRequest,
-    PreAuthorization,
+    AuthorizeProxyVoteRequest, AuthorizeRequest, AuthorizeValidatorDefinitionRequest,
+    AuthorizeValidatorVoteRequest, PreAuthorization,
 };
 
 /// A trait for checking whether a transaction plan is allowed by a policy.
@@ -34,6 +36,9 @@ pub trait Policy {
 
     /// Checks whether the proposed validator vote is allowed by this policy.
     fn check_validator_vote(&self, _request: &AuthorizeValidatorVoteRequest) -> anyhow::Result<()>;
+
+    /// Checks whether the proposed proxy vote is allowed by this policy.
+    fn check_proxy_vote(&self, _request: &AuthorizeProxyVoteRequest) -> anyhow::Result<()>;
 }
 
 /// A set of basic spend authorization policies.
@@ -42,8 +47,8 @@ pub trait Policy {
 /// file.  More complex policy logic than should be implemented by a custom implementation of
 /// the [`Policy`] trait.
 ///
-/// These policies do not permit validator votes or validator definition updates, so a custom policy
-/// must be used to approve these actions.
+/// These policies do not permit validator votes, proxy votes, or validator definition updates, so a
+/// custom policy must be used to approve these actions.
 #[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq)]
 #[serde(tag = "type")]
 pub enum AuthPolicy {
@@ -246,6 +251,10 @@ impl Policy for AuthPolicy {
     fn check_validator_vote(&self, _request: &AuthorizeValidatorVoteRequest) -> anyhow::Result<()> {
         anyhow::bail!("validator votes are not allowed by this policy")
     }
+
+    fn check_proxy_vote(&self, _request: &AuthorizeProxyVoteRequest) -> anyhow::Result<()> {
+        anyhow::bail!("proxy votes are not allowed by this policy")
+    }
 }
 
 impl Policy for PreAuthorizationPolicy {
@@ -272,4 +281,11 @@ impl Policy for PreAuthorizationPolicy {
             ProtoValidatorVoteBody::from(request.validator_vote.clone()).encode_to_vec(),
         )
     }
+
+    fn check_proxy_vote(&self, request: &AuthorizeProxyVoteRequest) -> anyhow::Result<()> {
+        self.check_pre_authorizations(
+            &request.pre_authorizations,
+            ProtoProxyVoteBody::from(request.proxy_vote.clone()).encode_to_vec(),
+        )
+    }
 }
@@ -1,4 +1,4 @@
-use penumbra_governance::ValidatorVoteBody;
+use penumbra_governance::{ProxyVoteBody, ValidatorVoteBody};
 use penumbra_proto::{custody::v1 as pb, DomainType};
 use penumbra_stake::validator::Validator;
 use penumbra_transaction::TransactionPlan;
@@ -133,3 +133,46 @@ impl From<AuthorizeValidatorVoteRequest> for pb::AuthorizeValidatorVoteRequest {
         }
     }
 }
+
+/// A proxy vote authorization request submitted to a custody service for approval.
+#[derive(Debug, Clone)]
+pub struct AuthorizeProxyVoteRequest {
+    /// The proxy vote to authorize.
+    pub proxy_vote: ProxyVoteBody,
+    /// Optionally, pre-authorization data, if required by the custodian.
+    pub pre_authorizations: Vec<PreAuthorization>,
+}
+
+impl DomainType for AuthorizeProxyVoteRequest {
+    type Proto = pb::AuthorizeProxyVoteRequest;
+}
+
+impl TryFrom<pb::AuthorizeProxyVoteRequest> for AuthorizeProxyVoteRequest {
+    type Error = anyhow::Error;
+    fn try_from(value: pb::AuthorizeProxyVoteRequest) -> Result<Self, Self::Error> {
+        Ok(Self {
+            proxy_vote: value
+                .proxy_vote
+                .ok_or_else(|| anyhow::anyhow!("missing proxy vote"))?
+                .try_into()?,
+            pre_authorizations: value
+                .pre_authorizations
+                .into_iter()
+                .map(TryInto::try_into)
+                .collect::<Result<Vec<_>, _>>()?,
+        })
+    }
+}
+
+impl From<AuthorizeProxyVoteRequest> for pb::AuthorizeProxyVoteRequest {
+    fn from(value: AuthorizeProxyVoteRequest) -> pb::AuthorizeProxyVoteRequest {
+        Self {
+            proxy_vote: Some(value.proxy_vote.into()),
+            pre_authorizations: value
+                .pre_authorizations
+                .into_iter()
+                .map(Into::into)
+                .collect(),
+        }
+    }
+}
@@ -4,7 +4,9 @@
 use decaf377_rdsa::{Signature, SpendAuth};
 use penumbra_proto::{
     core::component::{
-        governance::v1::ValidatorVoteBody as ProtoValidatorVoteBody,
+        governance::v1::{
+            ProxyVoteBody as ProtoProxyVoteBody, ValidatorVoteBody as ProtoValidatorVoteBody,
+        },
         stake::v1::Validator as ProtoValidator,
     },
     custody::v1::{self as pb, AuthorizeResponse},
@@ -15,8 +17,8 @@ use rand_core::OsRng;
 use tonic::{async_trait, Request, Response, Status};
 
 use crate::{
-    policy::Policy, AuthorizeRequest, AuthorizeValidatorDefinitionRequest,
-    AuthorizeValidatorVoteRequest,
+    policy::Policy, AuthorizeProxyVoteRequest, AuthorizeRequest,
+    AuthorizeValidatorDefinitionRequest, AuthorizeValidatorVoteRequest,
 };
 
 mod config;
@@ -90,6 +92,28 @@ impl SoftKms {
             .spend_auth_key()
             .sign(OsRng, &validator_vote_bytes))
     }
+
+    /// Attempt to authorize the requested proxy vote.
+    #[tracing::instrument(skip(self, request), name = "softhsm_sign_proxy_vote")]
+    pub fn sign_proxy_vote(
+        &self,
+        request: &AuthorizeProxyVoteRequest,
+    ) -> anyhow::Result<Signature<SpendAuth>> {
+        tracing::debug!(?request.proxy_vote);
+
+        for policy in &self.config.auth_policy {
+            policy.check_proxy_vote(request)?;
+        }
+
+        let protobuf_serialized: ProtoProxyVoteBody = request.proxy_vote.clone().into();
+        let proxy_vote_bytes = protobuf_serialized.encode_to_vec();
+
+        Ok(self
+            .config
+            .spend_key
+            .spend_auth_key()
+            .sign(OsRng, &proxy_vote_bytes))
+    }
 }
 
 #[async_trait]
@@ -154,6 +178,26 @@ impl pb::custody_service_server::CustodyService for SoftKms {
         Ok(Response::new(authorization_response))
     }
 
+    async fn authorize_proxy_vote(
+        &self,
+        request: Request<pb::AuthorizeProxyVoteRequest>,
+    ) -> Result<Response<pb::AuthorizeProxyVoteResponse>, Status> {
+        let request = request
+            .into_inner()
+            .try_into()
+            .map_err(|e: anyhow::Error| Status::invalid_argument(e.to_string()))?;
+
+        let proxy_vote_auth = self
+            .sign_proxy_vote(&request)
+            .map_err(|e| Status::unauthenticated(format!("{e:#}")))?;
+
+        let authorization_response = pb::AuthorizeProxyVoteResponse {
+            proxy_vote_auth: Some(proxy_vote_auth.into()),
+        };
+
+        Ok(Response::new(authorization_response))
+    }
+
     async fn export_full_viewing_key(
         &self,
         _request: Request<pb::ExportFullViewingKeyRequest>,
@@ -331,6 +331,15 @@ impl<T: Terminal + Sync + Send + 'static> pb::custody_service_server::CustodySer
         }))
     }
 
+    async fn authorize_proxy_vote(
+        &self,
+        _request: Request<pb::AuthorizeProxyVoteRequest>,
+    ) -> Result<Response<pb::AuthorizeProxyVoteResponse>, Status> {
+        Err(Status::unimplemented(
+            "threshold custody does not yet support signing proxy votes",
+        ))
+    }
+
     async fn export_full_viewing_key(
         &self,
         _request: Request<pb::ExportFullViewingKeyRequest>,
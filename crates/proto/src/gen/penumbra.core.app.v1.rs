@@ -102,6 +102,56 @@ impl ::prost::Name for AppParameters {
         ::prost::alloc::format!("penumbra.core.app.v1.{}", Self::NAME)
     }
 }
+/// Requests a simulation of submitting and enacting a governance proposal.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SimulateProposalRequest {
+    /// The proposal to simulate.
+    #[prost(message, optional, tag = "1")]
+    pub proposal: ::core::option::Option<
+        super::super::component::governance::v1::Proposal,
+    >,
+}
+impl ::prost::Name for SimulateProposalRequest {
+    const NAME: &'static str = "SimulateProposalRequest";
+    const PACKAGE: &'static str = "penumbra.core.app.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.app.v1.{}", Self::NAME)
+    }
+}
+/// The outcome of simulating a governance proposal.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SimulateProposalResponse {
+    /// If set, the reason the proposal would be rejected when submitted.
+    ///
+    /// If the proposal would be rejected, its enactment is not simulated, and the remaining fields
+    /// are unset.
+    #[prost(string, tag = "1")]
+    pub submission_error: ::prost::alloc::string::String,
+    /// If set, the reason the proposal would fail to enact if it passed.
+    #[prost(string, tag = "2")]
+    pub enactment_error: ::prost::alloc::string::String,
+    /// For parameter change proposals, and batches including one, the app parameters which would be
+    /// in effect once the proposal is enacted.
+    #[prost(message, optional, tag = "3")]
+    pub new_app_parameters: ::core::option::Option<AppParameters>,
+    /// For Community Pool spend proposals, if set, the reason the proposed transaction would fail to
+    /// execute. A batch fails to enact if its transaction fails, which is reported as its
+    /// `enactment_error`.
+    #[prost(string, tag = "4")]
+    pub community_pool_transaction_error: ::prost::alloc::string::String,
+    /// The balance of the Community Pool once the proposal is enacted.
+    #[prost(message, repeated, tag = "5")]
+    pub community_pool_balance: ::prost::alloc::vec::Vec<super::super::asset::v1::Value>,
+}
+impl ::prost::Name for SimulateProposalResponse {
+    const NAME: &'static str = "SimulateProposalResponse";
+    const PACKAGE: &'static str = "penumbra.core.app.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.app.v1.{}", Self::NAME)
+    }
+}
 /// Requests the global configuration data for the app.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -365,6 +415,40 @@ pub mod query_service_client {
                 );
             self.inner.unary(req, path, codec).await
         }
+        /// Simulates submitting a governance proposal and enacting it against the latest state,
+        /// without committing any changes, to report what it would change or why it would fail.
+        ///
+        /// Only served by nodes with expensive RPCs enabled.
+        pub async fn simulate_proposal(
+            &mut self,
+            request: impl tonic::IntoRequest<super::SimulateProposalRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::SimulateProposalResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/penumbra.core.app.v1.QueryService/SimulateProposal",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "penumbra.core.app.v1.QueryService",
+                        "SimulateProposal",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
     }
 }
 /// Generated server implementations.
@@ -391,6 +475,17 @@ pub mod query_service_server {
             tonic::Response<super::TransactionsByHeightResponse>,
             tonic::Status,
         >;
+        /// Simulates submitting a governance proposal and enacting it against the latest state,
+        /// without committing any changes, to report what it would change or why it would fail.
+        ///
+        /// Only served by nodes with expensive RPCs enabled.
+        async fn simulate_proposal(
+            &self,
+            request: tonic::Request<super::SimulateProposalRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::SimulateProposalResponse>,
+            tonic::Status,
+        >;
     }
     /// Query operations for the overall Penumbra application.
     #[derive(Debug)]
@@ -565,6 +660,53 @@ pub mod query_service_server {
                     };
                     Box::pin(fut)
                 }
+                "/penumbra.core.app.v1.QueryService/SimulateProposal" => {
+                    #[allow(non_camel_case_types)]
+                    struct SimulateProposalSvc<T: QueryService>(pub Arc<T>);
+                    impl<
+                        T: QueryService,
+                    > tonic::server::UnaryService<super::SimulateProposalRequest>
+                    for SimulateProposalSvc<T> {
+                        type Response = super::SimulateProposalResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::SimulateProposalRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as QueryService>::simulate_proposal(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = SimulateProposalSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
                 _ => {
                     Box::pin(async move {
                         Ok(
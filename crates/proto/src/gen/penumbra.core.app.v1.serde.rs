@@ -869,6 +869,269 @@ impl<'de> serde::Deserialize<'de> for GenesisContent {
         deserializer.deserialize_struct("penumbra.core.app.v1.GenesisContent", FIELDS, GeneratedVisitor)
     }
 }
+impl serde::Serialize for SimulateProposalRequest {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.proposal.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.app.v1.SimulateProposalRequest", len)?;
+        if let Some(v) = self.proposal.as_ref() {
+            struct_ser.serialize_field("proposal", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for SimulateProposalRequest {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "proposal",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Proposal,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "proposal" => Ok(GeneratedField::Proposal),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = SimulateProposalRequest;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.app.v1.SimulateProposalRequest")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<SimulateProposalRequest, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut proposal__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Proposal => {
+                            if proposal__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("proposal"));
+                            }
+                            proposal__ = map_.next_value()?;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(SimulateProposalRequest {
+                    proposal: proposal__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.app.v1.SimulateProposalRequest", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for SimulateProposalResponse {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.submission_error.is_empty() {
+            len += 1;
+        }
+        if !self.enactment_error.is_empty() {
+            len += 1;
+        }
+        if self.new_app_parameters.is_some() {
+            len += 1;
+        }
+        if !self.community_pool_transaction_error.is_empty() {
+            len += 1;
+        }
+        if !self.community_pool_balance.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.app.v1.SimulateProposalResponse", len)?;
+        if !self.submission_error.is_empty() {
+            struct_ser.serialize_field("submissionError", &self.submission_error)?;
+        }
+        if !self.enactment_error.is_empty() {
+            struct_ser.serialize_field("enactmentError", &self.enactment_error)?;
+        }
+        if let Some(v) = self.new_app_parameters.as_ref() {
+            struct_ser.serialize_field("newAppParameters", v)?;
+        }
+        if !self.community_pool_transaction_error.is_empty() {
+            struct_ser.serialize_field("communityPoolTransactionError", &self.community_pool_transaction_error)?;
+        }
+        if !self.community_pool_balance.is_empty() {
+            struct_ser.serialize_field("communityPoolBalance", &self.community_pool_balance)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for SimulateProposalResponse {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "submission_error",
+            "submissionError",
+            "enactment_error",
+            "enactmentError",
+            "new_app_parameters",
+            "newAppParameters",
+            "community_pool_transaction_error",
+            "communityPoolTransactionError",
+            "community_pool_balance",
+            "communityPoolBalance",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            SubmissionError,
+            EnactmentError,
+            NewAppParameters,
+            CommunityPoolTransactionError,
+            CommunityPoolBalance,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "submissionError" | "submission_error" => Ok(GeneratedField::SubmissionError),
+                            "enactmentError" | "enactment_error" => Ok(GeneratedField::EnactmentError),
+                            "newAppParameters" | "new_app_parameters" => Ok(GeneratedField::NewAppParameters),
+                            "communityPoolTransactionError" | "community_pool_transaction_error" => Ok(GeneratedField::CommunityPoolTransactionError),
+                            "communityPoolBalance" | "community_pool_balance" => Ok(GeneratedField::CommunityPoolBalance),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = SimulateProposalResponse;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.app.v1.SimulateProposalResponse")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<SimulateProposalResponse, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut submission_error__ = None;
+                let mut enactment_error__ = None;
+                let mut new_app_parameters__ = None;
+                let mut community_pool_transaction_error__ = None;
+                let mut community_pool_balance__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::SubmissionError => {
+                            if submission_error__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("submissionError"));
+                            }
+                            submission_error__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::EnactmentError => {
+                            if enactment_error__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("enactmentError"));
+                            }
+                            enactment_error__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::NewAppParameters => {
+                            if new_app_parameters__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("newAppParameters"));
+                            }
+                            new_app_parameters__ = map_.next_value()?;
+                        }
+                        GeneratedField::CommunityPoolTransactionError => {
+                            if community_pool_transaction_error__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("communityPoolTransactionError"));
+                            }
+                            community_pool_transaction_error__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::CommunityPoolBalance => {
+                            if community_pool_balance__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("communityPoolBalance"));
+                            }
+                            community_pool_balance__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(SimulateProposalResponse {
+                    submission_error: submission_error__.unwrap_or_default(),
+                    enactment_error: enactment_error__.unwrap_or_default(),
+                    new_app_parameters: new_app_parameters__,
+                    community_pool_transaction_error: community_pool_transaction_error__.unwrap_or_default(),
+                    community_pool_balance: community_pool_balance__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.app.v1.SimulateProposalResponse", FIELDS, GeneratedVisitor)
+    }
+}
 impl serde::Serialize for TransactionsByHeightRequest {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
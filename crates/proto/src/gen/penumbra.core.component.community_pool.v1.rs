@@ -35,6 +35,46 @@ impl ::prost::Name for GenesisContent {
         )
     }
 }
+/// A vesting schedule releasing funds set aside from the Community Pool to a recipient.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct VestingGrant {
+    /// The ID of the grant, which is the ID of the proposal that created it.
+    #[prost(uint64, tag = "1")]
+    pub id: u64,
+    /// The address that vested funds are paid to.
+    #[prost(message, optional, tag = "2")]
+    pub recipient: ::core::option::Option<super::super::super::keys::v1::Address>,
+    /// The total value of the grant.
+    ///
+    /// If the grant was cancelled, this is the value which had vested at the time.
+    #[prost(message, optional, tag = "3")]
+    pub total: ::core::option::Option<super::super::super::asset::v1::Value>,
+    /// The amount that vests in each epoch since the start epoch, once the cliff has passed.
+    #[prost(message, optional, tag = "4")]
+    pub release_per_epoch: ::core::option::Option<super::super::super::num::v1::Amount>,
+    /// The epoch in which the grant was created.
+    #[prost(uint64, tag = "5")]
+    pub start_epoch_index: u64,
+    /// The first epoch in which funds vest.
+    #[prost(uint64, tag = "6")]
+    pub cliff_epoch_index: u64,
+    /// The amount claimed by the recipient so far.
+    #[prost(message, optional, tag = "7")]
+    pub claimed: ::core::option::Option<super::super::super::num::v1::Amount>,
+    /// Whether the grant was cancelled by a later proposal.
+    #[prost(bool, tag = "8")]
+    pub cancelled: bool,
+}
+impl ::prost::Name for VestingGrant {
+    const NAME: &'static str = "VestingGrant";
+    const PACKAGE: &'static str = "penumbra.core.component.community_pool.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!(
+            "penumbra.core.component.community_pool.v1.{}", Self::NAME
+        )
+    }
+}
 /// Requests the list of all asset balances associated with the Community Pool.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -69,6 +109,41 @@ impl ::prost::Name for CommunityPoolAssetBalancesResponse {
         )
     }
 }
+/// Requests the Community Pool's vesting grants.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct VestingGrantsRequest {
+    /// (Optional): Only return the grants with these IDs, if excluded all will be returned.
+    #[prost(uint64, repeated, tag = "1")]
+    pub grant_ids: ::prost::alloc::vec::Vec<u64>,
+}
+impl ::prost::Name for VestingGrantsRequest {
+    const NAME: &'static str = "VestingGrantsRequest";
+    const PACKAGE: &'static str = "penumbra.core.component.community_pool.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!(
+            "penumbra.core.component.community_pool.v1.{}", Self::NAME
+        )
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct VestingGrantsResponse {
+    #[prost(message, optional, tag = "1")]
+    pub grant: ::core::option::Option<VestingGrant>,
+    /// The amount which has vested but has not yet been claimed.
+    #[prost(message, optional, tag = "2")]
+    pub claimable: ::core::option::Option<super::super::super::num::v1::Amount>,
+}
+impl ::prost::Name for VestingGrantsResponse {
+    const NAME: &'static str = "VestingGrantsResponse";
+    const PACKAGE: &'static str = "penumbra.core.component.community_pool.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!(
+            "penumbra.core.component.community_pool.v1.{}", Self::NAME
+        )
+    }
+}
 /// Generated client implementations.
 #[cfg(feature = "rpc")]
 pub mod query_service_client {
@@ -188,6 +263,37 @@ pub mod query_service_client {
                 );
             self.inner.server_streaming(req, path, codec).await
         }
+        /// Queries for the Community Pool's vesting grants.
+        pub async fn vesting_grants(
+            &mut self,
+            request: impl tonic::IntoRequest<super::VestingGrantsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<tonic::codec::Streaming<super::VestingGrantsResponse>>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/penumbra.core.component.community_pool.v1.QueryService/VestingGrants",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "penumbra.core.component.community_pool.v1.QueryService",
+                        "VestingGrants",
+                    ),
+                );
+            self.inner.server_streaming(req, path, codec).await
+        }
     }
 }
 /// Generated server implementations.
@@ -214,6 +320,20 @@ pub mod query_service_server {
             tonic::Response<Self::CommunityPoolAssetBalancesStream>,
             tonic::Status,
         >;
+        /// Server streaming response type for the VestingGrants method.
+        type VestingGrantsStream: tonic::codegen::tokio_stream::Stream<
+                Item = std::result::Result<super::VestingGrantsResponse, tonic::Status>,
+            >
+            + Send
+            + 'static;
+        /// Queries for the Community Pool's vesting grants.
+        async fn vesting_grants(
+            &self,
+            request: tonic::Request<super::VestingGrantsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<Self::VestingGrantsStream>,
+            tonic::Status,
+        >;
     }
     /// Query operations for the community_pool component.
     #[derive(Debug)]
@@ -349,6 +469,53 @@ pub mod query_service_server {
                     };
                     Box::pin(fut)
                 }
+                "/penumbra.core.component.community_pool.v1.QueryService/VestingGrants" => {
+                    #[allow(non_camel_case_types)]
+                    struct VestingGrantsSvc<T: QueryService>(pub Arc<T>);
+                    impl<
+                        T: QueryService,
+                    > tonic::server::ServerStreamingService<super::VestingGrantsRequest>
+                    for VestingGrantsSvc<T> {
+                        type Response = super::VestingGrantsResponse;
+                        type ResponseStream = T::VestingGrantsStream;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::ResponseStream>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::VestingGrantsRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as QueryService>::vesting_grants(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = VestingGrantsSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.server_streaming(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
                 _ => {
                     Box::pin(async move {
                         Ok(
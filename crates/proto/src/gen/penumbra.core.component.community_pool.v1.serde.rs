@@ -399,3 +399,440 @@ impl<'de> serde::Deserialize<'de> for GenesisContent {
         deserializer.deserialize_struct("penumbra.core.component.community_pool.v1.GenesisContent", FIELDS, GeneratedVisitor)
     }
 }
+impl serde::Serialize for VestingGrant {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.id != 0 {
+            len += 1;
+        }
+        if self.recipient.is_some() {
+            len += 1;
+        }
+        if self.total.is_some() {
+            len += 1;
+        }
+        if self.release_per_epoch.is_some() {
+            len += 1;
+        }
+        if self.start_epoch_index != 0 {
+            len += 1;
+        }
+        if self.cliff_epoch_index != 0 {
+            len += 1;
+        }
+        if self.claimed.is_some() {
+            len += 1;
+        }
+        if self.cancelled {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.community_pool.v1.VestingGrant", len)?;
+        if self.id != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("id", ToString::to_string(&self.id).as_str())?;
+        }
+        if let Some(v) = self.recipient.as_ref() {
+            struct_ser.serialize_field("recipient", v)?;
+        }
+        if let Some(v) = self.total.as_ref() {
+            struct_ser.serialize_field("total", v)?;
+        }
+        if let Some(v) = self.release_per_epoch.as_ref() {
+            struct_ser.serialize_field("releasePerEpoch", v)?;
+        }
+        if self.start_epoch_index != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("startEpochIndex", ToString::to_string(&self.start_epoch_index).as_str())?;
+        }
+        if self.cliff_epoch_index != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("cliffEpochIndex", ToString::to_string(&self.cliff_epoch_index).as_str())?;
+        }
+        if let Some(v) = self.claimed.as_ref() {
+            struct_ser.serialize_field("claimed", v)?;
+        }
+        if self.cancelled {
+            struct_ser.serialize_field("cancelled", &self.cancelled)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for VestingGrant {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "id",
+            "recipient",
+            "total",
+            "release_per_epoch",
+            "releasePerEpoch",
+            "start_epoch_index",
+            "startEpochIndex",
+            "cliff_epoch_index",
+            "cliffEpochIndex",
+            "claimed",
+            "cancelled",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Id,
+            Recipient,
+            Total,
+            ReleasePerEpoch,
+            StartEpochIndex,
+            CliffEpochIndex,
+            Claimed,
+            Cancelled,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "id" => Ok(GeneratedField::Id),
+                            "recipient" => Ok(GeneratedField::Recipient),
+                            "total" => Ok(GeneratedField::Total),
+                            "releasePerEpoch" | "release_per_epoch" => Ok(GeneratedField::ReleasePerEpoch),
+                            "startEpochIndex" | "start_epoch_index" => Ok(GeneratedField::StartEpochIndex),
+                            "cliffEpochIndex" | "cliff_epoch_index" => Ok(GeneratedField::CliffEpochIndex),
+                            "claimed" => Ok(GeneratedField::Claimed),
+                            "cancelled" => Ok(GeneratedField::Cancelled),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = VestingGrant;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.community_pool.v1.VestingGrant")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<VestingGrant, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut id__ = None;
+                let mut recipient__ = None;
+                let mut total__ = None;
+                let mut release_per_epoch__ = None;
+                let mut start_epoch_index__ = None;
+                let mut cliff_epoch_index__ = None;
+                let mut claimed__ = None;
+                let mut cancelled__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Id => {
+                            if id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("id"));
+                            }
+                            id__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Recipient => {
+                            if recipient__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("recipient"));
+                            }
+                            recipient__ = map_.next_value()?;
+                        }
+                        GeneratedField::Total => {
+                            if total__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("total"));
+                            }
+                            total__ = map_.next_value()?;
+                        }
+                        GeneratedField::ReleasePerEpoch => {
+                            if release_per_epoch__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("releasePerEpoch"));
+                            }
+                            release_per_epoch__ = map_.next_value()?;
+                        }
+                        GeneratedField::StartEpochIndex => {
+                            if start_epoch_index__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("startEpochIndex"));
+                            }
+                            start_epoch_index__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::CliffEpochIndex => {
+                            if cliff_epoch_index__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("cliffEpochIndex"));
+                            }
+                            cliff_epoch_index__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Claimed => {
+                            if claimed__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("claimed"));
+                            }
+                            claimed__ = map_.next_value()?;
+                        }
+                        GeneratedField::Cancelled => {
+                            if cancelled__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("cancelled"));
+                            }
+                            cancelled__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(VestingGrant {
+                    id: id__.unwrap_or_default(),
+                    recipient: recipient__,
+                    total: total__,
+                    release_per_epoch: release_per_epoch__,
+                    start_epoch_index: start_epoch_index__.unwrap_or_default(),
+                    cliff_epoch_index: cliff_epoch_index__.unwrap_or_default(),
+                    claimed: claimed__,
+                    cancelled: cancelled__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.community_pool.v1.VestingGrant", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for VestingGrantsRequest {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.grant_ids.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.community_pool.v1.VestingGrantsRequest", len)?;
+        if !self.grant_ids.is_empty() {
+            struct_ser.serialize_field("grantIds", &self.grant_ids.iter().map(ToString::to_string).collect::<Vec<_>>())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for VestingGrantsRequest {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "grant_ids",
+            "grantIds",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            GrantIds,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "grantIds" | "grant_ids" => Ok(GeneratedField::GrantIds),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = VestingGrantsRequest;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.community_pool.v1.VestingGrantsRequest")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<VestingGrantsRequest, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut grant_ids__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::GrantIds => {
+                            if grant_ids__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("grantIds"));
+                            }
+                            grant_ids__ = 
+                                Some(map_.next_value::<Vec<::pbjson::private::NumberDeserialize<_>>>()?
+                                    .into_iter().map(|x| x.0).collect())
+                            ;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(VestingGrantsRequest {
+                    grant_ids: grant_ids__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.community_pool.v1.VestingGrantsRequest", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for VestingGrantsResponse {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.grant.is_some() {
+            len += 1;
+        }
+        if self.claimable.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.community_pool.v1.VestingGrantsResponse", len)?;
+        if let Some(v) = self.grant.as_ref() {
+            struct_ser.serialize_field("grant", v)?;
+        }
+        if let Some(v) = self.claimable.as_ref() {
+            struct_ser.serialize_field("claimable", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for VestingGrantsResponse {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "grant",
+            "claimable",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Grant,
+            Claimable,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "grant" => Ok(GeneratedField::Grant),
+                            "claimable" => Ok(GeneratedField::Claimable),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = VestingGrantsResponse;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.community_pool.v1.VestingGrantsResponse")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<VestingGrantsResponse, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut grant__ = None;
+                let mut claimable__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Grant => {
+                            if grant__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("grant"));
+                            }
+                            grant__ = map_.next_value()?;
+                        }
+                        GeneratedField::Claimable => {
+                            if claimable__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("claimable"));
+                            }
+                            claimable__ = map_.next_value()?;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(VestingGrantsResponse {
+                    grant: grant__,
+                    claimable: claimable__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.community_pool.v1.VestingGrantsResponse", FIELDS, GeneratedVisitor)
+    }
+}
@@ -45,6 +45,17 @@ pub struct CompactBlock {
     /// The epoch index
     #[prost(uint64, tag = "11")]
     pub epoch_index: u64,
+    /// Executions against liquidity positions in this block, summarized per
+    /// position: there is at most one entry per position.
+    #[prost(message, repeated, tag = "12")]
+    pub position_executions: ::prost::alloc::vec::Vec<
+        super::super::dex::v1::PositionExecutionSummary,
+    >,
+    /// Positions evicted by the DEX at the end of this block.
+    #[prost(message, repeated, tag = "13")]
+    pub evicted_positions: ::prost::alloc::vec::Vec<
+        super::super::dex::v1::EventPositionEvicted,
+    >,
 }
 impl ::prost::Name for CompactBlock {
     const NAME: &'static str = "CompactBlock";
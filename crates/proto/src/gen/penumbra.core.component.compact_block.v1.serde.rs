@@ -42,6 +42,12 @@ impl serde::Serialize for CompactBlock {
         if self.epoch_index != 0 {
             len += 1;
         }
+        if !self.position_executions.is_empty() {
+            len += 1;
+        }
+        if !self.evicted_positions.is_empty() {
+            len += 1;
+        }
         let mut struct_ser = serializer.serialize_struct("penumbra.core.component.compact_block.v1.CompactBlock", len)?;
         if self.height != 0 {
             #[allow(clippy::needless_borrow)]
@@ -81,6 +87,12 @@ impl serde::Serialize for CompactBlock {
             #[allow(clippy::needless_borrow)]
             struct_ser.serialize_field("epochIndex", ToString::to_string(&self.epoch_index).as_str())?;
         }
+        if !self.position_executions.is_empty() {
+            struct_ser.serialize_field("positionExecutions", &self.position_executions)?;
+        }
+        if !self.evicted_positions.is_empty() {
+            struct_ser.serialize_field("evictedPositions", &self.evicted_positions)?;
+        }
         struct_ser.end()
     }
 }
@@ -113,6 +125,10 @@ impl<'de> serde::Deserialize<'de> for CompactBlock {
             "altGasPrices",
             "epoch_index",
             "epochIndex",
+            "position_executions",
+            "positionExecutions",
+            "evicted_positions",
+            "evictedPositions",
         ];
 
         #[allow(clippy::enum_variant_names)]
@@ -129,6 +145,8 @@ impl<'de> serde::Deserialize<'de> for CompactBlock {
             GasPrices,
             AltGasPrices,
             EpochIndex,
+            PositionExecutions,
+            EvictedPositions,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -163,6 +181,8 @@ impl<'de> serde::Deserialize<'de> for CompactBlock {
                             "gasPrices" | "gas_prices" => Ok(GeneratedField::GasPrices),
                             "altGasPrices" | "alt_gas_prices" => Ok(GeneratedField::AltGasPrices),
                             "epochIndex" | "epoch_index" => Ok(GeneratedField::EpochIndex),
+                            "positionExecutions" | "position_executions" => Ok(GeneratedField::PositionExecutions),
+                            "evictedPositions" | "evicted_positions" => Ok(GeneratedField::EvictedPositions),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -194,6 +214,8 @@ impl<'de> serde::Deserialize<'de> for CompactBlock {
                 let mut gas_prices__ = None;
                 let mut alt_gas_prices__ = None;
                 let mut epoch_index__ = None;
+                let mut position_executions__ = None;
+                let mut evicted_positions__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
                         GeneratedField::Height => {
@@ -272,6 +294,18 @@ impl<'de> serde::Deserialize<'de> for CompactBlock {
                                 Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
                             ;
                         }
+                        GeneratedField::PositionExecutions => {
+                            if position_executions__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("positionExecutions"));
+                            }
+                            position_executions__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::EvictedPositions => {
+                            if evicted_positions__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("evictedPositions"));
+                            }
+                            evicted_positions__ = Some(map_.next_value()?);
+                        }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
@@ -290,6 +324,8 @@ impl<'de> serde::Deserialize<'de> for CompactBlock {
                     gas_prices: gas_prices__,
                     alt_gas_prices: alt_gas_prices__.unwrap_or_default(),
                     epoch_index: epoch_index__.unwrap_or_default(),
+                    position_executions: position_executions__.unwrap_or_default(),
+                    evicted_positions: evicted_positions__.unwrap_or_default(),
                 })
             }
         }
@@ -586,6 +586,35 @@ impl ::prost::Name for Reserves {
         ::prost::alloc::format!("penumbra.core.component.dex.v1.{}", Self::NAME)
     }
 }
+/// The executions against a liquidity position during a single block, netted
+/// into the change of its reserves and the total inflow of each asset.
+///
+/// Only the data needed to account for the position's fills and fees is kept,
+/// since this is reported to every client in the compact block.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PositionExecutionSummary {
+    /// The ID of the position executed against.
+    #[prost(message, optional, tag = "1")]
+    pub position_id: ::core::option::Option<PositionId>,
+    /// The reserves of the position before the first execution in the block.
+    #[prost(message, optional, tag = "2")]
+    pub prev_reserves: ::core::option::Option<Reserves>,
+    /// The reserves of the position after the last execution in the block.
+    #[prost(message, optional, tag = "3")]
+    pub reserves: ::core::option::Option<Reserves>,
+    /// The total amount of each asset traded into the position by the block's
+    /// executions, on which the position's fees are charged.
+    #[prost(message, optional, tag = "4")]
+    pub inflows: ::core::option::Option<Reserves>,
+}
+impl ::prost::Name for PositionExecutionSummary {
+    const NAME: &'static str = "PositionExecutionSummary";
+    const PACKAGE: &'static str = "penumbra.core.component.dex.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.component.dex.v1.{}", Self::NAME)
+    }
+}
 /// Data identifying a position.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -1184,6 +1213,157 @@ impl ::prost::Name for LiquidityPositionsByPriceResponse {
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PositionEvictionRiskRequest {
+    #[prost(oneof = "position_eviction_risk_request::Position", tags = "1, 2")]
+    pub position: ::core::option::Option<position_eviction_risk_request::Position>,
+}
+/// Nested message and enum types in `PositionEvictionRiskRequest`.
+pub mod position_eviction_risk_request {
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum Position {
+        /// The ID of an open position.
+        #[prost(message, tag = "1")]
+        PositionId(super::PositionId),
+        /// A position that has not been opened yet.
+        #[prost(message, tag = "2")]
+        Hypothetical(super::Position),
+    }
+}
+impl ::prost::Name for PositionEvictionRiskRequest {
+    const NAME: &'static str = "PositionEvictionRiskRequest";
+    const PACKAGE: &'static str = "penumbra.core.component.dex.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.component.dex.v1.{}", Self::NAME)
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PositionEvictionRiskResponse {
+    /// The number of open positions on the trading pair, including the
+    /// requested position if it is hypothetical.
+    #[prost(uint64, tag = "1")]
+    pub position_count: u64,
+    /// The maximum number of positions on a trading pair before eviction kicks in.
+    #[prost(uint64, tag = "2")]
+    pub max_positions_per_pair: u64,
+    /// The number of positions with less inventory of asset 1 than the requested
+    /// position, i.e. its rank from the bottom of the eviction queue for 1 => 2.
+    #[prost(uint64, tag = "3")]
+    pub rank_1: u64,
+    /// The number of positions with less inventory of asset 2 than the requested
+    /// position, i.e. its rank from the bottom of the eviction queue for 2 => 1.
+    #[prost(uint64, tag = "4")]
+    pub rank_2: u64,
+    /// Whether the position would be evicted if the pair were evicted now, i.e.
+    /// whether it is among the `position_count - max_positions_per_pair` least
+    /// liquid positions in both directions.
+    #[prost(bool, tag = "5")]
+    pub at_risk: bool,
+}
+impl ::prost::Name for PositionEvictionRiskResponse {
+    const NAME: &'static str = "PositionEvictionRiskResponse";
+    const PACKAGE: &'static str = "penumbra.core.component.dex.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.component.dex.v1.{}", Self::NAME)
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CircuitBreakerStatusRequest {
+    /// If present, only return the value balances of these assets.
+    #[prost(message, repeated, tag = "1")]
+    pub asset_ids: ::prost::alloc::vec::Vec<super::super::super::asset::v1::AssetId>,
+    /// Only return circuit breaker trips recorded at or after this height.
+    #[prost(uint64, tag = "2")]
+    pub start_height: u64,
+}
+impl ::prost::Name for CircuitBreakerStatusRequest {
+    const NAME: &'static str = "CircuitBreakerStatusRequest";
+    const PACKAGE: &'static str = "penumbra.core.component.dex.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.component.dex.v1.{}", Self::NAME)
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CircuitBreakerStatusResponse {
+    /// The value balance held by the DEX for each asset.
+    #[prost(message, repeated, tag = "1")]
+    pub value_balances: ::prost::alloc::vec::Vec<
+        circuit_breaker_status_response::ValueBalance,
+    >,
+    /// The maximum number of path searches and executions for a single route-and-fill.
+    #[prost(uint32, tag = "2")]
+    pub max_execution_budget: u32,
+    /// The execution budget used by the most recent route-and-fill in each direction.
+    ///
+    /// The remaining budget is `max_execution_budget - used`.
+    #[prost(message, repeated, tag = "3")]
+    pub execution_budget_usage: ::prost::alloc::vec::Vec<
+        circuit_breaker_status_response::ExecutionBudgetUsage,
+    >,
+    /// The circuit breaker trips recorded since the requested height.
+    #[prost(message, repeated, tag = "4")]
+    pub trips: ::prost::alloc::vec::Vec<EventCircuitBreakerTrip>,
+}
+/// Nested message and enum types in `CircuitBreakerStatusResponse`.
+pub mod circuit_breaker_status_response {
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct ValueBalance {
+        /// The asset held by the DEX.
+        #[prost(message, optional, tag = "1")]
+        pub asset_id: ::core::option::Option<
+            super::super::super::super::asset::v1::AssetId,
+        >,
+        /// The amount of the asset the DEX can pay out.
+        #[prost(message, optional, tag = "2")]
+        pub balance: ::core::option::Option<super::super::super::super::num::v1::Amount>,
+    }
+    impl ::prost::Name for ValueBalance {
+        const NAME: &'static str = "ValueBalance";
+        const PACKAGE: &'static str = "penumbra.core.component.dex.v1";
+        fn full_name() -> ::prost::alloc::string::String {
+            ::prost::alloc::format!(
+                "penumbra.core.component.dex.v1.CircuitBreakerStatusResponse.{}",
+                Self::NAME
+            )
+        }
+    }
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct ExecutionBudgetUsage {
+        /// The direction in which the route-and-fill was performed.
+        #[prost(message, optional, tag = "1")]
+        pub trading_pair: ::core::option::Option<super::DirectedTradingPair>,
+        /// The height of the most recent route-and-fill in this direction.
+        #[prost(uint64, tag = "2")]
+        pub height: u64,
+        /// The number of path searches and executions it consumed.
+        #[prost(uint32, tag = "3")]
+        pub used: u32,
+    }
+    impl ::prost::Name for ExecutionBudgetUsage {
+        const NAME: &'static str = "ExecutionBudgetUsage";
+        const PACKAGE: &'static str = "penumbra.core.component.dex.v1";
+        fn full_name() -> ::prost::alloc::string::String {
+            ::prost::alloc::format!(
+                "penumbra.core.component.dex.v1.CircuitBreakerStatusResponse.{}",
+                Self::NAME
+            )
+        }
+    }
+}
+impl ::prost::Name for CircuitBreakerStatusResponse {
+    const NAME: &'static str = "CircuitBreakerStatusResponse";
+    const PACKAGE: &'static str = "penumbra.core.component.dex.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.component.dex.v1.{}", Self::NAME)
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
 pub struct SpreadRequest {
     #[prost(message, optional, tag = "2")]
     pub trading_pair: ::core::option::Option<TradingPair>,
@@ -1227,6 +1407,17 @@ pub struct SimulateTradeRequest {
     pub output: ::core::option::Option<super::super::super::asset::v1::AssetId>,
     #[prost(message, optional, tag = "3")]
     pub routing: ::core::option::Option<simulate_trade_request::Routing>,
+    /// If set, simulate against the state at this height rather than the latest state.
+    ///
+    /// Only heights whose state is still retained by the node can be simulated against.
+    #[prost(uint64, tag = "4")]
+    pub height: u64,
+    /// Hypothetical liquidity positions to open before simulating the trade.
+    #[prost(message, repeated, tag = "5")]
+    pub open_positions: ::prost::alloc::vec::Vec<Position>,
+    /// Existing liquidity positions to close before simulating the trade.
+    #[prost(message, repeated, tag = "6")]
+    pub close_positions: ::prost::alloc::vec::Vec<PositionId>,
 }
 /// Nested message and enum types in `SimulateTradeRequest`.
 pub mod simulate_trade_request {
@@ -1298,6 +1489,9 @@ pub struct SimulateTradeResponse {
     /// Estimated input amount that will not be swapped due to liquidity
     #[prost(message, optional, tag = "2")]
     pub unfilled: ::core::option::Option<super::super::super::asset::v1::Value>,
+    /// The height of the state the trade was simulated against.
+    #[prost(uint64, tag = "3")]
+    pub height: u64,
 }
 impl ::prost::Name for SimulateTradeResponse {
     const NAME: &'static str = "SimulateTradeResponse";
@@ -1404,6 +1598,32 @@ impl ::prost::Name for EventPositionClose {
         ::prost::alloc::format!("penumbra.core.component.dex.v1.{}", Self::NAME)
     }
 }
+/// Emitted when a position is closed by the DEX because its trading pair has
+/// more than `max_positions_per_pair` positions, and it is among the least
+/// liquid in both directions of the pair.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct EventPositionEvicted {
+    /// The ID of the evicted position.
+    #[prost(message, optional, tag = "1")]
+    pub position_id: ::core::option::Option<PositionId>,
+    /// The trading pair of the evicted position.
+    #[prost(message, optional, tag = "2")]
+    pub trading_pair: ::core::option::Option<TradingPair>,
+    /// The reserves of asset 1 at the time of eviction.
+    #[prost(message, optional, tag = "3")]
+    pub reserves_1: ::core::option::Option<super::super::super::num::v1::Amount>,
+    /// The reserves of asset 2 at the time of eviction.
+    #[prost(message, optional, tag = "4")]
+    pub reserves_2: ::core::option::Option<super::super::super::num::v1::Amount>,
+}
+impl ::prost::Name for EventPositionEvicted {
+    const NAME: &'static str = "EventPositionEvicted";
+    const PACKAGE: &'static str = "penumbra.core.component.dex.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.component.dex.v1.{}", Self::NAME)
+    }
+}
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct EventQueuePositionClose {
@@ -1555,6 +1775,84 @@ impl ::prost::Name for EventValueCircuitBreakerDebit {
         ::prost::alloc::format!("penumbra.core.component.dex.v1.{}", Self::NAME)
     }
 }
+/// Emitted at the end of a block for each DEX circuit breaker trip during that block.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct EventCircuitBreakerTrip {
+    /// The height at which the circuit breaker tripped.
+    #[prost(uint64, tag = "1")]
+    pub height: u64,
+    #[prost(oneof = "event_circuit_breaker_trip::Trip", tags = "2, 3")]
+    pub trip: ::core::option::Option<event_circuit_breaker_trip::Trip>,
+}
+/// Nested message and enum types in `EventCircuitBreakerTrip`.
+pub mod event_circuit_breaker_trip {
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum Trip {
+        #[prost(message, tag = "2")]
+        Value(super::ValueCircuitBreakerTrip),
+        #[prost(message, tag = "3")]
+        Execution(super::ExecutionCircuitBreakerTrip),
+    }
+}
+impl ::prost::Name for EventCircuitBreakerTrip {
+    const NAME: &'static str = "EventCircuitBreakerTrip";
+    const PACKAGE: &'static str = "penumbra.core.component.dex.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.component.dex.v1.{}", Self::NAME)
+    }
+}
+/// A debit from the DEX exceeded the value balance it holds for an asset.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ValueCircuitBreakerTrip {
+    /// The asset being debited.
+    #[prost(message, optional, tag = "1")]
+    pub asset_id: ::core::option::Option<super::super::super::asset::v1::AssetId>,
+    /// The balance of the asset in the DEX at the time of the debit.
+    #[prost(message, optional, tag = "2")]
+    pub balance: ::core::option::Option<super::super::super::num::v1::Amount>,
+    /// The amount that was requested.
+    #[prost(message, optional, tag = "3")]
+    pub debit: ::core::option::Option<super::super::super::num::v1::Amount>,
+    /// The transaction that attempted the debit, and was rejected.
+    #[prost(message, optional, tag = "4")]
+    pub transaction_id: ::core::option::Option<
+        super::super::super::txhash::v1::TransactionId,
+    >,
+}
+impl ::prost::Name for ValueCircuitBreakerTrip {
+    const NAME: &'static str = "ValueCircuitBreakerTrip";
+    const PACKAGE: &'static str = "penumbra.core.component.dex.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.component.dex.v1.{}", Self::NAME)
+    }
+}
+/// A route-and-fill exhausted its execution budget before filling its input.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ExecutionCircuitBreakerTrip {
+    /// The direction in which the route-and-fill was performed.
+    #[prost(message, optional, tag = "1")]
+    pub trading_pair: ::core::option::Option<DirectedTradingPair>,
+    /// The input to the route-and-fill.
+    #[prost(message, optional, tag = "2")]
+    pub input: ::core::option::Option<super::super::super::num::v1::Amount>,
+    /// The input left unfilled when the budget was exhausted.
+    #[prost(message, optional, tag = "3")]
+    pub unfilled: ::core::option::Option<super::super::super::num::v1::Amount>,
+    /// The execution budget that was exhausted.
+    #[prost(uint32, tag = "4")]
+    pub execution_budget: u32,
+}
+impl ::prost::Name for ExecutionCircuitBreakerTrip {
+    const NAME: &'static str = "ExecutionCircuitBreakerTrip";
+    const PACKAGE: &'static str = "penumbra.core.component.dex.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.component.dex.v1.{}", Self::NAME)
+    }
+}
 /// Emitted whenever there's non-empty candlestick data for a particular pair.
 ///
 /// Beware: if there's no activity on a given pair, there's no guarantee
@@ -2192,6 +2490,70 @@ pub mod query_service_client {
                 );
             self.inner.server_streaming(req, path, codec).await
         }
+        /// Get the rank of a position in its pair's eviction queue, to assess how close
+        /// it is to being evicted. The position need not be open yet.
+        pub async fn position_eviction_risk(
+            &mut self,
+            request: impl tonic::IntoRequest<super::PositionEvictionRiskRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::PositionEvictionRiskResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/penumbra.core.component.dex.v1.QueryService/PositionEvictionRisk",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "penumbra.core.component.dex.v1.QueryService",
+                        "PositionEvictionRisk",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// Get the status of the DEX circuit breakers: the value balance held for each
+        /// asset, the execution budget used by recent batch swaps, and the history of trips.
+        pub async fn circuit_breaker_status(
+            &mut self,
+            request: impl tonic::IntoRequest<super::CircuitBreakerStatusRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::CircuitBreakerStatusResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/penumbra.core.component.dex.v1.QueryService/CircuitBreakerStatus",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "penumbra.core.component.dex.v1.QueryService",
+                        "CircuitBreakerStatus",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
     }
 }
 /// Generated client implementations.
@@ -2474,6 +2836,24 @@ pub mod query_service_server {
             tonic::Response<Self::CandlestickDataStreamStream>,
             tonic::Status,
         >;
+        /// Get the rank of a position in its pair's eviction queue, to assess how close
+        /// it is to being evicted. The position need not be open yet.
+        async fn position_eviction_risk(
+            &self,
+            request: tonic::Request<super::PositionEvictionRiskRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::PositionEvictionRiskResponse>,
+            tonic::Status,
+        >;
+        /// Get the status of the DEX circuit breakers: the value balance held for each
+        /// asset, the execution budget used by recent batch swaps, and the history of trips.
+        async fn circuit_breaker_status(
+            &self,
+            request: tonic::Request<super::CircuitBreakerStatusRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::CircuitBreakerStatusResponse>,
+            tonic::Status,
+        >;
     }
     /// Query operations for the DEX component.
     #[derive(Debug)]
@@ -3137,6 +3517,100 @@ pub mod query_service_server {
                     };
                     Box::pin(fut)
                 }
+                "/penumbra.core.component.dex.v1.QueryService/PositionEvictionRisk" => {
+                    #[allow(non_camel_case_types)]
+                    struct PositionEvictionRiskSvc<T: QueryService>(pub Arc<T>);
+                    impl<
+                        T: QueryService,
+                    > tonic::server::UnaryService<super::PositionEvictionRiskRequest>
+                    for PositionEvictionRiskSvc<T> {
+                        type Response = super::PositionEvictionRiskResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::PositionEvictionRiskRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as QueryService>::position_eviction_risk(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = PositionEvictionRiskSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/penumbra.core.component.dex.v1.QueryService/CircuitBreakerStatus" => {
+                    #[allow(non_camel_case_types)]
+                    struct CircuitBreakerStatusSvc<T: QueryService>(pub Arc<T>);
+                    impl<
+                        T: QueryService,
+                    > tonic::server::UnaryService<super::CircuitBreakerStatusRequest>
+                    for CircuitBreakerStatusSvc<T> {
+                        type Response = super::CircuitBreakerStatusResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::CircuitBreakerStatusRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as QueryService>::circuit_breaker_status(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = CircuitBreakerStatusSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
                 _ => {
                     Box::pin(async move {
                         Ok(
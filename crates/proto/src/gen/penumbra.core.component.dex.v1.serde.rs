@@ -1691,7 +1691,7 @@ impl<'de> serde::Deserialize<'de> for CandlestickDataStreamResponse {
         deserializer.deserialize_struct("penumbra.core.component.dex.v1.CandlestickDataStreamResponse", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for DexParameters {
+impl serde::Serialize for CircuitBreakerStatusRequest {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -1699,66 +1699,40 @@ impl serde::Serialize for DexParameters {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.is_enabled {
-            len += 1;
-        }
-        if !self.fixed_candidates.is_empty() {
-            len += 1;
-        }
-        if self.max_hops != 0 {
+        if !self.asset_ids.is_empty() {
             len += 1;
         }
-        if self.max_positions_per_pair != 0 {
-            len += 1;
-        }
-        if self.max_execution_budget != 0 {
+        if self.start_height != 0 {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.DexParameters", len)?;
-        if self.is_enabled {
-            struct_ser.serialize_field("isEnabled", &self.is_enabled)?;
-        }
-        if !self.fixed_candidates.is_empty() {
-            struct_ser.serialize_field("fixedCandidates", &self.fixed_candidates)?;
-        }
-        if self.max_hops != 0 {
-            struct_ser.serialize_field("maxHops", &self.max_hops)?;
-        }
-        if self.max_positions_per_pair != 0 {
-            struct_ser.serialize_field("maxPositionsPerPair", &self.max_positions_per_pair)?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.CircuitBreakerStatusRequest", len)?;
+        if !self.asset_ids.is_empty() {
+            struct_ser.serialize_field("assetIds", &self.asset_ids)?;
         }
-        if self.max_execution_budget != 0 {
-            struct_ser.serialize_field("maxExecutionBudget", &self.max_execution_budget)?;
+        if self.start_height != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("startHeight", ToString::to_string(&self.start_height).as_str())?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for DexParameters {
+impl<'de> serde::Deserialize<'de> for CircuitBreakerStatusRequest {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "is_enabled",
-            "isEnabled",
-            "fixed_candidates",
-            "fixedCandidates",
-            "max_hops",
-            "maxHops",
-            "max_positions_per_pair",
-            "maxPositionsPerPair",
-            "max_execution_budget",
-            "maxExecutionBudget",
+            "asset_ids",
+            "assetIds",
+            "start_height",
+            "startHeight",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            IsEnabled,
-            FixedCandidates,
-            MaxHops,
-            MaxPositionsPerPair,
-            MaxExecutionBudget,
+            AssetIds,
+            StartHeight,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -1781,11 +1755,8 @@ impl<'de> serde::Deserialize<'de> for DexParameters {
                         E: serde::de::Error,
                     {
                         match value {
-                            "isEnabled" | "is_enabled" => Ok(GeneratedField::IsEnabled),
-                            "fixedCandidates" | "fixed_candidates" => Ok(GeneratedField::FixedCandidates),
-                            "maxHops" | "max_hops" => Ok(GeneratedField::MaxHops),
-                            "maxPositionsPerPair" | "max_positions_per_pair" => Ok(GeneratedField::MaxPositionsPerPair),
-                            "maxExecutionBudget" | "max_execution_budget" => Ok(GeneratedField::MaxExecutionBudget),
+                            "assetIds" | "asset_ids" => Ok(GeneratedField::AssetIds),
+                            "startHeight" | "start_height" => Ok(GeneratedField::StartHeight),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -1795,56 +1766,31 @@ impl<'de> serde::Deserialize<'de> for DexParameters {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = DexParameters;
+            type Value = CircuitBreakerStatusRequest;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.dex.v1.DexParameters")
+                formatter.write_str("struct penumbra.core.component.dex.v1.CircuitBreakerStatusRequest")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<DexParameters, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<CircuitBreakerStatusRequest, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut is_enabled__ = None;
-                let mut fixed_candidates__ = None;
-                let mut max_hops__ = None;
-                let mut max_positions_per_pair__ = None;
-                let mut max_execution_budget__ = None;
+                let mut asset_ids__ = None;
+                let mut start_height__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::IsEnabled => {
-                            if is_enabled__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("isEnabled"));
-                            }
-                            is_enabled__ = Some(map_.next_value()?);
-                        }
-                        GeneratedField::FixedCandidates => {
-                            if fixed_candidates__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("fixedCandidates"));
-                            }
-                            fixed_candidates__ = Some(map_.next_value()?);
-                        }
-                        GeneratedField::MaxHops => {
-                            if max_hops__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("maxHops"));
-                            }
-                            max_hops__ = 
-                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
-                            ;
-                        }
-                        GeneratedField::MaxPositionsPerPair => {
-                            if max_positions_per_pair__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("maxPositionsPerPair"));
+                        GeneratedField::AssetIds => {
+                            if asset_ids__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("assetIds"));
                             }
-                            max_positions_per_pair__ = 
-                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
-                            ;
+                            asset_ids__ = Some(map_.next_value()?);
                         }
-                        GeneratedField::MaxExecutionBudget => {
-                            if max_execution_budget__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("maxExecutionBudget"));
+                        GeneratedField::StartHeight => {
+                            if start_height__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("startHeight"));
                             }
-                            max_execution_budget__ = 
+                            start_height__ = 
                                 Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
                             ;
                         }
@@ -1853,19 +1799,16 @@ impl<'de> serde::Deserialize<'de> for DexParameters {
                         }
                     }
                 }
-                Ok(DexParameters {
-                    is_enabled: is_enabled__.unwrap_or_default(),
-                    fixed_candidates: fixed_candidates__.unwrap_or_default(),
-                    max_hops: max_hops__.unwrap_or_default(),
-                    max_positions_per_pair: max_positions_per_pair__.unwrap_or_default(),
-                    max_execution_budget: max_execution_budget__.unwrap_or_default(),
+                Ok(CircuitBreakerStatusRequest {
+                    asset_ids: asset_ids__.unwrap_or_default(),
+                    start_height: start_height__.unwrap_or_default(),
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.dex.v1.DexParameters", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.CircuitBreakerStatusRequest", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for DirectedTradingPair {
+impl serde::Serialize for CircuitBreakerStatusResponse {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -1873,37 +1816,56 @@ impl serde::Serialize for DirectedTradingPair {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.start.is_some() {
+        if !self.value_balances.is_empty() {
             len += 1;
         }
-        if self.end.is_some() {
+        if self.max_execution_budget != 0 {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.DirectedTradingPair", len)?;
-        if let Some(v) = self.start.as_ref() {
-            struct_ser.serialize_field("start", v)?;
+        if !self.execution_budget_usage.is_empty() {
+            len += 1;
         }
-        if let Some(v) = self.end.as_ref() {
-            struct_ser.serialize_field("end", v)?;
+        if !self.trips.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.CircuitBreakerStatusResponse", len)?;
+        if !self.value_balances.is_empty() {
+            struct_ser.serialize_field("valueBalances", &self.value_balances)?;
+        }
+        if self.max_execution_budget != 0 {
+            struct_ser.serialize_field("maxExecutionBudget", &self.max_execution_budget)?;
+        }
+        if !self.execution_budget_usage.is_empty() {
+            struct_ser.serialize_field("executionBudgetUsage", &self.execution_budget_usage)?;
+        }
+        if !self.trips.is_empty() {
+            struct_ser.serialize_field("trips", &self.trips)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for DirectedTradingPair {
+impl<'de> serde::Deserialize<'de> for CircuitBreakerStatusResponse {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "start",
-            "end",
+            "value_balances",
+            "valueBalances",
+            "max_execution_budget",
+            "maxExecutionBudget",
+            "execution_budget_usage",
+            "executionBudgetUsage",
+            "trips",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            Start,
-            End,
+            ValueBalances,
+            MaxExecutionBudget,
+            ExecutionBudgetUsage,
+            Trips,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -1926,8 +1888,10 @@ impl<'de> serde::Deserialize<'de> for DirectedTradingPair {
                         E: serde::de::Error,
                     {
                         match value {
-                            "start" => Ok(GeneratedField::Start),
-                            "end" => Ok(GeneratedField::End),
+                            "valueBalances" | "value_balances" => Ok(GeneratedField::ValueBalances),
+                            "maxExecutionBudget" | "max_execution_budget" => Ok(GeneratedField::MaxExecutionBudget),
+                            "executionBudgetUsage" | "execution_budget_usage" => Ok(GeneratedField::ExecutionBudgetUsage),
+                            "trips" => Ok(GeneratedField::Trips),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -1937,47 +1901,65 @@ impl<'de> serde::Deserialize<'de> for DirectedTradingPair {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = DirectedTradingPair;
+            type Value = CircuitBreakerStatusResponse;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.dex.v1.DirectedTradingPair")
+                formatter.write_str("struct penumbra.core.component.dex.v1.CircuitBreakerStatusResponse")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<DirectedTradingPair, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<CircuitBreakerStatusResponse, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut start__ = None;
-                let mut end__ = None;
+                let mut value_balances__ = None;
+                let mut max_execution_budget__ = None;
+                let mut execution_budget_usage__ = None;
+                let mut trips__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::Start => {
-                            if start__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("start"));
+                        GeneratedField::ValueBalances => {
+                            if value_balances__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("valueBalances"));
                             }
-                            start__ = map_.next_value()?;
+                            value_balances__ = Some(map_.next_value()?);
                         }
-                        GeneratedField::End => {
-                            if end__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("end"));
+                        GeneratedField::MaxExecutionBudget => {
+                            if max_execution_budget__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("maxExecutionBudget"));
                             }
-                            end__ = map_.next_value()?;
+                            max_execution_budget__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::ExecutionBudgetUsage => {
+                            if execution_budget_usage__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("executionBudgetUsage"));
+                            }
+                            execution_budget_usage__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Trips => {
+                            if trips__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("trips"));
+                            }
+                            trips__ = Some(map_.next_value()?);
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(DirectedTradingPair {
-                    start: start__,
-                    end: end__,
+                Ok(CircuitBreakerStatusResponse {
+                    value_balances: value_balances__.unwrap_or_default(),
+                    max_execution_budget: max_execution_budget__.unwrap_or_default(),
+                    execution_budget_usage: execution_budget_usage__.unwrap_or_default(),
+                    trips: trips__.unwrap_or_default(),
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.dex.v1.DirectedTradingPair", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.CircuitBreakerStatusResponse", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for EventArbExecution {
+impl serde::Serialize for circuit_breaker_status_response::ExecutionBudgetUsage {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -1985,39 +1967,47 @@ impl serde::Serialize for EventArbExecution {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
+        if self.trading_pair.is_some() {
+            len += 1;
+        }
         if self.height != 0 {
             len += 1;
         }
-        if self.swap_execution.is_some() {
+        if self.used != 0 {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.EventArbExecution", len)?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.CircuitBreakerStatusResponse.ExecutionBudgetUsage", len)?;
+        if let Some(v) = self.trading_pair.as_ref() {
+            struct_ser.serialize_field("tradingPair", v)?;
+        }
         if self.height != 0 {
             #[allow(clippy::needless_borrow)]
             struct_ser.serialize_field("height", ToString::to_string(&self.height).as_str())?;
         }
-        if let Some(v) = self.swap_execution.as_ref() {
-            struct_ser.serialize_field("swapExecution", v)?;
+        if self.used != 0 {
+            struct_ser.serialize_field("used", &self.used)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for EventArbExecution {
+impl<'de> serde::Deserialize<'de> for circuit_breaker_status_response::ExecutionBudgetUsage {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
+            "trading_pair",
+            "tradingPair",
             "height",
-            "swap_execution",
-            "swapExecution",
+            "used",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
+            TradingPair,
             Height,
-            SwapExecution,
+            Used,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -2040,8 +2030,9 @@ impl<'de> serde::Deserialize<'de> for EventArbExecution {
                         E: serde::de::Error,
                     {
                         match value {
+                            "tradingPair" | "trading_pair" => Ok(GeneratedField::TradingPair),
                             "height" => Ok(GeneratedField::Height),
-                            "swapExecution" | "swap_execution" => Ok(GeneratedField::SwapExecution),
+                            "used" => Ok(GeneratedField::Used),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -2051,20 +2042,27 @@ impl<'de> serde::Deserialize<'de> for EventArbExecution {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = EventArbExecution;
+            type Value = circuit_breaker_status_response::ExecutionBudgetUsage;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.dex.v1.EventArbExecution")
+                formatter.write_str("struct penumbra.core.component.dex.v1.CircuitBreakerStatusResponse.ExecutionBudgetUsage")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<EventArbExecution, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<circuit_breaker_status_response::ExecutionBudgetUsage, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
+                let mut trading_pair__ = None;
                 let mut height__ = None;
-                let mut swap_execution__ = None;
+                let mut used__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
+                        GeneratedField::TradingPair => {
+                            if trading_pair__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("tradingPair"));
+                            }
+                            trading_pair__ = map_.next_value()?;
+                        }
                         GeneratedField::Height => {
                             if height__.is_some() {
                                 return Err(serde::de::Error::duplicate_field("height"));
@@ -2073,27 +2071,30 @@ impl<'de> serde::Deserialize<'de> for EventArbExecution {
                                 Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
                             ;
                         }
-                        GeneratedField::SwapExecution => {
-                            if swap_execution__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("swapExecution"));
+                        GeneratedField::Used => {
+                            if used__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("used"));
                             }
-                            swap_execution__ = map_.next_value()?;
+                            used__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(EventArbExecution {
+                Ok(circuit_breaker_status_response::ExecutionBudgetUsage {
+                    trading_pair: trading_pair__,
                     height: height__.unwrap_or_default(),
-                    swap_execution: swap_execution__,
+                    used: used__.unwrap_or_default(),
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.dex.v1.EventArbExecution", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.CircuitBreakerStatusResponse.ExecutionBudgetUsage", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for EventBatchSwap {
+impl serde::Serialize for circuit_breaker_status_response::ValueBalance {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -2101,48 +2102,38 @@ impl serde::Serialize for EventBatchSwap {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.batch_swap_output_data.is_some() {
-            len += 1;
-        }
-        if self.swap_execution_1_for_2.is_some() {
+        if self.asset_id.is_some() {
             len += 1;
         }
-        if self.swap_execution_2_for_1.is_some() {
+        if self.balance.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.EventBatchSwap", len)?;
-        if let Some(v) = self.batch_swap_output_data.as_ref() {
-            struct_ser.serialize_field("batchSwapOutputData", v)?;
-        }
-        if let Some(v) = self.swap_execution_1_for_2.as_ref() {
-            struct_ser.serialize_field("swapExecution1For2", v)?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.CircuitBreakerStatusResponse.ValueBalance", len)?;
+        if let Some(v) = self.asset_id.as_ref() {
+            struct_ser.serialize_field("assetId", v)?;
         }
-        if let Some(v) = self.swap_execution_2_for_1.as_ref() {
-            struct_ser.serialize_field("swapExecution2For1", v)?;
+        if let Some(v) = self.balance.as_ref() {
+            struct_ser.serialize_field("balance", v)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for EventBatchSwap {
+impl<'de> serde::Deserialize<'de> for circuit_breaker_status_response::ValueBalance {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "batch_swap_output_data",
-            "batchSwapOutputData",
-            "swap_execution_1_for_2",
-            "swapExecution1For2",
-            "swap_execution_2_for_1",
-            "swapExecution2For1",
+            "asset_id",
+            "assetId",
+            "balance",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            BatchSwapOutputData,
-            SwapExecution1For2,
-            SwapExecution2For1,
+            AssetId,
+            Balance,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -2165,9 +2156,8 @@ impl<'de> serde::Deserialize<'de> for EventBatchSwap {
                         E: serde::de::Error,
                     {
                         match value {
-                            "batchSwapOutputData" | "batch_swap_output_data" => Ok(GeneratedField::BatchSwapOutputData),
-                            "swapExecution1For2" | "swap_execution_1_for_2" => Ok(GeneratedField::SwapExecution1For2),
-                            "swapExecution2For1" | "swap_execution_2_for_1" => Ok(GeneratedField::SwapExecution2For1),
+                            "assetId" | "asset_id" => Ok(GeneratedField::AssetId),
+                            "balance" => Ok(GeneratedField::Balance),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -2177,55 +2167,47 @@ impl<'de> serde::Deserialize<'de> for EventBatchSwap {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = EventBatchSwap;
+            type Value = circuit_breaker_status_response::ValueBalance;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.dex.v1.EventBatchSwap")
+                formatter.write_str("struct penumbra.core.component.dex.v1.CircuitBreakerStatusResponse.ValueBalance")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<EventBatchSwap, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<circuit_breaker_status_response::ValueBalance, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut batch_swap_output_data__ = None;
-                let mut swap_execution_1_for_2__ = None;
-                let mut swap_execution_2_for_1__ = None;
+                let mut asset_id__ = None;
+                let mut balance__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::BatchSwapOutputData => {
-                            if batch_swap_output_data__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("batchSwapOutputData"));
+                        GeneratedField::AssetId => {
+                            if asset_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("assetId"));
                             }
-                            batch_swap_output_data__ = map_.next_value()?;
+                            asset_id__ = map_.next_value()?;
                         }
-                        GeneratedField::SwapExecution1For2 => {
-                            if swap_execution_1_for_2__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("swapExecution1For2"));
+                        GeneratedField::Balance => {
+                            if balance__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("balance"));
                             }
-                            swap_execution_1_for_2__ = map_.next_value()?;
-                        }
-                        GeneratedField::SwapExecution2For1 => {
-                            if swap_execution_2_for_1__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("swapExecution2For1"));
-                            }
-                            swap_execution_2_for_1__ = map_.next_value()?;
+                            balance__ = map_.next_value()?;
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(EventBatchSwap {
-                    batch_swap_output_data: batch_swap_output_data__,
-                    swap_execution_1_for_2: swap_execution_1_for_2__,
-                    swap_execution_2_for_1: swap_execution_2_for_1__,
+                Ok(circuit_breaker_status_response::ValueBalance {
+                    asset_id: asset_id__,
+                    balance: balance__,
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.dex.v1.EventBatchSwap", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.CircuitBreakerStatusResponse.ValueBalance", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for EventCandlestickData {
+impl serde::Serialize for DexParameters {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -2233,37 +2215,66 @@ impl serde::Serialize for EventCandlestickData {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.pair.is_some() {
+        if self.is_enabled {
             len += 1;
         }
-        if self.stick.is_some() {
+        if !self.fixed_candidates.is_empty() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.EventCandlestickData", len)?;
-        if let Some(v) = self.pair.as_ref() {
-            struct_ser.serialize_field("pair", v)?;
+        if self.max_hops != 0 {
+            len += 1;
         }
-        if let Some(v) = self.stick.as_ref() {
-            struct_ser.serialize_field("stick", v)?;
+        if self.max_positions_per_pair != 0 {
+            len += 1;
+        }
+        if self.max_execution_budget != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.DexParameters", len)?;
+        if self.is_enabled {
+            struct_ser.serialize_field("isEnabled", &self.is_enabled)?;
+        }
+        if !self.fixed_candidates.is_empty() {
+            struct_ser.serialize_field("fixedCandidates", &self.fixed_candidates)?;
+        }
+        if self.max_hops != 0 {
+            struct_ser.serialize_field("maxHops", &self.max_hops)?;
+        }
+        if self.max_positions_per_pair != 0 {
+            struct_ser.serialize_field("maxPositionsPerPair", &self.max_positions_per_pair)?;
+        }
+        if self.max_execution_budget != 0 {
+            struct_ser.serialize_field("maxExecutionBudget", &self.max_execution_budget)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for EventCandlestickData {
+impl<'de> serde::Deserialize<'de> for DexParameters {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "pair",
-            "stick",
+            "is_enabled",
+            "isEnabled",
+            "fixed_candidates",
+            "fixedCandidates",
+            "max_hops",
+            "maxHops",
+            "max_positions_per_pair",
+            "maxPositionsPerPair",
+            "max_execution_budget",
+            "maxExecutionBudget",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            Pair,
-            Stick,
+            IsEnabled,
+            FixedCandidates,
+            MaxHops,
+            MaxPositionsPerPair,
+            MaxExecutionBudget,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -2286,8 +2297,11 @@ impl<'de> serde::Deserialize<'de> for EventCandlestickData {
                         E: serde::de::Error,
                     {
                         match value {
-                            "pair" => Ok(GeneratedField::Pair),
-                            "stick" => Ok(GeneratedField::Stick),
+                            "isEnabled" | "is_enabled" => Ok(GeneratedField::IsEnabled),
+                            "fixedCandidates" | "fixed_candidates" => Ok(GeneratedField::FixedCandidates),
+                            "maxHops" | "max_hops" => Ok(GeneratedField::MaxHops),
+                            "maxPositionsPerPair" | "max_positions_per_pair" => Ok(GeneratedField::MaxPositionsPerPair),
+                            "maxExecutionBudget" | "max_execution_budget" => Ok(GeneratedField::MaxExecutionBudget),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -2297,47 +2311,77 @@ impl<'de> serde::Deserialize<'de> for EventCandlestickData {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = EventCandlestickData;
+            type Value = DexParameters;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.dex.v1.EventCandlestickData")
+                formatter.write_str("struct penumbra.core.component.dex.v1.DexParameters")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<EventCandlestickData, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<DexParameters, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut pair__ = None;
-                let mut stick__ = None;
+                let mut is_enabled__ = None;
+                let mut fixed_candidates__ = None;
+                let mut max_hops__ = None;
+                let mut max_positions_per_pair__ = None;
+                let mut max_execution_budget__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::Pair => {
-                            if pair__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("pair"));
+                        GeneratedField::IsEnabled => {
+                            if is_enabled__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("isEnabled"));
                             }
-                            pair__ = map_.next_value()?;
+                            is_enabled__ = Some(map_.next_value()?);
                         }
-                        GeneratedField::Stick => {
-                            if stick__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("stick"));
+                        GeneratedField::FixedCandidates => {
+                            if fixed_candidates__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("fixedCandidates"));
                             }
-                            stick__ = map_.next_value()?;
+                            fixed_candidates__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::MaxHops => {
+                            if max_hops__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("maxHops"));
+                            }
+                            max_hops__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::MaxPositionsPerPair => {
+                            if max_positions_per_pair__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("maxPositionsPerPair"));
+                            }
+                            max_positions_per_pair__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::MaxExecutionBudget => {
+                            if max_execution_budget__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("maxExecutionBudget"));
+                            }
+                            max_execution_budget__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(EventCandlestickData {
-                    pair: pair__,
-                    stick: stick__,
+                Ok(DexParameters {
+                    is_enabled: is_enabled__.unwrap_or_default(),
+                    fixed_candidates: fixed_candidates__.unwrap_or_default(),
+                    max_hops: max_hops__.unwrap_or_default(),
+                    max_positions_per_pair: max_positions_per_pair__.unwrap_or_default(),
+                    max_execution_budget: max_execution_budget__.unwrap_or_default(),
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.dex.v1.EventCandlestickData", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.DexParameters", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for EventPositionClose {
+impl serde::Serialize for DirectedTradingPair {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -2345,30 +2389,37 @@ impl serde::Serialize for EventPositionClose {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.position_id.is_some() {
+        if self.start.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.EventPositionClose", len)?;
-        if let Some(v) = self.position_id.as_ref() {
-            struct_ser.serialize_field("positionId", v)?;
+        if self.end.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.DirectedTradingPair", len)?;
+        if let Some(v) = self.start.as_ref() {
+            struct_ser.serialize_field("start", v)?;
+        }
+        if let Some(v) = self.end.as_ref() {
+            struct_ser.serialize_field("end", v)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for EventPositionClose {
+impl<'de> serde::Deserialize<'de> for DirectedTradingPair {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "position_id",
-            "positionId",
+            "start",
+            "end",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            PositionId,
+            Start,
+            End,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -2391,7 +2442,8 @@ impl<'de> serde::Deserialize<'de> for EventPositionClose {
                         E: serde::de::Error,
                     {
                         match value {
-                            "positionId" | "position_id" => Ok(GeneratedField::PositionId),
+                            "start" => Ok(GeneratedField::Start),
+                            "end" => Ok(GeneratedField::End),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -2401,39 +2453,47 @@ impl<'de> serde::Deserialize<'de> for EventPositionClose {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = EventPositionClose;
+            type Value = DirectedTradingPair;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.dex.v1.EventPositionClose")
+                formatter.write_str("struct penumbra.core.component.dex.v1.DirectedTradingPair")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<EventPositionClose, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<DirectedTradingPair, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut position_id__ = None;
+                let mut start__ = None;
+                let mut end__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::PositionId => {
-                            if position_id__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("positionId"));
+                        GeneratedField::Start => {
+                            if start__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("start"));
                             }
-                            position_id__ = map_.next_value()?;
+                            start__ = map_.next_value()?;
+                        }
+                        GeneratedField::End => {
+                            if end__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("end"));
+                            }
+                            end__ = map_.next_value()?;
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(EventPositionClose {
-                    position_id: position_id__,
+                Ok(DirectedTradingPair {
+                    start: start__,
+                    end: end__,
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.dex.v1.EventPositionClose", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.DirectedTradingPair", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for EventPositionExecution {
+impl serde::Serialize for EventArbExecution {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -2441,69 +2501,808 @@ impl serde::Serialize for EventPositionExecution {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.position_id.is_some() {
-            len += 1;
-        }
-        if self.trading_pair.is_some() {
-            len += 1;
-        }
-        if self.reserves_1.is_some() {
-            len += 1;
-        }
-        if self.reserves_2.is_some() {
-            len += 1;
-        }
-        if self.prev_reserves_1.is_some() {
-            len += 1;
-        }
-        if self.prev_reserves_2.is_some() {
+        if self.height != 0 {
             len += 1;
         }
-        if self.context.is_some() {
+        if self.swap_execution.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.EventPositionExecution", len)?;
-        if let Some(v) = self.position_id.as_ref() {
-            struct_ser.serialize_field("positionId", v)?;
-        }
-        if let Some(v) = self.trading_pair.as_ref() {
-            struct_ser.serialize_field("tradingPair", v)?;
-        }
-        if let Some(v) = self.reserves_1.as_ref() {
-            struct_ser.serialize_field("reserves1", v)?;
-        }
-        if let Some(v) = self.reserves_2.as_ref() {
-            struct_ser.serialize_field("reserves2", v)?;
-        }
-        if let Some(v) = self.prev_reserves_1.as_ref() {
-            struct_ser.serialize_field("prevReserves1", v)?;
-        }
-        if let Some(v) = self.prev_reserves_2.as_ref() {
-            struct_ser.serialize_field("prevReserves2", v)?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.EventArbExecution", len)?;
+        if self.height != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("height", ToString::to_string(&self.height).as_str())?;
         }
-        if let Some(v) = self.context.as_ref() {
-            struct_ser.serialize_field("context", v)?;
+        if let Some(v) = self.swap_execution.as_ref() {
+            struct_ser.serialize_field("swapExecution", v)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for EventPositionExecution {
+impl<'de> serde::Deserialize<'de> for EventArbExecution {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "position_id",
-            "positionId",
-            "trading_pair",
-            "tradingPair",
-            "reserves_1",
-            "reserves1",
-            "reserves_2",
-            "reserves2",
-            "prev_reserves_1",
-            "prevReserves1",
+            "height",
+            "swap_execution",
+            "swapExecution",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Height,
+            SwapExecution,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "height" => Ok(GeneratedField::Height),
+                            "swapExecution" | "swap_execution" => Ok(GeneratedField::SwapExecution),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = EventArbExecution;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.dex.v1.EventArbExecution")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<EventArbExecution, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut height__ = None;
+                let mut swap_execution__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Height => {
+                            if height__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("height"));
+                            }
+                            height__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::SwapExecution => {
+                            if swap_execution__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("swapExecution"));
+                            }
+                            swap_execution__ = map_.next_value()?;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(EventArbExecution {
+                    height: height__.unwrap_or_default(),
+                    swap_execution: swap_execution__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.EventArbExecution", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for EventBatchSwap {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.batch_swap_output_data.is_some() {
+            len += 1;
+        }
+        if self.swap_execution_1_for_2.is_some() {
+            len += 1;
+        }
+        if self.swap_execution_2_for_1.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.EventBatchSwap", len)?;
+        if let Some(v) = self.batch_swap_output_data.as_ref() {
+            struct_ser.serialize_field("batchSwapOutputData", v)?;
+        }
+        if let Some(v) = self.swap_execution_1_for_2.as_ref() {
+            struct_ser.serialize_field("swapExecution1For2", v)?;
+        }
+        if let Some(v) = self.swap_execution_2_for_1.as_ref() {
+            struct_ser.serialize_field("swapExecution2For1", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for EventBatchSwap {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "batch_swap_output_data",
+            "batchSwapOutputData",
+            "swap_execution_1_for_2",
+            "swapExecution1For2",
+            "swap_execution_2_for_1",
+            "swapExecution2For1",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            BatchSwapOutputData,
+            SwapExecution1For2,
+            SwapExecution2For1,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "batchSwapOutputData" | "batch_swap_output_data" => Ok(GeneratedField::BatchSwapOutputData),
+                            "swapExecution1For2" | "swap_execution_1_for_2" => Ok(GeneratedField::SwapExecution1For2),
+                            "swapExecution2For1" | "swap_execution_2_for_1" => Ok(GeneratedField::SwapExecution2For1),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = EventBatchSwap;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.dex.v1.EventBatchSwap")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<EventBatchSwap, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut batch_swap_output_data__ = None;
+                let mut swap_execution_1_for_2__ = None;
+                let mut swap_execution_2_for_1__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::BatchSwapOutputData => {
+                            if batch_swap_output_data__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("batchSwapOutputData"));
+                            }
+                            batch_swap_output_data__ = map_.next_value()?;
+                        }
+                        GeneratedField::SwapExecution1For2 => {
+                            if swap_execution_1_for_2__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("swapExecution1For2"));
+                            }
+                            swap_execution_1_for_2__ = map_.next_value()?;
+                        }
+                        GeneratedField::SwapExecution2For1 => {
+                            if swap_execution_2_for_1__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("swapExecution2For1"));
+                            }
+                            swap_execution_2_for_1__ = map_.next_value()?;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(EventBatchSwap {
+                    batch_swap_output_data: batch_swap_output_data__,
+                    swap_execution_1_for_2: swap_execution_1_for_2__,
+                    swap_execution_2_for_1: swap_execution_2_for_1__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.EventBatchSwap", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for EventCandlestickData {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.pair.is_some() {
+            len += 1;
+        }
+        if self.stick.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.EventCandlestickData", len)?;
+        if let Some(v) = self.pair.as_ref() {
+            struct_ser.serialize_field("pair", v)?;
+        }
+        if let Some(v) = self.stick.as_ref() {
+            struct_ser.serialize_field("stick", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for EventCandlestickData {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "pair",
+            "stick",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Pair,
+            Stick,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "pair" => Ok(GeneratedField::Pair),
+                            "stick" => Ok(GeneratedField::Stick),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = EventCandlestickData;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.dex.v1.EventCandlestickData")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<EventCandlestickData, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut pair__ = None;
+                let mut stick__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Pair => {
+                            if pair__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("pair"));
+                            }
+                            pair__ = map_.next_value()?;
+                        }
+                        GeneratedField::Stick => {
+                            if stick__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("stick"));
+                            }
+                            stick__ = map_.next_value()?;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(EventCandlestickData {
+                    pair: pair__,
+                    stick: stick__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.EventCandlestickData", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for EventCircuitBreakerTrip {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.height != 0 {
+            len += 1;
+        }
+        if self.trip.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.EventCircuitBreakerTrip", len)?;
+        if self.height != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("height", ToString::to_string(&self.height).as_str())?;
+        }
+        if let Some(v) = self.trip.as_ref() {
+            match v {
+                event_circuit_breaker_trip::Trip::Value(v) => {
+                    struct_ser.serialize_field("value", v)?;
+                }
+                event_circuit_breaker_trip::Trip::Execution(v) => {
+                    struct_ser.serialize_field("execution", v)?;
+                }
+            }
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for EventCircuitBreakerTrip {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "height",
+            "value",
+            "execution",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Height,
+            Value,
+            Execution,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "height" => Ok(GeneratedField::Height),
+                            "value" => Ok(GeneratedField::Value),
+                            "execution" => Ok(GeneratedField::Execution),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = EventCircuitBreakerTrip;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.dex.v1.EventCircuitBreakerTrip")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<EventCircuitBreakerTrip, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut height__ = None;
+                let mut trip__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Height => {
+                            if height__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("height"));
+                            }
+                            height__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Value => {
+                            if trip__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("value"));
+                            }
+                            trip__ = map_.next_value::<::std::option::Option<_>>()?.map(event_circuit_breaker_trip::Trip::Value)
+;
+                        }
+                        GeneratedField::Execution => {
+                            if trip__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("execution"));
+                            }
+                            trip__ = map_.next_value::<::std::option::Option<_>>()?.map(event_circuit_breaker_trip::Trip::Execution)
+;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(EventCircuitBreakerTrip {
+                    height: height__.unwrap_or_default(),
+                    trip: trip__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.EventCircuitBreakerTrip", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for EventPositionClose {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.position_id.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.EventPositionClose", len)?;
+        if let Some(v) = self.position_id.as_ref() {
+            struct_ser.serialize_field("positionId", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for EventPositionClose {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "position_id",
+            "positionId",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            PositionId,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "positionId" | "position_id" => Ok(GeneratedField::PositionId),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = EventPositionClose;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.dex.v1.EventPositionClose")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<EventPositionClose, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut position_id__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::PositionId => {
+                            if position_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("positionId"));
+                            }
+                            position_id__ = map_.next_value()?;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(EventPositionClose {
+                    position_id: position_id__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.EventPositionClose", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for EventPositionEvicted {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.position_id.is_some() {
+            len += 1;
+        }
+        if self.trading_pair.is_some() {
+            len += 1;
+        }
+        if self.reserves_1.is_some() {
+            len += 1;
+        }
+        if self.reserves_2.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.EventPositionEvicted", len)?;
+        if let Some(v) = self.position_id.as_ref() {
+            struct_ser.serialize_field("positionId", v)?;
+        }
+        if let Some(v) = self.trading_pair.as_ref() {
+            struct_ser.serialize_field("tradingPair", v)?;
+        }
+        if let Some(v) = self.reserves_1.as_ref() {
+            struct_ser.serialize_field("reserves1", v)?;
+        }
+        if let Some(v) = self.reserves_2.as_ref() {
+            struct_ser.serialize_field("reserves2", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for EventPositionEvicted {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "position_id",
+            "positionId",
+            "trading_pair",
+            "tradingPair",
+            "reserves_1",
+            "reserves1",
+            "reserves_2",
+            "reserves2",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            PositionId,
+            TradingPair,
+            Reserves1,
+            Reserves2,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "positionId" | "position_id" => Ok(GeneratedField::PositionId),
+                            "tradingPair" | "trading_pair" => Ok(GeneratedField::TradingPair),
+                            "reserves1" | "reserves_1" => Ok(GeneratedField::Reserves1),
+                            "reserves2" | "reserves_2" => Ok(GeneratedField::Reserves2),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = EventPositionEvicted;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.dex.v1.EventPositionEvicted")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<EventPositionEvicted, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut position_id__ = None;
+                let mut trading_pair__ = None;
+                let mut reserves_1__ = None;
+                let mut reserves_2__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::PositionId => {
+                            if position_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("positionId"));
+                            }
+                            position_id__ = map_.next_value()?;
+                        }
+                        GeneratedField::TradingPair => {
+                            if trading_pair__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("tradingPair"));
+                            }
+                            trading_pair__ = map_.next_value()?;
+                        }
+                        GeneratedField::Reserves1 => {
+                            if reserves_1__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("reserves1"));
+                            }
+                            reserves_1__ = map_.next_value()?;
+                        }
+                        GeneratedField::Reserves2 => {
+                            if reserves_2__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("reserves2"));
+                            }
+                            reserves_2__ = map_.next_value()?;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(EventPositionEvicted {
+                    position_id: position_id__,
+                    trading_pair: trading_pair__,
+                    reserves_1: reserves_1__,
+                    reserves_2: reserves_2__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.EventPositionEvicted", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for EventPositionExecution {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.position_id.is_some() {
+            len += 1;
+        }
+        if self.trading_pair.is_some() {
+            len += 1;
+        }
+        if self.reserves_1.is_some() {
+            len += 1;
+        }
+        if self.reserves_2.is_some() {
+            len += 1;
+        }
+        if self.prev_reserves_1.is_some() {
+            len += 1;
+        }
+        if self.prev_reserves_2.is_some() {
+            len += 1;
+        }
+        if self.context.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.EventPositionExecution", len)?;
+        if let Some(v) = self.position_id.as_ref() {
+            struct_ser.serialize_field("positionId", v)?;
+        }
+        if let Some(v) = self.trading_pair.as_ref() {
+            struct_ser.serialize_field("tradingPair", v)?;
+        }
+        if let Some(v) = self.reserves_1.as_ref() {
+            struct_ser.serialize_field("reserves1", v)?;
+        }
+        if let Some(v) = self.reserves_2.as_ref() {
+            struct_ser.serialize_field("reserves2", v)?;
+        }
+        if let Some(v) = self.prev_reserves_1.as_ref() {
+            struct_ser.serialize_field("prevReserves1", v)?;
+        }
+        if let Some(v) = self.prev_reserves_2.as_ref() {
+            struct_ser.serialize_field("prevReserves2", v)?;
+        }
+        if let Some(v) = self.context.as_ref() {
+            struct_ser.serialize_field("context", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for EventPositionExecution {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "position_id",
+            "positionId",
+            "trading_pair",
+            "tradingPair",
+            "reserves_1",
+            "reserves1",
+            "reserves_2",
+            "reserves2",
+            "prev_reserves_1",
+            "prevReserves1",
             "prev_reserves_2",
             "prevReserves2",
             "context",
@@ -2512,12 +3311,519 @@ impl<'de> serde::Deserialize<'de> for EventPositionExecution {
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
             PositionId,
-            TradingPair,
-            Reserves1,
-            Reserves2,
-            PrevReserves1,
-            PrevReserves2,
-            Context,
+            TradingPair,
+            Reserves1,
+            Reserves2,
+            PrevReserves1,
+            PrevReserves2,
+            Context,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "positionId" | "position_id" => Ok(GeneratedField::PositionId),
+                            "tradingPair" | "trading_pair" => Ok(GeneratedField::TradingPair),
+                            "reserves1" | "reserves_1" => Ok(GeneratedField::Reserves1),
+                            "reserves2" | "reserves_2" => Ok(GeneratedField::Reserves2),
+                            "prevReserves1" | "prev_reserves_1" => Ok(GeneratedField::PrevReserves1),
+                            "prevReserves2" | "prev_reserves_2" => Ok(GeneratedField::PrevReserves2),
+                            "context" => Ok(GeneratedField::Context),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = EventPositionExecution;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.dex.v1.EventPositionExecution")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<EventPositionExecution, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut position_id__ = None;
+                let mut trading_pair__ = None;
+                let mut reserves_1__ = None;
+                let mut reserves_2__ = None;
+                let mut prev_reserves_1__ = None;
+                let mut prev_reserves_2__ = None;
+                let mut context__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::PositionId => {
+                            if position_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("positionId"));
+                            }
+                            position_id__ = map_.next_value()?;
+                        }
+                        GeneratedField::TradingPair => {
+                            if trading_pair__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("tradingPair"));
+                            }
+                            trading_pair__ = map_.next_value()?;
+                        }
+                        GeneratedField::Reserves1 => {
+                            if reserves_1__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("reserves1"));
+                            }
+                            reserves_1__ = map_.next_value()?;
+                        }
+                        GeneratedField::Reserves2 => {
+                            if reserves_2__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("reserves2"));
+                            }
+                            reserves_2__ = map_.next_value()?;
+                        }
+                        GeneratedField::PrevReserves1 => {
+                            if prev_reserves_1__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("prevReserves1"));
+                            }
+                            prev_reserves_1__ = map_.next_value()?;
+                        }
+                        GeneratedField::PrevReserves2 => {
+                            if prev_reserves_2__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("prevReserves2"));
+                            }
+                            prev_reserves_2__ = map_.next_value()?;
+                        }
+                        GeneratedField::Context => {
+                            if context__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("context"));
+                            }
+                            context__ = map_.next_value()?;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(EventPositionExecution {
+                    position_id: position_id__,
+                    trading_pair: trading_pair__,
+                    reserves_1: reserves_1__,
+                    reserves_2: reserves_2__,
+                    prev_reserves_1: prev_reserves_1__,
+                    prev_reserves_2: prev_reserves_2__,
+                    context: context__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.EventPositionExecution", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for EventPositionOpen {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.position_id.is_some() {
+            len += 1;
+        }
+        if self.trading_pair.is_some() {
+            len += 1;
+        }
+        if self.reserves_1.is_some() {
+            len += 1;
+        }
+        if self.reserves_2.is_some() {
+            len += 1;
+        }
+        if self.trading_fee != 0 {
+            len += 1;
+        }
+        if self.position.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.EventPositionOpen", len)?;
+        if let Some(v) = self.position_id.as_ref() {
+            struct_ser.serialize_field("positionId", v)?;
+        }
+        if let Some(v) = self.trading_pair.as_ref() {
+            struct_ser.serialize_field("tradingPair", v)?;
+        }
+        if let Some(v) = self.reserves_1.as_ref() {
+            struct_ser.serialize_field("reserves1", v)?;
+        }
+        if let Some(v) = self.reserves_2.as_ref() {
+            struct_ser.serialize_field("reserves2", v)?;
+        }
+        if self.trading_fee != 0 {
+            struct_ser.serialize_field("tradingFee", &self.trading_fee)?;
+        }
+        if let Some(v) = self.position.as_ref() {
+            struct_ser.serialize_field("position", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for EventPositionOpen {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "position_id",
+            "positionId",
+            "trading_pair",
+            "tradingPair",
+            "reserves_1",
+            "reserves1",
+            "reserves_2",
+            "reserves2",
+            "trading_fee",
+            "tradingFee",
+            "position",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            PositionId,
+            TradingPair,
+            Reserves1,
+            Reserves2,
+            TradingFee,
+            Position,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "positionId" | "position_id" => Ok(GeneratedField::PositionId),
+                            "tradingPair" | "trading_pair" => Ok(GeneratedField::TradingPair),
+                            "reserves1" | "reserves_1" => Ok(GeneratedField::Reserves1),
+                            "reserves2" | "reserves_2" => Ok(GeneratedField::Reserves2),
+                            "tradingFee" | "trading_fee" => Ok(GeneratedField::TradingFee),
+                            "position" => Ok(GeneratedField::Position),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = EventPositionOpen;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.dex.v1.EventPositionOpen")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<EventPositionOpen, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut position_id__ = None;
+                let mut trading_pair__ = None;
+                let mut reserves_1__ = None;
+                let mut reserves_2__ = None;
+                let mut trading_fee__ = None;
+                let mut position__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::PositionId => {
+                            if position_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("positionId"));
+                            }
+                            position_id__ = map_.next_value()?;
+                        }
+                        GeneratedField::TradingPair => {
+                            if trading_pair__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("tradingPair"));
+                            }
+                            trading_pair__ = map_.next_value()?;
+                        }
+                        GeneratedField::Reserves1 => {
+                            if reserves_1__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("reserves1"));
+                            }
+                            reserves_1__ = map_.next_value()?;
+                        }
+                        GeneratedField::Reserves2 => {
+                            if reserves_2__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("reserves2"));
+                            }
+                            reserves_2__ = map_.next_value()?;
+                        }
+                        GeneratedField::TradingFee => {
+                            if trading_fee__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("tradingFee"));
+                            }
+                            trading_fee__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Position => {
+                            if position__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("position"));
+                            }
+                            position__ = map_.next_value()?;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(EventPositionOpen {
+                    position_id: position_id__,
+                    trading_pair: trading_pair__,
+                    reserves_1: reserves_1__,
+                    reserves_2: reserves_2__,
+                    trading_fee: trading_fee__.unwrap_or_default(),
+                    position: position__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.EventPositionOpen", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for EventPositionWithdraw {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.position_id.is_some() {
+            len += 1;
+        }
+        if self.trading_pair.is_some() {
+            len += 1;
+        }
+        if self.reserves_1.is_some() {
+            len += 1;
+        }
+        if self.reserves_2.is_some() {
+            len += 1;
+        }
+        if self.sequence != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.EventPositionWithdraw", len)?;
+        if let Some(v) = self.position_id.as_ref() {
+            struct_ser.serialize_field("positionId", v)?;
+        }
+        if let Some(v) = self.trading_pair.as_ref() {
+            struct_ser.serialize_field("tradingPair", v)?;
+        }
+        if let Some(v) = self.reserves_1.as_ref() {
+            struct_ser.serialize_field("reserves1", v)?;
+        }
+        if let Some(v) = self.reserves_2.as_ref() {
+            struct_ser.serialize_field("reserves2", v)?;
+        }
+        if self.sequence != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("sequence", ToString::to_string(&self.sequence).as_str())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for EventPositionWithdraw {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "position_id",
+            "positionId",
+            "trading_pair",
+            "tradingPair",
+            "reserves_1",
+            "reserves1",
+            "reserves_2",
+            "reserves2",
+            "sequence",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            PositionId,
+            TradingPair,
+            Reserves1,
+            Reserves2,
+            Sequence,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "positionId" | "position_id" => Ok(GeneratedField::PositionId),
+                            "tradingPair" | "trading_pair" => Ok(GeneratedField::TradingPair),
+                            "reserves1" | "reserves_1" => Ok(GeneratedField::Reserves1),
+                            "reserves2" | "reserves_2" => Ok(GeneratedField::Reserves2),
+                            "sequence" => Ok(GeneratedField::Sequence),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = EventPositionWithdraw;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.dex.v1.EventPositionWithdraw")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<EventPositionWithdraw, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut position_id__ = None;
+                let mut trading_pair__ = None;
+                let mut reserves_1__ = None;
+                let mut reserves_2__ = None;
+                let mut sequence__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::PositionId => {
+                            if position_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("positionId"));
+                            }
+                            position_id__ = map_.next_value()?;
+                        }
+                        GeneratedField::TradingPair => {
+                            if trading_pair__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("tradingPair"));
+                            }
+                            trading_pair__ = map_.next_value()?;
+                        }
+                        GeneratedField::Reserves1 => {
+                            if reserves_1__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("reserves1"));
+                            }
+                            reserves_1__ = map_.next_value()?;
+                        }
+                        GeneratedField::Reserves2 => {
+                            if reserves_2__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("reserves2"));
+                            }
+                            reserves_2__ = map_.next_value()?;
+                        }
+                        GeneratedField::Sequence => {
+                            if sequence__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("sequence"));
+                            }
+                            sequence__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(EventPositionWithdraw {
+                    position_id: position_id__,
+                    trading_pair: trading_pair__,
+                    reserves_1: reserves_1__,
+                    reserves_2: reserves_2__,
+                    sequence: sequence__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.EventPositionWithdraw", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for EventQueuePositionClose {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.position_id.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.EventQueuePositionClose", len)?;
+        if let Some(v) = self.position_id.as_ref() {
+            struct_ser.serialize_field("positionId", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for EventQueuePositionClose {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "position_id",
+            "positionId",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            PositionId,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -2541,12 +3847,6 @@ impl<'de> serde::Deserialize<'de> for EventPositionExecution {
                     {
                         match value {
                             "positionId" | "position_id" => Ok(GeneratedField::PositionId),
-                            "tradingPair" | "trading_pair" => Ok(GeneratedField::TradingPair),
-                            "reserves1" | "reserves_1" => Ok(GeneratedField::Reserves1),
-                            "reserves2" | "reserves_2" => Ok(GeneratedField::Reserves2),
-                            "prevReserves1" | "prev_reserves_1" => Ok(GeneratedField::PrevReserves1),
-                            "prevReserves2" | "prev_reserves_2" => Ok(GeneratedField::PrevReserves2),
-                            "context" => Ok(GeneratedField::Context),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -2556,23 +3856,17 @@ impl<'de> serde::Deserialize<'de> for EventPositionExecution {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = EventPositionExecution;
+            type Value = EventQueuePositionClose;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.dex.v1.EventPositionExecution")
+                formatter.write_str("struct penumbra.core.component.dex.v1.EventQueuePositionClose")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<EventPositionExecution, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<EventQueuePositionClose, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
                 let mut position_id__ = None;
-                let mut trading_pair__ = None;
-                let mut reserves_1__ = None;
-                let mut reserves_2__ = None;
-                let mut prev_reserves_1__ = None;
-                let mut prev_reserves_2__ = None;
-                let mut context__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
                         GeneratedField::PositionId => {
@@ -2581,62 +3875,170 @@ impl<'de> serde::Deserialize<'de> for EventPositionExecution {
                             }
                             position_id__ = map_.next_value()?;
                         }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(EventQueuePositionClose {
+                    position_id: position_id__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.EventQueuePositionClose", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for EventSwap {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.trading_pair.is_some() {
+            len += 1;
+        }
+        if self.delta_1_i.is_some() {
+            len += 1;
+        }
+        if self.delta_2_i.is_some() {
+            len += 1;
+        }
+        if self.swap_commitment.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.EventSwap", len)?;
+        if let Some(v) = self.trading_pair.as_ref() {
+            struct_ser.serialize_field("tradingPair", v)?;
+        }
+        if let Some(v) = self.delta_1_i.as_ref() {
+            struct_ser.serialize_field("delta1I", v)?;
+        }
+        if let Some(v) = self.delta_2_i.as_ref() {
+            struct_ser.serialize_field("delta2I", v)?;
+        }
+        if let Some(v) = self.swap_commitment.as_ref() {
+            struct_ser.serialize_field("swapCommitment", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for EventSwap {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "trading_pair",
+            "tradingPair",
+            "delta_1_i",
+            "delta1I",
+            "delta_2_i",
+            "delta2I",
+            "swap_commitment",
+            "swapCommitment",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            TradingPair,
+            Delta1I,
+            Delta2I,
+            SwapCommitment,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "tradingPair" | "trading_pair" => Ok(GeneratedField::TradingPair),
+                            "delta1I" | "delta_1_i" => Ok(GeneratedField::Delta1I),
+                            "delta2I" | "delta_2_i" => Ok(GeneratedField::Delta2I),
+                            "swapCommitment" | "swap_commitment" => Ok(GeneratedField::SwapCommitment),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = EventSwap;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.dex.v1.EventSwap")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<EventSwap, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut trading_pair__ = None;
+                let mut delta_1_i__ = None;
+                let mut delta_2_i__ = None;
+                let mut swap_commitment__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
                         GeneratedField::TradingPair => {
                             if trading_pair__.is_some() {
                                 return Err(serde::de::Error::duplicate_field("tradingPair"));
                             }
                             trading_pair__ = map_.next_value()?;
                         }
-                        GeneratedField::Reserves1 => {
-                            if reserves_1__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("reserves1"));
-                            }
-                            reserves_1__ = map_.next_value()?;
-                        }
-                        GeneratedField::Reserves2 => {
-                            if reserves_2__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("reserves2"));
-                            }
-                            reserves_2__ = map_.next_value()?;
-                        }
-                        GeneratedField::PrevReserves1 => {
-                            if prev_reserves_1__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("prevReserves1"));
+                        GeneratedField::Delta1I => {
+                            if delta_1_i__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("delta1I"));
                             }
-                            prev_reserves_1__ = map_.next_value()?;
+                            delta_1_i__ = map_.next_value()?;
                         }
-                        GeneratedField::PrevReserves2 => {
-                            if prev_reserves_2__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("prevReserves2"));
+                        GeneratedField::Delta2I => {
+                            if delta_2_i__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("delta2I"));
                             }
-                            prev_reserves_2__ = map_.next_value()?;
+                            delta_2_i__ = map_.next_value()?;
                         }
-                        GeneratedField::Context => {
-                            if context__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("context"));
+                        GeneratedField::SwapCommitment => {
+                            if swap_commitment__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("swapCommitment"));
                             }
-                            context__ = map_.next_value()?;
+                            swap_commitment__ = map_.next_value()?;
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(EventPositionExecution {
-                    position_id: position_id__,
+                Ok(EventSwap {
                     trading_pair: trading_pair__,
-                    reserves_1: reserves_1__,
-                    reserves_2: reserves_2__,
-                    prev_reserves_1: prev_reserves_1__,
-                    prev_reserves_2: prev_reserves_2__,
-                    context: context__,
+                    delta_1_i: delta_1_i__,
+                    delta_2_i: delta_2_i__,
+                    swap_commitment: swap_commitment__,
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.dex.v1.EventPositionExecution", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.EventSwap", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for EventPositionOpen {
+impl serde::Serialize for EventSwapClaim {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -2644,74 +4046,56 @@ impl serde::Serialize for EventPositionOpen {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.position_id.is_some() {
-            len += 1;
-        }
         if self.trading_pair.is_some() {
             len += 1;
         }
-        if self.reserves_1.is_some() {
-            len += 1;
-        }
-        if self.reserves_2.is_some() {
+        if self.output_1_commitment.is_some() {
             len += 1;
         }
-        if self.trading_fee != 0 {
+        if self.output_2_commitment.is_some() {
             len += 1;
         }
-        if self.position.is_some() {
+        if self.nullifier.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.EventPositionOpen", len)?;
-        if let Some(v) = self.position_id.as_ref() {
-            struct_ser.serialize_field("positionId", v)?;
-        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.EventSwapClaim", len)?;
         if let Some(v) = self.trading_pair.as_ref() {
             struct_ser.serialize_field("tradingPair", v)?;
         }
-        if let Some(v) = self.reserves_1.as_ref() {
-            struct_ser.serialize_field("reserves1", v)?;
-        }
-        if let Some(v) = self.reserves_2.as_ref() {
-            struct_ser.serialize_field("reserves2", v)?;
+        if let Some(v) = self.output_1_commitment.as_ref() {
+            struct_ser.serialize_field("output1Commitment", v)?;
         }
-        if self.trading_fee != 0 {
-            struct_ser.serialize_field("tradingFee", &self.trading_fee)?;
+        if let Some(v) = self.output_2_commitment.as_ref() {
+            struct_ser.serialize_field("output2Commitment", v)?;
         }
-        if let Some(v) = self.position.as_ref() {
-            struct_ser.serialize_field("position", v)?;
+        if let Some(v) = self.nullifier.as_ref() {
+            struct_ser.serialize_field("nullifier", v)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for EventPositionOpen {
+impl<'de> serde::Deserialize<'de> for EventSwapClaim {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "position_id",
-            "positionId",
             "trading_pair",
             "tradingPair",
-            "reserves_1",
-            "reserves1",
-            "reserves_2",
-            "reserves2",
-            "trading_fee",
-            "tradingFee",
-            "position",
+            "output_1_commitment",
+            "output1Commitment",
+            "output_2_commitment",
+            "output2Commitment",
+            "nullifier",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            PositionId,
             TradingPair,
-            Reserves1,
-            Reserves2,
-            TradingFee,
-            Position,
+            Output1Commitment,
+            Output2Commitment,
+            Nullifier,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -2734,12 +4118,10 @@ impl<'de> serde::Deserialize<'de> for EventPositionOpen {
                         E: serde::de::Error,
                     {
                         match value {
-                            "positionId" | "position_id" => Ok(GeneratedField::PositionId),
                             "tradingPair" | "trading_pair" => Ok(GeneratedField::TradingPair),
-                            "reserves1" | "reserves_1" => Ok(GeneratedField::Reserves1),
-                            "reserves2" | "reserves_2" => Ok(GeneratedField::Reserves2),
-                            "tradingFee" | "trading_fee" => Ok(GeneratedField::TradingFee),
-                            "position" => Ok(GeneratedField::Position),
+                            "output1Commitment" | "output_1_commitment" => Ok(GeneratedField::Output1Commitment),
+                            "output2Commitment" | "output_2_commitment" => Ok(GeneratedField::Output2Commitment),
+                            "nullifier" => Ok(GeneratedField::Nullifier),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -2749,81 +4131,63 @@ impl<'de> serde::Deserialize<'de> for EventPositionOpen {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = EventPositionOpen;
+            type Value = EventSwapClaim;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.dex.v1.EventPositionOpen")
+                formatter.write_str("struct penumbra.core.component.dex.v1.EventSwapClaim")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<EventPositionOpen, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<EventSwapClaim, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut position_id__ = None;
                 let mut trading_pair__ = None;
-                let mut reserves_1__ = None;
-                let mut reserves_2__ = None;
-                let mut trading_fee__ = None;
-                let mut position__ = None;
+                let mut output_1_commitment__ = None;
+                let mut output_2_commitment__ = None;
+                let mut nullifier__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::PositionId => {
-                            if position_id__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("positionId"));
-                            }
-                            position_id__ = map_.next_value()?;
-                        }
                         GeneratedField::TradingPair => {
                             if trading_pair__.is_some() {
                                 return Err(serde::de::Error::duplicate_field("tradingPair"));
                             }
                             trading_pair__ = map_.next_value()?;
                         }
-                        GeneratedField::Reserves1 => {
-                            if reserves_1__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("reserves1"));
-                            }
-                            reserves_1__ = map_.next_value()?;
-                        }
-                        GeneratedField::Reserves2 => {
-                            if reserves_2__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("reserves2"));
+                        GeneratedField::Output1Commitment => {
+                            if output_1_commitment__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("output1Commitment"));
                             }
-                            reserves_2__ = map_.next_value()?;
+                            output_1_commitment__ = map_.next_value()?;
                         }
-                        GeneratedField::TradingFee => {
-                            if trading_fee__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("tradingFee"));
+                        GeneratedField::Output2Commitment => {
+                            if output_2_commitment__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("output2Commitment"));
                             }
-                            trading_fee__ = 
-                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
-                            ;
+                            output_2_commitment__ = map_.next_value()?;
                         }
-                        GeneratedField::Position => {
-                            if position__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("position"));
+                        GeneratedField::Nullifier => {
+                            if nullifier__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("nullifier"));
                             }
-                            position__ = map_.next_value()?;
+                            nullifier__ = map_.next_value()?;
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(EventPositionOpen {
-                    position_id: position_id__,
+                Ok(EventSwapClaim {
                     trading_pair: trading_pair__,
-                    reserves_1: reserves_1__,
-                    reserves_2: reserves_2__,
-                    trading_fee: trading_fee__.unwrap_or_default(),
-                    position: position__,
+                    output_1_commitment: output_1_commitment__,
+                    output_2_commitment: output_2_commitment__,
+                    nullifier: nullifier__,
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.dex.v1.EventPositionOpen", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.EventSwapClaim", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for EventPositionWithdraw {
+impl serde::Serialize for EventValueCircuitBreakerCredit {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -2831,66 +4195,48 @@ impl serde::Serialize for EventPositionWithdraw {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.position_id.is_some() {
-            len += 1;
-        }
-        if self.trading_pair.is_some() {
-            len += 1;
-        }
-        if self.reserves_1.is_some() {
+        if self.asset_id.is_some() {
             len += 1;
         }
-        if self.reserves_2.is_some() {
+        if self.previous_balance.is_some() {
             len += 1;
         }
-        if self.sequence != 0 {
+        if self.new_balance.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.EventPositionWithdraw", len)?;
-        if let Some(v) = self.position_id.as_ref() {
-            struct_ser.serialize_field("positionId", v)?;
-        }
-        if let Some(v) = self.trading_pair.as_ref() {
-            struct_ser.serialize_field("tradingPair", v)?;
-        }
-        if let Some(v) = self.reserves_1.as_ref() {
-            struct_ser.serialize_field("reserves1", v)?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.EventValueCircuitBreakerCredit", len)?;
+        if let Some(v) = self.asset_id.as_ref() {
+            struct_ser.serialize_field("assetId", v)?;
         }
-        if let Some(v) = self.reserves_2.as_ref() {
-            struct_ser.serialize_field("reserves2", v)?;
+        if let Some(v) = self.previous_balance.as_ref() {
+            struct_ser.serialize_field("previousBalance", v)?;
         }
-        if self.sequence != 0 {
-            #[allow(clippy::needless_borrow)]
-            struct_ser.serialize_field("sequence", ToString::to_string(&self.sequence).as_str())?;
+        if let Some(v) = self.new_balance.as_ref() {
+            struct_ser.serialize_field("newBalance", v)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for EventPositionWithdraw {
+impl<'de> serde::Deserialize<'de> for EventValueCircuitBreakerCredit {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "position_id",
-            "positionId",
-            "trading_pair",
-            "tradingPair",
-            "reserves_1",
-            "reserves1",
-            "reserves_2",
-            "reserves2",
-            "sequence",
+            "asset_id",
+            "assetId",
+            "previous_balance",
+            "previousBalance",
+            "new_balance",
+            "newBalance",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            PositionId,
-            TradingPair,
-            Reserves1,
-            Reserves2,
-            Sequence,
+            AssetId,
+            PreviousBalance,
+            NewBalance,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -2913,11 +4259,9 @@ impl<'de> serde::Deserialize<'de> for EventPositionWithdraw {
                         E: serde::de::Error,
                     {
                         match value {
-                            "positionId" | "position_id" => Ok(GeneratedField::PositionId),
-                            "tradingPair" | "trading_pair" => Ok(GeneratedField::TradingPair),
-                            "reserves1" | "reserves_1" => Ok(GeneratedField::Reserves1),
-                            "reserves2" | "reserves_2" => Ok(GeneratedField::Reserves2),
-                            "sequence" => Ok(GeneratedField::Sequence),
+                            "assetId" | "asset_id" => Ok(GeneratedField::AssetId),
+                            "previousBalance" | "previous_balance" => Ok(GeneratedField::PreviousBalance),
+                            "newBalance" | "new_balance" => Ok(GeneratedField::NewBalance),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -2927,73 +4271,55 @@ impl<'de> serde::Deserialize<'de> for EventPositionWithdraw {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = EventPositionWithdraw;
+            type Value = EventValueCircuitBreakerCredit;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.dex.v1.EventPositionWithdraw")
+                formatter.write_str("struct penumbra.core.component.dex.v1.EventValueCircuitBreakerCredit")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<EventPositionWithdraw, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<EventValueCircuitBreakerCredit, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut position_id__ = None;
-                let mut trading_pair__ = None;
-                let mut reserves_1__ = None;
-                let mut reserves_2__ = None;
-                let mut sequence__ = None;
+                let mut asset_id__ = None;
+                let mut previous_balance__ = None;
+                let mut new_balance__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::PositionId => {
-                            if position_id__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("positionId"));
-                            }
-                            position_id__ = map_.next_value()?;
-                        }
-                        GeneratedField::TradingPair => {
-                            if trading_pair__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("tradingPair"));
-                            }
-                            trading_pair__ = map_.next_value()?;
-                        }
-                        GeneratedField::Reserves1 => {
-                            if reserves_1__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("reserves1"));
+                        GeneratedField::AssetId => {
+                            if asset_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("assetId"));
                             }
-                            reserves_1__ = map_.next_value()?;
+                            asset_id__ = map_.next_value()?;
                         }
-                        GeneratedField::Reserves2 => {
-                            if reserves_2__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("reserves2"));
+                        GeneratedField::PreviousBalance => {
+                            if previous_balance__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("previousBalance"));
                             }
-                            reserves_2__ = map_.next_value()?;
+                            previous_balance__ = map_.next_value()?;
                         }
-                        GeneratedField::Sequence => {
-                            if sequence__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("sequence"));
+                        GeneratedField::NewBalance => {
+                            if new_balance__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("newBalance"));
                             }
-                            sequence__ = 
-                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
-                            ;
+                            new_balance__ = map_.next_value()?;
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(EventPositionWithdraw {
-                    position_id: position_id__,
-                    trading_pair: trading_pair__,
-                    reserves_1: reserves_1__,
-                    reserves_2: reserves_2__,
-                    sequence: sequence__.unwrap_or_default(),
+                Ok(EventValueCircuitBreakerCredit {
+                    asset_id: asset_id__,
+                    previous_balance: previous_balance__,
+                    new_balance: new_balance__,
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.dex.v1.EventPositionWithdraw", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.EventValueCircuitBreakerCredit", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for EventQueuePositionClose {
+impl serde::Serialize for EventValueCircuitBreakerDebit {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -3001,30 +4327,48 @@ impl serde::Serialize for EventQueuePositionClose {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.position_id.is_some() {
+        if self.asset_id.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.EventQueuePositionClose", len)?;
-        if let Some(v) = self.position_id.as_ref() {
-            struct_ser.serialize_field("positionId", v)?;
+        if self.previous_balance.is_some() {
+            len += 1;
+        }
+        if self.new_balance.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.EventValueCircuitBreakerDebit", len)?;
+        if let Some(v) = self.asset_id.as_ref() {
+            struct_ser.serialize_field("assetId", v)?;
+        }
+        if let Some(v) = self.previous_balance.as_ref() {
+            struct_ser.serialize_field("previousBalance", v)?;
+        }
+        if let Some(v) = self.new_balance.as_ref() {
+            struct_ser.serialize_field("newBalance", v)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for EventQueuePositionClose {
+impl<'de> serde::Deserialize<'de> for EventValueCircuitBreakerDebit {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "position_id",
-            "positionId",
+            "asset_id",
+            "assetId",
+            "previous_balance",
+            "previousBalance",
+            "new_balance",
+            "newBalance",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            PositionId,
+            AssetId,
+            PreviousBalance,
+            NewBalance,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -3047,7 +4391,9 @@ impl<'de> serde::Deserialize<'de> for EventQueuePositionClose {
                         E: serde::de::Error,
                     {
                         match value {
-                            "positionId" | "position_id" => Ok(GeneratedField::PositionId),
+                            "assetId" | "asset_id" => Ok(GeneratedField::AssetId),
+                            "previousBalance" | "previous_balance" => Ok(GeneratedField::PreviousBalance),
+                            "newBalance" | "new_balance" => Ok(GeneratedField::NewBalance),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -3057,39 +4403,55 @@ impl<'de> serde::Deserialize<'de> for EventQueuePositionClose {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = EventQueuePositionClose;
+            type Value = EventValueCircuitBreakerDebit;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.dex.v1.EventQueuePositionClose")
+                formatter.write_str("struct penumbra.core.component.dex.v1.EventValueCircuitBreakerDebit")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<EventQueuePositionClose, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<EventValueCircuitBreakerDebit, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut position_id__ = None;
+                let mut asset_id__ = None;
+                let mut previous_balance__ = None;
+                let mut new_balance__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::PositionId => {
-                            if position_id__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("positionId"));
+                        GeneratedField::AssetId => {
+                            if asset_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("assetId"));
                             }
-                            position_id__ = map_.next_value()?;
+                            asset_id__ = map_.next_value()?;
+                        }
+                        GeneratedField::PreviousBalance => {
+                            if previous_balance__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("previousBalance"));
+                            }
+                            previous_balance__ = map_.next_value()?;
+                        }
+                        GeneratedField::NewBalance => {
+                            if new_balance__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("newBalance"));
+                            }
+                            new_balance__ = map_.next_value()?;
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(EventQueuePositionClose {
-                    position_id: position_id__,
+                Ok(EventValueCircuitBreakerDebit {
+                    asset_id: asset_id__,
+                    previous_balance: previous_balance__,
+                    new_balance: new_balance__,
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.dex.v1.EventQueuePositionClose", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.EventValueCircuitBreakerDebit", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for EventSwap {
+impl serde::Serialize for ExecutionCircuitBreakerTrip {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -3100,32 +4462,32 @@ impl serde::Serialize for EventSwap {
         if self.trading_pair.is_some() {
             len += 1;
         }
-        if self.delta_1_i.is_some() {
+        if self.input.is_some() {
             len += 1;
         }
-        if self.delta_2_i.is_some() {
+        if self.unfilled.is_some() {
             len += 1;
         }
-        if self.swap_commitment.is_some() {
+        if self.execution_budget != 0 {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.EventSwap", len)?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.ExecutionCircuitBreakerTrip", len)?;
         if let Some(v) = self.trading_pair.as_ref() {
             struct_ser.serialize_field("tradingPair", v)?;
         }
-        if let Some(v) = self.delta_1_i.as_ref() {
-            struct_ser.serialize_field("delta1I", v)?;
+        if let Some(v) = self.input.as_ref() {
+            struct_ser.serialize_field("input", v)?;
         }
-        if let Some(v) = self.delta_2_i.as_ref() {
-            struct_ser.serialize_field("delta2I", v)?;
+        if let Some(v) = self.unfilled.as_ref() {
+            struct_ser.serialize_field("unfilled", v)?;
         }
-        if let Some(v) = self.swap_commitment.as_ref() {
-            struct_ser.serialize_field("swapCommitment", v)?;
+        if self.execution_budget != 0 {
+            struct_ser.serialize_field("executionBudget", &self.execution_budget)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for EventSwap {
+impl<'de> serde::Deserialize<'de> for ExecutionCircuitBreakerTrip {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
@@ -3134,20 +4496,18 @@ impl<'de> serde::Deserialize<'de> for EventSwap {
         const FIELDS: &[&str] = &[
             "trading_pair",
             "tradingPair",
-            "delta_1_i",
-            "delta1I",
-            "delta_2_i",
-            "delta2I",
-            "swap_commitment",
-            "swapCommitment",
+            "input",
+            "unfilled",
+            "execution_budget",
+            "executionBudget",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
             TradingPair,
-            Delta1I,
-            Delta2I,
-            SwapCommitment,
+            Input,
+            Unfilled,
+            ExecutionBudget,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -3171,9 +4531,9 @@ impl<'de> serde::Deserialize<'de> for EventSwap {
                     {
                         match value {
                             "tradingPair" | "trading_pair" => Ok(GeneratedField::TradingPair),
-                            "delta1I" | "delta_1_i" => Ok(GeneratedField::Delta1I),
-                            "delta2I" | "delta_2_i" => Ok(GeneratedField::Delta2I),
-                            "swapCommitment" | "swap_commitment" => Ok(GeneratedField::SwapCommitment),
+                            "input" => Ok(GeneratedField::Input),
+                            "unfilled" => Ok(GeneratedField::Unfilled),
+                            "executionBudget" | "execution_budget" => Ok(GeneratedField::ExecutionBudget),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -3183,20 +4543,20 @@ impl<'de> serde::Deserialize<'de> for EventSwap {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = EventSwap;
+            type Value = ExecutionCircuitBreakerTrip;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.dex.v1.EventSwap")
+                formatter.write_str("struct penumbra.core.component.dex.v1.ExecutionCircuitBreakerTrip")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<EventSwap, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ExecutionCircuitBreakerTrip, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
                 let mut trading_pair__ = None;
-                let mut delta_1_i__ = None;
-                let mut delta_2_i__ = None;
-                let mut swap_commitment__ = None;
+                let mut input__ = None;
+                let mut unfilled__ = None;
+                let mut execution_budget__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
                         GeneratedField::TradingPair => {
@@ -3205,41 +4565,43 @@ impl<'de> serde::Deserialize<'de> for EventSwap {
                             }
                             trading_pair__ = map_.next_value()?;
                         }
-                        GeneratedField::Delta1I => {
-                            if delta_1_i__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("delta1I"));
+                        GeneratedField::Input => {
+                            if input__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("input"));
                             }
-                            delta_1_i__ = map_.next_value()?;
+                            input__ = map_.next_value()?;
                         }
-                        GeneratedField::Delta2I => {
-                            if delta_2_i__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("delta2I"));
+                        GeneratedField::Unfilled => {
+                            if unfilled__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("unfilled"));
                             }
-                            delta_2_i__ = map_.next_value()?;
+                            unfilled__ = map_.next_value()?;
                         }
-                        GeneratedField::SwapCommitment => {
-                            if swap_commitment__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("swapCommitment"));
+                        GeneratedField::ExecutionBudget => {
+                            if execution_budget__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("executionBudget"));
                             }
-                            swap_commitment__ = map_.next_value()?;
+                            execution_budget__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(EventSwap {
+                Ok(ExecutionCircuitBreakerTrip {
                     trading_pair: trading_pair__,
-                    delta_1_i: delta_1_i__,
-                    delta_2_i: delta_2_i__,
-                    swap_commitment: swap_commitment__,
+                    input: input__,
+                    unfilled: unfilled__,
+                    execution_budget: execution_budget__.unwrap_or_default(),
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.dex.v1.EventSwap", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.ExecutionCircuitBreakerTrip", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for EventSwapClaim {
+impl serde::Serialize for GenesisContent {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -3247,56 +4609,30 @@ impl serde::Serialize for EventSwapClaim {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.trading_pair.is_some() {
-            len += 1;
-        }
-        if self.output_1_commitment.is_some() {
-            len += 1;
-        }
-        if self.output_2_commitment.is_some() {
-            len += 1;
-        }
-        if self.nullifier.is_some() {
+        if self.dex_params.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.EventSwapClaim", len)?;
-        if let Some(v) = self.trading_pair.as_ref() {
-            struct_ser.serialize_field("tradingPair", v)?;
-        }
-        if let Some(v) = self.output_1_commitment.as_ref() {
-            struct_ser.serialize_field("output1Commitment", v)?;
-        }
-        if let Some(v) = self.output_2_commitment.as_ref() {
-            struct_ser.serialize_field("output2Commitment", v)?;
-        }
-        if let Some(v) = self.nullifier.as_ref() {
-            struct_ser.serialize_field("nullifier", v)?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.GenesisContent", len)?;
+        if let Some(v) = self.dex_params.as_ref() {
+            struct_ser.serialize_field("dexParams", v)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for EventSwapClaim {
+impl<'de> serde::Deserialize<'de> for GenesisContent {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "trading_pair",
-            "tradingPair",
-            "output_1_commitment",
-            "output1Commitment",
-            "output_2_commitment",
-            "output2Commitment",
-            "nullifier",
+            "dex_params",
+            "dexParams",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            TradingPair,
-            Output1Commitment,
-            Output2Commitment,
-            Nullifier,
+            DexParams,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -3319,10 +4655,7 @@ impl<'de> serde::Deserialize<'de> for EventSwapClaim {
                         E: serde::de::Error,
                     {
                         match value {
-                            "tradingPair" | "trading_pair" => Ok(GeneratedField::TradingPair),
-                            "output1Commitment" | "output_1_commitment" => Ok(GeneratedField::Output1Commitment),
-                            "output2Commitment" | "output_2_commitment" => Ok(GeneratedField::Output2Commitment),
-                            "nullifier" => Ok(GeneratedField::Nullifier),
+                            "dexParams" | "dex_params" => Ok(GeneratedField::DexParams),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -3332,63 +4665,39 @@ impl<'de> serde::Deserialize<'de> for EventSwapClaim {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = EventSwapClaim;
+            type Value = GenesisContent;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.dex.v1.EventSwapClaim")
+                formatter.write_str("struct penumbra.core.component.dex.v1.GenesisContent")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<EventSwapClaim, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<GenesisContent, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut trading_pair__ = None;
-                let mut output_1_commitment__ = None;
-                let mut output_2_commitment__ = None;
-                let mut nullifier__ = None;
+                let mut dex_params__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::TradingPair => {
-                            if trading_pair__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("tradingPair"));
-                            }
-                            trading_pair__ = map_.next_value()?;
-                        }
-                        GeneratedField::Output1Commitment => {
-                            if output_1_commitment__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("output1Commitment"));
-                            }
-                            output_1_commitment__ = map_.next_value()?;
-                        }
-                        GeneratedField::Output2Commitment => {
-                            if output_2_commitment__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("output2Commitment"));
-                            }
-                            output_2_commitment__ = map_.next_value()?;
-                        }
-                        GeneratedField::Nullifier => {
-                            if nullifier__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("nullifier"));
+                        GeneratedField::DexParams => {
+                            if dex_params__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("dexParams"));
                             }
-                            nullifier__ = map_.next_value()?;
+                            dex_params__ = map_.next_value()?;
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(EventSwapClaim {
-                    trading_pair: trading_pair__,
-                    output_1_commitment: output_1_commitment__,
-                    output_2_commitment: output_2_commitment__,
-                    nullifier: nullifier__,
+                Ok(GenesisContent {
+                    dex_params: dex_params__,
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.dex.v1.EventSwapClaim", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.GenesisContent", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for EventValueCircuitBreakerCredit {
+impl serde::Serialize for LiquidityPositionByIdRequest {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -3396,48 +4705,30 @@ impl serde::Serialize for EventValueCircuitBreakerCredit {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.asset_id.is_some() {
-            len += 1;
-        }
-        if self.previous_balance.is_some() {
-            len += 1;
-        }
-        if self.new_balance.is_some() {
+        if self.position_id.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.EventValueCircuitBreakerCredit", len)?;
-        if let Some(v) = self.asset_id.as_ref() {
-            struct_ser.serialize_field("assetId", v)?;
-        }
-        if let Some(v) = self.previous_balance.as_ref() {
-            struct_ser.serialize_field("previousBalance", v)?;
-        }
-        if let Some(v) = self.new_balance.as_ref() {
-            struct_ser.serialize_field("newBalance", v)?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.LiquidityPositionByIdRequest", len)?;
+        if let Some(v) = self.position_id.as_ref() {
+            struct_ser.serialize_field("positionId", v)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for EventValueCircuitBreakerCredit {
+impl<'de> serde::Deserialize<'de> for LiquidityPositionByIdRequest {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "asset_id",
-            "assetId",
-            "previous_balance",
-            "previousBalance",
-            "new_balance",
-            "newBalance",
+            "position_id",
+            "positionId",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            AssetId,
-            PreviousBalance,
-            NewBalance,
+            PositionId,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -3460,9 +4751,7 @@ impl<'de> serde::Deserialize<'de> for EventValueCircuitBreakerCredit {
                         E: serde::de::Error,
                     {
                         match value {
-                            "assetId" | "asset_id" => Ok(GeneratedField::AssetId),
-                            "previousBalance" | "previous_balance" => Ok(GeneratedField::PreviousBalance),
-                            "newBalance" | "new_balance" => Ok(GeneratedField::NewBalance),
+                            "positionId" | "position_id" => Ok(GeneratedField::PositionId),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -3472,55 +4761,39 @@ impl<'de> serde::Deserialize<'de> for EventValueCircuitBreakerCredit {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = EventValueCircuitBreakerCredit;
+            type Value = LiquidityPositionByIdRequest;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.dex.v1.EventValueCircuitBreakerCredit")
+                formatter.write_str("struct penumbra.core.component.dex.v1.LiquidityPositionByIdRequest")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<EventValueCircuitBreakerCredit, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<LiquidityPositionByIdRequest, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut asset_id__ = None;
-                let mut previous_balance__ = None;
-                let mut new_balance__ = None;
+                let mut position_id__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::AssetId => {
-                            if asset_id__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("assetId"));
-                            }
-                            asset_id__ = map_.next_value()?;
-                        }
-                        GeneratedField::PreviousBalance => {
-                            if previous_balance__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("previousBalance"));
-                            }
-                            previous_balance__ = map_.next_value()?;
-                        }
-                        GeneratedField::NewBalance => {
-                            if new_balance__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("newBalance"));
+                        GeneratedField::PositionId => {
+                            if position_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("positionId"));
                             }
-                            new_balance__ = map_.next_value()?;
+                            position_id__ = map_.next_value()?;
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(EventValueCircuitBreakerCredit {
-                    asset_id: asset_id__,
-                    previous_balance: previous_balance__,
-                    new_balance: new_balance__,
+                Ok(LiquidityPositionByIdRequest {
+                    position_id: position_id__,
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.dex.v1.EventValueCircuitBreakerCredit", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.LiquidityPositionByIdRequest", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for EventValueCircuitBreakerDebit {
+impl serde::Serialize for LiquidityPositionByIdResponse {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -3528,48 +4801,29 @@ impl serde::Serialize for EventValueCircuitBreakerDebit {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.asset_id.is_some() {
-            len += 1;
-        }
-        if self.previous_balance.is_some() {
-            len += 1;
-        }
-        if self.new_balance.is_some() {
+        if self.data.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.EventValueCircuitBreakerDebit", len)?;
-        if let Some(v) = self.asset_id.as_ref() {
-            struct_ser.serialize_field("assetId", v)?;
-        }
-        if let Some(v) = self.previous_balance.as_ref() {
-            struct_ser.serialize_field("previousBalance", v)?;
-        }
-        if let Some(v) = self.new_balance.as_ref() {
-            struct_ser.serialize_field("newBalance", v)?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.LiquidityPositionByIdResponse", len)?;
+        if let Some(v) = self.data.as_ref() {
+            struct_ser.serialize_field("data", v)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for EventValueCircuitBreakerDebit {
+impl<'de> serde::Deserialize<'de> for LiquidityPositionByIdResponse {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "asset_id",
-            "assetId",
-            "previous_balance",
-            "previousBalance",
-            "new_balance",
-            "newBalance",
+            "data",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            AssetId,
-            PreviousBalance,
-            NewBalance,
+            Data,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -3592,9 +4846,7 @@ impl<'de> serde::Deserialize<'de> for EventValueCircuitBreakerDebit {
                         E: serde::de::Error,
                     {
                         match value {
-                            "assetId" | "asset_id" => Ok(GeneratedField::AssetId),
-                            "previousBalance" | "previous_balance" => Ok(GeneratedField::PreviousBalance),
-                            "newBalance" | "new_balance" => Ok(GeneratedField::NewBalance),
+                            "data" => Ok(GeneratedField::Data),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -3604,55 +4856,39 @@ impl<'de> serde::Deserialize<'de> for EventValueCircuitBreakerDebit {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = EventValueCircuitBreakerDebit;
+            type Value = LiquidityPositionByIdResponse;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.dex.v1.EventValueCircuitBreakerDebit")
+                formatter.write_str("struct penumbra.core.component.dex.v1.LiquidityPositionByIdResponse")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<EventValueCircuitBreakerDebit, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<LiquidityPositionByIdResponse, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut asset_id__ = None;
-                let mut previous_balance__ = None;
-                let mut new_balance__ = None;
+                let mut data__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::AssetId => {
-                            if asset_id__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("assetId"));
-                            }
-                            asset_id__ = map_.next_value()?;
-                        }
-                        GeneratedField::PreviousBalance => {
-                            if previous_balance__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("previousBalance"));
-                            }
-                            previous_balance__ = map_.next_value()?;
-                        }
-                        GeneratedField::NewBalance => {
-                            if new_balance__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("newBalance"));
+                        GeneratedField::Data => {
+                            if data__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("data"));
                             }
-                            new_balance__ = map_.next_value()?;
+                            data__ = map_.next_value()?;
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(EventValueCircuitBreakerDebit {
-                    asset_id: asset_id__,
-                    previous_balance: previous_balance__,
-                    new_balance: new_balance__,
+                Ok(LiquidityPositionByIdResponse {
+                    data: data__,
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.dex.v1.EventValueCircuitBreakerDebit", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.LiquidityPositionByIdResponse", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for GenesisContent {
+impl serde::Serialize for LiquidityPositionsByIdRequest {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -3660,30 +4896,30 @@ impl serde::Serialize for GenesisContent {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.dex_params.is_some() {
+        if !self.position_id.is_empty() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.GenesisContent", len)?;
-        if let Some(v) = self.dex_params.as_ref() {
-            struct_ser.serialize_field("dexParams", v)?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.LiquidityPositionsByIdRequest", len)?;
+        if !self.position_id.is_empty() {
+            struct_ser.serialize_field("positionId", &self.position_id)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for GenesisContent {
+impl<'de> serde::Deserialize<'de> for LiquidityPositionsByIdRequest {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "dex_params",
-            "dexParams",
+            "position_id",
+            "positionId",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            DexParams,
+            PositionId,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -3706,7 +4942,7 @@ impl<'de> serde::Deserialize<'de> for GenesisContent {
                         E: serde::de::Error,
                     {
                         match value {
-                            "dexParams" | "dex_params" => Ok(GeneratedField::DexParams),
+                            "positionId" | "position_id" => Ok(GeneratedField::PositionId),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -3716,39 +4952,39 @@ impl<'de> serde::Deserialize<'de> for GenesisContent {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = GenesisContent;
+            type Value = LiquidityPositionsByIdRequest;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.dex.v1.GenesisContent")
+                formatter.write_str("struct penumbra.core.component.dex.v1.LiquidityPositionsByIdRequest")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<GenesisContent, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<LiquidityPositionsByIdRequest, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut dex_params__ = None;
+                let mut position_id__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::DexParams => {
-                            if dex_params__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("dexParams"));
+                        GeneratedField::PositionId => {
+                            if position_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("positionId"));
                             }
-                            dex_params__ = map_.next_value()?;
+                            position_id__ = Some(map_.next_value()?);
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(GenesisContent {
-                    dex_params: dex_params__,
+                Ok(LiquidityPositionsByIdRequest {
+                    position_id: position_id__.unwrap_or_default(),
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.dex.v1.GenesisContent", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.LiquidityPositionsByIdRequest", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for LiquidityPositionByIdRequest {
+impl serde::Serialize for LiquidityPositionsByIdResponse {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -3756,30 +4992,29 @@ impl serde::Serialize for LiquidityPositionByIdRequest {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.position_id.is_some() {
+        if self.data.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.LiquidityPositionByIdRequest", len)?;
-        if let Some(v) = self.position_id.as_ref() {
-            struct_ser.serialize_field("positionId", v)?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.LiquidityPositionsByIdResponse", len)?;
+        if let Some(v) = self.data.as_ref() {
+            struct_ser.serialize_field("data", v)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for LiquidityPositionByIdRequest {
+impl<'de> serde::Deserialize<'de> for LiquidityPositionsByIdResponse {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "position_id",
-            "positionId",
+            "data",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            PositionId,
+            Data,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -3802,7 +5037,7 @@ impl<'de> serde::Deserialize<'de> for LiquidityPositionByIdRequest {
                         E: serde::de::Error,
                     {
                         match value {
-                            "positionId" | "position_id" => Ok(GeneratedField::PositionId),
+                            "data" => Ok(GeneratedField::Data),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -3812,39 +5047,39 @@ impl<'de> serde::Deserialize<'de> for LiquidityPositionByIdRequest {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = LiquidityPositionByIdRequest;
+            type Value = LiquidityPositionsByIdResponse;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.dex.v1.LiquidityPositionByIdRequest")
+                formatter.write_str("struct penumbra.core.component.dex.v1.LiquidityPositionsByIdResponse")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<LiquidityPositionByIdRequest, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<LiquidityPositionsByIdResponse, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut position_id__ = None;
+                let mut data__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::PositionId => {
-                            if position_id__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("positionId"));
+                        GeneratedField::Data => {
+                            if data__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("data"));
                             }
-                            position_id__ = map_.next_value()?;
+                            data__ = map_.next_value()?;
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(LiquidityPositionByIdRequest {
-                    position_id: position_id__,
+                Ok(LiquidityPositionsByIdResponse {
+                    data: data__,
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.dex.v1.LiquidityPositionByIdRequest", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.LiquidityPositionsByIdResponse", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for LiquidityPositionByIdResponse {
+impl serde::Serialize for LiquidityPositionsByPriceRequest {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -3852,29 +5087,39 @@ impl serde::Serialize for LiquidityPositionByIdResponse {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.data.is_some() {
+        if self.trading_pair.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.LiquidityPositionByIdResponse", len)?;
-        if let Some(v) = self.data.as_ref() {
-            struct_ser.serialize_field("data", v)?;
+        if self.limit != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.LiquidityPositionsByPriceRequest", len)?;
+        if let Some(v) = self.trading_pair.as_ref() {
+            struct_ser.serialize_field("tradingPair", v)?;
+        }
+        if self.limit != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("limit", ToString::to_string(&self.limit).as_str())?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for LiquidityPositionByIdResponse {
+impl<'de> serde::Deserialize<'de> for LiquidityPositionsByPriceRequest {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "data",
+            "trading_pair",
+            "tradingPair",
+            "limit",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            Data,
+            TradingPair,
+            Limit,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -3897,7 +5142,8 @@ impl<'de> serde::Deserialize<'de> for LiquidityPositionByIdResponse {
                         E: serde::de::Error,
                     {
                         match value {
-                            "data" => Ok(GeneratedField::Data),
+                            "tradingPair" | "trading_pair" => Ok(GeneratedField::TradingPair),
+                            "limit" => Ok(GeneratedField::Limit),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -3907,39 +5153,49 @@ impl<'de> serde::Deserialize<'de> for LiquidityPositionByIdResponse {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = LiquidityPositionByIdResponse;
+            type Value = LiquidityPositionsByPriceRequest;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.dex.v1.LiquidityPositionByIdResponse")
+                formatter.write_str("struct penumbra.core.component.dex.v1.LiquidityPositionsByPriceRequest")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<LiquidityPositionByIdResponse, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<LiquidityPositionsByPriceRequest, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut data__ = None;
+                let mut trading_pair__ = None;
+                let mut limit__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::Data => {
-                            if data__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("data"));
+                        GeneratedField::TradingPair => {
+                            if trading_pair__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("tradingPair"));
                             }
-                            data__ = map_.next_value()?;
+                            trading_pair__ = map_.next_value()?;
+                        }
+                        GeneratedField::Limit => {
+                            if limit__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("limit"));
+                            }
+                            limit__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(LiquidityPositionByIdResponse {
-                    data: data__,
+                Ok(LiquidityPositionsByPriceRequest {
+                    trading_pair: trading_pair__,
+                    limit: limit__.unwrap_or_default(),
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.dex.v1.LiquidityPositionByIdResponse", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.LiquidityPositionsByPriceRequest", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for LiquidityPositionsByIdRequest {
+impl serde::Serialize for LiquidityPositionsByPriceResponse {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -3947,30 +5203,37 @@ impl serde::Serialize for LiquidityPositionsByIdRequest {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if !self.position_id.is_empty() {
+        if self.data.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.LiquidityPositionsByIdRequest", len)?;
-        if !self.position_id.is_empty() {
-            struct_ser.serialize_field("positionId", &self.position_id)?;
+        if self.id.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.LiquidityPositionsByPriceResponse", len)?;
+        if let Some(v) = self.data.as_ref() {
+            struct_ser.serialize_field("data", v)?;
+        }
+        if let Some(v) = self.id.as_ref() {
+            struct_ser.serialize_field("id", v)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for LiquidityPositionsByIdRequest {
+impl<'de> serde::Deserialize<'de> for LiquidityPositionsByPriceResponse {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "position_id",
-            "positionId",
+            "data",
+            "id",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            PositionId,
+            Data,
+            Id,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -3993,7 +5256,8 @@ impl<'de> serde::Deserialize<'de> for LiquidityPositionsByIdRequest {
                         E: serde::de::Error,
                     {
                         match value {
-                            "positionId" | "position_id" => Ok(GeneratedField::PositionId),
+                            "data" => Ok(GeneratedField::Data),
+                            "id" => Ok(GeneratedField::Id),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -4003,39 +5267,47 @@ impl<'de> serde::Deserialize<'de> for LiquidityPositionsByIdRequest {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = LiquidityPositionsByIdRequest;
+            type Value = LiquidityPositionsByPriceResponse;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.dex.v1.LiquidityPositionsByIdRequest")
+                formatter.write_str("struct penumbra.core.component.dex.v1.LiquidityPositionsByPriceResponse")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<LiquidityPositionsByIdRequest, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<LiquidityPositionsByPriceResponse, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut position_id__ = None;
+                let mut data__ = None;
+                let mut id__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::PositionId => {
-                            if position_id__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("positionId"));
+                        GeneratedField::Data => {
+                            if data__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("data"));
                             }
-                            position_id__ = Some(map_.next_value()?);
+                            data__ = map_.next_value()?;
+                        }
+                        GeneratedField::Id => {
+                            if id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("id"));
+                            }
+                            id__ = map_.next_value()?;
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(LiquidityPositionsByIdRequest {
-                    position_id: position_id__.unwrap_or_default(),
+                Ok(LiquidityPositionsByPriceResponse {
+                    data: data__,
+                    id: id__,
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.dex.v1.LiquidityPositionsByIdRequest", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.LiquidityPositionsByPriceResponse", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for LiquidityPositionsByIdResponse {
+impl serde::Serialize for LiquidityPositionsRequest {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -4043,29 +5315,30 @@ impl serde::Serialize for LiquidityPositionsByIdResponse {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.data.is_some() {
+        if self.include_closed {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.LiquidityPositionsByIdResponse", len)?;
-        if let Some(v) = self.data.as_ref() {
-            struct_ser.serialize_field("data", v)?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.LiquidityPositionsRequest", len)?;
+        if self.include_closed {
+            struct_ser.serialize_field("includeClosed", &self.include_closed)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for LiquidityPositionsByIdResponse {
+impl<'de> serde::Deserialize<'de> for LiquidityPositionsRequest {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "data",
+            "include_closed",
+            "includeClosed",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            Data,
+            IncludeClosed,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -4088,7 +5361,7 @@ impl<'de> serde::Deserialize<'de> for LiquidityPositionsByIdResponse {
                         E: serde::de::Error,
                     {
                         match value {
-                            "data" => Ok(GeneratedField::Data),
+                            "includeClosed" | "include_closed" => Ok(GeneratedField::IncludeClosed),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -4098,39 +5371,39 @@ impl<'de> serde::Deserialize<'de> for LiquidityPositionsByIdResponse {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = LiquidityPositionsByIdResponse;
+            type Value = LiquidityPositionsRequest;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.dex.v1.LiquidityPositionsByIdResponse")
+                formatter.write_str("struct penumbra.core.component.dex.v1.LiquidityPositionsRequest")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<LiquidityPositionsByIdResponse, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<LiquidityPositionsRequest, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut data__ = None;
+                let mut include_closed__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::Data => {
-                            if data__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("data"));
+                        GeneratedField::IncludeClosed => {
+                            if include_closed__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("includeClosed"));
                             }
-                            data__ = map_.next_value()?;
+                            include_closed__ = Some(map_.next_value()?);
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(LiquidityPositionsByIdResponse {
-                    data: data__,
+                Ok(LiquidityPositionsRequest {
+                    include_closed: include_closed__.unwrap_or_default(),
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.dex.v1.LiquidityPositionsByIdResponse", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.LiquidityPositionsRequest", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for LiquidityPositionsByPriceRequest {
+impl serde::Serialize for LiquidityPositionsResponse {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -4138,39 +5411,29 @@ impl serde::Serialize for LiquidityPositionsByPriceRequest {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.trading_pair.is_some() {
-            len += 1;
-        }
-        if self.limit != 0 {
+        if self.data.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.LiquidityPositionsByPriceRequest", len)?;
-        if let Some(v) = self.trading_pair.as_ref() {
-            struct_ser.serialize_field("tradingPair", v)?;
-        }
-        if self.limit != 0 {
-            #[allow(clippy::needless_borrow)]
-            struct_ser.serialize_field("limit", ToString::to_string(&self.limit).as_str())?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.LiquidityPositionsResponse", len)?;
+        if let Some(v) = self.data.as_ref() {
+            struct_ser.serialize_field("data", v)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for LiquidityPositionsByPriceRequest {
+impl<'de> serde::Deserialize<'de> for LiquidityPositionsResponse {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "trading_pair",
-            "tradingPair",
-            "limit",
+            "data",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            TradingPair,
-            Limit,
+            Data,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -4193,8 +5456,7 @@ impl<'de> serde::Deserialize<'de> for LiquidityPositionsByPriceRequest {
                         E: serde::de::Error,
                     {
                         match value {
-                            "tradingPair" | "trading_pair" => Ok(GeneratedField::TradingPair),
-                            "limit" => Ok(GeneratedField::Limit),
+                            "data" => Ok(GeneratedField::Data),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -4204,49 +5466,39 @@ impl<'de> serde::Deserialize<'de> for LiquidityPositionsByPriceRequest {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = LiquidityPositionsByPriceRequest;
+            type Value = LiquidityPositionsResponse;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.dex.v1.LiquidityPositionsByPriceRequest")
+                formatter.write_str("struct penumbra.core.component.dex.v1.LiquidityPositionsResponse")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<LiquidityPositionsByPriceRequest, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<LiquidityPositionsResponse, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut trading_pair__ = None;
-                let mut limit__ = None;
+                let mut data__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::TradingPair => {
-                            if trading_pair__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("tradingPair"));
-                            }
-                            trading_pair__ = map_.next_value()?;
-                        }
-                        GeneratedField::Limit => {
-                            if limit__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("limit"));
+                        GeneratedField::Data => {
+                            if data__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("data"));
                             }
-                            limit__ = 
-                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
-                            ;
+                            data__ = map_.next_value()?;
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(LiquidityPositionsByPriceRequest {
-                    trading_pair: trading_pair__,
-                    limit: limit__.unwrap_or_default(),
+                Ok(LiquidityPositionsResponse {
+                    data: data__,
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.dex.v1.LiquidityPositionsByPriceRequest", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.LiquidityPositionsResponse", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for LiquidityPositionsByPriceResponse {
+impl serde::Serialize for LpNft {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -4254,37 +5506,38 @@ impl serde::Serialize for LiquidityPositionsByPriceResponse {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.data.is_some() {
+        if self.position_id.is_some() {
             len += 1;
         }
-        if self.id.is_some() {
+        if self.state.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.LiquidityPositionsByPriceResponse", len)?;
-        if let Some(v) = self.data.as_ref() {
-            struct_ser.serialize_field("data", v)?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.LpNft", len)?;
+        if let Some(v) = self.position_id.as_ref() {
+            struct_ser.serialize_field("positionId", v)?;
         }
-        if let Some(v) = self.id.as_ref() {
-            struct_ser.serialize_field("id", v)?;
+        if let Some(v) = self.state.as_ref() {
+            struct_ser.serialize_field("state", v)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for LiquidityPositionsByPriceResponse {
+impl<'de> serde::Deserialize<'de> for LpNft {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "data",
-            "id",
+            "position_id",
+            "positionId",
+            "state",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            Data,
-            Id,
+            PositionId,
+            State,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -4307,8 +5560,8 @@ impl<'de> serde::Deserialize<'de> for LiquidityPositionsByPriceResponse {
                         E: serde::de::Error,
                     {
                         match value {
-                            "data" => Ok(GeneratedField::Data),
-                            "id" => Ok(GeneratedField::Id),
+                            "positionId" | "position_id" => Ok(GeneratedField::PositionId),
+                            "state" => Ok(GeneratedField::State),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -4318,47 +5571,47 @@ impl<'de> serde::Deserialize<'de> for LiquidityPositionsByPriceResponse {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = LiquidityPositionsByPriceResponse;
+            type Value = LpNft;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.dex.v1.LiquidityPositionsByPriceResponse")
+                formatter.write_str("struct penumbra.core.component.dex.v1.LpNft")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<LiquidityPositionsByPriceResponse, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<LpNft, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut data__ = None;
-                let mut id__ = None;
+                let mut position_id__ = None;
+                let mut state__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::Data => {
-                            if data__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("data"));
+                        GeneratedField::PositionId => {
+                            if position_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("positionId"));
                             }
-                            data__ = map_.next_value()?;
+                            position_id__ = map_.next_value()?;
                         }
-                        GeneratedField::Id => {
-                            if id__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("id"));
+                        GeneratedField::State => {
+                            if state__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("state"));
                             }
-                            id__ = map_.next_value()?;
+                            state__ = map_.next_value()?;
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(LiquidityPositionsByPriceResponse {
-                    data: data__,
-                    id: id__,
+                Ok(LpNft {
+                    position_id: position_id__,
+                    state: state__,
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.dex.v1.LiquidityPositionsByPriceResponse", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.LpNft", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for LiquidityPositionsRequest {
+impl serde::Serialize for Position {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -4366,30 +5619,63 @@ impl serde::Serialize for LiquidityPositionsRequest {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.include_closed {
+        if self.phi.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.LiquidityPositionsRequest", len)?;
-        if self.include_closed {
-            struct_ser.serialize_field("includeClosed", &self.include_closed)?;
+        if !self.nonce.is_empty() {
+            len += 1;
+        }
+        if self.state.is_some() {
+            len += 1;
+        }
+        if self.reserves.is_some() {
+            len += 1;
+        }
+        if self.close_on_fill {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.Position", len)?;
+        if let Some(v) = self.phi.as_ref() {
+            struct_ser.serialize_field("phi", v)?;
+        }
+        if !self.nonce.is_empty() {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("nonce", pbjson::private::base64::encode(&self.nonce).as_str())?;
+        }
+        if let Some(v) = self.state.as_ref() {
+            struct_ser.serialize_field("state", v)?;
+        }
+        if let Some(v) = self.reserves.as_ref() {
+            struct_ser.serialize_field("reserves", v)?;
+        }
+        if self.close_on_fill {
+            struct_ser.serialize_field("closeOnFill", &self.close_on_fill)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for LiquidityPositionsRequest {
+impl<'de> serde::Deserialize<'de> for Position {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "include_closed",
-            "includeClosed",
+            "phi",
+            "nonce",
+            "state",
+            "reserves",
+            "close_on_fill",
+            "closeOnFill",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            IncludeClosed,
+            Phi,
+            Nonce,
+            State,
+            Reserves,
+            CloseOnFill,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -4412,7 +5698,11 @@ impl<'de> serde::Deserialize<'de> for LiquidityPositionsRequest {
                         E: serde::de::Error,
                     {
                         match value {
-                            "includeClosed" | "include_closed" => Ok(GeneratedField::IncludeClosed),
+                            "phi" => Ok(GeneratedField::Phi),
+                            "nonce" => Ok(GeneratedField::Nonce),
+                            "state" => Ok(GeneratedField::State),
+                            "reserves" => Ok(GeneratedField::Reserves),
+                            "closeOnFill" | "close_on_fill" => Ok(GeneratedField::CloseOnFill),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -4422,39 +5712,73 @@ impl<'de> serde::Deserialize<'de> for LiquidityPositionsRequest {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = LiquidityPositionsRequest;
+            type Value = Position;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.dex.v1.LiquidityPositionsRequest")
+                formatter.write_str("struct penumbra.core.component.dex.v1.Position")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<LiquidityPositionsRequest, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<Position, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut include_closed__ = None;
+                let mut phi__ = None;
+                let mut nonce__ = None;
+                let mut state__ = None;
+                let mut reserves__ = None;
+                let mut close_on_fill__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::IncludeClosed => {
-                            if include_closed__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("includeClosed"));
+                        GeneratedField::Phi => {
+                            if phi__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("phi"));
                             }
-                            include_closed__ = Some(map_.next_value()?);
+                            phi__ = map_.next_value()?;
+                        }
+                        GeneratedField::Nonce => {
+                            if nonce__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("nonce"));
+                            }
+                            nonce__ = 
+                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::State => {
+                            if state__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("state"));
+                            }
+                            state__ = map_.next_value()?;
+                        }
+                        GeneratedField::Reserves => {
+                            if reserves__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("reserves"));
+                            }
+                            reserves__ = map_.next_value()?;
+                        }
+                        GeneratedField::CloseOnFill => {
+                            if close_on_fill__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("closeOnFill"));
+                            }
+                            close_on_fill__ = Some(map_.next_value()?);
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(LiquidityPositionsRequest {
-                    include_closed: include_closed__.unwrap_or_default(),
+                Ok(Position {
+                    phi: phi__,
+                    nonce: nonce__.unwrap_or_default(),
+                    state: state__,
+                    reserves: reserves__,
+                    close_on_fill: close_on_fill__.unwrap_or_default(),
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.dex.v1.LiquidityPositionsRequest", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.Position", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for LiquidityPositionsResponse {
+impl serde::Serialize for PositionClose {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -4462,29 +5786,30 @@ impl serde::Serialize for LiquidityPositionsResponse {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.data.is_some() {
+        if self.position_id.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.LiquidityPositionsResponse", len)?;
-        if let Some(v) = self.data.as_ref() {
-            struct_ser.serialize_field("data", v)?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.PositionClose", len)?;
+        if let Some(v) = self.position_id.as_ref() {
+            struct_ser.serialize_field("positionId", v)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for LiquidityPositionsResponse {
+impl<'de> serde::Deserialize<'de> for PositionClose {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "data",
+            "position_id",
+            "positionId",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            Data,
+            PositionId,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -4507,7 +5832,7 @@ impl<'de> serde::Deserialize<'de> for LiquidityPositionsResponse {
                         E: serde::de::Error,
                     {
                         match value {
-                            "data" => Ok(GeneratedField::Data),
+                            "positionId" | "position_id" => Ok(GeneratedField::PositionId),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -4517,39 +5842,39 @@ impl<'de> serde::Deserialize<'de> for LiquidityPositionsResponse {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = LiquidityPositionsResponse;
+            type Value = PositionClose;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.dex.v1.LiquidityPositionsResponse")
+                formatter.write_str("struct penumbra.core.component.dex.v1.PositionClose")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<LiquidityPositionsResponse, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<PositionClose, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut data__ = None;
+                let mut position_id__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::Data => {
-                            if data__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("data"));
+                        GeneratedField::PositionId => {
+                            if position_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("positionId"));
                             }
-                            data__ = map_.next_value()?;
+                            position_id__ = map_.next_value()?;
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(LiquidityPositionsResponse {
-                    data: data__,
+                Ok(PositionClose {
+                    position_id: position_id__,
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.dex.v1.LiquidityPositionsResponse", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.PositionClose", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for LpNft {
+impl serde::Serialize for PositionEvictionRiskRequest {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -4557,23 +5882,24 @@ impl serde::Serialize for LpNft {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.position_id.is_some() {
-            len += 1;
-        }
-        if self.state.is_some() {
+        if self.position.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.LpNft", len)?;
-        if let Some(v) = self.position_id.as_ref() {
-            struct_ser.serialize_field("positionId", v)?;
-        }
-        if let Some(v) = self.state.as_ref() {
-            struct_ser.serialize_field("state", v)?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.PositionEvictionRiskRequest", len)?;
+        if let Some(v) = self.position.as_ref() {
+            match v {
+                position_eviction_risk_request::Position::PositionId(v) => {
+                    struct_ser.serialize_field("positionId", v)?;
+                }
+                position_eviction_risk_request::Position::Hypothetical(v) => {
+                    struct_ser.serialize_field("hypothetical", v)?;
+                }
+            }
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for LpNft {
+impl<'de> serde::Deserialize<'de> for PositionEvictionRiskRequest {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
@@ -4582,13 +5908,13 @@ impl<'de> serde::Deserialize<'de> for LpNft {
         const FIELDS: &[&str] = &[
             "position_id",
             "positionId",
-            "state",
+            "hypothetical",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
             PositionId,
-            State,
+            Hypothetical,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -4612,7 +5938,7 @@ impl<'de> serde::Deserialize<'de> for LpNft {
                     {
                         match value {
                             "positionId" | "position_id" => Ok(GeneratedField::PositionId),
-                            "state" => Ok(GeneratedField::State),
+                            "hypothetical" => Ok(GeneratedField::Hypothetical),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -4622,47 +5948,47 @@ impl<'de> serde::Deserialize<'de> for LpNft {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = LpNft;
+            type Value = PositionEvictionRiskRequest;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.dex.v1.LpNft")
+                formatter.write_str("struct penumbra.core.component.dex.v1.PositionEvictionRiskRequest")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<LpNft, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<PositionEvictionRiskRequest, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut position_id__ = None;
-                let mut state__ = None;
+                let mut position__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
                         GeneratedField::PositionId => {
-                            if position_id__.is_some() {
+                            if position__.is_some() {
                                 return Err(serde::de::Error::duplicate_field("positionId"));
                             }
-                            position_id__ = map_.next_value()?;
+                            position__ = map_.next_value::<::std::option::Option<_>>()?.map(position_eviction_risk_request::Position::PositionId)
+;
                         }
-                        GeneratedField::State => {
-                            if state__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("state"));
+                        GeneratedField::Hypothetical => {
+                            if position__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("hypothetical"));
                             }
-                            state__ = map_.next_value()?;
+                            position__ = map_.next_value::<::std::option::Option<_>>()?.map(position_eviction_risk_request::Position::Hypothetical)
+;
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(LpNft {
-                    position_id: position_id__,
-                    state: state__,
+                Ok(PositionEvictionRiskRequest {
+                    position: position__,
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.dex.v1.LpNft", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.PositionEvictionRiskRequest", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for Position {
+impl serde::Serialize for PositionEvictionRiskResponse {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -4670,63 +5996,70 @@ impl serde::Serialize for Position {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.phi.is_some() {
+        if self.position_count != 0 {
             len += 1;
         }
-        if !self.nonce.is_empty() {
+        if self.max_positions_per_pair != 0 {
             len += 1;
         }
-        if self.state.is_some() {
+        if self.rank_1 != 0 {
             len += 1;
         }
-        if self.reserves.is_some() {
+        if self.rank_2 != 0 {
             len += 1;
         }
-        if self.close_on_fill {
+        if self.at_risk {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.Position", len)?;
-        if let Some(v) = self.phi.as_ref() {
-            struct_ser.serialize_field("phi", v)?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.PositionEvictionRiskResponse", len)?;
+        if self.position_count != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("positionCount", ToString::to_string(&self.position_count).as_str())?;
         }
-        if !self.nonce.is_empty() {
+        if self.max_positions_per_pair != 0 {
             #[allow(clippy::needless_borrow)]
-            struct_ser.serialize_field("nonce", pbjson::private::base64::encode(&self.nonce).as_str())?;
+            struct_ser.serialize_field("maxPositionsPerPair", ToString::to_string(&self.max_positions_per_pair).as_str())?;
         }
-        if let Some(v) = self.state.as_ref() {
-            struct_ser.serialize_field("state", v)?;
+        if self.rank_1 != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("rank1", ToString::to_string(&self.rank_1).as_str())?;
         }
-        if let Some(v) = self.reserves.as_ref() {
-            struct_ser.serialize_field("reserves", v)?;
+        if self.rank_2 != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("rank2", ToString::to_string(&self.rank_2).as_str())?;
         }
-        if self.close_on_fill {
-            struct_ser.serialize_field("closeOnFill", &self.close_on_fill)?;
+        if self.at_risk {
+            struct_ser.serialize_field("atRisk", &self.at_risk)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for Position {
+impl<'de> serde::Deserialize<'de> for PositionEvictionRiskResponse {
     #[allow(deprecated)]
-    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
-    where
-        D: serde::Deserializer<'de>,
-    {
-        const FIELDS: &[&str] = &[
-            "phi",
-            "nonce",
-            "state",
-            "reserves",
-            "close_on_fill",
-            "closeOnFill",
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "position_count",
+            "positionCount",
+            "max_positions_per_pair",
+            "maxPositionsPerPair",
+            "rank_1",
+            "rank1",
+            "rank_2",
+            "rank2",
+            "at_risk",
+            "atRisk",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            Phi,
-            Nonce,
-            State,
-            Reserves,
-            CloseOnFill,
+            PositionCount,
+            MaxPositionsPerPair,
+            Rank1,
+            Rank2,
+            AtRisk,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -4749,11 +6082,11 @@ impl<'de> serde::Deserialize<'de> for Position {
                         E: serde::de::Error,
                     {
                         match value {
-                            "phi" => Ok(GeneratedField::Phi),
-                            "nonce" => Ok(GeneratedField::Nonce),
-                            "state" => Ok(GeneratedField::State),
-                            "reserves" => Ok(GeneratedField::Reserves),
-                            "closeOnFill" | "close_on_fill" => Ok(GeneratedField::CloseOnFill),
+                            "positionCount" | "position_count" => Ok(GeneratedField::PositionCount),
+                            "maxPositionsPerPair" | "max_positions_per_pair" => Ok(GeneratedField::MaxPositionsPerPair),
+                            "rank1" | "rank_1" => Ok(GeneratedField::Rank1),
+                            "rank2" | "rank_2" => Ok(GeneratedField::Rank2),
+                            "atRisk" | "at_risk" => Ok(GeneratedField::AtRisk),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -4763,73 +6096,79 @@ impl<'de> serde::Deserialize<'de> for Position {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = Position;
+            type Value = PositionEvictionRiskResponse;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.dex.v1.Position")
+                formatter.write_str("struct penumbra.core.component.dex.v1.PositionEvictionRiskResponse")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<Position, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<PositionEvictionRiskResponse, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut phi__ = None;
-                let mut nonce__ = None;
-                let mut state__ = None;
-                let mut reserves__ = None;
-                let mut close_on_fill__ = None;
+                let mut position_count__ = None;
+                let mut max_positions_per_pair__ = None;
+                let mut rank_1__ = None;
+                let mut rank_2__ = None;
+                let mut at_risk__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::Phi => {
-                            if phi__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("phi"));
+                        GeneratedField::PositionCount => {
+                            if position_count__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("positionCount"));
                             }
-                            phi__ = map_.next_value()?;
+                            position_count__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
                         }
-                        GeneratedField::Nonce => {
-                            if nonce__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("nonce"));
+                        GeneratedField::MaxPositionsPerPair => {
+                            if max_positions_per_pair__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("maxPositionsPerPair"));
                             }
-                            nonce__ = 
-                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
+                            max_positions_per_pair__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
                             ;
                         }
-                        GeneratedField::State => {
-                            if state__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("state"));
+                        GeneratedField::Rank1 => {
+                            if rank_1__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("rank1"));
                             }
-                            state__ = map_.next_value()?;
+                            rank_1__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
                         }
-                        GeneratedField::Reserves => {
-                            if reserves__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("reserves"));
+                        GeneratedField::Rank2 => {
+                            if rank_2__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("rank2"));
                             }
-                            reserves__ = map_.next_value()?;
+                            rank_2__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
                         }
-                        GeneratedField::CloseOnFill => {
-                            if close_on_fill__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("closeOnFill"));
+                        GeneratedField::AtRisk => {
+                            if at_risk__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("atRisk"));
                             }
-                            close_on_fill__ = Some(map_.next_value()?);
+                            at_risk__ = Some(map_.next_value()?);
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(Position {
-                    phi: phi__,
-                    nonce: nonce__.unwrap_or_default(),
-                    state: state__,
-                    reserves: reserves__,
-                    close_on_fill: close_on_fill__.unwrap_or_default(),
+                Ok(PositionEvictionRiskResponse {
+                    position_count: position_count__.unwrap_or_default(),
+                    max_positions_per_pair: max_positions_per_pair__.unwrap_or_default(),
+                    rank_1: rank_1__.unwrap_or_default(),
+                    rank_2: rank_2__.unwrap_or_default(),
+                    at_risk: at_risk__.unwrap_or_default(),
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.dex.v1.Position", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.PositionEvictionRiskResponse", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for PositionClose {
+impl serde::Serialize for PositionExecutionSummary {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -4840,14 +6179,32 @@ impl serde::Serialize for PositionClose {
         if self.position_id.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.PositionClose", len)?;
+        if self.prev_reserves.is_some() {
+            len += 1;
+        }
+        if self.reserves.is_some() {
+            len += 1;
+        }
+        if self.inflows.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.PositionExecutionSummary", len)?;
         if let Some(v) = self.position_id.as_ref() {
             struct_ser.serialize_field("positionId", v)?;
         }
+        if let Some(v) = self.prev_reserves.as_ref() {
+            struct_ser.serialize_field("prevReserves", v)?;
+        }
+        if let Some(v) = self.reserves.as_ref() {
+            struct_ser.serialize_field("reserves", v)?;
+        }
+        if let Some(v) = self.inflows.as_ref() {
+            struct_ser.serialize_field("inflows", v)?;
+        }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for PositionClose {
+impl<'de> serde::Deserialize<'de> for PositionExecutionSummary {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
@@ -4856,11 +6213,18 @@ impl<'de> serde::Deserialize<'de> for PositionClose {
         const FIELDS: &[&str] = &[
             "position_id",
             "positionId",
+            "prev_reserves",
+            "prevReserves",
+            "reserves",
+            "inflows",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
             PositionId,
+            PrevReserves,
+            Reserves,
+            Inflows,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -4884,6 +6248,9 @@ impl<'de> serde::Deserialize<'de> for PositionClose {
                     {
                         match value {
                             "positionId" | "position_id" => Ok(GeneratedField::PositionId),
+                            "prevReserves" | "prev_reserves" => Ok(GeneratedField::PrevReserves),
+                            "reserves" => Ok(GeneratedField::Reserves),
+                            "inflows" => Ok(GeneratedField::Inflows),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -4893,17 +6260,20 @@ impl<'de> serde::Deserialize<'de> for PositionClose {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = PositionClose;
+            type Value = PositionExecutionSummary;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.dex.v1.PositionClose")
+                formatter.write_str("struct penumbra.core.component.dex.v1.PositionExecutionSummary")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<PositionClose, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<PositionExecutionSummary, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
                 let mut position_id__ = None;
+                let mut prev_reserves__ = None;
+                let mut reserves__ = None;
+                let mut inflows__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
                         GeneratedField::PositionId => {
@@ -4912,17 +6282,38 @@ impl<'de> serde::Deserialize<'de> for PositionClose {
                             }
                             position_id__ = map_.next_value()?;
                         }
+                        GeneratedField::PrevReserves => {
+                            if prev_reserves__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("prevReserves"));
+                            }
+                            prev_reserves__ = map_.next_value()?;
+                        }
+                        GeneratedField::Reserves => {
+                            if reserves__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("reserves"));
+                            }
+                            reserves__ = map_.next_value()?;
+                        }
+                        GeneratedField::Inflows => {
+                            if inflows__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("inflows"));
+                            }
+                            inflows__ = map_.next_value()?;
+                        }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(PositionClose {
+                Ok(PositionExecutionSummary {
                     position_id: position_id__,
+                    prev_reserves: prev_reserves__,
+                    reserves: reserves__,
+                    inflows: inflows__,
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.dex.v1.PositionClose", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.PositionExecutionSummary", FIELDS, GeneratedVisitor)
     }
 }
 impl serde::Serialize for PositionId {
@@ -5972,6 +7363,15 @@ impl serde::Serialize for SimulateTradeRequest {
         if self.routing.is_some() {
             len += 1;
         }
+        if self.height != 0 {
+            len += 1;
+        }
+        if !self.open_positions.is_empty() {
+            len += 1;
+        }
+        if !self.close_positions.is_empty() {
+            len += 1;
+        }
         let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.SimulateTradeRequest", len)?;
         if let Some(v) = self.input.as_ref() {
             struct_ser.serialize_field("input", v)?;
@@ -5982,6 +7382,16 @@ impl serde::Serialize for SimulateTradeRequest {
         if let Some(v) = self.routing.as_ref() {
             struct_ser.serialize_field("routing", v)?;
         }
+        if self.height != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("height", ToString::to_string(&self.height).as_str())?;
+        }
+        if !self.open_positions.is_empty() {
+            struct_ser.serialize_field("openPositions", &self.open_positions)?;
+        }
+        if !self.close_positions.is_empty() {
+            struct_ser.serialize_field("closePositions", &self.close_positions)?;
+        }
         struct_ser.end()
     }
 }
@@ -5995,6 +7405,11 @@ impl<'de> serde::Deserialize<'de> for SimulateTradeRequest {
             "input",
             "output",
             "routing",
+            "height",
+            "open_positions",
+            "openPositions",
+            "close_positions",
+            "closePositions",
         ];
 
         #[allow(clippy::enum_variant_names)]
@@ -6002,6 +7417,9 @@ impl<'de> serde::Deserialize<'de> for SimulateTradeRequest {
             Input,
             Output,
             Routing,
+            Height,
+            OpenPositions,
+            ClosePositions,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -6027,6 +7445,9 @@ impl<'de> serde::Deserialize<'de> for SimulateTradeRequest {
                             "input" => Ok(GeneratedField::Input),
                             "output" => Ok(GeneratedField::Output),
                             "routing" => Ok(GeneratedField::Routing),
+                            "height" => Ok(GeneratedField::Height),
+                            "openPositions" | "open_positions" => Ok(GeneratedField::OpenPositions),
+                            "closePositions" | "close_positions" => Ok(GeneratedField::ClosePositions),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -6049,6 +7470,9 @@ impl<'de> serde::Deserialize<'de> for SimulateTradeRequest {
                 let mut input__ = None;
                 let mut output__ = None;
                 let mut routing__ = None;
+                let mut height__ = None;
+                let mut open_positions__ = None;
+                let mut close_positions__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
                         GeneratedField::Input => {
@@ -6069,6 +7493,26 @@ impl<'de> serde::Deserialize<'de> for SimulateTradeRequest {
                             }
                             routing__ = map_.next_value()?;
                         }
+                        GeneratedField::Height => {
+                            if height__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("height"));
+                            }
+                            height__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::OpenPositions => {
+                            if open_positions__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("openPositions"));
+                            }
+                            open_positions__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::ClosePositions => {
+                            if close_positions__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("closePositions"));
+                            }
+                            close_positions__ = Some(map_.next_value()?);
+                        }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
@@ -6078,6 +7522,9 @@ impl<'de> serde::Deserialize<'de> for SimulateTradeRequest {
                     input: input__,
                     output: output__,
                     routing: routing__,
+                    height: height__.unwrap_or_default(),
+                    open_positions: open_positions__.unwrap_or_default(),
+                    close_positions: close_positions__.unwrap_or_default(),
                 })
             }
         }
@@ -6356,6 +7803,9 @@ impl serde::Serialize for SimulateTradeResponse {
         if self.unfilled.is_some() {
             len += 1;
         }
+        if self.height != 0 {
+            len += 1;
+        }
         let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.SimulateTradeResponse", len)?;
         if let Some(v) = self.output.as_ref() {
             struct_ser.serialize_field("output", v)?;
@@ -6363,6 +7813,10 @@ impl serde::Serialize for SimulateTradeResponse {
         if let Some(v) = self.unfilled.as_ref() {
             struct_ser.serialize_field("unfilled", v)?;
         }
+        if self.height != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("height", ToString::to_string(&self.height).as_str())?;
+        }
         struct_ser.end()
     }
 }
@@ -6375,12 +7829,14 @@ impl<'de> serde::Deserialize<'de> for SimulateTradeResponse {
         const FIELDS: &[&str] = &[
             "output",
             "unfilled",
+            "height",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
             Output,
             Unfilled,
+            Height,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -6405,6 +7861,7 @@ impl<'de> serde::Deserialize<'de> for SimulateTradeResponse {
                         match value {
                             "output" => Ok(GeneratedField::Output),
                             "unfilled" => Ok(GeneratedField::Unfilled),
+                            "height" => Ok(GeneratedField::Height),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -6426,6 +7883,7 @@ impl<'de> serde::Deserialize<'de> for SimulateTradeResponse {
             {
                 let mut output__ = None;
                 let mut unfilled__ = None;
+                let mut height__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
                         GeneratedField::Output => {
@@ -6440,6 +7898,14 @@ impl<'de> serde::Deserialize<'de> for SimulateTradeResponse {
                             }
                             unfilled__ = map_.next_value()?;
                         }
+                        GeneratedField::Height => {
+                            if height__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("height"));
+                            }
+                            height__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
@@ -6448,6 +7914,7 @@ impl<'de> serde::Deserialize<'de> for SimulateTradeResponse {
                 Ok(SimulateTradeResponse {
                     output: output__,
                     unfilled: unfilled__,
+                    height: height__.unwrap_or_default(),
                 })
             }
         }
@@ -9754,6 +11221,154 @@ impl<'de> serde::Deserialize<'de> for TradingPair {
         deserializer.deserialize_struct("penumbra.core.component.dex.v1.TradingPair", FIELDS, GeneratedVisitor)
     }
 }
+impl serde::Serialize for ValueCircuitBreakerTrip {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.asset_id.is_some() {
+            len += 1;
+        }
+        if self.balance.is_some() {
+            len += 1;
+        }
+        if self.debit.is_some() {
+            len += 1;
+        }
+        if self.transaction_id.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.ValueCircuitBreakerTrip", len)?;
+        if let Some(v) = self.asset_id.as_ref() {
+            struct_ser.serialize_field("assetId", v)?;
+        }
+        if let Some(v) = self.balance.as_ref() {
+            struct_ser.serialize_field("balance", v)?;
+        }
+        if let Some(v) = self.debit.as_ref() {
+            struct_ser.serialize_field("debit", v)?;
+        }
+        if let Some(v) = self.transaction_id.as_ref() {
+            struct_ser.serialize_field("transactionId", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for ValueCircuitBreakerTrip {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "asset_id",
+            "assetId",
+            "balance",
+            "debit",
+            "transaction_id",
+            "transactionId",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            AssetId,
+            Balance,
+            Debit,
+            TransactionId,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "assetId" | "asset_id" => Ok(GeneratedField::AssetId),
+                            "balance" => Ok(GeneratedField::Balance),
+                            "debit" => Ok(GeneratedField::Debit),
+                            "transactionId" | "transaction_id" => Ok(GeneratedField::TransactionId),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = ValueCircuitBreakerTrip;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.dex.v1.ValueCircuitBreakerTrip")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ValueCircuitBreakerTrip, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut asset_id__ = None;
+                let mut balance__ = None;
+                let mut debit__ = None;
+                let mut transaction_id__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::AssetId => {
+                            if asset_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("assetId"));
+                            }
+                            asset_id__ = map_.next_value()?;
+                        }
+                        GeneratedField::Balance => {
+                            if balance__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("balance"));
+                            }
+                            balance__ = map_.next_value()?;
+                        }
+                        GeneratedField::Debit => {
+                            if debit__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("debit"));
+                            }
+                            debit__ = map_.next_value()?;
+                        }
+                        GeneratedField::TransactionId => {
+                            if transaction_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("transactionId"));
+                            }
+                            transaction_id__ = map_.next_value()?;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(ValueCircuitBreakerTrip {
+                    asset_id: asset_id__,
+                    balance: balance__,
+                    debit: debit__,
+                    transaction_id: transaction_id__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.ValueCircuitBreakerTrip", FIELDS, GeneratedVisitor)
+    }
+}
 impl serde::Serialize for ZkSwapClaimProof {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
@@ -174,6 +174,18 @@ pub struct DelegatorVoteBody {
     pub rk: ::core::option::Option<
         super::super::super::super::crypto::decaf377_rdsa::v1::SpendVerificationKey,
     >,
+    /// If set, the voting weight is delegated to this proxy for the proposal.
+    ///
+    /// If the proxy casts a `ProxyVote` on the proposal, it replaces `vote`; otherwise, `vote` is
+    /// counted as usual.
+    #[prost(message, optional, tag = "8")]
+    pub proxy: ::core::option::Option<super::super::super::keys::v1::GovernanceKey>,
+    /// If set, the `proxy` choice is recorded as a standing delegation for the note, which also
+    /// applies to proposals started after this vote, until the note is spent.
+    ///
+    /// If set while `proxy` is unset, any standing delegation for the note is cleared.
+    #[prost(bool, tag = "9")]
+    pub standing_proxy: bool,
 }
 impl ::prost::Name for DelegatorVoteBody {
     const NAME: &'static str = "DelegatorVoteBody";
@@ -270,6 +282,12 @@ pub struct DelegatorVotePlan {
     /// The second blinding factor to use for the ZK delegator vote proof.
     #[prost(bytes = "vec", tag = "9")]
     pub proof_blinding_s: ::prost::alloc::vec::Vec<u8>,
+    /// The proxy to delegate the voting weight to, if any.
+    #[prost(message, optional, tag = "10")]
+    pub proxy: ::core::option::Option<super::super::super::keys::v1::GovernanceKey>,
+    /// Whether to record the proxy choice as a standing delegation for the note.
+    #[prost(bool, tag = "11")]
+    pub standing_proxy: bool,
 }
 impl ::prost::Name for DelegatorVotePlan {
     const NAME: &'static str = "DelegatorVotePlan";
@@ -278,6 +296,73 @@ impl ::prost::Name for DelegatorVotePlan {
         ::prost::alloc::format!("penumbra.core.component.governance.v1.{}", Self::NAME)
     }
 }
+/// A vote cast by a proxy, on behalf of the delegators who delegated their voting weight to it.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ProxyVote {
+    /// The effecting data for the vote.
+    #[prost(message, optional, tag = "1")]
+    pub body: ::core::option::Option<ProxyVoteBody>,
+    /// The vote authorization signature is authorizing data.
+    #[prost(message, optional, tag = "2")]
+    pub auth_sig: ::core::option::Option<
+        super::super::super::super::crypto::decaf377_rdsa::v1::SpendAuthSignature,
+    >,
+}
+impl ::prost::Name for ProxyVote {
+    const NAME: &'static str = "ProxyVote";
+    const PACKAGE: &'static str = "penumbra.core.component.governance.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.component.governance.v1.{}", Self::NAME)
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ProxyVoteBody {
+    /// The proposal being voted on.
+    #[prost(uint64, tag = "1")]
+    pub proposal: u64,
+    /// The vote.
+    #[prost(message, optional, tag = "2")]
+    pub vote: ::core::option::Option<Vote>,
+    /// The governance key identifying the proxy, which signs the vote.
+    #[prost(message, optional, tag = "3")]
+    pub proxy: ::core::option::Option<super::super::super::keys::v1::GovernanceKey>,
+}
+impl ::prost::Name for ProxyVoteBody {
+    const NAME: &'static str = "ProxyVoteBody";
+    const PACKAGE: &'static str = "penumbra.core.component.governance.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.component.governance.v1.{}", Self::NAME)
+    }
+}
+/// A standing delegation of a delegation note's voting weight to a proxy, recorded by a
+/// `DelegatorVote` with `standing_proxy` set.
+///
+/// It applies to proposals started after it was recorded, until the note is spent.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct StandingProxyDelegation {
+    /// The proxy the voting weight is delegated to.
+    #[prost(message, optional, tag = "1")]
+    pub proxy: ::core::option::Option<super::super::super::keys::v1::GovernanceKey>,
+    /// The validator the note is delegated to.
+    #[prost(message, optional, tag = "2")]
+    pub identity_key: ::core::option::Option<super::super::super::keys::v1::IdentityKey>,
+    /// The value of the delegation note.
+    #[prost(message, optional, tag = "3")]
+    pub value: ::core::option::Option<super::super::super::asset::v1::Value>,
+    /// The block height at which the delegation was recorded.
+    #[prost(uint64, tag = "4")]
+    pub height: u64,
+}
+impl ::prost::Name for StandingProxyDelegation {
+    const NAME: &'static str = "StandingProxyDelegation";
+    const PACKAGE: &'static str = "penumbra.core.component.governance.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.component.governance.v1.{}", Self::NAME)
+    }
+}
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CommunityPoolDeposit {
@@ -323,6 +408,26 @@ impl ::prost::Name for CommunityPoolOutput {
         ::prost::alloc::format!("penumbra.core.component.governance.v1.{}", Self::NAME)
     }
 }
+/// Claims funds which have vested from a Community Pool vesting grant.
+///
+/// The claimed funds are always paid to the recipient of the grant, so anyone may submit a claim.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CommunityPoolVestingClaim {
+    /// The ID of the grant to claim from.
+    #[prost(uint64, tag = "1")]
+    pub grant_id: u64,
+    /// The amount to claim, of the grant's asset.
+    #[prost(message, optional, tag = "2")]
+    pub amount: ::core::option::Option<super::super::super::num::v1::Amount>,
+}
+impl ::prost::Name for CommunityPoolVestingClaim {
+    const NAME: &'static str = "CommunityPoolVestingClaim";
+    const PACKAGE: &'static str = "penumbra.core.component.governance.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.component.governance.v1.{}", Self::NAME)
+    }
+}
 /// A vote on a proposal.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -603,7 +708,10 @@ pub struct Proposal {
     #[prost(string, tag = "2")]
     pub description: ::prost::alloc::string::String,
     /// The proposal's payload.
-    #[prost(oneof = "proposal::Payload", tags = "5, 6, 7, 8, 9, 10, 11")]
+    #[prost(
+        oneof = "proposal::Payload",
+        tags = "5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16"
+    )]
     pub payload: ::core::option::Option<proposal::Payload>,
 }
 /// Nested message and enum types in `Proposal`.
@@ -757,6 +865,218 @@ pub mod proposal {
             )
         }
     }
+    /// A Community Pool vesting grant proposal sets aside funds from the Community Pool, which are
+    /// released to the recipient every epoch once the cliff has passed.
+    ///
+    /// The grant is identified by the ID of the proposal that created it.
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct CommunityPoolVestingGrant {
+        /// The address that vested funds are paid to.
+        #[prost(message, optional, tag = "1")]
+        pub recipient: ::core::option::Option<
+            super::super::super::super::keys::v1::Address,
+        >,
+        /// The total value of the grant, set aside when the proposal passes.
+        #[prost(message, optional, tag = "2")]
+        pub total: ::core::option::Option<super::super::super::super::asset::v1::Value>,
+        /// The number of epochs after the proposal passes before any funds vest.
+        #[prost(uint64, tag = "3")]
+        pub cliff_epochs: u64,
+        /// The amount that vests in each epoch since the proposal passed, once the cliff has passed.
+        #[prost(message, optional, tag = "4")]
+        pub release_per_epoch: ::core::option::Option<
+            super::super::super::super::num::v1::Amount,
+        >,
+    }
+    impl ::prost::Name for CommunityPoolVestingGrant {
+        const NAME: &'static str = "CommunityPoolVestingGrant";
+        const PACKAGE: &'static str = "penumbra.core.component.governance.v1";
+        fn full_name() -> ::prost::alloc::string::String {
+            ::prost::alloc::format!(
+                "penumbra.core.component.governance.v1.Proposal.{}", Self::NAME
+            )
+        }
+    }
+    /// Cancel a Community Pool vesting grant created by an earlier proposal.
+    ///
+    /// Funds which have vested by the time the proposal passes remain claimable by the recipient, and
+    /// the rest are returned to the Community Pool.
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct CancelCommunityPoolVestingGrant {
+        /// The ID of the grant, which is the ID of the proposal that created it.
+        #[prost(uint64, tag = "1")]
+        pub grant_id: u64,
+    }
+    impl ::prost::Name for CancelCommunityPoolVestingGrant {
+        const NAME: &'static str = "CancelCommunityPoolVestingGrant";
+        const PACKAGE: &'static str = "penumbra.core.component.governance.v1";
+        fn full_name() -> ::prost::alloc::string::String {
+            ::prost::alloc::format!(
+                "penumbra.core.component.governance.v1.Proposal.{}", Self::NAME
+            )
+        }
+    }
+    /// A batch proposal enacts several payloads atomically when passed: if any of its conditions is
+    /// not met, or any payload fails to enact, none of them take effect.
+    ///
+    /// A batch is enacted at the start of the block after it passes, so that its parameter changes
+    /// and Community Pool spends take effect together with the rest of its payloads.
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct Batch {
+        /// The payloads making up the batch, enacted in order.
+        ///
+        /// A batch may contain at most one payload of each kind.
+        #[prost(message, repeated, tag = "1")]
+        pub payloads: ::prost::alloc::vec::Vec<BatchPayload>,
+        /// The conditions on the chain state under which the batch is enacted, checked when it is
+        /// enacted.
+        #[prost(message, repeated, tag = "2")]
+        pub conditions: ::prost::alloc::vec::Vec<BatchCondition>,
+    }
+    impl ::prost::Name for Batch {
+        const NAME: &'static str = "Batch";
+        const PACKAGE: &'static str = "penumbra.core.component.governance.v1";
+        fn full_name() -> ::prost::alloc::string::String {
+            ::prost::alloc::format!(
+                "penumbra.core.component.governance.v1.Proposal.{}", Self::NAME
+            )
+        }
+    }
+    /// A payload of a batch proposal.
+    ///
+    /// Emergency proposals are passed by validators alone, and batches cannot be nested, so neither
+    /// can be batched.
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct BatchPayload {
+        #[prost(
+            oneof = "batch_payload::Payload",
+            tags = "1, 2, 3, 4, 5, 6, 7, 8, 9, 10"
+        )]
+        pub payload: ::core::option::Option<batch_payload::Payload>,
+    }
+    /// Nested message and enum types in `BatchPayload`.
+    pub mod batch_payload {
+        #[allow(clippy::derive_partial_eq_without_eq)]
+        #[derive(Clone, PartialEq, ::prost::Oneof)]
+        pub enum Payload {
+            #[prost(message, tag = "1")]
+            Signaling(super::Signaling),
+            #[prost(message, tag = "2")]
+            ParameterChange(super::ParameterChange),
+            #[prost(message, tag = "3")]
+            CommunityPoolSpend(super::CommunityPoolSpend),
+            #[prost(message, tag = "4")]
+            UpgradePlan(super::UpgradePlan),
+            #[prost(message, tag = "5")]
+            FreezeIbcClient(super::FreezeIbcClient),
+            #[prost(message, tag = "6")]
+            UnfreezeIbcClient(super::UnfreezeIbcClient),
+            #[prost(message, tag = "7")]
+            CommunityPoolVestingGrant(super::CommunityPoolVestingGrant),
+            #[prost(message, tag = "8")]
+            CancelCommunityPoolVestingGrant(super::CancelCommunityPoolVestingGrant),
+            #[prost(message, tag = "9")]
+            RegisterInterchainAccount(super::RegisterInterchainAccount),
+            #[prost(message, tag = "10")]
+            InterchainAccountTx(super::InterchainAccountTx),
+        }
+    }
+    impl ::prost::Name for BatchPayload {
+        const NAME: &'static str = "BatchPayload";
+        const PACKAGE: &'static str = "penumbra.core.component.governance.v1";
+        fn full_name() -> ::prost::alloc::string::String {
+            ::prost::alloc::format!(
+                "penumbra.core.component.governance.v1.Proposal.{}", Self::NAME
+            )
+        }
+    }
+    /// A condition on the chain state under which a batch proposal is enacted.
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct BatchCondition {
+        #[prost(oneof = "batch_condition::Condition", tags = "1, 2")]
+        pub condition: ::core::option::Option<batch_condition::Condition>,
+    }
+    /// Nested message and enum types in `BatchCondition`.
+    pub mod batch_condition {
+        #[allow(clippy::derive_partial_eq_without_eq)]
+        #[derive(Clone, PartialEq, ::prost::Oneof)]
+        pub enum Condition {
+            /// The proposal with this ID must have passed, and must precede the batch.
+            #[prost(uint64, tag = "1")]
+            ProposalPassed(u64),
+            /// The Community Pool must hold at least this value.
+            #[prost(message, tag = "2")]
+            CommunityPoolBalanceAtLeast(
+                super::super::super::super::super::asset::v1::Value,
+            ),
+        }
+    }
+    impl ::prost::Name for BatchCondition {
+        const NAME: &'static str = "BatchCondition";
+        const PACKAGE: &'static str = "penumbra.core.component.governance.v1";
+        fn full_name() -> ::prost::alloc::string::String {
+            ::prost::alloc::format!(
+                "penumbra.core.component.governance.v1.Proposal.{}", Self::NAME
+            )
+        }
+    }
+    /// Register an interchain account for the Community Pool on the chain at the other end of an
+    /// IBC connection, using ICS-27.
+    ///
+    /// When the proposal passes, Penumbra begins the handshake of the account's channel, which a
+    /// relayer completes. An account whose channel was closed can be registered again.
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct RegisterInterchainAccount {
+        /// The connection (on the Penumbra side) to the chain to host the account.
+        #[prost(string, tag = "1")]
+        pub connection_id: ::prost::alloc::string::String,
+    }
+    impl ::prost::Name for RegisterInterchainAccount {
+        const NAME: &'static str = "RegisterInterchainAccount";
+        const PACKAGE: &'static str = "penumbra.core.component.governance.v1";
+        fn full_name() -> ::prost::alloc::string::String {
+            ::prost::alloc::format!(
+                "penumbra.core.component.governance.v1.Proposal.{}", Self::NAME
+            )
+        }
+    }
+    /// Send a transaction to the Community Pool's interchain account on the chain at the other end
+    /// of an IBC connection.
+    ///
+    /// The host chain's acknowledgement of the transaction is recorded in Penumbra's state.
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct InterchainAccountTx {
+        /// The connection (on the Penumbra side) to the chain hosting the account.
+        #[prost(string, tag = "1")]
+        pub connection_id: ::prost::alloc::string::String,
+        /// The messages for the account to execute on the host chain, in order.
+        #[prost(message, repeated, tag = "2")]
+        pub messages: ::prost::alloc::vec::Vec<
+            super::super::super::ibc::v1::InterchainAccountMessage,
+        >,
+        /// A memo to include in the packet.
+        #[prost(string, tag = "3")]
+        pub memo: ::prost::alloc::string::String,
+        /// How long after the proposal passes the transaction times out, in seconds.
+        #[prost(uint64, tag = "4")]
+        pub timeout_seconds: u64,
+    }
+    impl ::prost::Name for InterchainAccountTx {
+        const NAME: &'static str = "InterchainAccountTx";
+        const PACKAGE: &'static str = "penumbra.core.component.governance.v1";
+        fn full_name() -> ::prost::alloc::string::String {
+            ::prost::alloc::format!(
+                "penumbra.core.component.governance.v1.Proposal.{}", Self::NAME
+            )
+        }
+    }
     /// The proposal's payload.
     #[allow(clippy::derive_partial_eq_without_eq)]
     #[derive(Clone, PartialEq, ::prost::Oneof)]
@@ -775,6 +1095,16 @@ pub mod proposal {
         FreezeIbcClient(FreezeIbcClient),
         #[prost(message, tag = "11")]
         UnfreezeIbcClient(UnfreezeIbcClient),
+        #[prost(message, tag = "12")]
+        CommunityPoolVestingGrant(CommunityPoolVestingGrant),
+        #[prost(message, tag = "13")]
+        CancelCommunityPoolVestingGrant(CancelCommunityPoolVestingGrant),
+        #[prost(message, tag = "14")]
+        Batch(Batch),
+        #[prost(message, tag = "15")]
+        RegisterInterchainAccount(RegisterInterchainAccount),
+        #[prost(message, tag = "16")]
+        InterchainAccountTx(InterchainAccountTx),
     }
 }
 impl ::prost::Name for Proposal {
@@ -1270,6 +1600,20 @@ impl ::prost::Name for EventValidatorVote {
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+pub struct EventProxyVote {
+    /// The proxy vote.
+    #[prost(message, optional, tag = "1")]
+    pub vote: ::core::option::Option<ProxyVote>,
+}
+impl ::prost::Name for EventProxyVote {
+    const NAME: &'static str = "EventProxyVote";
+    const PACKAGE: &'static str = "penumbra.core.component.governance.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.component.governance.v1.{}", Self::NAME)
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
 pub struct EventProposalWithdraw {
     /// Details on the withdrawn proposal.
     #[prost(message, optional, tag = "1")]
@@ -1357,6 +1701,11 @@ pub enum ProposalKind {
     UpgradePlan = 5,
     FreezeIbcClient = 6,
     UnfreezeIbcClient = 7,
+    CommunityPoolVestingGrant = 8,
+    CancelCommunityPoolVestingGrant = 9,
+    Batch = 10,
+    RegisterInterchainAccount = 11,
+    InterchainAccountTx = 12,
 }
 impl ProposalKind {
     /// String value of the enum field names used in the ProtoBuf definition.
@@ -1373,6 +1722,17 @@ impl ProposalKind {
             ProposalKind::UpgradePlan => "PROPOSAL_KIND_UPGRADE_PLAN",
             ProposalKind::FreezeIbcClient => "PROPOSAL_KIND_FREEZE_IBC_CLIENT",
             ProposalKind::UnfreezeIbcClient => "PROPOSAL_KIND_UNFREEZE_IBC_CLIENT",
+            ProposalKind::CommunityPoolVestingGrant => {
+                "PROPOSAL_KIND_COMMUNITY_POOL_VESTING_GRANT"
+            }
+            ProposalKind::CancelCommunityPoolVestingGrant => {
+                "PROPOSAL_KIND_CANCEL_COMMUNITY_POOL_VESTING_GRANT"
+            }
+            ProposalKind::Batch => "PROPOSAL_KIND_BATCH",
+            ProposalKind::RegisterInterchainAccount => {
+                "PROPOSAL_KIND_REGISTER_INTERCHAIN_ACCOUNT"
+            }
+            ProposalKind::InterchainAccountTx => "PROPOSAL_KIND_INTERCHAIN_ACCOUNT_TX",
         }
     }
     /// Creates an enum from field names used in the ProtoBuf definition.
@@ -1386,6 +1746,17 @@ impl ProposalKind {
             "PROPOSAL_KIND_UPGRADE_PLAN" => Some(Self::UpgradePlan),
             "PROPOSAL_KIND_FREEZE_IBC_CLIENT" => Some(Self::FreezeIbcClient),
             "PROPOSAL_KIND_UNFREEZE_IBC_CLIENT" => Some(Self::UnfreezeIbcClient),
+            "PROPOSAL_KIND_COMMUNITY_POOL_VESTING_GRANT" => {
+                Some(Self::CommunityPoolVestingGrant)
+            }
+            "PROPOSAL_KIND_CANCEL_COMMUNITY_POOL_VESTING_GRANT" => {
+                Some(Self::CancelCommunityPoolVestingGrant)
+            }
+            "PROPOSAL_KIND_BATCH" => Some(Self::Batch),
+            "PROPOSAL_KIND_REGISTER_INTERCHAIN_ACCOUNT" => {
+                Some(Self::RegisterInterchainAccount)
+            }
+            "PROPOSAL_KIND_INTERCHAIN_ACCOUNT_TX" => Some(Self::InterchainAccountTx),
             _ => None,
         }
     }
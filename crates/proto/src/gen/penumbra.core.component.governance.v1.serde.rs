@@ -900,6 +900,122 @@ impl<'de> serde::Deserialize<'de> for CommunityPoolSpend {
         deserializer.deserialize_struct("penumbra.core.component.governance.v1.CommunityPoolSpend", FIELDS, GeneratedVisitor)
     }
 }
+impl serde::Serialize for CommunityPoolVestingClaim {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.grant_id != 0 {
+            len += 1;
+        }
+        if self.amount.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.governance.v1.CommunityPoolVestingClaim", len)?;
+        if self.grant_id != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("grantId", ToString::to_string(&self.grant_id).as_str())?;
+        }
+        if let Some(v) = self.amount.as_ref() {
+            struct_ser.serialize_field("amount", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for CommunityPoolVestingClaim {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "grant_id",
+            "grantId",
+            "amount",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            GrantId,
+            Amount,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "grantId" | "grant_id" => Ok(GeneratedField::GrantId),
+                            "amount" => Ok(GeneratedField::Amount),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = CommunityPoolVestingClaim;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.governance.v1.CommunityPoolVestingClaim")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<CommunityPoolVestingClaim, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut grant_id__ = None;
+                let mut amount__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::GrantId => {
+                            if grant_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("grantId"));
+                            }
+                            grant_id__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Amount => {
+                            if amount__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("amount"));
+                            }
+                            amount__ = map_.next_value()?;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(CommunityPoolVestingClaim {
+                    grant_id: grant_id__.unwrap_or_default(),
+                    amount: amount__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.governance.v1.CommunityPoolVestingClaim", FIELDS, GeneratedVisitor)
+    }
+}
 impl serde::Serialize for DelegatorVote {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
@@ -1059,6 +1175,12 @@ impl serde::Serialize for DelegatorVoteBody {
         if self.rk.is_some() {
             len += 1;
         }
+        if self.proxy.is_some() {
+            len += 1;
+        }
+        if self.standing_proxy {
+            len += 1;
+        }
         let mut struct_ser = serializer.serialize_struct("penumbra.core.component.governance.v1.DelegatorVoteBody", len)?;
         if self.proposal != 0 {
             #[allow(clippy::needless_borrow)]
@@ -1083,6 +1205,12 @@ impl serde::Serialize for DelegatorVoteBody {
         if let Some(v) = self.rk.as_ref() {
             struct_ser.serialize_field("rk", v)?;
         }
+        if let Some(v) = self.proxy.as_ref() {
+            struct_ser.serialize_field("proxy", v)?;
+        }
+        if self.standing_proxy {
+            struct_ser.serialize_field("standingProxy", &self.standing_proxy)?;
+        }
         struct_ser.end()
     }
 }
@@ -1102,6 +1230,9 @@ impl<'de> serde::Deserialize<'de> for DelegatorVoteBody {
             "unbondedAmount",
             "nullifier",
             "rk",
+            "proxy",
+            "standing_proxy",
+            "standingProxy",
         ];
 
         #[allow(clippy::enum_variant_names)]
@@ -1113,6 +1244,8 @@ impl<'de> serde::Deserialize<'de> for DelegatorVoteBody {
             UnbondedAmount,
             Nullifier,
             Rk,
+            Proxy,
+            StandingProxy,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -1142,6 +1275,8 @@ impl<'de> serde::Deserialize<'de> for DelegatorVoteBody {
                             "unbondedAmount" | "unbonded_amount" => Ok(GeneratedField::UnbondedAmount),
                             "nullifier" => Ok(GeneratedField::Nullifier),
                             "rk" => Ok(GeneratedField::Rk),
+                            "proxy" => Ok(GeneratedField::Proxy),
+                            "standingProxy" | "standing_proxy" => Ok(GeneratedField::StandingProxy),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -1168,6 +1303,8 @@ impl<'de> serde::Deserialize<'de> for DelegatorVoteBody {
                 let mut unbonded_amount__ = None;
                 let mut nullifier__ = None;
                 let mut rk__ = None;
+                let mut proxy__ = None;
+                let mut standing_proxy__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
                         GeneratedField::Proposal => {
@@ -1216,6 +1353,18 @@ impl<'de> serde::Deserialize<'de> for DelegatorVoteBody {
                             }
                             rk__ = map_.next_value()?;
                         }
+                        GeneratedField::Proxy => {
+                            if proxy__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("proxy"));
+                            }
+                            proxy__ = map_.next_value()?;
+                        }
+                        GeneratedField::StandingProxy => {
+                            if standing_proxy__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("standingProxy"));
+                            }
+                            standing_proxy__ = Some(map_.next_value()?);
+                        }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
@@ -1229,6 +1378,8 @@ impl<'de> serde::Deserialize<'de> for DelegatorVoteBody {
                     unbonded_amount: unbonded_amount__,
                     nullifier: nullifier__,
                     rk: rk__,
+                    proxy: proxy__,
+                    standing_proxy: standing_proxy__.unwrap_or_default(),
                 })
             }
         }
@@ -1270,6 +1421,12 @@ impl serde::Serialize for DelegatorVotePlan {
         if !self.proof_blinding_s.is_empty() {
             len += 1;
         }
+        if self.proxy.is_some() {
+            len += 1;
+        }
+        if self.standing_proxy {
+            len += 1;
+        }
         let mut struct_ser = serializer.serialize_struct("penumbra.core.component.governance.v1.DelegatorVotePlan", len)?;
         if self.proposal != 0 {
             #[allow(clippy::needless_borrow)]
@@ -1304,6 +1461,12 @@ impl serde::Serialize for DelegatorVotePlan {
             #[allow(clippy::needless_borrow)]
             struct_ser.serialize_field("proofBlindingS", pbjson::private::base64::encode(&self.proof_blinding_s).as_str())?;
         }
+        if let Some(v) = self.proxy.as_ref() {
+            struct_ser.serialize_field("proxy", v)?;
+        }
+        if self.standing_proxy {
+            struct_ser.serialize_field("standingProxy", &self.standing_proxy)?;
+        }
         struct_ser.end()
     }
 }
@@ -1329,6 +1492,9 @@ impl<'de> serde::Deserialize<'de> for DelegatorVotePlan {
             "proofBlindingR",
             "proof_blinding_s",
             "proofBlindingS",
+            "proxy",
+            "standing_proxy",
+            "standingProxy",
         ];
 
         #[allow(clippy::enum_variant_names)]
@@ -1342,6 +1508,8 @@ impl<'de> serde::Deserialize<'de> for DelegatorVotePlan {
             Randomizer,
             ProofBlindingR,
             ProofBlindingS,
+            Proxy,
+            StandingProxy,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -1373,6 +1541,8 @@ impl<'de> serde::Deserialize<'de> for DelegatorVotePlan {
                             "randomizer" => Ok(GeneratedField::Randomizer),
                             "proofBlindingR" | "proof_blinding_r" => Ok(GeneratedField::ProofBlindingR),
                             "proofBlindingS" | "proof_blinding_s" => Ok(GeneratedField::ProofBlindingS),
+                            "proxy" => Ok(GeneratedField::Proxy),
+                            "standingProxy" | "standing_proxy" => Ok(GeneratedField::StandingProxy),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -1401,6 +1571,8 @@ impl<'de> serde::Deserialize<'de> for DelegatorVotePlan {
                 let mut randomizer__ = None;
                 let mut proof_blinding_r__ = None;
                 let mut proof_blinding_s__ = None;
+                let mut proxy__ = None;
+                let mut standing_proxy__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
                         GeneratedField::Proposal => {
@@ -1469,6 +1641,18 @@ impl<'de> serde::Deserialize<'de> for DelegatorVotePlan {
                                 Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
                             ;
                         }
+                        GeneratedField::Proxy => {
+                            if proxy__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("proxy"));
+                            }
+                            proxy__ = map_.next_value()?;
+                        }
+                        GeneratedField::StandingProxy => {
+                            if standing_proxy__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("standingProxy"));
+                            }
+                            standing_proxy__ = Some(map_.next_value()?);
+                        }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
@@ -1484,6 +1668,8 @@ impl<'de> serde::Deserialize<'de> for DelegatorVotePlan {
                     randomizer: randomizer__.unwrap_or_default(),
                     proof_blinding_r: proof_blinding_r__.unwrap_or_default(),
                     proof_blinding_s: proof_blinding_s__.unwrap_or_default(),
+                    proxy: proxy__,
+                    standing_proxy: standing_proxy__.unwrap_or_default(),
                 })
             }
         }
@@ -2667,6 +2853,101 @@ impl<'de> serde::Deserialize<'de> for EventProposalWithdraw {
         deserializer.deserialize_struct("penumbra.core.component.governance.v1.EventProposalWithdraw", FIELDS, GeneratedVisitor)
     }
 }
+impl serde::Serialize for EventProxyVote {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.vote.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.governance.v1.EventProxyVote", len)?;
+        if let Some(v) = self.vote.as_ref() {
+            struct_ser.serialize_field("vote", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for EventProxyVote {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "vote",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Vote,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "vote" => Ok(GeneratedField::Vote),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = EventProxyVote;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.governance.v1.EventProxyVote")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<EventProxyVote, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut vote__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Vote => {
+                            if vote__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("vote"));
+                            }
+                            vote__ = map_.next_value()?;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(EventProxyVote {
+                    vote: vote__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.governance.v1.EventProxyVote", FIELDS, GeneratedVisitor)
+    }
+}
 impl serde::Serialize for EventValidatorVote {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
@@ -3275,6 +3556,21 @@ impl serde::Serialize for Proposal {
                 proposal::Payload::UnfreezeIbcClient(v) => {
                     struct_ser.serialize_field("unfreezeIbcClient", v)?;
                 }
+                proposal::Payload::CommunityPoolVestingGrant(v) => {
+                    struct_ser.serialize_field("communityPoolVestingGrant", v)?;
+                }
+                proposal::Payload::CancelCommunityPoolVestingGrant(v) => {
+                    struct_ser.serialize_field("cancelCommunityPoolVestingGrant", v)?;
+                }
+                proposal::Payload::Batch(v) => {
+                    struct_ser.serialize_field("batch", v)?;
+                }
+                proposal::Payload::RegisterInterchainAccount(v) => {
+                    struct_ser.serialize_field("registerInterchainAccount", v)?;
+                }
+                proposal::Payload::InterchainAccountTx(v) => {
+                    struct_ser.serialize_field("interchainAccountTx", v)?;
+                }
             }
         }
         struct_ser.end()
@@ -3302,6 +3598,15 @@ impl<'de> serde::Deserialize<'de> for Proposal {
             "freezeIbcClient",
             "unfreeze_ibc_client",
             "unfreezeIbcClient",
+            "community_pool_vesting_grant",
+            "communityPoolVestingGrant",
+            "cancel_community_pool_vesting_grant",
+            "cancelCommunityPoolVestingGrant",
+            "batch",
+            "register_interchain_account",
+            "registerInterchainAccount",
+            "interchain_account_tx",
+            "interchainAccountTx",
         ];
 
         #[allow(clippy::enum_variant_names)]
@@ -3316,6 +3621,11 @@ impl<'de> serde::Deserialize<'de> for Proposal {
             UpgradePlan,
             FreezeIbcClient,
             UnfreezeIbcClient,
+            CommunityPoolVestingGrant,
+            CancelCommunityPoolVestingGrant,
+            Batch,
+            RegisterInterchainAccount,
+            InterchainAccountTx,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -3348,6 +3658,11 @@ impl<'de> serde::Deserialize<'de> for Proposal {
                             "upgradePlan" | "upgrade_plan" => Ok(GeneratedField::UpgradePlan),
                             "freezeIbcClient" | "freeze_ibc_client" => Ok(GeneratedField::FreezeIbcClient),
                             "unfreezeIbcClient" | "unfreeze_ibc_client" => Ok(GeneratedField::UnfreezeIbcClient),
+                            "communityPoolVestingGrant" | "community_pool_vesting_grant" => Ok(GeneratedField::CommunityPoolVestingGrant),
+                            "cancelCommunityPoolVestingGrant" | "cancel_community_pool_vesting_grant" => Ok(GeneratedField::CancelCommunityPoolVestingGrant),
+                            "batch" => Ok(GeneratedField::Batch),
+                            "registerInterchainAccount" | "register_interchain_account" => Ok(GeneratedField::RegisterInterchainAccount),
+                            "interchainAccountTx" | "interchain_account_tx" => Ok(GeneratedField::InterchainAccountTx),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -3440,6 +3755,41 @@ impl<'de> serde::Deserialize<'de> for Proposal {
                                 return Err(serde::de::Error::duplicate_field("unfreezeIbcClient"));
                             }
                             payload__ = map_.next_value::<::std::option::Option<_>>()?.map(proposal::Payload::UnfreezeIbcClient)
+;
+                        }
+                        GeneratedField::CommunityPoolVestingGrant => {
+                            if payload__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("communityPoolVestingGrant"));
+                            }
+                            payload__ = map_.next_value::<::std::option::Option<_>>()?.map(proposal::Payload::CommunityPoolVestingGrant)
+;
+                        }
+                        GeneratedField::CancelCommunityPoolVestingGrant => {
+                            if payload__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("cancelCommunityPoolVestingGrant"));
+                            }
+                            payload__ = map_.next_value::<::std::option::Option<_>>()?.map(proposal::Payload::CancelCommunityPoolVestingGrant)
+;
+                        }
+                        GeneratedField::Batch => {
+                            if payload__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("batch"));
+                            }
+                            payload__ = map_.next_value::<::std::option::Option<_>>()?.map(proposal::Payload::Batch)
+;
+                        }
+                        GeneratedField::RegisterInterchainAccount => {
+                            if payload__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("registerInterchainAccount"));
+                            }
+                            payload__ = map_.next_value::<::std::option::Option<_>>()?.map(proposal::Payload::RegisterInterchainAccount)
+;
+                        }
+                        GeneratedField::InterchainAccountTx => {
+                            if payload__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("interchainAccountTx"));
+                            }
+                            payload__ = map_.next_value::<::std::option::Option<_>>()?.map(proposal::Payload::InterchainAccountTx)
 ;
                         }
                         GeneratedField::__SkipField__ => {
@@ -3458,7 +3808,7 @@ impl<'de> serde::Deserialize<'de> for Proposal {
         deserializer.deserialize_struct("penumbra.core.component.governance.v1.Proposal", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for proposal::CommunityPoolSpend {
+impl serde::Serialize for proposal::Batch {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -3466,30 +3816,37 @@ impl serde::Serialize for proposal::CommunityPoolSpend {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.transaction_plan.is_some() {
+        if !self.payloads.is_empty() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.governance.v1.Proposal.CommunityPoolSpend", len)?;
-        if let Some(v) = self.transaction_plan.as_ref() {
-            struct_ser.serialize_field("transactionPlan", v)?;
+        if !self.conditions.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.governance.v1.Proposal.Batch", len)?;
+        if !self.payloads.is_empty() {
+            struct_ser.serialize_field("payloads", &self.payloads)?;
+        }
+        if !self.conditions.is_empty() {
+            struct_ser.serialize_field("conditions", &self.conditions)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for proposal::CommunityPoolSpend {
+impl<'de> serde::Deserialize<'de> for proposal::Batch {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "transaction_plan",
-            "transactionPlan",
+            "payloads",
+            "conditions",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            TransactionPlan,
+            Payloads,
+            Conditions,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -3512,7 +3869,8 @@ impl<'de> serde::Deserialize<'de> for proposal::CommunityPoolSpend {
                         E: serde::de::Error,
                     {
                         match value {
-                            "transactionPlan" | "transaction_plan" => Ok(GeneratedField::TransactionPlan),
+                            "payloads" => Ok(GeneratedField::Payloads),
+                            "conditions" => Ok(GeneratedField::Conditions),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -3522,39 +3880,47 @@ impl<'de> serde::Deserialize<'de> for proposal::CommunityPoolSpend {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = proposal::CommunityPoolSpend;
+            type Value = proposal::Batch;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.governance.v1.Proposal.CommunityPoolSpend")
+                formatter.write_str("struct penumbra.core.component.governance.v1.Proposal.Batch")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<proposal::CommunityPoolSpend, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<proposal::Batch, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut transaction_plan__ = None;
+                let mut payloads__ = None;
+                let mut conditions__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::TransactionPlan => {
-                            if transaction_plan__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("transactionPlan"));
+                        GeneratedField::Payloads => {
+                            if payloads__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("payloads"));
                             }
-                            transaction_plan__ = map_.next_value()?;
+                            payloads__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Conditions => {
+                            if conditions__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("conditions"));
+                            }
+                            conditions__ = Some(map_.next_value()?);
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(proposal::CommunityPoolSpend {
-                    transaction_plan: transaction_plan__,
+                Ok(proposal::Batch {
+                    payloads: payloads__.unwrap_or_default(),
+                    conditions: conditions__.unwrap_or_default(),
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.governance.v1.Proposal.CommunityPoolSpend", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.governance.v1.Proposal.Batch", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for proposal::Emergency {
+impl serde::Serialize for proposal::BatchCondition {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -3562,30 +3928,41 @@ impl serde::Serialize for proposal::Emergency {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.halt_chain {
+        if self.condition.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.governance.v1.Proposal.Emergency", len)?;
-        if self.halt_chain {
-            struct_ser.serialize_field("haltChain", &self.halt_chain)?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.governance.v1.Proposal.BatchCondition", len)?;
+        if let Some(v) = self.condition.as_ref() {
+            match v {
+                proposal::batch_condition::Condition::ProposalPassed(v) => {
+                    #[allow(clippy::needless_borrow)]
+                    struct_ser.serialize_field("proposalPassed", ToString::to_string(&v).as_str())?;
+                }
+                proposal::batch_condition::Condition::CommunityPoolBalanceAtLeast(v) => {
+                    struct_ser.serialize_field("communityPoolBalanceAtLeast", v)?;
+                }
+            }
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for proposal::Emergency {
+impl<'de> serde::Deserialize<'de> for proposal::BatchCondition {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "halt_chain",
-            "haltChain",
+            "proposal_passed",
+            "proposalPassed",
+            "community_pool_balance_at_least",
+            "communityPoolBalanceAtLeast",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            HaltChain,
+            ProposalPassed,
+            CommunityPoolBalanceAtLeast,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -3608,7 +3985,8 @@ impl<'de> serde::Deserialize<'de> for proposal::Emergency {
                         E: serde::de::Error,
                     {
                         match value {
-                            "haltChain" | "halt_chain" => Ok(GeneratedField::HaltChain),
+                            "proposalPassed" | "proposal_passed" => Ok(GeneratedField::ProposalPassed),
+                            "communityPoolBalanceAtLeast" | "community_pool_balance_at_least" => Ok(GeneratedField::CommunityPoolBalanceAtLeast),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -3618,7 +3996,682 @@ impl<'de> serde::Deserialize<'de> for proposal::Emergency {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = proposal::Emergency;
+            type Value = proposal::BatchCondition;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.governance.v1.Proposal.BatchCondition")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<proposal::BatchCondition, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut condition__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::ProposalPassed => {
+                            if condition__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("proposalPassed"));
+                            }
+                            condition__ = map_.next_value::<::std::option::Option<::pbjson::private::NumberDeserialize<_>>>()?.map(|x| proposal::batch_condition::Condition::ProposalPassed(x.0));
+                        }
+                        GeneratedField::CommunityPoolBalanceAtLeast => {
+                            if condition__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("communityPoolBalanceAtLeast"));
+                            }
+                            condition__ = map_.next_value::<::std::option::Option<_>>()?.map(proposal::batch_condition::Condition::CommunityPoolBalanceAtLeast)
+;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(proposal::BatchCondition {
+                    condition: condition__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.governance.v1.Proposal.BatchCondition", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for proposal::BatchPayload {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.payload.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.governance.v1.Proposal.BatchPayload", len)?;
+        if let Some(v) = self.payload.as_ref() {
+            match v {
+                proposal::batch_payload::Payload::Signaling(v) => {
+                    struct_ser.serialize_field("signaling", v)?;
+                }
+                proposal::batch_payload::Payload::ParameterChange(v) => {
+                    struct_ser.serialize_field("parameterChange", v)?;
+                }
+                proposal::batch_payload::Payload::CommunityPoolSpend(v) => {
+                    struct_ser.serialize_field("communityPoolSpend", v)?;
+                }
+                proposal::batch_payload::Payload::UpgradePlan(v) => {
+                    struct_ser.serialize_field("upgradePlan", v)?;
+                }
+                proposal::batch_payload::Payload::FreezeIbcClient(v) => {
+                    struct_ser.serialize_field("freezeIbcClient", v)?;
+                }
+                proposal::batch_payload::Payload::UnfreezeIbcClient(v) => {
+                    struct_ser.serialize_field("unfreezeIbcClient", v)?;
+                }
+                proposal::batch_payload::Payload::CommunityPoolVestingGrant(v) => {
+                    struct_ser.serialize_field("communityPoolVestingGrant", v)?;
+                }
+                proposal::batch_payload::Payload::CancelCommunityPoolVestingGrant(v) => {
+                    struct_ser.serialize_field("cancelCommunityPoolVestingGrant", v)?;
+                }
+                proposal::batch_payload::Payload::RegisterInterchainAccount(v) => {
+                    struct_ser.serialize_field("registerInterchainAccount", v)?;
+                }
+                proposal::batch_payload::Payload::InterchainAccountTx(v) => {
+                    struct_ser.serialize_field("interchainAccountTx", v)?;
+                }
+            }
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for proposal::BatchPayload {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "signaling",
+            "parameter_change",
+            "parameterChange",
+            "community_pool_spend",
+            "communityPoolSpend",
+            "upgrade_plan",
+            "upgradePlan",
+            "freeze_ibc_client",
+            "freezeIbcClient",
+            "unfreeze_ibc_client",
+            "unfreezeIbcClient",
+            "community_pool_vesting_grant",
+            "communityPoolVestingGrant",
+            "cancel_community_pool_vesting_grant",
+            "cancelCommunityPoolVestingGrant",
+            "register_interchain_account",
+            "registerInterchainAccount",
+            "interchain_account_tx",
+            "interchainAccountTx",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Signaling,
+            ParameterChange,
+            CommunityPoolSpend,
+            UpgradePlan,
+            FreezeIbcClient,
+            UnfreezeIbcClient,
+            CommunityPoolVestingGrant,
+            CancelCommunityPoolVestingGrant,
+            RegisterInterchainAccount,
+            InterchainAccountTx,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "signaling" => Ok(GeneratedField::Signaling),
+                            "parameterChange" | "parameter_change" => Ok(GeneratedField::ParameterChange),
+                            "communityPoolSpend" | "community_pool_spend" => Ok(GeneratedField::CommunityPoolSpend),
+                            "upgradePlan" | "upgrade_plan" => Ok(GeneratedField::UpgradePlan),
+                            "freezeIbcClient" | "freeze_ibc_client" => Ok(GeneratedField::FreezeIbcClient),
+                            "unfreezeIbcClient" | "unfreeze_ibc_client" => Ok(GeneratedField::UnfreezeIbcClient),
+                            "communityPoolVestingGrant" | "community_pool_vesting_grant" => Ok(GeneratedField::CommunityPoolVestingGrant),
+                            "cancelCommunityPoolVestingGrant" | "cancel_community_pool_vesting_grant" => Ok(GeneratedField::CancelCommunityPoolVestingGrant),
+                            "registerInterchainAccount" | "register_interchain_account" => Ok(GeneratedField::RegisterInterchainAccount),
+                            "interchainAccountTx" | "interchain_account_tx" => Ok(GeneratedField::InterchainAccountTx),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = proposal::BatchPayload;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.governance.v1.Proposal.BatchPayload")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<proposal::BatchPayload, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut payload__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Signaling => {
+                            if payload__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("signaling"));
+                            }
+                            payload__ = map_.next_value::<::std::option::Option<_>>()?.map(proposal::batch_payload::Payload::Signaling)
+;
+                        }
+                        GeneratedField::ParameterChange => {
+                            if payload__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("parameterChange"));
+                            }
+                            payload__ = map_.next_value::<::std::option::Option<_>>()?.map(proposal::batch_payload::Payload::ParameterChange)
+;
+                        }
+                        GeneratedField::CommunityPoolSpend => {
+                            if payload__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("communityPoolSpend"));
+                            }
+                            payload__ = map_.next_value::<::std::option::Option<_>>()?.map(proposal::batch_payload::Payload::CommunityPoolSpend)
+;
+                        }
+                        GeneratedField::UpgradePlan => {
+                            if payload__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("upgradePlan"));
+                            }
+                            payload__ = map_.next_value::<::std::option::Option<_>>()?.map(proposal::batch_payload::Payload::UpgradePlan)
+;
+                        }
+                        GeneratedField::FreezeIbcClient => {
+                            if payload__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("freezeIbcClient"));
+                            }
+                            payload__ = map_.next_value::<::std::option::Option<_>>()?.map(proposal::batch_payload::Payload::FreezeIbcClient)
+;
+                        }
+                        GeneratedField::UnfreezeIbcClient => {
+                            if payload__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("unfreezeIbcClient"));
+                            }
+                            payload__ = map_.next_value::<::std::option::Option<_>>()?.map(proposal::batch_payload::Payload::UnfreezeIbcClient)
+;
+                        }
+                        GeneratedField::CommunityPoolVestingGrant => {
+                            if payload__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("communityPoolVestingGrant"));
+                            }
+                            payload__ = map_.next_value::<::std::option::Option<_>>()?.map(proposal::batch_payload::Payload::CommunityPoolVestingGrant)
+;
+                        }
+                        GeneratedField::CancelCommunityPoolVestingGrant => {
+                            if payload__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("cancelCommunityPoolVestingGrant"));
+                            }
+                            payload__ = map_.next_value::<::std::option::Option<_>>()?.map(proposal::batch_payload::Payload::CancelCommunityPoolVestingGrant)
+;
+                        }
+                        GeneratedField::RegisterInterchainAccount => {
+                            if payload__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("registerInterchainAccount"));
+                            }
+                            payload__ = map_.next_value::<::std::option::Option<_>>()?.map(proposal::batch_payload::Payload::RegisterInterchainAccount)
+;
+                        }
+                        GeneratedField::InterchainAccountTx => {
+                            if payload__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("interchainAccountTx"));
+                            }
+                            payload__ = map_.next_value::<::std::option::Option<_>>()?.map(proposal::batch_payload::Payload::InterchainAccountTx)
+;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(proposal::BatchPayload {
+                    payload: payload__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.governance.v1.Proposal.BatchPayload", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for proposal::CancelCommunityPoolVestingGrant {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.grant_id != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.governance.v1.Proposal.CancelCommunityPoolVestingGrant", len)?;
+        if self.grant_id != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("grantId", ToString::to_string(&self.grant_id).as_str())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for proposal::CancelCommunityPoolVestingGrant {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "grant_id",
+            "grantId",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            GrantId,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "grantId" | "grant_id" => Ok(GeneratedField::GrantId),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = proposal::CancelCommunityPoolVestingGrant;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.governance.v1.Proposal.CancelCommunityPoolVestingGrant")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<proposal::CancelCommunityPoolVestingGrant, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut grant_id__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::GrantId => {
+                            if grant_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("grantId"));
+                            }
+                            grant_id__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(proposal::CancelCommunityPoolVestingGrant {
+                    grant_id: grant_id__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.governance.v1.Proposal.CancelCommunityPoolVestingGrant", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for proposal::CommunityPoolSpend {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.transaction_plan.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.governance.v1.Proposal.CommunityPoolSpend", len)?;
+        if let Some(v) = self.transaction_plan.as_ref() {
+            struct_ser.serialize_field("transactionPlan", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for proposal::CommunityPoolSpend {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "transaction_plan",
+            "transactionPlan",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            TransactionPlan,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "transactionPlan" | "transaction_plan" => Ok(GeneratedField::TransactionPlan),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = proposal::CommunityPoolSpend;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.governance.v1.Proposal.CommunityPoolSpend")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<proposal::CommunityPoolSpend, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut transaction_plan__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::TransactionPlan => {
+                            if transaction_plan__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("transactionPlan"));
+                            }
+                            transaction_plan__ = map_.next_value()?;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(proposal::CommunityPoolSpend {
+                    transaction_plan: transaction_plan__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.governance.v1.Proposal.CommunityPoolSpend", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for proposal::CommunityPoolVestingGrant {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.recipient.is_some() {
+            len += 1;
+        }
+        if self.total.is_some() {
+            len += 1;
+        }
+        if self.cliff_epochs != 0 {
+            len += 1;
+        }
+        if self.release_per_epoch.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.governance.v1.Proposal.CommunityPoolVestingGrant", len)?;
+        if let Some(v) = self.recipient.as_ref() {
+            struct_ser.serialize_field("recipient", v)?;
+        }
+        if let Some(v) = self.total.as_ref() {
+            struct_ser.serialize_field("total", v)?;
+        }
+        if self.cliff_epochs != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("cliffEpochs", ToString::to_string(&self.cliff_epochs).as_str())?;
+        }
+        if let Some(v) = self.release_per_epoch.as_ref() {
+            struct_ser.serialize_field("releasePerEpoch", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for proposal::CommunityPoolVestingGrant {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "recipient",
+            "total",
+            "cliff_epochs",
+            "cliffEpochs",
+            "release_per_epoch",
+            "releasePerEpoch",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Recipient,
+            Total,
+            CliffEpochs,
+            ReleasePerEpoch,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "recipient" => Ok(GeneratedField::Recipient),
+                            "total" => Ok(GeneratedField::Total),
+                            "cliffEpochs" | "cliff_epochs" => Ok(GeneratedField::CliffEpochs),
+                            "releasePerEpoch" | "release_per_epoch" => Ok(GeneratedField::ReleasePerEpoch),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = proposal::CommunityPoolVestingGrant;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.governance.v1.Proposal.CommunityPoolVestingGrant")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<proposal::CommunityPoolVestingGrant, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut recipient__ = None;
+                let mut total__ = None;
+                let mut cliff_epochs__ = None;
+                let mut release_per_epoch__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Recipient => {
+                            if recipient__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("recipient"));
+                            }
+                            recipient__ = map_.next_value()?;
+                        }
+                        GeneratedField::Total => {
+                            if total__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("total"));
+                            }
+                            total__ = map_.next_value()?;
+                        }
+                        GeneratedField::CliffEpochs => {
+                            if cliff_epochs__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("cliffEpochs"));
+                            }
+                            cliff_epochs__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::ReleasePerEpoch => {
+                            if release_per_epoch__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("releasePerEpoch"));
+                            }
+                            release_per_epoch__ = map_.next_value()?;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(proposal::CommunityPoolVestingGrant {
+                    recipient: recipient__,
+                    total: total__,
+                    cliff_epochs: cliff_epochs__.unwrap_or_default(),
+                    release_per_epoch: release_per_epoch__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.governance.v1.Proposal.CommunityPoolVestingGrant", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for proposal::Emergency {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.halt_chain {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.governance.v1.Proposal.Emergency", len)?;
+        if self.halt_chain {
+            struct_ser.serialize_field("haltChain", &self.halt_chain)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for proposal::Emergency {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "halt_chain",
+            "haltChain",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            HaltChain,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "haltChain" | "halt_chain" => Ok(GeneratedField::HaltChain),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = proposal::Emergency;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
                 formatter.write_str("struct penumbra.core.component.governance.v1.Proposal.Emergency")
@@ -3746,6 +4799,157 @@ impl<'de> serde::Deserialize<'de> for proposal::FreezeIbcClient {
         deserializer.deserialize_struct("penumbra.core.component.governance.v1.Proposal.FreezeIbcClient", FIELDS, GeneratedVisitor)
     }
 }
+impl serde::Serialize for proposal::InterchainAccountTx {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.connection_id.is_empty() {
+            len += 1;
+        }
+        if !self.messages.is_empty() {
+            len += 1;
+        }
+        if !self.memo.is_empty() {
+            len += 1;
+        }
+        if self.timeout_seconds != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.governance.v1.Proposal.InterchainAccountTx", len)?;
+        if !self.connection_id.is_empty() {
+            struct_ser.serialize_field("connectionId", &self.connection_id)?;
+        }
+        if !self.messages.is_empty() {
+            struct_ser.serialize_field("messages", &self.messages)?;
+        }
+        if !self.memo.is_empty() {
+            struct_ser.serialize_field("memo", &self.memo)?;
+        }
+        if self.timeout_seconds != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("timeoutSeconds", ToString::to_string(&self.timeout_seconds).as_str())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for proposal::InterchainAccountTx {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "connection_id",
+            "connectionId",
+            "messages",
+            "memo",
+            "timeout_seconds",
+            "timeoutSeconds",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            ConnectionId,
+            Messages,
+            Memo,
+            TimeoutSeconds,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "connectionId" | "connection_id" => Ok(GeneratedField::ConnectionId),
+                            "messages" => Ok(GeneratedField::Messages),
+                            "memo" => Ok(GeneratedField::Memo),
+                            "timeoutSeconds" | "timeout_seconds" => Ok(GeneratedField::TimeoutSeconds),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = proposal::InterchainAccountTx;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.governance.v1.Proposal.InterchainAccountTx")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<proposal::InterchainAccountTx, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut connection_id__ = None;
+                let mut messages__ = None;
+                let mut memo__ = None;
+                let mut timeout_seconds__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::ConnectionId => {
+                            if connection_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("connectionId"));
+                            }
+                            connection_id__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Messages => {
+                            if messages__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("messages"));
+                            }
+                            messages__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Memo => {
+                            if memo__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("memo"));
+                            }
+                            memo__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::TimeoutSeconds => {
+                            if timeout_seconds__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("timeoutSeconds"));
+                            }
+                            timeout_seconds__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(proposal::InterchainAccountTx {
+                    connection_id: connection_id__.unwrap_or_default(),
+                    messages: messages__.unwrap_or_default(),
+                    memo: memo__.unwrap_or_default(),
+                    timeout_seconds: timeout_seconds__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.governance.v1.Proposal.InterchainAccountTx", FIELDS, GeneratedVisitor)
+    }
+}
 impl serde::Serialize for proposal::ParameterChange {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
@@ -3799,10 +5003,133 @@ impl<'de> serde::Deserialize<'de> for proposal::ParameterChange {
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            OldParameters,
-            NewParameters,
-            Preconditions,
-            Changes,
+            OldParameters,
+            NewParameters,
+            Preconditions,
+            Changes,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "oldParameters" | "old_parameters" => Ok(GeneratedField::OldParameters),
+                            "newParameters" | "new_parameters" => Ok(GeneratedField::NewParameters),
+                            "preconditions" => Ok(GeneratedField::Preconditions),
+                            "changes" => Ok(GeneratedField::Changes),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = proposal::ParameterChange;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.governance.v1.Proposal.ParameterChange")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<proposal::ParameterChange, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut old_parameters__ = None;
+                let mut new_parameters__ = None;
+                let mut preconditions__ = None;
+                let mut changes__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::OldParameters => {
+                            if old_parameters__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("oldParameters"));
+                            }
+                            old_parameters__ = map_.next_value()?;
+                        }
+                        GeneratedField::NewParameters => {
+                            if new_parameters__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("newParameters"));
+                            }
+                            new_parameters__ = map_.next_value()?;
+                        }
+                        GeneratedField::Preconditions => {
+                            if preconditions__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("preconditions"));
+                            }
+                            preconditions__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Changes => {
+                            if changes__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("changes"));
+                            }
+                            changes__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(proposal::ParameterChange {
+                    old_parameters: old_parameters__,
+                    new_parameters: new_parameters__,
+                    preconditions: preconditions__.unwrap_or_default(),
+                    changes: changes__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.governance.v1.Proposal.ParameterChange", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for proposal::RegisterInterchainAccount {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.connection_id.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.governance.v1.Proposal.RegisterInterchainAccount", len)?;
+        if !self.connection_id.is_empty() {
+            struct_ser.serialize_field("connectionId", &self.connection_id)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for proposal::RegisterInterchainAccount {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "connection_id",
+            "connectionId",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            ConnectionId,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -3825,10 +5152,7 @@ impl<'de> serde::Deserialize<'de> for proposal::ParameterChange {
                         E: serde::de::Error,
                     {
                         match value {
-                            "oldParameters" | "old_parameters" => Ok(GeneratedField::OldParameters),
-                            "newParameters" | "new_parameters" => Ok(GeneratedField::NewParameters),
-                            "preconditions" => Ok(GeneratedField::Preconditions),
-                            "changes" => Ok(GeneratedField::Changes),
+                            "connectionId" | "connection_id" => Ok(GeneratedField::ConnectionId),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -3838,60 +5162,36 @@ impl<'de> serde::Deserialize<'de> for proposal::ParameterChange {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = proposal::ParameterChange;
+            type Value = proposal::RegisterInterchainAccount;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.governance.v1.Proposal.ParameterChange")
+                formatter.write_str("struct penumbra.core.component.governance.v1.Proposal.RegisterInterchainAccount")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<proposal::ParameterChange, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<proposal::RegisterInterchainAccount, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut old_parameters__ = None;
-                let mut new_parameters__ = None;
-                let mut preconditions__ = None;
-                let mut changes__ = None;
+                let mut connection_id__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::OldParameters => {
-                            if old_parameters__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("oldParameters"));
-                            }
-                            old_parameters__ = map_.next_value()?;
-                        }
-                        GeneratedField::NewParameters => {
-                            if new_parameters__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("newParameters"));
-                            }
-                            new_parameters__ = map_.next_value()?;
-                        }
-                        GeneratedField::Preconditions => {
-                            if preconditions__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("preconditions"));
+                        GeneratedField::ConnectionId => {
+                            if connection_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("connectionId"));
                             }
-                            preconditions__ = Some(map_.next_value()?);
-                        }
-                        GeneratedField::Changes => {
-                            if changes__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("changes"));
-                            }
-                            changes__ = Some(map_.next_value()?);
+                            connection_id__ = Some(map_.next_value()?);
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(proposal::ParameterChange {
-                    old_parameters: old_parameters__,
-                    new_parameters: new_parameters__,
-                    preconditions: preconditions__.unwrap_or_default(),
-                    changes: changes__.unwrap_or_default(),
+                Ok(proposal::RegisterInterchainAccount {
+                    connection_id: connection_id__.unwrap_or_default(),
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.governance.v1.Proposal.ParameterChange", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.governance.v1.Proposal.RegisterInterchainAccount", FIELDS, GeneratedVisitor)
     }
 }
 impl serde::Serialize for proposal::Signaling {
@@ -4842,6 +6142,11 @@ impl serde::Serialize for ProposalKind {
             Self::UpgradePlan => "PROPOSAL_KIND_UPGRADE_PLAN",
             Self::FreezeIbcClient => "PROPOSAL_KIND_FREEZE_IBC_CLIENT",
             Self::UnfreezeIbcClient => "PROPOSAL_KIND_UNFREEZE_IBC_CLIENT",
+            Self::CommunityPoolVestingGrant => "PROPOSAL_KIND_COMMUNITY_POOL_VESTING_GRANT",
+            Self::CancelCommunityPoolVestingGrant => "PROPOSAL_KIND_CANCEL_COMMUNITY_POOL_VESTING_GRANT",
+            Self::Batch => "PROPOSAL_KIND_BATCH",
+            Self::RegisterInterchainAccount => "PROPOSAL_KIND_REGISTER_INTERCHAIN_ACCOUNT",
+            Self::InterchainAccountTx => "PROPOSAL_KIND_INTERCHAIN_ACCOUNT_TX",
         };
         serializer.serialize_str(variant)
     }
@@ -4861,6 +6166,11 @@ impl<'de> serde::Deserialize<'de> for ProposalKind {
             "PROPOSAL_KIND_UPGRADE_PLAN",
             "PROPOSAL_KIND_FREEZE_IBC_CLIENT",
             "PROPOSAL_KIND_UNFREEZE_IBC_CLIENT",
+            "PROPOSAL_KIND_COMMUNITY_POOL_VESTING_GRANT",
+            "PROPOSAL_KIND_CANCEL_COMMUNITY_POOL_VESTING_GRANT",
+            "PROPOSAL_KIND_BATCH",
+            "PROPOSAL_KIND_REGISTER_INTERCHAIN_ACCOUNT",
+            "PROPOSAL_KIND_INTERCHAIN_ACCOUNT_TX",
         ];
 
         struct GeneratedVisitor;
@@ -4909,6 +6219,11 @@ impl<'de> serde::Deserialize<'de> for ProposalKind {
                     "PROPOSAL_KIND_UPGRADE_PLAN" => Ok(ProposalKind::UpgradePlan),
                     "PROPOSAL_KIND_FREEZE_IBC_CLIENT" => Ok(ProposalKind::FreezeIbcClient),
                     "PROPOSAL_KIND_UNFREEZE_IBC_CLIENT" => Ok(ProposalKind::UnfreezeIbcClient),
+                    "PROPOSAL_KIND_COMMUNITY_POOL_VESTING_GRANT" => Ok(ProposalKind::CommunityPoolVestingGrant),
+                    "PROPOSAL_KIND_CANCEL_COMMUNITY_POOL_VESTING_GRANT" => Ok(ProposalKind::CancelCommunityPoolVestingGrant),
+                    "PROPOSAL_KIND_BATCH" => Ok(ProposalKind::Batch),
+                    "PROPOSAL_KIND_REGISTER_INTERCHAIN_ACCOUNT" => Ok(ProposalKind::RegisterInterchainAccount),
+                    "PROPOSAL_KIND_INTERCHAIN_ACCOUNT_TX" => Ok(ProposalKind::InterchainAccountTx),
                     _ => Err(serde::de::Error::unknown_variant(value, FIELDS)),
                 }
             }
@@ -6276,25 +7591,242 @@ impl serde::Serialize for proposal_state::Withdrawn {
         if !self.reason.is_empty() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.governance.v1.ProposalState.Withdrawn", len)?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.governance.v1.ProposalState.Withdrawn", len)?;
+        if !self.reason.is_empty() {
+            struct_ser.serialize_field("reason", &self.reason)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for proposal_state::Withdrawn {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "reason",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Reason,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "reason" => Ok(GeneratedField::Reason),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = proposal_state::Withdrawn;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.governance.v1.ProposalState.Withdrawn")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<proposal_state::Withdrawn, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut reason__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Reason => {
+                            if reason__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("reason"));
+                            }
+                            reason__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(proposal_state::Withdrawn {
+                    reason: reason__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.governance.v1.ProposalState.Withdrawn", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for ProposalSubmit {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.proposal.is_some() {
+            len += 1;
+        }
+        if self.deposit_amount.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.governance.v1.ProposalSubmit", len)?;
+        if let Some(v) = self.proposal.as_ref() {
+            struct_ser.serialize_field("proposal", v)?;
+        }
+        if let Some(v) = self.deposit_amount.as_ref() {
+            struct_ser.serialize_field("depositAmount", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for ProposalSubmit {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "proposal",
+            "deposit_amount",
+            "depositAmount",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Proposal,
+            DepositAmount,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "proposal" => Ok(GeneratedField::Proposal),
+                            "depositAmount" | "deposit_amount" => Ok(GeneratedField::DepositAmount),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = ProposalSubmit;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.governance.v1.ProposalSubmit")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ProposalSubmit, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut proposal__ = None;
+                let mut deposit_amount__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Proposal => {
+                            if proposal__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("proposal"));
+                            }
+                            proposal__ = map_.next_value()?;
+                        }
+                        GeneratedField::DepositAmount => {
+                            if deposit_amount__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("depositAmount"));
+                            }
+                            deposit_amount__ = map_.next_value()?;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(ProposalSubmit {
+                    proposal: proposal__,
+                    deposit_amount: deposit_amount__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.governance.v1.ProposalSubmit", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for ProposalWithdraw {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.proposal != 0 {
+            len += 1;
+        }
+        if !self.reason.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.governance.v1.ProposalWithdraw", len)?;
+        if self.proposal != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("proposal", ToString::to_string(&self.proposal).as_str())?;
+        }
         if !self.reason.is_empty() {
             struct_ser.serialize_field("reason", &self.reason)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for proposal_state::Withdrawn {
+impl<'de> serde::Deserialize<'de> for ProposalWithdraw {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
+            "proposal",
             "reason",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
+            Proposal,
             Reason,
             __SkipField__,
         }
@@ -6318,6 +7850,7 @@ impl<'de> serde::Deserialize<'de> for proposal_state::Withdrawn {
                         E: serde::de::Error,
                     {
                         match value {
+                            "proposal" => Ok(GeneratedField::Proposal),
                             "reason" => Ok(GeneratedField::Reason),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
@@ -6328,19 +7861,28 @@ impl<'de> serde::Deserialize<'de> for proposal_state::Withdrawn {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = proposal_state::Withdrawn;
+            type Value = ProposalWithdraw;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.governance.v1.ProposalState.Withdrawn")
+                formatter.write_str("struct penumbra.core.component.governance.v1.ProposalWithdraw")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<proposal_state::Withdrawn, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ProposalWithdraw, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
+                let mut proposal__ = None;
                 let mut reason__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
+                        GeneratedField::Proposal => {
+                            if proposal__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("proposal"));
+                            }
+                            proposal__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
                         GeneratedField::Reason => {
                             if reason__.is_some() {
                                 return Err(serde::de::Error::duplicate_field("reason"));
@@ -6352,15 +7894,16 @@ impl<'de> serde::Deserialize<'de> for proposal_state::Withdrawn {
                         }
                     }
                 }
-                Ok(proposal_state::Withdrawn {
+                Ok(ProposalWithdraw {
+                    proposal: proposal__.unwrap_or_default(),
                     reason: reason__.unwrap_or_default(),
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.governance.v1.ProposalState.Withdrawn", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.governance.v1.ProposalWithdraw", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for ProposalSubmit {
+impl serde::Serialize for ProxyVote {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -6368,38 +7911,38 @@ impl serde::Serialize for ProposalSubmit {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.proposal.is_some() {
+        if self.body.is_some() {
             len += 1;
         }
-        if self.deposit_amount.is_some() {
+        if self.auth_sig.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.governance.v1.ProposalSubmit", len)?;
-        if let Some(v) = self.proposal.as_ref() {
-            struct_ser.serialize_field("proposal", v)?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.governance.v1.ProxyVote", len)?;
+        if let Some(v) = self.body.as_ref() {
+            struct_ser.serialize_field("body", v)?;
         }
-        if let Some(v) = self.deposit_amount.as_ref() {
-            struct_ser.serialize_field("depositAmount", v)?;
+        if let Some(v) = self.auth_sig.as_ref() {
+            struct_ser.serialize_field("authSig", v)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for ProposalSubmit {
+impl<'de> serde::Deserialize<'de> for ProxyVote {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "proposal",
-            "deposit_amount",
-            "depositAmount",
+            "body",
+            "auth_sig",
+            "authSig",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            Proposal,
-            DepositAmount,
+            Body,
+            AuthSig,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -6422,8 +7965,8 @@ impl<'de> serde::Deserialize<'de> for ProposalSubmit {
                         E: serde::de::Error,
                     {
                         match value {
-                            "proposal" => Ok(GeneratedField::Proposal),
-                            "depositAmount" | "deposit_amount" => Ok(GeneratedField::DepositAmount),
+                            "body" => Ok(GeneratedField::Body),
+                            "authSig" | "auth_sig" => Ok(GeneratedField::AuthSig),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -6433,47 +7976,47 @@ impl<'de> serde::Deserialize<'de> for ProposalSubmit {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = ProposalSubmit;
+            type Value = ProxyVote;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.governance.v1.ProposalSubmit")
+                formatter.write_str("struct penumbra.core.component.governance.v1.ProxyVote")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ProposalSubmit, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ProxyVote, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut proposal__ = None;
-                let mut deposit_amount__ = None;
+                let mut body__ = None;
+                let mut auth_sig__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::Proposal => {
-                            if proposal__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("proposal"));
+                        GeneratedField::Body => {
+                            if body__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("body"));
                             }
-                            proposal__ = map_.next_value()?;
+                            body__ = map_.next_value()?;
                         }
-                        GeneratedField::DepositAmount => {
-                            if deposit_amount__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("depositAmount"));
+                        GeneratedField::AuthSig => {
+                            if auth_sig__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("authSig"));
                             }
-                            deposit_amount__ = map_.next_value()?;
+                            auth_sig__ = map_.next_value()?;
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(ProposalSubmit {
-                    proposal: proposal__,
-                    deposit_amount: deposit_amount__,
+                Ok(ProxyVote {
+                    body: body__,
+                    auth_sig: auth_sig__,
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.governance.v1.ProposalSubmit", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.governance.v1.ProxyVote", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for ProposalWithdraw {
+impl serde::Serialize for ProxyVoteBody {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -6484,21 +8027,27 @@ impl serde::Serialize for ProposalWithdraw {
         if self.proposal != 0 {
             len += 1;
         }
-        if !self.reason.is_empty() {
+        if self.vote.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.governance.v1.ProposalWithdraw", len)?;
+        if self.proxy.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.governance.v1.ProxyVoteBody", len)?;
         if self.proposal != 0 {
             #[allow(clippy::needless_borrow)]
             struct_ser.serialize_field("proposal", ToString::to_string(&self.proposal).as_str())?;
         }
-        if !self.reason.is_empty() {
-            struct_ser.serialize_field("reason", &self.reason)?;
+        if let Some(v) = self.vote.as_ref() {
+            struct_ser.serialize_field("vote", v)?;
+        }
+        if let Some(v) = self.proxy.as_ref() {
+            struct_ser.serialize_field("proxy", v)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for ProposalWithdraw {
+impl<'de> serde::Deserialize<'de> for ProxyVoteBody {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
@@ -6506,13 +8055,15 @@ impl<'de> serde::Deserialize<'de> for ProposalWithdraw {
     {
         const FIELDS: &[&str] = &[
             "proposal",
-            "reason",
+            "vote",
+            "proxy",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
             Proposal,
-            Reason,
+            Vote,
+            Proxy,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -6536,7 +8087,8 @@ impl<'de> serde::Deserialize<'de> for ProposalWithdraw {
                     {
                         match value {
                             "proposal" => Ok(GeneratedField::Proposal),
-                            "reason" => Ok(GeneratedField::Reason),
+                            "vote" => Ok(GeneratedField::Vote),
+                            "proxy" => Ok(GeneratedField::Proxy),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -6546,18 +8098,19 @@ impl<'de> serde::Deserialize<'de> for ProposalWithdraw {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = ProposalWithdraw;
+            type Value = ProxyVoteBody;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.governance.v1.ProposalWithdraw")
+                formatter.write_str("struct penumbra.core.component.governance.v1.ProxyVoteBody")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ProposalWithdraw, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ProxyVoteBody, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
                 let mut proposal__ = None;
-                let mut reason__ = None;
+                let mut vote__ = None;
+                let mut proxy__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
                         GeneratedField::Proposal => {
@@ -6568,24 +8121,31 @@ impl<'de> serde::Deserialize<'de> for ProposalWithdraw {
                                 Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
                             ;
                         }
-                        GeneratedField::Reason => {
-                            if reason__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("reason"));
+                        GeneratedField::Vote => {
+                            if vote__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("vote"));
                             }
-                            reason__ = Some(map_.next_value()?);
+                            vote__ = map_.next_value()?;
+                        }
+                        GeneratedField::Proxy => {
+                            if proxy__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("proxy"));
+                            }
+                            proxy__ = map_.next_value()?;
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(ProposalWithdraw {
+                Ok(ProxyVoteBody {
                     proposal: proposal__.unwrap_or_default(),
-                    reason: reason__.unwrap_or_default(),
+                    vote: vote__,
+                    proxy: proxy__,
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.governance.v1.ProposalWithdraw", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.governance.v1.ProxyVoteBody", FIELDS, GeneratedVisitor)
     }
 }
 impl serde::Serialize for Ratio {
@@ -6706,6 +8266,156 @@ impl<'de> serde::Deserialize<'de> for Ratio {
         deserializer.deserialize_struct("penumbra.core.component.governance.v1.Ratio", FIELDS, GeneratedVisitor)
     }
 }
+impl serde::Serialize for StandingProxyDelegation {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.proxy.is_some() {
+            len += 1;
+        }
+        if self.identity_key.is_some() {
+            len += 1;
+        }
+        if self.value.is_some() {
+            len += 1;
+        }
+        if self.height != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.governance.v1.StandingProxyDelegation", len)?;
+        if let Some(v) = self.proxy.as_ref() {
+            struct_ser.serialize_field("proxy", v)?;
+        }
+        if let Some(v) = self.identity_key.as_ref() {
+            struct_ser.serialize_field("identityKey", v)?;
+        }
+        if let Some(v) = self.value.as_ref() {
+            struct_ser.serialize_field("value", v)?;
+        }
+        if self.height != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("height", ToString::to_string(&self.height).as_str())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for StandingProxyDelegation {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "proxy",
+            "identity_key",
+            "identityKey",
+            "value",
+            "height",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Proxy,
+            IdentityKey,
+            Value,
+            Height,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "proxy" => Ok(GeneratedField::Proxy),
+                            "identityKey" | "identity_key" => Ok(GeneratedField::IdentityKey),
+                            "value" => Ok(GeneratedField::Value),
+                            "height" => Ok(GeneratedField::Height),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = StandingProxyDelegation;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.governance.v1.StandingProxyDelegation")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<StandingProxyDelegation, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut proxy__ = None;
+                let mut identity_key__ = None;
+                let mut value__ = None;
+                let mut height__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Proxy => {
+                            if proxy__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("proxy"));
+                            }
+                            proxy__ = map_.next_value()?;
+                        }
+                        GeneratedField::IdentityKey => {
+                            if identity_key__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("identityKey"));
+                            }
+                            identity_key__ = map_.next_value()?;
+                        }
+                        GeneratedField::Value => {
+                            if value__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("value"));
+                            }
+                            value__ = map_.next_value()?;
+                        }
+                        GeneratedField::Height => {
+                            if height__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("height"));
+                            }
+                            height__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(StandingProxyDelegation {
+                    proxy: proxy__,
+                    identity_key: identity_key__,
+                    value: value__,
+                    height: height__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.governance.v1.StandingProxyDelegation", FIELDS, GeneratedVisitor)
+    }
+}
 impl serde::Serialize for Tally {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
@@ -188,6 +188,9 @@ pub struct IbcParameters {
     /// Whether outbound ICS-20 transfers are enabled
     #[prost(bool, tag = "3")]
     pub outbound_ics20_transfers_enabled: bool,
+    /// Rate limits on ICS-20 transfers of particular assets over particular channels.
+    #[prost(message, repeated, tag = "4")]
+    pub ics20_rate_limits: ::prost::alloc::vec::Vec<Ics20RateLimit>,
 }
 impl ::prost::Name for IbcParameters {
     const NAME: &'static str = "IbcParameters";
@@ -196,6 +199,182 @@ impl ::prost::Name for IbcParameters {
         ::prost::alloc::format!("penumbra.core.component.ibc.v1.{}", Self::NAME)
     }
 }
+/// A limit on the net flow of one asset over one channel, within a window of blocks.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Ics20RateLimit {
+    /// The channel (on the Penumbra side) the limit applies to.
+    #[prost(string, tag = "1")]
+    pub channel_id: ::prost::alloc::string::String,
+    /// The asset the limit applies to.
+    #[prost(message, optional, tag = "2")]
+    pub asset_id: ::core::option::Option<super::super::super::asset::v1::AssetId>,
+    /// The maximum net amount that may be transferred in over the channel within a window.
+    #[prost(message, optional, tag = "3")]
+    pub max_inflow: ::core::option::Option<super::super::super::num::v1::Amount>,
+    /// The maximum net amount that may be transferred out over the channel within a window.
+    #[prost(message, optional, tag = "4")]
+    pub max_outflow: ::core::option::Option<super::super::super::num::v1::Amount>,
+    /// The length of a window, in blocks.
+    #[prost(uint64, tag = "5")]
+    pub window_blocks: u64,
+}
+impl ::prost::Name for Ics20RateLimit {
+    const NAME: &'static str = "Ics20RateLimit";
+    const PACKAGE: &'static str = "penumbra.core.component.ibc.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.component.ibc.v1.{}", Self::NAME)
+    }
+}
+/// The flow of one asset over one channel within the current rate limit window.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Ics20RateLimitUsage {
+    /// The height at which the current window started.
+    #[prost(uint64, tag = "1")]
+    pub window_start_height: u64,
+    /// The amount transferred in over the channel since the window started.
+    #[prost(message, optional, tag = "2")]
+    pub inflow: ::core::option::Option<super::super::super::num::v1::Amount>,
+    /// The amount transferred out over the channel since the window started.
+    #[prost(message, optional, tag = "3")]
+    pub outflow: ::core::option::Option<super::super::super::num::v1::Amount>,
+}
+impl ::prost::Name for Ics20RateLimitUsage {
+    const NAME: &'static str = "Ics20RateLimitUsage";
+    const PACKAGE: &'static str = "penumbra.core.component.ibc.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.component.ibc.v1.{}", Self::NAME)
+    }
+}
+/// An interchain account on a counterparty chain, controlled by the Community Pool over ICS-27.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct InterchainAccount {
+    /// The connection (on the Penumbra side) to the chain hosting the account.
+    #[prost(string, tag = "1")]
+    pub connection_id: ::prost::alloc::string::String,
+    /// The controller port of the account's channel.
+    #[prost(string, tag = "2")]
+    pub port_id: ::prost::alloc::string::String,
+    /// The account's channel (on the Penumbra side).
+    #[prost(string, tag = "3")]
+    pub channel_id: ::prost::alloc::string::String,
+    /// The address of the account on the host chain, or empty if the channel handshake
+    /// registering it has yet to complete.
+    #[prost(string, tag = "4")]
+    pub address: ::prost::alloc::string::String,
+}
+impl ::prost::Name for InterchainAccount {
+    const NAME: &'static str = "InterchainAccount";
+    const PACKAGE: &'static str = "penumbra.core.component.ibc.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.component.ibc.v1.{}", Self::NAME)
+    }
+}
+/// A message to be executed by an interchain account on its host chain.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct InterchainAccountMessage {
+    /// The type URL of the host chain's message, e.g. `/cosmos.staking.v1beta1.MsgDelegate`.
+    #[prost(string, tag = "1")]
+    pub type_url: ::prost::alloc::string::String,
+    /// The protobuf encoding of the message.
+    #[prost(bytes = "vec", tag = "2")]
+    pub value: ::prost::alloc::vec::Vec<u8>,
+}
+impl ::prost::Name for InterchainAccountMessage {
+    const NAME: &'static str = "InterchainAccountMessage";
+    const PACKAGE: &'static str = "penumbra.core.component.ibc.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.component.ibc.v1.{}", Self::NAME)
+    }
+}
+/// A transaction sent to an interchain account, and its outcome on the host chain.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct InterchainAccountTx {
+    /// The ID of the governance proposal that sent the transaction.
+    #[prost(uint64, tag = "1")]
+    pub proposal_id: u64,
+    /// The connection to the chain hosting the account.
+    #[prost(string, tag = "2")]
+    pub connection_id: ::prost::alloc::string::String,
+    /// The channel the transaction was sent over.
+    #[prost(string, tag = "3")]
+    pub channel_id: ::prost::alloc::string::String,
+    /// The sequence number of the packet carrying the transaction.
+    #[prost(uint64, tag = "4")]
+    pub sequence: u64,
+    #[prost(enumeration = "interchain_account_tx::Status", tag = "5")]
+    pub status: i32,
+    /// The result returned by the host chain, if the transaction succeeded.
+    #[prost(bytes = "vec", tag = "6")]
+    pub result: ::prost::alloc::vec::Vec<u8>,
+    /// The error returned by the host chain, if the transaction failed.
+    #[prost(string, tag = "7")]
+    pub error: ::prost::alloc::string::String,
+}
+/// Nested message and enum types in `InterchainAccountTx`.
+pub mod interchain_account_tx {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Status {
+        Unspecified = 0,
+        /// The transaction has yet to be acknowledged by the host chain.
+        Pending = 1,
+        /// The host chain executed the transaction.
+        Succeeded = 2,
+        /// The host chain failed to execute the transaction.
+        Failed = 3,
+        /// The transaction timed out before the host chain received it, closing the account's
+        /// channel.
+        TimedOut = 4,
+    }
+    impl Status {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Status::Unspecified => "STATUS_UNSPECIFIED",
+                Status::Pending => "STATUS_PENDING",
+                Status::Succeeded => "STATUS_SUCCEEDED",
+                Status::Failed => "STATUS_FAILED",
+                Status::TimedOut => "STATUS_TIMED_OUT",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "STATUS_UNSPECIFIED" => Some(Self::Unspecified),
+                "STATUS_PENDING" => Some(Self::Pending),
+                "STATUS_SUCCEEDED" => Some(Self::Succeeded),
+                "STATUS_FAILED" => Some(Self::Failed),
+                "STATUS_TIMED_OUT" => Some(Self::TimedOut),
+                _ => None,
+            }
+        }
+    }
+}
+impl ::prost::Name for InterchainAccountTx {
+    const NAME: &'static str = "InterchainAccountTx";
+    const PACKAGE: &'static str = "penumbra.core.component.ibc.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.component.ibc.v1.{}", Self::NAME)
+    }
+}
 /// IBC genesis state.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -211,3 +390,445 @@ impl ::prost::Name for GenesisContent {
         ::prost::alloc::format!("penumbra.core.component.ibc.v1.{}", Self::NAME)
     }
 }
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct InterchainAccountsRequest {}
+impl ::prost::Name for InterchainAccountsRequest {
+    const NAME: &'static str = "InterchainAccountsRequest";
+    const PACKAGE: &'static str = "penumbra.core.component.ibc.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.component.ibc.v1.{}", Self::NAME)
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct InterchainAccountsResponse {
+    #[prost(message, repeated, tag = "1")]
+    pub accounts: ::prost::alloc::vec::Vec<InterchainAccount>,
+}
+impl ::prost::Name for InterchainAccountsResponse {
+    const NAME: &'static str = "InterchainAccountsResponse";
+    const PACKAGE: &'static str = "penumbra.core.component.ibc.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.component.ibc.v1.{}", Self::NAME)
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct InterchainAccountTxsRequest {
+    /// The connection to the chain hosting the account.
+    #[prost(string, tag = "1")]
+    pub connection_id: ::prost::alloc::string::String,
+}
+impl ::prost::Name for InterchainAccountTxsRequest {
+    const NAME: &'static str = "InterchainAccountTxsRequest";
+    const PACKAGE: &'static str = "penumbra.core.component.ibc.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.component.ibc.v1.{}", Self::NAME)
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct InterchainAccountTxsResponse {
+    /// The transactions sent to the account, in the order they were sent.
+    #[prost(message, repeated, tag = "1")]
+    pub txs: ::prost::alloc::vec::Vec<InterchainAccountTx>,
+}
+impl ::prost::Name for InterchainAccountTxsResponse {
+    const NAME: &'static str = "InterchainAccountTxsResponse";
+    const PACKAGE: &'static str = "penumbra.core.component.ibc.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.component.ibc.v1.{}", Self::NAME)
+    }
+}
+/// Generated client implementations.
+#[cfg(feature = "rpc")]
+pub mod query_service_client {
+    #![allow(unused_variables, dead_code, missing_docs, clippy::let_unit_value)]
+    use tonic::codegen::*;
+    use tonic::codegen::http::Uri;
+    /// Query operations for Penumbra's IBC applications, other than the standard IBC queries.
+    #[derive(Debug, Clone)]
+    pub struct QueryServiceClient<T> {
+        inner: tonic::client::Grpc<T>,
+    }
+    impl QueryServiceClient<tonic::transport::Channel> {
+        /// Attempt to create a new client by connecting to a given endpoint.
+        pub async fn connect<D>(dst: D) -> Result<Self, tonic::transport::Error>
+        where
+            D: TryInto<tonic::transport::Endpoint>,
+            D::Error: Into<StdError>,
+        {
+            let conn = tonic::transport::Endpoint::new(dst)?.connect().await?;
+            Ok(Self::new(conn))
+        }
+    }
+    impl<T> QueryServiceClient<T>
+    where
+        T: tonic::client::GrpcService<tonic::body::BoxBody>,
+        T::Error: Into<StdError>,
+        T::ResponseBody: Body<Data = Bytes> + Send + 'static,
+        <T::ResponseBody as Body>::Error: Into<StdError> + Send,
+    {
+        pub fn new(inner: T) -> Self {
+            let inner = tonic::client::Grpc::new(inner);
+            Self { inner }
+        }
+        pub fn with_origin(inner: T, origin: Uri) -> Self {
+            let inner = tonic::client::Grpc::with_origin(inner, origin);
+            Self { inner }
+        }
+        pub fn with_interceptor<F>(
+            inner: T,
+            interceptor: F,
+        ) -> QueryServiceClient<InterceptedService<T, F>>
+        where
+            F: tonic::service::Interceptor,
+            T::ResponseBody: Default,
+            T: tonic::codegen::Service<
+                http::Request<tonic::body::BoxBody>,
+                Response = http::Response<
+                    <T as tonic::client::GrpcService<tonic::body::BoxBody>>::ResponseBody,
+                >,
+            >,
+            <T as tonic::codegen::Service<
+                http::Request<tonic::body::BoxBody>,
+            >>::Error: Into<StdError> + Send + Sync,
+        {
+            QueryServiceClient::new(InterceptedService::new(inner, interceptor))
+        }
+        /// Compress requests with the given encoding.
+        ///
+        /// This requires the server to support it otherwise it might respond with an
+        /// error.
+        #[must_use]
+        pub fn send_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.inner = self.inner.send_compressed(encoding);
+            self
+        }
+        /// Enable decompressing responses.
+        #[must_use]
+        pub fn accept_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.inner = self.inner.accept_compressed(encoding);
+            self
+        }
+        /// Limits the maximum size of a decoded message.
+        ///
+        /// Default: `4MB`
+        #[must_use]
+        pub fn max_decoding_message_size(mut self, limit: usize) -> Self {
+            self.inner = self.inner.max_decoding_message_size(limit);
+            self
+        }
+        /// Limits the maximum size of an encoded message.
+        ///
+        /// Default: `usize::MAX`
+        #[must_use]
+        pub fn max_encoding_message_size(mut self, limit: usize) -> Self {
+            self.inner = self.inner.max_encoding_message_size(limit);
+            self
+        }
+        /// Lists the Community Pool's interchain accounts.
+        pub async fn interchain_accounts(
+            &mut self,
+            request: impl tonic::IntoRequest<super::InterchainAccountsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::InterchainAccountsResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/penumbra.core.component.ibc.v1.QueryService/InterchainAccounts",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "penumbra.core.component.ibc.v1.QueryService",
+                        "InterchainAccounts",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// Lists the transactions sent to an interchain account, and their outcomes.
+        pub async fn interchain_account_txs(
+            &mut self,
+            request: impl tonic::IntoRequest<super::InterchainAccountTxsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::InterchainAccountTxsResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/penumbra.core.component.ibc.v1.QueryService/InterchainAccountTxs",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "penumbra.core.component.ibc.v1.QueryService",
+                        "InterchainAccountTxs",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+    }
+}
+/// Generated server implementations.
+#[cfg(feature = "rpc")]
+pub mod query_service_server {
+    #![allow(unused_variables, dead_code, missing_docs, clippy::let_unit_value)]
+    use tonic::codegen::*;
+    /// Generated trait containing gRPC methods that should be implemented for use with QueryServiceServer.
+    #[async_trait]
+    pub trait QueryService: Send + Sync + 'static {
+        /// Lists the Community Pool's interchain accounts.
+        async fn interchain_accounts(
+            &self,
+            request: tonic::Request<super::InterchainAccountsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::InterchainAccountsResponse>,
+            tonic::Status,
+        >;
+        /// Lists the transactions sent to an interchain account, and their outcomes.
+        async fn interchain_account_txs(
+            &self,
+            request: tonic::Request<super::InterchainAccountTxsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::InterchainAccountTxsResponse>,
+            tonic::Status,
+        >;
+    }
+    /// Query operations for Penumbra's IBC applications, other than the standard IBC queries.
+    #[derive(Debug)]
+    pub struct QueryServiceServer<T: QueryService> {
+        inner: _Inner<T>,
+        accept_compression_encodings: EnabledCompressionEncodings,
+        send_compression_encodings: EnabledCompressionEncodings,
+        max_decoding_message_size: Option<usize>,
+        max_encoding_message_size: Option<usize>,
+    }
+    struct _Inner<T>(Arc<T>);
+    impl<T: QueryService> QueryServiceServer<T> {
+        pub fn new(inner: T) -> Self {
+            Self::from_arc(Arc::new(inner))
+        }
+        pub fn from_arc(inner: Arc<T>) -> Self {
+            let inner = _Inner(inner);
+            Self {
+                inner,
+                accept_compression_encodings: Default::default(),
+                send_compression_encodings: Default::default(),
+                max_decoding_message_size: None,
+                max_encoding_message_size: None,
+            }
+        }
+        pub fn with_interceptor<F>(
+            inner: T,
+            interceptor: F,
+        ) -> InterceptedService<Self, F>
+        where
+            F: tonic::service::Interceptor,
+        {
+            InterceptedService::new(Self::new(inner), interceptor)
+        }
+        /// Enable decompressing requests with the given encoding.
+        #[must_use]
+        pub fn accept_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.accept_compression_encodings.enable(encoding);
+            self
+        }
+        /// Compress responses with the given encoding, if the client supports it.
+        #[must_use]
+        pub fn send_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.send_compression_encodings.enable(encoding);
+            self
+        }
+        /// Limits the maximum size of a decoded message.
+        ///
+        /// Default: `4MB`
+        #[must_use]
+        pub fn max_decoding_message_size(mut self, limit: usize) -> Self {
+            self.max_decoding_message_size = Some(limit);
+            self
+        }
+        /// Limits the maximum size of an encoded message.
+        ///
+        /// Default: `usize::MAX`
+        #[must_use]
+        pub fn max_encoding_message_size(mut self, limit: usize) -> Self {
+            self.max_encoding_message_size = Some(limit);
+            self
+        }
+    }
+    impl<T, B> tonic::codegen::Service<http::Request<B>> for QueryServiceServer<T>
+    where
+        T: QueryService,
+        B: Body + Send + 'static,
+        B::Error: Into<StdError> + Send + 'static,
+    {
+        type Response = http::Response<tonic::body::BoxBody>;
+        type Error = std::convert::Infallible;
+        type Future = BoxFuture<Self::Response, Self::Error>;
+        fn poll_ready(
+            &mut self,
+            _cx: &mut Context<'_>,
+        ) -> Poll<std::result::Result<(), Self::Error>> {
+            Poll::Ready(Ok(()))
+        }
+        fn call(&mut self, req: http::Request<B>) -> Self::Future {
+            let inner = self.inner.clone();
+            match req.uri().path() {
+                "/penumbra.core.component.ibc.v1.QueryService/InterchainAccounts" => {
+                    #[allow(non_camel_case_types)]
+                    struct InterchainAccountsSvc<T: QueryService>(pub Arc<T>);
+                    impl<
+                        T: QueryService,
+                    > tonic::server::UnaryService<super::InterchainAccountsRequest>
+                    for InterchainAccountsSvc<T> {
+                        type Response = super::InterchainAccountsResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::InterchainAccountsRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as QueryService>::interchain_accounts(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = InterchainAccountsSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/penumbra.core.component.ibc.v1.QueryService/InterchainAccountTxs" => {
+                    #[allow(non_camel_case_types)]
+                    struct InterchainAccountTxsSvc<T: QueryService>(pub Arc<T>);
+                    impl<
+                        T: QueryService,
+                    > tonic::server::UnaryService<super::InterchainAccountTxsRequest>
+                    for InterchainAccountTxsSvc<T> {
+                        type Response = super::InterchainAccountTxsResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::InterchainAccountTxsRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as QueryService>::interchain_account_txs(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = InterchainAccountTxsSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                _ => {
+                    Box::pin(async move {
+                        Ok(
+                            http::Response::builder()
+                                .status(200)
+                                .header("grpc-status", "12")
+                                .header("content-type", "application/grpc")
+                                .body(empty_body())
+                                .unwrap(),
+                        )
+                    })
+                }
+            }
+        }
+    }
+    impl<T: QueryService> Clone for QueryServiceServer<T> {
+        fn clone(&self) -> Self {
+            let inner = self.inner.clone();
+            Self {
+                inner,
+                accept_compression_encodings: self.accept_compression_encodings,
+                send_compression_encodings: self.send_compression_encodings,
+                max_decoding_message_size: self.max_decoding_message_size,
+                max_encoding_message_size: self.max_encoding_message_size,
+            }
+        }
+    }
+    impl<T: QueryService> Clone for _Inner<T> {
+        fn clone(&self) -> Self {
+            Self(Arc::clone(&self.0))
+        }
+    }
+    impl<T: std::fmt::Debug> std::fmt::Debug for _Inner<T> {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            write!(f, "{:?}", self.0)
+        }
+    }
+    impl<T: QueryService> tonic::server::NamedService for QueryServiceServer<T> {
+        const NAME: &'static str = "penumbra.core.component.ibc.v1.QueryService";
+    }
+}
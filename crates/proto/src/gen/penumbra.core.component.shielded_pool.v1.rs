@@ -666,6 +666,60 @@ impl ::prost::Name for OutputPlan {
         )
     }
 }
+/// Requests the ICS-20 rate limits in effect.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Ics20RateLimitsRequest {
+    /// If set, only return limits on this channel.
+    #[prost(string, tag = "1")]
+    pub channel_id: ::prost::alloc::string::String,
+    /// If set, only return limits on this asset.
+    #[prost(message, optional, tag = "2")]
+    pub asset_id: ::core::option::Option<super::super::super::asset::v1::AssetId>,
+}
+impl ::prost::Name for Ics20RateLimitsRequest {
+    const NAME: &'static str = "Ics20RateLimitsRequest";
+    const PACKAGE: &'static str = "penumbra.core.component.shielded_pool.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!(
+            "penumbra.core.component.shielded_pool.v1.{}", Self::NAME
+        )
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Ics20RateLimitsResponse {
+    #[prost(message, repeated, tag = "1")]
+    pub limits: ::prost::alloc::vec::Vec<Ics20RateLimitStatus>,
+}
+impl ::prost::Name for Ics20RateLimitsResponse {
+    const NAME: &'static str = "Ics20RateLimitsResponse";
+    const PACKAGE: &'static str = "penumbra.core.component.shielded_pool.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!(
+            "penumbra.core.component.shielded_pool.v1.{}", Self::NAME
+        )
+    }
+}
+/// An ICS-20 rate limit, along with its usage in the current window.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Ics20RateLimitStatus {
+    #[prost(message, optional, tag = "1")]
+    pub limit: ::core::option::Option<super::super::ibc::v1::Ics20RateLimit>,
+    /// The usage in the current window. Zero if nothing has been transferred in the window.
+    #[prost(message, optional, tag = "2")]
+    pub usage: ::core::option::Option<super::super::ibc::v1::Ics20RateLimitUsage>,
+}
+impl ::prost::Name for Ics20RateLimitStatus {
+    const NAME: &'static str = "Ics20RateLimitStatus";
+    const PACKAGE: &'static str = "penumbra.core.component.shielded_pool.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!(
+            "penumbra.core.component.shielded_pool.v1.{}", Self::NAME
+        )
+    }
+}
 /// Requests information on an asset by asset id
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -1033,6 +1087,37 @@ pub mod query_service_client {
                 );
             self.inner.server_streaming(req, path, codec).await
         }
+        /// Requests the ICS-20 rate limits in effect, along with their usage in the current window.
+        pub async fn ics20_rate_limits(
+            &mut self,
+            request: impl tonic::IntoRequest<super::Ics20RateLimitsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::Ics20RateLimitsResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/penumbra.core.component.shielded_pool.v1.QueryService/Ics20RateLimits",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "penumbra.core.component.shielded_pool.v1.QueryService",
+                        "Ics20RateLimits",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
     }
 }
 /// Generated server implementations.
@@ -1071,6 +1156,14 @@ pub mod query_service_server {
             tonic::Response<Self::AssetMetadataByIdsStream>,
             tonic::Status,
         >;
+        /// Requests the ICS-20 rate limits in effect, along with their usage in the current window.
+        async fn ics20_rate_limits(
+            &self,
+            request: tonic::Request<super::Ics20RateLimitsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::Ics20RateLimitsResponse>,
+            tonic::Status,
+        >;
     }
     /// Query operations for the shielded pool component.
     #[derive(Debug)]
@@ -1248,6 +1341,53 @@ pub mod query_service_server {
                     };
                     Box::pin(fut)
                 }
+                "/penumbra.core.component.shielded_pool.v1.QueryService/Ics20RateLimits" => {
+                    #[allow(non_camel_case_types)]
+                    struct Ics20RateLimitsSvc<T: QueryService>(pub Arc<T>);
+                    impl<
+                        T: QueryService,
+                    > tonic::server::UnaryService<super::Ics20RateLimitsRequest>
+                    for Ics20RateLimitsSvc<T> {
+                        type Response = super::Ics20RateLimitsResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::Ics20RateLimitsRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as QueryService>::ics20_rate_limits(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = Ics20RateLimitsSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
                 _ => {
                     Box::pin(async move {
                         Ok(
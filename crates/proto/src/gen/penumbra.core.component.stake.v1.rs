@@ -438,6 +438,53 @@ impl ::prost::Name for Undelegate {
         ::prost::alloc::format!("penumbra.core.component.stake.v1.{}", Self::NAME)
     }
 }
+/// A transaction action moving stake from one validator's delegation pool to
+/// another's, converting the source validator's delegation tokens into the
+/// destination validator's delegation tokens at the current epoch's rates.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Redelegate {
+    /// The identity key of the validator to move stake away from.
+    #[prost(message, optional, tag = "1")]
+    pub source_validator: ::core::option::Option<
+        super::super::super::keys::v1::IdentityKey,
+    >,
+    /// The identity key of the validator to move stake to.
+    #[prost(message, optional, tag = "2")]
+    pub destination_validator: ::core::option::Option<
+        super::super::super::keys::v1::IdentityKey,
+    >,
+    /// The index of the epoch in which this redelegation was performed.
+    /// The redelegation takes effect in the next epoch.
+    #[prost(uint64, tag = "3")]
+    pub epoch_index: u64,
+    /// The amount of the source validator's delegation tokens consumed by this action.
+    #[prost(message, optional, tag = "4")]
+    pub source_delegation_amount: ::core::option::Option<
+        super::super::super::num::v1::Amount,
+    >,
+    /// The amount of unbonded stake represented by the source delegation tokens.
+    ///
+    /// This is implied by the source validator's exchange rate in the specified epoch.
+    #[prost(message, optional, tag = "5")]
+    pub unbonded_amount: ::core::option::Option<super::super::super::num::v1::Amount>,
+    /// The amount of the destination validator's delegation tokens produced by this action.
+    ///
+    /// This is implied by the destination validator's exchange rate in the specified epoch
+    /// (and should be checked in transaction validation!), but including it allows
+    /// stateless verification that the transaction is internally consistent.
+    #[prost(message, optional, tag = "6")]
+    pub destination_delegation_amount: ::core::option::Option<
+        super::super::super::num::v1::Amount,
+    >,
+}
+impl ::prost::Name for Redelegate {
+    const NAME: &'static str = "Redelegate";
+    const PACKAGE: &'static str = "penumbra.core.component.stake.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.component.stake.v1.{}", Self::NAME)
+    }
+}
 /// A transaction action finishing an undelegation, converting (slashable)
 /// "unbonding tokens" to (unslashable) staking tokens.
 #[allow(clippy::derive_partial_eq_without_eq)]
@@ -733,6 +780,66 @@ impl ::prost::Name for CurrentValidatorRateResponse {
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ValidatorRateHistoryRequest {
+    #[prost(message, optional, tag = "1")]
+    pub identity_key: ::core::option::Option<super::super::super::keys::v1::IdentityKey>,
+    /// The index of the first epoch to return the rate data of.
+    #[prost(uint64, tag = "2")]
+    pub start_epoch_index: u64,
+    /// The maximum number of epochs to return the rate data of, or 0 for no limit.
+    #[prost(uint64, tag = "3")]
+    pub limit: u64,
+}
+impl ::prost::Name for ValidatorRateHistoryRequest {
+    const NAME: &'static str = "ValidatorRateHistoryRequest";
+    const PACKAGE: &'static str = "penumbra.core.component.stake.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.component.stake.v1.{}", Self::NAME)
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ValidatorRateHistoryResponse {
+    /// The rate data of the validator in each epoch from the start epoch, in order.
+    ///
+    /// Rate data is recorded at each epoch boundary, so the epoch in which the validator
+    /// was defined is omitted.
+    #[prost(message, repeated, tag = "1")]
+    pub history: ::prost::alloc::vec::Vec<
+        validator_rate_history_response::EpochRateData,
+    >,
+}
+/// Nested message and enum types in `ValidatorRateHistoryResponse`.
+pub mod validator_rate_history_response {
+    /// The rate data of the validator in an epoch.
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct EpochRateData {
+        #[prost(uint64, tag = "1")]
+        pub epoch_index: u64,
+        #[prost(message, optional, tag = "2")]
+        pub rate_data: ::core::option::Option<super::RateData>,
+    }
+    impl ::prost::Name for EpochRateData {
+        const NAME: &'static str = "EpochRateData";
+        const PACKAGE: &'static str = "penumbra.core.component.stake.v1";
+        fn full_name() -> ::prost::alloc::string::String {
+            ::prost::alloc::format!(
+                "penumbra.core.component.stake.v1.ValidatorRateHistoryResponse.{}",
+                Self::NAME
+            )
+        }
+    }
+}
+impl ::prost::Name for ValidatorRateHistoryResponse {
+    const NAME: &'static str = "ValidatorRateHistoryResponse";
+    const PACKAGE: &'static str = "penumbra.core.component.stake.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.component.stake.v1.{}", Self::NAME)
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ValidatorUptimeRequest {
     #[prost(message, optional, tag = "2")]
     pub identity_key: ::core::option::Option<super::super::super::keys::v1::IdentityKey>,
@@ -757,6 +864,57 @@ impl ::prost::Name for ValidatorUptimeResponse {
         ::prost::alloc::format!("penumbra.core.component.stake.v1.{}", Self::NAME)
     }
 }
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ValidatorCommissionScheduleRequest {
+    #[prost(message, optional, tag = "2")]
+    pub identity_key: ::core::option::Option<super::super::super::keys::v1::IdentityKey>,
+}
+impl ::prost::Name for ValidatorCommissionScheduleRequest {
+    const NAME: &'static str = "ValidatorCommissionScheduleRequest";
+    const PACKAGE: &'static str = "penumbra.core.component.stake.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.component.stake.v1.{}", Self::NAME)
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ValidatorCommissionScheduleResponse {
+    /// The pending increase of the validator's commission, absent if there is none.
+    #[prost(message, optional, tag = "1")]
+    pub pending: ::core::option::Option<PendingFundingStreams>,
+}
+impl ::prost::Name for ValidatorCommissionScheduleResponse {
+    const NAME: &'static str = "ValidatorCommissionScheduleResponse";
+    const PACKAGE: &'static str = "penumbra.core.component.stake.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.component.stake.v1.{}", Self::NAME)
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CurrentConsensusKeysRequest {}
+impl ::prost::Name for CurrentConsensusKeysRequest {
+    const NAME: &'static str = "CurrentConsensusKeysRequest";
+    const PACKAGE: &'static str = "penumbra.core.component.stake.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.component.stake.v1.{}", Self::NAME)
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CurrentConsensusKeysResponse {
+    /// The consensus keys of the validator set most recently reported to CometBFT.
+    #[prost(message, optional, tag = "1")]
+    pub current_consensus_keys: ::core::option::Option<CurrentConsensusKeys>,
+}
+impl ::prost::Name for CurrentConsensusKeysResponse {
+    const NAME: &'static str = "CurrentConsensusKeysResponse";
+    const PACKAGE: &'static str = "penumbra.core.component.stake.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.component.stake.v1.{}", Self::NAME)
+    }
+}
 /// Staking configuration data.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -792,6 +950,14 @@ pub struct StakeParameters {
     /// The number of blocks that must elapse before an unbonding note can be claimed.
     #[prost(uint64, tag = "9")]
     pub unbonding_delay: u64,
+    /// The maximum increase of a validator's total commission, in basis points, that
+    /// can take effect in a single epoch. Zero means that increases are not limited.
+    #[prost(uint64, tag = "10")]
+    pub max_commission_increase_bps: u64,
+    /// The number of epochs that must elapse before an increase of a validator's
+    /// commission takes effect.
+    #[prost(uint64, tag = "11")]
+    pub commission_increase_notice_epochs: u64,
 }
 impl ::prost::Name for StakeParameters {
     const NAME: &'static str = "StakeParameters";
@@ -800,6 +966,24 @@ impl ::prost::Name for StakeParameters {
         ::prost::alloc::format!("penumbra.core.component.stake.v1.{}", Self::NAME)
     }
 }
+/// An increase of a validator's commission that has been announced, but has not yet taken effect.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PendingFundingStreams {
+    /// The funding streams that will replace the validator's current funding streams.
+    #[prost(message, repeated, tag = "1")]
+    pub funding_streams: ::prost::alloc::vec::Vec<FundingStream>,
+    /// The index of the epoch at the start of which the new funding streams take effect.
+    #[prost(uint64, tag = "2")]
+    pub effective_epoch_index: u64,
+}
+impl ::prost::Name for PendingFundingStreams {
+    const NAME: &'static str = "PendingFundingStreams";
+    const PACKAGE: &'static str = "penumbra.core.component.stake.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.component.stake.v1.{}", Self::NAME)
+    }
+}
 /// Genesis data for the staking component.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -974,6 +1158,30 @@ impl ::prost::Name for EventUndelegate {
         ::prost::alloc::format!("penumbra.core.component.stake.v1.{}", Self::NAME)
     }
 }
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct EventRedelegate {
+    /// The identity key of the validator the stake was moved away from.
+    #[prost(message, optional, tag = "1")]
+    pub source_identity_key: ::core::option::Option<
+        super::super::super::keys::v1::IdentityKey,
+    >,
+    /// The identity key of the validator the stake was moved to.
+    #[prost(message, optional, tag = "2")]
+    pub destination_identity_key: ::core::option::Option<
+        super::super::super::keys::v1::IdentityKey,
+    >,
+    /// The amount of stake redelegated, in the staking token.
+    #[prost(message, optional, tag = "3")]
+    pub amount: ::core::option::Option<super::super::super::num::v1::Amount>,
+}
+impl ::prost::Name for EventRedelegate {
+    const NAME: &'static str = "EventRedelegate";
+    const PACKAGE: &'static str = "penumbra.core.component.stake.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.component.stake.v1.{}", Self::NAME)
+    }
+}
 /// Indicates a slashing penalty was applied to a validator's reward rates.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -1234,6 +1442,37 @@ pub mod query_service_client {
                 );
             self.inner.unary(req, path, codec).await
         }
+        /// Queries for the rate data of a validator in past epochs.
+        pub async fn validator_rate_history(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ValidatorRateHistoryRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ValidatorRateHistoryResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/penumbra.core.component.stake.v1.QueryService/ValidatorRateHistory",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "penumbra.core.component.stake.v1.QueryService",
+                        "ValidatorRateHistory",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
         pub async fn validator_uptime(
             &mut self,
             request: impl tonic::IntoRequest<super::ValidatorUptimeRequest>,
@@ -1264,6 +1503,68 @@ pub mod query_service_client {
                 );
             self.inner.unary(req, path, codec).await
         }
+        /// Queries for the pending increase of a validator's commission, if any.
+        pub async fn validator_commission_schedule(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ValidatorCommissionScheduleRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ValidatorCommissionScheduleResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/penumbra.core.component.stake.v1.QueryService/ValidatorCommissionSchedule",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "penumbra.core.component.stake.v1.QueryService",
+                        "ValidatorCommissionSchedule",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// Queries for the consensus keys currently known to CometBFT.
+        pub async fn current_consensus_keys(
+            &mut self,
+            request: impl tonic::IntoRequest<super::CurrentConsensusKeysRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::CurrentConsensusKeysResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/penumbra.core.component.stake.v1.QueryService/CurrentConsensusKeys",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "penumbra.core.component.stake.v1.QueryService",
+                        "CurrentConsensusKeys",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
     }
 }
 /// Generated server implementations.
@@ -1317,6 +1618,14 @@ pub mod query_service_server {
             tonic::Response<super::CurrentValidatorRateResponse>,
             tonic::Status,
         >;
+        /// Queries for the rate data of a validator in past epochs.
+        async fn validator_rate_history(
+            &self,
+            request: tonic::Request<super::ValidatorRateHistoryRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ValidatorRateHistoryResponse>,
+            tonic::Status,
+        >;
         async fn validator_uptime(
             &self,
             request: tonic::Request<super::ValidatorUptimeRequest>,
@@ -1324,6 +1633,22 @@ pub mod query_service_server {
             tonic::Response<super::ValidatorUptimeResponse>,
             tonic::Status,
         >;
+        /// Queries for the pending increase of a validator's commission, if any.
+        async fn validator_commission_schedule(
+            &self,
+            request: tonic::Request<super::ValidatorCommissionScheduleRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ValidatorCommissionScheduleResponse>,
+            tonic::Status,
+        >;
+        /// Queries for the consensus keys currently known to CometBFT.
+        async fn current_consensus_keys(
+            &self,
+            request: tonic::Request<super::CurrentConsensusKeysRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::CurrentConsensusKeysResponse>,
+            tonic::Status,
+        >;
     }
     /// Query operations for the staking component.
     #[derive(Debug)]
@@ -1639,6 +1964,53 @@ pub mod query_service_server {
                     };
                     Box::pin(fut)
                 }
+                "/penumbra.core.component.stake.v1.QueryService/ValidatorRateHistory" => {
+                    #[allow(non_camel_case_types)]
+                    struct ValidatorRateHistorySvc<T: QueryService>(pub Arc<T>);
+                    impl<
+                        T: QueryService,
+                    > tonic::server::UnaryService<super::ValidatorRateHistoryRequest>
+                    for ValidatorRateHistorySvc<T> {
+                        type Response = super::ValidatorRateHistoryResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ValidatorRateHistoryRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as QueryService>::validator_rate_history(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = ValidatorRateHistorySvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
                 "/penumbra.core.component.stake.v1.QueryService/ValidatorUptime" => {
                     #[allow(non_camel_case_types)]
                     struct ValidatorUptimeSvc<T: QueryService>(pub Arc<T>);
@@ -1685,6 +2057,106 @@ pub mod query_service_server {
                     };
                     Box::pin(fut)
                 }
+                "/penumbra.core.component.stake.v1.QueryService/ValidatorCommissionSchedule" => {
+                    #[allow(non_camel_case_types)]
+                    struct ValidatorCommissionScheduleSvc<T: QueryService>(pub Arc<T>);
+                    impl<
+                        T: QueryService,
+                    > tonic::server::UnaryService<
+                        super::ValidatorCommissionScheduleRequest,
+                    > for ValidatorCommissionScheduleSvc<T> {
+                        type Response = super::ValidatorCommissionScheduleResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<
+                                super::ValidatorCommissionScheduleRequest,
+                            >,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as QueryService>::validator_commission_schedule(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = ValidatorCommissionScheduleSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/penumbra.core.component.stake.v1.QueryService/CurrentConsensusKeys" => {
+                    #[allow(non_camel_case_types)]
+                    struct CurrentConsensusKeysSvc<T: QueryService>(pub Arc<T>);
+                    impl<
+                        T: QueryService,
+                    > tonic::server::UnaryService<super::CurrentConsensusKeysRequest>
+                    for CurrentConsensusKeysSvc<T> {
+                        type Response = super::CurrentConsensusKeysResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::CurrentConsensusKeysRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as QueryService>::current_consensus_keys(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = CurrentConsensusKeysSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
                 _ => {
                     Box::pin(async move {
                         Ok(
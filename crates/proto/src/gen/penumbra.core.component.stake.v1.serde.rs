@@ -445,6 +445,174 @@ impl<'de> serde::Deserialize<'de> for CurrentConsensusKeys {
         deserializer.deserialize_struct("penumbra.core.component.stake.v1.CurrentConsensusKeys", FIELDS, GeneratedVisitor)
     }
 }
+impl serde::Serialize for CurrentConsensusKeysRequest {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let len = 0;
+        let struct_ser = serializer.serialize_struct("penumbra.core.component.stake.v1.CurrentConsensusKeysRequest", len)?;
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for CurrentConsensusKeysRequest {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                            Ok(GeneratedField::__SkipField__)
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = CurrentConsensusKeysRequest;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.stake.v1.CurrentConsensusKeysRequest")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<CurrentConsensusKeysRequest, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                while map_.next_key::<GeneratedField>()?.is_some() {
+                    let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                }
+                Ok(CurrentConsensusKeysRequest {
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.stake.v1.CurrentConsensusKeysRequest", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for CurrentConsensusKeysResponse {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.current_consensus_keys.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.stake.v1.CurrentConsensusKeysResponse", len)?;
+        if let Some(v) = self.current_consensus_keys.as_ref() {
+            struct_ser.serialize_field("currentConsensusKeys", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for CurrentConsensusKeysResponse {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "current_consensus_keys",
+            "currentConsensusKeys",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            CurrentConsensusKeys,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "currentConsensusKeys" | "current_consensus_keys" => Ok(GeneratedField::CurrentConsensusKeys),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = CurrentConsensusKeysResponse;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.stake.v1.CurrentConsensusKeysResponse")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<CurrentConsensusKeysResponse, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut current_consensus_keys__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::CurrentConsensusKeys => {
+                            if current_consensus_keys__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("currentConsensusKeys"));
+                            }
+                            current_consensus_keys__ = map_.next_value()?;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(CurrentConsensusKeysResponse {
+                    current_consensus_keys: current_consensus_keys__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.stake.v1.CurrentConsensusKeysResponse", FIELDS, GeneratedVisitor)
+    }
+}
 impl serde::Serialize for CurrentValidatorRateRequest {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
@@ -1128,6 +1296,137 @@ impl<'de> serde::Deserialize<'de> for EventRateDataChange {
         deserializer.deserialize_struct("penumbra.core.component.stake.v1.EventRateDataChange", FIELDS, GeneratedVisitor)
     }
 }
+impl serde::Serialize for EventRedelegate {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.source_identity_key.is_some() {
+            len += 1;
+        }
+        if self.destination_identity_key.is_some() {
+            len += 1;
+        }
+        if self.amount.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.stake.v1.EventRedelegate", len)?;
+        if let Some(v) = self.source_identity_key.as_ref() {
+            struct_ser.serialize_field("sourceIdentityKey", v)?;
+        }
+        if let Some(v) = self.destination_identity_key.as_ref() {
+            struct_ser.serialize_field("destinationIdentityKey", v)?;
+        }
+        if let Some(v) = self.amount.as_ref() {
+            struct_ser.serialize_field("amount", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for EventRedelegate {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "source_identity_key",
+            "sourceIdentityKey",
+            "destination_identity_key",
+            "destinationIdentityKey",
+            "amount",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            SourceIdentityKey,
+            DestinationIdentityKey,
+            Amount,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "sourceIdentityKey" | "source_identity_key" => Ok(GeneratedField::SourceIdentityKey),
+                            "destinationIdentityKey" | "destination_identity_key" => Ok(GeneratedField::DestinationIdentityKey),
+                            "amount" => Ok(GeneratedField::Amount),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = EventRedelegate;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.stake.v1.EventRedelegate")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<EventRedelegate, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut source_identity_key__ = None;
+                let mut destination_identity_key__ = None;
+                let mut amount__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::SourceIdentityKey => {
+                            if source_identity_key__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("sourceIdentityKey"));
+                            }
+                            source_identity_key__ = map_.next_value()?;
+                        }
+                        GeneratedField::DestinationIdentityKey => {
+                            if destination_identity_key__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("destinationIdentityKey"));
+                            }
+                            destination_identity_key__ = map_.next_value()?;
+                        }
+                        GeneratedField::Amount => {
+                            if amount__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("amount"));
+                            }
+                            amount__ = map_.next_value()?;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(EventRedelegate {
+                    source_identity_key: source_identity_key__,
+                    destination_identity_key: destination_identity_key__,
+                    amount: amount__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.stake.v1.EventRedelegate", FIELDS, GeneratedVisitor)
+    }
+}
 impl serde::Serialize for EventSlashingPenaltyApplied {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
@@ -2818,7 +3117,7 @@ impl<'de> serde::Deserialize<'de> for Penalty {
         deserializer.deserialize_struct("penumbra.core.component.stake.v1.Penalty", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for RateData {
+impl serde::Serialize for PendingFundingStreams {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -2826,36 +3125,153 @@ impl serde::Serialize for RateData {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.identity_key.is_some() {
-            len += 1;
-        }
-        if self.epoch_index != 0 {
-            len += 1;
-        }
-        if self.validator_reward_rate.is_some() {
+        if !self.funding_streams.is_empty() {
             len += 1;
         }
-        if self.validator_exchange_rate.is_some() {
+        if self.effective_epoch_index != 0 {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.stake.v1.RateData", len)?;
-        if let Some(v) = self.identity_key.as_ref() {
-            struct_ser.serialize_field("identityKey", v)?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.stake.v1.PendingFundingStreams", len)?;
+        if !self.funding_streams.is_empty() {
+            struct_ser.serialize_field("fundingStreams", &self.funding_streams)?;
         }
-        if self.epoch_index != 0 {
+        if self.effective_epoch_index != 0 {
             #[allow(clippy::needless_borrow)]
-            struct_ser.serialize_field("epochIndex", ToString::to_string(&self.epoch_index).as_str())?;
-        }
-        if let Some(v) = self.validator_reward_rate.as_ref() {
-            struct_ser.serialize_field("validatorRewardRate", v)?;
-        }
-        if let Some(v) = self.validator_exchange_rate.as_ref() {
-            struct_ser.serialize_field("validatorExchangeRate", v)?;
+            struct_ser.serialize_field("effectiveEpochIndex", ToString::to_string(&self.effective_epoch_index).as_str())?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for RateData {
+impl<'de> serde::Deserialize<'de> for PendingFundingStreams {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "funding_streams",
+            "fundingStreams",
+            "effective_epoch_index",
+            "effectiveEpochIndex",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            FundingStreams,
+            EffectiveEpochIndex,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "fundingStreams" | "funding_streams" => Ok(GeneratedField::FundingStreams),
+                            "effectiveEpochIndex" | "effective_epoch_index" => Ok(GeneratedField::EffectiveEpochIndex),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = PendingFundingStreams;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.stake.v1.PendingFundingStreams")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<PendingFundingStreams, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut funding_streams__ = None;
+                let mut effective_epoch_index__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::FundingStreams => {
+                            if funding_streams__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("fundingStreams"));
+                            }
+                            funding_streams__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::EffectiveEpochIndex => {
+                            if effective_epoch_index__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("effectiveEpochIndex"));
+                            }
+                            effective_epoch_index__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(PendingFundingStreams {
+                    funding_streams: funding_streams__.unwrap_or_default(),
+                    effective_epoch_index: effective_epoch_index__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.stake.v1.PendingFundingStreams", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for RateData {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.identity_key.is_some() {
+            len += 1;
+        }
+        if self.epoch_index != 0 {
+            len += 1;
+        }
+        if self.validator_reward_rate.is_some() {
+            len += 1;
+        }
+        if self.validator_exchange_rate.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.stake.v1.RateData", len)?;
+        if let Some(v) = self.identity_key.as_ref() {
+            struct_ser.serialize_field("identityKey", v)?;
+        }
+        if self.epoch_index != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("epochIndex", ToString::to_string(&self.epoch_index).as_str())?;
+        }
+        if let Some(v) = self.validator_reward_rate.as_ref() {
+            struct_ser.serialize_field("validatorRewardRate", v)?;
+        }
+        if let Some(v) = self.validator_exchange_rate.as_ref() {
+            struct_ser.serialize_field("validatorExchangeRate", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for RateData {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
@@ -2971,6 +3387,195 @@ impl<'de> serde::Deserialize<'de> for RateData {
         deserializer.deserialize_struct("penumbra.core.component.stake.v1.RateData", FIELDS, GeneratedVisitor)
     }
 }
+impl serde::Serialize for Redelegate {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.source_validator.is_some() {
+            len += 1;
+        }
+        if self.destination_validator.is_some() {
+            len += 1;
+        }
+        if self.epoch_index != 0 {
+            len += 1;
+        }
+        if self.source_delegation_amount.is_some() {
+            len += 1;
+        }
+        if self.unbonded_amount.is_some() {
+            len += 1;
+        }
+        if self.destination_delegation_amount.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.stake.v1.Redelegate", len)?;
+        if let Some(v) = self.source_validator.as_ref() {
+            struct_ser.serialize_field("sourceValidator", v)?;
+        }
+        if let Some(v) = self.destination_validator.as_ref() {
+            struct_ser.serialize_field("destinationValidator", v)?;
+        }
+        if self.epoch_index != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("epochIndex", ToString::to_string(&self.epoch_index).as_str())?;
+        }
+        if let Some(v) = self.source_delegation_amount.as_ref() {
+            struct_ser.serialize_field("sourceDelegationAmount", v)?;
+        }
+        if let Some(v) = self.unbonded_amount.as_ref() {
+            struct_ser.serialize_field("unbondedAmount", v)?;
+        }
+        if let Some(v) = self.destination_delegation_amount.as_ref() {
+            struct_ser.serialize_field("destinationDelegationAmount", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for Redelegate {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "source_validator",
+            "sourceValidator",
+            "destination_validator",
+            "destinationValidator",
+            "epoch_index",
+            "epochIndex",
+            "source_delegation_amount",
+            "sourceDelegationAmount",
+            "unbonded_amount",
+            "unbondedAmount",
+            "destination_delegation_amount",
+            "destinationDelegationAmount",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            SourceValidator,
+            DestinationValidator,
+            EpochIndex,
+            SourceDelegationAmount,
+            UnbondedAmount,
+            DestinationDelegationAmount,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "sourceValidator" | "source_validator" => Ok(GeneratedField::SourceValidator),
+                            "destinationValidator" | "destination_validator" => Ok(GeneratedField::DestinationValidator),
+                            "epochIndex" | "epoch_index" => Ok(GeneratedField::EpochIndex),
+                            "sourceDelegationAmount" | "source_delegation_amount" => Ok(GeneratedField::SourceDelegationAmount),
+                            "unbondedAmount" | "unbonded_amount" => Ok(GeneratedField::UnbondedAmount),
+                            "destinationDelegationAmount" | "destination_delegation_amount" => Ok(GeneratedField::DestinationDelegationAmount),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = Redelegate;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.stake.v1.Redelegate")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<Redelegate, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut source_validator__ = None;
+                let mut destination_validator__ = None;
+                let mut epoch_index__ = None;
+                let mut source_delegation_amount__ = None;
+                let mut unbonded_amount__ = None;
+                let mut destination_delegation_amount__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::SourceValidator => {
+                            if source_validator__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("sourceValidator"));
+                            }
+                            source_validator__ = map_.next_value()?;
+                        }
+                        GeneratedField::DestinationValidator => {
+                            if destination_validator__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("destinationValidator"));
+                            }
+                            destination_validator__ = map_.next_value()?;
+                        }
+                        GeneratedField::EpochIndex => {
+                            if epoch_index__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("epochIndex"));
+                            }
+                            epoch_index__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::SourceDelegationAmount => {
+                            if source_delegation_amount__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("sourceDelegationAmount"));
+                            }
+                            source_delegation_amount__ = map_.next_value()?;
+                        }
+                        GeneratedField::UnbondedAmount => {
+                            if unbonded_amount__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("unbondedAmount"));
+                            }
+                            unbonded_amount__ = map_.next_value()?;
+                        }
+                        GeneratedField::DestinationDelegationAmount => {
+                            if destination_delegation_amount__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("destinationDelegationAmount"));
+                            }
+                            destination_delegation_amount__ = map_.next_value()?;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(Redelegate {
+                    source_validator: source_validator__,
+                    destination_validator: destination_validator__,
+                    epoch_index: epoch_index__.unwrap_or_default(),
+                    source_delegation_amount: source_delegation_amount__,
+                    unbonded_amount: unbonded_amount__,
+                    destination_delegation_amount: destination_delegation_amount__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.stake.v1.Redelegate", FIELDS, GeneratedVisitor)
+    }
+}
 impl serde::Serialize for StakeParameters {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
@@ -3006,6 +3611,12 @@ impl serde::Serialize for StakeParameters {
         if self.unbonding_delay != 0 {
             len += 1;
         }
+        if self.max_commission_increase_bps != 0 {
+            len += 1;
+        }
+        if self.commission_increase_notice_epochs != 0 {
+            len += 1;
+        }
         let mut struct_ser = serializer.serialize_struct("penumbra.core.component.stake.v1.StakeParameters", len)?;
         if self.unbonding_epochs != 0 {
             #[allow(clippy::needless_borrow)]
@@ -3042,6 +3653,14 @@ impl serde::Serialize for StakeParameters {
             #[allow(clippy::needless_borrow)]
             struct_ser.serialize_field("unbondingDelay", ToString::to_string(&self.unbonding_delay).as_str())?;
         }
+        if self.max_commission_increase_bps != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("maxCommissionIncreaseBps", ToString::to_string(&self.max_commission_increase_bps).as_str())?;
+        }
+        if self.commission_increase_notice_epochs != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("commissionIncreaseNoticeEpochs", ToString::to_string(&self.commission_increase_notice_epochs).as_str())?;
+        }
         struct_ser.end()
     }
 }
@@ -3070,6 +3689,10 @@ impl<'de> serde::Deserialize<'de> for StakeParameters {
             "minValidatorStake",
             "unbonding_delay",
             "unbondingDelay",
+            "max_commission_increase_bps",
+            "maxCommissionIncreaseBps",
+            "commission_increase_notice_epochs",
+            "commissionIncreaseNoticeEpochs",
         ];
 
         #[allow(clippy::enum_variant_names)]
@@ -3083,6 +3706,8 @@ impl<'de> serde::Deserialize<'de> for StakeParameters {
             MissedBlocksMaximum,
             MinValidatorStake,
             UnbondingDelay,
+            MaxCommissionIncreaseBps,
+            CommissionIncreaseNoticeEpochs,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -3114,6 +3739,8 @@ impl<'de> serde::Deserialize<'de> for StakeParameters {
                             "missedBlocksMaximum" | "missed_blocks_maximum" => Ok(GeneratedField::MissedBlocksMaximum),
                             "minValidatorStake" | "min_validator_stake" => Ok(GeneratedField::MinValidatorStake),
                             "unbondingDelay" | "unbonding_delay" => Ok(GeneratedField::UnbondingDelay),
+                            "maxCommissionIncreaseBps" | "max_commission_increase_bps" => Ok(GeneratedField::MaxCommissionIncreaseBps),
+                            "commissionIncreaseNoticeEpochs" | "commission_increase_notice_epochs" => Ok(GeneratedField::CommissionIncreaseNoticeEpochs),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -3142,6 +3769,8 @@ impl<'de> serde::Deserialize<'de> for StakeParameters {
                 let mut missed_blocks_maximum__ = None;
                 let mut min_validator_stake__ = None;
                 let mut unbonding_delay__ = None;
+                let mut max_commission_increase_bps__ = None;
+                let mut commission_increase_notice_epochs__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
                         GeneratedField::UnbondingEpochs => {
@@ -3214,8 +3843,24 @@ impl<'de> serde::Deserialize<'de> for StakeParameters {
                                 Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
                             ;
                         }
-                        GeneratedField::__SkipField__ => {
-                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        GeneratedField::MaxCommissionIncreaseBps => {
+                            if max_commission_increase_bps__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("maxCommissionIncreaseBps"));
+                            }
+                            max_commission_increase_bps__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::CommissionIncreaseNoticeEpochs => {
+                            if commission_increase_notice_epochs__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("commissionIncreaseNoticeEpochs"));
+                            }
+                            commission_increase_notice_epochs__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
@@ -3229,6 +3874,8 @@ impl<'de> serde::Deserialize<'de> for StakeParameters {
                     missed_blocks_maximum: missed_blocks_maximum__.unwrap_or_default(),
                     min_validator_stake: min_validator_stake__,
                     unbonding_delay: unbonding_delay__.unwrap_or_default(),
+                    max_commission_increase_bps: max_commission_increase_bps__.unwrap_or_default(),
+                    commission_increase_notice_epochs: commission_increase_notice_epochs__.unwrap_or_default(),
                 })
             }
         }
@@ -4310,6 +4957,197 @@ impl<'de> serde::Deserialize<'de> for Validator {
         deserializer.deserialize_struct("penumbra.core.component.stake.v1.Validator", FIELDS, GeneratedVisitor)
     }
 }
+impl serde::Serialize for ValidatorCommissionScheduleRequest {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.identity_key.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.stake.v1.ValidatorCommissionScheduleRequest", len)?;
+        if let Some(v) = self.identity_key.as_ref() {
+            struct_ser.serialize_field("identityKey", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for ValidatorCommissionScheduleRequest {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "identity_key",
+            "identityKey",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            IdentityKey,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "identityKey" | "identity_key" => Ok(GeneratedField::IdentityKey),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = ValidatorCommissionScheduleRequest;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.stake.v1.ValidatorCommissionScheduleRequest")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ValidatorCommissionScheduleRequest, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut identity_key__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::IdentityKey => {
+                            if identity_key__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("identityKey"));
+                            }
+                            identity_key__ = map_.next_value()?;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(ValidatorCommissionScheduleRequest {
+                    identity_key: identity_key__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.stake.v1.ValidatorCommissionScheduleRequest", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for ValidatorCommissionScheduleResponse {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.pending.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.stake.v1.ValidatorCommissionScheduleResponse", len)?;
+        if let Some(v) = self.pending.as_ref() {
+            struct_ser.serialize_field("pending", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for ValidatorCommissionScheduleResponse {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "pending",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Pending,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "pending" => Ok(GeneratedField::Pending),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = ValidatorCommissionScheduleResponse;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.stake.v1.ValidatorCommissionScheduleResponse")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ValidatorCommissionScheduleResponse, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut pending__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Pending => {
+                            if pending__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("pending"));
+                            }
+                            pending__ = map_.next_value()?;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(ValidatorCommissionScheduleResponse {
+                    pending: pending__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.stake.v1.ValidatorCommissionScheduleResponse", FIELDS, GeneratedVisitor)
+    }
+}
 impl serde::Serialize for ValidatorDefinition {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
@@ -5077,6 +5915,355 @@ impl<'de> serde::Deserialize<'de> for ValidatorPenaltyResponse {
         deserializer.deserialize_struct("penumbra.core.component.stake.v1.ValidatorPenaltyResponse", FIELDS, GeneratedVisitor)
     }
 }
+impl serde::Serialize for ValidatorRateHistoryRequest {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.identity_key.is_some() {
+            len += 1;
+        }
+        if self.start_epoch_index != 0 {
+            len += 1;
+        }
+        if self.limit != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.stake.v1.ValidatorRateHistoryRequest", len)?;
+        if let Some(v) = self.identity_key.as_ref() {
+            struct_ser.serialize_field("identityKey", v)?;
+        }
+        if self.start_epoch_index != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("startEpochIndex", ToString::to_string(&self.start_epoch_index).as_str())?;
+        }
+        if self.limit != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("limit", ToString::to_string(&self.limit).as_str())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for ValidatorRateHistoryRequest {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "identity_key",
+            "identityKey",
+            "start_epoch_index",
+            "startEpochIndex",
+            "limit",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            IdentityKey,
+            StartEpochIndex,
+            Limit,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "identityKey" | "identity_key" => Ok(GeneratedField::IdentityKey),
+                            "startEpochIndex" | "start_epoch_index" => Ok(GeneratedField::StartEpochIndex),
+                            "limit" => Ok(GeneratedField::Limit),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = ValidatorRateHistoryRequest;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.stake.v1.ValidatorRateHistoryRequest")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ValidatorRateHistoryRequest, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut identity_key__ = None;
+                let mut start_epoch_index__ = None;
+                let mut limit__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::IdentityKey => {
+                            if identity_key__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("identityKey"));
+                            }
+                            identity_key__ = map_.next_value()?;
+                        }
+                        GeneratedField::StartEpochIndex => {
+                            if start_epoch_index__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("startEpochIndex"));
+                            }
+                            start_epoch_index__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Limit => {
+                            if limit__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("limit"));
+                            }
+                            limit__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(ValidatorRateHistoryRequest {
+                    identity_key: identity_key__,
+                    start_epoch_index: start_epoch_index__.unwrap_or_default(),
+                    limit: limit__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.stake.v1.ValidatorRateHistoryRequest", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for ValidatorRateHistoryResponse {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.history.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.stake.v1.ValidatorRateHistoryResponse", len)?;
+        if !self.history.is_empty() {
+            struct_ser.serialize_field("history", &self.history)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for ValidatorRateHistoryResponse {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "history",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            History,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "history" => Ok(GeneratedField::History),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = ValidatorRateHistoryResponse;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.stake.v1.ValidatorRateHistoryResponse")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ValidatorRateHistoryResponse, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut history__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::History => {
+                            if history__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("history"));
+                            }
+                            history__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(ValidatorRateHistoryResponse {
+                    history: history__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.stake.v1.ValidatorRateHistoryResponse", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for validator_rate_history_response::EpochRateData {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.epoch_index != 0 {
+            len += 1;
+        }
+        if self.rate_data.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.stake.v1.ValidatorRateHistoryResponse.EpochRateData", len)?;
+        if self.epoch_index != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("epochIndex", ToString::to_string(&self.epoch_index).as_str())?;
+        }
+        if let Some(v) = self.rate_data.as_ref() {
+            struct_ser.serialize_field("rateData", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for validator_rate_history_response::EpochRateData {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "epoch_index",
+            "epochIndex",
+            "rate_data",
+            "rateData",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            EpochIndex,
+            RateData,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "epochIndex" | "epoch_index" => Ok(GeneratedField::EpochIndex),
+                            "rateData" | "rate_data" => Ok(GeneratedField::RateData),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = validator_rate_history_response::EpochRateData;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.stake.v1.ValidatorRateHistoryResponse.EpochRateData")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<validator_rate_history_response::EpochRateData, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut epoch_index__ = None;
+                let mut rate_data__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::EpochIndex => {
+                            if epoch_index__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("epochIndex"));
+                            }
+                            epoch_index__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::RateData => {
+                            if rate_data__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("rateData"));
+                            }
+                            rate_data__ = map_.next_value()?;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(validator_rate_history_response::EpochRateData {
+                    epoch_index: epoch_index__.unwrap_or_default(),
+                    rate_data: rate_data__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.stake.v1.ValidatorRateHistoryResponse.EpochRateData", FIELDS, GeneratedVisitor)
+    }
+}
 impl serde::Serialize for ValidatorState {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
@@ -129,7 +129,7 @@ impl ::prost::Name for DetectionData {
 pub struct Action {
     #[prost(
         oneof = "action::Action",
-        tags = "1, 2, 3, 4, 16, 17, 18, 19, 20, 21, 22, 30, 31, 32, 34, 40, 41, 42, 50, 51, 52, 53, 54, 55, 200"
+        tags = "1, 2, 3, 4, 16, 17, 18, 19, 20, 21, 22, 30, 31, 32, 34, 40, 41, 42, 43, 50, 51, 52, 56, 57, 53, 54, 55, 200"
     )]
     pub action: ::core::option::Option<action::Action>,
 }
@@ -186,6 +186,8 @@ pub mod action {
         Undelegate(super::super::super::component::stake::v1::Undelegate),
         #[prost(message, tag = "42")]
         UndelegateClaim(super::super::super::component::stake::v1::UndelegateClaim),
+        #[prost(message, tag = "43")]
+        Redelegate(super::super::super::component::stake::v1::Redelegate),
         /// Community Pool
         #[prost(message, tag = "50")]
         CommunityPoolSpend(
@@ -199,6 +201,12 @@ pub mod action {
         CommunityPoolDeposit(
             super::super::super::component::governance::v1::CommunityPoolDeposit,
         ),
+        #[prost(message, tag = "56")]
+        CommunityPoolVestingClaim(
+            super::super::super::component::governance::v1::CommunityPoolVestingClaim,
+        ),
+        #[prost(message, tag = "57")]
+        ProxyVote(super::super::super::component::governance::v1::ProxyVote),
         /// Dutch auctions
         #[prost(message, tag = "53")]
         ActionDutchAuctionSchedule(
@@ -435,7 +443,7 @@ impl ::prost::Name for TransactionBodyView {
 pub struct ActionView {
     #[prost(
         oneof = "action_view::ActionView",
-        tags = "1, 2, 3, 4, 21, 16, 17, 18, 19, 20, 22, 30, 31, 32, 34, 41, 42, 50, 51, 52, 53, 54, 55, 43, 200"
+        tags = "1, 2, 3, 4, 21, 16, 17, 18, 19, 20, 22, 30, 31, 32, 34, 41, 42, 44, 50, 51, 52, 56, 57, 53, 54, 55, 43, 200"
     )]
     pub action_view: ::core::option::Option<action_view::ActionView>,
 }
@@ -489,6 +497,8 @@ pub mod action_view {
         Delegate(super::super::super::component::stake::v1::Delegate),
         #[prost(message, tag = "42")]
         Undelegate(super::super::super::component::stake::v1::Undelegate),
+        #[prost(message, tag = "44")]
+        Redelegate(super::super::super::component::stake::v1::Redelegate),
         /// Community Pool
         #[prost(message, tag = "50")]
         CommunityPoolSpend(
@@ -502,6 +512,12 @@ pub mod action_view {
         CommunityPoolDeposit(
             super::super::super::component::governance::v1::CommunityPoolDeposit,
         ),
+        #[prost(message, tag = "56")]
+        CommunityPoolVestingClaim(
+            super::super::super::component::governance::v1::CommunityPoolVestingClaim,
+        ),
+        #[prost(message, tag = "57")]
+        ProxyVote(super::super::super::component::governance::v1::ProxyVote),
         /// Dutch auctions
         #[prost(message, tag = "53")]
         ActionDutchAuctionSchedule(
@@ -629,7 +645,7 @@ impl ::prost::Name for DetectionDataPlan {
 pub struct ActionPlan {
     #[prost(
         oneof = "action_plan::Action",
-        tags = "1, 2, 3, 4, 16, 17, 18, 19, 20, 21, 22, 200, 30, 31, 32, 34, 40, 41, 42, 50, 51, 52, 53, 54, 55"
+        tags = "1, 2, 3, 4, 16, 17, 18, 19, 20, 21, 22, 200, 30, 31, 32, 34, 40, 41, 42, 43, 50, 51, 52, 56, 57, 53, 54, 55"
     )]
     pub action: ::core::option::Option<action_plan::Action>,
 }
@@ -692,6 +708,10 @@ pub mod action_plan {
         Undelegate(super::super::super::component::stake::v1::Undelegate),
         #[prost(message, tag = "42")]
         UndelegateClaim(super::super::super::component::stake::v1::UndelegateClaimPlan),
+        /// We don't need any extra information (yet) to understand redelegations,
+        /// because we don't yet use flow encryption.
+        #[prost(message, tag = "43")]
+        Redelegate(super::super::super::component::stake::v1::Redelegate),
         /// Community Pool
         #[prost(message, tag = "50")]
         CommunityPoolSpend(
@@ -705,6 +725,12 @@ pub mod action_plan {
         CommunityPoolDeposit(
             super::super::super::component::governance::v1::CommunityPoolDeposit,
         ),
+        #[prost(message, tag = "56")]
+        CommunityPoolVestingClaim(
+            super::super::super::component::governance::v1::CommunityPoolVestingClaim,
+        ),
+        #[prost(message, tag = "57")]
+        ProxyVote(super::super::super::component::governance::v1::ProxyVote),
         /// Dutch auctions
         #[prost(message, tag = "53")]
         ActionDutchAuctionSchedule(
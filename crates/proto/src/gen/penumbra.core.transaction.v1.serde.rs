@@ -66,6 +66,9 @@ impl serde::Serialize for Action {
                 action::Action::UndelegateClaim(v) => {
                     struct_ser.serialize_field("undelegateClaim", v)?;
                 }
+                action::Action::Redelegate(v) => {
+                    struct_ser.serialize_field("redelegate", v)?;
+                }
                 action::Action::CommunityPoolSpend(v) => {
                     struct_ser.serialize_field("communityPoolSpend", v)?;
                 }
@@ -75,6 +78,12 @@ impl serde::Serialize for Action {
                 action::Action::CommunityPoolDeposit(v) => {
                     struct_ser.serialize_field("communityPoolDeposit", v)?;
                 }
+                action::Action::CommunityPoolVestingClaim(v) => {
+                    struct_ser.serialize_field("communityPoolVestingClaim", v)?;
+                }
+                action::Action::ProxyVote(v) => {
+                    struct_ser.serialize_field("proxyVote", v)?;
+                }
                 action::Action::ActionDutchAuctionSchedule(v) => {
                     struct_ser.serialize_field("actionDutchAuctionSchedule", v)?;
                 }
@@ -130,12 +139,17 @@ impl<'de> serde::Deserialize<'de> for Action {
             "undelegate",
             "undelegate_claim",
             "undelegateClaim",
+            "redelegate",
             "community_pool_spend",
             "communityPoolSpend",
             "community_pool_output",
             "communityPoolOutput",
             "community_pool_deposit",
             "communityPoolDeposit",
+            "community_pool_vesting_claim",
+            "communityPoolVestingClaim",
+            "proxy_vote",
+            "proxyVote",
             "action_dutch_auction_schedule",
             "actionDutchAuctionSchedule",
             "action_dutch_auction_end",
@@ -166,9 +180,12 @@ impl<'de> serde::Deserialize<'de> for Action {
             Delegate,
             Undelegate,
             UndelegateClaim,
+            Redelegate,
             CommunityPoolSpend,
             CommunityPoolOutput,
             CommunityPoolDeposit,
+            CommunityPoolVestingClaim,
+            ProxyVote,
             ActionDutchAuctionSchedule,
             ActionDutchAuctionEnd,
             ActionDutchAuctionWithdraw,
@@ -213,9 +230,12 @@ impl<'de> serde::Deserialize<'de> for Action {
                             "delegate" => Ok(GeneratedField::Delegate),
                             "undelegate" => Ok(GeneratedField::Undelegate),
                             "undelegateClaim" | "undelegate_claim" => Ok(GeneratedField::UndelegateClaim),
+                            "redelegate" => Ok(GeneratedField::Redelegate),
                             "communityPoolSpend" | "community_pool_spend" => Ok(GeneratedField::CommunityPoolSpend),
                             "communityPoolOutput" | "community_pool_output" => Ok(GeneratedField::CommunityPoolOutput),
                             "communityPoolDeposit" | "community_pool_deposit" => Ok(GeneratedField::CommunityPoolDeposit),
+                            "communityPoolVestingClaim" | "community_pool_vesting_claim" => Ok(GeneratedField::CommunityPoolVestingClaim),
+                            "proxyVote" | "proxy_vote" => Ok(GeneratedField::ProxyVote),
                             "actionDutchAuctionSchedule" | "action_dutch_auction_schedule" => Ok(GeneratedField::ActionDutchAuctionSchedule),
                             "actionDutchAuctionEnd" | "action_dutch_auction_end" => Ok(GeneratedField::ActionDutchAuctionEnd),
                             "actionDutchAuctionWithdraw" | "action_dutch_auction_withdraw" => Ok(GeneratedField::ActionDutchAuctionWithdraw),
@@ -366,6 +386,13 @@ impl<'de> serde::Deserialize<'de> for Action {
                                 return Err(serde::de::Error::duplicate_field("undelegateClaim"));
                             }
                             action__ = map_.next_value::<::std::option::Option<_>>()?.map(action::Action::UndelegateClaim)
+;
+                        }
+                        GeneratedField::Redelegate => {
+                            if action__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("redelegate"));
+                            }
+                            action__ = map_.next_value::<::std::option::Option<_>>()?.map(action::Action::Redelegate)
 ;
                         }
                         GeneratedField::CommunityPoolSpend => {
@@ -387,6 +414,20 @@ impl<'de> serde::Deserialize<'de> for Action {
                                 return Err(serde::de::Error::duplicate_field("communityPoolDeposit"));
                             }
                             action__ = map_.next_value::<::std::option::Option<_>>()?.map(action::Action::CommunityPoolDeposit)
+;
+                        }
+                        GeneratedField::CommunityPoolVestingClaim => {
+                            if action__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("communityPoolVestingClaim"));
+                            }
+                            action__ = map_.next_value::<::std::option::Option<_>>()?.map(action::Action::CommunityPoolVestingClaim)
+;
+                        }
+                        GeneratedField::ProxyVote => {
+                            if action__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("proxyVote"));
+                            }
+                            action__ = map_.next_value::<::std::option::Option<_>>()?.map(action::Action::ProxyVote)
 ;
                         }
                         GeneratedField::ActionDutchAuctionSchedule => {
@@ -501,6 +542,9 @@ impl serde::Serialize for ActionPlan {
                 action_plan::Action::UndelegateClaim(v) => {
                     struct_ser.serialize_field("undelegateClaim", v)?;
                 }
+                action_plan::Action::Redelegate(v) => {
+                    struct_ser.serialize_field("redelegate", v)?;
+                }
                 action_plan::Action::CommunityPoolSpend(v) => {
                     struct_ser.serialize_field("communityPoolSpend", v)?;
                 }
@@ -510,6 +554,12 @@ impl serde::Serialize for ActionPlan {
                 action_plan::Action::CommunityPoolDeposit(v) => {
                     struct_ser.serialize_field("communityPoolDeposit", v)?;
                 }
+                action_plan::Action::CommunityPoolVestingClaim(v) => {
+                    struct_ser.serialize_field("communityPoolVestingClaim", v)?;
+                }
+                action_plan::Action::ProxyVote(v) => {
+                    struct_ser.serialize_field("proxyVote", v)?;
+                }
                 action_plan::Action::ActionDutchAuctionSchedule(v) => {
                     struct_ser.serialize_field("actionDutchAuctionSchedule", v)?;
                 }
@@ -564,12 +614,17 @@ impl<'de> serde::Deserialize<'de> for ActionPlan {
             "undelegate",
             "undelegate_claim",
             "undelegateClaim",
+            "redelegate",
             "community_pool_spend",
             "communityPoolSpend",
             "community_pool_output",
             "communityPoolOutput",
             "community_pool_deposit",
             "communityPoolDeposit",
+            "community_pool_vesting_claim",
+            "communityPoolVestingClaim",
+            "proxy_vote",
+            "proxyVote",
             "action_dutch_auction_schedule",
             "actionDutchAuctionSchedule",
             "action_dutch_auction_end",
@@ -599,9 +654,12 @@ impl<'de> serde::Deserialize<'de> for ActionPlan {
             Delegate,
             Undelegate,
             UndelegateClaim,
+            Redelegate,
             CommunityPoolSpend,
             CommunityPoolOutput,
             CommunityPoolDeposit,
+            CommunityPoolVestingClaim,
+            ProxyVote,
             ActionDutchAuctionSchedule,
             ActionDutchAuctionEnd,
             ActionDutchAuctionWithdraw,
@@ -646,9 +704,12 @@ impl<'de> serde::Deserialize<'de> for ActionPlan {
                             "delegate" => Ok(GeneratedField::Delegate),
                             "undelegate" => Ok(GeneratedField::Undelegate),
                             "undelegateClaim" | "undelegate_claim" => Ok(GeneratedField::UndelegateClaim),
+                            "redelegate" => Ok(GeneratedField::Redelegate),
                             "communityPoolSpend" | "community_pool_spend" => Ok(GeneratedField::CommunityPoolSpend),
                             "communityPoolOutput" | "community_pool_output" => Ok(GeneratedField::CommunityPoolOutput),
                             "communityPoolDeposit" | "community_pool_deposit" => Ok(GeneratedField::CommunityPoolDeposit),
+                            "communityPoolVestingClaim" | "community_pool_vesting_claim" => Ok(GeneratedField::CommunityPoolVestingClaim),
+                            "proxyVote" | "proxy_vote" => Ok(GeneratedField::ProxyVote),
                             "actionDutchAuctionSchedule" | "action_dutch_auction_schedule" => Ok(GeneratedField::ActionDutchAuctionSchedule),
                             "actionDutchAuctionEnd" | "action_dutch_auction_end" => Ok(GeneratedField::ActionDutchAuctionEnd),
                             "actionDutchAuctionWithdraw" | "action_dutch_auction_withdraw" => Ok(GeneratedField::ActionDutchAuctionWithdraw),
@@ -805,6 +866,13 @@ impl<'de> serde::Deserialize<'de> for ActionPlan {
                                 return Err(serde::de::Error::duplicate_field("undelegateClaim"));
                             }
                             action__ = map_.next_value::<::std::option::Option<_>>()?.map(action_plan::Action::UndelegateClaim)
+;
+                        }
+                        GeneratedField::Redelegate => {
+                            if action__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("redelegate"));
+                            }
+                            action__ = map_.next_value::<::std::option::Option<_>>()?.map(action_plan::Action::Redelegate)
 ;
                         }
                         GeneratedField::CommunityPoolSpend => {
@@ -826,6 +894,20 @@ impl<'de> serde::Deserialize<'de> for ActionPlan {
                                 return Err(serde::de::Error::duplicate_field("communityPoolDeposit"));
                             }
                             action__ = map_.next_value::<::std::option::Option<_>>()?.map(action_plan::Action::CommunityPoolDeposit)
+;
+                        }
+                        GeneratedField::CommunityPoolVestingClaim => {
+                            if action__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("communityPoolVestingClaim"));
+                            }
+                            action__ = map_.next_value::<::std::option::Option<_>>()?.map(action_plan::Action::CommunityPoolVestingClaim)
+;
+                        }
+                        GeneratedField::ProxyVote => {
+                            if action__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("proxyVote"));
+                            }
+                            action__ = map_.next_value::<::std::option::Option<_>>()?.map(action_plan::Action::ProxyVote)
 ;
                         }
                         GeneratedField::ActionDutchAuctionSchedule => {
@@ -927,6 +1009,9 @@ impl serde::Serialize for ActionView {
                 action_view::ActionView::Undelegate(v) => {
                     struct_ser.serialize_field("undelegate", v)?;
                 }
+                action_view::ActionView::Redelegate(v) => {
+                    struct_ser.serialize_field("redelegate", v)?;
+                }
                 action_view::ActionView::CommunityPoolSpend(v) => {
                     struct_ser.serialize_field("communityPoolSpend", v)?;
                 }
@@ -936,6 +1021,12 @@ impl serde::Serialize for ActionView {
                 action_view::ActionView::CommunityPoolDeposit(v) => {
                     struct_ser.serialize_field("communityPoolDeposit", v)?;
                 }
+                action_view::ActionView::CommunityPoolVestingClaim(v) => {
+                    struct_ser.serialize_field("communityPoolVestingClaim", v)?;
+                }
+                action_view::ActionView::ProxyVote(v) => {
+                    struct_ser.serialize_field("proxyVote", v)?;
+                }
                 action_view::ActionView::ActionDutchAuctionSchedule(v) => {
                     struct_ser.serialize_field("actionDutchAuctionSchedule", v)?;
                 }
@@ -992,12 +1083,17 @@ impl<'de> serde::Deserialize<'de> for ActionView {
             "positionRewardClaim",
             "delegate",
             "undelegate",
+            "redelegate",
             "community_pool_spend",
             "communityPoolSpend",
             "community_pool_output",
             "communityPoolOutput",
             "community_pool_deposit",
             "communityPoolDeposit",
+            "community_pool_vesting_claim",
+            "communityPoolVestingClaim",
+            "proxy_vote",
+            "proxyVote",
             "action_dutch_auction_schedule",
             "actionDutchAuctionSchedule",
             "action_dutch_auction_end",
@@ -1029,9 +1125,12 @@ impl<'de> serde::Deserialize<'de> for ActionView {
             PositionRewardClaim,
             Delegate,
             Undelegate,
+            Redelegate,
             CommunityPoolSpend,
             CommunityPoolOutput,
             CommunityPoolDeposit,
+            CommunityPoolVestingClaim,
+            ProxyVote,
             ActionDutchAuctionSchedule,
             ActionDutchAuctionEnd,
             ActionDutchAuctionWithdraw,
@@ -1076,9 +1175,12 @@ impl<'de> serde::Deserialize<'de> for ActionView {
                             "positionRewardClaim" | "position_reward_claim" => Ok(GeneratedField::PositionRewardClaim),
                             "delegate" => Ok(GeneratedField::Delegate),
                             "undelegate" => Ok(GeneratedField::Undelegate),
+                            "redelegate" => Ok(GeneratedField::Redelegate),
                             "communityPoolSpend" | "community_pool_spend" => Ok(GeneratedField::CommunityPoolSpend),
                             "communityPoolOutput" | "community_pool_output" => Ok(GeneratedField::CommunityPoolOutput),
                             "communityPoolDeposit" | "community_pool_deposit" => Ok(GeneratedField::CommunityPoolDeposit),
+                            "communityPoolVestingClaim" | "community_pool_vesting_claim" => Ok(GeneratedField::CommunityPoolVestingClaim),
+                            "proxyVote" | "proxy_vote" => Ok(GeneratedField::ProxyVote),
                             "actionDutchAuctionSchedule" | "action_dutch_auction_schedule" => Ok(GeneratedField::ActionDutchAuctionSchedule),
                             "actionDutchAuctionEnd" | "action_dutch_auction_end" => Ok(GeneratedField::ActionDutchAuctionEnd),
                             "actionDutchAuctionWithdraw" | "action_dutch_auction_withdraw" => Ok(GeneratedField::ActionDutchAuctionWithdraw),
@@ -1223,6 +1325,13 @@ impl<'de> serde::Deserialize<'de> for ActionView {
                                 return Err(serde::de::Error::duplicate_field("undelegate"));
                             }
                             action_view__ = map_.next_value::<::std::option::Option<_>>()?.map(action_view::ActionView::Undelegate)
+;
+                        }
+                        GeneratedField::Redelegate => {
+                            if action_view__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("redelegate"));
+                            }
+                            action_view__ = map_.next_value::<::std::option::Option<_>>()?.map(action_view::ActionView::Redelegate)
 ;
                         }
                         GeneratedField::CommunityPoolSpend => {
@@ -1244,6 +1353,20 @@ impl<'de> serde::Deserialize<'de> for ActionView {
                                 return Err(serde::de::Error::duplicate_field("communityPoolDeposit"));
                             }
                             action_view__ = map_.next_value::<::std::option::Option<_>>()?.map(action_view::ActionView::CommunityPoolDeposit)
+;
+                        }
+                        GeneratedField::CommunityPoolVestingClaim => {
+                            if action_view__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("communityPoolVestingClaim"));
+                            }
+                            action_view__ = map_.next_value::<::std::option::Option<_>>()?.map(action_view::ActionView::CommunityPoolVestingClaim)
+;
+                        }
+                        GeneratedField::ProxyVote => {
+                            if action_view__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("proxyVote"));
+                            }
+                            action_view__ = map_.next_value::<::std::option::Option<_>>()?.map(action_view::ActionView::ProxyVote)
 ;
                         }
                         GeneratedField::ActionDutchAuctionSchedule => {
@@ -117,6 +117,43 @@ impl ::prost::Name for AuthorizeValidatorVoteResponse {
         ::prost::alloc::format!("penumbra.custody.v1.{}", Self::NAME)
     }
 }
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AuthorizeProxyVoteRequest {
+    /// The proxy vote to authorize.
+    #[prost(message, optional, tag = "1")]
+    pub proxy_vote: ::core::option::Option<
+        super::super::core::component::governance::v1::ProxyVoteBody,
+    >,
+    /// Optionally, pre-authorization data, if required by the custodian.
+    ///
+    /// Pre-authorization data is backend-specific, and backends are free to ignore it.
+    #[prost(message, repeated, tag = "2")]
+    pub pre_authorizations: ::prost::alloc::vec::Vec<PreAuthorization>,
+}
+impl ::prost::Name for AuthorizeProxyVoteRequest {
+    const NAME: &'static str = "AuthorizeProxyVoteRequest";
+    const PACKAGE: &'static str = "penumbra.custody.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.custody.v1.{}", Self::NAME)
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AuthorizeProxyVoteResponse {
+    /// The authorization signature for the proxy vote.
+    #[prost(message, optional, tag = "1")]
+    pub proxy_vote_auth: ::core::option::Option<
+        super::super::crypto::decaf377_rdsa::v1::SpendAuthSignature,
+    >,
+}
+impl ::prost::Name for AuthorizeProxyVoteResponse {
+    const NAME: &'static str = "AuthorizeProxyVoteResponse";
+    const PACKAGE: &'static str = "penumbra.custody.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.custody.v1.{}", Self::NAME)
+    }
+}
 /// A pre-authorization packet.  This allows a custodian to delegate (partial)
 /// signing authority to other authorization mechanisms.  Details of how a
 /// custodian manages those keys are out-of-scope for the custody protocol and
@@ -405,6 +442,37 @@ pub mod custody_service_client {
                 );
             self.inner.unary(req, path, codec).await
         }
+        /// Requests authorization of the given proxy vote.
+        pub async fn authorize_proxy_vote(
+            &mut self,
+            request: impl tonic::IntoRequest<super::AuthorizeProxyVoteRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::AuthorizeProxyVoteResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/penumbra.custody.v1.CustodyService/AuthorizeProxyVote",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "penumbra.custody.v1.CustodyService",
+                        "AuthorizeProxyVote",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
         /// Requests the full viewing key from the custodian.
         ///
         /// Custody backends should decide whether to honor this request, and how to
@@ -509,6 +577,14 @@ pub mod custody_service_server {
             tonic::Response<super::AuthorizeValidatorVoteResponse>,
             tonic::Status,
         >;
+        /// Requests authorization of the given proxy vote.
+        async fn authorize_proxy_vote(
+            &self,
+            request: tonic::Request<super::AuthorizeProxyVoteRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::AuthorizeProxyVoteResponse>,
+            tonic::Status,
+        >;
         /// Requests the full viewing key from the custodian.
         ///
         /// Custody backends should decide whether to honor this request, and how to
@@ -775,6 +851,53 @@ pub mod custody_service_server {
                     };
                     Box::pin(fut)
                 }
+                "/penumbra.custody.v1.CustodyService/AuthorizeProxyVote" => {
+                    #[allow(non_camel_case_types)]
+                    struct AuthorizeProxyVoteSvc<T: CustodyService>(pub Arc<T>);
+                    impl<
+                        T: CustodyService,
+                    > tonic::server::UnaryService<super::AuthorizeProxyVoteRequest>
+                    for AuthorizeProxyVoteSvc<T> {
+                        type Response = super::AuthorizeProxyVoteResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::AuthorizeProxyVoteRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as CustodyService>::authorize_proxy_vote(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = AuthorizeProxyVoteSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
                 "/penumbra.custody.v1.CustodyService/ExportFullViewingKey" => {
                     #[allow(non_camel_case_types)]
                     struct ExportFullViewingKeySvc<T: CustodyService>(pub Arc<T>);
@@ -298,6 +298,68 @@ impl ::prost::Name for GetBlockByHeightResponse {
         ::prost::alloc::format!("penumbra.util.tendermint_proxy.v1.{}", Self::NAME)
     }
 }
+/// GetValidatorSetRequest is the request type for the Query/GetValidatorSet RPC method.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetValidatorSetRequest {
+    #[prost(int64, tag = "1")]
+    pub height: i64,
+}
+impl ::prost::Name for GetValidatorSetRequest {
+    const NAME: &'static str = "GetValidatorSetRequest";
+    const PACKAGE: &'static str = "penumbra.util.tendermint_proxy.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.util.tendermint_proxy.v1.{}", Self::NAME)
+    }
+}
+/// GetValidatorSetResponse is the response type for the Query/GetValidatorSet RPC method.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetValidatorSetResponse {
+    #[prost(message, repeated, tag = "1")]
+    pub validators: ::prost::alloc::vec::Vec<
+        super::super::super::super::tendermint::types::Validator,
+    >,
+}
+impl ::prost::Name for GetValidatorSetResponse {
+    const NAME: &'static str = "GetValidatorSetResponse";
+    const PACKAGE: &'static str = "penumbra.util.tendermint_proxy.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.util.tendermint_proxy.v1.{}", Self::NAME)
+    }
+}
+/// SearchTxEventsRequest is the request type for the Query/SearchTxEvents RPC method.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SearchTxEventsRequest {
+    /// A CometBFT event query, such as `send_packet.packet_sequence = '1'`.
+    #[prost(string, tag = "1")]
+    pub query: ::prost::alloc::string::String,
+}
+impl ::prost::Name for SearchTxEventsRequest {
+    const NAME: &'static str = "SearchTxEventsRequest";
+    const PACKAGE: &'static str = "penumbra.util.tendermint_proxy.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.util.tendermint_proxy.v1.{}", Self::NAME)
+    }
+}
+/// SearchTxEventsResponse is the response type for the Query/SearchTxEvents RPC method.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SearchTxEventsResponse {
+    /// The events of the matching transactions, most recent first.
+    #[prost(message, repeated, tag = "1")]
+    pub events: ::prost::alloc::vec::Vec<
+        super::super::super::super::tendermint::abci::Event,
+    >,
+}
+impl ::prost::Name for SearchTxEventsResponse {
+    const NAME: &'static str = "SearchTxEventsResponse";
+    const PACKAGE: &'static str = "penumbra.util.tendermint_proxy.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.util.tendermint_proxy.v1.{}", Self::NAME)
+    }
+}
 /// Generated client implementations.
 #[cfg(feature = "rpc")]
 pub mod tendermint_proxy_service_client {
@@ -572,6 +634,68 @@ pub mod tendermint_proxy_service_client {
                 );
             self.inner.unary(req, path, codec).await
         }
+        /// GetValidatorSet queries the validator set for a given height.
+        pub async fn get_validator_set(
+            &mut self,
+            request: impl tonic::IntoRequest<super::GetValidatorSetRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetValidatorSetResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/penumbra.util.tendermint_proxy.v1.TendermintProxyService/GetValidatorSet",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "penumbra.util.tendermint_proxy.v1.TendermintProxyService",
+                        "GetValidatorSet",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// SearchTxEvents searches the events of indexed transactions matching a query.
+        pub async fn search_tx_events(
+            &mut self,
+            request: impl tonic::IntoRequest<super::SearchTxEventsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::SearchTxEventsResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/penumbra.util.tendermint_proxy.v1.TendermintProxyService/SearchTxEvents",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "penumbra.util.tendermint_proxy.v1.TendermintProxyService",
+                        "SearchTxEvents",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
     }
 }
 /// Generated server implementations.
@@ -629,6 +753,22 @@ pub mod tendermint_proxy_service_server {
             tonic::Response<super::GetBlockByHeightResponse>,
             tonic::Status,
         >;
+        /// GetValidatorSet queries the validator set for a given height.
+        async fn get_validator_set(
+            &self,
+            request: tonic::Request<super::GetValidatorSetRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetValidatorSetResponse>,
+            tonic::Status,
+        >;
+        /// SearchTxEvents searches the events of indexed transactions matching a query.
+        async fn search_tx_events(
+            &self,
+            request: tonic::Request<super::SearchTxEventsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::SearchTxEventsResponse>,
+            tonic::Status,
+        >;
     }
     /// Defines the gRPC query service for proxying requests to an upstream Tendermint RPC.
     #[derive(Debug)]
@@ -1000,6 +1140,106 @@ pub mod tendermint_proxy_service_server {
                     };
                     Box::pin(fut)
                 }
+                "/penumbra.util.tendermint_proxy.v1.TendermintProxyService/GetValidatorSet" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetValidatorSetSvc<T: TendermintProxyService>(pub Arc<T>);
+                    impl<
+                        T: TendermintProxyService,
+                    > tonic::server::UnaryService<super::GetValidatorSetRequest>
+                    for GetValidatorSetSvc<T> {
+                        type Response = super::GetValidatorSetResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::GetValidatorSetRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as TendermintProxyService>::get_validator_set(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = GetValidatorSetSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/penumbra.util.tendermint_proxy.v1.TendermintProxyService/SearchTxEvents" => {
+                    #[allow(non_camel_case_types)]
+                    struct SearchTxEventsSvc<T: TendermintProxyService>(pub Arc<T>);
+                    impl<
+                        T: TendermintProxyService,
+                    > tonic::server::UnaryService<super::SearchTxEventsRequest>
+                    for SearchTxEventsSvc<T> {
+                        type Response = super::SearchTxEventsResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::SearchTxEventsRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as TendermintProxyService>::search_tx_events(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = SearchTxEventsSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
                 _ => {
                     Box::pin(async move {
                         Ok(
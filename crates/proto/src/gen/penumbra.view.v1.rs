@@ -1532,23 +1532,15 @@ impl ::prost::Name for SwapRecord {
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
-pub struct OwnedPositionIdsRequest {
-    /// If present, return only positions with this position state.
-    #[prost(message, optional, tag = "1")]
-    pub position_state: ::core::option::Option<
-        super::super::core::component::dex::v1::PositionState,
-    >,
-    /// If present, return only positions for this trading pair.
-    #[prost(message, optional, tag = "2")]
-    pub trading_pair: ::core::option::Option<
-        super::super::core::component::dex::v1::TradingPair,
+pub struct PositionPerformanceRequest {
+    /// If present, only return the performance of the positions with these IDs.
+    #[prost(message, repeated, tag = "1")]
+    pub position_ids: ::prost::alloc::vec::Vec<
+        super::super::core::component::dex::v1::PositionId,
     >,
-    /// If present, return only positions for this subaccount index.
-    #[prost(message, optional, tag = "3")]
-    pub subaccount: ::core::option::Option<super::super::core::keys::v1::AddressIndex>,
 }
-impl ::prost::Name for OwnedPositionIdsRequest {
-    const NAME: &'static str = "OwnedPositionIdsRequest";
+impl ::prost::Name for PositionPerformanceRequest {
+    const NAME: &'static str = "PositionPerformanceRequest";
     const PACKAGE: &'static str = "penumbra.view.v1";
     fn full_name() -> ::prost::alloc::string::String {
         ::prost::alloc::format!("penumbra.view.v1.{}", Self::NAME)
@@ -1556,32 +1548,109 @@ impl ::prost::Name for OwnedPositionIdsRequest {
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
-pub struct OwnedPositionIdsResponse {
+pub struct PositionPerformanceResponse {
+    #[prost(message, optional, tag = "1")]
+    pub performance: ::core::option::Option<PositionPerformance>,
+}
+impl ::prost::Name for PositionPerformanceResponse {
+    const NAME: &'static str = "PositionPerformanceResponse";
+    const PACKAGE: &'static str = "penumbra.view.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.view.v1.{}", Self::NAME)
+    }
+}
+/// The accounting of a liquidity position controlled by the user's wallet.
+///
+/// Executions are recorded netted per block, so fills in opposite directions
+/// within a single block only contribute their net effect.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PositionPerformance {
+    /// The ID of the position.
     #[prost(message, optional, tag = "1")]
     pub position_id: ::core::option::Option<
         super::super::core::component::dex::v1::PositionId,
     >,
-    /// The subaccount this position belongs to.
+    /// The position as it was opened, including its initial reserves.
     #[prost(message, optional, tag = "2")]
-    pub subaccount: ::core::option::Option<super::super::core::keys::v1::AddressIndex>,
+    pub opened: ::core::option::Option<super::super::core::component::dex::v1::Position>,
+    /// The height at which the position was opened.
+    #[prost(uint64, tag = "3")]
+    pub height_opened: u64,
+    /// The state of the position, as known to the local view service.
+    #[prost(message, optional, tag = "4")]
+    pub state: ::core::option::Option<
+        super::super::core::component::dex::v1::PositionState,
+    >,
+    /// The reserves of the position after its latest execution.
+    ///
+    /// Once the position has been withdrawn, these are the reserves realized on withdrawal.
+    #[prost(message, optional, tag = "5")]
+    pub reserves: ::core::option::Option<
+        super::super::core::component::dex::v1::Reserves,
+    >,
+    /// The number of blocks in which the position was executed against.
+    #[prost(uint64, tag = "6")]
+    pub executions: u64,
+    /// The cumulative amount of asset 1 swapped into the position.
+    #[prost(message, optional, tag = "7")]
+    pub filled_in_1: ::core::option::Option<super::super::core::num::v1::Amount>,
+    /// The cumulative amount of asset 2 swapped into the position.
+    #[prost(message, optional, tag = "8")]
+    pub filled_in_2: ::core::option::Option<super::super::core::num::v1::Amount>,
+    /// The cumulative amount of asset 1 swapped out of the position.
+    #[prost(message, optional, tag = "9")]
+    pub filled_out_1: ::core::option::Option<super::super::core::num::v1::Amount>,
+    /// The cumulative amount of asset 2 swapped out of the position.
+    #[prost(message, optional, tag = "10")]
+    pub filled_out_2: ::core::option::Option<super::super::core::num::v1::Amount>,
+    /// The fees earned in asset 1, according to the position's fee tier.
+    #[prost(message, optional, tag = "11")]
+    pub fees_1: ::core::option::Option<super::super::core::num::v1::Amount>,
+    /// The fees earned in asset 2, according to the position's fee tier.
+    #[prost(message, optional, tag = "12")]
+    pub fees_2: ::core::option::Option<super::super::core::num::v1::Amount>,
+    /// The height at which the position was withdrawn, or 0 if it has not been.
+    #[prost(uint64, tag = "13")]
+    pub height_withdrawn: u64,
+    /// The height at which the position was evicted by the DEX, or 0 if it has not been.
+    #[prost(uint64, tag = "14")]
+    pub height_evicted: u64,
 }
-impl ::prost::Name for OwnedPositionIdsResponse {
-    const NAME: &'static str = "OwnedPositionIdsResponse";
+impl ::prost::Name for PositionPerformance {
+    const NAME: &'static str = "PositionPerformance";
     const PACKAGE: &'static str = "penumbra.view.v1";
     fn full_name() -> ::prost::alloc::string::String {
         ::prost::alloc::format!("penumbra.view.v1.{}", Self::NAME)
     }
 }
-/// Requests information on an asset by asset id
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
-pub struct AssetMetadataByIdRequest {
-    /// The asset id to request information on.
-    #[prost(message, optional, tag = "2")]
-    pub asset_id: ::core::option::Option<super::super::core::asset::v1::AssetId>,
+pub struct CreateScheduleRequest {
+    /// The request to plan at each occurrence of the schedule.
+    ///
+    /// The expiry height and epoch of the request are ignored, and set at planning time.
+    #[prost(message, optional, tag = "1")]
+    pub plan_request: ::core::option::Option<TransactionPlannerRequest>,
+    /// The height of the first occurrence. If unset, the schedule starts at the next block.
+    ///
+    /// Must be unset for epoch-aligned schedules.
+    #[prost(uint64, tag = "2")]
+    pub start_height: u64,
+    /// The number of blocks between occurrences, or 0 for a one-off transaction.
+    ///
+    /// Ignored for epoch-aligned schedules.
+    #[prost(uint64, tag = "3")]
+    pub interval: u64,
+    /// The maximum number of occurrences, or 0 for no limit.
+    #[prost(uint64, tag = "4")]
+    pub max_occurrences: u64,
+    /// If set, the schedule is executed at the start of every epoch, beginning with the next one.
+    #[prost(bool, tag = "5")]
+    pub every_epoch: bool,
 }
-impl ::prost::Name for AssetMetadataByIdRequest {
-    const NAME: &'static str = "AssetMetadataByIdRequest";
+impl ::prost::Name for CreateScheduleRequest {
+    const NAME: &'static str = "CreateScheduleRequest";
     const PACKAGE: &'static str = "penumbra.view.v1";
     fn full_name() -> ::prost::alloc::string::String {
         ::prost::alloc::format!("penumbra.view.v1.{}", Self::NAME)
@@ -1589,129 +1658,248 @@ impl ::prost::Name for AssetMetadataByIdRequest {
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
-pub struct AssetMetadataByIdResponse {
-    /// If present, information on the requested asset.
-    ///
-    /// If the requested asset was unknown, this field will not be present.
-    #[prost(message, optional, tag = "1")]
-    pub denom_metadata: ::core::option::Option<super::super::core::asset::v1::Metadata>,
+pub struct CreateScheduleResponse {
+    /// The ID of the newly created schedule.
+    #[prost(uint64, tag = "1")]
+    pub schedule_id: u64,
 }
-impl ::prost::Name for AssetMetadataByIdResponse {
-    const NAME: &'static str = "AssetMetadataByIdResponse";
+impl ::prost::Name for CreateScheduleResponse {
+    const NAME: &'static str = "CreateScheduleResponse";
     const PACKAGE: &'static str = "penumbra.view.v1";
     fn full_name() -> ::prost::alloc::string::String {
         ::prost::alloc::format!("penumbra.view.v1.{}", Self::NAME)
     }
 }
-/// Requests `ValueView`s of delegation tokens for the given address index. The
-/// returned `ValueView`s will include the `ValidatorInfo` for the delegated
-/// validator in their `extended_metadata` fields.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
-pub struct DelegationsByAddressIndexRequest {
-    /// The address index to fetch delegation balances for.
+pub struct SchedulesRequest {
+    /// If present, only return the schedules with these IDs.
+    #[prost(uint64, repeated, tag = "1")]
+    pub schedule_ids: ::prost::alloc::vec::Vec<u64>,
+}
+impl ::prost::Name for SchedulesRequest {
+    const NAME: &'static str = "SchedulesRequest";
+    const PACKAGE: &'static str = "penumbra.view.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.view.v1.{}", Self::NAME)
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SchedulesResponse {
     #[prost(message, optional, tag = "1")]
-    pub address_index: ::core::option::Option<
-        super::super::core::keys::v1::AddressIndex,
-    >,
-    #[prost(enumeration = "delegations_by_address_index_request::Filter", tag = "2")]
-    pub filter: i32,
+    pub schedule: ::core::option::Option<ScheduledTransaction>,
+    /// The past executions of the schedule, ordered by height.
+    #[prost(message, repeated, tag = "2")]
+    pub executions: ::prost::alloc::vec::Vec<ScheduledTransactionExecution>,
 }
-/// Nested message and enum types in `DelegationsByAddressIndexRequest`.
-pub mod delegations_by_address_index_request {
-    #[derive(
-        Clone,
-        Copy,
-        Debug,
-        PartialEq,
-        Eq,
-        Hash,
-        PartialOrd,
-        Ord,
-        ::prost::Enumeration
-    )]
-    #[repr(i32)]
-    pub enum Filter {
-        /// By default, returns delegations for all active validators. For validators
-        /// that the given address index has no delegation tokens for, a `ValueView`
-        /// with a balance of `0` will be returned.
-        Unspecified = 0,
-        /// Returns only delegations to active validators that the given address
-        /// index holds delegation tokens for.
-        AllActiveWithNonzeroBalances = 1,
-        /// Return delegations for all validators, whether active or not. For
-        /// validators that the given address index has no delegation tokens for, a
-        /// `ValueView` with a balance of `0` will be returned.
-        All = 2,
+impl ::prost::Name for SchedulesResponse {
+    const NAME: &'static str = "SchedulesResponse";
+    const PACKAGE: &'static str = "penumbra.view.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.view.v1.{}", Self::NAME)
     }
-    impl Filter {
-        /// String value of the enum field names used in the ProtoBuf definition.
-        ///
-        /// The values are not transformed in any way and thus are considered stable
-        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
-        pub fn as_str_name(&self) -> &'static str {
-            match self {
-                Filter::Unspecified => "FILTER_UNSPECIFIED",
-                Filter::AllActiveWithNonzeroBalances => {
-                    "FILTER_ALL_ACTIVE_WITH_NONZERO_BALANCES"
-                }
-                Filter::All => "FILTER_ALL",
-            }
-        }
-        /// Creates an enum from field names used in the ProtoBuf definition.
-        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
-            match value {
-                "FILTER_UNSPECIFIED" => Some(Self::Unspecified),
-                "FILTER_ALL_ACTIVE_WITH_NONZERO_BALANCES" => {
-                    Some(Self::AllActiveWithNonzeroBalances)
-                }
-                "FILTER_ALL" => Some(Self::All),
-                _ => None,
-            }
-        }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct UpdateScheduleStatusRequest {
+    /// The ID of the schedule to update.
+    #[prost(uint64, tag = "1")]
+    pub schedule_id: u64,
+    /// The new status of the schedule: active, paused or cancelled.
+    #[prost(enumeration = "ScheduleStatus", tag = "2")]
+    pub status: i32,
+}
+impl ::prost::Name for UpdateScheduleStatusRequest {
+    const NAME: &'static str = "UpdateScheduleStatusRequest";
+    const PACKAGE: &'static str = "penumbra.view.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.view.v1.{}", Self::NAME)
     }
 }
-impl ::prost::Name for DelegationsByAddressIndexRequest {
-    const NAME: &'static str = "DelegationsByAddressIndexRequest";
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct UpdateScheduleStatusResponse {}
+impl ::prost::Name for UpdateScheduleStatusResponse {
+    const NAME: &'static str = "UpdateScheduleStatusResponse";
     const PACKAGE: &'static str = "penumbra.view.v1";
     fn full_name() -> ::prost::alloc::string::String {
         ::prost::alloc::format!("penumbra.view.v1.{}", Self::NAME)
     }
 }
-/// Contains a `ValueView` of delegation tokens for the requested address index.
-/// The `ValueView` includes the `ValidatorInfo` for the delegated validator in
-/// cits `extended_metadata` field.
+/// A transaction scheduled to be planned and submitted by the view service.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
-pub struct DelegationsByAddressIndexResponse {
+pub struct ScheduledTransaction {
+    /// The ID of the schedule.
+    #[prost(uint64, tag = "1")]
+    pub id: u64,
+    /// The request to plan at each occurrence of the schedule.
+    #[prost(message, optional, tag = "2")]
+    pub plan_request: ::core::option::Option<TransactionPlannerRequest>,
+    /// The height of the first occurrence.
+    #[prost(uint64, tag = "3")]
+    pub start_height: u64,
+    /// The number of blocks between occurrences, or 0 for a one-off transaction.
+    #[prost(uint64, tag = "4")]
+    pub interval: u64,
+    /// The maximum number of occurrences, or 0 for no limit.
+    #[prost(uint64, tag = "5")]
+    pub max_occurrences: u64,
+    /// The number of occurrences executed so far, successful or not.
+    #[prost(uint64, tag = "6")]
+    pub occurrences: u64,
+    /// The height of the next occurrence.
+    ///
+    /// For epoch-aligned schedules, this is the latest height at which the next epoch can start,
+    /// and is brought forward if the current epoch ends early.
+    #[prost(uint64, tag = "7")]
+    pub next_height: u64,
+    #[prost(enumeration = "ScheduleStatus", tag = "8")]
+    pub status: i32,
+    /// Whether the schedule is executed at the start of every epoch. If so, `interval` is the
+    /// epoch duration.
+    #[prost(bool, tag = "9")]
+    pub every_epoch: bool,
+}
+impl ::prost::Name for ScheduledTransaction {
+    const NAME: &'static str = "ScheduledTransaction";
+    const PACKAGE: &'static str = "penumbra.view.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.view.v1.{}", Self::NAME)
+    }
+}
+/// The outcome of one occurrence of a scheduled transaction.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ScheduledTransactionExecution {
+    /// The ID of the schedule.
+    #[prost(uint64, tag = "1")]
+    pub schedule_id: u64,
+    /// The height at which the occurrence was executed.
+    #[prost(uint64, tag = "2")]
+    pub height: u64,
+    /// The ID of the transaction submitted for the occurrence, if it was submitted.
+    #[prost(message, optional, tag = "3")]
+    pub transaction_id: ::core::option::Option<
+        super::super::core::txhash::v1::TransactionId,
+    >,
+    /// The error that prevented the occurrence from completing, if any.
+    ///
+    /// An occurrence can have both a transaction and an error, if its transaction was
+    /// confirmed but the outputs of its swaps could not be claimed.
+    #[prost(string, tag = "4")]
+    pub error: ::prost::alloc::string::String,
+}
+impl ::prost::Name for ScheduledTransactionExecution {
+    const NAME: &'static str = "ScheduledTransactionExecution";
+    const PACKAGE: &'static str = "penumbra.view.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.view.v1.{}", Self::NAME)
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct StakingRewardsRequest {
+    /// If present, only return the rewards from these validators.
+    #[prost(message, repeated, tag = "1")]
+    pub identity_keys: ::prost::alloc::vec::Vec<
+        super::super::core::keys::v1::IdentityKey,
+    >,
+}
+impl ::prost::Name for StakingRewardsRequest {
+    const NAME: &'static str = "StakingRewardsRequest";
+    const PACKAGE: &'static str = "penumbra.view.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.view.v1.{}", Self::NAME)
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct StakingRewardsResponse {
     #[prost(message, optional, tag = "1")]
-    pub value_view: ::core::option::Option<super::super::core::asset::v1::ValueView>,
+    pub rewards: ::core::option::Option<StakingRewards>,
 }
-impl ::prost::Name for DelegationsByAddressIndexResponse {
-    const NAME: &'static str = "DelegationsByAddressIndexResponse";
+impl ::prost::Name for StakingRewardsResponse {
+    const NAME: &'static str = "StakingRewardsResponse";
     const PACKAGE: &'static str = "penumbra.view.v1";
     fn full_name() -> ::prost::alloc::string::String {
         ::prost::alloc::format!("penumbra.view.v1.{}", Self::NAME)
     }
 }
-/// Requests unbonding tokens for a given address index, with optional filtering
-/// for whether the tokens are currently claimable.
+/// The staking rewards earned by the user's wallet from delegations to a validator.
+///
+/// Amounts are denominated in the staking token. Delegation tokens acquired other
+/// than by delegating, e.g. received in a transfer, have no known cost, and are
+/// excluded from the reward accounting.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
-pub struct UnbondingTokensByAddressIndexRequest {
-    #[prost(
-        enumeration = "unbonding_tokens_by_address_index_request::Filter",
-        tag = "1"
-    )]
-    pub filter: i32,
-    /// The address index to fetch unbonding tokens for.
+pub struct StakingRewards {
+    /// The identity key of the validator.
+    #[prost(message, optional, tag = "1")]
+    pub identity_key: ::core::option::Option<super::super::core::keys::v1::IdentityKey>,
+    /// The rate data of the validator in the current epoch.
     #[prost(message, optional, tag = "2")]
-    pub address_index: ::core::option::Option<
-        super::super::core::keys::v1::AddressIndex,
+    pub current_rate_data: ::core::option::Option<
+        super::super::core::component::stake::v1::RateData,
     >,
+    /// The amount of the validator's delegation tokens currently held by the wallet.
+    #[prost(message, optional, tag = "3")]
+    pub delegation_tokens: ::core::option::Option<super::super::core::num::v1::Amount>,
+    /// The value of the held delegation tokens at the current exchange rate.
+    #[prost(message, optional, tag = "4")]
+    pub current_value: ::core::option::Option<super::super::core::num::v1::Amount>,
+    /// The amount of stake originally delegated for the held delegation tokens.
+    #[prost(message, optional, tag = "5")]
+    pub cost_basis: ::core::option::Option<super::super::core::num::v1::Amount>,
+    /// The rewards realized by undelegating or redelegating, before any slashing
+    /// penalty applied when claiming the unbonding tokens.
+    #[prost(message, optional, tag = "6")]
+    pub realized_rewards: ::core::option::Option<super::super::core::num::v1::Amount>,
+    /// The rewards accrued by the held delegation tokens.
+    #[prost(message, optional, tag = "7")]
+    pub unrealized_rewards: ::core::option::Option<super::super::core::num::v1::Amount>,
+    /// The projected annual rate of return of a delegation to the validator, in
+    /// basis points, annualized from the growth of its exchange rate over recent epochs.
+    ///
+    /// Epochs are timed from the chain's recent block times, assuming they don't end early.
+    #[prost(uint64, tag = "8")]
+    pub projected_apr_bps: u64,
+    /// The wallet's delegations and undelegations, in the order they were made.
+    #[prost(message, repeated, tag = "9")]
+    pub history: ::prost::alloc::vec::Vec<StakingRewardsEvent>,
 }
-/// Nested message and enum types in `UnbondingTokensByAddressIndexRequest`.
-pub mod unbonding_tokens_by_address_index_request {
+impl ::prost::Name for StakingRewards {
+    const NAME: &'static str = "StakingRewards";
+    const PACKAGE: &'static str = "penumbra.view.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.view.v1.{}", Self::NAME)
+    }
+}
+/// A change in the wallet's delegation to a validator.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct StakingRewardsEvent {
+    #[prost(enumeration = "staking_rewards_event::Kind", tag = "1")]
+    pub kind: i32,
+    /// The height of the transaction.
+    #[prost(uint64, tag = "2")]
+    pub height: u64,
+    /// The epoch whose exchange rate applied to the change.
+    #[prost(uint64, tag = "3")]
+    pub epoch_index: u64,
+    /// The amount of delegation tokens acquired or disposed of.
+    #[prost(message, optional, tag = "4")]
+    pub delegation_amount: ::core::option::Option<super::super::core::num::v1::Amount>,
+    /// The amount of stake they were worth.
+    #[prost(message, optional, tag = "5")]
+    pub unbonded_amount: ::core::option::Option<super::super::core::num::v1::Amount>,
+    /// For undelegations, the rewards realized.
+    #[prost(message, optional, tag = "6")]
+    pub realized_rewards: ::core::option::Option<super::super::core::num::v1::Amount>,
+}
+/// Nested message and enum types in `StakingRewardsEvent`.
+pub mod staking_rewards_event {
     #[derive(
         Clone,
         Copy,
@@ -1724,70 +1912,673 @@ pub mod unbonding_tokens_by_address_index_request {
         ::prost::Enumeration
     )]
     #[repr(i32)]
-    pub enum Filter {
-        /// Return all unbonding tokens, regardless of whether they're claimable
-        /// right now.
+    pub enum Kind {
         Unspecified = 0,
-        /// Return all unbonding tokens that are currently claimable. This includes:
-        ///
-        /// * tokens that have passed the `unbonding_delay` (from `StakeParameters`)
-        /// * tokens for unbonded validators
-        Claimable = 1,
-        /// Return all unbonding tokens that are not yet claimable, because they are
-        /// still in the `unbonding_delay` (from `StakeParameters`) period.
-        NotYetClaimable = 2,
+        /// Delegation tokens were acquired, by delegating or redelegating to the validator.
+        Delegation = 1,
+        /// Delegation tokens were disposed of, by undelegating or redelegating away from the validator.
+        Undelegation = 2,
     }
-    impl Filter {
+    impl Kind {
         /// String value of the enum field names used in the ProtoBuf definition.
         ///
         /// The values are not transformed in any way and thus are considered stable
         /// (if the ProtoBuf definition does not change) and safe for programmatic use.
         pub fn as_str_name(&self) -> &'static str {
             match self {
-                Filter::Unspecified => "FILTER_UNSPECIFIED",
-                Filter::Claimable => "FILTER_CLAIMABLE",
-                Filter::NotYetClaimable => "FILTER_NOT_YET_CLAIMABLE",
+                Kind::Unspecified => "KIND_UNSPECIFIED",
+                Kind::Delegation => "KIND_DELEGATION",
+                Kind::Undelegation => "KIND_UNDELEGATION",
             }
         }
         /// Creates an enum from field names used in the ProtoBuf definition.
         pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
             match value {
-                "FILTER_UNSPECIFIED" => Some(Self::Unspecified),
-                "FILTER_CLAIMABLE" => Some(Self::Claimable),
-                "FILTER_NOT_YET_CLAIMABLE" => Some(Self::NotYetClaimable),
+                "KIND_UNSPECIFIED" => Some(Self::Unspecified),
+                "KIND_DELEGATION" => Some(Self::Delegation),
+                "KIND_UNDELEGATION" => Some(Self::Undelegation),
                 _ => None,
             }
         }
     }
 }
-impl ::prost::Name for UnbondingTokensByAddressIndexRequest {
-    const NAME: &'static str = "UnbondingTokensByAddressIndexRequest";
+impl ::prost::Name for StakingRewardsEvent {
+    const NAME: &'static str = "StakingRewardsEvent";
     const PACKAGE: &'static str = "penumbra.view.v1";
     fn full_name() -> ::prost::alloc::string::String {
         ::prost::alloc::format!("penumbra.view.v1.{}", Self::NAME)
     }
 }
-/// Returns unbonding tokens for the given address index, optionally filtered by
-/// whether the tokens are currently claimable.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GovernanceFeedRequest {
+    /// Whether to include proposals that are no longer voting.
+    #[prost(bool, tag = "1")]
+    pub inactive: bool,
+    /// If set, only return proposals the wallet can act on: those it can still vote
+    /// on, and those whose deposit it can claim.
+    #[prost(bool, tag = "2")]
+    pub actionable_only: bool,
+    /// If set, record the current state of the returned proposals as seen, so that
+    /// later requests only flag proposals whose state has changed since.
+    #[prost(bool, tag = "3")]
+    pub mark_seen: bool,
+}
+impl ::prost::Name for GovernanceFeedRequest {
+    const NAME: &'static str = "GovernanceFeedRequest";
+    const PACKAGE: &'static str = "penumbra.view.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.view.v1.{}", Self::NAME)
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GovernanceFeedResponse {
+    #[prost(message, optional, tag = "1")]
+    pub item: ::core::option::Option<GovernanceFeedItem>,
+}
+impl ::prost::Name for GovernanceFeedResponse {
+    const NAME: &'static str = "GovernanceFeedResponse";
+    const PACKAGE: &'static str = "penumbra.view.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.view.v1.{}", Self::NAME)
+    }
+}
+/// A governance proposal, from the perspective of the user's wallet.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GovernanceFeedItem {
+    /// The proposal.
+    #[prost(message, optional, tag = "1")]
+    pub proposal: ::core::option::Option<
+        super::super::core::component::governance::v1::Proposal,
+    >,
+    /// The current state of the proposal.
+    #[prost(message, optional, tag = "2")]
+    pub state: ::core::option::Option<
+        super::super::core::component::governance::v1::ProposalState,
+    >,
+    /// The block height at which the proposal started voting.
+    #[prost(uint64, tag = "3")]
+    pub start_block_height: u64,
+    /// The block height at which the proposal ends voting.
+    #[prost(uint64, tag = "4")]
+    pub end_block_height: u64,
+    /// The number of the wallet's notes that are eligible to vote on the proposal
+    /// and have not yet voted on it, if the proposal is still voting.
+    #[prost(uint64, tag = "5")]
+    pub votable_notes: u64,
+    /// The voting power of those notes, in the staking token.
+    #[prost(message, optional, tag = "6")]
+    pub votable_power: ::core::option::Option<super::super::core::num::v1::Amount>,
+    /// The votes cast by the wallet as a delegator on the proposal.
+    #[prost(message, repeated, tag = "7")]
+    pub delegator_votes: ::prost::alloc::vec::Vec<
+        super::super::core::component::governance::v1::Vote,
+    >,
+    /// The vote cast by the wallet as a proxy on the proposal, if any.
+    #[prost(message, optional, tag = "8")]
+    pub proxy_vote: ::core::option::Option<
+        super::super::core::component::governance::v1::Vote,
+    >,
+    /// The deposit the wallet can claim with a `ProposalDepositClaim`, if the
+    /// proposal is finished and the wallet holds its deposit NFT.
+    #[prost(message, optional, tag = "9")]
+    pub claimable_deposit: ::core::option::Option<super::super::core::num::v1::Amount>,
+    /// Whether the proposal's state has changed since the feed was last marked as
+    /// seen. This is always set the first time a proposal appears in the feed.
+    #[prost(bool, tag = "10")]
+    pub state_changed: bool,
+}
+impl ::prost::Name for GovernanceFeedItem {
+    const NAME: &'static str = "GovernanceFeedItem";
+    const PACKAGE: &'static str = "penumbra.view.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.view.v1.{}", Self::NAME)
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Ics20WithdrawalsRequest {
+    /// If present, only return the withdrawals made in this transaction.
+    #[prost(message, optional, tag = "1")]
+    pub transaction_id: ::core::option::Option<
+        super::super::core::txhash::v1::TransactionId,
+    >,
+    /// Whether to include withdrawals that were acknowledged or refunded.
+    #[prost(bool, tag = "2")]
+    pub settled: bool,
+}
+impl ::prost::Name for Ics20WithdrawalsRequest {
+    const NAME: &'static str = "Ics20WithdrawalsRequest";
+    const PACKAGE: &'static str = "penumbra.view.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.view.v1.{}", Self::NAME)
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Ics20WithdrawalsResponse {
+    #[prost(message, optional, tag = "1")]
+    pub withdrawal: ::core::option::Option<Ics20WithdrawalRecord>,
+}
+impl ::prost::Name for Ics20WithdrawalsResponse {
+    const NAME: &'static str = "Ics20WithdrawalsResponse";
+    const PACKAGE: &'static str = "penumbra.view.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.view.v1.{}", Self::NAME)
+    }
+}
+/// An ICS-20 withdrawal sent by the user's wallet.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Ics20WithdrawalRecord {
+    /// The withdrawal action.
+    #[prost(message, optional, tag = "1")]
+    pub withdrawal: ::core::option::Option<
+        super::super::core::component::ibc::v1::Ics20Withdrawal,
+    >,
+    /// The ID of the transaction containing the withdrawal.
+    #[prost(message, optional, tag = "2")]
+    pub transaction_id: ::core::option::Option<
+        super::super::core::txhash::v1::TransactionId,
+    >,
+    /// The height at which the withdrawal was sent.
+    #[prost(uint64, tag = "3")]
+    pub height_sent: u64,
+    /// The sequence number of the withdrawal's packet on its channel, or 0 if it is
+    /// not known.
+    #[prost(uint64, tag = "4")]
+    pub sequence: u64,
+    #[prost(enumeration = "Ics20WithdrawalStatus", tag = "5")]
+    pub status: i32,
+    /// The height at which the withdrawal was acknowledged or refunded, or 0 if it
+    /// has not been.
+    #[prost(uint64, tag = "6")]
+    pub height_settled: u64,
+    /// The commitment of the note refunding the withdrawal, if it was refunded.
+    #[prost(message, optional, tag = "7")]
+    pub refund_note_commitment: ::core::option::Option<
+        super::super::crypto::tct::v1::StateCommitment,
+    >,
+}
+impl ::prost::Name for Ics20WithdrawalRecord {
+    const NAME: &'static str = "Ics20WithdrawalRecord";
+    const PACKAGE: &'static str = "penumbra.view.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.view.v1.{}", Self::NAME)
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct OwnedPositionIdsRequest {
+    /// If present, return only positions with this position state.
+    #[prost(message, optional, tag = "1")]
+    pub position_state: ::core::option::Option<
+        super::super::core::component::dex::v1::PositionState,
+    >,
+    /// If present, return only positions for this trading pair.
+    #[prost(message, optional, tag = "2")]
+    pub trading_pair: ::core::option::Option<
+        super::super::core::component::dex::v1::TradingPair,
+    >,
+    /// If present, return only positions for this subaccount index.
+    #[prost(message, optional, tag = "3")]
+    pub subaccount: ::core::option::Option<super::super::core::keys::v1::AddressIndex>,
+}
+impl ::prost::Name for OwnedPositionIdsRequest {
+    const NAME: &'static str = "OwnedPositionIdsRequest";
+    const PACKAGE: &'static str = "penumbra.view.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.view.v1.{}", Self::NAME)
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct OwnedPositionIdsResponse {
+    #[prost(message, optional, tag = "1")]
+    pub position_id: ::core::option::Option<
+        super::super::core::component::dex::v1::PositionId,
+    >,
+    /// The subaccount this position belongs to.
+    #[prost(message, optional, tag = "2")]
+    pub subaccount: ::core::option::Option<super::super::core::keys::v1::AddressIndex>,
+}
+impl ::prost::Name for OwnedPositionIdsResponse {
+    const NAME: &'static str = "OwnedPositionIdsResponse";
+    const PACKAGE: &'static str = "penumbra.view.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.view.v1.{}", Self::NAME)
+    }
+}
+/// Requests information on an asset by asset id
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AssetMetadataByIdRequest {
+    /// The asset id to request information on.
+    #[prost(message, optional, tag = "2")]
+    pub asset_id: ::core::option::Option<super::super::core::asset::v1::AssetId>,
+}
+impl ::prost::Name for AssetMetadataByIdRequest {
+    const NAME: &'static str = "AssetMetadataByIdRequest";
+    const PACKAGE: &'static str = "penumbra.view.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.view.v1.{}", Self::NAME)
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AssetMetadataByIdResponse {
+    /// If present, information on the requested asset.
+    ///
+    /// If the requested asset was unknown, this field will not be present.
+    #[prost(message, optional, tag = "1")]
+    pub denom_metadata: ::core::option::Option<super::super::core::asset::v1::Metadata>,
+}
+impl ::prost::Name for AssetMetadataByIdResponse {
+    const NAME: &'static str = "AssetMetadataByIdResponse";
+    const PACKAGE: &'static str = "penumbra.view.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.view.v1.{}", Self::NAME)
+    }
+}
+/// Requests `ValueView`s of delegation tokens for the given address index. The
+/// returned `ValueView`s will include the `ValidatorInfo` for the delegated
+/// validator in their `extended_metadata` fields.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct DelegationsByAddressIndexRequest {
+    /// The address index to fetch delegation balances for.
+    #[prost(message, optional, tag = "1")]
+    pub address_index: ::core::option::Option<
+        super::super::core::keys::v1::AddressIndex,
+    >,
+    #[prost(enumeration = "delegations_by_address_index_request::Filter", tag = "2")]
+    pub filter: i32,
+}
+/// Nested message and enum types in `DelegationsByAddressIndexRequest`.
+pub mod delegations_by_address_index_request {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Filter {
+        /// By default, returns delegations for all active validators. For validators
+        /// that the given address index has no delegation tokens for, a `ValueView`
+        /// with a balance of `0` will be returned.
+        Unspecified = 0,
+        /// Returns only delegations to active validators that the given address
+        /// index holds delegation tokens for.
+        AllActiveWithNonzeroBalances = 1,
+        /// Return delegations for all validators, whether active or not. For
+        /// validators that the given address index has no delegation tokens for, a
+        /// `ValueView` with a balance of `0` will be returned.
+        All = 2,
+    }
+    impl Filter {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Filter::Unspecified => "FILTER_UNSPECIFIED",
+                Filter::AllActiveWithNonzeroBalances => {
+                    "FILTER_ALL_ACTIVE_WITH_NONZERO_BALANCES"
+                }
+                Filter::All => "FILTER_ALL",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "FILTER_UNSPECIFIED" => Some(Self::Unspecified),
+                "FILTER_ALL_ACTIVE_WITH_NONZERO_BALANCES" => {
+                    Some(Self::AllActiveWithNonzeroBalances)
+                }
+                "FILTER_ALL" => Some(Self::All),
+                _ => None,
+            }
+        }
+    }
+}
+impl ::prost::Name for DelegationsByAddressIndexRequest {
+    const NAME: &'static str = "DelegationsByAddressIndexRequest";
+    const PACKAGE: &'static str = "penumbra.view.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.view.v1.{}", Self::NAME)
+    }
+}
+/// Contains a `ValueView` of delegation tokens for the requested address index.
+/// The `ValueView` includes the `ValidatorInfo` for the delegated validator in
+/// cits `extended_metadata` field.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct DelegationsByAddressIndexResponse {
+    #[prost(message, optional, tag = "1")]
+    pub value_view: ::core::option::Option<super::super::core::asset::v1::ValueView>,
+}
+impl ::prost::Name for DelegationsByAddressIndexResponse {
+    const NAME: &'static str = "DelegationsByAddressIndexResponse";
+    const PACKAGE: &'static str = "penumbra.view.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.view.v1.{}", Self::NAME)
+    }
+}
+/// Requests unbonding tokens for a given address index, with optional filtering
+/// for whether the tokens are currently claimable.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct UnbondingTokensByAddressIndexRequest {
+    #[prost(
+        enumeration = "unbonding_tokens_by_address_index_request::Filter",
+        tag = "1"
+    )]
+    pub filter: i32,
+    /// The address index to fetch unbonding tokens for.
+    #[prost(message, optional, tag = "2")]
+    pub address_index: ::core::option::Option<
+        super::super::core::keys::v1::AddressIndex,
+    >,
+}
+/// Nested message and enum types in `UnbondingTokensByAddressIndexRequest`.
+pub mod unbonding_tokens_by_address_index_request {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Filter {
+        /// Return all unbonding tokens, regardless of whether they're claimable
+        /// right now.
+        Unspecified = 0,
+        /// Return all unbonding tokens that are currently claimable. This includes:
+        ///
+        /// * tokens that have passed the `unbonding_delay` (from `StakeParameters`)
+        /// * tokens for unbonded validators
+        Claimable = 1,
+        /// Return all unbonding tokens that are not yet claimable, because they are
+        /// still in the `unbonding_delay` (from `StakeParameters`) period.
+        NotYetClaimable = 2,
+    }
+    impl Filter {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Filter::Unspecified => "FILTER_UNSPECIFIED",
+                Filter::Claimable => "FILTER_CLAIMABLE",
+                Filter::NotYetClaimable => "FILTER_NOT_YET_CLAIMABLE",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "FILTER_UNSPECIFIED" => Some(Self::Unspecified),
+                "FILTER_CLAIMABLE" => Some(Self::Claimable),
+                "FILTER_NOT_YET_CLAIMABLE" => Some(Self::NotYetClaimable),
+                _ => None,
+            }
+        }
+    }
+}
+impl ::prost::Name for UnbondingTokensByAddressIndexRequest {
+    const NAME: &'static str = "UnbondingTokensByAddressIndexRequest";
+    const PACKAGE: &'static str = "penumbra.view.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.view.v1.{}", Self::NAME)
+    }
+}
+/// Returns unbonding tokens for the given address index, optionally filtered by
+/// whether the tokens are currently claimable.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct UnbondingTokensByAddressIndexResponse {
     /// A `ValueView` representing the amount of the given unbonding token.
     #[prost(message, optional, tag = "1")]
-    pub value_view: ::core::option::Option<super::super::core::asset::v1::ValueView>,
-    /// Whether the unbonding token is currently claimable. This will only be
-    /// `true` if the `unbonding_delay` (from `StakeParameters`) has passed or the
-    /// validator has unbonded.
+    pub value_view: ::core::option::Option<super::super::core::asset::v1::ValueView>,
+    /// Whether the unbonding token is currently claimable. This will only be
+    /// `true` if the `unbonding_delay` (from `StakeParameters`) has passed or the
+    /// validator has unbonded.
+    #[prost(bool, tag = "2")]
+    pub claimable: bool,
+}
+impl ::prost::Name for UnbondingTokensByAddressIndexResponse {
+    const NAME: &'static str = "UnbondingTokensByAddressIndexResponse";
+    const PACKAGE: &'static str = "penumbra.view.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.view.v1.{}", Self::NAME)
+    }
+}
+/// A forwarding account on a counterparty chain, which forwards its deposits over
+/// IBC to one of the wallet's addresses.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ForwardingAccount {
+    /// The chain ID of the counterparty chain.
+    #[prost(string, tag = "1")]
+    pub chain_id: ::prost::alloc::string::String,
+    /// The gRPC endpoint of a counterparty chain node.
+    #[prost(string, tag = "2")]
+    pub node: ::prost::alloc::string::String,
+    /// The counterparty chain's IBC channel to Penumbra.
+    #[prost(string, tag = "3")]
+    pub channel: ::prost::alloc::string::String,
+    /// The address index of the wallet's address receiving the forwarded deposits.
+    #[prost(message, optional, tag = "4")]
+    pub address_index: ::core::option::Option<
+        super::super::core::keys::v1::AddressIndex,
+    >,
+    /// The address of the forwarding account on the counterparty chain.
+    #[prost(string, tag = "5")]
+    pub forwarding_address: ::prost::alloc::string::String,
+    /// Whether the forwarding account has been registered on the counterparty chain.
+    #[prost(bool, tag = "6")]
+    pub registered: bool,
+}
+impl ::prost::Name for ForwardingAccount {
+    const NAME: &'static str = "ForwardingAccount";
+    const PACKAGE: &'static str = "penumbra.view.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.view.v1.{}", Self::NAME)
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AddForwardingAccountRequest {
+    /// The chain ID of the counterparty chain.
+    #[prost(string, tag = "1")]
+    pub chain_id: ::prost::alloc::string::String,
+    /// The gRPC endpoint of a counterparty chain node.
+    #[prost(string, tag = "2")]
+    pub node: ::prost::alloc::string::String,
+    /// The counterparty chain's IBC channel to Penumbra.
+    #[prost(string, tag = "3")]
+    pub channel: ::prost::alloc::string::String,
+    /// The address index of the wallet's address to forward deposits to.
+    #[prost(message, optional, tag = "4")]
+    pub address_index: ::core::option::Option<
+        super::super::core::keys::v1::AddressIndex,
+    >,
+    /// Whether the forwarding account is already registered on the counterparty chain.
+    #[prost(bool, tag = "5")]
+    pub registered: bool,
+}
+impl ::prost::Name for AddForwardingAccountRequest {
+    const NAME: &'static str = "AddForwardingAccountRequest";
+    const PACKAGE: &'static str = "penumbra.view.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.view.v1.{}", Self::NAME)
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AddForwardingAccountResponse {
+    #[prost(message, optional, tag = "1")]
+    pub account: ::core::option::Option<ForwardingAccount>,
+}
+impl ::prost::Name for AddForwardingAccountResponse {
+    const NAME: &'static str = "AddForwardingAccountResponse";
+    const PACKAGE: &'static str = "penumbra.view.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.view.v1.{}", Self::NAME)
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ForwardingAccountsRequest {
+    /// If present, only return the forwarding accounts on this chain.
+    #[prost(string, tag = "1")]
+    pub chain_id: ::prost::alloc::string::String,
+    /// Whether to query the counterparty chains for the deposits pending in each account.
     #[prost(bool, tag = "2")]
-    pub claimable: bool,
+    pub check_pending: bool,
 }
-impl ::prost::Name for UnbondingTokensByAddressIndexResponse {
-    const NAME: &'static str = "UnbondingTokensByAddressIndexResponse";
+impl ::prost::Name for ForwardingAccountsRequest {
+    const NAME: &'static str = "ForwardingAccountsRequest";
+    const PACKAGE: &'static str = "penumbra.view.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.view.v1.{}", Self::NAME)
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ForwardingAccountsResponse {
+    #[prost(message, optional, tag = "1")]
+    pub account: ::core::option::Option<ForwardingAccount>,
+    /// The deposits in the account that have yet to be forwarded.
+    #[prost(message, repeated, tag = "2")]
+    pub pending_deposits: ::prost::alloc::vec::Vec<
+        forwarding_accounts_response::Deposit,
+    >,
+}
+/// Nested message and enum types in `ForwardingAccountsResponse`.
+pub mod forwarding_accounts_response {
+    /// A funds deposit into a forwarding account, denominated in the counterparty chain's denom.
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct Deposit {
+        #[prost(string, tag = "1")]
+        pub denom: ::prost::alloc::string::String,
+        #[prost(string, tag = "2")]
+        pub amount: ::prost::alloc::string::String,
+    }
+    impl ::prost::Name for Deposit {
+        const NAME: &'static str = "Deposit";
+        const PACKAGE: &'static str = "penumbra.view.v1";
+        fn full_name() -> ::prost::alloc::string::String {
+            ::prost::alloc::format!(
+                "penumbra.view.v1.ForwardingAccountsResponse.{}", Self::NAME
+            )
+        }
+    }
+}
+impl ::prost::Name for ForwardingAccountsResponse {
+    const NAME: &'static str = "ForwardingAccountsResponse";
     const PACKAGE: &'static str = "penumbra.view.v1";
     fn full_name() -> ::prost::alloc::string::String {
         ::prost::alloc::format!("penumbra.view.v1.{}", Self::NAME)
     }
 }
+/// The status of a scheduled transaction.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum ScheduleStatus {
+    Unspecified = 0,
+    /// The schedule will be executed when its next height arrives.
+    Active = 1,
+    /// The schedule is skipped until it is resumed.
+    Paused = 2,
+    /// The schedule was cancelled, and will never be executed again.
+    Cancelled = 3,
+    /// The schedule reached its maximum number of occurrences.
+    Completed = 4,
+}
+impl ScheduleStatus {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            ScheduleStatus::Unspecified => "SCHEDULE_STATUS_UNSPECIFIED",
+            ScheduleStatus::Active => "SCHEDULE_STATUS_ACTIVE",
+            ScheduleStatus::Paused => "SCHEDULE_STATUS_PAUSED",
+            ScheduleStatus::Cancelled => "SCHEDULE_STATUS_CANCELLED",
+            ScheduleStatus::Completed => "SCHEDULE_STATUS_COMPLETED",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "SCHEDULE_STATUS_UNSPECIFIED" => Some(Self::Unspecified),
+            "SCHEDULE_STATUS_ACTIVE" => Some(Self::Active),
+            "SCHEDULE_STATUS_PAUSED" => Some(Self::Paused),
+            "SCHEDULE_STATUS_CANCELLED" => Some(Self::Cancelled),
+            "SCHEDULE_STATUS_COMPLETED" => Some(Self::Completed),
+            _ => None,
+        }
+    }
+}
+/// The status of an ICS-20 withdrawal sent by the user's wallet.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum Ics20WithdrawalStatus {
+    Unspecified = 0,
+    /// The withdrawal is in flight, awaiting an acknowledgement or a timeout.
+    Pending = 1,
+    /// The counterparty acknowledged receiving the withdrawal.
+    Acknowledged = 2,
+    /// The withdrawal's timeout has passed without it being acknowledged, and its
+    /// funds will be refunded once a relayer delivers the timeout.
+    TimedOut = 3,
+    /// The withdrawal timed out or failed on the counterparty, and its funds were
+    /// refunded to its return address.
+    Refunded = 4,
+}
+impl Ics20WithdrawalStatus {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            Ics20WithdrawalStatus::Unspecified => "ICS20_WITHDRAWAL_STATUS_UNSPECIFIED",
+            Ics20WithdrawalStatus::Pending => "ICS20_WITHDRAWAL_STATUS_PENDING",
+            Ics20WithdrawalStatus::Acknowledged => "ICS20_WITHDRAWAL_STATUS_ACKNOWLEDGED",
+            Ics20WithdrawalStatus::TimedOut => "ICS20_WITHDRAWAL_STATUS_TIMED_OUT",
+            Ics20WithdrawalStatus::Refunded => "ICS20_WITHDRAWAL_STATUS_REFUNDED",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "ICS20_WITHDRAWAL_STATUS_UNSPECIFIED" => Some(Self::Unspecified),
+            "ICS20_WITHDRAWAL_STATUS_PENDING" => Some(Self::Pending),
+            "ICS20_WITHDRAWAL_STATUS_ACKNOWLEDGED" => Some(Self::Acknowledged),
+            "ICS20_WITHDRAWAL_STATUS_TIMED_OUT" => Some(Self::TimedOut),
+            "ICS20_WITHDRAWAL_STATUS_REFUNDED" => Some(Self::Refunded),
+            _ => None,
+        }
+    }
+}
 /// Generated client implementations.
 #[cfg(feature = "rpc")]
 pub mod view_service_client {
@@ -2751,6 +3542,283 @@ pub mod view_service_client {
                 .insert(GrpcMethod::new("penumbra.view.v1.ViewService", "Auctions"));
             self.inner.server_streaming(req, path, codec).await
         }
+        /// Gets the performance of the liquidity positions controlled by the user's wallet,
+        /// as computed from the executions recorded against them since they were opened.
+        pub async fn position_performance(
+            &mut self,
+            request: impl tonic::IntoRequest<super::PositionPerformanceRequest>,
+        ) -> std::result::Result<
+            tonic::Response<tonic::codec::Streaming<super::PositionPerformanceResponse>>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/penumbra.view.v1.ViewService/PositionPerformance",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "penumbra.view.v1.ViewService",
+                        "PositionPerformance",
+                    ),
+                );
+            self.inner.server_streaming(req, path, codec).await
+        }
+        /// Schedules a transaction to be planned and submitted at a future height,
+        /// optionally recurring at a fixed interval.
+        ///
+        /// Scheduled transactions are only executed by view servers that have access
+        /// to a custody service, such as `pclientd` in custody mode.
+        pub async fn create_schedule(
+            &mut self,
+            request: impl tonic::IntoRequest<super::CreateScheduleRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::CreateScheduleResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/penumbra.view.v1.ViewService/CreateSchedule",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new("penumbra.view.v1.ViewService", "CreateSchedule"),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// Gets the scheduled transactions stored by the view service, along with
+        /// the outcome of each of their past executions.
+        pub async fn schedules(
+            &mut self,
+            request: impl tonic::IntoRequest<super::SchedulesRequest>,
+        ) -> std::result::Result<
+            tonic::Response<tonic::codec::Streaming<super::SchedulesResponse>>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/penumbra.view.v1.ViewService/Schedules",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("penumbra.view.v1.ViewService", "Schedules"));
+            self.inner.server_streaming(req, path, codec).await
+        }
+        /// Pauses, resumes or cancels a scheduled transaction.
+        pub async fn update_schedule_status(
+            &mut self,
+            request: impl tonic::IntoRequest<super::UpdateScheduleStatusRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::UpdateScheduleStatusResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/penumbra.view.v1.ViewService/UpdateScheduleStatus",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "penumbra.view.v1.ViewService",
+                        "UpdateScheduleStatus",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// Gets the staking rewards earned by the user's wallet from each validator it has
+        /// delegated to, as computed from the wallet's delegation history and the validators'
+        /// current rate data, along with a projection of the annual rate of return.
+        pub async fn staking_rewards(
+            &mut self,
+            request: impl tonic::IntoRequest<super::StakingRewardsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<tonic::codec::Streaming<super::StakingRewardsResponse>>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/penumbra.view.v1.ViewService/StakingRewards",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new("penumbra.view.v1.ViewService", "StakingRewards"),
+                );
+            self.inner.server_streaming(req, path, codec).await
+        }
+        /// Gets the governance proposals on chain, annotated with the user's wallet's
+        /// relationship to each: the voting power it can still vote with, whether it has
+        /// already voted, whether it can claim back a proposal deposit, and whether the
+        /// proposal's state has changed since the feed was last marked as seen.
+        pub async fn governance_feed(
+            &mut self,
+            request: impl tonic::IntoRequest<super::GovernanceFeedRequest>,
+        ) -> std::result::Result<
+            tonic::Response<tonic::codec::Streaming<super::GovernanceFeedResponse>>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/penumbra.view.v1.ViewService/GovernanceFeed",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new("penumbra.view.v1.ViewService", "GovernanceFeed"),
+                );
+            self.inner.server_streaming(req, path, codec).await
+        }
+        /// Gets the ICS-20 withdrawals sent by the user's wallet, along with the status
+        /// of each: whether it is still in flight, was acknowledged by the counterparty,
+        /// has timed out, or was refunded to its return address.
+        pub async fn ics20_withdrawals(
+            &mut self,
+            request: impl tonic::IntoRequest<super::Ics20WithdrawalsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<tonic::codec::Streaming<super::Ics20WithdrawalsResponse>>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/penumbra.view.v1.ViewService/Ics20Withdrawals",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new("penumbra.view.v1.ViewService", "Ics20Withdrawals"),
+                );
+            self.inner.server_streaming(req, path, codec).await
+        }
+        /// Adds a forwarding account on a counterparty chain, such as a Noble forwarding
+        /// account, to the wallet's registry. Deposits into a forwarding account are
+        /// forwarded over IBC to one of the wallet's addresses once the account is
+        /// registered on the counterparty chain, which the view service does
+        /// automatically when it detects a deposit.
+        pub async fn add_forwarding_account(
+            &mut self,
+            request: impl tonic::IntoRequest<super::AddForwardingAccountRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::AddForwardingAccountResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/penumbra.view.v1.ViewService/AddForwardingAccount",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "penumbra.view.v1.ViewService",
+                        "AddForwardingAccount",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// Gets the forwarding accounts in the wallet's registry, optionally along with
+        /// the deposits pending in each on its counterparty chain.
+        pub async fn forwarding_accounts(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ForwardingAccountsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<tonic::codec::Streaming<super::ForwardingAccountsResponse>>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/penumbra.view.v1.ViewService/ForwardingAccounts",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new("penumbra.view.v1.ViewService", "ForwardingAccounts"),
+                );
+            self.inner.server_streaming(req, path, codec).await
+        }
     }
 }
 /// Generated server implementations.
@@ -3118,6 +4186,138 @@ pub mod view_service_server {
             &self,
             request: tonic::Request<super::AuctionsRequest>,
         ) -> std::result::Result<tonic::Response<Self::AuctionsStream>, tonic::Status>;
+        /// Server streaming response type for the PositionPerformance method.
+        type PositionPerformanceStream: tonic::codegen::tokio_stream::Stream<
+                Item = std::result::Result<
+                    super::PositionPerformanceResponse,
+                    tonic::Status,
+                >,
+            >
+            + Send
+            + 'static;
+        /// Gets the performance of the liquidity positions controlled by the user's wallet,
+        /// as computed from the executions recorded against them since they were opened.
+        async fn position_performance(
+            &self,
+            request: tonic::Request<super::PositionPerformanceRequest>,
+        ) -> std::result::Result<
+            tonic::Response<Self::PositionPerformanceStream>,
+            tonic::Status,
+        >;
+        /// Schedules a transaction to be planned and submitted at a future height,
+        /// optionally recurring at a fixed interval.
+        ///
+        /// Scheduled transactions are only executed by view servers that have access
+        /// to a custody service, such as `pclientd` in custody mode.
+        async fn create_schedule(
+            &self,
+            request: tonic::Request<super::CreateScheduleRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::CreateScheduleResponse>,
+            tonic::Status,
+        >;
+        /// Server streaming response type for the Schedules method.
+        type SchedulesStream: tonic::codegen::tokio_stream::Stream<
+                Item = std::result::Result<super::SchedulesResponse, tonic::Status>,
+            >
+            + Send
+            + 'static;
+        /// Gets the scheduled transactions stored by the view service, along with
+        /// the outcome of each of their past executions.
+        async fn schedules(
+            &self,
+            request: tonic::Request<super::SchedulesRequest>,
+        ) -> std::result::Result<tonic::Response<Self::SchedulesStream>, tonic::Status>;
+        /// Pauses, resumes or cancels a scheduled transaction.
+        async fn update_schedule_status(
+            &self,
+            request: tonic::Request<super::UpdateScheduleStatusRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::UpdateScheduleStatusResponse>,
+            tonic::Status,
+        >;
+        /// Server streaming response type for the StakingRewards method.
+        type StakingRewardsStream: tonic::codegen::tokio_stream::Stream<
+                Item = std::result::Result<super::StakingRewardsResponse, tonic::Status>,
+            >
+            + Send
+            + 'static;
+        /// Gets the staking rewards earned by the user's wallet from each validator it has
+        /// delegated to, as computed from the wallet's delegation history and the validators'
+        /// current rate data, along with a projection of the annual rate of return.
+        async fn staking_rewards(
+            &self,
+            request: tonic::Request<super::StakingRewardsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<Self::StakingRewardsStream>,
+            tonic::Status,
+        >;
+        /// Server streaming response type for the GovernanceFeed method.
+        type GovernanceFeedStream: tonic::codegen::tokio_stream::Stream<
+                Item = std::result::Result<super::GovernanceFeedResponse, tonic::Status>,
+            >
+            + Send
+            + 'static;
+        /// Gets the governance proposals on chain, annotated with the user's wallet's
+        /// relationship to each: the voting power it can still vote with, whether it has
+        /// already voted, whether it can claim back a proposal deposit, and whether the
+        /// proposal's state has changed since the feed was last marked as seen.
+        async fn governance_feed(
+            &self,
+            request: tonic::Request<super::GovernanceFeedRequest>,
+        ) -> std::result::Result<
+            tonic::Response<Self::GovernanceFeedStream>,
+            tonic::Status,
+        >;
+        /// Server streaming response type for the Ics20Withdrawals method.
+        type Ics20WithdrawalsStream: tonic::codegen::tokio_stream::Stream<
+                Item = std::result::Result<
+                    super::Ics20WithdrawalsResponse,
+                    tonic::Status,
+                >,
+            >
+            + Send
+            + 'static;
+        /// Gets the ICS-20 withdrawals sent by the user's wallet, along with the status
+        /// of each: whether it is still in flight, was acknowledged by the counterparty,
+        /// has timed out, or was refunded to its return address.
+        async fn ics20_withdrawals(
+            &self,
+            request: tonic::Request<super::Ics20WithdrawalsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<Self::Ics20WithdrawalsStream>,
+            tonic::Status,
+        >;
+        /// Adds a forwarding account on a counterparty chain, such as a Noble forwarding
+        /// account, to the wallet's registry. Deposits into a forwarding account are
+        /// forwarded over IBC to one of the wallet's addresses once the account is
+        /// registered on the counterparty chain, which the view service does
+        /// automatically when it detects a deposit.
+        async fn add_forwarding_account(
+            &self,
+            request: tonic::Request<super::AddForwardingAccountRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::AddForwardingAccountResponse>,
+            tonic::Status,
+        >;
+        /// Server streaming response type for the ForwardingAccounts method.
+        type ForwardingAccountsStream: tonic::codegen::tokio_stream::Stream<
+                Item = std::result::Result<
+                    super::ForwardingAccountsResponse,
+                    tonic::Status,
+                >,
+            >
+            + Send
+            + 'static;
+        /// Gets the forwarding accounts in the wallet's registry, optionally along with
+        /// the deposits pending in each on its counterparty chain.
+        async fn forwarding_accounts(
+            &self,
+            request: tonic::Request<super::ForwardingAccountsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<Self::ForwardingAccountsStream>,
+            tonic::Status,
+        >;
     }
     /// The view RPC is used by a view client, who wants to do some
     /// transaction-related actions, to request data from a view service, which is
@@ -3204,23 +4404,258 @@ pub mod view_service_server {
             match req.uri().path() {
                 "/penumbra.view.v1.ViewService/Status" => {
                     #[allow(non_camel_case_types)]
-                    struct StatusSvc<T: ViewService>(pub Arc<T>);
+                    struct StatusSvc<T: ViewService>(pub Arc<T>);
+                    impl<
+                        T: ViewService,
+                    > tonic::server::UnaryService<super::StatusRequest>
+                    for StatusSvc<T> {
+                        type Response = super::StatusResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::StatusRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as ViewService>::status(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = StatusSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/penumbra.view.v1.ViewService/StatusStream" => {
+                    #[allow(non_camel_case_types)]
+                    struct StatusStreamSvc<T: ViewService>(pub Arc<T>);
+                    impl<
+                        T: ViewService,
+                    > tonic::server::ServerStreamingService<super::StatusStreamRequest>
+                    for StatusStreamSvc<T> {
+                        type Response = super::StatusStreamResponse;
+                        type ResponseStream = T::StatusStreamStream;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::ResponseStream>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::StatusStreamRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as ViewService>::status_stream(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = StatusStreamSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.server_streaming(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/penumbra.view.v1.ViewService/Notes" => {
+                    #[allow(non_camel_case_types)]
+                    struct NotesSvc<T: ViewService>(pub Arc<T>);
+                    impl<
+                        T: ViewService,
+                    > tonic::server::ServerStreamingService<super::NotesRequest>
+                    for NotesSvc<T> {
+                        type Response = super::NotesResponse;
+                        type ResponseStream = T::NotesStream;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::ResponseStream>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::NotesRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as ViewService>::notes(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = NotesSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.server_streaming(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/penumbra.view.v1.ViewService/NotesForVoting" => {
+                    #[allow(non_camel_case_types)]
+                    struct NotesForVotingSvc<T: ViewService>(pub Arc<T>);
+                    impl<
+                        T: ViewService,
+                    > tonic::server::ServerStreamingService<super::NotesForVotingRequest>
+                    for NotesForVotingSvc<T> {
+                        type Response = super::NotesForVotingResponse;
+                        type ResponseStream = T::NotesForVotingStream;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::ResponseStream>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::NotesForVotingRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as ViewService>::notes_for_voting(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = NotesForVotingSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.server_streaming(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/penumbra.view.v1.ViewService/Assets" => {
+                    #[allow(non_camel_case_types)]
+                    struct AssetsSvc<T: ViewService>(pub Arc<T>);
+                    impl<
+                        T: ViewService,
+                    > tonic::server::ServerStreamingService<super::AssetsRequest>
+                    for AssetsSvc<T> {
+                        type Response = super::AssetsResponse;
+                        type ResponseStream = T::AssetsStream;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::ResponseStream>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::AssetsRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as ViewService>::assets(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = AssetsSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.server_streaming(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/penumbra.view.v1.ViewService/AssetMetadataById" => {
+                    #[allow(non_camel_case_types)]
+                    struct AssetMetadataByIdSvc<T: ViewService>(pub Arc<T>);
                     impl<
                         T: ViewService,
-                    > tonic::server::UnaryService<super::StatusRequest>
-                    for StatusSvc<T> {
-                        type Response = super::StatusResponse;
+                    > tonic::server::UnaryService<super::AssetMetadataByIdRequest>
+                    for AssetMetadataByIdSvc<T> {
+                        type Response = super::AssetMetadataByIdResponse;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
                             tonic::Status,
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::StatusRequest>,
+                            request: tonic::Request<super::AssetMetadataByIdRequest>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as ViewService>::status(&inner, request).await
+                                <T as ViewService>::asset_metadata_by_id(&inner, request)
+                                    .await
                             };
                             Box::pin(fut)
                         }
@@ -3232,7 +4667,7 @@ pub mod view_service_server {
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
-                        let method = StatusSvc(inner);
+                        let method = AssetMetadataByIdSvc(inner);
                         let codec = tonic::codec::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -3248,26 +4683,210 @@ pub mod view_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/penumbra.view.v1.ViewService/StatusStream" => {
+                "/penumbra.view.v1.ViewService/AppParameters" => {
                     #[allow(non_camel_case_types)]
-                    struct StatusStreamSvc<T: ViewService>(pub Arc<T>);
+                    struct AppParametersSvc<T: ViewService>(pub Arc<T>);
                     impl<
                         T: ViewService,
-                    > tonic::server::ServerStreamingService<super::StatusStreamRequest>
-                    for StatusStreamSvc<T> {
-                        type Response = super::StatusStreamResponse;
-                        type ResponseStream = T::StatusStreamStream;
+                    > tonic::server::UnaryService<super::AppParametersRequest>
+                    for AppParametersSvc<T> {
+                        type Response = super::AppParametersResponse;
                         type Future = BoxFuture<
-                            tonic::Response<Self::ResponseStream>,
+                            tonic::Response<Self::Response>,
                             tonic::Status,
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::StatusStreamRequest>,
+                            request: tonic::Request<super::AppParametersRequest>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as ViewService>::status_stream(&inner, request).await
+                                <T as ViewService>::app_parameters(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = AppParametersSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/penumbra.view.v1.ViewService/GasPrices" => {
+                    #[allow(non_camel_case_types)]
+                    struct GasPricesSvc<T: ViewService>(pub Arc<T>);
+                    impl<
+                        T: ViewService,
+                    > tonic::server::UnaryService<super::GasPricesRequest>
+                    for GasPricesSvc<T> {
+                        type Response = super::GasPricesResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::GasPricesRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as ViewService>::gas_prices(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = GasPricesSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/penumbra.view.v1.ViewService/FMDParameters" => {
+                    #[allow(non_camel_case_types)]
+                    struct FMDParametersSvc<T: ViewService>(pub Arc<T>);
+                    impl<
+                        T: ViewService,
+                    > tonic::server::UnaryService<super::FmdParametersRequest>
+                    for FMDParametersSvc<T> {
+                        type Response = super::FmdParametersResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::FmdParametersRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as ViewService>::fmd_parameters(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = FMDParametersSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/penumbra.view.v1.ViewService/AddressByIndex" => {
+                    #[allow(non_camel_case_types)]
+                    struct AddressByIndexSvc<T: ViewService>(pub Arc<T>);
+                    impl<
+                        T: ViewService,
+                    > tonic::server::UnaryService<super::AddressByIndexRequest>
+                    for AddressByIndexSvc<T> {
+                        type Response = super::AddressByIndexResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::AddressByIndexRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as ViewService>::address_by_index(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = AddressByIndexSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/penumbra.view.v1.ViewService/TransparentAddress" => {
+                    #[allow(non_camel_case_types)]
+                    struct TransparentAddressSvc<T: ViewService>(pub Arc<T>);
+                    impl<
+                        T: ViewService,
+                    > tonic::server::UnaryService<super::TransparentAddressRequest>
+                    for TransparentAddressSvc<T> {
+                        type Response = super::TransparentAddressResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::TransparentAddressRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as ViewService>::transparent_address(&inner, request)
+                                    .await
                             };
                             Box::pin(fut)
                         }
@@ -3279,7 +4898,7 @@ pub mod view_service_server {
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
-                        let method = StatusStreamSvc(inner);
+                        let method = TransparentAddressSvc(inner);
                         let codec = tonic::codec::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -3290,31 +4909,30 @@ pub mod view_service_server {
                                 max_decoding_message_size,
                                 max_encoding_message_size,
                             );
-                        let res = grpc.server_streaming(method, req).await;
+                        let res = grpc.unary(method, req).await;
                         Ok(res)
                     };
                     Box::pin(fut)
                 }
-                "/penumbra.view.v1.ViewService/Notes" => {
+                "/penumbra.view.v1.ViewService/WalletId" => {
                     #[allow(non_camel_case_types)]
-                    struct NotesSvc<T: ViewService>(pub Arc<T>);
+                    struct WalletIdSvc<T: ViewService>(pub Arc<T>);
                     impl<
                         T: ViewService,
-                    > tonic::server::ServerStreamingService<super::NotesRequest>
-                    for NotesSvc<T> {
-                        type Response = super::NotesResponse;
-                        type ResponseStream = T::NotesStream;
+                    > tonic::server::UnaryService<super::WalletIdRequest>
+                    for WalletIdSvc<T> {
+                        type Response = super::WalletIdResponse;
                         type Future = BoxFuture<
-                            tonic::Response<Self::ResponseStream>,
+                            tonic::Response<Self::Response>,
                             tonic::Status,
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::NotesRequest>,
+                            request: tonic::Request<super::WalletIdRequest>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as ViewService>::notes(&inner, request).await
+                                <T as ViewService>::wallet_id(&inner, request).await
                             };
                             Box::pin(fut)
                         }
@@ -3326,7 +4944,7 @@ pub mod view_service_server {
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
-                        let method = NotesSvc(inner);
+                        let method = WalletIdSvc(inner);
                         let codec = tonic::codec::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -3337,31 +4955,30 @@ pub mod view_service_server {
                                 max_decoding_message_size,
                                 max_encoding_message_size,
                             );
-                        let res = grpc.server_streaming(method, req).await;
+                        let res = grpc.unary(method, req).await;
                         Ok(res)
                     };
                     Box::pin(fut)
                 }
-                "/penumbra.view.v1.ViewService/NotesForVoting" => {
+                "/penumbra.view.v1.ViewService/IndexByAddress" => {
                     #[allow(non_camel_case_types)]
-                    struct NotesForVotingSvc<T: ViewService>(pub Arc<T>);
+                    struct IndexByAddressSvc<T: ViewService>(pub Arc<T>);
                     impl<
                         T: ViewService,
-                    > tonic::server::ServerStreamingService<super::NotesForVotingRequest>
-                    for NotesForVotingSvc<T> {
-                        type Response = super::NotesForVotingResponse;
-                        type ResponseStream = T::NotesForVotingStream;
+                    > tonic::server::UnaryService<super::IndexByAddressRequest>
+                    for IndexByAddressSvc<T> {
+                        type Response = super::IndexByAddressResponse;
                         type Future = BoxFuture<
-                            tonic::Response<Self::ResponseStream>,
+                            tonic::Response<Self::Response>,
                             tonic::Status,
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::NotesForVotingRequest>,
+                            request: tonic::Request<super::IndexByAddressRequest>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as ViewService>::notes_for_voting(&inner, request).await
+                                <T as ViewService>::index_by_address(&inner, request).await
                             };
                             Box::pin(fut)
                         }
@@ -3373,7 +4990,7 @@ pub mod view_service_server {
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
-                        let method = NotesForVotingSvc(inner);
+                        let method = IndexByAddressSvc(inner);
                         let codec = tonic::codec::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -3384,31 +5001,30 @@ pub mod view_service_server {
                                 max_decoding_message_size,
                                 max_encoding_message_size,
                             );
-                        let res = grpc.server_streaming(method, req).await;
+                        let res = grpc.unary(method, req).await;
                         Ok(res)
                     };
                     Box::pin(fut)
                 }
-                "/penumbra.view.v1.ViewService/Assets" => {
+                "/penumbra.view.v1.ViewService/EphemeralAddress" => {
                     #[allow(non_camel_case_types)]
-                    struct AssetsSvc<T: ViewService>(pub Arc<T>);
+                    struct EphemeralAddressSvc<T: ViewService>(pub Arc<T>);
                     impl<
                         T: ViewService,
-                    > tonic::server::ServerStreamingService<super::AssetsRequest>
-                    for AssetsSvc<T> {
-                        type Response = super::AssetsResponse;
-                        type ResponseStream = T::AssetsStream;
+                    > tonic::server::UnaryService<super::EphemeralAddressRequest>
+                    for EphemeralAddressSvc<T> {
+                        type Response = super::EphemeralAddressResponse;
                         type Future = BoxFuture<
-                            tonic::Response<Self::ResponseStream>,
+                            tonic::Response<Self::Response>,
                             tonic::Status,
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::AssetsRequest>,
+                            request: tonic::Request<super::EphemeralAddressRequest>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as ViewService>::assets(&inner, request).await
+                                <T as ViewService>::ephemeral_address(&inner, request).await
                             };
                             Box::pin(fut)
                         }
@@ -3420,7 +5036,7 @@ pub mod view_service_server {
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
-                        let method = AssetsSvc(inner);
+                        let method = EphemeralAddressSvc(inner);
                         let codec = tonic::codec::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -3431,31 +5047,31 @@ pub mod view_service_server {
                                 max_decoding_message_size,
                                 max_encoding_message_size,
                             );
-                        let res = grpc.server_streaming(method, req).await;
+                        let res = grpc.unary(method, req).await;
                         Ok(res)
                     };
                     Box::pin(fut)
                 }
-                "/penumbra.view.v1.ViewService/AssetMetadataById" => {
+                "/penumbra.view.v1.ViewService/Balances" => {
                     #[allow(non_camel_case_types)]
-                    struct AssetMetadataByIdSvc<T: ViewService>(pub Arc<T>);
+                    struct BalancesSvc<T: ViewService>(pub Arc<T>);
                     impl<
                         T: ViewService,
-                    > tonic::server::UnaryService<super::AssetMetadataByIdRequest>
-                    for AssetMetadataByIdSvc<T> {
-                        type Response = super::AssetMetadataByIdResponse;
+                    > tonic::server::ServerStreamingService<super::BalancesRequest>
+                    for BalancesSvc<T> {
+                        type Response = super::BalancesResponse;
+                        type ResponseStream = T::BalancesStream;
                         type Future = BoxFuture<
-                            tonic::Response<Self::Response>,
+                            tonic::Response<Self::ResponseStream>,
                             tonic::Status,
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::AssetMetadataByIdRequest>,
+                            request: tonic::Request<super::BalancesRequest>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as ViewService>::asset_metadata_by_id(&inner, request)
-                                    .await
+                                <T as ViewService>::balances(&inner, request).await
                             };
                             Box::pin(fut)
                         }
@@ -3467,7 +5083,7 @@ pub mod view_service_server {
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
-                        let method = AssetMetadataByIdSvc(inner);
+                        let method = BalancesSvc(inner);
                         let codec = tonic::codec::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -3478,30 +5094,31 @@ pub mod view_service_server {
                                 max_decoding_message_size,
                                 max_encoding_message_size,
                             );
-                        let res = grpc.unary(method, req).await;
+                        let res = grpc.server_streaming(method, req).await;
                         Ok(res)
                     };
                     Box::pin(fut)
                 }
-                "/penumbra.view.v1.ViewService/AppParameters" => {
+                "/penumbra.view.v1.ViewService/NoteByCommitment" => {
                     #[allow(non_camel_case_types)]
-                    struct AppParametersSvc<T: ViewService>(pub Arc<T>);
+                    struct NoteByCommitmentSvc<T: ViewService>(pub Arc<T>);
                     impl<
                         T: ViewService,
-                    > tonic::server::UnaryService<super::AppParametersRequest>
-                    for AppParametersSvc<T> {
-                        type Response = super::AppParametersResponse;
+                    > tonic::server::UnaryService<super::NoteByCommitmentRequest>
+                    for NoteByCommitmentSvc<T> {
+                        type Response = super::NoteByCommitmentResponse;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
                             tonic::Status,
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::AppParametersRequest>,
+                            request: tonic::Request<super::NoteByCommitmentRequest>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as ViewService>::app_parameters(&inner, request).await
+                                <T as ViewService>::note_by_commitment(&inner, request)
+                                    .await
                             };
                             Box::pin(fut)
                         }
@@ -3513,7 +5130,7 @@ pub mod view_service_server {
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
-                        let method = AppParametersSvc(inner);
+                        let method = NoteByCommitmentSvc(inner);
                         let codec = tonic::codec::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -3529,25 +5146,26 @@ pub mod view_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/penumbra.view.v1.ViewService/GasPrices" => {
+                "/penumbra.view.v1.ViewService/SwapByCommitment" => {
                     #[allow(non_camel_case_types)]
-                    struct GasPricesSvc<T: ViewService>(pub Arc<T>);
+                    struct SwapByCommitmentSvc<T: ViewService>(pub Arc<T>);
                     impl<
                         T: ViewService,
-                    > tonic::server::UnaryService<super::GasPricesRequest>
-                    for GasPricesSvc<T> {
-                        type Response = super::GasPricesResponse;
+                    > tonic::server::UnaryService<super::SwapByCommitmentRequest>
+                    for SwapByCommitmentSvc<T> {
+                        type Response = super::SwapByCommitmentResponse;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
                             tonic::Status,
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::GasPricesRequest>,
+                            request: tonic::Request<super::SwapByCommitmentRequest>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as ViewService>::gas_prices(&inner, request).await
+                                <T as ViewService>::swap_by_commitment(&inner, request)
+                                    .await
                             };
                             Box::pin(fut)
                         }
@@ -3559,7 +5177,7 @@ pub mod view_service_server {
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
-                        let method = GasPricesSvc(inner);
+                        let method = SwapByCommitmentSvc(inner);
                         let codec = tonic::codec::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -3575,25 +5193,26 @@ pub mod view_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/penumbra.view.v1.ViewService/FMDParameters" => {
+                "/penumbra.view.v1.ViewService/UnclaimedSwaps" => {
                     #[allow(non_camel_case_types)]
-                    struct FMDParametersSvc<T: ViewService>(pub Arc<T>);
+                    struct UnclaimedSwapsSvc<T: ViewService>(pub Arc<T>);
                     impl<
                         T: ViewService,
-                    > tonic::server::UnaryService<super::FmdParametersRequest>
-                    for FMDParametersSvc<T> {
-                        type Response = super::FmdParametersResponse;
+                    > tonic::server::ServerStreamingService<super::UnclaimedSwapsRequest>
+                    for UnclaimedSwapsSvc<T> {
+                        type Response = super::UnclaimedSwapsResponse;
+                        type ResponseStream = T::UnclaimedSwapsStream;
                         type Future = BoxFuture<
-                            tonic::Response<Self::Response>,
+                            tonic::Response<Self::ResponseStream>,
                             tonic::Status,
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::FmdParametersRequest>,
+                            request: tonic::Request<super::UnclaimedSwapsRequest>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as ViewService>::fmd_parameters(&inner, request).await
+                                <T as ViewService>::unclaimed_swaps(&inner, request).await
                             };
                             Box::pin(fut)
                         }
@@ -3605,7 +5224,7 @@ pub mod view_service_server {
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
-                        let method = FMDParametersSvc(inner);
+                        let method = UnclaimedSwapsSvc(inner);
                         let codec = tonic::codec::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -3616,30 +5235,30 @@ pub mod view_service_server {
                                 max_decoding_message_size,
                                 max_encoding_message_size,
                             );
-                        let res = grpc.unary(method, req).await;
+                        let res = grpc.server_streaming(method, req).await;
                         Ok(res)
                     };
                     Box::pin(fut)
                 }
-                "/penumbra.view.v1.ViewService/AddressByIndex" => {
+                "/penumbra.view.v1.ViewService/NullifierStatus" => {
                     #[allow(non_camel_case_types)]
-                    struct AddressByIndexSvc<T: ViewService>(pub Arc<T>);
+                    struct NullifierStatusSvc<T: ViewService>(pub Arc<T>);
                     impl<
                         T: ViewService,
-                    > tonic::server::UnaryService<super::AddressByIndexRequest>
-                    for AddressByIndexSvc<T> {
-                        type Response = super::AddressByIndexResponse;
+                    > tonic::server::UnaryService<super::NullifierStatusRequest>
+                    for NullifierStatusSvc<T> {
+                        type Response = super::NullifierStatusResponse;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
                             tonic::Status,
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::AddressByIndexRequest>,
+                            request: tonic::Request<super::NullifierStatusRequest>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as ViewService>::address_by_index(&inner, request).await
+                                <T as ViewService>::nullifier_status(&inner, request).await
                             };
                             Box::pin(fut)
                         }
@@ -3651,7 +5270,7 @@ pub mod view_service_server {
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
-                        let method = AddressByIndexSvc(inner);
+                        let method = NullifierStatusSvc(inner);
                         let codec = tonic::codec::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -3667,25 +5286,28 @@ pub mod view_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/penumbra.view.v1.ViewService/TransparentAddress" => {
+                "/penumbra.view.v1.ViewService/TransactionInfoByHash" => {
                     #[allow(non_camel_case_types)]
-                    struct TransparentAddressSvc<T: ViewService>(pub Arc<T>);
+                    struct TransactionInfoByHashSvc<T: ViewService>(pub Arc<T>);
                     impl<
                         T: ViewService,
-                    > tonic::server::UnaryService<super::TransparentAddressRequest>
-                    for TransparentAddressSvc<T> {
-                        type Response = super::TransparentAddressResponse;
+                    > tonic::server::UnaryService<super::TransactionInfoByHashRequest>
+                    for TransactionInfoByHashSvc<T> {
+                        type Response = super::TransactionInfoByHashResponse;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
                             tonic::Status,
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::TransparentAddressRequest>,
+                            request: tonic::Request<super::TransactionInfoByHashRequest>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as ViewService>::transparent_address(&inner, request)
+                                <T as ViewService>::transaction_info_by_hash(
+                                        &inner,
+                                        request,
+                                    )
                                     .await
                             };
                             Box::pin(fut)
@@ -3698,7 +5320,7 @@ pub mod view_service_server {
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
-                        let method = TransparentAddressSvc(inner);
+                        let method = TransactionInfoByHashSvc(inner);
                         let codec = tonic::codec::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -3714,25 +5336,27 @@ pub mod view_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/penumbra.view.v1.ViewService/WalletId" => {
+                "/penumbra.view.v1.ViewService/TransactionInfo" => {
                     #[allow(non_camel_case_types)]
-                    struct WalletIdSvc<T: ViewService>(pub Arc<T>);
+                    struct TransactionInfoSvc<T: ViewService>(pub Arc<T>);
                     impl<
                         T: ViewService,
-                    > tonic::server::UnaryService<super::WalletIdRequest>
-                    for WalletIdSvc<T> {
-                        type Response = super::WalletIdResponse;
+                    > tonic::server::ServerStreamingService<
+                        super::TransactionInfoRequest,
+                    > for TransactionInfoSvc<T> {
+                        type Response = super::TransactionInfoResponse;
+                        type ResponseStream = T::TransactionInfoStream;
                         type Future = BoxFuture<
-                            tonic::Response<Self::Response>,
+                            tonic::Response<Self::ResponseStream>,
                             tonic::Status,
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::WalletIdRequest>,
+                            request: tonic::Request<super::TransactionInfoRequest>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as ViewService>::wallet_id(&inner, request).await
+                                <T as ViewService>::transaction_info(&inner, request).await
                             };
                             Box::pin(fut)
                         }
@@ -3744,7 +5368,7 @@ pub mod view_service_server {
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
-                        let method = WalletIdSvc(inner);
+                        let method = TransactionInfoSvc(inner);
                         let codec = tonic::codec::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -3755,30 +5379,33 @@ pub mod view_service_server {
                                 max_decoding_message_size,
                                 max_encoding_message_size,
                             );
-                        let res = grpc.unary(method, req).await;
+                        let res = grpc.server_streaming(method, req).await;
                         Ok(res)
                     };
                     Box::pin(fut)
                 }
-                "/penumbra.view.v1.ViewService/IndexByAddress" => {
+                "/penumbra.view.v1.ViewService/OwnedPositionIds" => {
                     #[allow(non_camel_case_types)]
-                    struct IndexByAddressSvc<T: ViewService>(pub Arc<T>);
+                    struct OwnedPositionIdsSvc<T: ViewService>(pub Arc<T>);
                     impl<
                         T: ViewService,
-                    > tonic::server::UnaryService<super::IndexByAddressRequest>
-                    for IndexByAddressSvc<T> {
-                        type Response = super::IndexByAddressResponse;
+                    > tonic::server::ServerStreamingService<
+                        super::OwnedPositionIdsRequest,
+                    > for OwnedPositionIdsSvc<T> {
+                        type Response = super::OwnedPositionIdsResponse;
+                        type ResponseStream = T::OwnedPositionIdsStream;
                         type Future = BoxFuture<
-                            tonic::Response<Self::Response>,
+                            tonic::Response<Self::ResponseStream>,
                             tonic::Status,
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::IndexByAddressRequest>,
+                            request: tonic::Request<super::OwnedPositionIdsRequest>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as ViewService>::index_by_address(&inner, request).await
+                                <T as ViewService>::owned_position_ids(&inner, request)
+                                    .await
                             };
                             Box::pin(fut)
                         }
@@ -3790,7 +5417,7 @@ pub mod view_service_server {
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
-                        let method = IndexByAddressSvc(inner);
+                        let method = OwnedPositionIdsSvc(inner);
                         let codec = tonic::codec::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -3801,30 +5428,31 @@ pub mod view_service_server {
                                 max_decoding_message_size,
                                 max_encoding_message_size,
                             );
-                        let res = grpc.unary(method, req).await;
+                        let res = grpc.server_streaming(method, req).await;
                         Ok(res)
                     };
                     Box::pin(fut)
                 }
-                "/penumbra.view.v1.ViewService/EphemeralAddress" => {
+                "/penumbra.view.v1.ViewService/TransactionPlanner" => {
                     #[allow(non_camel_case_types)]
-                    struct EphemeralAddressSvc<T: ViewService>(pub Arc<T>);
+                    struct TransactionPlannerSvc<T: ViewService>(pub Arc<T>);
                     impl<
                         T: ViewService,
-                    > tonic::server::UnaryService<super::EphemeralAddressRequest>
-                    for EphemeralAddressSvc<T> {
-                        type Response = super::EphemeralAddressResponse;
+                    > tonic::server::UnaryService<super::TransactionPlannerRequest>
+                    for TransactionPlannerSvc<T> {
+                        type Response = super::TransactionPlannerResponse;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
                             tonic::Status,
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::EphemeralAddressRequest>,
+                            request: tonic::Request<super::TransactionPlannerRequest>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as ViewService>::ephemeral_address(&inner, request).await
+                                <T as ViewService>::transaction_planner(&inner, request)
+                                    .await
                             };
                             Box::pin(fut)
                         }
@@ -3836,7 +5464,7 @@ pub mod view_service_server {
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
-                        let method = EphemeralAddressSvc(inner);
+                        let method = TransactionPlannerSvc(inner);
                         let codec = tonic::codec::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -3852,26 +5480,25 @@ pub mod view_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/penumbra.view.v1.ViewService/Balances" => {
+                "/penumbra.view.v1.ViewService/Witness" => {
                     #[allow(non_camel_case_types)]
-                    struct BalancesSvc<T: ViewService>(pub Arc<T>);
+                    struct WitnessSvc<T: ViewService>(pub Arc<T>);
                     impl<
                         T: ViewService,
-                    > tonic::server::ServerStreamingService<super::BalancesRequest>
-                    for BalancesSvc<T> {
-                        type Response = super::BalancesResponse;
-                        type ResponseStream = T::BalancesStream;
+                    > tonic::server::UnaryService<super::WitnessRequest>
+                    for WitnessSvc<T> {
+                        type Response = super::WitnessResponse;
                         type Future = BoxFuture<
-                            tonic::Response<Self::ResponseStream>,
+                            tonic::Response<Self::Response>,
                             tonic::Status,
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::BalancesRequest>,
+                            request: tonic::Request<super::WitnessRequest>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as ViewService>::balances(&inner, request).await
+                                <T as ViewService>::witness(&inner, request).await
                             };
                             Box::pin(fut)
                         }
@@ -3883,7 +5510,7 @@ pub mod view_service_server {
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
-                        let method = BalancesSvc(inner);
+                        let method = WitnessSvc(inner);
                         let codec = tonic::codec::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -3894,31 +5521,32 @@ pub mod view_service_server {
                                 max_decoding_message_size,
                                 max_encoding_message_size,
                             );
-                        let res = grpc.server_streaming(method, req).await;
+                        let res = grpc.unary(method, req).await;
                         Ok(res)
                     };
                     Box::pin(fut)
                 }
-                "/penumbra.view.v1.ViewService/NoteByCommitment" => {
+                "/penumbra.view.v1.ViewService/WitnessAndBuild" => {
                     #[allow(non_camel_case_types)]
-                    struct NoteByCommitmentSvc<T: ViewService>(pub Arc<T>);
+                    struct WitnessAndBuildSvc<T: ViewService>(pub Arc<T>);
                     impl<
                         T: ViewService,
-                    > tonic::server::UnaryService<super::NoteByCommitmentRequest>
-                    for NoteByCommitmentSvc<T> {
-                        type Response = super::NoteByCommitmentResponse;
+                    > tonic::server::ServerStreamingService<
+                        super::WitnessAndBuildRequest,
+                    > for WitnessAndBuildSvc<T> {
+                        type Response = super::WitnessAndBuildResponse;
+                        type ResponseStream = T::WitnessAndBuildStream;
                         type Future = BoxFuture<
-                            tonic::Response<Self::Response>,
+                            tonic::Response<Self::ResponseStream>,
                             tonic::Status,
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::NoteByCommitmentRequest>,
+                            request: tonic::Request<super::WitnessAndBuildRequest>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as ViewService>::note_by_commitment(&inner, request)
-                                    .await
+                                <T as ViewService>::witness_and_build(&inner, request).await
                             };
                             Box::pin(fut)
                         }
@@ -3930,7 +5558,7 @@ pub mod view_service_server {
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
-                        let method = NoteByCommitmentSvc(inner);
+                        let method = WitnessAndBuildSvc(inner);
                         let codec = tonic::codec::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -3941,30 +5569,32 @@ pub mod view_service_server {
                                 max_decoding_message_size,
                                 max_encoding_message_size,
                             );
-                        let res = grpc.unary(method, req).await;
+                        let res = grpc.server_streaming(method, req).await;
                         Ok(res)
                     };
                     Box::pin(fut)
                 }
-                "/penumbra.view.v1.ViewService/SwapByCommitment" => {
+                "/penumbra.view.v1.ViewService/AuthorizeAndBuild" => {
                     #[allow(non_camel_case_types)]
-                    struct SwapByCommitmentSvc<T: ViewService>(pub Arc<T>);
+                    struct AuthorizeAndBuildSvc<T: ViewService>(pub Arc<T>);
                     impl<
                         T: ViewService,
-                    > tonic::server::UnaryService<super::SwapByCommitmentRequest>
-                    for SwapByCommitmentSvc<T> {
-                        type Response = super::SwapByCommitmentResponse;
+                    > tonic::server::ServerStreamingService<
+                        super::AuthorizeAndBuildRequest,
+                    > for AuthorizeAndBuildSvc<T> {
+                        type Response = super::AuthorizeAndBuildResponse;
+                        type ResponseStream = T::AuthorizeAndBuildStream;
                         type Future = BoxFuture<
-                            tonic::Response<Self::Response>,
+                            tonic::Response<Self::ResponseStream>,
                             tonic::Status,
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::SwapByCommitmentRequest>,
+                            request: tonic::Request<super::AuthorizeAndBuildRequest>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as ViewService>::swap_by_commitment(&inner, request)
+                                <T as ViewService>::authorize_and_build(&inner, request)
                                     .await
                             };
                             Box::pin(fut)
@@ -3977,7 +5607,7 @@ pub mod view_service_server {
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
-                        let method = SwapByCommitmentSvc(inner);
+                        let method = AuthorizeAndBuildSvc(inner);
                         let codec = tonic::codec::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -3988,31 +5618,33 @@ pub mod view_service_server {
                                 max_decoding_message_size,
                                 max_encoding_message_size,
                             );
-                        let res = grpc.unary(method, req).await;
+                        let res = grpc.server_streaming(method, req).await;
                         Ok(res)
                     };
                     Box::pin(fut)
                 }
-                "/penumbra.view.v1.ViewService/UnclaimedSwaps" => {
+                "/penumbra.view.v1.ViewService/BroadcastTransaction" => {
                     #[allow(non_camel_case_types)]
-                    struct UnclaimedSwapsSvc<T: ViewService>(pub Arc<T>);
+                    struct BroadcastTransactionSvc<T: ViewService>(pub Arc<T>);
                     impl<
                         T: ViewService,
-                    > tonic::server::ServerStreamingService<super::UnclaimedSwapsRequest>
-                    for UnclaimedSwapsSvc<T> {
-                        type Response = super::UnclaimedSwapsResponse;
-                        type ResponseStream = T::UnclaimedSwapsStream;
+                    > tonic::server::ServerStreamingService<
+                        super::BroadcastTransactionRequest,
+                    > for BroadcastTransactionSvc<T> {
+                        type Response = super::BroadcastTransactionResponse;
+                        type ResponseStream = T::BroadcastTransactionStream;
                         type Future = BoxFuture<
                             tonic::Response<Self::ResponseStream>,
                             tonic::Status,
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::UnclaimedSwapsRequest>,
+                            request: tonic::Request<super::BroadcastTransactionRequest>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as ViewService>::unclaimed_swaps(&inner, request).await
+                                <T as ViewService>::broadcast_transaction(&inner, request)
+                                    .await
                             };
                             Box::pin(fut)
                         }
@@ -4024,7 +5656,7 @@ pub mod view_service_server {
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
-                        let method = UnclaimedSwapsSvc(inner);
+                        let method = BroadcastTransactionSvc(inner);
                         let codec = tonic::codec::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -4040,25 +5672,33 @@ pub mod view_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/penumbra.view.v1.ViewService/NullifierStatus" => {
+                "/penumbra.view.v1.ViewService/DelegationsByAddressIndex" => {
                     #[allow(non_camel_case_types)]
-                    struct NullifierStatusSvc<T: ViewService>(pub Arc<T>);
+                    struct DelegationsByAddressIndexSvc<T: ViewService>(pub Arc<T>);
                     impl<
                         T: ViewService,
-                    > tonic::server::UnaryService<super::NullifierStatusRequest>
-                    for NullifierStatusSvc<T> {
-                        type Response = super::NullifierStatusResponse;
+                    > tonic::server::ServerStreamingService<
+                        super::DelegationsByAddressIndexRequest,
+                    > for DelegationsByAddressIndexSvc<T> {
+                        type Response = super::DelegationsByAddressIndexResponse;
+                        type ResponseStream = T::DelegationsByAddressIndexStream;
                         type Future = BoxFuture<
-                            tonic::Response<Self::Response>,
+                            tonic::Response<Self::ResponseStream>,
                             tonic::Status,
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::NullifierStatusRequest>,
+                            request: tonic::Request<
+                                super::DelegationsByAddressIndexRequest,
+                            >,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as ViewService>::nullifier_status(&inner, request).await
+                                <T as ViewService>::delegations_by_address_index(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
                             };
                             Box::pin(fut)
                         }
@@ -4070,7 +5710,7 @@ pub mod view_service_server {
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
-                        let method = NullifierStatusSvc(inner);
+                        let method = DelegationsByAddressIndexSvc(inner);
                         let codec = tonic::codec::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -4081,30 +5721,34 @@ pub mod view_service_server {
                                 max_decoding_message_size,
                                 max_encoding_message_size,
                             );
-                        let res = grpc.unary(method, req).await;
+                        let res = grpc.server_streaming(method, req).await;
                         Ok(res)
                     };
                     Box::pin(fut)
                 }
-                "/penumbra.view.v1.ViewService/TransactionInfoByHash" => {
+                "/penumbra.view.v1.ViewService/UnbondingTokensByAddressIndex" => {
                     #[allow(non_camel_case_types)]
-                    struct TransactionInfoByHashSvc<T: ViewService>(pub Arc<T>);
+                    struct UnbondingTokensByAddressIndexSvc<T: ViewService>(pub Arc<T>);
                     impl<
                         T: ViewService,
-                    > tonic::server::UnaryService<super::TransactionInfoByHashRequest>
-                    for TransactionInfoByHashSvc<T> {
-                        type Response = super::TransactionInfoByHashResponse;
+                    > tonic::server::ServerStreamingService<
+                        super::UnbondingTokensByAddressIndexRequest,
+                    > for UnbondingTokensByAddressIndexSvc<T> {
+                        type Response = super::UnbondingTokensByAddressIndexResponse;
+                        type ResponseStream = T::UnbondingTokensByAddressIndexStream;
                         type Future = BoxFuture<
-                            tonic::Response<Self::Response>,
+                            tonic::Response<Self::ResponseStream>,
                             tonic::Status,
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::TransactionInfoByHashRequest>,
+                            request: tonic::Request<
+                                super::UnbondingTokensByAddressIndexRequest,
+                            >,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as ViewService>::transaction_info_by_hash(
+                                <T as ViewService>::unbonding_tokens_by_address_index(
                                         &inner,
                                         request,
                                     )
@@ -4120,7 +5764,7 @@ pub mod view_service_server {
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
-                        let method = TransactionInfoByHashSvc(inner);
+                        let method = UnbondingTokensByAddressIndexSvc(inner);
                         let codec = tonic::codec::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -4131,32 +5775,31 @@ pub mod view_service_server {
                                 max_decoding_message_size,
                                 max_encoding_message_size,
                             );
-                        let res = grpc.unary(method, req).await;
+                        let res = grpc.server_streaming(method, req).await;
                         Ok(res)
                     };
                     Box::pin(fut)
                 }
-                "/penumbra.view.v1.ViewService/TransactionInfo" => {
+                "/penumbra.view.v1.ViewService/Auctions" => {
                     #[allow(non_camel_case_types)]
-                    struct TransactionInfoSvc<T: ViewService>(pub Arc<T>);
+                    struct AuctionsSvc<T: ViewService>(pub Arc<T>);
                     impl<
                         T: ViewService,
-                    > tonic::server::ServerStreamingService<
-                        super::TransactionInfoRequest,
-                    > for TransactionInfoSvc<T> {
-                        type Response = super::TransactionInfoResponse;
-                        type ResponseStream = T::TransactionInfoStream;
+                    > tonic::server::ServerStreamingService<super::AuctionsRequest>
+                    for AuctionsSvc<T> {
+                        type Response = super::AuctionsResponse;
+                        type ResponseStream = T::AuctionsStream;
                         type Future = BoxFuture<
                             tonic::Response<Self::ResponseStream>,
                             tonic::Status,
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::TransactionInfoRequest>,
+                            request: tonic::Request<super::AuctionsRequest>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as ViewService>::transaction_info(&inner, request).await
+                                <T as ViewService>::auctions(&inner, request).await
                             };
                             Box::pin(fut)
                         }
@@ -4168,7 +5811,7 @@ pub mod view_service_server {
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
-                        let method = TransactionInfoSvc(inner);
+                        let method = AuctionsSvc(inner);
                         let codec = tonic::codec::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -4184,27 +5827,27 @@ pub mod view_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/penumbra.view.v1.ViewService/OwnedPositionIds" => {
+                "/penumbra.view.v1.ViewService/PositionPerformance" => {
                     #[allow(non_camel_case_types)]
-                    struct OwnedPositionIdsSvc<T: ViewService>(pub Arc<T>);
+                    struct PositionPerformanceSvc<T: ViewService>(pub Arc<T>);
                     impl<
                         T: ViewService,
                     > tonic::server::ServerStreamingService<
-                        super::OwnedPositionIdsRequest,
-                    > for OwnedPositionIdsSvc<T> {
-                        type Response = super::OwnedPositionIdsResponse;
-                        type ResponseStream = T::OwnedPositionIdsStream;
+                        super::PositionPerformanceRequest,
+                    > for PositionPerformanceSvc<T> {
+                        type Response = super::PositionPerformanceResponse;
+                        type ResponseStream = T::PositionPerformanceStream;
                         type Future = BoxFuture<
                             tonic::Response<Self::ResponseStream>,
                             tonic::Status,
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::OwnedPositionIdsRequest>,
+                            request: tonic::Request<super::PositionPerformanceRequest>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as ViewService>::owned_position_ids(&inner, request)
+                                <T as ViewService>::position_performance(&inner, request)
                                     .await
                             };
                             Box::pin(fut)
@@ -4217,7 +5860,7 @@ pub mod view_service_server {
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
-                        let method = OwnedPositionIdsSvc(inner);
+                        let method = PositionPerformanceSvc(inner);
                         let codec = tonic::codec::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -4233,26 +5876,25 @@ pub mod view_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/penumbra.view.v1.ViewService/TransactionPlanner" => {
+                "/penumbra.view.v1.ViewService/CreateSchedule" => {
                     #[allow(non_camel_case_types)]
-                    struct TransactionPlannerSvc<T: ViewService>(pub Arc<T>);
+                    struct CreateScheduleSvc<T: ViewService>(pub Arc<T>);
                     impl<
                         T: ViewService,
-                    > tonic::server::UnaryService<super::TransactionPlannerRequest>
-                    for TransactionPlannerSvc<T> {
-                        type Response = super::TransactionPlannerResponse;
+                    > tonic::server::UnaryService<super::CreateScheduleRequest>
+                    for CreateScheduleSvc<T> {
+                        type Response = super::CreateScheduleResponse;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
                             tonic::Status,
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::TransactionPlannerRequest>,
+                            request: tonic::Request<super::CreateScheduleRequest>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as ViewService>::transaction_planner(&inner, request)
-                                    .await
+                                <T as ViewService>::create_schedule(&inner, request).await
                             };
                             Box::pin(fut)
                         }
@@ -4264,7 +5906,7 @@ pub mod view_service_server {
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
-                        let method = TransactionPlannerSvc(inner);
+                        let method = CreateScheduleSvc(inner);
                         let codec = tonic::codec::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -4280,25 +5922,26 @@ pub mod view_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/penumbra.view.v1.ViewService/Witness" => {
+                "/penumbra.view.v1.ViewService/Schedules" => {
                     #[allow(non_camel_case_types)]
-                    struct WitnessSvc<T: ViewService>(pub Arc<T>);
+                    struct SchedulesSvc<T: ViewService>(pub Arc<T>);
                     impl<
                         T: ViewService,
-                    > tonic::server::UnaryService<super::WitnessRequest>
-                    for WitnessSvc<T> {
-                        type Response = super::WitnessResponse;
+                    > tonic::server::ServerStreamingService<super::SchedulesRequest>
+                    for SchedulesSvc<T> {
+                        type Response = super::SchedulesResponse;
+                        type ResponseStream = T::SchedulesStream;
                         type Future = BoxFuture<
-                            tonic::Response<Self::Response>,
+                            tonic::Response<Self::ResponseStream>,
                             tonic::Status,
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::WitnessRequest>,
+                            request: tonic::Request<super::SchedulesRequest>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as ViewService>::witness(&inner, request).await
+                                <T as ViewService>::schedules(&inner, request).await
                             };
                             Box::pin(fut)
                         }
@@ -4310,7 +5953,7 @@ pub mod view_service_server {
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
-                        let method = WitnessSvc(inner);
+                        let method = SchedulesSvc(inner);
                         let codec = tonic::codec::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -4321,32 +5964,31 @@ pub mod view_service_server {
                                 max_decoding_message_size,
                                 max_encoding_message_size,
                             );
-                        let res = grpc.unary(method, req).await;
+                        let res = grpc.server_streaming(method, req).await;
                         Ok(res)
                     };
                     Box::pin(fut)
                 }
-                "/penumbra.view.v1.ViewService/WitnessAndBuild" => {
+                "/penumbra.view.v1.ViewService/UpdateScheduleStatus" => {
                     #[allow(non_camel_case_types)]
-                    struct WitnessAndBuildSvc<T: ViewService>(pub Arc<T>);
+                    struct UpdateScheduleStatusSvc<T: ViewService>(pub Arc<T>);
                     impl<
                         T: ViewService,
-                    > tonic::server::ServerStreamingService<
-                        super::WitnessAndBuildRequest,
-                    > for WitnessAndBuildSvc<T> {
-                        type Response = super::WitnessAndBuildResponse;
-                        type ResponseStream = T::WitnessAndBuildStream;
+                    > tonic::server::UnaryService<super::UpdateScheduleStatusRequest>
+                    for UpdateScheduleStatusSvc<T> {
+                        type Response = super::UpdateScheduleStatusResponse;
                         type Future = BoxFuture<
-                            tonic::Response<Self::ResponseStream>,
+                            tonic::Response<Self::Response>,
                             tonic::Status,
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::WitnessAndBuildRequest>,
+                            request: tonic::Request<super::UpdateScheduleStatusRequest>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as ViewService>::witness_and_build(&inner, request).await
+                                <T as ViewService>::update_schedule_status(&inner, request)
+                                    .await
                             };
                             Box::pin(fut)
                         }
@@ -4358,7 +6000,7 @@ pub mod view_service_server {
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
-                        let method = WitnessAndBuildSvc(inner);
+                        let method = UpdateScheduleStatusSvc(inner);
                         let codec = tonic::codec::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -4369,33 +6011,31 @@ pub mod view_service_server {
                                 max_decoding_message_size,
                                 max_encoding_message_size,
                             );
-                        let res = grpc.server_streaming(method, req).await;
+                        let res = grpc.unary(method, req).await;
                         Ok(res)
                     };
                     Box::pin(fut)
                 }
-                "/penumbra.view.v1.ViewService/AuthorizeAndBuild" => {
+                "/penumbra.view.v1.ViewService/StakingRewards" => {
                     #[allow(non_camel_case_types)]
-                    struct AuthorizeAndBuildSvc<T: ViewService>(pub Arc<T>);
+                    struct StakingRewardsSvc<T: ViewService>(pub Arc<T>);
                     impl<
                         T: ViewService,
-                    > tonic::server::ServerStreamingService<
-                        super::AuthorizeAndBuildRequest,
-                    > for AuthorizeAndBuildSvc<T> {
-                        type Response = super::AuthorizeAndBuildResponse;
-                        type ResponseStream = T::AuthorizeAndBuildStream;
+                    > tonic::server::ServerStreamingService<super::StakingRewardsRequest>
+                    for StakingRewardsSvc<T> {
+                        type Response = super::StakingRewardsResponse;
+                        type ResponseStream = T::StakingRewardsStream;
                         type Future = BoxFuture<
                             tonic::Response<Self::ResponseStream>,
                             tonic::Status,
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::AuthorizeAndBuildRequest>,
+                            request: tonic::Request<super::StakingRewardsRequest>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as ViewService>::authorize_and_build(&inner, request)
-                                    .await
+                                <T as ViewService>::staking_rewards(&inner, request).await
                             };
                             Box::pin(fut)
                         }
@@ -4407,7 +6047,7 @@ pub mod view_service_server {
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
-                        let method = AuthorizeAndBuildSvc(inner);
+                        let method = StakingRewardsSvc(inner);
                         let codec = tonic::codec::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -4423,28 +6063,26 @@ pub mod view_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/penumbra.view.v1.ViewService/BroadcastTransaction" => {
+                "/penumbra.view.v1.ViewService/GovernanceFeed" => {
                     #[allow(non_camel_case_types)]
-                    struct BroadcastTransactionSvc<T: ViewService>(pub Arc<T>);
+                    struct GovernanceFeedSvc<T: ViewService>(pub Arc<T>);
                     impl<
                         T: ViewService,
-                    > tonic::server::ServerStreamingService<
-                        super::BroadcastTransactionRequest,
-                    > for BroadcastTransactionSvc<T> {
-                        type Response = super::BroadcastTransactionResponse;
-                        type ResponseStream = T::BroadcastTransactionStream;
+                    > tonic::server::ServerStreamingService<super::GovernanceFeedRequest>
+                    for GovernanceFeedSvc<T> {
+                        type Response = super::GovernanceFeedResponse;
+                        type ResponseStream = T::GovernanceFeedStream;
                         type Future = BoxFuture<
                             tonic::Response<Self::ResponseStream>,
                             tonic::Status,
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::BroadcastTransactionRequest>,
+                            request: tonic::Request<super::GovernanceFeedRequest>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as ViewService>::broadcast_transaction(&inner, request)
-                                    .await
+                                <T as ViewService>::governance_feed(&inner, request).await
                             };
                             Box::pin(fut)
                         }
@@ -4456,7 +6094,7 @@ pub mod view_service_server {
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
-                        let method = BroadcastTransactionSvc(inner);
+                        let method = GovernanceFeedSvc(inner);
                         let codec = tonic::codec::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -4472,33 +6110,27 @@ pub mod view_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/penumbra.view.v1.ViewService/DelegationsByAddressIndex" => {
+                "/penumbra.view.v1.ViewService/Ics20Withdrawals" => {
                     #[allow(non_camel_case_types)]
-                    struct DelegationsByAddressIndexSvc<T: ViewService>(pub Arc<T>);
+                    struct Ics20WithdrawalsSvc<T: ViewService>(pub Arc<T>);
                     impl<
                         T: ViewService,
                     > tonic::server::ServerStreamingService<
-                        super::DelegationsByAddressIndexRequest,
-                    > for DelegationsByAddressIndexSvc<T> {
-                        type Response = super::DelegationsByAddressIndexResponse;
-                        type ResponseStream = T::DelegationsByAddressIndexStream;
+                        super::Ics20WithdrawalsRequest,
+                    > for Ics20WithdrawalsSvc<T> {
+                        type Response = super::Ics20WithdrawalsResponse;
+                        type ResponseStream = T::Ics20WithdrawalsStream;
                         type Future = BoxFuture<
                             tonic::Response<Self::ResponseStream>,
                             tonic::Status,
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<
-                                super::DelegationsByAddressIndexRequest,
-                            >,
+                            request: tonic::Request<super::Ics20WithdrawalsRequest>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as ViewService>::delegations_by_address_index(
-                                        &inner,
-                                        request,
-                                    )
-                                    .await
+                                <T as ViewService>::ics20_withdrawals(&inner, request).await
                             };
                             Box::pin(fut)
                         }
@@ -4510,7 +6142,7 @@ pub mod view_service_server {
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
-                        let method = DelegationsByAddressIndexSvc(inner);
+                        let method = Ics20WithdrawalsSvc(inner);
                         let codec = tonic::codec::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -4526,32 +6158,25 @@ pub mod view_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/penumbra.view.v1.ViewService/UnbondingTokensByAddressIndex" => {
+                "/penumbra.view.v1.ViewService/AddForwardingAccount" => {
                     #[allow(non_camel_case_types)]
-                    struct UnbondingTokensByAddressIndexSvc<T: ViewService>(pub Arc<T>);
+                    struct AddForwardingAccountSvc<T: ViewService>(pub Arc<T>);
                     impl<
                         T: ViewService,
-                    > tonic::server::ServerStreamingService<
-                        super::UnbondingTokensByAddressIndexRequest,
-                    > for UnbondingTokensByAddressIndexSvc<T> {
-                        type Response = super::UnbondingTokensByAddressIndexResponse;
-                        type ResponseStream = T::UnbondingTokensByAddressIndexStream;
+                    > tonic::server::UnaryService<super::AddForwardingAccountRequest>
+                    for AddForwardingAccountSvc<T> {
+                        type Response = super::AddForwardingAccountResponse;
                         type Future = BoxFuture<
-                            tonic::Response<Self::ResponseStream>,
+                            tonic::Response<Self::Response>,
                             tonic::Status,
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<
-                                super::UnbondingTokensByAddressIndexRequest,
-                            >,
+                            request: tonic::Request<super::AddForwardingAccountRequest>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as ViewService>::unbonding_tokens_by_address_index(
-                                        &inner,
-                                        request,
-                                    )
+                                <T as ViewService>::add_forwarding_account(&inner, request)
                                     .await
                             };
                             Box::pin(fut)
@@ -4564,7 +6189,7 @@ pub mod view_service_server {
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
-                        let method = UnbondingTokensByAddressIndexSvc(inner);
+                        let method = AddForwardingAccountSvc(inner);
                         let codec = tonic::codec::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -4575,31 +6200,33 @@ pub mod view_service_server {
                                 max_decoding_message_size,
                                 max_encoding_message_size,
                             );
-                        let res = grpc.server_streaming(method, req).await;
+                        let res = grpc.unary(method, req).await;
                         Ok(res)
                     };
                     Box::pin(fut)
                 }
-                "/penumbra.view.v1.ViewService/Auctions" => {
+                "/penumbra.view.v1.ViewService/ForwardingAccounts" => {
                     #[allow(non_camel_case_types)]
-                    struct AuctionsSvc<T: ViewService>(pub Arc<T>);
+                    struct ForwardingAccountsSvc<T: ViewService>(pub Arc<T>);
                     impl<
                         T: ViewService,
-                    > tonic::server::ServerStreamingService<super::AuctionsRequest>
-                    for AuctionsSvc<T> {
-                        type Response = super::AuctionsResponse;
-                        type ResponseStream = T::AuctionsStream;
+                    > tonic::server::ServerStreamingService<
+                        super::ForwardingAccountsRequest,
+                    > for ForwardingAccountsSvc<T> {
+                        type Response = super::ForwardingAccountsResponse;
+                        type ResponseStream = T::ForwardingAccountsStream;
                         type Future = BoxFuture<
                             tonic::Response<Self::ResponseStream>,
                             tonic::Status,
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::AuctionsRequest>,
+                            request: tonic::Request<super::ForwardingAccountsRequest>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as ViewService>::auctions(&inner, request).await
+                                <T as ViewService>::forwarding_accounts(&inner, request)
+                                    .await
                             };
                             Box::pin(fut)
                         }
@@ -4611,7 +6238,7 @@ pub mod view_service_server {
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
-                        let method = AuctionsSvc(inner);
+                        let method = ForwardingAccountsSvc(inner);
                         let codec = tonic::codec::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -1,3 +1,263 @@
+impl serde::Serialize for AddForwardingAccountRequest {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.chain_id.is_empty() {
+            len += 1;
+        }
+        if !self.node.is_empty() {
+            len += 1;
+        }
+        if !self.channel.is_empty() {
+            len += 1;
+        }
+        if self.address_index.is_some() {
+            len += 1;
+        }
+        if self.registered {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.AddForwardingAccountRequest", len)?;
+        if !self.chain_id.is_empty() {
+            struct_ser.serialize_field("chainId", &self.chain_id)?;
+        }
+        if !self.node.is_empty() {
+            struct_ser.serialize_field("node", &self.node)?;
+        }
+        if !self.channel.is_empty() {
+            struct_ser.serialize_field("channel", &self.channel)?;
+        }
+        if let Some(v) = self.address_index.as_ref() {
+            struct_ser.serialize_field("addressIndex", v)?;
+        }
+        if self.registered {
+            struct_ser.serialize_field("registered", &self.registered)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for AddForwardingAccountRequest {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "chain_id",
+            "chainId",
+            "node",
+            "channel",
+            "address_index",
+            "addressIndex",
+            "registered",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            ChainId,
+            Node,
+            Channel,
+            AddressIndex,
+            Registered,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "chainId" | "chain_id" => Ok(GeneratedField::ChainId),
+                            "node" => Ok(GeneratedField::Node),
+                            "channel" => Ok(GeneratedField::Channel),
+                            "addressIndex" | "address_index" => Ok(GeneratedField::AddressIndex),
+                            "registered" => Ok(GeneratedField::Registered),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = AddForwardingAccountRequest;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.view.v1.AddForwardingAccountRequest")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<AddForwardingAccountRequest, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut chain_id__ = None;
+                let mut node__ = None;
+                let mut channel__ = None;
+                let mut address_index__ = None;
+                let mut registered__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::ChainId => {
+                            if chain_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("chainId"));
+                            }
+                            chain_id__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Node => {
+                            if node__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("node"));
+                            }
+                            node__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Channel => {
+                            if channel__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("channel"));
+                            }
+                            channel__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::AddressIndex => {
+                            if address_index__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("addressIndex"));
+                            }
+                            address_index__ = map_.next_value()?;
+                        }
+                        GeneratedField::Registered => {
+                            if registered__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("registered"));
+                            }
+                            registered__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(AddForwardingAccountRequest {
+                    chain_id: chain_id__.unwrap_or_default(),
+                    node: node__.unwrap_or_default(),
+                    channel: channel__.unwrap_or_default(),
+                    address_index: address_index__,
+                    registered: registered__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.view.v1.AddForwardingAccountRequest", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for AddForwardingAccountResponse {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.account.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.AddForwardingAccountResponse", len)?;
+        if let Some(v) = self.account.as_ref() {
+            struct_ser.serialize_field("account", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for AddForwardingAccountResponse {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "account",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Account,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "account" => Ok(GeneratedField::Account),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = AddForwardingAccountResponse;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.view.v1.AddForwardingAccountResponse")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<AddForwardingAccountResponse, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut account__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Account => {
+                            if account__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("account"));
+                            }
+                            account__ = map_.next_value()?;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(AddForwardingAccountResponse {
+                    account: account__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.view.v1.AddForwardingAccountResponse", FIELDS, GeneratedVisitor)
+    }
+}
 impl serde::Serialize for AddressByIndexRequest {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
@@ -2267,7 +2527,7 @@ impl<'de> serde::Deserialize<'de> for broadcast_transaction_response::Confirmed
         deserializer.deserialize_struct("penumbra.view.v1.BroadcastTransactionResponse.Confirmed", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for DelegationsByAddressIndexRequest {
+impl serde::Serialize for CreateScheduleRequest {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -2275,40 +2535,68 @@ impl serde::Serialize for DelegationsByAddressIndexRequest {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.address_index.is_some() {
+        if self.plan_request.is_some() {
             len += 1;
         }
-        if self.filter != 0 {
+        if self.start_height != 0 {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.DelegationsByAddressIndexRequest", len)?;
-        if let Some(v) = self.address_index.as_ref() {
-            struct_ser.serialize_field("addressIndex", v)?;
+        if self.interval != 0 {
+            len += 1;
         }
-        if self.filter != 0 {
-            let v = delegations_by_address_index_request::Filter::try_from(self.filter)
-                .map_err(|_| serde::ser::Error::custom(format!("Invalid variant {}", self.filter)))?;
-            struct_ser.serialize_field("filter", &v)?;
+        if self.max_occurrences != 0 {
+            len += 1;
+        }
+        if self.every_epoch {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.CreateScheduleRequest", len)?;
+        if let Some(v) = self.plan_request.as_ref() {
+            struct_ser.serialize_field("planRequest", v)?;
+        }
+        if self.start_height != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("startHeight", ToString::to_string(&self.start_height).as_str())?;
+        }
+        if self.interval != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("interval", ToString::to_string(&self.interval).as_str())?;
+        }
+        if self.max_occurrences != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("maxOccurrences", ToString::to_string(&self.max_occurrences).as_str())?;
+        }
+        if self.every_epoch {
+            struct_ser.serialize_field("everyEpoch", &self.every_epoch)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for DelegationsByAddressIndexRequest {
+impl<'de> serde::Deserialize<'de> for CreateScheduleRequest {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "address_index",
-            "addressIndex",
-            "filter",
+            "plan_request",
+            "planRequest",
+            "start_height",
+            "startHeight",
+            "interval",
+            "max_occurrences",
+            "maxOccurrences",
+            "every_epoch",
+            "everyEpoch",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            AddressIndex,
-            Filter,
+            PlanRequest,
+            StartHeight,
+            Interval,
+            MaxOccurrences,
+            EveryEpoch,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -2331,8 +2619,11 @@ impl<'de> serde::Deserialize<'de> for DelegationsByAddressIndexRequest {
                         E: serde::de::Error,
                     {
                         match value {
-                            "addressIndex" | "address_index" => Ok(GeneratedField::AddressIndex),
-                            "filter" => Ok(GeneratedField::Filter),
+                            "planRequest" | "plan_request" => Ok(GeneratedField::PlanRequest),
+                            "startHeight" | "start_height" => Ok(GeneratedField::StartHeight),
+                            "interval" => Ok(GeneratedField::Interval),
+                            "maxOccurrences" | "max_occurrences" => Ok(GeneratedField::MaxOccurrences),
+                            "everyEpoch" | "every_epoch" => Ok(GeneratedField::EveryEpoch),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -2342,36 +2633,280 @@ impl<'de> serde::Deserialize<'de> for DelegationsByAddressIndexRequest {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = DelegationsByAddressIndexRequest;
+            type Value = CreateScheduleRequest;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.view.v1.DelegationsByAddressIndexRequest")
+                formatter.write_str("struct penumbra.view.v1.CreateScheduleRequest")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<DelegationsByAddressIndexRequest, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<CreateScheduleRequest, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut address_index__ = None;
-                let mut filter__ = None;
+                let mut plan_request__ = None;
+                let mut start_height__ = None;
+                let mut interval__ = None;
+                let mut max_occurrences__ = None;
+                let mut every_epoch__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::AddressIndex => {
-                            if address_index__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("addressIndex"));
+                        GeneratedField::PlanRequest => {
+                            if plan_request__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("planRequest"));
                             }
-                            address_index__ = map_.next_value()?;
+                            plan_request__ = map_.next_value()?;
                         }
-                        GeneratedField::Filter => {
-                            if filter__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("filter"));
+                        GeneratedField::StartHeight => {
+                            if start_height__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("startHeight"));
                             }
-                            filter__ = Some(map_.next_value::<delegations_by_address_index_request::Filter>()? as i32);
-                        }
-                        GeneratedField::__SkipField__ => {
-                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                            start_height__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
                         }
-                    }
+                        GeneratedField::Interval => {
+                            if interval__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("interval"));
+                            }
+                            interval__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::MaxOccurrences => {
+                            if max_occurrences__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("maxOccurrences"));
+                            }
+                            max_occurrences__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::EveryEpoch => {
+                            if every_epoch__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("everyEpoch"));
+                            }
+                            every_epoch__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(CreateScheduleRequest {
+                    plan_request: plan_request__,
+                    start_height: start_height__.unwrap_or_default(),
+                    interval: interval__.unwrap_or_default(),
+                    max_occurrences: max_occurrences__.unwrap_or_default(),
+                    every_epoch: every_epoch__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.view.v1.CreateScheduleRequest", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for CreateScheduleResponse {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.schedule_id != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.CreateScheduleResponse", len)?;
+        if self.schedule_id != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("scheduleId", ToString::to_string(&self.schedule_id).as_str())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for CreateScheduleResponse {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "schedule_id",
+            "scheduleId",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            ScheduleId,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "scheduleId" | "schedule_id" => Ok(GeneratedField::ScheduleId),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = CreateScheduleResponse;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.view.v1.CreateScheduleResponse")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<CreateScheduleResponse, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut schedule_id__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::ScheduleId => {
+                            if schedule_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("scheduleId"));
+                            }
+                            schedule_id__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(CreateScheduleResponse {
+                    schedule_id: schedule_id__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.view.v1.CreateScheduleResponse", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for DelegationsByAddressIndexRequest {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.address_index.is_some() {
+            len += 1;
+        }
+        if self.filter != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.DelegationsByAddressIndexRequest", len)?;
+        if let Some(v) = self.address_index.as_ref() {
+            struct_ser.serialize_field("addressIndex", v)?;
+        }
+        if self.filter != 0 {
+            let v = delegations_by_address_index_request::Filter::try_from(self.filter)
+                .map_err(|_| serde::ser::Error::custom(format!("Invalid variant {}", self.filter)))?;
+            struct_ser.serialize_field("filter", &v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for DelegationsByAddressIndexRequest {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "address_index",
+            "addressIndex",
+            "filter",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            AddressIndex,
+            Filter,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "addressIndex" | "address_index" => Ok(GeneratedField::AddressIndex),
+                            "filter" => Ok(GeneratedField::Filter),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = DelegationsByAddressIndexRequest;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.view.v1.DelegationsByAddressIndexRequest")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<DelegationsByAddressIndexRequest, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut address_index__ = None;
+                let mut filter__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::AddressIndex => {
+                            if address_index__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("addressIndex"));
+                            }
+                            address_index__ = map_.next_value()?;
+                        }
+                        GeneratedField::Filter => {
+                            if filter__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("filter"));
+                            }
+                            filter__ = Some(map_.next_value::<delegations_by_address_index_request::Filter>()? as i32);
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
                 }
                 Ok(DelegationsByAddressIndexRequest {
                     address_index: address_index__,
@@ -2910,29 +3445,80 @@ impl<'de> serde::Deserialize<'de> for FmdParametersResponse {
         deserializer.deserialize_struct("penumbra.view.v1.FMDParametersResponse", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for GasPricesRequest {
+impl serde::Serialize for ForwardingAccount {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
         S: serde::Serializer,
     {
         use serde::ser::SerializeStruct;
-        let len = 0;
-        let struct_ser = serializer.serialize_struct("penumbra.view.v1.GasPricesRequest", len)?;
+        let mut len = 0;
+        if !self.chain_id.is_empty() {
+            len += 1;
+        }
+        if !self.node.is_empty() {
+            len += 1;
+        }
+        if !self.channel.is_empty() {
+            len += 1;
+        }
+        if self.address_index.is_some() {
+            len += 1;
+        }
+        if !self.forwarding_address.is_empty() {
+            len += 1;
+        }
+        if self.registered {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.ForwardingAccount", len)?;
+        if !self.chain_id.is_empty() {
+            struct_ser.serialize_field("chainId", &self.chain_id)?;
+        }
+        if !self.node.is_empty() {
+            struct_ser.serialize_field("node", &self.node)?;
+        }
+        if !self.channel.is_empty() {
+            struct_ser.serialize_field("channel", &self.channel)?;
+        }
+        if let Some(v) = self.address_index.as_ref() {
+            struct_ser.serialize_field("addressIndex", v)?;
+        }
+        if !self.forwarding_address.is_empty() {
+            struct_ser.serialize_field("forwardingAddress", &self.forwarding_address)?;
+        }
+        if self.registered {
+            struct_ser.serialize_field("registered", &self.registered)?;
+        }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for GasPricesRequest {
+impl<'de> serde::Deserialize<'de> for ForwardingAccount {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
+            "chain_id",
+            "chainId",
+            "node",
+            "channel",
+            "address_index",
+            "addressIndex",
+            "forwarding_address",
+            "forwardingAddress",
+            "registered",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
+            ChainId,
+            Node,
+            Channel,
+            AddressIndex,
+            ForwardingAddress,
+            Registered,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -2954,7 +3540,15 @@ impl<'de> serde::Deserialize<'de> for GasPricesRequest {
                     where
                         E: serde::de::Error,
                     {
-                            Ok(GeneratedField::__SkipField__)
+                        match value {
+                            "chainId" | "chain_id" => Ok(GeneratedField::ChainId),
+                            "node" => Ok(GeneratedField::Node),
+                            "channel" => Ok(GeneratedField::Channel),
+                            "addressIndex" | "address_index" => Ok(GeneratedField::AddressIndex),
+                            "forwardingAddress" | "forwarding_address" => Ok(GeneratedField::ForwardingAddress),
+                            "registered" => Ok(GeneratedField::Registered),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
                     }
                 }
                 deserializer.deserialize_identifier(GeneratedVisitor)
@@ -2962,67 +3556,119 @@ impl<'de> serde::Deserialize<'de> for GasPricesRequest {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = GasPricesRequest;
+            type Value = ForwardingAccount;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.view.v1.GasPricesRequest")
+                formatter.write_str("struct penumbra.view.v1.ForwardingAccount")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<GasPricesRequest, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ForwardingAccount, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                while map_.next_key::<GeneratedField>()?.is_some() {
-                    let _ = map_.next_value::<serde::de::IgnoredAny>()?;
-                }
-                Ok(GasPricesRequest {
-                })
-            }
-        }
-        deserializer.deserialize_struct("penumbra.view.v1.GasPricesRequest", FIELDS, GeneratedVisitor)
-    }
-}
-impl serde::Serialize for GasPricesResponse {
-    #[allow(deprecated)]
-    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
-    where
-        S: serde::Serializer,
+                let mut chain_id__ = None;
+                let mut node__ = None;
+                let mut channel__ = None;
+                let mut address_index__ = None;
+                let mut forwarding_address__ = None;
+                let mut registered__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::ChainId => {
+                            if chain_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("chainId"));
+                            }
+                            chain_id__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Node => {
+                            if node__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("node"));
+                            }
+                            node__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Channel => {
+                            if channel__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("channel"));
+                            }
+                            channel__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::AddressIndex => {
+                            if address_index__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("addressIndex"));
+                            }
+                            address_index__ = map_.next_value()?;
+                        }
+                        GeneratedField::ForwardingAddress => {
+                            if forwarding_address__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("forwardingAddress"));
+                            }
+                            forwarding_address__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Registered => {
+                            if registered__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("registered"));
+                            }
+                            registered__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(ForwardingAccount {
+                    chain_id: chain_id__.unwrap_or_default(),
+                    node: node__.unwrap_or_default(),
+                    channel: channel__.unwrap_or_default(),
+                    address_index: address_index__,
+                    forwarding_address: forwarding_address__.unwrap_or_default(),
+                    registered: registered__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.view.v1.ForwardingAccount", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for ForwardingAccountsRequest {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.gas_prices.is_some() {
+        if !self.chain_id.is_empty() {
             len += 1;
         }
-        if !self.alt_gas_prices.is_empty() {
+        if self.check_pending {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.GasPricesResponse", len)?;
-        if let Some(v) = self.gas_prices.as_ref() {
-            struct_ser.serialize_field("gasPrices", v)?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.ForwardingAccountsRequest", len)?;
+        if !self.chain_id.is_empty() {
+            struct_ser.serialize_field("chainId", &self.chain_id)?;
         }
-        if !self.alt_gas_prices.is_empty() {
-            struct_ser.serialize_field("altGasPrices", &self.alt_gas_prices)?;
+        if self.check_pending {
+            struct_ser.serialize_field("checkPending", &self.check_pending)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for GasPricesResponse {
+impl<'de> serde::Deserialize<'de> for ForwardingAccountsRequest {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "gas_prices",
-            "gasPrices",
-            "alt_gas_prices",
-            "altGasPrices",
+            "chain_id",
+            "chainId",
+            "check_pending",
+            "checkPending",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            GasPrices,
-            AltGasPrices,
+            ChainId,
+            CheckPending,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -3045,8 +3691,8 @@ impl<'de> serde::Deserialize<'de> for GasPricesResponse {
                         E: serde::de::Error,
                     {
                         match value {
-                            "gasPrices" | "gas_prices" => Ok(GeneratedField::GasPrices),
-                            "altGasPrices" | "alt_gas_prices" => Ok(GeneratedField::AltGasPrices),
+                            "chainId" | "chain_id" => Ok(GeneratedField::ChainId),
+                            "checkPending" | "check_pending" => Ok(GeneratedField::CheckPending),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -3056,47 +3702,47 @@ impl<'de> serde::Deserialize<'de> for GasPricesResponse {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = GasPricesResponse;
+            type Value = ForwardingAccountsRequest;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.view.v1.GasPricesResponse")
+                formatter.write_str("struct penumbra.view.v1.ForwardingAccountsRequest")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<GasPricesResponse, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ForwardingAccountsRequest, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut gas_prices__ = None;
-                let mut alt_gas_prices__ = None;
+                let mut chain_id__ = None;
+                let mut check_pending__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::GasPrices => {
-                            if gas_prices__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("gasPrices"));
+                        GeneratedField::ChainId => {
+                            if chain_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("chainId"));
                             }
-                            gas_prices__ = map_.next_value()?;
+                            chain_id__ = Some(map_.next_value()?);
                         }
-                        GeneratedField::AltGasPrices => {
-                            if alt_gas_prices__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("altGasPrices"));
+                        GeneratedField::CheckPending => {
+                            if check_pending__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("checkPending"));
                             }
-                            alt_gas_prices__ = Some(map_.next_value()?);
+                            check_pending__ = Some(map_.next_value()?);
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(GasPricesResponse {
-                    gas_prices: gas_prices__,
-                    alt_gas_prices: alt_gas_prices__.unwrap_or_default(),
+                Ok(ForwardingAccountsRequest {
+                    chain_id: chain_id__.unwrap_or_default(),
+                    check_pending: check_pending__.unwrap_or_default(),
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.view.v1.GasPricesResponse", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.view.v1.ForwardingAccountsRequest", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for IndexByAddressRequest {
+impl serde::Serialize for ForwardingAccountsResponse {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -3104,29 +3750,38 @@ impl serde::Serialize for IndexByAddressRequest {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.address.is_some() {
+        if self.account.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.IndexByAddressRequest", len)?;
-        if let Some(v) = self.address.as_ref() {
-            struct_ser.serialize_field("address", v)?;
+        if !self.pending_deposits.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.ForwardingAccountsResponse", len)?;
+        if let Some(v) = self.account.as_ref() {
+            struct_ser.serialize_field("account", v)?;
+        }
+        if !self.pending_deposits.is_empty() {
+            struct_ser.serialize_field("pendingDeposits", &self.pending_deposits)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for IndexByAddressRequest {
+impl<'de> serde::Deserialize<'de> for ForwardingAccountsResponse {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "address",
+            "account",
+            "pending_deposits",
+            "pendingDeposits",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            Address,
+            Account,
+            PendingDeposits,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -3149,7 +3804,8 @@ impl<'de> serde::Deserialize<'de> for IndexByAddressRequest {
                         E: serde::de::Error,
                     {
                         match value {
-                            "address" => Ok(GeneratedField::Address),
+                            "account" => Ok(GeneratedField::Account),
+                            "pendingDeposits" | "pending_deposits" => Ok(GeneratedField::PendingDeposits),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -3159,39 +3815,47 @@ impl<'de> serde::Deserialize<'de> for IndexByAddressRequest {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = IndexByAddressRequest;
+            type Value = ForwardingAccountsResponse;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.view.v1.IndexByAddressRequest")
+                formatter.write_str("struct penumbra.view.v1.ForwardingAccountsResponse")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<IndexByAddressRequest, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ForwardingAccountsResponse, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut address__ = None;
+                let mut account__ = None;
+                let mut pending_deposits__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::Address => {
-                            if address__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("address"));
+                        GeneratedField::Account => {
+                            if account__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("account"));
                             }
-                            address__ = map_.next_value()?;
+                            account__ = map_.next_value()?;
+                        }
+                        GeneratedField::PendingDeposits => {
+                            if pending_deposits__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("pendingDeposits"));
+                            }
+                            pending_deposits__ = Some(map_.next_value()?);
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(IndexByAddressRequest {
-                    address: address__,
+                Ok(ForwardingAccountsResponse {
+                    account: account__,
+                    pending_deposits: pending_deposits__.unwrap_or_default(),
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.view.v1.IndexByAddressRequest", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.view.v1.ForwardingAccountsResponse", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for IndexByAddressResponse {
+impl serde::Serialize for forwarding_accounts_response::Deposit {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -3199,30 +3863,37 @@ impl serde::Serialize for IndexByAddressResponse {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.address_index.is_some() {
+        if !self.denom.is_empty() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.IndexByAddressResponse", len)?;
-        if let Some(v) = self.address_index.as_ref() {
-            struct_ser.serialize_field("addressIndex", v)?;
+        if !self.amount.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.ForwardingAccountsResponse.Deposit", len)?;
+        if !self.denom.is_empty() {
+            struct_ser.serialize_field("denom", &self.denom)?;
+        }
+        if !self.amount.is_empty() {
+            struct_ser.serialize_field("amount", &self.amount)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for IndexByAddressResponse {
+impl<'de> serde::Deserialize<'de> for forwarding_accounts_response::Deposit {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "address_index",
-            "addressIndex",
+            "denom",
+            "amount",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            AddressIndex,
+            Denom,
+            Amount,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -3245,7 +3916,8 @@ impl<'de> serde::Deserialize<'de> for IndexByAddressResponse {
                         E: serde::de::Error,
                     {
                         match value {
-                            "addressIndex" | "address_index" => Ok(GeneratedField::AddressIndex),
+                            "denom" => Ok(GeneratedField::Denom),
+                            "amount" => Ok(GeneratedField::Amount),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -3255,79 +3927,69 @@ impl<'de> serde::Deserialize<'de> for IndexByAddressResponse {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = IndexByAddressResponse;
+            type Value = forwarding_accounts_response::Deposit;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.view.v1.IndexByAddressResponse")
+                formatter.write_str("struct penumbra.view.v1.ForwardingAccountsResponse.Deposit")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<IndexByAddressResponse, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<forwarding_accounts_response::Deposit, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut address_index__ = None;
+                let mut denom__ = None;
+                let mut amount__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::AddressIndex => {
-                            if address_index__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("addressIndex"));
+                        GeneratedField::Denom => {
+                            if denom__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("denom"));
                             }
-                            address_index__ = map_.next_value()?;
+                            denom__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Amount => {
+                            if amount__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("amount"));
+                            }
+                            amount__ = Some(map_.next_value()?);
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(IndexByAddressResponse {
-                    address_index: address_index__,
+                Ok(forwarding_accounts_response::Deposit {
+                    denom: denom__.unwrap_or_default(),
+                    amount: amount__.unwrap_or_default(),
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.view.v1.IndexByAddressResponse", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.view.v1.ForwardingAccountsResponse.Deposit", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for NoteByCommitmentRequest {
+impl serde::Serialize for GasPricesRequest {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
         S: serde::Serializer,
     {
         use serde::ser::SerializeStruct;
-        let mut len = 0;
-        if self.note_commitment.is_some() {
-            len += 1;
-        }
-        if self.await_detection {
-            len += 1;
-        }
-        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.NoteByCommitmentRequest", len)?;
-        if let Some(v) = self.note_commitment.as_ref() {
-            struct_ser.serialize_field("noteCommitment", v)?;
-        }
-        if self.await_detection {
-            struct_ser.serialize_field("awaitDetection", &self.await_detection)?;
-        }
+        let len = 0;
+        let struct_ser = serializer.serialize_struct("penumbra.view.v1.GasPricesRequest", len)?;
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for NoteByCommitmentRequest {
+impl<'de> serde::Deserialize<'de> for GasPricesRequest {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "note_commitment",
-            "noteCommitment",
-            "await_detection",
-            "awaitDetection",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            NoteCommitment,
-            AwaitDetection,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -3349,11 +4011,7 @@ impl<'de> serde::Deserialize<'de> for NoteByCommitmentRequest {
                     where
                         E: serde::de::Error,
                     {
-                        match value {
-                            "noteCommitment" | "note_commitment" => Ok(GeneratedField::NoteCommitment),
-                            "awaitDetection" | "await_detection" => Ok(GeneratedField::AwaitDetection),
-                            _ => Ok(GeneratedField::__SkipField__),
-                        }
+                            Ok(GeneratedField::__SkipField__)
                     }
                 }
                 deserializer.deserialize_identifier(GeneratedVisitor)
@@ -3361,47 +4019,27 @@ impl<'de> serde::Deserialize<'de> for NoteByCommitmentRequest {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = NoteByCommitmentRequest;
+            type Value = GasPricesRequest;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.view.v1.NoteByCommitmentRequest")
+                formatter.write_str("struct penumbra.view.v1.GasPricesRequest")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<NoteByCommitmentRequest, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<GasPricesRequest, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut note_commitment__ = None;
-                let mut await_detection__ = None;
-                while let Some(k) = map_.next_key()? {
-                    match k {
-                        GeneratedField::NoteCommitment => {
-                            if note_commitment__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("noteCommitment"));
-                            }
-                            note_commitment__ = map_.next_value()?;
-                        }
-                        GeneratedField::AwaitDetection => {
-                            if await_detection__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("awaitDetection"));
-                            }
-                            await_detection__ = Some(map_.next_value()?);
-                        }
-                        GeneratedField::__SkipField__ => {
-                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
-                        }
-                    }
+                while map_.next_key::<GeneratedField>()?.is_some() {
+                    let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                 }
-                Ok(NoteByCommitmentRequest {
-                    note_commitment: note_commitment__,
-                    await_detection: await_detection__.unwrap_or_default(),
+                Ok(GasPricesRequest {
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.view.v1.NoteByCommitmentRequest", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.view.v1.GasPricesRequest", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for NoteByCommitmentResponse {
+impl serde::Serialize for GasPricesResponse {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -3409,30 +4047,39 @@ impl serde::Serialize for NoteByCommitmentResponse {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.spendable_note.is_some() {
+        if self.gas_prices.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.NoteByCommitmentResponse", len)?;
-        if let Some(v) = self.spendable_note.as_ref() {
-            struct_ser.serialize_field("spendableNote", v)?;
+        if !self.alt_gas_prices.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.GasPricesResponse", len)?;
+        if let Some(v) = self.gas_prices.as_ref() {
+            struct_ser.serialize_field("gasPrices", v)?;
+        }
+        if !self.alt_gas_prices.is_empty() {
+            struct_ser.serialize_field("altGasPrices", &self.alt_gas_prices)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for NoteByCommitmentResponse {
+impl<'de> serde::Deserialize<'de> for GasPricesResponse {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "spendable_note",
-            "spendableNote",
+            "gas_prices",
+            "gasPrices",
+            "alt_gas_prices",
+            "altGasPrices",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            SpendableNote,
+            GasPrices,
+            AltGasPrices,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -3455,7 +4102,8 @@ impl<'de> serde::Deserialize<'de> for NoteByCommitmentResponse {
                         E: serde::de::Error,
                     {
                         match value {
-                            "spendableNote" | "spendable_note" => Ok(GeneratedField::SpendableNote),
+                            "gasPrices" | "gas_prices" => Ok(GeneratedField::GasPrices),
+                            "altGasPrices" | "alt_gas_prices" => Ok(GeneratedField::AltGasPrices),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -3465,39 +4113,47 @@ impl<'de> serde::Deserialize<'de> for NoteByCommitmentResponse {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = NoteByCommitmentResponse;
+            type Value = GasPricesResponse;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.view.v1.NoteByCommitmentResponse")
+                formatter.write_str("struct penumbra.view.v1.GasPricesResponse")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<NoteByCommitmentResponse, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<GasPricesResponse, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut spendable_note__ = None;
+                let mut gas_prices__ = None;
+                let mut alt_gas_prices__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::SpendableNote => {
-                            if spendable_note__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("spendableNote"));
+                        GeneratedField::GasPrices => {
+                            if gas_prices__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("gasPrices"));
                             }
-                            spendable_note__ = map_.next_value()?;
+                            gas_prices__ = map_.next_value()?;
+                        }
+                        GeneratedField::AltGasPrices => {
+                            if alt_gas_prices__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("altGasPrices"));
+                            }
+                            alt_gas_prices__ = Some(map_.next_value()?);
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(NoteByCommitmentResponse {
-                    spendable_note: spendable_note__,
+                Ok(GasPricesResponse {
+                    gas_prices: gas_prices__,
+                    alt_gas_prices: alt_gas_prices__.unwrap_or_default(),
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.view.v1.NoteByCommitmentResponse", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.view.v1.GasPricesResponse", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for NotesForVotingRequest {
+impl serde::Serialize for GovernanceFeedItem {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -3505,40 +4161,112 @@ impl serde::Serialize for NotesForVotingRequest {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.votable_at_height != 0 {
+        if self.proposal.is_some() {
             len += 1;
         }
-        if self.address_index.is_some() {
+        if self.state.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.NotesForVotingRequest", len)?;
-        if self.votable_at_height != 0 {
+        if self.start_block_height != 0 {
+            len += 1;
+        }
+        if self.end_block_height != 0 {
+            len += 1;
+        }
+        if self.votable_notes != 0 {
+            len += 1;
+        }
+        if self.votable_power.is_some() {
+            len += 1;
+        }
+        if !self.delegator_votes.is_empty() {
+            len += 1;
+        }
+        if self.proxy_vote.is_some() {
+            len += 1;
+        }
+        if self.claimable_deposit.is_some() {
+            len += 1;
+        }
+        if self.state_changed {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.GovernanceFeedItem", len)?;
+        if let Some(v) = self.proposal.as_ref() {
+            struct_ser.serialize_field("proposal", v)?;
+        }
+        if let Some(v) = self.state.as_ref() {
+            struct_ser.serialize_field("state", v)?;
+        }
+        if self.start_block_height != 0 {
             #[allow(clippy::needless_borrow)]
-            struct_ser.serialize_field("votableAtHeight", ToString::to_string(&self.votable_at_height).as_str())?;
+            struct_ser.serialize_field("startBlockHeight", ToString::to_string(&self.start_block_height).as_str())?;
         }
-        if let Some(v) = self.address_index.as_ref() {
-            struct_ser.serialize_field("addressIndex", v)?;
+        if self.end_block_height != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("endBlockHeight", ToString::to_string(&self.end_block_height).as_str())?;
+        }
+        if self.votable_notes != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("votableNotes", ToString::to_string(&self.votable_notes).as_str())?;
+        }
+        if let Some(v) = self.votable_power.as_ref() {
+            struct_ser.serialize_field("votablePower", v)?;
+        }
+        if !self.delegator_votes.is_empty() {
+            struct_ser.serialize_field("delegatorVotes", &self.delegator_votes)?;
+        }
+        if let Some(v) = self.proxy_vote.as_ref() {
+            struct_ser.serialize_field("proxyVote", v)?;
+        }
+        if let Some(v) = self.claimable_deposit.as_ref() {
+            struct_ser.serialize_field("claimableDeposit", v)?;
+        }
+        if self.state_changed {
+            struct_ser.serialize_field("stateChanged", &self.state_changed)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for NotesForVotingRequest {
+impl<'de> serde::Deserialize<'de> for GovernanceFeedItem {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "votable_at_height",
-            "votableAtHeight",
-            "address_index",
-            "addressIndex",
+            "proposal",
+            "state",
+            "start_block_height",
+            "startBlockHeight",
+            "end_block_height",
+            "endBlockHeight",
+            "votable_notes",
+            "votableNotes",
+            "votable_power",
+            "votablePower",
+            "delegator_votes",
+            "delegatorVotes",
+            "proxy_vote",
+            "proxyVote",
+            "claimable_deposit",
+            "claimableDeposit",
+            "state_changed",
+            "stateChanged",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            VotableAtHeight,
-            AddressIndex,
+            Proposal,
+            State,
+            StartBlockHeight,
+            EndBlockHeight,
+            VotableNotes,
+            VotablePower,
+            DelegatorVotes,
+            ProxyVote,
+            ClaimableDeposit,
+            StateChanged,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -3561,8 +4289,16 @@ impl<'de> serde::Deserialize<'de> for NotesForVotingRequest {
                         E: serde::de::Error,
                     {
                         match value {
-                            "votableAtHeight" | "votable_at_height" => Ok(GeneratedField::VotableAtHeight),
-                            "addressIndex" | "address_index" => Ok(GeneratedField::AddressIndex),
+                            "proposal" => Ok(GeneratedField::Proposal),
+                            "state" => Ok(GeneratedField::State),
+                            "startBlockHeight" | "start_block_height" => Ok(GeneratedField::StartBlockHeight),
+                            "endBlockHeight" | "end_block_height" => Ok(GeneratedField::EndBlockHeight),
+                            "votableNotes" | "votable_notes" => Ok(GeneratedField::VotableNotes),
+                            "votablePower" | "votable_power" => Ok(GeneratedField::VotablePower),
+                            "delegatorVotes" | "delegator_votes" => Ok(GeneratedField::DelegatorVotes),
+                            "proxyVote" | "proxy_vote" => Ok(GeneratedField::ProxyVote),
+                            "claimableDeposit" | "claimable_deposit" => Ok(GeneratedField::ClaimableDeposit),
+                            "stateChanged" | "state_changed" => Ok(GeneratedField::StateChanged),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -3572,49 +4308,117 @@ impl<'de> serde::Deserialize<'de> for NotesForVotingRequest {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = NotesForVotingRequest;
+            type Value = GovernanceFeedItem;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.view.v1.NotesForVotingRequest")
+                formatter.write_str("struct penumbra.view.v1.GovernanceFeedItem")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<NotesForVotingRequest, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<GovernanceFeedItem, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut votable_at_height__ = None;
-                let mut address_index__ = None;
+                let mut proposal__ = None;
+                let mut state__ = None;
+                let mut start_block_height__ = None;
+                let mut end_block_height__ = None;
+                let mut votable_notes__ = None;
+                let mut votable_power__ = None;
+                let mut delegator_votes__ = None;
+                let mut proxy_vote__ = None;
+                let mut claimable_deposit__ = None;
+                let mut state_changed__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::VotableAtHeight => {
-                            if votable_at_height__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("votableAtHeight"));
+                        GeneratedField::Proposal => {
+                            if proposal__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("proposal"));
                             }
-                            votable_at_height__ = 
+                            proposal__ = map_.next_value()?;
+                        }
+                        GeneratedField::State => {
+                            if state__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("state"));
+                            }
+                            state__ = map_.next_value()?;
+                        }
+                        GeneratedField::StartBlockHeight => {
+                            if start_block_height__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("startBlockHeight"));
+                            }
+                            start_block_height__ = 
                                 Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
                             ;
                         }
-                        GeneratedField::AddressIndex => {
-                            if address_index__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("addressIndex"));
+                        GeneratedField::EndBlockHeight => {
+                            if end_block_height__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("endBlockHeight"));
                             }
-                            address_index__ = map_.next_value()?;
+                            end_block_height__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::VotableNotes => {
+                            if votable_notes__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("votableNotes"));
+                            }
+                            votable_notes__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::VotablePower => {
+                            if votable_power__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("votablePower"));
+                            }
+                            votable_power__ = map_.next_value()?;
+                        }
+                        GeneratedField::DelegatorVotes => {
+                            if delegator_votes__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("delegatorVotes"));
+                            }
+                            delegator_votes__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::ProxyVote => {
+                            if proxy_vote__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("proxyVote"));
+                            }
+                            proxy_vote__ = map_.next_value()?;
+                        }
+                        GeneratedField::ClaimableDeposit => {
+                            if claimable_deposit__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("claimableDeposit"));
+                            }
+                            claimable_deposit__ = map_.next_value()?;
+                        }
+                        GeneratedField::StateChanged => {
+                            if state_changed__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("stateChanged"));
+                            }
+                            state_changed__ = Some(map_.next_value()?);
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(NotesForVotingRequest {
-                    votable_at_height: votable_at_height__.unwrap_or_default(),
-                    address_index: address_index__,
+                Ok(GovernanceFeedItem {
+                    proposal: proposal__,
+                    state: state__,
+                    start_block_height: start_block_height__.unwrap_or_default(),
+                    end_block_height: end_block_height__.unwrap_or_default(),
+                    votable_notes: votable_notes__.unwrap_or_default(),
+                    votable_power: votable_power__,
+                    delegator_votes: delegator_votes__.unwrap_or_default(),
+                    proxy_vote: proxy_vote__,
+                    claimable_deposit: claimable_deposit__,
+                    state_changed: state_changed__.unwrap_or_default(),
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.view.v1.NotesForVotingRequest", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.view.v1.GovernanceFeedItem", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for NotesForVotingResponse {
+impl serde::Serialize for GovernanceFeedRequest {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -3622,39 +4426,47 @@ impl serde::Serialize for NotesForVotingResponse {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.note_record.is_some() {
+        if self.inactive {
             len += 1;
         }
-        if self.identity_key.is_some() {
+        if self.actionable_only {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.NotesForVotingResponse", len)?;
-        if let Some(v) = self.note_record.as_ref() {
-            struct_ser.serialize_field("noteRecord", v)?;
+        if self.mark_seen {
+            len += 1;
         }
-        if let Some(v) = self.identity_key.as_ref() {
-            struct_ser.serialize_field("identityKey", v)?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.GovernanceFeedRequest", len)?;
+        if self.inactive {
+            struct_ser.serialize_field("inactive", &self.inactive)?;
+        }
+        if self.actionable_only {
+            struct_ser.serialize_field("actionableOnly", &self.actionable_only)?;
+        }
+        if self.mark_seen {
+            struct_ser.serialize_field("markSeen", &self.mark_seen)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for NotesForVotingResponse {
+impl<'de> serde::Deserialize<'de> for GovernanceFeedRequest {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "note_record",
-            "noteRecord",
-            "identity_key",
-            "identityKey",
+            "inactive",
+            "actionable_only",
+            "actionableOnly",
+            "mark_seen",
+            "markSeen",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            NoteRecord,
-            IdentityKey,
+            Inactive,
+            ActionableOnly,
+            MarkSeen,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -3677,8 +4489,9 @@ impl<'de> serde::Deserialize<'de> for NotesForVotingResponse {
                         E: serde::de::Error,
                     {
                         match value {
-                            "noteRecord" | "note_record" => Ok(GeneratedField::NoteRecord),
-                            "identityKey" | "identity_key" => Ok(GeneratedField::IdentityKey),
+                            "inactive" => Ok(GeneratedField::Inactive),
+                            "actionableOnly" | "actionable_only" => Ok(GeneratedField::ActionableOnly),
+                            "markSeen" | "mark_seen" => Ok(GeneratedField::MarkSeen),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -3688,47 +4501,55 @@ impl<'de> serde::Deserialize<'de> for NotesForVotingResponse {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = NotesForVotingResponse;
+            type Value = GovernanceFeedRequest;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.view.v1.NotesForVotingResponse")
+                formatter.write_str("struct penumbra.view.v1.GovernanceFeedRequest")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<NotesForVotingResponse, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<GovernanceFeedRequest, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut note_record__ = None;
-                let mut identity_key__ = None;
+                let mut inactive__ = None;
+                let mut actionable_only__ = None;
+                let mut mark_seen__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::NoteRecord => {
-                            if note_record__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("noteRecord"));
+                        GeneratedField::Inactive => {
+                            if inactive__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("inactive"));
                             }
-                            note_record__ = map_.next_value()?;
+                            inactive__ = Some(map_.next_value()?);
                         }
-                        GeneratedField::IdentityKey => {
-                            if identity_key__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("identityKey"));
+                        GeneratedField::ActionableOnly => {
+                            if actionable_only__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("actionableOnly"));
                             }
-                            identity_key__ = map_.next_value()?;
+                            actionable_only__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::MarkSeen => {
+                            if mark_seen__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("markSeen"));
+                            }
+                            mark_seen__ = Some(map_.next_value()?);
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(NotesForVotingResponse {
-                    note_record: note_record__,
-                    identity_key: identity_key__,
+                Ok(GovernanceFeedRequest {
+                    inactive: inactive__.unwrap_or_default(),
+                    actionable_only: actionable_only__.unwrap_or_default(),
+                    mark_seen: mark_seen__.unwrap_or_default(),
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.view.v1.NotesForVotingResponse", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.view.v1.GovernanceFeedRequest", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for NotesRequest {
+impl serde::Serialize for GovernanceFeedResponse {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -3736,57 +4557,29 @@ impl serde::Serialize for NotesRequest {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.include_spent {
-            len += 1;
-        }
-        if self.asset_id.is_some() {
-            len += 1;
-        }
-        if self.address_index.is_some() {
-            len += 1;
-        }
-        if self.amount_to_spend.is_some() {
+        if self.item.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.NotesRequest", len)?;
-        if self.include_spent {
-            struct_ser.serialize_field("includeSpent", &self.include_spent)?;
-        }
-        if let Some(v) = self.asset_id.as_ref() {
-            struct_ser.serialize_field("assetId", v)?;
-        }
-        if let Some(v) = self.address_index.as_ref() {
-            struct_ser.serialize_field("addressIndex", v)?;
-        }
-        if let Some(v) = self.amount_to_spend.as_ref() {
-            struct_ser.serialize_field("amountToSpend", v)?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.GovernanceFeedResponse", len)?;
+        if let Some(v) = self.item.as_ref() {
+            struct_ser.serialize_field("item", v)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for NotesRequest {
+impl<'de> serde::Deserialize<'de> for GovernanceFeedResponse {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "include_spent",
-            "includeSpent",
-            "asset_id",
-            "assetId",
-            "address_index",
-            "addressIndex",
-            "amount_to_spend",
-            "amountToSpend",
+            "item",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            IncludeSpent,
-            AssetId,
-            AddressIndex,
-            AmountToSpend,
+            Item,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -3809,10 +4602,7 @@ impl<'de> serde::Deserialize<'de> for NotesRequest {
                         E: serde::de::Error,
                     {
                         match value {
-                            "includeSpent" | "include_spent" => Ok(GeneratedField::IncludeSpent),
-                            "assetId" | "asset_id" => Ok(GeneratedField::AssetId),
-                            "addressIndex" | "address_index" => Ok(GeneratedField::AddressIndex),
-                            "amountToSpend" | "amount_to_spend" => Ok(GeneratedField::AmountToSpend),
+                            "item" => Ok(GeneratedField::Item),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -3822,63 +4612,39 @@ impl<'de> serde::Deserialize<'de> for NotesRequest {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = NotesRequest;
+            type Value = GovernanceFeedResponse;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.view.v1.NotesRequest")
+                formatter.write_str("struct penumbra.view.v1.GovernanceFeedResponse")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<NotesRequest, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<GovernanceFeedResponse, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut include_spent__ = None;
-                let mut asset_id__ = None;
-                let mut address_index__ = None;
-                let mut amount_to_spend__ = None;
+                let mut item__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::IncludeSpent => {
-                            if include_spent__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("includeSpent"));
-                            }
-                            include_spent__ = Some(map_.next_value()?);
-                        }
-                        GeneratedField::AssetId => {
-                            if asset_id__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("assetId"));
-                            }
-                            asset_id__ = map_.next_value()?;
-                        }
-                        GeneratedField::AddressIndex => {
-                            if address_index__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("addressIndex"));
-                            }
-                            address_index__ = map_.next_value()?;
-                        }
-                        GeneratedField::AmountToSpend => {
-                            if amount_to_spend__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("amountToSpend"));
+                        GeneratedField::Item => {
+                            if item__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("item"));
                             }
-                            amount_to_spend__ = map_.next_value()?;
+                            item__ = map_.next_value()?;
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(NotesRequest {
-                    include_spent: include_spent__.unwrap_or_default(),
-                    asset_id: asset_id__,
-                    address_index: address_index__,
-                    amount_to_spend: amount_to_spend__,
+                Ok(GovernanceFeedResponse {
+                    item: item__,
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.view.v1.NotesRequest", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.view.v1.GovernanceFeedResponse", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for NotesResponse {
+impl serde::Serialize for Ics20WithdrawalRecord {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -3886,30 +4652,86 @@ impl serde::Serialize for NotesResponse {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.note_record.is_some() {
+        if self.withdrawal.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.NotesResponse", len)?;
-        if let Some(v) = self.note_record.as_ref() {
-            struct_ser.serialize_field("noteRecord", v)?;
+        if self.transaction_id.is_some() {
+            len += 1;
         }
-        struct_ser.end()
-    }
-}
-impl<'de> serde::Deserialize<'de> for NotesResponse {
-    #[allow(deprecated)]
-    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
-    where
-        D: serde::Deserializer<'de>,
-    {
-        const FIELDS: &[&str] = &[
-            "note_record",
-            "noteRecord",
-        ];
-
-        #[allow(clippy::enum_variant_names)]
-        enum GeneratedField {
-            NoteRecord,
+        if self.height_sent != 0 {
+            len += 1;
+        }
+        if self.sequence != 0 {
+            len += 1;
+        }
+        if self.status != 0 {
+            len += 1;
+        }
+        if self.height_settled != 0 {
+            len += 1;
+        }
+        if self.refund_note_commitment.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.Ics20WithdrawalRecord", len)?;
+        if let Some(v) = self.withdrawal.as_ref() {
+            struct_ser.serialize_field("withdrawal", v)?;
+        }
+        if let Some(v) = self.transaction_id.as_ref() {
+            struct_ser.serialize_field("transactionId", v)?;
+        }
+        if self.height_sent != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("heightSent", ToString::to_string(&self.height_sent).as_str())?;
+        }
+        if self.sequence != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("sequence", ToString::to_string(&self.sequence).as_str())?;
+        }
+        if self.status != 0 {
+            let v = Ics20WithdrawalStatus::try_from(self.status)
+                .map_err(|_| serde::ser::Error::custom(format!("Invalid variant {}", self.status)))?;
+            struct_ser.serialize_field("status", &v)?;
+        }
+        if self.height_settled != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("heightSettled", ToString::to_string(&self.height_settled).as_str())?;
+        }
+        if let Some(v) = self.refund_note_commitment.as_ref() {
+            struct_ser.serialize_field("refundNoteCommitment", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for Ics20WithdrawalRecord {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "withdrawal",
+            "transaction_id",
+            "transactionId",
+            "height_sent",
+            "heightSent",
+            "sequence",
+            "status",
+            "height_settled",
+            "heightSettled",
+            "refund_note_commitment",
+            "refundNoteCommitment",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Withdrawal,
+            TransactionId,
+            HeightSent,
+            Sequence,
+            Status,
+            HeightSettled,
+            RefundNoteCommitment,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -3932,7 +4754,13 @@ impl<'de> serde::Deserialize<'de> for NotesResponse {
                         E: serde::de::Error,
                     {
                         match value {
-                            "noteRecord" | "note_record" => Ok(GeneratedField::NoteRecord),
+                            "withdrawal" => Ok(GeneratedField::Withdrawal),
+                            "transactionId" | "transaction_id" => Ok(GeneratedField::TransactionId),
+                            "heightSent" | "height_sent" => Ok(GeneratedField::HeightSent),
+                            "sequence" => Ok(GeneratedField::Sequence),
+                            "status" => Ok(GeneratedField::Status),
+                            "heightSettled" | "height_settled" => Ok(GeneratedField::HeightSettled),
+                            "refundNoteCommitment" | "refund_note_commitment" => Ok(GeneratedField::RefundNoteCommitment),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -3942,39 +4770,173 @@ impl<'de> serde::Deserialize<'de> for NotesResponse {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = NotesResponse;
+            type Value = Ics20WithdrawalRecord;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.view.v1.NotesResponse")
+                formatter.write_str("struct penumbra.view.v1.Ics20WithdrawalRecord")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<NotesResponse, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<Ics20WithdrawalRecord, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut note_record__ = None;
+                let mut withdrawal__ = None;
+                let mut transaction_id__ = None;
+                let mut height_sent__ = None;
+                let mut sequence__ = None;
+                let mut status__ = None;
+                let mut height_settled__ = None;
+                let mut refund_note_commitment__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::NoteRecord => {
-                            if note_record__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("noteRecord"));
+                        GeneratedField::Withdrawal => {
+                            if withdrawal__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("withdrawal"));
                             }
-                            note_record__ = map_.next_value()?;
+                            withdrawal__ = map_.next_value()?;
+                        }
+                        GeneratedField::TransactionId => {
+                            if transaction_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("transactionId"));
+                            }
+                            transaction_id__ = map_.next_value()?;
+                        }
+                        GeneratedField::HeightSent => {
+                            if height_sent__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("heightSent"));
+                            }
+                            height_sent__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Sequence => {
+                            if sequence__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("sequence"));
+                            }
+                            sequence__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Status => {
+                            if status__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("status"));
+                            }
+                            status__ = Some(map_.next_value::<Ics20WithdrawalStatus>()? as i32);
+                        }
+                        GeneratedField::HeightSettled => {
+                            if height_settled__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("heightSettled"));
+                            }
+                            height_settled__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::RefundNoteCommitment => {
+                            if refund_note_commitment__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("refundNoteCommitment"));
+                            }
+                            refund_note_commitment__ = map_.next_value()?;
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(NotesResponse {
-                    note_record: note_record__,
+                Ok(Ics20WithdrawalRecord {
+                    withdrawal: withdrawal__,
+                    transaction_id: transaction_id__,
+                    height_sent: height_sent__.unwrap_or_default(),
+                    sequence: sequence__.unwrap_or_default(),
+                    status: status__.unwrap_or_default(),
+                    height_settled: height_settled__.unwrap_or_default(),
+                    refund_note_commitment: refund_note_commitment__,
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.view.v1.NotesResponse", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.view.v1.Ics20WithdrawalRecord", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for NullifierStatusRequest {
+impl serde::Serialize for Ics20WithdrawalStatus {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let variant = match self {
+            Self::Unspecified => "ICS20_WITHDRAWAL_STATUS_UNSPECIFIED",
+            Self::Pending => "ICS20_WITHDRAWAL_STATUS_PENDING",
+            Self::Acknowledged => "ICS20_WITHDRAWAL_STATUS_ACKNOWLEDGED",
+            Self::TimedOut => "ICS20_WITHDRAWAL_STATUS_TIMED_OUT",
+            Self::Refunded => "ICS20_WITHDRAWAL_STATUS_REFUNDED",
+        };
+        serializer.serialize_str(variant)
+    }
+}
+impl<'de> serde::Deserialize<'de> for Ics20WithdrawalStatus {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "ICS20_WITHDRAWAL_STATUS_UNSPECIFIED",
+            "ICS20_WITHDRAWAL_STATUS_PENDING",
+            "ICS20_WITHDRAWAL_STATUS_ACKNOWLEDGED",
+            "ICS20_WITHDRAWAL_STATUS_TIMED_OUT",
+            "ICS20_WITHDRAWAL_STATUS_REFUNDED",
+        ];
+
+        struct GeneratedVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = Ics20WithdrawalStatus;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(formatter, "expected one of: {:?}", &FIELDS)
+            }
+
+            fn visit_i64<E>(self, v: i64) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                i32::try_from(v)
+                    .ok()
+                    .and_then(|x| x.try_into().ok())
+                    .ok_or_else(|| {
+                        serde::de::Error::invalid_value(serde::de::Unexpected::Signed(v), &self)
+                    })
+            }
+
+            fn visit_u64<E>(self, v: u64) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                i32::try_from(v)
+                    .ok()
+                    .and_then(|x| x.try_into().ok())
+                    .ok_or_else(|| {
+                        serde::de::Error::invalid_value(serde::de::Unexpected::Unsigned(v), &self)
+                    })
+            }
+
+            fn visit_str<E>(self, value: &str) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                match value {
+                    "ICS20_WITHDRAWAL_STATUS_UNSPECIFIED" => Ok(Ics20WithdrawalStatus::Unspecified),
+                    "ICS20_WITHDRAWAL_STATUS_PENDING" => Ok(Ics20WithdrawalStatus::Pending),
+                    "ICS20_WITHDRAWAL_STATUS_ACKNOWLEDGED" => Ok(Ics20WithdrawalStatus::Acknowledged),
+                    "ICS20_WITHDRAWAL_STATUS_TIMED_OUT" => Ok(Ics20WithdrawalStatus::TimedOut),
+                    "ICS20_WITHDRAWAL_STATUS_REFUNDED" => Ok(Ics20WithdrawalStatus::Refunded),
+                    _ => Err(serde::de::Error::unknown_variant(value, FIELDS)),
+                }
+            }
+        }
+        deserializer.deserialize_any(GeneratedVisitor)
+    }
+}
+impl serde::Serialize for Ics20WithdrawalsRequest {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -3982,38 +4944,38 @@ impl serde::Serialize for NullifierStatusRequest {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.nullifier.is_some() {
+        if self.transaction_id.is_some() {
             len += 1;
         }
-        if self.await_detection {
+        if self.settled {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.NullifierStatusRequest", len)?;
-        if let Some(v) = self.nullifier.as_ref() {
-            struct_ser.serialize_field("nullifier", v)?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.Ics20WithdrawalsRequest", len)?;
+        if let Some(v) = self.transaction_id.as_ref() {
+            struct_ser.serialize_field("transactionId", v)?;
         }
-        if self.await_detection {
-            struct_ser.serialize_field("awaitDetection", &self.await_detection)?;
+        if self.settled {
+            struct_ser.serialize_field("settled", &self.settled)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for NullifierStatusRequest {
+impl<'de> serde::Deserialize<'de> for Ics20WithdrawalsRequest {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "nullifier",
-            "await_detection",
-            "awaitDetection",
+            "transaction_id",
+            "transactionId",
+            "settled",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            Nullifier,
-            AwaitDetection,
+            TransactionId,
+            Settled,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -4036,8 +4998,8 @@ impl<'de> serde::Deserialize<'de> for NullifierStatusRequest {
                         E: serde::de::Error,
                     {
                         match value {
-                            "nullifier" => Ok(GeneratedField::Nullifier),
-                            "awaitDetection" | "await_detection" => Ok(GeneratedField::AwaitDetection),
+                            "transactionId" | "transaction_id" => Ok(GeneratedField::TransactionId),
+                            "settled" => Ok(GeneratedField::Settled),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -4047,47 +5009,47 @@ impl<'de> serde::Deserialize<'de> for NullifierStatusRequest {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = NullifierStatusRequest;
+            type Value = Ics20WithdrawalsRequest;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.view.v1.NullifierStatusRequest")
+                formatter.write_str("struct penumbra.view.v1.Ics20WithdrawalsRequest")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<NullifierStatusRequest, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<Ics20WithdrawalsRequest, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut nullifier__ = None;
-                let mut await_detection__ = None;
+                let mut transaction_id__ = None;
+                let mut settled__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::Nullifier => {
-                            if nullifier__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("nullifier"));
+                        GeneratedField::TransactionId => {
+                            if transaction_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("transactionId"));
                             }
-                            nullifier__ = map_.next_value()?;
+                            transaction_id__ = map_.next_value()?;
                         }
-                        GeneratedField::AwaitDetection => {
-                            if await_detection__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("awaitDetection"));
+                        GeneratedField::Settled => {
+                            if settled__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("settled"));
                             }
-                            await_detection__ = Some(map_.next_value()?);
+                            settled__ = Some(map_.next_value()?);
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(NullifierStatusRequest {
-                    nullifier: nullifier__,
-                    await_detection: await_detection__.unwrap_or_default(),
+                Ok(Ics20WithdrawalsRequest {
+                    transaction_id: transaction_id__,
+                    settled: settled__.unwrap_or_default(),
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.view.v1.NullifierStatusRequest", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.view.v1.Ics20WithdrawalsRequest", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for NullifierStatusResponse {
+impl serde::Serialize for Ics20WithdrawalsResponse {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -4095,29 +5057,29 @@ impl serde::Serialize for NullifierStatusResponse {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.spent {
+        if self.withdrawal.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.NullifierStatusResponse", len)?;
-        if self.spent {
-            struct_ser.serialize_field("spent", &self.spent)?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.Ics20WithdrawalsResponse", len)?;
+        if let Some(v) = self.withdrawal.as_ref() {
+            struct_ser.serialize_field("withdrawal", v)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for NullifierStatusResponse {
+impl<'de> serde::Deserialize<'de> for Ics20WithdrawalsResponse {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "spent",
+            "withdrawal",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            Spent,
+            Withdrawal,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -4140,7 +5102,7 @@ impl<'de> serde::Deserialize<'de> for NullifierStatusResponse {
                         E: serde::de::Error,
                     {
                         match value {
-                            "spent" => Ok(GeneratedField::Spent),
+                            "withdrawal" => Ok(GeneratedField::Withdrawal),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -4150,39 +5112,39 @@ impl<'de> serde::Deserialize<'de> for NullifierStatusResponse {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = NullifierStatusResponse;
+            type Value = Ics20WithdrawalsResponse;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.view.v1.NullifierStatusResponse")
+                formatter.write_str("struct penumbra.view.v1.Ics20WithdrawalsResponse")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<NullifierStatusResponse, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<Ics20WithdrawalsResponse, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut spent__ = None;
+                let mut withdrawal__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::Spent => {
-                            if spent__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("spent"));
+                        GeneratedField::Withdrawal => {
+                            if withdrawal__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("withdrawal"));
                             }
-                            spent__ = Some(map_.next_value()?);
+                            withdrawal__ = map_.next_value()?;
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(NullifierStatusResponse {
-                    spent: spent__.unwrap_or_default(),
+                Ok(Ics20WithdrawalsResponse {
+                    withdrawal: withdrawal__,
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.view.v1.NullifierStatusResponse", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.view.v1.Ics20WithdrawalsResponse", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for OwnedPositionIdsRequest {
+impl serde::Serialize for IndexByAddressRequest {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -4190,47 +5152,29 @@ impl serde::Serialize for OwnedPositionIdsRequest {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.position_state.is_some() {
-            len += 1;
-        }
-        if self.trading_pair.is_some() {
-            len += 1;
-        }
-        if self.subaccount.is_some() {
+        if self.address.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.OwnedPositionIdsRequest", len)?;
-        if let Some(v) = self.position_state.as_ref() {
-            struct_ser.serialize_field("positionState", v)?;
-        }
-        if let Some(v) = self.trading_pair.as_ref() {
-            struct_ser.serialize_field("tradingPair", v)?;
-        }
-        if let Some(v) = self.subaccount.as_ref() {
-            struct_ser.serialize_field("subaccount", v)?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.IndexByAddressRequest", len)?;
+        if let Some(v) = self.address.as_ref() {
+            struct_ser.serialize_field("address", v)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for OwnedPositionIdsRequest {
+impl<'de> serde::Deserialize<'de> for IndexByAddressRequest {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "position_state",
-            "positionState",
-            "trading_pair",
-            "tradingPair",
-            "subaccount",
+            "address",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            PositionState,
-            TradingPair,
-            Subaccount,
+            Address,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -4253,9 +5197,7 @@ impl<'de> serde::Deserialize<'de> for OwnedPositionIdsRequest {
                         E: serde::de::Error,
                     {
                         match value {
-                            "positionState" | "position_state" => Ok(GeneratedField::PositionState),
-                            "tradingPair" | "trading_pair" => Ok(GeneratedField::TradingPair),
-                            "subaccount" => Ok(GeneratedField::Subaccount),
+                            "address" => Ok(GeneratedField::Address),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -4265,55 +5207,39 @@ impl<'de> serde::Deserialize<'de> for OwnedPositionIdsRequest {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = OwnedPositionIdsRequest;
+            type Value = IndexByAddressRequest;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.view.v1.OwnedPositionIdsRequest")
+                formatter.write_str("struct penumbra.view.v1.IndexByAddressRequest")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<OwnedPositionIdsRequest, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<IndexByAddressRequest, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut position_state__ = None;
-                let mut trading_pair__ = None;
-                let mut subaccount__ = None;
+                let mut address__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::PositionState => {
-                            if position_state__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("positionState"));
-                            }
-                            position_state__ = map_.next_value()?;
-                        }
-                        GeneratedField::TradingPair => {
-                            if trading_pair__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("tradingPair"));
-                            }
-                            trading_pair__ = map_.next_value()?;
-                        }
-                        GeneratedField::Subaccount => {
-                            if subaccount__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("subaccount"));
+                        GeneratedField::Address => {
+                            if address__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("address"));
                             }
-                            subaccount__ = map_.next_value()?;
+                            address__ = map_.next_value()?;
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(OwnedPositionIdsRequest {
-                    position_state: position_state__,
-                    trading_pair: trading_pair__,
-                    subaccount: subaccount__,
+                Ok(IndexByAddressRequest {
+                    address: address__,
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.view.v1.OwnedPositionIdsRequest", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.view.v1.IndexByAddressRequest", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for OwnedPositionIdsResponse {
+impl serde::Serialize for IndexByAddressResponse {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -4321,38 +5247,30 @@ impl serde::Serialize for OwnedPositionIdsResponse {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.position_id.is_some() {
-            len += 1;
-        }
-        if self.subaccount.is_some() {
+        if self.address_index.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.OwnedPositionIdsResponse", len)?;
-        if let Some(v) = self.position_id.as_ref() {
-            struct_ser.serialize_field("positionId", v)?;
-        }
-        if let Some(v) = self.subaccount.as_ref() {
-            struct_ser.serialize_field("subaccount", v)?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.IndexByAddressResponse", len)?;
+        if let Some(v) = self.address_index.as_ref() {
+            struct_ser.serialize_field("addressIndex", v)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for OwnedPositionIdsResponse {
+impl<'de> serde::Deserialize<'de> for IndexByAddressResponse {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "position_id",
-            "positionId",
-            "subaccount",
+            "address_index",
+            "addressIndex",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            PositionId,
-            Subaccount,
+            AddressIndex,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -4375,8 +5293,7 @@ impl<'de> serde::Deserialize<'de> for OwnedPositionIdsResponse {
                         E: serde::de::Error,
                     {
                         match value {
-                            "positionId" | "position_id" => Ok(GeneratedField::PositionId),
-                            "subaccount" => Ok(GeneratedField::Subaccount),
+                            "addressIndex" | "address_index" => Ok(GeneratedField::AddressIndex),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -4386,47 +5303,39 @@ impl<'de> serde::Deserialize<'de> for OwnedPositionIdsResponse {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = OwnedPositionIdsResponse;
+            type Value = IndexByAddressResponse;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.view.v1.OwnedPositionIdsResponse")
+                formatter.write_str("struct penumbra.view.v1.IndexByAddressResponse")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<OwnedPositionIdsResponse, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<IndexByAddressResponse, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut position_id__ = None;
-                let mut subaccount__ = None;
+                let mut address_index__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::PositionId => {
-                            if position_id__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("positionId"));
-                            }
-                            position_id__ = map_.next_value()?;
-                        }
-                        GeneratedField::Subaccount => {
-                            if subaccount__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("subaccount"));
+                        GeneratedField::AddressIndex => {
+                            if address_index__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("addressIndex"));
                             }
-                            subaccount__ = map_.next_value()?;
+                            address_index__ = map_.next_value()?;
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(OwnedPositionIdsResponse {
-                    position_id: position_id__,
-                    subaccount: subaccount__,
+                Ok(IndexByAddressResponse {
+                    address_index: address_index__,
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.view.v1.OwnedPositionIdsResponse", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.view.v1.IndexByAddressResponse", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for SpendableNoteRecord {
+impl serde::Serialize for NoteByCommitmentRequest {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -4437,98 +5346,3244 @@ impl serde::Serialize for SpendableNoteRecord {
         if self.note_commitment.is_some() {
             len += 1;
         }
-        if self.note.is_some() {
-            len += 1;
-        }
-        if self.address_index.is_some() {
-            len += 1;
-        }
-        if self.nullifier.is_some() {
-            len += 1;
-        }
-        if self.height_created != 0 {
-            len += 1;
-        }
-        if self.height_spent != 0 {
-            len += 1;
-        }
-        if self.position != 0 {
-            len += 1;
-        }
-        if self.source.is_some() {
-            len += 1;
-        }
-        if self.return_address.is_some() {
+        if self.await_detection {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.SpendableNoteRecord", len)?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.NoteByCommitmentRequest", len)?;
         if let Some(v) = self.note_commitment.as_ref() {
             struct_ser.serialize_field("noteCommitment", v)?;
         }
-        if let Some(v) = self.note.as_ref() {
-            struct_ser.serialize_field("note", v)?;
-        }
-        if let Some(v) = self.address_index.as_ref() {
-            struct_ser.serialize_field("addressIndex", v)?;
-        }
-        if let Some(v) = self.nullifier.as_ref() {
-            struct_ser.serialize_field("nullifier", v)?;
+        if self.await_detection {
+            struct_ser.serialize_field("awaitDetection", &self.await_detection)?;
         }
-        if self.height_created != 0 {
-            #[allow(clippy::needless_borrow)]
-            struct_ser.serialize_field("heightCreated", ToString::to_string(&self.height_created).as_str())?;
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for NoteByCommitmentRequest {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "note_commitment",
+            "noteCommitment",
+            "await_detection",
+            "awaitDetection",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            NoteCommitment,
+            AwaitDetection,
+            __SkipField__,
         }
-        if self.height_spent != 0 {
-            #[allow(clippy::needless_borrow)]
-            struct_ser.serialize_field("heightSpent", ToString::to_string(&self.height_spent).as_str())?;
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "noteCommitment" | "note_commitment" => Ok(GeneratedField::NoteCommitment),
+                            "awaitDetection" | "await_detection" => Ok(GeneratedField::AwaitDetection),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
         }
-        if self.position != 0 {
-            #[allow(clippy::needless_borrow)]
-            struct_ser.serialize_field("position", ToString::to_string(&self.position).as_str())?;
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = NoteByCommitmentRequest;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.view.v1.NoteByCommitmentRequest")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<NoteByCommitmentRequest, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut note_commitment__ = None;
+                let mut await_detection__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::NoteCommitment => {
+                            if note_commitment__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("noteCommitment"));
+                            }
+                            note_commitment__ = map_.next_value()?;
+                        }
+                        GeneratedField::AwaitDetection => {
+                            if await_detection__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("awaitDetection"));
+                            }
+                            await_detection__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(NoteByCommitmentRequest {
+                    note_commitment: note_commitment__,
+                    await_detection: await_detection__.unwrap_or_default(),
+                })
+            }
         }
-        if let Some(v) = self.source.as_ref() {
-            struct_ser.serialize_field("source", v)?;
+        deserializer.deserialize_struct("penumbra.view.v1.NoteByCommitmentRequest", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for NoteByCommitmentResponse {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.spendable_note.is_some() {
+            len += 1;
         }
-        if let Some(v) = self.return_address.as_ref() {
-            struct_ser.serialize_field("returnAddress", v)?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.NoteByCommitmentResponse", len)?;
+        if let Some(v) = self.spendable_note.as_ref() {
+            struct_ser.serialize_field("spendableNote", v)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for SpendableNoteRecord {
+impl<'de> serde::Deserialize<'de> for NoteByCommitmentResponse {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "note_commitment",
-            "noteCommitment",
-            "note",
-            "address_index",
-            "addressIndex",
-            "nullifier",
-            "height_created",
-            "heightCreated",
-            "height_spent",
-            "heightSpent",
-            "position",
-            "source",
-            "return_address",
-            "returnAddress",
+            "spendable_note",
+            "spendableNote",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            SpendableNote,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "spendableNote" | "spendable_note" => Ok(GeneratedField::SpendableNote),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = NoteByCommitmentResponse;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.view.v1.NoteByCommitmentResponse")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<NoteByCommitmentResponse, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut spendable_note__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::SpendableNote => {
+                            if spendable_note__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("spendableNote"));
+                            }
+                            spendable_note__ = map_.next_value()?;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(NoteByCommitmentResponse {
+                    spendable_note: spendable_note__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.view.v1.NoteByCommitmentResponse", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for NotesForVotingRequest {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.votable_at_height != 0 {
+            len += 1;
+        }
+        if self.address_index.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.NotesForVotingRequest", len)?;
+        if self.votable_at_height != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("votableAtHeight", ToString::to_string(&self.votable_at_height).as_str())?;
+        }
+        if let Some(v) = self.address_index.as_ref() {
+            struct_ser.serialize_field("addressIndex", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for NotesForVotingRequest {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "votable_at_height",
+            "votableAtHeight",
+            "address_index",
+            "addressIndex",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            VotableAtHeight,
+            AddressIndex,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "votableAtHeight" | "votable_at_height" => Ok(GeneratedField::VotableAtHeight),
+                            "addressIndex" | "address_index" => Ok(GeneratedField::AddressIndex),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = NotesForVotingRequest;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.view.v1.NotesForVotingRequest")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<NotesForVotingRequest, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut votable_at_height__ = None;
+                let mut address_index__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::VotableAtHeight => {
+                            if votable_at_height__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("votableAtHeight"));
+                            }
+                            votable_at_height__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::AddressIndex => {
+                            if address_index__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("addressIndex"));
+                            }
+                            address_index__ = map_.next_value()?;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(NotesForVotingRequest {
+                    votable_at_height: votable_at_height__.unwrap_or_default(),
+                    address_index: address_index__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.view.v1.NotesForVotingRequest", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for NotesForVotingResponse {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.note_record.is_some() {
+            len += 1;
+        }
+        if self.identity_key.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.NotesForVotingResponse", len)?;
+        if let Some(v) = self.note_record.as_ref() {
+            struct_ser.serialize_field("noteRecord", v)?;
+        }
+        if let Some(v) = self.identity_key.as_ref() {
+            struct_ser.serialize_field("identityKey", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for NotesForVotingResponse {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "note_record",
+            "noteRecord",
+            "identity_key",
+            "identityKey",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            NoteRecord,
+            IdentityKey,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "noteRecord" | "note_record" => Ok(GeneratedField::NoteRecord),
+                            "identityKey" | "identity_key" => Ok(GeneratedField::IdentityKey),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = NotesForVotingResponse;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.view.v1.NotesForVotingResponse")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<NotesForVotingResponse, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut note_record__ = None;
+                let mut identity_key__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::NoteRecord => {
+                            if note_record__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("noteRecord"));
+                            }
+                            note_record__ = map_.next_value()?;
+                        }
+                        GeneratedField::IdentityKey => {
+                            if identity_key__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("identityKey"));
+                            }
+                            identity_key__ = map_.next_value()?;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(NotesForVotingResponse {
+                    note_record: note_record__,
+                    identity_key: identity_key__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.view.v1.NotesForVotingResponse", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for NotesRequest {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.include_spent {
+            len += 1;
+        }
+        if self.asset_id.is_some() {
+            len += 1;
+        }
+        if self.address_index.is_some() {
+            len += 1;
+        }
+        if self.amount_to_spend.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.NotesRequest", len)?;
+        if self.include_spent {
+            struct_ser.serialize_field("includeSpent", &self.include_spent)?;
+        }
+        if let Some(v) = self.asset_id.as_ref() {
+            struct_ser.serialize_field("assetId", v)?;
+        }
+        if let Some(v) = self.address_index.as_ref() {
+            struct_ser.serialize_field("addressIndex", v)?;
+        }
+        if let Some(v) = self.amount_to_spend.as_ref() {
+            struct_ser.serialize_field("amountToSpend", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for NotesRequest {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "include_spent",
+            "includeSpent",
+            "asset_id",
+            "assetId",
+            "address_index",
+            "addressIndex",
+            "amount_to_spend",
+            "amountToSpend",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            IncludeSpent,
+            AssetId,
+            AddressIndex,
+            AmountToSpend,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "includeSpent" | "include_spent" => Ok(GeneratedField::IncludeSpent),
+                            "assetId" | "asset_id" => Ok(GeneratedField::AssetId),
+                            "addressIndex" | "address_index" => Ok(GeneratedField::AddressIndex),
+                            "amountToSpend" | "amount_to_spend" => Ok(GeneratedField::AmountToSpend),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = NotesRequest;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.view.v1.NotesRequest")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<NotesRequest, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut include_spent__ = None;
+                let mut asset_id__ = None;
+                let mut address_index__ = None;
+                let mut amount_to_spend__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::IncludeSpent => {
+                            if include_spent__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("includeSpent"));
+                            }
+                            include_spent__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::AssetId => {
+                            if asset_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("assetId"));
+                            }
+                            asset_id__ = map_.next_value()?;
+                        }
+                        GeneratedField::AddressIndex => {
+                            if address_index__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("addressIndex"));
+                            }
+                            address_index__ = map_.next_value()?;
+                        }
+                        GeneratedField::AmountToSpend => {
+                            if amount_to_spend__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("amountToSpend"));
+                            }
+                            amount_to_spend__ = map_.next_value()?;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(NotesRequest {
+                    include_spent: include_spent__.unwrap_or_default(),
+                    asset_id: asset_id__,
+                    address_index: address_index__,
+                    amount_to_spend: amount_to_spend__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.view.v1.NotesRequest", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for NotesResponse {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.note_record.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.NotesResponse", len)?;
+        if let Some(v) = self.note_record.as_ref() {
+            struct_ser.serialize_field("noteRecord", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for NotesResponse {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "note_record",
+            "noteRecord",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            NoteRecord,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "noteRecord" | "note_record" => Ok(GeneratedField::NoteRecord),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = NotesResponse;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.view.v1.NotesResponse")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<NotesResponse, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut note_record__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::NoteRecord => {
+                            if note_record__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("noteRecord"));
+                            }
+                            note_record__ = map_.next_value()?;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(NotesResponse {
+                    note_record: note_record__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.view.v1.NotesResponse", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for NullifierStatusRequest {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.nullifier.is_some() {
+            len += 1;
+        }
+        if self.await_detection {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.NullifierStatusRequest", len)?;
+        if let Some(v) = self.nullifier.as_ref() {
+            struct_ser.serialize_field("nullifier", v)?;
+        }
+        if self.await_detection {
+            struct_ser.serialize_field("awaitDetection", &self.await_detection)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for NullifierStatusRequest {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "nullifier",
+            "await_detection",
+            "awaitDetection",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Nullifier,
+            AwaitDetection,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "nullifier" => Ok(GeneratedField::Nullifier),
+                            "awaitDetection" | "await_detection" => Ok(GeneratedField::AwaitDetection),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = NullifierStatusRequest;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.view.v1.NullifierStatusRequest")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<NullifierStatusRequest, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut nullifier__ = None;
+                let mut await_detection__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Nullifier => {
+                            if nullifier__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("nullifier"));
+                            }
+                            nullifier__ = map_.next_value()?;
+                        }
+                        GeneratedField::AwaitDetection => {
+                            if await_detection__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("awaitDetection"));
+                            }
+                            await_detection__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(NullifierStatusRequest {
+                    nullifier: nullifier__,
+                    await_detection: await_detection__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.view.v1.NullifierStatusRequest", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for NullifierStatusResponse {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.spent {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.NullifierStatusResponse", len)?;
+        if self.spent {
+            struct_ser.serialize_field("spent", &self.spent)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for NullifierStatusResponse {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "spent",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Spent,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "spent" => Ok(GeneratedField::Spent),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = NullifierStatusResponse;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.view.v1.NullifierStatusResponse")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<NullifierStatusResponse, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut spent__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Spent => {
+                            if spent__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("spent"));
+                            }
+                            spent__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(NullifierStatusResponse {
+                    spent: spent__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.view.v1.NullifierStatusResponse", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for OwnedPositionIdsRequest {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.position_state.is_some() {
+            len += 1;
+        }
+        if self.trading_pair.is_some() {
+            len += 1;
+        }
+        if self.subaccount.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.OwnedPositionIdsRequest", len)?;
+        if let Some(v) = self.position_state.as_ref() {
+            struct_ser.serialize_field("positionState", v)?;
+        }
+        if let Some(v) = self.trading_pair.as_ref() {
+            struct_ser.serialize_field("tradingPair", v)?;
+        }
+        if let Some(v) = self.subaccount.as_ref() {
+            struct_ser.serialize_field("subaccount", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for OwnedPositionIdsRequest {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "position_state",
+            "positionState",
+            "trading_pair",
+            "tradingPair",
+            "subaccount",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            PositionState,
+            TradingPair,
+            Subaccount,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "positionState" | "position_state" => Ok(GeneratedField::PositionState),
+                            "tradingPair" | "trading_pair" => Ok(GeneratedField::TradingPair),
+                            "subaccount" => Ok(GeneratedField::Subaccount),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = OwnedPositionIdsRequest;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.view.v1.OwnedPositionIdsRequest")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<OwnedPositionIdsRequest, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut position_state__ = None;
+                let mut trading_pair__ = None;
+                let mut subaccount__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::PositionState => {
+                            if position_state__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("positionState"));
+                            }
+                            position_state__ = map_.next_value()?;
+                        }
+                        GeneratedField::TradingPair => {
+                            if trading_pair__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("tradingPair"));
+                            }
+                            trading_pair__ = map_.next_value()?;
+                        }
+                        GeneratedField::Subaccount => {
+                            if subaccount__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("subaccount"));
+                            }
+                            subaccount__ = map_.next_value()?;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(OwnedPositionIdsRequest {
+                    position_state: position_state__,
+                    trading_pair: trading_pair__,
+                    subaccount: subaccount__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.view.v1.OwnedPositionIdsRequest", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for OwnedPositionIdsResponse {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.position_id.is_some() {
+            len += 1;
+        }
+        if self.subaccount.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.OwnedPositionIdsResponse", len)?;
+        if let Some(v) = self.position_id.as_ref() {
+            struct_ser.serialize_field("positionId", v)?;
+        }
+        if let Some(v) = self.subaccount.as_ref() {
+            struct_ser.serialize_field("subaccount", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for OwnedPositionIdsResponse {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "position_id",
+            "positionId",
+            "subaccount",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            PositionId,
+            Subaccount,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "positionId" | "position_id" => Ok(GeneratedField::PositionId),
+                            "subaccount" => Ok(GeneratedField::Subaccount),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = OwnedPositionIdsResponse;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.view.v1.OwnedPositionIdsResponse")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<OwnedPositionIdsResponse, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut position_id__ = None;
+                let mut subaccount__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::PositionId => {
+                            if position_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("positionId"));
+                            }
+                            position_id__ = map_.next_value()?;
+                        }
+                        GeneratedField::Subaccount => {
+                            if subaccount__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("subaccount"));
+                            }
+                            subaccount__ = map_.next_value()?;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(OwnedPositionIdsResponse {
+                    position_id: position_id__,
+                    subaccount: subaccount__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.view.v1.OwnedPositionIdsResponse", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for PositionPerformance {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.position_id.is_some() {
+            len += 1;
+        }
+        if self.opened.is_some() {
+            len += 1;
+        }
+        if self.height_opened != 0 {
+            len += 1;
+        }
+        if self.state.is_some() {
+            len += 1;
+        }
+        if self.reserves.is_some() {
+            len += 1;
+        }
+        if self.executions != 0 {
+            len += 1;
+        }
+        if self.filled_in_1.is_some() {
+            len += 1;
+        }
+        if self.filled_in_2.is_some() {
+            len += 1;
+        }
+        if self.filled_out_1.is_some() {
+            len += 1;
+        }
+        if self.filled_out_2.is_some() {
+            len += 1;
+        }
+        if self.fees_1.is_some() {
+            len += 1;
+        }
+        if self.fees_2.is_some() {
+            len += 1;
+        }
+        if self.height_withdrawn != 0 {
+            len += 1;
+        }
+        if self.height_evicted != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.PositionPerformance", len)?;
+        if let Some(v) = self.position_id.as_ref() {
+            struct_ser.serialize_field("positionId", v)?;
+        }
+        if let Some(v) = self.opened.as_ref() {
+            struct_ser.serialize_field("opened", v)?;
+        }
+        if self.height_opened != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("heightOpened", ToString::to_string(&self.height_opened).as_str())?;
+        }
+        if let Some(v) = self.state.as_ref() {
+            struct_ser.serialize_field("state", v)?;
+        }
+        if let Some(v) = self.reserves.as_ref() {
+            struct_ser.serialize_field("reserves", v)?;
+        }
+        if self.executions != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("executions", ToString::to_string(&self.executions).as_str())?;
+        }
+        if let Some(v) = self.filled_in_1.as_ref() {
+            struct_ser.serialize_field("filledIn1", v)?;
+        }
+        if let Some(v) = self.filled_in_2.as_ref() {
+            struct_ser.serialize_field("filledIn2", v)?;
+        }
+        if let Some(v) = self.filled_out_1.as_ref() {
+            struct_ser.serialize_field("filledOut1", v)?;
+        }
+        if let Some(v) = self.filled_out_2.as_ref() {
+            struct_ser.serialize_field("filledOut2", v)?;
+        }
+        if let Some(v) = self.fees_1.as_ref() {
+            struct_ser.serialize_field("fees1", v)?;
+        }
+        if let Some(v) = self.fees_2.as_ref() {
+            struct_ser.serialize_field("fees2", v)?;
+        }
+        if self.height_withdrawn != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("heightWithdrawn", ToString::to_string(&self.height_withdrawn).as_str())?;
+        }
+        if self.height_evicted != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("heightEvicted", ToString::to_string(&self.height_evicted).as_str())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for PositionPerformance {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "position_id",
+            "positionId",
+            "opened",
+            "height_opened",
+            "heightOpened",
+            "state",
+            "reserves",
+            "executions",
+            "filled_in_1",
+            "filledIn1",
+            "filled_in_2",
+            "filledIn2",
+            "filled_out_1",
+            "filledOut1",
+            "filled_out_2",
+            "filledOut2",
+            "fees_1",
+            "fees1",
+            "fees_2",
+            "fees2",
+            "height_withdrawn",
+            "heightWithdrawn",
+            "height_evicted",
+            "heightEvicted",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            PositionId,
+            Opened,
+            HeightOpened,
+            State,
+            Reserves,
+            Executions,
+            FilledIn1,
+            FilledIn2,
+            FilledOut1,
+            FilledOut2,
+            Fees1,
+            Fees2,
+            HeightWithdrawn,
+            HeightEvicted,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "positionId" | "position_id" => Ok(GeneratedField::PositionId),
+                            "opened" => Ok(GeneratedField::Opened),
+                            "heightOpened" | "height_opened" => Ok(GeneratedField::HeightOpened),
+                            "state" => Ok(GeneratedField::State),
+                            "reserves" => Ok(GeneratedField::Reserves),
+                            "executions" => Ok(GeneratedField::Executions),
+                            "filledIn1" | "filled_in_1" => Ok(GeneratedField::FilledIn1),
+                            "filledIn2" | "filled_in_2" => Ok(GeneratedField::FilledIn2),
+                            "filledOut1" | "filled_out_1" => Ok(GeneratedField::FilledOut1),
+                            "filledOut2" | "filled_out_2" => Ok(GeneratedField::FilledOut2),
+                            "fees1" | "fees_1" => Ok(GeneratedField::Fees1),
+                            "fees2" | "fees_2" => Ok(GeneratedField::Fees2),
+                            "heightWithdrawn" | "height_withdrawn" => Ok(GeneratedField::HeightWithdrawn),
+                            "heightEvicted" | "height_evicted" => Ok(GeneratedField::HeightEvicted),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = PositionPerformance;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.view.v1.PositionPerformance")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<PositionPerformance, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut position_id__ = None;
+                let mut opened__ = None;
+                let mut height_opened__ = None;
+                let mut state__ = None;
+                let mut reserves__ = None;
+                let mut executions__ = None;
+                let mut filled_in_1__ = None;
+                let mut filled_in_2__ = None;
+                let mut filled_out_1__ = None;
+                let mut filled_out_2__ = None;
+                let mut fees_1__ = None;
+                let mut fees_2__ = None;
+                let mut height_withdrawn__ = None;
+                let mut height_evicted__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::PositionId => {
+                            if position_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("positionId"));
+                            }
+                            position_id__ = map_.next_value()?;
+                        }
+                        GeneratedField::Opened => {
+                            if opened__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("opened"));
+                            }
+                            opened__ = map_.next_value()?;
+                        }
+                        GeneratedField::HeightOpened => {
+                            if height_opened__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("heightOpened"));
+                            }
+                            height_opened__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::State => {
+                            if state__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("state"));
+                            }
+                            state__ = map_.next_value()?;
+                        }
+                        GeneratedField::Reserves => {
+                            if reserves__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("reserves"));
+                            }
+                            reserves__ = map_.next_value()?;
+                        }
+                        GeneratedField::Executions => {
+                            if executions__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("executions"));
+                            }
+                            executions__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::FilledIn1 => {
+                            if filled_in_1__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("filledIn1"));
+                            }
+                            filled_in_1__ = map_.next_value()?;
+                        }
+                        GeneratedField::FilledIn2 => {
+                            if filled_in_2__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("filledIn2"));
+                            }
+                            filled_in_2__ = map_.next_value()?;
+                        }
+                        GeneratedField::FilledOut1 => {
+                            if filled_out_1__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("filledOut1"));
+                            }
+                            filled_out_1__ = map_.next_value()?;
+                        }
+                        GeneratedField::FilledOut2 => {
+                            if filled_out_2__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("filledOut2"));
+                            }
+                            filled_out_2__ = map_.next_value()?;
+                        }
+                        GeneratedField::Fees1 => {
+                            if fees_1__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("fees1"));
+                            }
+                            fees_1__ = map_.next_value()?;
+                        }
+                        GeneratedField::Fees2 => {
+                            if fees_2__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("fees2"));
+                            }
+                            fees_2__ = map_.next_value()?;
+                        }
+                        GeneratedField::HeightWithdrawn => {
+                            if height_withdrawn__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("heightWithdrawn"));
+                            }
+                            height_withdrawn__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::HeightEvicted => {
+                            if height_evicted__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("heightEvicted"));
+                            }
+                            height_evicted__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(PositionPerformance {
+                    position_id: position_id__,
+                    opened: opened__,
+                    height_opened: height_opened__.unwrap_or_default(),
+                    state: state__,
+                    reserves: reserves__,
+                    executions: executions__.unwrap_or_default(),
+                    filled_in_1: filled_in_1__,
+                    filled_in_2: filled_in_2__,
+                    filled_out_1: filled_out_1__,
+                    filled_out_2: filled_out_2__,
+                    fees_1: fees_1__,
+                    fees_2: fees_2__,
+                    height_withdrawn: height_withdrawn__.unwrap_or_default(),
+                    height_evicted: height_evicted__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.view.v1.PositionPerformance", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for PositionPerformanceRequest {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.position_ids.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.PositionPerformanceRequest", len)?;
+        if !self.position_ids.is_empty() {
+            struct_ser.serialize_field("positionIds", &self.position_ids)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for PositionPerformanceRequest {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "position_ids",
+            "positionIds",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            PositionIds,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "positionIds" | "position_ids" => Ok(GeneratedField::PositionIds),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = PositionPerformanceRequest;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.view.v1.PositionPerformanceRequest")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<PositionPerformanceRequest, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut position_ids__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::PositionIds => {
+                            if position_ids__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("positionIds"));
+                            }
+                            position_ids__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(PositionPerformanceRequest {
+                    position_ids: position_ids__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.view.v1.PositionPerformanceRequest", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for PositionPerformanceResponse {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.performance.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.PositionPerformanceResponse", len)?;
+        if let Some(v) = self.performance.as_ref() {
+            struct_ser.serialize_field("performance", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for PositionPerformanceResponse {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "performance",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Performance,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "performance" => Ok(GeneratedField::Performance),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = PositionPerformanceResponse;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.view.v1.PositionPerformanceResponse")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<PositionPerformanceResponse, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut performance__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Performance => {
+                            if performance__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("performance"));
+                            }
+                            performance__ = map_.next_value()?;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(PositionPerformanceResponse {
+                    performance: performance__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.view.v1.PositionPerformanceResponse", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for ScheduleStatus {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let variant = match self {
+            Self::Unspecified => "SCHEDULE_STATUS_UNSPECIFIED",
+            Self::Active => "SCHEDULE_STATUS_ACTIVE",
+            Self::Paused => "SCHEDULE_STATUS_PAUSED",
+            Self::Cancelled => "SCHEDULE_STATUS_CANCELLED",
+            Self::Completed => "SCHEDULE_STATUS_COMPLETED",
+        };
+        serializer.serialize_str(variant)
+    }
+}
+impl<'de> serde::Deserialize<'de> for ScheduleStatus {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "SCHEDULE_STATUS_UNSPECIFIED",
+            "SCHEDULE_STATUS_ACTIVE",
+            "SCHEDULE_STATUS_PAUSED",
+            "SCHEDULE_STATUS_CANCELLED",
+            "SCHEDULE_STATUS_COMPLETED",
+        ];
+
+        struct GeneratedVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = ScheduleStatus;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(formatter, "expected one of: {:?}", &FIELDS)
+            }
+
+            fn visit_i64<E>(self, v: i64) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                i32::try_from(v)
+                    .ok()
+                    .and_then(|x| x.try_into().ok())
+                    .ok_or_else(|| {
+                        serde::de::Error::invalid_value(serde::de::Unexpected::Signed(v), &self)
+                    })
+            }
+
+            fn visit_u64<E>(self, v: u64) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                i32::try_from(v)
+                    .ok()
+                    .and_then(|x| x.try_into().ok())
+                    .ok_or_else(|| {
+                        serde::de::Error::invalid_value(serde::de::Unexpected::Unsigned(v), &self)
+                    })
+            }
+
+            fn visit_str<E>(self, value: &str) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                match value {
+                    "SCHEDULE_STATUS_UNSPECIFIED" => Ok(ScheduleStatus::Unspecified),
+                    "SCHEDULE_STATUS_ACTIVE" => Ok(ScheduleStatus::Active),
+                    "SCHEDULE_STATUS_PAUSED" => Ok(ScheduleStatus::Paused),
+                    "SCHEDULE_STATUS_CANCELLED" => Ok(ScheduleStatus::Cancelled),
+                    "SCHEDULE_STATUS_COMPLETED" => Ok(ScheduleStatus::Completed),
+                    _ => Err(serde::de::Error::unknown_variant(value, FIELDS)),
+                }
+            }
+        }
+        deserializer.deserialize_any(GeneratedVisitor)
+    }
+}
+impl serde::Serialize for ScheduledTransaction {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.id != 0 {
+            len += 1;
+        }
+        if self.plan_request.is_some() {
+            len += 1;
+        }
+        if self.start_height != 0 {
+            len += 1;
+        }
+        if self.interval != 0 {
+            len += 1;
+        }
+        if self.max_occurrences != 0 {
+            len += 1;
+        }
+        if self.occurrences != 0 {
+            len += 1;
+        }
+        if self.next_height != 0 {
+            len += 1;
+        }
+        if self.status != 0 {
+            len += 1;
+        }
+        if self.every_epoch {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.ScheduledTransaction", len)?;
+        if self.id != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("id", ToString::to_string(&self.id).as_str())?;
+        }
+        if let Some(v) = self.plan_request.as_ref() {
+            struct_ser.serialize_field("planRequest", v)?;
+        }
+        if self.start_height != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("startHeight", ToString::to_string(&self.start_height).as_str())?;
+        }
+        if self.interval != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("interval", ToString::to_string(&self.interval).as_str())?;
+        }
+        if self.max_occurrences != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("maxOccurrences", ToString::to_string(&self.max_occurrences).as_str())?;
+        }
+        if self.occurrences != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("occurrences", ToString::to_string(&self.occurrences).as_str())?;
+        }
+        if self.next_height != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("nextHeight", ToString::to_string(&self.next_height).as_str())?;
+        }
+        if self.status != 0 {
+            let v = ScheduleStatus::try_from(self.status)
+                .map_err(|_| serde::ser::Error::custom(format!("Invalid variant {}", self.status)))?;
+            struct_ser.serialize_field("status", &v)?;
+        }
+        if self.every_epoch {
+            struct_ser.serialize_field("everyEpoch", &self.every_epoch)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for ScheduledTransaction {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "id",
+            "plan_request",
+            "planRequest",
+            "start_height",
+            "startHeight",
+            "interval",
+            "max_occurrences",
+            "maxOccurrences",
+            "occurrences",
+            "next_height",
+            "nextHeight",
+            "status",
+            "every_epoch",
+            "everyEpoch",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Id,
+            PlanRequest,
+            StartHeight,
+            Interval,
+            MaxOccurrences,
+            Occurrences,
+            NextHeight,
+            Status,
+            EveryEpoch,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "id" => Ok(GeneratedField::Id),
+                            "planRequest" | "plan_request" => Ok(GeneratedField::PlanRequest),
+                            "startHeight" | "start_height" => Ok(GeneratedField::StartHeight),
+                            "interval" => Ok(GeneratedField::Interval),
+                            "maxOccurrences" | "max_occurrences" => Ok(GeneratedField::MaxOccurrences),
+                            "occurrences" => Ok(GeneratedField::Occurrences),
+                            "nextHeight" | "next_height" => Ok(GeneratedField::NextHeight),
+                            "status" => Ok(GeneratedField::Status),
+                            "everyEpoch" | "every_epoch" => Ok(GeneratedField::EveryEpoch),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = ScheduledTransaction;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.view.v1.ScheduledTransaction")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ScheduledTransaction, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut id__ = None;
+                let mut plan_request__ = None;
+                let mut start_height__ = None;
+                let mut interval__ = None;
+                let mut max_occurrences__ = None;
+                let mut occurrences__ = None;
+                let mut next_height__ = None;
+                let mut status__ = None;
+                let mut every_epoch__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Id => {
+                            if id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("id"));
+                            }
+                            id__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::PlanRequest => {
+                            if plan_request__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("planRequest"));
+                            }
+                            plan_request__ = map_.next_value()?;
+                        }
+                        GeneratedField::StartHeight => {
+                            if start_height__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("startHeight"));
+                            }
+                            start_height__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Interval => {
+                            if interval__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("interval"));
+                            }
+                            interval__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::MaxOccurrences => {
+                            if max_occurrences__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("maxOccurrences"));
+                            }
+                            max_occurrences__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Occurrences => {
+                            if occurrences__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("occurrences"));
+                            }
+                            occurrences__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::NextHeight => {
+                            if next_height__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("nextHeight"));
+                            }
+                            next_height__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Status => {
+                            if status__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("status"));
+                            }
+                            status__ = Some(map_.next_value::<ScheduleStatus>()? as i32);
+                        }
+                        GeneratedField::EveryEpoch => {
+                            if every_epoch__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("everyEpoch"));
+                            }
+                            every_epoch__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(ScheduledTransaction {
+                    id: id__.unwrap_or_default(),
+                    plan_request: plan_request__,
+                    start_height: start_height__.unwrap_or_default(),
+                    interval: interval__.unwrap_or_default(),
+                    max_occurrences: max_occurrences__.unwrap_or_default(),
+                    occurrences: occurrences__.unwrap_or_default(),
+                    next_height: next_height__.unwrap_or_default(),
+                    status: status__.unwrap_or_default(),
+                    every_epoch: every_epoch__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.view.v1.ScheduledTransaction", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for ScheduledTransactionExecution {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.schedule_id != 0 {
+            len += 1;
+        }
+        if self.height != 0 {
+            len += 1;
+        }
+        if self.transaction_id.is_some() {
+            len += 1;
+        }
+        if !self.error.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.ScheduledTransactionExecution", len)?;
+        if self.schedule_id != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("scheduleId", ToString::to_string(&self.schedule_id).as_str())?;
+        }
+        if self.height != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("height", ToString::to_string(&self.height).as_str())?;
+        }
+        if let Some(v) = self.transaction_id.as_ref() {
+            struct_ser.serialize_field("transactionId", v)?;
+        }
+        if !self.error.is_empty() {
+            struct_ser.serialize_field("error", &self.error)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for ScheduledTransactionExecution {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "schedule_id",
+            "scheduleId",
+            "height",
+            "transaction_id",
+            "transactionId",
+            "error",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            ScheduleId,
+            Height,
+            TransactionId,
+            Error,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "scheduleId" | "schedule_id" => Ok(GeneratedField::ScheduleId),
+                            "height" => Ok(GeneratedField::Height),
+                            "transactionId" | "transaction_id" => Ok(GeneratedField::TransactionId),
+                            "error" => Ok(GeneratedField::Error),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = ScheduledTransactionExecution;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.view.v1.ScheduledTransactionExecution")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ScheduledTransactionExecution, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut schedule_id__ = None;
+                let mut height__ = None;
+                let mut transaction_id__ = None;
+                let mut error__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::ScheduleId => {
+                            if schedule_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("scheduleId"));
+                            }
+                            schedule_id__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Height => {
+                            if height__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("height"));
+                            }
+                            height__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::TransactionId => {
+                            if transaction_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("transactionId"));
+                            }
+                            transaction_id__ = map_.next_value()?;
+                        }
+                        GeneratedField::Error => {
+                            if error__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("error"));
+                            }
+                            error__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(ScheduledTransactionExecution {
+                    schedule_id: schedule_id__.unwrap_or_default(),
+                    height: height__.unwrap_or_default(),
+                    transaction_id: transaction_id__,
+                    error: error__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.view.v1.ScheduledTransactionExecution", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for SchedulesRequest {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.schedule_ids.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.SchedulesRequest", len)?;
+        if !self.schedule_ids.is_empty() {
+            struct_ser.serialize_field("scheduleIds", &self.schedule_ids.iter().map(ToString::to_string).collect::<Vec<_>>())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for SchedulesRequest {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "schedule_ids",
+            "scheduleIds",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            ScheduleIds,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "scheduleIds" | "schedule_ids" => Ok(GeneratedField::ScheduleIds),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = SchedulesRequest;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.view.v1.SchedulesRequest")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<SchedulesRequest, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut schedule_ids__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::ScheduleIds => {
+                            if schedule_ids__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("scheduleIds"));
+                            }
+                            schedule_ids__ = 
+                                Some(map_.next_value::<Vec<::pbjson::private::NumberDeserialize<_>>>()?
+                                    .into_iter().map(|x| x.0).collect())
+                            ;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(SchedulesRequest {
+                    schedule_ids: schedule_ids__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.view.v1.SchedulesRequest", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for SchedulesResponse {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.schedule.is_some() {
+            len += 1;
+        }
+        if !self.executions.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.SchedulesResponse", len)?;
+        if let Some(v) = self.schedule.as_ref() {
+            struct_ser.serialize_field("schedule", v)?;
+        }
+        if !self.executions.is_empty() {
+            struct_ser.serialize_field("executions", &self.executions)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for SchedulesResponse {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "schedule",
+            "executions",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Schedule,
+            Executions,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "schedule" => Ok(GeneratedField::Schedule),
+                            "executions" => Ok(GeneratedField::Executions),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = SchedulesResponse;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.view.v1.SchedulesResponse")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<SchedulesResponse, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut schedule__ = None;
+                let mut executions__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Schedule => {
+                            if schedule__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("schedule"));
+                            }
+                            schedule__ = map_.next_value()?;
+                        }
+                        GeneratedField::Executions => {
+                            if executions__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("executions"));
+                            }
+                            executions__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(SchedulesResponse {
+                    schedule: schedule__,
+                    executions: executions__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.view.v1.SchedulesResponse", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for SpendableNoteRecord {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.note_commitment.is_some() {
+            len += 1;
+        }
+        if self.note.is_some() {
+            len += 1;
+        }
+        if self.address_index.is_some() {
+            len += 1;
+        }
+        if self.nullifier.is_some() {
+            len += 1;
+        }
+        if self.height_created != 0 {
+            len += 1;
+        }
+        if self.height_spent != 0 {
+            len += 1;
+        }
+        if self.position != 0 {
+            len += 1;
+        }
+        if self.source.is_some() {
+            len += 1;
+        }
+        if self.return_address.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.SpendableNoteRecord", len)?;
+        if let Some(v) = self.note_commitment.as_ref() {
+            struct_ser.serialize_field("noteCommitment", v)?;
+        }
+        if let Some(v) = self.note.as_ref() {
+            struct_ser.serialize_field("note", v)?;
+        }
+        if let Some(v) = self.address_index.as_ref() {
+            struct_ser.serialize_field("addressIndex", v)?;
+        }
+        if let Some(v) = self.nullifier.as_ref() {
+            struct_ser.serialize_field("nullifier", v)?;
+        }
+        if self.height_created != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("heightCreated", ToString::to_string(&self.height_created).as_str())?;
+        }
+        if self.height_spent != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("heightSpent", ToString::to_string(&self.height_spent).as_str())?;
+        }
+        if self.position != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("position", ToString::to_string(&self.position).as_str())?;
+        }
+        if let Some(v) = self.source.as_ref() {
+            struct_ser.serialize_field("source", v)?;
+        }
+        if let Some(v) = self.return_address.as_ref() {
+            struct_ser.serialize_field("returnAddress", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for SpendableNoteRecord {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "note_commitment",
+            "noteCommitment",
+            "note",
+            "address_index",
+            "addressIndex",
+            "nullifier",
+            "height_created",
+            "heightCreated",
+            "height_spent",
+            "heightSpent",
+            "position",
+            "source",
+            "return_address",
+            "returnAddress",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            NoteCommitment,
+            Note,
+            AddressIndex,
+            Nullifier,
+            HeightCreated,
+            HeightSpent,
+            Position,
+            Source,
+            ReturnAddress,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "noteCommitment" | "note_commitment" => Ok(GeneratedField::NoteCommitment),
+                            "note" => Ok(GeneratedField::Note),
+                            "addressIndex" | "address_index" => Ok(GeneratedField::AddressIndex),
+                            "nullifier" => Ok(GeneratedField::Nullifier),
+                            "heightCreated" | "height_created" => Ok(GeneratedField::HeightCreated),
+                            "heightSpent" | "height_spent" => Ok(GeneratedField::HeightSpent),
+                            "position" => Ok(GeneratedField::Position),
+                            "source" => Ok(GeneratedField::Source),
+                            "returnAddress" | "return_address" => Ok(GeneratedField::ReturnAddress),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = SpendableNoteRecord;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.view.v1.SpendableNoteRecord")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<SpendableNoteRecord, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut note_commitment__ = None;
+                let mut note__ = None;
+                let mut address_index__ = None;
+                let mut nullifier__ = None;
+                let mut height_created__ = None;
+                let mut height_spent__ = None;
+                let mut position__ = None;
+                let mut source__ = None;
+                let mut return_address__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::NoteCommitment => {
+                            if note_commitment__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("noteCommitment"));
+                            }
+                            note_commitment__ = map_.next_value()?;
+                        }
+                        GeneratedField::Note => {
+                            if note__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("note"));
+                            }
+                            note__ = map_.next_value()?;
+                        }
+                        GeneratedField::AddressIndex => {
+                            if address_index__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("addressIndex"));
+                            }
+                            address_index__ = map_.next_value()?;
+                        }
+                        GeneratedField::Nullifier => {
+                            if nullifier__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("nullifier"));
+                            }
+                            nullifier__ = map_.next_value()?;
+                        }
+                        GeneratedField::HeightCreated => {
+                            if height_created__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("heightCreated"));
+                            }
+                            height_created__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::HeightSpent => {
+                            if height_spent__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("heightSpent"));
+                            }
+                            height_spent__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Position => {
+                            if position__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("position"));
+                            }
+                            position__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Source => {
+                            if source__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("source"));
+                            }
+                            source__ = map_.next_value()?;
+                        }
+                        GeneratedField::ReturnAddress => {
+                            if return_address__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("returnAddress"));
+                            }
+                            return_address__ = map_.next_value()?;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(SpendableNoteRecord {
+                    note_commitment: note_commitment__,
+                    note: note__,
+                    address_index: address_index__,
+                    nullifier: nullifier__,
+                    height_created: height_created__.unwrap_or_default(),
+                    height_spent: height_spent__.unwrap_or_default(),
+                    position: position__.unwrap_or_default(),
+                    source: source__,
+                    return_address: return_address__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.view.v1.SpendableNoteRecord", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for StakingRewards {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.identity_key.is_some() {
+            len += 1;
+        }
+        if self.current_rate_data.is_some() {
+            len += 1;
+        }
+        if self.delegation_tokens.is_some() {
+            len += 1;
+        }
+        if self.current_value.is_some() {
+            len += 1;
+        }
+        if self.cost_basis.is_some() {
+            len += 1;
+        }
+        if self.realized_rewards.is_some() {
+            len += 1;
+        }
+        if self.unrealized_rewards.is_some() {
+            len += 1;
+        }
+        if self.projected_apr_bps != 0 {
+            len += 1;
+        }
+        if !self.history.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.StakingRewards", len)?;
+        if let Some(v) = self.identity_key.as_ref() {
+            struct_ser.serialize_field("identityKey", v)?;
+        }
+        if let Some(v) = self.current_rate_data.as_ref() {
+            struct_ser.serialize_field("currentRateData", v)?;
+        }
+        if let Some(v) = self.delegation_tokens.as_ref() {
+            struct_ser.serialize_field("delegationTokens", v)?;
+        }
+        if let Some(v) = self.current_value.as_ref() {
+            struct_ser.serialize_field("currentValue", v)?;
+        }
+        if let Some(v) = self.cost_basis.as_ref() {
+            struct_ser.serialize_field("costBasis", v)?;
+        }
+        if let Some(v) = self.realized_rewards.as_ref() {
+            struct_ser.serialize_field("realizedRewards", v)?;
+        }
+        if let Some(v) = self.unrealized_rewards.as_ref() {
+            struct_ser.serialize_field("unrealizedRewards", v)?;
+        }
+        if self.projected_apr_bps != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("projectedAprBps", ToString::to_string(&self.projected_apr_bps).as_str())?;
+        }
+        if !self.history.is_empty() {
+            struct_ser.serialize_field("history", &self.history)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for StakingRewards {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "identity_key",
+            "identityKey",
+            "current_rate_data",
+            "currentRateData",
+            "delegation_tokens",
+            "delegationTokens",
+            "current_value",
+            "currentValue",
+            "cost_basis",
+            "costBasis",
+            "realized_rewards",
+            "realizedRewards",
+            "unrealized_rewards",
+            "unrealizedRewards",
+            "projected_apr_bps",
+            "projectedAprBps",
+            "history",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            IdentityKey,
+            CurrentRateData,
+            DelegationTokens,
+            CurrentValue,
+            CostBasis,
+            RealizedRewards,
+            UnrealizedRewards,
+            ProjectedAprBps,
+            History,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "identityKey" | "identity_key" => Ok(GeneratedField::IdentityKey),
+                            "currentRateData" | "current_rate_data" => Ok(GeneratedField::CurrentRateData),
+                            "delegationTokens" | "delegation_tokens" => Ok(GeneratedField::DelegationTokens),
+                            "currentValue" | "current_value" => Ok(GeneratedField::CurrentValue),
+                            "costBasis" | "cost_basis" => Ok(GeneratedField::CostBasis),
+                            "realizedRewards" | "realized_rewards" => Ok(GeneratedField::RealizedRewards),
+                            "unrealizedRewards" | "unrealized_rewards" => Ok(GeneratedField::UnrealizedRewards),
+                            "projectedAprBps" | "projected_apr_bps" => Ok(GeneratedField::ProjectedAprBps),
+                            "history" => Ok(GeneratedField::History),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = StakingRewards;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.view.v1.StakingRewards")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<StakingRewards, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut identity_key__ = None;
+                let mut current_rate_data__ = None;
+                let mut delegation_tokens__ = None;
+                let mut current_value__ = None;
+                let mut cost_basis__ = None;
+                let mut realized_rewards__ = None;
+                let mut unrealized_rewards__ = None;
+                let mut projected_apr_bps__ = None;
+                let mut history__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::IdentityKey => {
+                            if identity_key__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("identityKey"));
+                            }
+                            identity_key__ = map_.next_value()?;
+                        }
+                        GeneratedField::CurrentRateData => {
+                            if current_rate_data__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("currentRateData"));
+                            }
+                            current_rate_data__ = map_.next_value()?;
+                        }
+                        GeneratedField::DelegationTokens => {
+                            if delegation_tokens__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("delegationTokens"));
+                            }
+                            delegation_tokens__ = map_.next_value()?;
+                        }
+                        GeneratedField::CurrentValue => {
+                            if current_value__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("currentValue"));
+                            }
+                            current_value__ = map_.next_value()?;
+                        }
+                        GeneratedField::CostBasis => {
+                            if cost_basis__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("costBasis"));
+                            }
+                            cost_basis__ = map_.next_value()?;
+                        }
+                        GeneratedField::RealizedRewards => {
+                            if realized_rewards__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("realizedRewards"));
+                            }
+                            realized_rewards__ = map_.next_value()?;
+                        }
+                        GeneratedField::UnrealizedRewards => {
+                            if unrealized_rewards__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("unrealizedRewards"));
+                            }
+                            unrealized_rewards__ = map_.next_value()?;
+                        }
+                        GeneratedField::ProjectedAprBps => {
+                            if projected_apr_bps__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("projectedAprBps"));
+                            }
+                            projected_apr_bps__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::History => {
+                            if history__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("history"));
+                            }
+                            history__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(StakingRewards {
+                    identity_key: identity_key__,
+                    current_rate_data: current_rate_data__,
+                    delegation_tokens: delegation_tokens__,
+                    current_value: current_value__,
+                    cost_basis: cost_basis__,
+                    realized_rewards: realized_rewards__,
+                    unrealized_rewards: unrealized_rewards__,
+                    projected_apr_bps: projected_apr_bps__.unwrap_or_default(),
+                    history: history__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.view.v1.StakingRewards", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for StakingRewardsEvent {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.kind != 0 {
+            len += 1;
+        }
+        if self.height != 0 {
+            len += 1;
+        }
+        if self.epoch_index != 0 {
+            len += 1;
+        }
+        if self.delegation_amount.is_some() {
+            len += 1;
+        }
+        if self.unbonded_amount.is_some() {
+            len += 1;
+        }
+        if self.realized_rewards.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.StakingRewardsEvent", len)?;
+        if self.kind != 0 {
+            let v = staking_rewards_event::Kind::try_from(self.kind)
+                .map_err(|_| serde::ser::Error::custom(format!("Invalid variant {}", self.kind)))?;
+            struct_ser.serialize_field("kind", &v)?;
+        }
+        if self.height != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("height", ToString::to_string(&self.height).as_str())?;
+        }
+        if self.epoch_index != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("epochIndex", ToString::to_string(&self.epoch_index).as_str())?;
+        }
+        if let Some(v) = self.delegation_amount.as_ref() {
+            struct_ser.serialize_field("delegationAmount", v)?;
+        }
+        if let Some(v) = self.unbonded_amount.as_ref() {
+            struct_ser.serialize_field("unbondedAmount", v)?;
+        }
+        if let Some(v) = self.realized_rewards.as_ref() {
+            struct_ser.serialize_field("realizedRewards", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for StakingRewardsEvent {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "kind",
+            "height",
+            "epoch_index",
+            "epochIndex",
+            "delegation_amount",
+            "delegationAmount",
+            "unbonded_amount",
+            "unbondedAmount",
+            "realized_rewards",
+            "realizedRewards",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Kind,
+            Height,
+            EpochIndex,
+            DelegationAmount,
+            UnbondedAmount,
+            RealizedRewards,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "kind" => Ok(GeneratedField::Kind),
+                            "height" => Ok(GeneratedField::Height),
+                            "epochIndex" | "epoch_index" => Ok(GeneratedField::EpochIndex),
+                            "delegationAmount" | "delegation_amount" => Ok(GeneratedField::DelegationAmount),
+                            "unbondedAmount" | "unbonded_amount" => Ok(GeneratedField::UnbondedAmount),
+                            "realizedRewards" | "realized_rewards" => Ok(GeneratedField::RealizedRewards),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = StakingRewardsEvent;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.view.v1.StakingRewardsEvent")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<StakingRewardsEvent, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut kind__ = None;
+                let mut height__ = None;
+                let mut epoch_index__ = None;
+                let mut delegation_amount__ = None;
+                let mut unbonded_amount__ = None;
+                let mut realized_rewards__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Kind => {
+                            if kind__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("kind"));
+                            }
+                            kind__ = Some(map_.next_value::<staking_rewards_event::Kind>()? as i32);
+                        }
+                        GeneratedField::Height => {
+                            if height__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("height"));
+                            }
+                            height__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::EpochIndex => {
+                            if epoch_index__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("epochIndex"));
+                            }
+                            epoch_index__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::DelegationAmount => {
+                            if delegation_amount__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("delegationAmount"));
+                            }
+                            delegation_amount__ = map_.next_value()?;
+                        }
+                        GeneratedField::UnbondedAmount => {
+                            if unbonded_amount__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("unbondedAmount"));
+                            }
+                            unbonded_amount__ = map_.next_value()?;
+                        }
+                        GeneratedField::RealizedRewards => {
+                            if realized_rewards__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("realizedRewards"));
+                            }
+                            realized_rewards__ = map_.next_value()?;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(StakingRewardsEvent {
+                    kind: kind__.unwrap_or_default(),
+                    height: height__.unwrap_or_default(),
+                    epoch_index: epoch_index__.unwrap_or_default(),
+                    delegation_amount: delegation_amount__,
+                    unbonded_amount: unbonded_amount__,
+                    realized_rewards: realized_rewards__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.view.v1.StakingRewardsEvent", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for staking_rewards_event::Kind {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let variant = match self {
+            Self::Unspecified => "KIND_UNSPECIFIED",
+            Self::Delegation => "KIND_DELEGATION",
+            Self::Undelegation => "KIND_UNDELEGATION",
+        };
+        serializer.serialize_str(variant)
+    }
+}
+impl<'de> serde::Deserialize<'de> for staking_rewards_event::Kind {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "KIND_UNSPECIFIED",
+            "KIND_DELEGATION",
+            "KIND_UNDELEGATION",
+        ];
+
+        struct GeneratedVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = staking_rewards_event::Kind;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(formatter, "expected one of: {:?}", &FIELDS)
+            }
+
+            fn visit_i64<E>(self, v: i64) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                i32::try_from(v)
+                    .ok()
+                    .and_then(|x| x.try_into().ok())
+                    .ok_or_else(|| {
+                        serde::de::Error::invalid_value(serde::de::Unexpected::Signed(v), &self)
+                    })
+            }
+
+            fn visit_u64<E>(self, v: u64) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                i32::try_from(v)
+                    .ok()
+                    .and_then(|x| x.try_into().ok())
+                    .ok_or_else(|| {
+                        serde::de::Error::invalid_value(serde::de::Unexpected::Unsigned(v), &self)
+                    })
+            }
+
+            fn visit_str<E>(self, value: &str) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                match value {
+                    "KIND_UNSPECIFIED" => Ok(staking_rewards_event::Kind::Unspecified),
+                    "KIND_DELEGATION" => Ok(staking_rewards_event::Kind::Delegation),
+                    "KIND_UNDELEGATION" => Ok(staking_rewards_event::Kind::Undelegation),
+                    _ => Err(serde::de::Error::unknown_variant(value, FIELDS)),
+                }
+            }
+        }
+        deserializer.deserialize_any(GeneratedVisitor)
+    }
+}
+impl serde::Serialize for StakingRewardsRequest {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.identity_keys.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.StakingRewardsRequest", len)?;
+        if !self.identity_keys.is_empty() {
+            struct_ser.serialize_field("identityKeys", &self.identity_keys)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for StakingRewardsRequest {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "identity_keys",
+            "identityKeys",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            IdentityKeys,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "identityKeys" | "identity_keys" => Ok(GeneratedField::IdentityKeys),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = StakingRewardsRequest;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.view.v1.StakingRewardsRequest")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<StakingRewardsRequest, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut identity_keys__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::IdentityKeys => {
+                            if identity_keys__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("identityKeys"));
+                            }
+                            identity_keys__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(StakingRewardsRequest {
+                    identity_keys: identity_keys__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.view.v1.StakingRewardsRequest", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for StakingRewardsResponse {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.rewards.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.StakingRewardsResponse", len)?;
+        if let Some(v) = self.rewards.as_ref() {
+            struct_ser.serialize_field("rewards", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for StakingRewardsResponse {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "rewards",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            NoteCommitment,
-            Note,
-            AddressIndex,
-            Nullifier,
-            HeightCreated,
-            HeightSpent,
-            Position,
-            Source,
-            ReturnAddress,
+            Rewards,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -4551,15 +8606,7 @@ impl<'de> serde::Deserialize<'de> for SpendableNoteRecord {
                         E: serde::de::Error,
                     {
                         match value {
-                            "noteCommitment" | "note_commitment" => Ok(GeneratedField::NoteCommitment),
-                            "note" => Ok(GeneratedField::Note),
-                            "addressIndex" | "address_index" => Ok(GeneratedField::AddressIndex),
-                            "nullifier" => Ok(GeneratedField::Nullifier),
-                            "heightCreated" | "height_created" => Ok(GeneratedField::HeightCreated),
-                            "heightSpent" | "height_spent" => Ok(GeneratedField::HeightSpent),
-                            "position" => Ok(GeneratedField::Position),
-                            "source" => Ok(GeneratedField::Source),
-                            "returnAddress" | "return_address" => Ok(GeneratedField::ReturnAddress),
+                            "rewards" => Ok(GeneratedField::Rewards),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -4569,106 +8616,36 @@ impl<'de> serde::Deserialize<'de> for SpendableNoteRecord {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = SpendableNoteRecord;
+            type Value = StakingRewardsResponse;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.view.v1.SpendableNoteRecord")
+                formatter.write_str("struct penumbra.view.v1.StakingRewardsResponse")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<SpendableNoteRecord, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<StakingRewardsResponse, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut note_commitment__ = None;
-                let mut note__ = None;
-                let mut address_index__ = None;
-                let mut nullifier__ = None;
-                let mut height_created__ = None;
-                let mut height_spent__ = None;
-                let mut position__ = None;
-                let mut source__ = None;
-                let mut return_address__ = None;
+                let mut rewards__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::NoteCommitment => {
-                            if note_commitment__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("noteCommitment"));
-                            }
-                            note_commitment__ = map_.next_value()?;
-                        }
-                        GeneratedField::Note => {
-                            if note__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("note"));
-                            }
-                            note__ = map_.next_value()?;
-                        }
-                        GeneratedField::AddressIndex => {
-                            if address_index__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("addressIndex"));
-                            }
-                            address_index__ = map_.next_value()?;
-                        }
-                        GeneratedField::Nullifier => {
-                            if nullifier__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("nullifier"));
-                            }
-                            nullifier__ = map_.next_value()?;
-                        }
-                        GeneratedField::HeightCreated => {
-                            if height_created__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("heightCreated"));
-                            }
-                            height_created__ = 
-                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
-                            ;
-                        }
-                        GeneratedField::HeightSpent => {
-                            if height_spent__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("heightSpent"));
-                            }
-                            height_spent__ = 
-                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
-                            ;
-                        }
-                        GeneratedField::Position => {
-                            if position__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("position"));
-                            }
-                            position__ = 
-                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
-                            ;
-                        }
-                        GeneratedField::Source => {
-                            if source__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("source"));
-                            }
-                            source__ = map_.next_value()?;
-                        }
-                        GeneratedField::ReturnAddress => {
-                            if return_address__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("returnAddress"));
+                        GeneratedField::Rewards => {
+                            if rewards__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("rewards"));
                             }
-                            return_address__ = map_.next_value()?;
+                            rewards__ = map_.next_value()?;
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(SpendableNoteRecord {
-                    note_commitment: note_commitment__,
-                    note: note__,
-                    address_index: address_index__,
-                    nullifier: nullifier__,
-                    height_created: height_created__.unwrap_or_default(),
-                    height_spent: height_spent__.unwrap_or_default(),
-                    position: position__.unwrap_or_default(),
-                    source: source__,
-                    return_address: return_address__,
+                Ok(StakingRewardsResponse {
+                    rewards: rewards__,
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.view.v1.SpendableNoteRecord", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.view.v1.StakingRewardsResponse", FIELDS, GeneratedVisitor)
     }
 }
 impl serde::Serialize for StatusRequest {
@@ -8992,6 +12969,196 @@ impl<'de> serde::Deserialize<'de> for UnclaimedSwapsResponse {
         deserializer.deserialize_struct("penumbra.view.v1.UnclaimedSwapsResponse", FIELDS, GeneratedVisitor)
     }
 }
+impl serde::Serialize for UpdateScheduleStatusRequest {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.schedule_id != 0 {
+            len += 1;
+        }
+        if self.status != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.UpdateScheduleStatusRequest", len)?;
+        if self.schedule_id != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("scheduleId", ToString::to_string(&self.schedule_id).as_str())?;
+        }
+        if self.status != 0 {
+            let v = ScheduleStatus::try_from(self.status)
+                .map_err(|_| serde::ser::Error::custom(format!("Invalid variant {}", self.status)))?;
+            struct_ser.serialize_field("status", &v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for UpdateScheduleStatusRequest {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "schedule_id",
+            "scheduleId",
+            "status",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            ScheduleId,
+            Status,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "scheduleId" | "schedule_id" => Ok(GeneratedField::ScheduleId),
+                            "status" => Ok(GeneratedField::Status),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = UpdateScheduleStatusRequest;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.view.v1.UpdateScheduleStatusRequest")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<UpdateScheduleStatusRequest, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut schedule_id__ = None;
+                let mut status__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::ScheduleId => {
+                            if schedule_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("scheduleId"));
+                            }
+                            schedule_id__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Status => {
+                            if status__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("status"));
+                            }
+                            status__ = Some(map_.next_value::<ScheduleStatus>()? as i32);
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(UpdateScheduleStatusRequest {
+                    schedule_id: schedule_id__.unwrap_or_default(),
+                    status: status__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.view.v1.UpdateScheduleStatusRequest", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for UpdateScheduleStatusResponse {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let len = 0;
+        let struct_ser = serializer.serialize_struct("penumbra.view.v1.UpdateScheduleStatusResponse", len)?;
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for UpdateScheduleStatusResponse {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                            Ok(GeneratedField::__SkipField__)
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = UpdateScheduleStatusResponse;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.view.v1.UpdateScheduleStatusResponse")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<UpdateScheduleStatusResponse, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                while map_.next_key::<GeneratedField>()?.is_some() {
+                    let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                }
+                Ok(UpdateScheduleStatusResponse {
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.view.v1.UpdateScheduleStatusResponse", FIELDS, GeneratedVisitor)
+    }
+}
 impl serde::Serialize for WalletIdRequest {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
@@ -402,106 +402,6 @@ impl SignedMsgType {
         }
     }
 }
-#[allow(clippy::derive_partial_eq_without_eq)]
-#[derive(Clone, PartialEq, ::prost::Message)]
-pub struct Evidence {
-    #[prost(oneof = "evidence::Sum", tags = "1, 2")]
-    pub sum: ::core::option::Option<evidence::Sum>,
-}
-/// Nested message and enum types in `Evidence`.
-pub mod evidence {
-    #[allow(clippy::derive_partial_eq_without_eq)]
-    #[derive(Clone, PartialEq, ::prost::Oneof)]
-    pub enum Sum {
-        #[prost(message, tag = "1")]
-        DuplicateVoteEvidence(super::DuplicateVoteEvidence),
-        #[prost(message, tag = "2")]
-        LightClientAttackEvidence(super::LightClientAttackEvidence),
-    }
-}
-impl ::prost::Name for Evidence {
-    const NAME: &'static str = "Evidence";
-    const PACKAGE: &'static str = "tendermint.types";
-    fn full_name() -> ::prost::alloc::string::String {
-        ::prost::alloc::format!("tendermint.types.{}", Self::NAME)
-    }
-}
-/// DuplicateVoteEvidence contains evidence of a validator signed two conflicting votes.
-#[allow(clippy::derive_partial_eq_without_eq)]
-#[derive(Clone, PartialEq, ::prost::Message)]
-pub struct DuplicateVoteEvidence {
-    #[prost(message, optional, tag = "1")]
-    pub vote_a: ::core::option::Option<Vote>,
-    #[prost(message, optional, tag = "2")]
-    pub vote_b: ::core::option::Option<Vote>,
-    #[prost(int64, tag = "3")]
-    pub total_voting_power: i64,
-    #[prost(int64, tag = "4")]
-    pub validator_power: i64,
-    #[prost(message, optional, tag = "5")]
-    pub timestamp: ::core::option::Option<::pbjson_types::Timestamp>,
-}
-impl ::prost::Name for DuplicateVoteEvidence {
-    const NAME: &'static str = "DuplicateVoteEvidence";
-    const PACKAGE: &'static str = "tendermint.types";
-    fn full_name() -> ::prost::alloc::string::String {
-        ::prost::alloc::format!("tendermint.types.{}", Self::NAME)
-    }
-}
-/// LightClientAttackEvidence contains evidence of a set of validators attempting to mislead a light client.
-#[allow(clippy::derive_partial_eq_without_eq)]
-#[derive(Clone, PartialEq, ::prost::Message)]
-pub struct LightClientAttackEvidence {
-    #[prost(message, optional, tag = "1")]
-    pub conflicting_block: ::core::option::Option<LightBlock>,
-    #[prost(int64, tag = "2")]
-    pub common_height: i64,
-    #[prost(message, repeated, tag = "3")]
-    pub byzantine_validators: ::prost::alloc::vec::Vec<Validator>,
-    #[prost(int64, tag = "4")]
-    pub total_voting_power: i64,
-    #[prost(message, optional, tag = "5")]
-    pub timestamp: ::core::option::Option<::pbjson_types::Timestamp>,
-}
-impl ::prost::Name for LightClientAttackEvidence {
-    const NAME: &'static str = "LightClientAttackEvidence";
-    const PACKAGE: &'static str = "tendermint.types";
-    fn full_name() -> ::prost::alloc::string::String {
-        ::prost::alloc::format!("tendermint.types.{}", Self::NAME)
-    }
-}
-#[allow(clippy::derive_partial_eq_without_eq)]
-#[derive(Clone, PartialEq, ::prost::Message)]
-pub struct EvidenceList {
-    #[prost(message, repeated, tag = "1")]
-    pub evidence: ::prost::alloc::vec::Vec<Evidence>,
-}
-impl ::prost::Name for EvidenceList {
-    const NAME: &'static str = "EvidenceList";
-    const PACKAGE: &'static str = "tendermint.types";
-    fn full_name() -> ::prost::alloc::string::String {
-        ::prost::alloc::format!("tendermint.types.{}", Self::NAME)
-    }
-}
-#[allow(clippy::derive_partial_eq_without_eq)]
-#[derive(Clone, PartialEq, ::prost::Message)]
-pub struct Block {
-    #[prost(message, optional, tag = "1")]
-    pub header: ::core::option::Option<Header>,
-    #[prost(message, optional, tag = "2")]
-    pub data: ::core::option::Option<Data>,
-    #[prost(message, optional, tag = "3")]
-    pub evidence: ::core::option::Option<EvidenceList>,
-    #[prost(message, optional, tag = "4")]
-    pub last_commit: ::core::option::Option<Commit>,
-}
-impl ::prost::Name for Block {
-    const NAME: &'static str = "Block";
-    const PACKAGE: &'static str = "tendermint.types";
-    fn full_name() -> ::prost::alloc::string::String {
-        ::prost::alloc::format!("tendermint.types.{}", Self::NAME)
-    }
-}
 /// ConsensusParams contains consensus critical parameters that determine the
 /// validity of blocks.
 #[allow(clippy::derive_partial_eq_without_eq)]
@@ -620,3 +520,103 @@ impl ::prost::Name for HashedParams {
         ::prost::alloc::format!("tendermint.types.{}", Self::NAME)
     }
 }
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Evidence {
+    #[prost(oneof = "evidence::Sum", tags = "1, 2")]
+    pub sum: ::core::option::Option<evidence::Sum>,
+}
+/// Nested message and enum types in `Evidence`.
+pub mod evidence {
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum Sum {
+        #[prost(message, tag = "1")]
+        DuplicateVoteEvidence(super::DuplicateVoteEvidence),
+        #[prost(message, tag = "2")]
+        LightClientAttackEvidence(super::LightClientAttackEvidence),
+    }
+}
+impl ::prost::Name for Evidence {
+    const NAME: &'static str = "Evidence";
+    const PACKAGE: &'static str = "tendermint.types";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("tendermint.types.{}", Self::NAME)
+    }
+}
+/// DuplicateVoteEvidence contains evidence of a validator signed two conflicting votes.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct DuplicateVoteEvidence {
+    #[prost(message, optional, tag = "1")]
+    pub vote_a: ::core::option::Option<Vote>,
+    #[prost(message, optional, tag = "2")]
+    pub vote_b: ::core::option::Option<Vote>,
+    #[prost(int64, tag = "3")]
+    pub total_voting_power: i64,
+    #[prost(int64, tag = "4")]
+    pub validator_power: i64,
+    #[prost(message, optional, tag = "5")]
+    pub timestamp: ::core::option::Option<::pbjson_types::Timestamp>,
+}
+impl ::prost::Name for DuplicateVoteEvidence {
+    const NAME: &'static str = "DuplicateVoteEvidence";
+    const PACKAGE: &'static str = "tendermint.types";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("tendermint.types.{}", Self::NAME)
+    }
+}
+/// LightClientAttackEvidence contains evidence of a set of validators attempting to mislead a light client.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct LightClientAttackEvidence {
+    #[prost(message, optional, tag = "1")]
+    pub conflicting_block: ::core::option::Option<LightBlock>,
+    #[prost(int64, tag = "2")]
+    pub common_height: i64,
+    #[prost(message, repeated, tag = "3")]
+    pub byzantine_validators: ::prost::alloc::vec::Vec<Validator>,
+    #[prost(int64, tag = "4")]
+    pub total_voting_power: i64,
+    #[prost(message, optional, tag = "5")]
+    pub timestamp: ::core::option::Option<::pbjson_types::Timestamp>,
+}
+impl ::prost::Name for LightClientAttackEvidence {
+    const NAME: &'static str = "LightClientAttackEvidence";
+    const PACKAGE: &'static str = "tendermint.types";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("tendermint.types.{}", Self::NAME)
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct EvidenceList {
+    #[prost(message, repeated, tag = "1")]
+    pub evidence: ::prost::alloc::vec::Vec<Evidence>,
+}
+impl ::prost::Name for EvidenceList {
+    const NAME: &'static str = "EvidenceList";
+    const PACKAGE: &'static str = "tendermint.types";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("tendermint.types.{}", Self::NAME)
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Block {
+    #[prost(message, optional, tag = "1")]
+    pub header: ::core::option::Option<Header>,
+    #[prost(message, optional, tag = "2")]
+    pub data: ::core::option::Option<Data>,
+    #[prost(message, optional, tag = "3")]
+    pub evidence: ::core::option::Option<EvidenceList>,
+    #[prost(message, optional, tag = "4")]
+    pub last_commit: ::core::option::Option<Commit>,
+}
+impl ::prost::Name for Block {
+    const NAME: &'static str = "Block";
+    const PACKAGE: &'static str = "tendermint.types";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("tendermint.types.{}", Self::NAME)
+    }
+}
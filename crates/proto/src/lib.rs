@@ -345,6 +345,9 @@ pub mod cosmos {
                 include!("gen/cosmos.crypto.multisig.v1beta1.rs");
             }
         }
+        pub mod secp256k1 {
+            include!("gen/cosmos.crypto.secp256k1.rs");
+        }
     }
 }
 
@@ -236,6 +236,33 @@ impl From<tendermint::validator::Info> for crate::tendermint::types::Validator {
     }
 }
 
+impl TryFrom<crate::tendermint::types::Validator> for tendermint::validator::Info {
+    type Error = anyhow::Error;
+    fn try_from(
+        crate::tendermint::types::Validator {
+            address: _,
+            pub_key,
+            voting_power,
+            proposer_priority,
+        }: crate::tendermint::types::Validator,
+    ) -> Result<Self, Self::Error> {
+        use crate::tendermint::crypto::public_key::Sum;
+        let pub_key = match pub_key
+            .and_then(|pub_key| pub_key.sum)
+            .ok_or(anyhow!("missing public key"))?
+        {
+            Sum::Ed25519(bytes) => tendermint::PublicKey::from_raw_ed25519(&bytes)
+                .ok_or(anyhow!("invalid ed25519 public key"))?,
+            Sum::Secp256k1(_) => anyhow::bail!("unsupported secp256k1 public key"),
+        };
+        // The address is derived from the public key, since the one converted from an `Info`
+        // above is hex-encoded.
+        let mut info = Self::new(pub_key, voting_power.try_into()?);
+        info.proposer_priority = proposer_priority.into();
+        Ok(info)
+    }
+}
+
 impl From<tendermint::node::info::ProtocolVersionInfo> for crate::tendermint::p2p::ProtocolVersion {
     fn from(
         tendermint::node::info::ProtocolVersionInfo {
@@ -689,6 +716,16 @@ impl TryFrom<tendermint::block::Commit> for crate::tendermint::types::Commit {
     }
 }
 
+impl TryFrom<crate::tendermint::types::Commit> for tendermint::block::Commit {
+    type Error = anyhow::Error;
+    fn try_from(commit: crate::tendermint::types::Commit) -> Result<Self, Self::Error> {
+        use crate::Message;
+        // Both protobuf types share an encoding, and `tendermint` converts from its own.
+        let commit = tendermint_proto::types::Commit::decode(commit.encode_to_vec().as_slice())?;
+        Ok(commit.try_into()?)
+    }
+}
+
 impl TryFrom<tendermint::block::CommitSig> for crate::tendermint::types::CommitSig {
     // TODO(kate): ideally this would not return a tonic status object, but we'll use this for
     // now to avoid invasively refactoring this code.
@@ -773,3 +810,66 @@ impl From<tendermint::block::parts::Header> for crate::tendermint::types::PartSe
         }
     }
 }
+
+// === get_validator_set ===
+
+impl From<tendermint_rpc::endpoint::validators::Response> for penumbra_pb::GetValidatorSetResponse {
+    fn from(
+        tendermint_rpc::endpoint::validators::Response {
+            validators,
+            block_height: _,
+            total: _,
+        }: tendermint_rpc::endpoint::validators::Response,
+    ) -> Self {
+        Self {
+            validators: validators.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+// === search_tx_events ===
+
+impl From<tendermint_rpc::endpoint::tx_search::Response> for penumbra_pb::SearchTxEventsResponse {
+    fn from(
+        tendermint_rpc::endpoint::tx_search::Response {
+            txs,
+            total_count: _,
+        }: tendermint_rpc::endpoint::tx_search::Response,
+    ) -> Self {
+        Self {
+            events: txs
+                .into_iter()
+                .flat_map(|tx| tx.tx_result.events)
+                .map(Into::into)
+                .collect(),
+        }
+    }
+}
+
+impl From<tendermint::abci::Event> for crate::tendermint::abci::Event {
+    fn from(tendermint::abci::Event { kind, attributes }: tendermint::abci::Event) -> Self {
+        Self {
+            r#type: kind,
+            attributes: attributes
+                .into_iter()
+                .map(|tendermint::abci::EventAttribute { key, value, index }| {
+                    crate::tendermint::abci::EventAttribute { key, value, index }
+                })
+                .collect(),
+        }
+    }
+}
+
+impl From<crate::tendermint::abci::Event> for tendermint::abci::Event {
+    fn from(
+        crate::tendermint::abci::Event { r#type, attributes }: crate::tendermint::abci::Event,
+    ) -> Self {
+        Self {
+            kind: r#type,
+            attributes: attributes
+                .into_iter()
+                .map(|attribute| (attribute.key, attribute.value, attribute.index).into())
+                .collect(),
+        }
+    }
+}
@@ -6,7 +6,8 @@ use {
             AbciQueryResponse, BroadcastTxAsyncRequest, BroadcastTxAsyncResponse,
             BroadcastTxSyncRequest, BroadcastTxSyncResponse, GetBlockByHeightRequest,
             GetBlockByHeightResponse, GetStatusRequest, GetStatusResponse, GetTxRequest,
-            GetTxResponse, SyncInfo,
+            GetTxResponse, GetValidatorSetRequest, GetValidatorSetResponse, SearchTxEventsRequest,
+            SearchTxEventsResponse, SyncInfo,
         },
     },
     std::{
@@ -237,4 +238,20 @@ impl TendermintProxyService for TestNodeProxy {
         })
         .map(tonic::Response::new)
     }
+
+    #[instrument(level = "info", skip_all)]
+    async fn get_validator_set(
+        &self,
+        _req: tonic::Request<GetValidatorSetRequest>,
+    ) -> Result<tonic::Response<GetValidatorSetResponse>, Status> {
+        Err(Status::unimplemented("get_validator_set"))
+    }
+
+    #[instrument(level = "info", skip_all)]
+    async fn search_tx_events(
+        &self,
+        _req: tonic::Request<SearchTxEventsRequest>,
+    ) -> Result<tonic::Response<SearchTxEventsResponse>, Status> {
+        Err(Status::unimplemented("search_tx_events"))
+    }
 }
@@ -4,6 +4,8 @@ use {
         AbciQueryResponse, BroadcastTxAsyncRequest, BroadcastTxAsyncResponse,
         BroadcastTxSyncRequest, BroadcastTxSyncResponse, GetBlockByHeightRequest,
         GetBlockByHeightResponse, GetStatusRequest, GetStatusResponse, GetTxRequest, GetTxResponse,
+        GetValidatorSetRequest, GetValidatorSetResponse, SearchTxEventsRequest,
+        SearchTxEventsResponse,
     },
     tonic::Status,
     tracing::instrument,
@@ -74,4 +76,20 @@ impl TendermintProxyService for StubProxy {
     ) -> Result<tonic::Response<GetBlockByHeightResponse>, Status> {
         Err(Status::unimplemented("get_block_by_height"))
     }
+
+    #[instrument(level = "info", skip_all)]
+    async fn get_validator_set(
+        &self,
+        _req: tonic::Request<GetValidatorSetRequest>,
+    ) -> Result<tonic::Response<GetValidatorSetResponse>, Status> {
+        Err(Status::unimplemented("get_validator_set"))
+    }
+
+    #[instrument(level = "info", skip_all)]
+    async fn search_tx_events(
+        &self,
+        _req: tonic::Request<SearchTxEventsRequest>,
+    ) -> Result<tonic::Response<SearchTxEventsResponse>, Status> {
+        Err(Status::unimplemented("search_tx_events"))
+    }
 }
@@ -5,13 +5,15 @@ use penumbra_proto::{
         AbciQueryResponse, BroadcastTxAsyncRequest, BroadcastTxAsyncResponse,
         BroadcastTxSyncRequest, BroadcastTxSyncResponse, GetBlockByHeightRequest,
         GetBlockByHeightResponse, GetStatusRequest, GetStatusResponse, GetTxRequest, GetTxResponse,
+        GetValidatorSetRequest, GetValidatorSetResponse, SearchTxEventsRequest,
+        SearchTxEventsResponse,
     },
     DomainType,
 };
 use penumbra_transaction::Transaction;
 use tap::TapFallible;
 use tendermint::{abci::Code, block::Height};
-use tendermint_rpc::{Client, HttpClient};
+use tendermint_rpc::{query::Query, Client, HttpClient, Order, Paging};
 use tonic::Status;
 use tracing::instrument;
 
@@ -208,4 +210,55 @@ impl TendermintProxyService for TendermintProxy {
             })
             .map(tonic::Response::new)
     }
+
+    #[instrument(level = "info", skip_all)]
+    async fn get_validator_set(
+        &self,
+        req: tonic::Request<GetValidatorSetRequest>,
+    ) -> Result<tonic::Response<GetValidatorSetResponse>, Status> {
+        // generic bounds on HttpClient::new are not well-constructed, so we have to
+        // render the URL as a String, then borrow it, then re-parse the borrowed &str
+        let client = HttpClient::new(self.tendermint_url.to_string().as_ref()).map_err(|e| {
+            tonic::Status::unavailable(format!("error creating tendermint http client: {e:#?}"))
+        })?;
+
+        // Parse the height from the inbound client request.
+        let GetValidatorSetRequest { height } = req.into_inner();
+        let height = tendermint::block::Height::try_from(height)
+            .map_err(|e| Status::invalid_argument(format!("invalid height: {e}")))?;
+
+        // Fetch every page of the validator set.
+        client
+            .validators(height, Paging::All)
+            .await
+            .map(GetValidatorSetResponse::from)
+            .map(tonic::Response::new)
+            .map_err(|e| tonic::Status::unavailable(format!("error querying validators: {e}")))
+    }
+
+    #[instrument(level = "info", skip_all)]
+    async fn search_tx_events(
+        &self,
+        req: tonic::Request<SearchTxEventsRequest>,
+    ) -> Result<tonic::Response<SearchTxEventsResponse>, Status> {
+        // generic bounds on HttpClient::new are not well-constructed, so we have to
+        // render the URL as a String, then borrow it, then re-parse the borrowed &str
+        let client = HttpClient::new(self.tendermint_url.to_string().as_ref()).map_err(|e| {
+            tonic::Status::unavailable(format!("error creating tendermint http client: {e:#?}"))
+        })?;
+
+        // Parse the query from the inbound client request.
+        let SearchTxEventsRequest { query } = req.into_inner();
+        let query = query
+            .parse::<Query>()
+            .map_err(|e| Status::invalid_argument(format!("invalid query: {e}")))?;
+
+        // Search the first page of matching transactions, most recent first.
+        client
+            .tx_search(query, false, 1, 100, Order::Descending)
+            .await
+            .map(SearchTxEventsResponse::from)
+            .map(tonic::Response::new)
+            .map_err(|e| tonic::Status::unavailable(format!("error searching txs: {e}")))
+    }
 }
@@ -0,0 +1,72 @@
+use std::time::Duration;
+
+use anyhow::{anyhow, ensure};
+use penumbra_proto::core::component::sct::v1::{
+    query_service_client::QueryServiceClient as SctQueryServiceClient, TimestampByHeightRequest,
+};
+use tonic::transport::Channel;
+
+/// Estimates the time between blocks from the timestamps of the `sample_blocks` blocks up to
+/// `height`, as recorded by the chain.
+pub async fn observed_block_time(
+    channel: Channel,
+    height: u64,
+    sample_blocks: u64,
+) -> anyhow::Result<Duration> {
+    let start_height = height.saturating_sub(sample_blocks).max(1);
+    ensure!(
+        start_height < height,
+        "not enough blocks to estimate the block time"
+    );
+
+    let mut client = SctQueryServiceClient::new(channel);
+    let start = timestamp_nanos(&mut client, start_height).await?;
+    let end = timestamp_nanos(&mut client, height).await?;
+
+    average_block_time(end - start, height - start_height)
+}
+
+/// Returns the timestamp of the block at `height`, in nanoseconds since the Unix epoch.
+async fn timestamp_nanos(
+    client: &mut SctQueryServiceClient<Channel>,
+    height: u64,
+) -> anyhow::Result<i128> {
+    let timestamp = client
+        .timestamp_by_height(TimestampByHeightRequest { height })
+        .await?
+        .into_inner()
+        .timestamp
+        .ok_or_else(|| anyhow!("missing timestamp for height {height}"))?;
+    Ok(i128::from(timestamp.seconds) * 1_000_000_000 + i128::from(timestamp.nanos))
+}
+
+/// Averages the `elapsed_nanos` it took to produce `blocks` blocks.
+fn average_block_time(elapsed_nanos: i128, blocks: u64) -> anyhow::Result<Duration> {
+    ensure!(blocks > 0, "no blocks to average over");
+    let nanos = u64::try_from(elapsed_nanos / i128::from(blocks))
+        .map_err(|_| anyhow!("block timestamps are out of order"))?;
+    Ok(Duration::from_nanos(nanos))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn block_time_is_averaged() {
+        assert_eq!(
+            average_block_time(50_000_000_000, 10).expect("blocks are averaged"),
+            Duration::from_secs(5)
+        );
+        assert_eq!(
+            average_block_time(5_500_000_000, 2).expect("blocks are averaged"),
+            Duration::from_millis(2750)
+        );
+    }
+
+    #[test]
+    fn out_of_order_timestamps_are_rejected() {
+        assert!(average_block_time(-1_000_000_000, 10).is_err());
+        assert!(average_block_time(1_000_000_000, 0).is_err());
+    }
+}
@@ -6,6 +6,7 @@ use pbjson_types::Any;
 use penumbra_auction::auction::AuctionId;
 use tonic::{codegen::Bytes, Streaming};
 use tracing::instrument;
+use url::Url;
 
 use penumbra_app::params::AppParameters;
 use penumbra_asset::{
@@ -30,7 +31,11 @@ use penumbra_transaction::{
     txhash::TransactionId, AuthorizationData, Transaction, TransactionPlan, WitnessData,
 };
 
-use crate::{SpendableNoteRecord, StatusStreamResponse, SwapRecord, TransactionInfo};
+use crate::{
+    ForwardingAccount, GovernanceFeedItem, Ics20WithdrawalRecord, PositionPerformance,
+    ScheduleStatus, ScheduledTransaction, ScheduledTransactionExecution, SpendableNoteRecord,
+    StakingRewards, StatusStreamResponse, SwapRecord, TransactionInfo,
+};
 
 pub(crate) type BroadcastStatusStream = Pin<
     Box<dyn Future<Output = Result<Streaming<BroadcastTransactionResponse>, anyhow::Error>> + Send>,
@@ -187,6 +192,111 @@ pub trait ViewClient {
         trading_pair: Option<TradingPair>,
     ) -> Pin<Box<dyn Future<Output = Result<Vec<position::Id>>> + Send + 'static>>;
 
+    /// Queries for the performance of liquidity positions owned by the full viewing key,
+    /// restricted to the given positions if `position_ids` is non-empty.
+    fn position_performance(
+        &mut self,
+        position_ids: Vec<position::Id>,
+    ) -> Pin<Box<dyn Future<Output = Result<Vec<PositionPerformance>>> + Send + 'static>>;
+
+    /// Queries for the staking rewards earned from each validator the wallet has delegated to,
+    /// restricted to the given validators if `identity_keys` is non-empty.
+    fn staking_rewards(
+        &mut self,
+        identity_keys: Vec<IdentityKey>,
+    ) -> Pin<Box<dyn Future<Output = Result<Vec<StakingRewards>>> + Send + 'static>>;
+
+    /// Queries for the governance proposals on chain, annotated with the wallet's relationship to
+    /// each. If `mark_seen` is set, the proposals' current states are recorded as seen, so later
+    /// queries only flag the proposals whose state has changed since.
+    fn governance_feed(
+        &mut self,
+        inactive: bool,
+        actionable_only: bool,
+        mark_seen: bool,
+    ) -> Pin<Box<dyn Future<Output = Result<Vec<GovernanceFeedItem>>> + Send + 'static>>;
+
+    /// Queries for the ICS-20 withdrawals sent by the wallet, restricted to those in the
+    /// transaction `transaction_id` if it is given, and including those already acknowledged or
+    /// refunded if `settled` is set.
+    fn ics20_withdrawals(
+        &mut self,
+        transaction_id: Option<TransactionId>,
+        settled: bool,
+    ) -> Pin<Box<dyn Future<Output = Result<Vec<Ics20WithdrawalRecord>>> + Send + 'static>>;
+
+    /// Adds a forwarding account for the address `address_index` on the counterparty chain
+    /// `chain_id` to the wallet's registry, returning it.
+    ///
+    /// Unless it is already `registered`, the view service registers the account on the
+    /// counterparty chain once it detects a deposit into it.
+    fn add_forwarding_account(
+        &mut self,
+        chain_id: String,
+        node: Url,
+        channel: String,
+        address_index: AddressIndex,
+        registered: bool,
+    ) -> Pin<Box<dyn Future<Output = Result<ForwardingAccount>> + Send + 'static>>;
+
+    /// Queries for the forwarding accounts in the wallet's registry, restricted to those on the
+    /// chain `chain_id` if it is given, along with the deposits pending in each if
+    /// `check_pending` is set.
+    fn forwarding_accounts(
+        &mut self,
+        chain_id: Option<String>,
+        check_pending: bool,
+    ) -> Pin<
+        Box<
+            dyn Future<
+                    Output = Result<
+                        Vec<(
+                            ForwardingAccount,
+                            Vec<pb::forwarding_accounts_response::Deposit>,
+                        )>,
+                    >,
+                > + Send
+                + 'static,
+        >,
+    >;
+
+    /// Schedules the transaction described by `plan_request` to be planned and submitted
+    /// at `start_height`, and then every `interval` blocks, returning the ID of the schedule.
+    ///
+    /// If `every_epoch` is set, the transaction is instead submitted at the start of every
+    /// epoch, beginning with the next one, and `start_height` must be 0.
+    fn create_schedule(
+        &mut self,
+        plan_request: pb::TransactionPlannerRequest,
+        start_height: u64,
+        interval: u64,
+        max_occurrences: u64,
+        every_epoch: bool,
+    ) -> Pin<Box<dyn Future<Output = Result<u64>> + Send + 'static>>;
+
+    /// Queries for the scheduled transactions and their past executions, restricted
+    /// to the given schedules if `schedule_ids` is non-empty.
+    fn schedules(
+        &mut self,
+        schedule_ids: Vec<u64>,
+    ) -> Pin<
+        Box<
+            dyn Future<
+                    Output = Result<
+                        Vec<(ScheduledTransaction, Vec<ScheduledTransactionExecution>)>,
+                    >,
+                > + Send
+                + 'static,
+        >,
+    >;
+
+    /// Pauses, resumes or cancels a scheduled transaction.
+    fn update_schedule_status(
+        &mut self,
+        schedule_id: u64,
+        status: ScheduleStatus,
+    ) -> Pin<Box<dyn Future<Output = Result<()>> + Send + 'static>>;
+
     /// Generates a full perspective for a selected transaction using a full viewing key
     fn transaction_info_by_hash(
         &mut self,
@@ -729,6 +839,283 @@ where
         .boxed()
     }
 
+    fn position_performance(
+        &mut self,
+        position_ids: Vec<position::Id>,
+    ) -> Pin<Box<dyn Future<Output = Result<Vec<PositionPerformance>>> + Send + 'static>> {
+        let mut self2 = self.clone();
+        async move {
+            let rsp = ViewServiceClient::position_performance(
+                &mut self2,
+                tonic::Request::new(pb::PositionPerformanceRequest {
+                    position_ids: position_ids.into_iter().map(Into::into).collect(),
+                }),
+            );
+
+            let pb_performances: Vec<_> = rsp.await?.into_inner().try_collect().await?;
+
+            pb_performances
+                .into_iter()
+                .map(|rsp| {
+                    rsp.performance
+                        .ok_or_else(|| {
+                            anyhow::anyhow!("empty PositionPerformanceResponse message")
+                        })?
+                        .try_into()
+                })
+                .collect()
+        }
+        .boxed()
+    }
+
+    fn staking_rewards(
+        &mut self,
+        identity_keys: Vec<IdentityKey>,
+    ) -> Pin<Box<dyn Future<Output = Result<Vec<StakingRewards>>> + Send + 'static>> {
+        let mut self2 = self.clone();
+        async move {
+            let rsp = ViewServiceClient::staking_rewards(
+                &mut self2,
+                tonic::Request::new(pb::StakingRewardsRequest {
+                    identity_keys: identity_keys.into_iter().map(Into::into).collect(),
+                }),
+            );
+
+            let pb_rewards: Vec<_> = rsp.await?.into_inner().try_collect().await?;
+
+            pb_rewards
+                .into_iter()
+                .map(|rsp| {
+                    rsp.rewards
+                        .ok_or_else(|| anyhow::anyhow!("empty StakingRewardsResponse message"))?
+                        .try_into()
+                })
+                .collect()
+        }
+        .boxed()
+    }
+
+    fn governance_feed(
+        &mut self,
+        inactive: bool,
+        actionable_only: bool,
+        mark_seen: bool,
+    ) -> Pin<Box<dyn Future<Output = Result<Vec<GovernanceFeedItem>>> + Send + 'static>> {
+        let mut self2 = self.clone();
+        async move {
+            let rsp = ViewServiceClient::governance_feed(
+                &mut self2,
+                tonic::Request::new(pb::GovernanceFeedRequest {
+                    inactive,
+                    actionable_only,
+                    mark_seen,
+                }),
+            );
+
+            let pb_items: Vec<_> = rsp.await?.into_inner().try_collect().await?;
+
+            pb_items
+                .into_iter()
+                .map(|rsp| {
+                    rsp.item
+                        .ok_or_else(|| anyhow::anyhow!("empty GovernanceFeedResponse message"))?
+                        .try_into()
+                })
+                .collect()
+        }
+        .boxed()
+    }
+
+    fn ics20_withdrawals(
+        &mut self,
+        transaction_id: Option<TransactionId>,
+        settled: bool,
+    ) -> Pin<Box<dyn Future<Output = Result<Vec<Ics20WithdrawalRecord>>> + Send + 'static>> {
+        let mut self2 = self.clone();
+        async move {
+            let rsp = ViewServiceClient::ics20_withdrawals(
+                &mut self2,
+                tonic::Request::new(pb::Ics20WithdrawalsRequest {
+                    transaction_id: transaction_id.map(Into::into),
+                    settled,
+                }),
+            );
+
+            let pb_withdrawals: Vec<_> = rsp.await?.into_inner().try_collect().await?;
+
+            pb_withdrawals
+                .into_iter()
+                .map(|rsp| {
+                    rsp.withdrawal
+                        .ok_or_else(|| anyhow::anyhow!("empty Ics20WithdrawalsResponse message"))?
+                        .try_into()
+                })
+                .collect()
+        }
+        .boxed()
+    }
+
+    fn add_forwarding_account(
+        &mut self,
+        chain_id: String,
+        node: Url,
+        channel: String,
+        address_index: AddressIndex,
+        registered: bool,
+    ) -> Pin<Box<dyn Future<Output = Result<ForwardingAccount>> + Send + 'static>> {
+        let mut self2 = self.clone();
+        async move {
+            let rsp = ViewServiceClient::add_forwarding_account(
+                &mut self2,
+                tonic::Request::new(pb::AddForwardingAccountRequest {
+                    chain_id,
+                    node: node.to_string(),
+                    channel,
+                    address_index: Some(address_index.into()),
+                    registered,
+                }),
+            );
+
+            rsp.await?
+                .into_inner()
+                .account
+                .ok_or_else(|| anyhow::anyhow!("empty AddForwardingAccountResponse message"))?
+                .try_into()
+        }
+        .boxed()
+    }
+
+    fn forwarding_accounts(
+        &mut self,
+        chain_id: Option<String>,
+        check_pending: bool,
+    ) -> Pin<
+        Box<
+            dyn Future<
+                    Output = Result<
+                        Vec<(
+                            ForwardingAccount,
+                            Vec<pb::forwarding_accounts_response::Deposit>,
+                        )>,
+                    >,
+                > + Send
+                + 'static,
+        >,
+    > {
+        let mut self2 = self.clone();
+        async move {
+            let rsp = ViewServiceClient::forwarding_accounts(
+                &mut self2,
+                tonic::Request::new(pb::ForwardingAccountsRequest {
+                    chain_id: chain_id.unwrap_or_default(),
+                    check_pending,
+                }),
+            );
+
+            let pb_accounts: Vec<_> = rsp.await?.into_inner().try_collect().await?;
+
+            pb_accounts
+                .into_iter()
+                .map(|rsp| {
+                    let account = rsp
+                        .account
+                        .ok_or_else(|| anyhow::anyhow!("empty ForwardingAccountsResponse message"))?
+                        .try_into()?;
+                    Ok((account, rsp.pending_deposits))
+                })
+                .collect()
+        }
+        .boxed()
+    }
+
+    fn create_schedule(
+        &mut self,
+        plan_request: pb::TransactionPlannerRequest,
+        start_height: u64,
+        interval: u64,
+        max_occurrences: u64,
+        every_epoch: bool,
+    ) -> Pin<Box<dyn Future<Output = Result<u64>> + Send + 'static>> {
+        let mut self2 = self.clone();
+        async move {
+            let rsp = ViewServiceClient::create_schedule(
+                &mut self2,
+                tonic::Request::new(pb::CreateScheduleRequest {
+                    plan_request: Some(plan_request),
+                    start_height,
+                    interval,
+                    max_occurrences,
+                    every_epoch,
+                }),
+            );
+
+            Ok(rsp.await?.into_inner().schedule_id)
+        }
+        .boxed()
+    }
+
+    fn schedules(
+        &mut self,
+        schedule_ids: Vec<u64>,
+    ) -> Pin<
+        Box<
+            dyn Future<
+                    Output = Result<
+                        Vec<(ScheduledTransaction, Vec<ScheduledTransactionExecution>)>,
+                    >,
+                > + Send
+                + 'static,
+        >,
+    > {
+        let mut self2 = self.clone();
+        async move {
+            let rsp = ViewServiceClient::schedules(
+                &mut self2,
+                tonic::Request::new(pb::SchedulesRequest { schedule_ids }),
+            );
+
+            let pb_schedules: Vec<_> = rsp.await?.into_inner().try_collect().await?;
+
+            pb_schedules
+                .into_iter()
+                .map(|rsp| {
+                    let schedule = rsp
+                        .schedule
+                        .ok_or_else(|| anyhow::anyhow!("empty SchedulesResponse message"))?
+                        .try_into()?;
+                    let executions = rsp
+                        .executions
+                        .into_iter()
+                        .map(TryInto::try_into)
+                        .collect::<Result<Vec<_>>>()?;
+                    Ok((schedule, executions))
+                })
+                .collect()
+        }
+        .boxed()
+    }
+
+    fn update_schedule_status(
+        &mut self,
+        schedule_id: u64,
+        status: ScheduleStatus,
+    ) -> Pin<Box<dyn Future<Output = Result<()>> + Send + 'static>> {
+        let mut self2 = self.clone();
+        async move {
+            let rsp = ViewServiceClient::update_schedule_status(
+                &mut self2,
+                tonic::Request::new(pb::UpdateScheduleStatusRequest {
+                    schedule_id,
+                    status: pb::ScheduleStatus::from(status) as i32,
+                }),
+            );
+
+            rsp.await?;
+            Ok(())
+        }
+        .boxed()
+    }
+
     fn transaction_info_by_hash(
         &mut self,
         id: TransactionId,
@@ -0,0 +1,595 @@
+use std::time::Duration;
+
+use anyhow::{anyhow, Context};
+use async_trait::async_trait;
+use penumbra_keys::{keys::AddressIndex, Address};
+use penumbra_proto::{
+    cosmos::{
+        bank::v1beta1::{query_client::QueryClient as BankQueryClient, QueryAllBalancesRequest},
+        base::abci::v1beta1::TxResponse,
+        tx::v1beta1::{
+            mode_info::{Single, Sum},
+            service_client::ServiceClient as CosmosServiceClient,
+            AuthInfo as CosmosAuthInfo, BroadcastTxRequest as CosmosBroadcastTxRequest,
+            Fee as CosmosFee, ModeInfo, SignerInfo as CosmosSignerInfo, Tx as CosmosTx,
+            TxBody as CosmosTxBody,
+        },
+    },
+    noble::forwarding::v1::{
+        query_client::QueryClient as ForwardingQueryClient, ForwardingPubKey, MsgRegisterAccount,
+        QueryAddress,
+    },
+    view::v1::{self as pb, forwarding_accounts_response::Deposit},
+    DomainType, Message, Name as _,
+};
+use serde::{Deserialize, Serialize};
+use tonic::transport::{Channel, ClientTlsConfig};
+use url::Url;
+
+use crate::Storage;
+
+/// How often to check the unregistered forwarding accounts for deposits.
+const POLL_INTERVAL: Duration = Duration::from_secs(30);
+
+/// A forwarding account on a counterparty chain, which forwards its deposits over IBC to one of
+/// the wallet's addresses once it has been registered.
+///
+/// Forwarding accounts follow the scheme of Noble's forwarding module: the account's address is
+/// derived from the counterparty's channel to Penumbra and the address receiving the deposits, so
+/// deposits can be made before the account is registered.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(try_from = "pb::ForwardingAccount", into = "pb::ForwardingAccount")]
+pub struct ForwardingAccount {
+    pub chain_id: String,
+    /// The gRPC endpoint of a counterparty chain node.
+    pub node: Url,
+    /// The counterparty chain's IBC channel to Penumbra.
+    pub channel: String,
+    pub address_index: AddressIndex,
+    pub forwarding_address: String,
+    pub registered: bool,
+}
+
+impl DomainType for ForwardingAccount {
+    type Proto = pb::ForwardingAccount;
+}
+
+impl From<ForwardingAccount> for pb::ForwardingAccount {
+    fn from(value: ForwardingAccount) -> Self {
+        Self {
+            chain_id: value.chain_id,
+            node: value.node.to_string(),
+            channel: value.channel,
+            address_index: Some(value.address_index.into()),
+            forwarding_address: value.forwarding_address,
+            registered: value.registered,
+        }
+    }
+}
+
+impl TryFrom<pb::ForwardingAccount> for ForwardingAccount {
+    type Error = anyhow::Error;
+
+    fn try_from(value: pb::ForwardingAccount) -> Result<Self, Self::Error> {
+        Ok(Self {
+            chain_id: value.chain_id,
+            node: value.node.parse().context("invalid node url")?,
+            channel: value.channel,
+            address_index: value
+                .address_index
+                .ok_or_else(|| anyhow!("missing address index"))?
+                .try_into()?,
+            forwarding_address: value.forwarding_address,
+            registered: value.registered,
+        })
+    }
+}
+
+async fn connect(node: &Url) -> anyhow::Result<Channel> {
+    let endpoint = Channel::from_shared(node.to_string())?;
+    let endpoint = if node.scheme() == "https" {
+        endpoint.tls_config(ClientTlsConfig::new())?
+    } else {
+        endpoint
+    };
+    Ok(endpoint.connect().await?)
+}
+
+/// Submits a transaction registering the forwarding account for `address` on `channel` to the
+/// counterparty chain `node`, returning the node's response.
+///
+/// The registration transaction is unsigned, so it can be submitted by anyone.
+pub async fn register_forwarding_account(
+    node: &Url,
+    channel: &str,
+    address: &Address,
+) -> anyhow::Result<TxResponse> {
+    let forwarding_address = address.noble_forwarding_address(channel);
+
+    let tx = CosmosTx {
+        body: Some(CosmosTxBody {
+            messages: vec![pbjson_types::Any {
+                type_url: MsgRegisterAccount::type_url(),
+                value: MsgRegisterAccount {
+                    signer: forwarding_address.to_string(),
+                    recipient: address.to_string(),
+                    channel: channel.to_string(),
+                }
+                .encode_to_vec()
+                .into(),
+            }],
+            memo: "".to_string(),
+            timeout_height: 0,
+            extension_options: vec![],
+            non_critical_extension_options: vec![],
+        }),
+        auth_info: Some(CosmosAuthInfo {
+            signer_infos: vec![CosmosSignerInfo {
+                public_key: Some(pbjson_types::Any {
+                    type_url: ForwardingPubKey::type_url(),
+                    value: ForwardingPubKey {
+                        key: forwarding_address.bytes(),
+                    }
+                    .encode_to_vec()
+                    .into(),
+                }),
+                mode_info: Some(ModeInfo {
+                    // SIGN_MODE_DIRECT
+                    sum: Some(Sum::Single(Single { mode: 1 })),
+                }),
+                sequence: 0,
+            }],
+            fee: Some(CosmosFee {
+                amount: vec![],
+                gas_limit: 200000u64,
+                payer: "".to_string(),
+                granter: "".to_string(),
+            }),
+            tip: None,
+        }),
+        signatures: vec![vec![]],
+    };
+
+    let mut client = CosmosServiceClient::new(connect(node).await?);
+    client
+        .broadcast_tx(CosmosBroadcastTxRequest {
+            tx_bytes: tx.encode_to_vec().into(),
+            // sync
+            mode: 2,
+        })
+        .await?
+        .into_inner()
+        .tx_response
+        .ok_or_else(|| anyhow!("missing tx response"))
+}
+
+/// Checks whether the forwarding account for `address` on `channel` is registered on the
+/// counterparty chain `node`.
+async fn forwarding_account_registered(
+    node: &Url,
+    channel: &str,
+    address: &Address,
+) -> anyhow::Result<bool> {
+    let mut client = ForwardingQueryClient::new(connect(node).await?);
+    let response = client
+        .address(QueryAddress {
+            channel: channel.to_string(),
+            recipient: address.to_string(),
+        })
+        .await?
+        .into_inner();
+    Ok(response.exists)
+}
+
+/// Queries the counterparty chain for the deposits in a forwarding account that have yet to be
+/// forwarded.
+pub(crate) async fn pending_deposits(account: &ForwardingAccount) -> anyhow::Result<Vec<Deposit>> {
+    let mut client = BankQueryClient::new(connect(&account.node).await?);
+    let response = client
+        .all_balances(QueryAllBalancesRequest {
+            address: account.forwarding_address.clone(),
+            pagination: None,
+            resolve_denom: false,
+        })
+        .await?
+        .into_inner();
+    Ok(response
+        .balances
+        .into_iter()
+        .map(|coin| Deposit {
+            denom: coin.denom,
+            amount: coin.amount,
+        })
+        .collect())
+}
+
+/// The counterparty chains of the wallet's forwarding accounts, as seen by the registrar.
+#[async_trait]
+pub(crate) trait Counterparty: Send + Sync {
+    /// Checks whether the forwarding account for `address` is registered.
+    async fn registered(
+        &self,
+        account: &ForwardingAccount,
+        address: &Address,
+    ) -> anyhow::Result<bool>;
+
+    /// Returns the deposits in the forwarding account that have yet to be forwarded.
+    async fn pending_deposits(&self, account: &ForwardingAccount) -> anyhow::Result<Vec<Deposit>>;
+
+    /// Submits a transaction registering the forwarding account for `address`.
+    async fn register(
+        &self,
+        account: &ForwardingAccount,
+        address: &Address,
+    ) -> anyhow::Result<TxResponse>;
+}
+
+/// Reaches each counterparty chain through the node recorded with the forwarding account.
+pub(crate) struct CounterpartyNodes;
+
+#[async_trait]
+impl Counterparty for CounterpartyNodes {
+    async fn registered(
+        &self,
+        account: &ForwardingAccount,
+        address: &Address,
+    ) -> anyhow::Result<bool> {
+        forwarding_account_registered(&account.node, &account.channel, address).await
+    }
+
+    async fn pending_deposits(&self, account: &ForwardingAccount) -> anyhow::Result<Vec<Deposit>> {
+        pending_deposits(account).await
+    }
+
+    async fn register(
+        &self,
+        account: &ForwardingAccount,
+        address: &Address,
+    ) -> anyhow::Result<TxResponse> {
+        register_forwarding_account(&account.node, &account.channel, address).await
+    }
+}
+
+/// Registers the forwarding accounts in the wallet's registry once deposits arrive in them.
+pub(crate) struct ForwardingRegistrar<C = CounterpartyNodes> {
+    storage: Storage,
+    counterparty: C,
+}
+
+impl ForwardingRegistrar {
+    pub fn new(storage: Storage) -> Self {
+        Self {
+            storage,
+            counterparty: CounterpartyNodes,
+        }
+    }
+}
+
+impl<C: Counterparty> ForwardingRegistrar<C> {
+    /// Checks on the unregistered forwarding accounts every [`POLL_INTERVAL`], until the task is
+    /// dropped.
+    ///
+    /// Errors are logged, and the accounts are checked again at the next interval.
+    pub async fn run(self) {
+        let mut interval = tokio::time::interval(POLL_INTERVAL);
+        interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+
+        loop {
+            interval.tick().await;
+
+            if let Err(error) = self.check_all().await {
+                tracing::warn!(?error, "could not check forwarding accounts");
+            }
+        }
+    }
+
+    /// Checks each unregistered forwarding account in the registry, logging the accounts which
+    /// could not be checked.
+    async fn check_all(&self) -> anyhow::Result<()> {
+        let fvk = self.storage.full_viewing_key().await?;
+        for account in self.storage.forwarding_accounts(None).await? {
+            if account.registered {
+                continue;
+            }
+            let (address, _dtk) = fvk.payment_address(account.address_index);
+            if let Err(error) = self.check(&account, &address).await {
+                tracing::warn!(
+                    chain_id = account.chain_id,
+                    forwarding_address = account.forwarding_address,
+                    ?error,
+                    "could not check forwarding account"
+                );
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Registers `account` if it holds deposits, or records that it is registered if it already
+    /// is.
+    ///
+    /// The account is only marked as registered once the counterparty chain reports it as such,
+    /// so a registration transaction that fails to execute is retried on the next check.
+    async fn check(&self, account: &ForwardingAccount, address: &Address) -> anyhow::Result<()> {
+        if self.counterparty.registered(account, address).await? {
+            return self
+                .storage
+                .record_forwarding_account_registered(&account.chain_id, account.address_index)
+                .await;
+        }
+
+        if self
+            .counterparty
+            .pending_deposits(account)
+            .await?
+            .is_empty()
+        {
+            return Ok(());
+        }
+
+        tracing::info!(
+            chain_id = account.chain_id,
+            forwarding_address = account.forwarding_address,
+            "registering forwarding account to forward its deposits"
+        );
+        let response = self.counterparty.register(account, address).await?;
+        if response.code != 0 {
+            anyhow::bail!(
+                "registration transaction failed with code {}: {}",
+                response.code,
+                response.raw_log
+            );
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Mutex;
+
+    use camino::{Utf8Path, Utf8PathBuf};
+    use penumbra_app::params::AppParameters;
+    use penumbra_keys::test_keys::FULL_VIEWING_KEY;
+
+    use super::*;
+
+    fn account(chain_id: &str, index: u32) -> ForwardingAccount {
+        let address_index = AddressIndex::from(index);
+        let channel = "channel-21".to_string();
+        let (address, _dtk) = FULL_VIEWING_KEY.payment_address(address_index);
+        ForwardingAccount {
+            chain_id: chain_id.to_string(),
+            node: "http://127.0.0.1:9090".parse().expect("url is valid"),
+            forwarding_address: address.noble_forwarding_address(&channel).to_string(),
+            channel,
+            address_index,
+            registered: false,
+        }
+    }
+
+    async fn initialize(path: Option<&Utf8Path>) -> anyhow::Result<Storage> {
+        Storage::initialize(path, FULL_VIEWING_KEY.clone(), AppParameters::default()).await
+    }
+
+    /// A counterparty whose forwarding accounts hold `deposits`, and are registered once
+    /// `registered` is set.
+    #[derive(Default)]
+    struct MockCounterparty {
+        registered: bool,
+        deposits: Vec<Deposit>,
+        /// The code of the registration transactions' responses.
+        code: u32,
+        /// A chain whose node can't be reached.
+        unreachable: Option<String>,
+        /// The forwarding addresses of the accounts checked on, and of those registered.
+        checked: Mutex<Vec<String>>,
+        registrations: Mutex<Vec<String>>,
+    }
+
+    impl MockCounterparty {
+        fn checked(&self) -> Vec<String> {
+            self.checked.lock().expect("mutex is not poisoned").clone()
+        }
+
+        fn registrations(&self) -> Vec<String> {
+            self.registrations
+                .lock()
+                .expect("mutex is not poisoned")
+                .clone()
+        }
+    }
+
+    #[async_trait]
+    impl Counterparty for MockCounterparty {
+        async fn registered(
+            &self,
+            account: &ForwardingAccount,
+            _address: &Address,
+        ) -> anyhow::Result<bool> {
+            if self.unreachable.as_ref() == Some(&account.chain_id) {
+                anyhow::bail!("could not connect to {}", account.node);
+            }
+            self.checked
+                .lock()
+                .expect("mutex is not poisoned")
+                .push(account.forwarding_address.clone());
+            Ok(self.registered)
+        }
+
+        async fn pending_deposits(
+            &self,
+            _account: &ForwardingAccount,
+        ) -> anyhow::Result<Vec<Deposit>> {
+            Ok(self.deposits.clone())
+        }
+
+        async fn register(
+            &self,
+            account: &ForwardingAccount,
+            _address: &Address,
+        ) -> anyhow::Result<TxResponse> {
+            self.registrations
+                .lock()
+                .expect("mutex is not poisoned")
+                .push(account.forwarding_address.clone());
+            Ok(TxResponse {
+                code: self.code,
+                ..Default::default()
+            })
+        }
+    }
+
+    fn deposit() -> Deposit {
+        Deposit {
+            denom: "uusdc".to_string(),
+            amount: "1000000".to_string(),
+        }
+    }
+
+    #[tokio::test]
+    async fn forwarding_accounts_are_recorded() -> anyhow::Result<()> {
+        let storage = initialize(None).await?;
+
+        storage
+            .record_forwarding_account(account("noble-1", 0))
+            .await?;
+        storage
+            .record_forwarding_account(account("grand-1", 1))
+            .await?;
+        // Adding an account again on the same chain for the same address replaces it.
+        let mut replacement = account("noble-1", 0);
+        replacement.channel = "channel-4".to_string();
+        storage.record_forwarding_account(replacement).await?;
+
+        let accounts = storage.forwarding_accounts(None).await?;
+        assert_eq!(
+            accounts
+                .iter()
+                .map(|account| (account.chain_id.as_str(), account.channel.as_str()))
+                .collect::<Vec<_>>(),
+            [("grand-1", "channel-21"), ("noble-1", "channel-4")]
+        );
+
+        storage
+            .record_forwarding_account_registered("noble-1", AddressIndex::from(0))
+            .await?;
+        let accounts = storage
+            .forwarding_accounts(Some("noble-1".to_string()))
+            .await?;
+        assert_eq!(accounts.len(), 1);
+        assert!(accounts[0].registered);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn forwarding_accounts_survive_a_reset() -> anyhow::Result<()> {
+        let dir = tempfile::tempdir()?;
+        let path = Utf8PathBuf::try_from(dir.path().join("pcli-view.sqlite"))?;
+
+        let storage = initialize(Some(&path)).await?;
+        storage
+            .record_forwarding_account(account("noble-1", 0))
+            .await?;
+        drop(storage);
+
+        // Resetting the view database leaves the registry alongside it in place.
+        std::fs::remove_file(&path)?;
+        assert!(Storage::registry_path(&path).is_file());
+
+        let storage = initialize(Some(&path)).await?;
+        let accounts = storage.forwarding_accounts(None).await?;
+        assert_eq!(accounts.len(), 1);
+        assert_eq!(accounts[0].chain_id, "noble-1");
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn accounts_are_registered_once_they_hold_deposits() -> anyhow::Result<()> {
+        let storage = initialize(None).await?;
+        let account = account("noble-1", 0);
+        storage.record_forwarding_account(account.clone()).await?;
+
+        // An empty account is left unregistered.
+        let registrar = ForwardingRegistrar {
+            storage: storage.clone(),
+            counterparty: MockCounterparty::default(),
+        };
+        registrar.check_all().await?;
+        assert!(registrar.counterparty.registrations().is_empty());
+
+        // Once it holds deposits, it's registered, but only recorded as such once the
+        // counterparty reports it.
+        let registrar = ForwardingRegistrar {
+            storage: storage.clone(),
+            counterparty: MockCounterparty {
+                deposits: vec![deposit()],
+                ..Default::default()
+            },
+        };
+        registrar.check_all().await?;
+        assert_eq!(
+            registrar.counterparty.registrations(),
+            [account.forwarding_address]
+        );
+        assert!(!storage.forwarding_accounts(None).await?[0].registered);
+
+        let registrar = ForwardingRegistrar {
+            storage: storage.clone(),
+            counterparty: MockCounterparty {
+                registered: true,
+                deposits: vec![deposit()],
+                ..Default::default()
+            },
+        };
+        registrar.check_all().await?;
+        assert!(registrar.counterparty.registrations().is_empty());
+        assert!(storage.forwarding_accounts(None).await?[0].registered);
+
+        // Registered accounts aren't checked on again.
+        registrar.check_all().await?;
+        assert_eq!(registrar.counterparty.checked().len(), 1);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn failed_checks_do_not_stop_the_others() -> anyhow::Result<()> {
+        let storage = initialize(None).await?;
+        let unreachable = account("down-1", 0);
+        let reachable = account("noble-1", 1);
+        storage.record_forwarding_account(unreachable).await?;
+        storage.record_forwarding_account(reachable.clone()).await?;
+
+        let registrar = ForwardingRegistrar {
+            storage: storage.clone(),
+            counterparty: MockCounterparty {
+                deposits: vec![deposit()],
+                unreachable: Some("down-1".to_string()),
+                ..Default::default()
+            },
+        };
+        registrar.check_all().await?;
+        assert_eq!(
+            registrar.counterparty.registrations(),
+            [reachable.forwarding_address.clone()]
+        );
+
+        // A registration transaction which fails to execute is reported.
+        let registrar = ForwardingRegistrar {
+            storage,
+            counterparty: MockCounterparty {
+                deposits: vec![deposit()],
+                code: 5,
+                ..Default::default()
+            },
+        };
+        let (address, _dtk) = FULL_VIEWING_KEY.payment_address(reachable.address_index);
+        assert!(registrar.check(&reachable, &address).await.is_err());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,374 @@
+use std::collections::BTreeMap;
+
+use anyhow::anyhow;
+use penumbra_governance::{proposal_state::State as ProposalState, Proposal, Vote};
+use penumbra_num::Amount;
+use penumbra_proto::{view::v1 as pb, DomainType};
+use penumbra_sct::Nullifier;
+use serde::{Deserialize, Serialize};
+
+/// A governance proposal, as of the last time it was updated during sync.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct ProposalRecord {
+    pub proposal: Proposal,
+    pub state: ProposalState,
+    pub start_block_height: u64,
+    pub end_block_height: u64,
+    pub deposit_amount: Amount,
+}
+
+impl ProposalRecord {
+    /// Whether the proposal's voting has yet to conclude, so that its state can still change
+    /// without any action by the wallet.
+    pub fn is_unfinished(&self) -> bool {
+        self.state.is_voting() || self.state.is_withdrawn()
+    }
+}
+
+/// The votes cast by the wallet on a proposal, as recorded during sync.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub(crate) struct WalletVotes {
+    /// The votes cast as a delegator, by the nullifier of the note voted with.
+    pub delegator_votes: BTreeMap<Nullifier, Vote>,
+    /// The vote cast as a proxy, if any.
+    pub proxy_vote: Option<Vote>,
+}
+
+/// A governance proposal, from the perspective of the wallet.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(try_from = "pb::GovernanceFeedItem", into = "pb::GovernanceFeedItem")]
+pub struct GovernanceFeedItem {
+    pub proposal: Proposal,
+    pub state: ProposalState,
+    pub start_block_height: u64,
+    pub end_block_height: u64,
+    /// The number of the wallet's notes that can still vote on the proposal.
+    pub votable_notes: u64,
+    /// The voting power of those notes, in the staking token.
+    pub votable_power: Amount,
+    /// The votes cast by the wallet as a delegator.
+    pub delegator_votes: Vec<Vote>,
+    /// The vote cast by the wallet as a proxy, if any.
+    pub proxy_vote: Option<Vote>,
+    /// The deposit the wallet can claim, if the proposal is finished and the wallet holds its
+    /// deposit NFT.
+    pub claimable_deposit: Option<Amount>,
+    /// Whether the proposal's state has changed since the feed was last marked as seen.
+    pub state_changed: bool,
+}
+
+impl GovernanceFeedItem {
+    /// Describes a proposal from the perspective of the wallet, given:
+    ///
+    /// - the wallet's votes on the proposal;
+    /// - the nullifiers of the wallet's notes that were eligible to vote on the proposal, along
+    ///   with their voting power at its start;
+    /// - whether the wallet holds the proposal's deposit NFT;
+    /// - the encoded state of the proposal when the feed was last marked as seen, if ever.
+    pub(crate) fn new(
+        record: ProposalRecord,
+        votes: WalletVotes,
+        voting_notes: &[(Nullifier, Amount)],
+        holds_deposit: bool,
+        seen_state: Option<&[u8]>,
+    ) -> Self {
+        let mut votable_notes = 0;
+        let mut votable_power = Amount::zero();
+        if record.state.is_voting() {
+            for (nullifier, power) in voting_notes {
+                if !votes.delegator_votes.contains_key(nullifier) {
+                    votable_notes += 1;
+                    votable_power += *power;
+                }
+            }
+        }
+
+        let mut delegator_votes = Vec::new();
+        for vote in votes.delegator_votes.into_values() {
+            if !delegator_votes.contains(&vote) {
+                delegator_votes.push(vote);
+            }
+        }
+
+        let claimable_deposit =
+            (record.state.is_finished() && holds_deposit).then_some(record.deposit_amount);
+        let state_changed = seen_state != Some(&record.state.encode_to_vec()[..]);
+
+        Self {
+            proposal: record.proposal,
+            state: record.state,
+            start_block_height: record.start_block_height,
+            end_block_height: record.end_block_height,
+            votable_notes,
+            votable_power,
+            delegator_votes,
+            proxy_vote: votes.proxy_vote,
+            claimable_deposit,
+            state_changed,
+        }
+    }
+
+    /// Whether the proposal belongs in a feed which includes proposals that are no longer voting
+    /// only if `inactive` is set, and only actionable proposals if `actionable_only` is set.
+    ///
+    /// Finished proposals with a claimable deposit are always included.
+    pub fn is_listed(&self, inactive: bool, actionable_only: bool) -> bool {
+        let active = self.state.is_voting() || self.claimable_deposit.is_some();
+        (active || inactive) && (!actionable_only || self.is_actionable())
+    }
+
+    /// Whether the wallet can still vote on the proposal as a delegator.
+    pub fn can_vote(&self) -> bool {
+        self.votable_notes > 0
+    }
+
+    /// Whether the wallet can act on the proposal, by voting or by claiming its deposit.
+    pub fn is_actionable(&self) -> bool {
+        self.can_vote() || self.claimable_deposit.is_some()
+    }
+}
+
+impl DomainType for GovernanceFeedItem {
+    type Proto = pb::GovernanceFeedItem;
+}
+
+impl From<GovernanceFeedItem> for pb::GovernanceFeedItem {
+    fn from(item: GovernanceFeedItem) -> Self {
+        Self {
+            proposal: Some(item.proposal.into()),
+            state: Some(item.state.into()),
+            start_block_height: item.start_block_height,
+            end_block_height: item.end_block_height,
+            votable_notes: item.votable_notes,
+            votable_power: Some(item.votable_power.into()),
+            delegator_votes: item.delegator_votes.into_iter().map(Into::into).collect(),
+            proxy_vote: item.proxy_vote.map(Into::into),
+            claimable_deposit: item.claimable_deposit.map(Into::into),
+            state_changed: item.state_changed,
+        }
+    }
+}
+
+impl TryFrom<pb::GovernanceFeedItem> for GovernanceFeedItem {
+    type Error = anyhow::Error;
+
+    fn try_from(item: pb::GovernanceFeedItem) -> Result<Self, Self::Error> {
+        Ok(Self {
+            proposal: item
+                .proposal
+                .ok_or_else(|| anyhow!("missing proposal"))?
+                .try_into()?,
+            state: item
+                .state
+                .ok_or_else(|| anyhow!("missing proposal state"))?
+                .try_into()?,
+            start_block_height: item.start_block_height,
+            end_block_height: item.end_block_height,
+            votable_notes: item.votable_notes,
+            votable_power: item.votable_power.unwrap_or_default().try_into()?,
+            delegator_votes: item
+                .delegator_votes
+                .into_iter()
+                .map(TryInto::try_into)
+                .collect::<Result<_, _>>()?,
+            proxy_vote: item.proxy_vote.map(TryInto::try_into).transpose()?,
+            claimable_deposit: item.claimable_deposit.map(TryInto::try_into).transpose()?,
+            state_changed: item.state_changed,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use decaf377::Fq;
+    use penumbra_app::params::AppParameters;
+    use penumbra_governance::{
+        proposal_state::{Outcome, Withdrawn},
+        ProposalPayload,
+    };
+    use penumbra_stake::{rate::RateData, IdentityKey};
+
+    use super::*;
+    use crate::Storage;
+
+    fn record(id: u64, state: ProposalState) -> ProposalRecord {
+        ProposalRecord {
+            proposal: Proposal {
+                id,
+                title: format!("proposal {id}"),
+                description: String::new(),
+                payload: ProposalPayload::Signaling { commit: None },
+            },
+            state,
+            start_block_height: 10,
+            end_block_height: 20,
+            deposit_amount: 1000u64.into(),
+        }
+    }
+
+    fn finished() -> ProposalState {
+        ProposalState::Finished {
+            outcome: Outcome::Failed {
+                withdrawn: Withdrawn::No,
+            },
+        }
+    }
+
+    fn nullifier(n: u64) -> Nullifier {
+        Nullifier(Fq::from(n))
+    }
+
+    #[test]
+    fn votable_notes_exclude_those_which_voted() {
+        let votes = WalletVotes {
+            delegator_votes: [(nullifier(1), Vote::Yes), (nullifier(2), Vote::Yes)]
+                .into_iter()
+                .collect(),
+            proxy_vote: Some(Vote::No),
+        };
+        let voting_notes = [
+            (nullifier(1), 5u64.into()),
+            (nullifier(2), 7u64.into()),
+            (nullifier(3), 11u64.into()),
+        ];
+
+        let item = GovernanceFeedItem::new(
+            record(0, ProposalState::Voting),
+            votes,
+            &voting_notes,
+            false,
+            None,
+        );
+        assert_eq!(item.votable_notes, 1);
+        assert_eq!(item.votable_power, Amount::from(11u64));
+        assert_eq!(item.delegator_votes, vec![Vote::Yes]);
+        assert_eq!(item.proxy_vote, Some(Vote::No));
+        assert!(item.is_actionable());
+    }
+
+    #[test]
+    fn notes_cannot_vote_once_voting_concludes() {
+        let item = GovernanceFeedItem::new(
+            record(0, finished()),
+            WalletVotes::default(),
+            &[(nullifier(1), 5u64.into())],
+            false,
+            None,
+        );
+        assert!(!item.can_vote());
+        assert!(!item.is_listed(false, false));
+        assert!(item.is_listed(true, false));
+        assert!(!item.is_listed(true, true));
+    }
+
+    #[test]
+    fn deposits_are_claimable_once_finished() {
+        let voting = GovernanceFeedItem::new(
+            record(0, ProposalState::Voting),
+            WalletVotes::default(),
+            &[],
+            true,
+            None,
+        );
+        assert_eq!(voting.claimable_deposit, None);
+
+        let finished = GovernanceFeedItem::new(
+            record(0, finished()),
+            WalletVotes::default(),
+            &[],
+            true,
+            None,
+        );
+        assert_eq!(finished.claimable_deposit, Some(Amount::from(1000u64)));
+        assert!(finished.is_listed(false, true));
+    }
+
+    #[test]
+    fn state_changes_are_flagged_against_the_seen_state() {
+        let seen = ProposalState::Voting.encode_to_vec();
+
+        let unchanged = GovernanceFeedItem::new(
+            record(0, ProposalState::Voting),
+            WalletVotes::default(),
+            &[],
+            false,
+            Some(&seen),
+        );
+        assert!(!unchanged.state_changed);
+
+        let changed = GovernanceFeedItem::new(
+            record(0, finished()),
+            WalletVotes::default(),
+            &[],
+            false,
+            Some(&seen),
+        );
+        assert!(changed.state_changed);
+
+        let new = GovernanceFeedItem::new(
+            record(0, ProposalState::Voting),
+            WalletVotes::default(),
+            &[],
+            false,
+            None,
+        );
+        assert!(new.state_changed);
+    }
+
+    #[tokio::test]
+    async fn proposals_and_votes_are_recorded() -> anyhow::Result<()> {
+        let storage = Storage::initialize(
+            None::<&str>,
+            penumbra_keys::test_keys::FULL_VIEWING_KEY.clone(),
+            AppParameters::default(),
+        )
+        .await?;
+
+        let identity_key: IdentityKey =
+            penumbra_proto::core::keys::v1::IdentityKey { ik: vec![1; 32] }.try_into()?;
+        let rate_data = RateData {
+            identity_key,
+            validator_reward_rate: 0u64.into(),
+            validator_exchange_rate: 100_000_000u64.into(),
+        };
+
+        storage
+            .record_governance_proposal(
+                record(0, ProposalState::Voting),
+                Some(vec![rate_data.clone()]),
+            )
+            .await?;
+        // Updating the state of the proposal keeps its rate data.
+        storage
+            .record_governance_proposal(record(0, finished()), None)
+            .await?;
+
+        let proposals = storage.governance_proposals().await?;
+        assert_eq!(proposals.len(), 1);
+        assert_eq!(proposals[&0], record(0, finished()));
+        assert_eq!(
+            storage.governance_proposal_rate_data(0).await?,
+            [(identity_key, rate_data)].into_iter().collect()
+        );
+
+        storage
+            .record_governance_vote(0, Some(nullifier(1)), Vote::Yes)
+            .await?;
+        // A vote seen again when resyncing is only recorded once.
+        storage
+            .record_governance_vote(0, Some(nullifier(1)), Vote::Yes)
+            .await?;
+        storage.record_governance_vote(0, None, Vote::No).await?;
+
+        let votes = storage.governance_votes().await?;
+        assert_eq!(
+            votes[&0],
+            WalletVotes {
+                delegator_votes: [(nullifier(1), Vote::Yes)].into_iter().collect(),
+                proxy_vote: Some(Vote::No),
+            }
+        );
+
+        Ok(())
+    }
+}
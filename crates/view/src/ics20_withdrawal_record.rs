@@ -0,0 +1,509 @@
+use std::{fmt, str::FromStr};
+
+use anyhow::anyhow;
+use ibc_proto::ibc::core::channel::v1::PacketState;
+use penumbra_proto::{view::v1 as pb, DomainType};
+use penumbra_shielded_pool::Ics20Withdrawal;
+use penumbra_tct as tct;
+use penumbra_transaction::txhash::TransactionId;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+use crate::storage::PendingIcs20Withdrawal;
+
+/// The status of an [`Ics20WithdrawalRecord`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(
+    try_from = "pb::Ics20WithdrawalStatus",
+    into = "pb::Ics20WithdrawalStatus"
+)]
+pub enum Ics20WithdrawalStatus {
+    /// The withdrawal is in flight, awaiting an acknowledgement or a timeout.
+    Pending,
+    /// The counterparty acknowledged receiving the withdrawal.
+    Acknowledged,
+    /// The withdrawal's timeout has passed, and its funds will be refunded once a relayer
+    /// delivers the timeout.
+    ///
+    /// This status is never stored: it is derived from a pending withdrawal's timeout time.
+    TimedOut,
+    /// The withdrawal timed out or failed on the counterparty, and its funds were refunded.
+    Refunded,
+}
+
+impl Ics20WithdrawalStatus {
+    /// Whether the withdrawal's funds have either arrived on the counterparty or been refunded.
+    pub fn is_settled(&self) -> bool {
+        matches!(
+            self,
+            Ics20WithdrawalStatus::Acknowledged | Ics20WithdrawalStatus::Refunded
+        )
+    }
+}
+
+impl fmt::Display for Ics20WithdrawalStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Ics20WithdrawalStatus::Pending => write!(f, "pending"),
+            Ics20WithdrawalStatus::Acknowledged => write!(f, "acknowledged"),
+            Ics20WithdrawalStatus::TimedOut => write!(f, "timed out"),
+            Ics20WithdrawalStatus::Refunded => write!(f, "refunded"),
+        }
+    }
+}
+
+impl FromStr for Ics20WithdrawalStatus {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> anyhow::Result<Self> {
+        match s {
+            "pending" => Ok(Ics20WithdrawalStatus::Pending),
+            "acknowledged" => Ok(Ics20WithdrawalStatus::Acknowledged),
+            "timed out" => Ok(Ics20WithdrawalStatus::TimedOut),
+            "refunded" => Ok(Ics20WithdrawalStatus::Refunded),
+            _ => Err(anyhow!("unknown ics20 withdrawal status {}", s)),
+        }
+    }
+}
+
+impl From<Ics20WithdrawalStatus> for pb::Ics20WithdrawalStatus {
+    fn from(value: Ics20WithdrawalStatus) -> Self {
+        match value {
+            Ics20WithdrawalStatus::Pending => pb::Ics20WithdrawalStatus::Pending,
+            Ics20WithdrawalStatus::Acknowledged => pb::Ics20WithdrawalStatus::Acknowledged,
+            Ics20WithdrawalStatus::TimedOut => pb::Ics20WithdrawalStatus::TimedOut,
+            Ics20WithdrawalStatus::Refunded => pb::Ics20WithdrawalStatus::Refunded,
+        }
+    }
+}
+
+impl TryFrom<pb::Ics20WithdrawalStatus> for Ics20WithdrawalStatus {
+    type Error = anyhow::Error;
+
+    fn try_from(value: pb::Ics20WithdrawalStatus) -> Result<Self, Self::Error> {
+        match value {
+            pb::Ics20WithdrawalStatus::Pending => Ok(Ics20WithdrawalStatus::Pending),
+            pb::Ics20WithdrawalStatus::Acknowledged => Ok(Ics20WithdrawalStatus::Acknowledged),
+            pb::Ics20WithdrawalStatus::TimedOut => Ok(Ics20WithdrawalStatus::TimedOut),
+            pb::Ics20WithdrawalStatus::Refunded => Ok(Ics20WithdrawalStatus::Refunded),
+            pb::Ics20WithdrawalStatus::Unspecified => {
+                Err(anyhow!("unspecified ics20 withdrawal status"))
+            }
+        }
+    }
+}
+
+/// An ICS-20 withdrawal sent by the wallet, and what became of it.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(
+    try_from = "pb::Ics20WithdrawalRecord",
+    into = "pb::Ics20WithdrawalRecord"
+)]
+pub struct Ics20WithdrawalRecord {
+    pub withdrawal: Ics20Withdrawal,
+    pub transaction_id: TransactionId,
+    pub height_sent: u64,
+    /// The sequence number of the withdrawal's packet, once it is known.
+    pub sequence: Option<u64>,
+    pub status: Ics20WithdrawalStatus,
+    /// The height at which the withdrawal was acknowledged or refunded.
+    pub height_settled: Option<u64>,
+    /// The commitment of the note refunding the withdrawal, if it was refunded.
+    pub refund_note_commitment: Option<tct::StateCommitment>,
+}
+
+impl Ics20WithdrawalRecord {
+    /// Marks the withdrawal as timed out if it is still pending at `now`, in nanoseconds after
+    /// the unix epoch, but its timeout time has passed.
+    ///
+    /// Withdrawals timed out by their timeout height are only marked as such once refunded, since
+    /// the counterparty's height isn't known.
+    pub fn check_timeout(&mut self, now: u64) {
+        if self.status == Ics20WithdrawalStatus::Pending && self.withdrawal.timeout_time <= now {
+            self.status = Ics20WithdrawalStatus::TimedOut;
+        }
+    }
+}
+
+/// Computes the commitment the chain stores for the packet sent by `withdrawal`, while the packet
+/// is awaiting an acknowledgement or a timeout.
+pub(crate) fn packet_commitment(withdrawal: &Ics20Withdrawal) -> Vec<u8> {
+    let mut commit = Vec::new();
+    commit.extend_from_slice(&withdrawal.timeout_time.to_be_bytes());
+    commit.extend_from_slice(&withdrawal.timeout_height.revision_number.to_be_bytes());
+    commit.extend_from_slice(&withdrawal.timeout_height.revision_height.to_be_bytes());
+    commit.extend_from_slice(&Sha256::digest(withdrawal.packet_data()));
+    Sha256::digest(&commit).to_vec()
+}
+
+/// An update to a pending ICS-20 withdrawal, learned from the packet commitments on its channel.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum PendingIcs20WithdrawalUpdate {
+    /// The withdrawal's packet is still in flight, with this sequence number.
+    Sequence(u64),
+    /// The withdrawal's packet was acknowledged by the counterparty.
+    Acknowledged,
+}
+
+/// Determines how `withdrawal` is updated by the packet `commitments` on its channel, as of
+/// `query_height`, once the wallet has synced up to `sync_height`.
+///
+/// A packet commitment is deleted once the packet is acknowledged or times out, and refunds are
+/// detected when scanning blocks. So a missing commitment only means the withdrawal was
+/// acknowledged if the wallet has already scanned the block which deleted it: if the chain is
+/// ahead of the wallet, the refund may be in a block it hasn't scanned yet.
+pub(crate) fn pending_ics20_withdrawal_update(
+    withdrawal: &PendingIcs20Withdrawal,
+    commitments: &[PacketState],
+    query_height: u64,
+    sync_height: u64,
+) -> Option<PendingIcs20WithdrawalUpdate> {
+    match commitments
+        .iter()
+        .find(|commitment| commitment.data == withdrawal.packet_commitment)
+    {
+        Some(commitment) if withdrawal.sequence.is_none() => {
+            Some(PendingIcs20WithdrawalUpdate::Sequence(commitment.sequence))
+        }
+        Some(_) => None,
+        None if query_height <= sync_height => Some(PendingIcs20WithdrawalUpdate::Acknowledged),
+        None => None,
+    }
+}
+
+impl DomainType for Ics20WithdrawalRecord {
+    type Proto = pb::Ics20WithdrawalRecord;
+}
+
+impl From<Ics20WithdrawalRecord> for pb::Ics20WithdrawalRecord {
+    fn from(value: Ics20WithdrawalRecord) -> Self {
+        Self {
+            withdrawal: Some(value.withdrawal.into()),
+            transaction_id: Some(value.transaction_id.into()),
+            height_sent: value.height_sent,
+            sequence: value.sequence.unwrap_or(0),
+            status: pb::Ics20WithdrawalStatus::from(value.status) as i32,
+            height_settled: value.height_settled.unwrap_or(0),
+            refund_note_commitment: value.refund_note_commitment.map(Into::into),
+        }
+    }
+}
+
+impl TryFrom<pb::Ics20WithdrawalRecord> for Ics20WithdrawalRecord {
+    type Error = anyhow::Error;
+
+    fn try_from(value: pb::Ics20WithdrawalRecord) -> Result<Self, Self::Error> {
+        Ok(Self {
+            withdrawal: value
+                .withdrawal
+                .ok_or_else(|| anyhow!("missing withdrawal"))?
+                .try_into()?,
+            transaction_id: value
+                .transaction_id
+                .ok_or_else(|| anyhow!("missing transaction id"))?
+                .try_into()?,
+            height_sent: value.height_sent,
+            sequence: (value.sequence > 0).then_some(value.sequence),
+            status: pb::Ics20WithdrawalStatus::try_from(value.status)
+                .map_err(|_| anyhow!("invalid ics20 withdrawal status {}", value.status))?
+                .try_into()?,
+            height_settled: (value.height_settled > 0).then_some(value.height_settled),
+            refund_note_commitment: value
+                .refund_note_commitment
+                .map(TryInto::try_into)
+                .transpose()?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use decaf377::Fq;
+    use ibc_types::{
+        core::{
+            channel::{packet::Sequence, ChannelId, Packet, PortId},
+            client::Height as IbcHeight,
+        },
+        timestamp::Timestamp,
+    };
+    use penumbra_app::params::AppParameters;
+    use penumbra_ibc::component::commit_packet;
+    use penumbra_proto::core::component::ibc::v1::FungibleTokenPacketData;
+    use penumbra_sct::{CommitmentSource, Nullifier};
+    use penumbra_shielded_pool::{Note, Rseed};
+    use penumbra_transaction::{Action, Transaction};
+    use tonic::transport::Channel;
+
+    use super::*;
+    use crate::{sync::FilteredBlock, SpendableNoteRecord, Storage};
+
+    fn withdrawal(amount: u64, timeout_height: IbcHeight) -> Ics20Withdrawal {
+        Ics20Withdrawal {
+            amount: amount.into(),
+            denom: penumbra_asset::STAKING_TOKEN_DENOM.clone(),
+            destination_chain_address: "osmo1cw0hm7dt5prn5pvmfkarn2sp8czlg7sa4vsh2y".to_string(),
+            return_address: penumbra_keys::test_keys::ADDRESS_0.clone(),
+            timeout_height,
+            timeout_time: 1_700_000_000_000_000_000,
+            source_channel: ChannelId::new(0),
+            use_compat_address: false,
+            ics20_memo: String::new(),
+            use_transparent_address: false,
+        }
+    }
+
+    fn timeout_height() -> IbcHeight {
+        IbcHeight {
+            revision_number: 1,
+            revision_height: 1000,
+        }
+    }
+
+    /// The packet the chain sends for `withdrawal`, built as `SendPacketWrite` builds it.
+    fn packet(withdrawal: &Ics20Withdrawal) -> Packet {
+        Packet {
+            sequence: Sequence::from(1),
+            port_on_a: PortId::transfer(),
+            chan_on_a: withdrawal.source_channel.clone(),
+            port_on_b: PortId::transfer(),
+            chan_on_b: ChannelId::new(7),
+            data: withdrawal.packet_data(),
+            timeout_height_on_b: withdrawal.timeout_height.into(),
+            timeout_timestamp_on_b: Timestamp::from_nanoseconds(withdrawal.timeout_time)
+                .expect("timeout time is valid"),
+        }
+    }
+
+    fn pending(sequence: Option<u64>) -> PendingIcs20Withdrawal {
+        PendingIcs20Withdrawal {
+            tx_hash: [0; 32],
+            action_index: 0,
+            channel_id: "channel-0".to_string(),
+            packet_commitment: vec![1; 32],
+            sequence,
+        }
+    }
+
+    fn packet_state(data: Vec<u8>, sequence: u64) -> PacketState {
+        PacketState {
+            port_id: "transfer".to_string(),
+            channel_id: "channel-0".to_string(),
+            sequence,
+            data,
+        }
+    }
+
+    fn block(height: u64, new_notes: Vec<SpendableNoteRecord>) -> FilteredBlock {
+        FilteredBlock {
+            new_notes: new_notes
+                .into_iter()
+                .map(|record| (record.note_commitment, record))
+                .collect(),
+            new_swaps: BTreeMap::new(),
+            spent_nullifiers: Vec::new(),
+            height,
+            fmd_parameters: None,
+            app_parameters_updated: false,
+            gas_prices: None,
+            position_executions: Vec::new(),
+            evicted_positions: Vec::new(),
+        }
+    }
+
+    fn transaction(withdrawals: Vec<Ics20Withdrawal>) -> Transaction {
+        let mut transaction = Transaction::default();
+        transaction.transaction_body.actions = withdrawals
+            .into_iter()
+            .map(Action::Ics20Withdrawal)
+            .collect();
+        transaction
+    }
+
+    /// The `n`th note minted for an ICS-20 packet received from `sender`, as a refund or a
+    /// deposit.
+    fn transfer_note(
+        n: u64,
+        withdrawal: &Ics20Withdrawal,
+        packet_seq: u64,
+        sender: String,
+    ) -> SpendableNoteRecord {
+        let note = Note::from_parts(
+            withdrawal.return_address.clone(),
+            withdrawal.value(),
+            Rseed([n as u8; 32]),
+        )
+        .expect("note is valid");
+        SpendableNoteRecord {
+            note_commitment: note.commit(),
+            note,
+            address_index: 0u32.into(),
+            nullifier: Nullifier(Fq::from(n)),
+            height_created: 0,
+            height_spent: None,
+            position: n.into(),
+            source: CommitmentSource::Ics20Transfer {
+                packet_seq,
+                channel_id: withdrawal.source_channel.to_string(),
+                sender,
+            },
+            return_address: None,
+        }
+    }
+
+    async fn storage() -> anyhow::Result<Storage> {
+        Storage::initialize(
+            None::<&str>,
+            penumbra_keys::test_keys::FULL_VIEWING_KEY.clone(),
+            AppParameters::default(),
+        )
+        .await
+    }
+
+    // The channel is only used to fetch updated app parameters, which the test blocks don't have.
+    fn channel() -> Channel {
+        Channel::from_static("http://127.0.0.1:8080").connect_lazy()
+    }
+
+    #[test]
+    fn packet_commitment_matches_the_chain() {
+        let with_timeout_height = withdrawal(100, timeout_height());
+        let without_timeout_height = withdrawal(
+            100,
+            IbcHeight {
+                revision_number: 0,
+                revision_height: 0,
+            },
+        );
+
+        for withdrawal in [with_timeout_height, without_timeout_height] {
+            assert_eq!(
+                packet_commitment(&withdrawal),
+                commit_packet(&packet(&withdrawal))
+            );
+        }
+    }
+
+    #[test]
+    fn missing_commitment_is_only_an_acknowledgement_once_synced() {
+        let commitments = [packet_state(vec![1; 32], 3), packet_state(vec![2; 32], 4)];
+
+        assert_eq!(
+            pending_ics20_withdrawal_update(&pending(None), &commitments, 10, 10),
+            Some(PendingIcs20WithdrawalUpdate::Sequence(3))
+        );
+        assert_eq!(
+            pending_ics20_withdrawal_update(&pending(Some(3)), &commitments, 10, 10),
+            None
+        );
+
+        // The refund for the withdrawal may be in a block the wallet hasn't scanned yet.
+        let commitments = [packet_state(vec![2; 32], 4)];
+        assert_eq!(
+            pending_ics20_withdrawal_update(&pending(Some(3)), &commitments, 11, 10),
+            None
+        );
+        assert_eq!(
+            pending_ics20_withdrawal_update(&pending(Some(3)), &commitments, 10, 10),
+            Some(PendingIcs20WithdrawalUpdate::Acknowledged)
+        );
+    }
+
+    #[tokio::test]
+    async fn pending_withdrawal_is_acknowledged() -> anyhow::Result<()> {
+        let storage = storage().await?;
+        let mut sct = tct::Tree::new();
+        let withdrawal = withdrawal(100, timeout_height());
+
+        storage
+            .record_block(
+                block(0, vec![]),
+                vec![transaction(vec![withdrawal.clone()])],
+                &mut sct,
+                channel(),
+            )
+            .await?;
+
+        let pending = storage.pending_ics20_withdrawals().await?;
+        assert_eq!(pending.len(), 1);
+        assert_eq!(pending[0].channel_id, "channel-0");
+        assert_eq!(pending[0].packet_commitment, packet_commitment(&withdrawal));
+        assert_eq!(pending[0].sequence, None);
+
+        storage
+            .record_ics20_withdrawal_sequence(&pending[0], 3)
+            .await?;
+        storage
+            .record_ics20_withdrawal_acknowledged(&pending[0], 5)
+            .await?;
+        // A settled withdrawal is not acknowledged again.
+        storage
+            .record_ics20_withdrawal_acknowledged(&pending[0], 6)
+            .await?;
+
+        assert!(storage.pending_ics20_withdrawals().await?.is_empty());
+        let records = storage.ics20_withdrawals(None).await?;
+        assert_eq!(records.len(), 1);
+        assert_eq!(records[0].status, Ics20WithdrawalStatus::Acknowledged);
+        assert_eq!(records[0].height_sent, 0);
+        assert_eq!(records[0].sequence, Some(3));
+        assert_eq!(records[0].height_settled, Some(5));
+        assert_eq!(records[0].refund_note_commitment, None);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn pending_withdrawal_is_refunded() -> anyhow::Result<()> {
+        let storage = storage().await?;
+        let mut sct = tct::Tree::new();
+        let first = withdrawal(100, timeout_height());
+        let second = withdrawal(200, timeout_height());
+        let sender = FungibleTokenPacketData::from(first.clone()).sender;
+
+        storage
+            .record_block(
+                block(0, vec![]),
+                vec![transaction(vec![first.clone(), second.clone()])],
+                &mut sct,
+                channel(),
+            )
+            .await?;
+        for withdrawal in storage.pending_ics20_withdrawals().await? {
+            storage
+                .record_ics20_withdrawal_sequence(&withdrawal, 3 + withdrawal.action_index)
+                .await?;
+        }
+
+        // A deposit from the counterparty on the same channel refunds nothing.
+        let deposit = transfer_note(
+            0,
+            &second,
+            4,
+            "osmo1cw0hm7dt5prn5pvmfkarn2sp8czlg7sa4vsh2y".to_string(),
+        );
+        storage
+            .record_block(block(1, vec![deposit]), vec![], &mut sct, channel())
+            .await?;
+        assert_eq!(storage.pending_ics20_withdrawals().await?.len(), 2);
+
+        // The refund is matched to the withdrawal by the sequence number of its packet.
+        let refund = transfer_note(1, &second, 4, sender);
+        let refund_commitment = refund.note_commitment;
+        storage
+            .record_block(block(2, vec![refund]), vec![], &mut sct, channel())
+            .await?;
+
+        let records = storage.ics20_withdrawals(None).await?;
+        assert_eq!(records.len(), 2);
+        assert_eq!(records[0].status, Ics20WithdrawalStatus::Pending);
+        assert_eq!(records[0].sequence, Some(3));
+        assert_eq!(records[1].status, Ics20WithdrawalStatus::Refunded);
+        assert_eq!(records[1].sequence, Some(4));
+        assert_eq!(records[1].height_settled, Some(2));
+        assert_eq!(records[1].refund_note_commitment, Some(refund_commitment));
+
+        Ok(())
+    }
+}
@@ -13,11 +13,18 @@
 #![recursion_limit = "512"]
 // Requires nightly.
 #![cfg_attr(docsrs, feature(doc_auto_cfg))]
+mod block_time;
 mod client;
+mod forwarding;
+mod governance_feed;
+mod ics20_withdrawal_record;
 mod metrics;
 mod note_record;
 mod planner;
+mod position_performance;
+mod scheduled_transaction;
 mod service;
+mod staking_rewards;
 mod status;
 mod storage;
 mod swap_record;
@@ -25,11 +32,20 @@ mod sync;
 mod transaction_info;
 mod worker;
 
+pub use crate::block_time::observed_block_time;
 pub use crate::client::ViewClient;
+pub use crate::forwarding::{register_forwarding_account, ForwardingAccount};
+pub use crate::governance_feed::GovernanceFeedItem;
+pub use crate::ics20_withdrawal_record::{Ics20WithdrawalRecord, Ics20WithdrawalStatus};
 pub use crate::metrics::register_metrics;
 pub use crate::note_record::SpendableNoteRecord;
 pub use crate::planner::Planner;
+pub use crate::position_performance::PositionPerformance;
+pub use crate::scheduled_transaction::{
+    ScheduleStatus, ScheduledTransaction, ScheduledTransactionExecution,
+};
 pub use crate::service::ViewServer;
+pub use crate::staking_rewards::{StakingRewards, StakingRewardsEvent, StakingRewardsEventKind};
 pub use crate::status::StatusStreamResponse;
 pub use crate::storage::Storage;
 pub use crate::swap_record::SwapRecord;
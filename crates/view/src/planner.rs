@@ -19,7 +19,7 @@ use penumbra_auction::auction::{
     dutch::actions::{ActionDutchAuctionEnd, ActionDutchAuctionSchedule},
     AuctionId,
 };
-use penumbra_community_pool::CommunityPoolDeposit;
+use penumbra_community_pool::{CommunityPoolDeposit, CommunityPoolVestingClaim};
 use penumbra_dex::{
     lp::action::{PositionClose, PositionOpen},
     lp::plan::PositionWithdrawPlan,
@@ -33,14 +33,14 @@ use penumbra_dex::{
 use penumbra_fee::{Fee, FeeTier, GasPrices};
 use penumbra_governance::{
     proposal_state, DelegatorVotePlan, Proposal, ProposalDepositClaim, ProposalSubmit,
-    ProposalWithdraw, ValidatorVote, Vote,
+    ProposalWithdraw, ProxyVote, ValidatorVote, Vote,
 };
 use penumbra_ibc::IbcRelay;
 use penumbra_keys::{keys::AddressIndex, Address};
 use penumbra_num::Amount;
 use penumbra_proto::view::v1::{NotesForVotingRequest, NotesRequest};
 use penumbra_shielded_pool::{Ics20Withdrawal, Note, OutputPlan, SpendPlan};
-use penumbra_stake::{rate::RateData, validator, IdentityKey, UndelegateClaimPlan};
+use penumbra_stake::{rate::RateData, validator, GovernanceKey, IdentityKey, UndelegateClaimPlan};
 use penumbra_tct as tct;
 use penumbra_transaction::{
     memo::MemoPlaintext,
@@ -330,6 +330,22 @@ impl<R: RngCore + CryptoRng> Planner<R> {
         self
     }
 
+    /// Add a redelegation from the validator described by `source_rate_data`
+    /// to the validator described by `destination_rate_data` to this transaction.
+    #[instrument(skip(self))]
+    pub fn redelegate(
+        &mut self,
+        epoch: Epoch,
+        delegation_amount: Amount,
+        source_rate_data: RateData,
+        destination_rate_data: RateData,
+    ) -> &mut Self {
+        let redelegation =
+            source_rate_data.build_redelegate(&destination_rate_data, epoch, delegation_amount);
+        self.action_list.push(redelegation);
+        self
+    }
+
     /// Add an undelegate claim to this transaction.
     #[instrument(skip(self))]
     pub fn undelegate_claim(&mut self, claim_plan: UndelegateClaimPlan) -> &mut Self {
@@ -384,6 +400,15 @@ impl<R: RngCore + CryptoRng> Planner<R> {
         self
     }
 
+    /// Claim vested funds from a Community Pool vesting grant, which are paid to the grant's
+    /// recipient.
+    #[instrument(skip(self))]
+    pub fn community_pool_vesting_claim(&mut self, grant_id: u64, amount: Amount) -> &mut Self {
+        self.action_list
+            .push(CommunityPoolVestingClaim { grant_id, amount });
+        self
+    }
+
     /// Cast a validator vote in this transaction.
     #[instrument(skip(self))]
     pub fn validator_vote(&mut self, vote: ValidatorVote) -> &mut Self {
@@ -391,6 +416,13 @@ impl<R: RngCore + CryptoRng> Planner<R> {
         self
     }
 
+    /// Cast a proxy vote in this transaction, on behalf of the delegators who chose this proxy.
+    #[instrument(skip(self))]
+    pub fn proxy_vote(&mut self, vote: ProxyVote) -> &mut Self {
+        self.action_list.push(vote);
+        self
+    }
+
     /// Perform an ICS-20 withdrawal
     #[instrument(skip(self))]
     pub fn ics20_withdrawal(&mut self, withdrawal: Ics20Withdrawal) -> &mut Self {
@@ -406,6 +438,11 @@ impl<R: RngCore + CryptoRng> Planner<R> {
     }
 
     /// Vote with all possible vote weight on a given proposal.
+    ///
+    /// If a `proxy` is given, the vote weight is delegated to that proxy for this proposal, and
+    /// `vote` only counts if the proxy does not vote. If `standing_proxy` is set, the choice of
+    /// proxy is also recorded on chain for proposals started later, until the notes are spent;
+    /// setting it without a `proxy` clears any such standing delegation.
     #[instrument(skip_all)]
     pub async fn delegator_vote<V: ViewClient>(
         // TODO this sucks, why isn't there a bundle of proposal data to use for voting
@@ -419,6 +456,8 @@ impl<R: RngCore + CryptoRng> Planner<R> {
         start_block_height: u64,
         start_position: tct::Position,
         start_rate_data: BTreeMap<IdentityKey, RateData>,
+        proxy: Option<GovernanceKey>,
+        standing_proxy: bool,
     ) -> Result<&mut Self, anyhow::Error> {
         let voting_notes = view
             .notes_for_voting(NotesForVotingRequest {
@@ -452,6 +491,8 @@ impl<R: RngCore + CryptoRng> Planner<R> {
                 record.note.clone(),
                 record.position,
                 voting_power_at_vote_start,
+                proxy,
+                standing_proxy,
             );
             self.delegator_vote_precise(plan);
         }
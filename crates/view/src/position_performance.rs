@@ -0,0 +1,244 @@
+use penumbra_dex::lp::{
+    position::{self, Position},
+    PositionExecutionSummary, Reserves,
+};
+use penumbra_num::Amount;
+use penumbra_proto::{view::v1 as pb, DomainType};
+use serde::{Deserialize, Serialize};
+
+/// The accounting of a liquidity position controlled by the wallet, derived
+/// from the executions recorded against it since it was opened.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(try_from = "pb::PositionPerformance", into = "pb::PositionPerformance")]
+pub struct PositionPerformance {
+    pub position_id: position::Id,
+    /// The position as it was opened, including its initial reserves.
+    pub opened: Position,
+    pub height_opened: u64,
+    /// The state of the position, as known to the view service.
+    pub state: position::State,
+    /// The reserves after the latest execution, which are the reserves realized
+    /// on withdrawal once the position has been withdrawn.
+    pub reserves: Reserves,
+    /// The number of blocks in which the position was executed against.
+    pub executions: u64,
+    pub filled_in_1: Amount,
+    pub filled_in_2: Amount,
+    pub filled_out_1: Amount,
+    pub filled_out_2: Amount,
+    pub fees_1: Amount,
+    pub fees_2: Amount,
+    pub height_withdrawn: Option<u64>,
+    /// The height at which the position was closed by the DEX eviction mechanism, if it was.
+    pub height_evicted: Option<u64>,
+}
+
+impl PositionPerformance {
+    /// Starts the accounting of a position that has not been executed against yet.
+    pub fn new(
+        opened: Position,
+        height_opened: u64,
+        state: position::State,
+        height_withdrawn: Option<u64>,
+    ) -> Self {
+        Self {
+            position_id: opened.id(),
+            reserves: opened.reserves.clone(),
+            opened,
+            height_opened,
+            state,
+            executions: 0,
+            filled_in_1: Amount::zero(),
+            filled_in_2: Amount::zero(),
+            filled_out_1: Amount::zero(),
+            filled_out_2: Amount::zero(),
+            fees_1: Amount::zero(),
+            fees_2: Amount::zero(),
+            height_withdrawn,
+            height_evicted: None,
+        }
+    }
+
+    /// Accounts for the executions against the position during a block.
+    ///
+    /// The fee is charged on the input to the position, so the fees earned are
+    /// the fee tier's share of each inflow.
+    pub fn record_execution(&mut self, summary: &PositionExecutionSummary) {
+        let fee = self.opened.phi.component.fee;
+        let outflows = summary.outflows();
+
+        self.filled_in_1 += summary.inflows.r1;
+        self.filled_in_2 += summary.inflows.r2;
+        self.fees_1 += fee_share(summary.inflows.r1, fee);
+        self.fees_2 += fee_share(summary.inflows.r2, fee);
+        self.filled_out_1 += outflows.r1;
+        self.filled_out_2 += outflows.r2;
+
+        self.reserves = summary.reserves.clone();
+        self.executions += 1;
+    }
+}
+
+/// Computes the share of `amount` corresponding to a fee of `fee_bps` basis points,
+/// without overflowing for large amounts.
+fn fee_share(amount: Amount, fee_bps: u32) -> Amount {
+    let amount = amount.value();
+    let fee_bps = u128::from(fee_bps);
+    ((amount / 10_000) * fee_bps + (amount % 10_000) * fee_bps / 10_000).into()
+}
+
+impl DomainType for PositionPerformance {
+    type Proto = pb::PositionPerformance;
+}
+
+impl From<PositionPerformance> for pb::PositionPerformance {
+    fn from(msg: PositionPerformance) -> Self {
+        pb::PositionPerformance {
+            position_id: Some(msg.position_id.into()),
+            opened: Some(msg.opened.into()),
+            height_opened: msg.height_opened,
+            state: Some(msg.state.into()),
+            reserves: Some(msg.reserves.into()),
+            executions: msg.executions,
+            filled_in_1: Some(msg.filled_in_1.into()),
+            filled_in_2: Some(msg.filled_in_2.into()),
+            filled_out_1: Some(msg.filled_out_1.into()),
+            filled_out_2: Some(msg.filled_out_2.into()),
+            fees_1: Some(msg.fees_1.into()),
+            fees_2: Some(msg.fees_2.into()),
+            height_withdrawn: msg.height_withdrawn.unwrap_or(0),
+            height_evicted: msg.height_evicted.unwrap_or(0),
+        }
+    }
+}
+
+impl TryFrom<pb::PositionPerformance> for PositionPerformance {
+    type Error = anyhow::Error;
+
+    fn try_from(value: pb::PositionPerformance) -> Result<Self, Self::Error> {
+        fn amount(value: Option<penumbra_proto::core::num::v1::Amount>) -> anyhow::Result<Amount> {
+            value
+                .ok_or_else(|| anyhow::anyhow!("missing amount"))?
+                .try_into()
+        }
+
+        Ok(Self {
+            position_id: value
+                .position_id
+                .ok_or_else(|| anyhow::anyhow!("missing position_id"))?
+                .try_into()?,
+            opened: value
+                .opened
+                .ok_or_else(|| anyhow::anyhow!("missing opened"))?
+                .try_into()?,
+            height_opened: value.height_opened,
+            state: value
+                .state
+                .ok_or_else(|| anyhow::anyhow!("missing state"))?
+                .try_into()?,
+            reserves: value
+                .reserves
+                .ok_or_else(|| anyhow::anyhow!("missing reserves"))?
+                .try_into()?,
+            executions: value.executions,
+            filled_in_1: amount(value.filled_in_1)?,
+            filled_in_2: amount(value.filled_in_2)?,
+            filled_out_1: amount(value.filled_out_1)?,
+            filled_out_2: amount(value.filled_out_2)?,
+            fees_1: amount(value.fees_1)?,
+            fees_2: amount(value.fees_2)?,
+            height_withdrawn: if value.height_withdrawn > 0 {
+                Some(value.height_withdrawn)
+            } else {
+                None
+            },
+            height_evicted: if value.height_evicted > 0 {
+                Some(value.height_evicted)
+            } else {
+                None
+            },
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use penumbra_asset::asset;
+    use penumbra_dex::{DirectedTradingPair, TradingPair};
+
+    use super::*;
+
+    fn reserves(r1: u64, r2: u64) -> Reserves {
+        Reserves {
+            r1: r1.into(),
+            r2: r2.into(),
+        }
+    }
+
+    /// A position with a 1% fee and 100,000 of asset 2 in reserve.
+    fn opened() -> Position {
+        let cache = asset::Cache::with_known_assets();
+        let pair = TradingPair::new(
+            cache.get_unit("upenumbra").unwrap().id(),
+            cache.get_unit("ugm").unwrap().id(),
+        );
+        Position::new_with_nonce(
+            [0; 32],
+            DirectedTradingPair::new(pair.asset_1(), pair.asset_2()),
+            100,
+            1u64.into(),
+            1u64.into(),
+            reserves(0, 100_000),
+        )
+    }
+
+    #[test]
+    fn execution_in_one_direction_accrues_fees_on_the_inflow() {
+        let mut performance = PositionPerformance::new(opened(), 1, position::State::Opened, None);
+
+        let new = reserves(20_000, 81_000);
+        performance.record_execution(&PositionExecutionSummary::new(
+            performance.position_id,
+            &reserves(0, 100_000),
+            &new,
+        ));
+
+        assert_eq!(performance.executions, 1);
+        assert_eq!(performance.filled_in_1, 20_000u64.into());
+        assert_eq!(performance.filled_out_2, 19_000u64.into());
+        assert_eq!(performance.fees_1, 200u64.into());
+        assert_eq!(performance.fees_2, Amount::zero());
+        assert_eq!(performance.reserves, new);
+    }
+
+    #[test]
+    fn executions_in_both_directions_in_a_block_are_not_netted_away() {
+        let mut performance = PositionPerformance::new(opened(), 1, position::State::Opened, None);
+
+        // Filled one way and back again within a block, ending with a small gain of asset 1.
+        let mut summary = PositionExecutionSummary::new(
+            performance.position_id,
+            &reserves(0, 100_000),
+            &reserves(50_000, 50_000),
+        );
+        summary.record(&reserves(50_000, 50_000), &reserves(100, 100_000));
+        performance.record_execution(&summary);
+
+        assert_eq!(performance.executions, 1);
+        assert_eq!(performance.filled_in_1, 50_000u64.into());
+        assert_eq!(performance.filled_in_2, 50_000u64.into());
+        assert_eq!(performance.filled_out_1, 49_900u64.into());
+        assert_eq!(performance.filled_out_2, 50_000u64.into());
+        assert_eq!(performance.fees_1, 500u64.into());
+        assert_eq!(performance.fees_2, 500u64.into());
+        assert_eq!(performance.reserves, reserves(100, 100_000));
+    }
+
+    #[test]
+    fn fee_share_does_not_overflow() {
+        assert_eq!(fee_share(10_000u64.into(), 30), 30u64.into());
+        assert_eq!(fee_share(9_999u64.into(), 10_000), 9_999u64.into());
+        let max = Amount::from(u128::MAX);
+        assert_eq!(fee_share(max, 10_000), max);
+    }
+}
@@ -0,0 +1,306 @@
+use std::{fmt, str::FromStr};
+
+use anyhow::anyhow;
+use penumbra_proto::{view::v1 as pb, DomainType};
+use penumbra_transaction::txhash::TransactionId;
+use serde::{Deserialize, Serialize};
+
+/// The status of a [`ScheduledTransaction`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(try_from = "pb::ScheduleStatus", into = "pb::ScheduleStatus")]
+pub enum ScheduleStatus {
+    /// The schedule will be executed when its next height arrives.
+    Active,
+    /// The schedule is skipped until it is resumed.
+    Paused,
+    /// The schedule was cancelled, and will never be executed again.
+    Cancelled,
+    /// The schedule reached its maximum number of occurrences.
+    Completed,
+}
+
+impl ScheduleStatus {
+    /// Whether a schedule in this status can still be executed in the future.
+    pub fn is_terminal(&self) -> bool {
+        matches!(self, ScheduleStatus::Cancelled | ScheduleStatus::Completed)
+    }
+}
+
+impl fmt::Display for ScheduleStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ScheduleStatus::Active => write!(f, "active"),
+            ScheduleStatus::Paused => write!(f, "paused"),
+            ScheduleStatus::Cancelled => write!(f, "cancelled"),
+            ScheduleStatus::Completed => write!(f, "completed"),
+        }
+    }
+}
+
+impl FromStr for ScheduleStatus {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> anyhow::Result<Self> {
+        match s {
+            "active" => Ok(ScheduleStatus::Active),
+            "paused" => Ok(ScheduleStatus::Paused),
+            "cancelled" => Ok(ScheduleStatus::Cancelled),
+            "completed" => Ok(ScheduleStatus::Completed),
+            _ => Err(anyhow!("unknown schedule status {}", s)),
+        }
+    }
+}
+
+impl From<ScheduleStatus> for pb::ScheduleStatus {
+    fn from(value: ScheduleStatus) -> Self {
+        match value {
+            ScheduleStatus::Active => pb::ScheduleStatus::Active,
+            ScheduleStatus::Paused => pb::ScheduleStatus::Paused,
+            ScheduleStatus::Cancelled => pb::ScheduleStatus::Cancelled,
+            ScheduleStatus::Completed => pb::ScheduleStatus::Completed,
+        }
+    }
+}
+
+impl TryFrom<pb::ScheduleStatus> for ScheduleStatus {
+    type Error = anyhow::Error;
+
+    fn try_from(value: pb::ScheduleStatus) -> Result<Self, Self::Error> {
+        match value {
+            pb::ScheduleStatus::Active => Ok(ScheduleStatus::Active),
+            pb::ScheduleStatus::Paused => Ok(ScheduleStatus::Paused),
+            pb::ScheduleStatus::Cancelled => Ok(ScheduleStatus::Cancelled),
+            pb::ScheduleStatus::Completed => Ok(ScheduleStatus::Completed),
+            pb::ScheduleStatus::Unspecified => Err(anyhow!("unspecified schedule status")),
+        }
+    }
+}
+
+/// A transaction scheduled to be planned and submitted by the view service,
+/// once, at a fixed interval, or at the start of every epoch.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(
+    try_from = "pb::ScheduledTransaction",
+    into = "pb::ScheduledTransaction"
+)]
+pub struct ScheduledTransaction {
+    pub id: u64,
+    /// The request to plan at each occurrence of the schedule.
+    pub plan_request: pb::TransactionPlannerRequest,
+    pub start_height: u64,
+    /// The number of blocks between occurrences, or 0 for a one-off transaction.
+    ///
+    /// For epoch-aligned schedules, this is the epoch duration.
+    pub interval: u64,
+    /// The maximum number of occurrences, or 0 for no limit.
+    pub max_occurrences: u64,
+    /// The number of occurrences executed so far, successful or not.
+    pub occurrences: u64,
+    /// The height of the next occurrence.
+    ///
+    /// For epoch-aligned schedules, this is the latest height at which the next epoch can start,
+    /// and is brought forward by [`ScheduledTransaction::align_to_epoch`] if the epoch ends early.
+    pub next_height: u64,
+    pub status: ScheduleStatus,
+    /// Whether the schedule is executed at the start of every epoch.
+    pub every_epoch: bool,
+}
+
+impl ScheduledTransaction {
+    /// Returns the status and next height of the schedule after an occurrence at `height`.
+    ///
+    /// Occurrences missed while the schedule was not being executed are skipped,
+    /// rather than executed back-to-back.
+    pub fn advance(&self, height: u64) -> (ScheduleStatus, u64) {
+        let occurrences = self.occurrences + 1;
+        let exhausted = self.max_occurrences != 0 && occurrences >= self.max_occurrences;
+        if self.interval == 0 || exhausted {
+            return (ScheduleStatus::Completed, self.next_height);
+        }
+        if self.every_epoch {
+            // The epoch containing `height` ends at the latest `interval` blocks later, so the
+            // next occurrence is brought forward once that epoch ends.
+            return (self.status, height + self.interval);
+        }
+        let missed = height.saturating_sub(self.next_height) / self.interval;
+        (self.status, self.next_height + (missed + 1) * self.interval)
+    }
+
+    /// Returns the next height of the schedule once an epoch ends, so that the next epoch
+    /// starts at `next_epoch_start_height`.
+    ///
+    /// Epochs never last longer than the epoch duration, but may end early, so an epoch-aligned
+    /// schedule is only ever brought forward.
+    pub fn align_to_epoch(&self, next_epoch_start_height: u64) -> u64 {
+        if self.every_epoch {
+            self.next_height.min(next_epoch_start_height)
+        } else {
+            self.next_height
+        }
+    }
+}
+
+impl DomainType for ScheduledTransaction {
+    type Proto = pb::ScheduledTransaction;
+}
+
+impl From<ScheduledTransaction> for pb::ScheduledTransaction {
+    fn from(value: ScheduledTransaction) -> Self {
+        Self {
+            id: value.id,
+            plan_request: Some(value.plan_request),
+            start_height: value.start_height,
+            interval: value.interval,
+            max_occurrences: value.max_occurrences,
+            occurrences: value.occurrences,
+            next_height: value.next_height,
+            status: pb::ScheduleStatus::from(value.status) as i32,
+            every_epoch: value.every_epoch,
+        }
+    }
+}
+
+impl TryFrom<pb::ScheduledTransaction> for ScheduledTransaction {
+    type Error = anyhow::Error;
+
+    fn try_from(value: pb::ScheduledTransaction) -> Result<Self, Self::Error> {
+        Ok(Self {
+            id: value.id,
+            plan_request: value
+                .plan_request
+                .ok_or_else(|| anyhow!("missing plan request"))?,
+            start_height: value.start_height,
+            interval: value.interval,
+            max_occurrences: value.max_occurrences,
+            occurrences: value.occurrences,
+            next_height: value.next_height,
+            status: pb::ScheduleStatus::try_from(value.status)
+                .map_err(|_| anyhow!("invalid schedule status {}", value.status))?
+                .try_into()?,
+            every_epoch: value.every_epoch,
+        })
+    }
+}
+
+/// The outcome of one occurrence of a [`ScheduledTransaction`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(
+    try_from = "pb::ScheduledTransactionExecution",
+    into = "pb::ScheduledTransactionExecution"
+)]
+pub struct ScheduledTransactionExecution {
+    pub schedule_id: u64,
+    pub height: u64,
+    /// The ID of the transaction submitted for the occurrence, if it was submitted.
+    pub transaction_id: Option<TransactionId>,
+    /// The error that prevented the occurrence from completing, if any.
+    ///
+    /// An occurrence can have both a transaction and an error, if its transaction was
+    /// confirmed but the outputs of its swaps could not be claimed.
+    pub error: Option<String>,
+}
+
+impl DomainType for ScheduledTransactionExecution {
+    type Proto = pb::ScheduledTransactionExecution;
+}
+
+impl From<ScheduledTransactionExecution> for pb::ScheduledTransactionExecution {
+    fn from(value: ScheduledTransactionExecution) -> Self {
+        Self {
+            schedule_id: value.schedule_id,
+            height: value.height,
+            transaction_id: value.transaction_id.map(Into::into),
+            error: value.error.unwrap_or_default(),
+        }
+    }
+}
+
+impl TryFrom<pb::ScheduledTransactionExecution> for ScheduledTransactionExecution {
+    type Error = anyhow::Error;
+
+    fn try_from(value: pb::ScheduledTransactionExecution) -> Result<Self, Self::Error> {
+        Ok(Self {
+            schedule_id: value.schedule_id,
+            height: value.height,
+            transaction_id: value.transaction_id.map(TryInto::try_into).transpose()?,
+            error: Some(value.error).filter(|error| !error.is_empty()),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn schedule(interval: u64, max_occurrences: u64, every_epoch: bool) -> ScheduledTransaction {
+        ScheduledTransaction {
+            id: 1,
+            plan_request: Default::default(),
+            start_height: 100,
+            interval,
+            max_occurrences,
+            occurrences: 0,
+            next_height: 100,
+            status: ScheduleStatus::Active,
+            every_epoch,
+        }
+    }
+
+    #[test]
+    fn one_off_schedule_completes() {
+        let schedule = schedule(0, 1, false);
+        assert_eq!(schedule.advance(100), (ScheduleStatus::Completed, 100));
+    }
+
+    #[test]
+    fn recurring_schedule_advances_by_its_interval() {
+        let schedule = schedule(10, 0, false);
+        assert_eq!(schedule.advance(100), (ScheduleStatus::Active, 110));
+        // Executing a little late doesn't shift the schedule.
+        assert_eq!(schedule.advance(105), (ScheduleStatus::Active, 110));
+    }
+
+    #[test]
+    fn missed_occurrences_are_skipped() {
+        let schedule = schedule(10, 0, false);
+        assert_eq!(schedule.advance(110), (ScheduleStatus::Active, 120));
+        assert_eq!(schedule.advance(135), (ScheduleStatus::Active, 140));
+    }
+
+    #[test]
+    fn schedule_completes_after_max_occurrences() {
+        let mut schedule = schedule(10, 3, false);
+        schedule.occurrences = 1;
+        assert_eq!(schedule.advance(100), (ScheduleStatus::Active, 110));
+        schedule.occurrences = 2;
+        schedule.next_height = 110;
+        assert_eq!(schedule.advance(110), (ScheduleStatus::Completed, 110));
+    }
+
+    #[test]
+    fn paused_schedule_stays_paused() {
+        let mut schedule = schedule(10, 0, false);
+        schedule.status = ScheduleStatus::Paused;
+        assert_eq!(schedule.advance(100), (ScheduleStatus::Paused, 110));
+    }
+
+    #[test]
+    fn epoch_schedule_follows_epoch_boundaries() {
+        let mut schedule = schedule(50, 0, true);
+        // Executed late in an epoch starting at 100, the next occurrence is bounded by the
+        // latest end of that epoch...
+        let (_, next_height) = schedule.advance(120);
+        assert_eq!(next_height, 170);
+        schedule.next_height = next_height;
+        // ...and brought forward to the start of the next epoch, whether the epoch ends on
+        // schedule or early.
+        assert_eq!(schedule.align_to_epoch(150), 150);
+        assert_eq!(schedule.align_to_epoch(130), 130);
+    }
+
+    #[test]
+    fn only_epoch_schedules_are_aligned() {
+        let schedule = schedule(50, 0, false);
+        assert_eq!(schedule.align_to_epoch(80), 100);
+    }
+}
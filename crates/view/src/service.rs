@@ -2,6 +2,7 @@ use std::{
     collections::{BTreeMap, BTreeSet},
     pin::Pin,
     sync::{Arc, Mutex},
+    time::{SystemTime, UNIX_EPOCH},
 };
 
 use anyhow::{anyhow, Context};
@@ -31,6 +32,7 @@ use penumbra_dex::{
     TradingPair,
 };
 use penumbra_fee::Fee;
+use penumbra_governance::{proposal_nft, ProposalNft};
 use penumbra_keys::{
     keys::WalletId,
     keys::{AddressIndex, FullViewingKey},
@@ -38,6 +40,13 @@ use penumbra_keys::{
 };
 use penumbra_num::Amount;
 use penumbra_proto::{
+    core::component::sct::v1::{
+        query_service_client::QueryServiceClient as SctQueryServiceClient, EpochByHeightRequest,
+    },
+    core::component::stake::v1::{
+        query_service_client::QueryServiceClient as StakeQueryServiceClient,
+        CurrentValidatorRateRequest, ValidatorRateHistoryRequest,
+    },
     util::tendermint_proxy::v1::{
         tendermint_proxy_service_client::TendermintProxyServiceClient, BroadcastTxSyncRequest,
         GetStatusRequest, GetStatusResponse, SyncInfo,
@@ -54,13 +63,18 @@ use penumbra_proto::{
     },
     DomainType,
 };
-use penumbra_stake::rate::RateData;
+use penumbra_stake::{rate::RateData, DelegationToken, IdentityKey};
 use penumbra_tct::{Proof, StateCommitment};
 use penumbra_transaction::{
     AuthorizationData, Transaction, TransactionPerspective, TransactionPlan, WitnessData,
 };
 
-use crate::{worker::Worker, Planner, Storage};
+use crate::{
+    forwarding::{pending_deposits, ForwardingRegistrar},
+    staking_rewards::{self, StakingRewardsTracker},
+    worker::Worker,
+    ForwardingAccount, GovernanceFeedItem, Planner, ScheduleStatus, Storage,
+};
 
 /// A [`futures::Stream`] of broadcast transaction responses.
 ///
@@ -138,9 +152,12 @@ impl ViewServer {
                 .await?
                 .tap(|_| tracing::debug!("constructed view server worker"));
 
-        tokio::spawn(worker.run().instrument(span))
+        tokio::spawn(worker.run().instrument(span.clone()))
             .tap(|_| tracing::debug!("spawned view server worker"));
 
+        let registrar = ForwardingRegistrar::new(storage.clone());
+        tokio::spawn(registrar.run().instrument(span));
+
         Ok(Self {
             storage,
             error_slot,
@@ -354,6 +371,75 @@ impl ViewServer {
             partial_sync_height: full_sync_height, // Set these as the same for backwards compatibility following adding the partial_sync_height
         })
     }
+
+    /// Gets the governance proposals on chain, annotated with the wallet's relationship to each,
+    /// along with the encoded state of each proposal.
+    ///
+    /// The proposals and the wallet's votes on them are tracked during sync, so this does not
+    /// query the node.
+    #[instrument(skip(self))]
+    async fn governance_feed_items(
+        &self,
+        inactive: bool,
+        actionable_only: bool,
+    ) -> anyhow::Result<Vec<(GovernanceFeedItem, Vec<u8>)>> {
+        let mut votes = self.storage.governance_votes().await?;
+
+        // Find the proposals whose deposit NFTs are held by the wallet.
+        let mut held_deposits = BTreeSet::<u64>::new();
+        for balance in self.storage.balances(None, None).await? {
+            let metadata = self.storage.asset_by_id(&balance.id).await?;
+            if let Some(Ok(nft)) = metadata.map(ProposalNft::try_from) {
+                if matches!(
+                    nft.proposal_state(),
+                    proposal_nft::Kind::Deposit | proposal_nft::Kind::UnbondingDeposit
+                ) {
+                    held_deposits.insert(nft.proposal_id());
+                }
+            }
+        }
+
+        let seen_states = self.storage.seen_proposal_states().await?;
+
+        let mut items = Vec::new();
+        for (proposal_id, record) in self.storage.governance_proposals().await? {
+            // Find the notes that could vote on the proposal, with their voting power.
+            let mut voting_notes = Vec::new();
+            if record.state.is_voting() {
+                let rate_data = self
+                    .storage
+                    .governance_proposal_rate_data(proposal_id)
+                    .await?;
+                for (note, identity_key) in self
+                    .storage
+                    .notes_for_voting(None, record.start_block_height)
+                    .await?
+                {
+                    if let Some(rate_data) = rate_data.get(&identity_key) {
+                        voting_notes.push((
+                            note.nullifier,
+                            rate_data.unbonded_amount(note.note.amount()),
+                        ));
+                    }
+                }
+            }
+
+            let encoded_state = record.state.encode_to_vec();
+            let item = GovernanceFeedItem::new(
+                record,
+                votes.remove(&proposal_id).unwrap_or_default(),
+                &voting_notes,
+                held_deposits.contains(&proposal_id),
+                seen_states.get(&proposal_id).map(Vec::as_slice),
+            );
+
+            if item.is_listed(inactive, actionable_only) {
+                items.push((item, encoded_state));
+            }
+        }
+
+        Ok(items)
+    }
 }
 
 #[async_trait]
@@ -403,6 +489,29 @@ impl ViewService for ViewServer {
     >;
     type AuctionsStream =
         Pin<Box<dyn futures::Stream<Item = Result<pb::AuctionsResponse, tonic::Status>> + Send>>;
+    type StakingRewardsStream = Pin<
+        Box<dyn futures::Stream<Item = Result<pb::StakingRewardsResponse, tonic::Status>> + Send>,
+    >;
+    type GovernanceFeedStream = Pin<
+        Box<dyn futures::Stream<Item = Result<pb::GovernanceFeedResponse, tonic::Status>> + Send>,
+    >;
+    type PositionPerformanceStream = Pin<
+        Box<
+            dyn futures::Stream<Item = Result<pb::PositionPerformanceResponse, tonic::Status>>
+                + Send,
+        >,
+    >;
+    type SchedulesStream =
+        Pin<Box<dyn futures::Stream<Item = Result<pb::SchedulesResponse, tonic::Status>> + Send>>;
+    type Ics20WithdrawalsStream = Pin<
+        Box<dyn futures::Stream<Item = Result<pb::Ics20WithdrawalsResponse, tonic::Status>> + Send>,
+    >;
+    type ForwardingAccountsStream = Pin<
+        Box<
+            dyn futures::Stream<Item = Result<pb::ForwardingAccountsResponse, tonic::Status>>
+                + Send,
+        >,
+    >;
 
     #[instrument(skip_all, level = "trace")]
     async fn auctions(
@@ -1758,6 +1867,535 @@ impl ViewService for ViewServer {
         ))
     }
 
+    #[instrument(skip_all, level = "trace")]
+    async fn position_performance(
+        &self,
+        request: tonic::Request<pb::PositionPerformanceRequest>,
+    ) -> Result<tonic::Response<Self::PositionPerformanceStream>, tonic::Status> {
+        self.check_worker().await?;
+
+        let position_ids = request
+            .into_inner()
+            .position_ids
+            .into_iter()
+            .map(position::Id::try_from)
+            .collect::<anyhow::Result<Vec<_>>>()
+            .map_err(|e| e.context("could not decode position id"))
+            .map_err(|e| tonic::Status::invalid_argument(format!("{:#}", e)))?;
+
+        let performances = self
+            .storage
+            .position_performance(position_ids)
+            .await
+            .map_err(|e| {
+                tonic::Status::unavailable(format!("error getting position performance: {e}"))
+            })?;
+
+        let stream = try_stream! {
+            for performance in performances {
+                yield pb::PositionPerformanceResponse {
+                    performance: Some(performance.into()),
+                }
+            }
+        };
+
+        Ok(tonic::Response::new(
+            stream
+                .map_err(|e: anyhow::Error| {
+                    tonic::Status::unavailable(format!("error getting position performance: {e}"))
+                })
+                .boxed(),
+        ))
+    }
+
+    #[instrument(skip_all, level = "trace")]
+    async fn create_schedule(
+        &self,
+        request: tonic::Request<pb::CreateScheduleRequest>,
+    ) -> Result<tonic::Response<pb::CreateScheduleResponse>, tonic::Status> {
+        self.check_worker().await?;
+
+        let pb::CreateScheduleRequest {
+            plan_request,
+            start_height,
+            interval,
+            max_occurrences,
+            every_epoch,
+        } = request.into_inner();
+
+        let plan_request =
+            plan_request.ok_or_else(|| tonic::Status::invalid_argument("Missing plan request"))?;
+        if every_epoch && start_height != 0 {
+            return Err(tonic::Status::invalid_argument(
+                "epoch-aligned schedules start at the next epoch",
+            ));
+        }
+
+        let next_height = self
+            .storage
+            .last_sync_height()
+            .await
+            .map_err(|e| tonic::Status::unavailable(format!("error getting sync height: {e}")))?
+            .unwrap_or_default()
+            + 1;
+
+        let (start_height, interval) = if every_epoch {
+            // The current epoch started at or before the next block, so the next epoch starts at
+            // the latest one epoch duration after it; the worker brings the schedule forward to
+            // the actual start of the next epoch once the current one ends.
+            let epoch_duration = self
+                .storage
+                .app_params()
+                .await
+                .map_err(|e| tonic::Status::internal(format!("could not get app params: {e:#}")))?
+                .sct_params
+                .epoch_duration;
+            (next_height + epoch_duration, epoch_duration)
+        } else if start_height == 0 {
+            // Default to starting at the next block.
+            (next_height, interval)
+        } else {
+            (start_height, interval)
+        };
+
+        let schedule_id = self
+            .storage
+            .create_schedule(
+                plan_request,
+                start_height,
+                interval,
+                max_occurrences,
+                every_epoch,
+            )
+            .await
+            .map_err(|e| tonic::Status::internal(format!("error creating schedule: {e}")))?;
+
+        Ok(tonic::Response::new(pb::CreateScheduleResponse {
+            schedule_id,
+        }))
+    }
+
+    #[instrument(skip_all, level = "trace")]
+    async fn schedules(
+        &self,
+        request: tonic::Request<pb::SchedulesRequest>,
+    ) -> Result<tonic::Response<Self::SchedulesStream>, tonic::Status> {
+        self.check_worker().await?;
+
+        let schedules = self
+            .storage
+            .schedules(request.into_inner().schedule_ids)
+            .await
+            .map_err(|e| tonic::Status::unavailable(format!("error getting schedules: {e}")))?;
+
+        let storage = self.storage.clone();
+        let stream = try_stream! {
+            for schedule in schedules {
+                let executions = storage.schedule_executions(schedule.id).await?;
+                yield pb::SchedulesResponse {
+                    schedule: Some(schedule.into()),
+                    executions: executions.into_iter().map(Into::into).collect(),
+                }
+            }
+        };
+
+        Ok(tonic::Response::new(
+            stream
+                .map_err(|e: anyhow::Error| {
+                    tonic::Status::unavailable(format!("error getting schedules: {e}"))
+                })
+                .boxed(),
+        ))
+    }
+
+    #[instrument(skip_all, level = "trace")]
+    async fn update_schedule_status(
+        &self,
+        request: tonic::Request<pb::UpdateScheduleStatusRequest>,
+    ) -> Result<tonic::Response<pb::UpdateScheduleStatusResponse>, tonic::Status> {
+        self.check_worker().await?;
+
+        let pb::UpdateScheduleStatusRequest {
+            schedule_id,
+            status,
+        } = request.into_inner();
+
+        let status: ScheduleStatus = pb::ScheduleStatus::try_from(status)
+            .map_err(|_| anyhow!("invalid schedule status {}", status))
+            .and_then(TryInto::try_into)
+            .map_err(|e| tonic::Status::invalid_argument(format!("{:#}", e)))?;
+
+        if status == ScheduleStatus::Completed {
+            return Err(tonic::Status::invalid_argument(
+                "schedules can only be set active, paused or cancelled",
+            ));
+        }
+
+        self.storage
+            .update_schedule_status(schedule_id, status)
+            .await
+            .map_err(|e| tonic::Status::failed_precondition(format!("{:#}", e)))?;
+
+        Ok(tonic::Response::new(pb::UpdateScheduleStatusResponse {}))
+    }
+
+    #[instrument(skip_all, level = "trace")]
+    async fn staking_rewards(
+        &self,
+        request: tonic::Request<pb::StakingRewardsRequest>,
+    ) -> Result<tonic::Response<Self::StakingRewardsStream>, tonic::Status> {
+        self.check_worker().await?;
+
+        let identity_keys = request
+            .into_inner()
+            .identity_keys
+            .into_iter()
+            .map(IdentityKey::try_from)
+            .collect::<anyhow::Result<BTreeSet<_>>>()
+            .map_err(|e| e.context("could not decode identity key"))
+            .map_err(|e| tonic::Status::invalid_argument(format!("{:#}", e)))?;
+        let is_requested = |identity_key: &IdentityKey| {
+            identity_keys.is_empty() || identity_keys.contains(identity_key)
+        };
+
+        // Replay the wallet's delegation history, in the order it happened.
+        let mut transactions =
+            self.storage.transactions(None, None).await.map_err(|e| {
+                tonic::Status::unavailable(format!("error getting transactions: {e}"))
+            })?;
+        transactions.sort_by_key(|(height, _, _)| *height);
+
+        let mut trackers = BTreeMap::<IdentityKey, StakingRewardsTracker>::new();
+        for (height, _, transaction) in transactions {
+            // Only the wallet's own notes are found, telling its actions apart from those of
+            // other parties to the transaction.
+            let mut spent = BTreeSet::new();
+            for nullifier in transaction.spent_nullifiers() {
+                if let Ok(record) = self.storage.note_by_nullifier(nullifier, false).await {
+                    spent.insert(record.note.asset_id());
+                }
+            }
+            let mut created = BTreeSet::new();
+            for commitment in transaction.state_commitments() {
+                if let Ok(record) = self.storage.note_by_commitment(commitment, false).await {
+                    created.insert(record.note.asset_id());
+                }
+            }
+            staking_rewards::record_actions(
+                &mut trackers,
+                height,
+                transaction.actions(),
+                &spent,
+                &created,
+            );
+        }
+
+        // Total the delegation tokens held across all accounts.
+        let mut holdings = BTreeMap::<IdentityKey, Amount>::new();
+        let balances = self
+            .storage
+            .balances(None, None)
+            .await
+            .map_err(|e| tonic::Status::unavailable(format!("error getting balances: {e}")))?;
+        for balance in balances {
+            let metadata = self.storage.asset_by_id(&balance.id).await.map_err(|e| {
+                tonic::Status::unavailable(format!("error getting asset metadata: {e}"))
+            })?;
+            if let Some(Ok(token)) = metadata.map(DelegationToken::try_from) {
+                *holdings.entry(token.validator()).or_default() += Amount::from(balance.amount);
+            }
+        }
+        for identity_key in holdings.keys() {
+            trackers.entry(*identity_key).or_default();
+        }
+        trackers.retain(|identity_key, _| is_requested(identity_key));
+
+        let epoch_duration = self
+            .storage
+            .app_params()
+            .await
+            .map_err(|e| tonic::Status::unavailable(format!("error getting app params: {e}")))?
+            .sct_params
+            .epoch_duration;
+        let sync_height = self
+            .storage
+            .last_sync_height()
+            .await
+            .map_err(|e| tonic::Status::unavailable(format!("error getting sync height: {e}")))?
+            .unwrap_or_default();
+        let channel = Channel::from_shared(self.node.to_string())
+            .map_err(|e| tonic::Status::invalid_argument(e.to_string()))?
+            .connect()
+            .await
+            .map_err(|e| tonic::Status::unavailable(e.to_string()))?;
+
+        // Time epochs by the chain's recent block times, measured over the last epoch.
+        let epochs_per_year =
+            match crate::observed_block_time(channel.clone(), sync_height, epoch_duration).await {
+                Ok(block_time) => staking_rewards::epochs_per_year(block_time, epoch_duration),
+                Err(error) => {
+                    tracing::warn!(?error, "can't estimate block time, not projecting returns");
+                    0.0
+                }
+            };
+        let current_epoch_index = SctQueryServiceClient::new(channel.clone())
+            .epoch_by_height(EpochByHeightRequest {
+                height: sync_height,
+            })
+            .await?
+            .into_inner()
+            .epoch
+            .map(|epoch| epoch.index)
+            .unwrap_or_default();
+        let start_epoch_index =
+            current_epoch_index.saturating_sub(staking_rewards::apr_window_epochs(epochs_per_year));
+        let mut client = StakeQueryServiceClient::new(channel);
+
+        let stream = try_stream! {
+            for (identity_key, tracker) in trackers {
+                let rate_data: RateData = client
+                    .current_validator_rate(CurrentValidatorRateRequest {
+                        identity_key: Some(identity_key.into()),
+                    })
+                    .await?
+                    .into_inner()
+                    .data
+                    .ok_or_else(|| anyhow!("empty CurrentValidatorRateResponse message"))?
+                    .try_into()?;
+                let rate_history = client
+                    .validator_rate_history(ValidatorRateHistoryRequest {
+                        identity_key: Some(identity_key.into()),
+                        start_epoch_index,
+                        limit: 0,
+                    })
+                    .await?
+                    .into_inner()
+                    .history
+                    .into_iter()
+                    .map(|entry| {
+                        let rate_data: RateData = entry
+                            .rate_data
+                            .ok_or_else(|| anyhow!("missing rate data"))?
+                            .try_into()?;
+                        anyhow::Ok((entry.epoch_index, rate_data))
+                    })
+                    .collect::<anyhow::Result<Vec<_>>>()?;
+                let held = holdings.get(&identity_key).cloned().unwrap_or_default();
+
+                yield pb::StakingRewardsResponse {
+                    rewards: Some(
+                        tracker
+                            .finish(rate_data, &rate_history, held, epochs_per_year)
+                            .into(),
+                    ),
+                }
+            }
+        };
+
+        Ok(tonic::Response::new(
+            stream
+                .map_err(|e: anyhow::Error| {
+                    tonic::Status::unavailable(format!("error getting staking rewards: {e}"))
+                })
+                .boxed(),
+        ))
+    }
+
+    #[instrument(skip_all, level = "trace")]
+    async fn governance_feed(
+        &self,
+        request: tonic::Request<pb::GovernanceFeedRequest>,
+    ) -> Result<tonic::Response<Self::GovernanceFeedStream>, tonic::Status> {
+        self.check_worker().await?;
+
+        let pb::GovernanceFeedRequest {
+            inactive,
+            actionable_only,
+            mark_seen,
+        } = request.into_inner();
+
+        let items = self
+            .governance_feed_items(inactive, actionable_only)
+            .await
+            .map_err(|e| tonic::Status::unavailable(format!("error getting proposals: {e:#}")))?;
+
+        if mark_seen {
+            self.storage
+                .record_seen_proposal_states(
+                    items
+                        .iter()
+                        .map(|(item, encoded_state)| (item.proposal.id, encoded_state.clone()))
+                        .collect(),
+                )
+                .await
+                .map_err(|e| {
+                    tonic::Status::unavailable(format!("error recording proposal states: {e:#}"))
+                })?;
+        }
+
+        let stream = try_stream! {
+            for (item, _) in items {
+                yield pb::GovernanceFeedResponse {
+                    item: Some(item.into()),
+                }
+            }
+        };
+
+        Ok(tonic::Response::new(
+            stream
+                .map_err(|e: anyhow::Error| {
+                    tonic::Status::unavailable(format!("error getting proposals: {e}"))
+                })
+                .boxed(),
+        ))
+    }
+
+    #[instrument(skip_all, level = "trace")]
+    async fn ics20_withdrawals(
+        &self,
+        request: tonic::Request<pb::Ics20WithdrawalsRequest>,
+    ) -> Result<tonic::Response<Self::Ics20WithdrawalsStream>, tonic::Status> {
+        self.check_worker().await?;
+
+        let pb::Ics20WithdrawalsRequest {
+            transaction_id,
+            settled,
+        } = request.into_inner();
+
+        let transaction_id = transaction_id
+            .map(TryInto::try_into)
+            .transpose()
+            .map_err(|e: anyhow::Error| tonic::Status::invalid_argument(format!("{:#}", e)))?;
+
+        let withdrawals = self
+            .storage
+            .ics20_withdrawals(transaction_id)
+            .await
+            .map_err(|e| {
+                tonic::Status::unavailable(format!("error getting ics20 withdrawals: {e:#}"))
+            })?;
+
+        // Withdrawals past their timeout are reported as timed out until they are refunded.
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_err(|e| tonic::Status::internal(format!("error getting current time: {e}")))?
+            .as_nanos() as u64;
+
+        let stream = stream::iter(
+            withdrawals
+                .into_iter()
+                .map(|record| record.tap_mut(|record| record.check_timeout(now)))
+                .filter(move |record| settled || !record.status.is_settled())
+                .map(|record| {
+                    Ok(pb::Ics20WithdrawalsResponse {
+                        withdrawal: Some(record.into()),
+                    })
+                }),
+        );
+
+        Ok(tonic::Response::new(stream.boxed()))
+    }
+
+    #[instrument(skip_all, level = "trace")]
+    async fn add_forwarding_account(
+        &self,
+        request: tonic::Request<pb::AddForwardingAccountRequest>,
+    ) -> Result<tonic::Response<pb::AddForwardingAccountResponse>, tonic::Status> {
+        self.check_worker().await?;
+
+        let pb::AddForwardingAccountRequest {
+            chain_id,
+            node,
+            channel,
+            address_index,
+            registered,
+        } = request.into_inner();
+
+        if chain_id.is_empty() || channel.is_empty() {
+            return Err(tonic::Status::invalid_argument(
+                "forwarding accounts require a chain id and a channel",
+            ));
+        }
+        let node: Url = node
+            .parse()
+            .map_err(|e| tonic::Status::invalid_argument(format!("invalid node url: {e}")))?;
+        let address_index: AddressIndex = address_index
+            .ok_or_else(|| tonic::Status::invalid_argument("missing address index"))?
+            .try_into()
+            .map_err(|e| tonic::Status::invalid_argument(format!("{:#}", e)))?;
+
+        let fvk =
+            self.storage.full_viewing_key().await.map_err(|_| {
+                tonic::Status::failed_precondition("Error retrieving full viewing key")
+            })?;
+        let (address, _dtk) = fvk.payment_address(address_index);
+
+        let account = ForwardingAccount {
+            chain_id,
+            node,
+            forwarding_address: address.noble_forwarding_address(&channel).to_string(),
+            channel,
+            address_index,
+            registered,
+        };
+
+        self.storage
+            .record_forwarding_account(account.clone())
+            .await
+            .map_err(|e| {
+                tonic::Status::internal(format!("error recording forwarding account: {e:#}"))
+            })?;
+
+        Ok(tonic::Response::new(pb::AddForwardingAccountResponse {
+            account: Some(account.into()),
+        }))
+    }
+
+    #[instrument(skip_all, level = "trace")]
+    async fn forwarding_accounts(
+        &self,
+        request: tonic::Request<pb::ForwardingAccountsRequest>,
+    ) -> Result<tonic::Response<Self::ForwardingAccountsStream>, tonic::Status> {
+        self.check_worker().await?;
+
+        let pb::ForwardingAccountsRequest {
+            chain_id,
+            check_pending,
+        } = request.into_inner();
+
+        let accounts = self
+            .storage
+            .forwarding_accounts((!chain_id.is_empty()).then_some(chain_id))
+            .await
+            .map_err(|e| {
+                tonic::Status::unavailable(format!("error getting forwarding accounts: {e:#}"))
+            })?;
+
+        let stream = try_stream! {
+            for account in accounts {
+                let pending_deposits = if check_pending {
+                    pending_deposits(&account).await.with_context(|| {
+                        format!("could not query {} for pending deposits", account.chain_id)
+                    })?
+                } else {
+                    Vec::new()
+                };
+                yield pb::ForwardingAccountsResponse {
+                    account: Some(account.into()),
+                    pending_deposits,
+                }
+            }
+        };
+
+        Ok(tonic::Response::new(
+            stream
+                .map_err(|e: anyhow::Error| {
+                    tonic::Status::unavailable(format!("error getting forwarding accounts: {e:#}"))
+                })
+                .boxed(),
+        ))
+    }
+
     #[instrument(skip_all, level = "trace")]
     async fn authorize_and_build(
         &self,
@@ -0,0 +1,574 @@
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    time::Duration,
+};
+
+use anyhow::anyhow;
+use penumbra_asset::asset;
+use penumbra_num::{fixpoint::U128x128, Amount};
+use penumbra_proto::{view::v1 as pb, DomainType};
+use penumbra_stake::{rate::RateData, DelegationToken, IdentityKey};
+use penumbra_transaction::Action;
+use serde::{Deserialize, Serialize};
+
+const SECONDS_PER_YEAR: f64 = 365.0 * 24.0 * 60.0 * 60.0;
+
+/// The period over which the growth of a validator's exchange rate is annualized.
+const APR_WINDOW: Duration = Duration::from_secs(30 * 24 * 60 * 60);
+
+/// Whether a [`StakingRewardsEvent`] acquired or disposed of delegation tokens.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StakingRewardsEventKind {
+    Delegation,
+    Undelegation,
+}
+
+/// A change in the wallet's delegation to a validator.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(try_from = "pb::StakingRewardsEvent", into = "pb::StakingRewardsEvent")]
+pub struct StakingRewardsEvent {
+    pub kind: StakingRewardsEventKind,
+    pub height: u64,
+    /// The epoch whose exchange rate applied to the change.
+    pub epoch_index: u64,
+    pub delegation_amount: Amount,
+    pub unbonded_amount: Amount,
+    /// For undelegations, the rewards realized.
+    pub realized_rewards: Amount,
+}
+
+/// The staking rewards earned by the wallet from delegations to a validator,
+/// derived from its history of delegations and undelegations.
+///
+/// Delegation tokens acquired other than by delegating have no known cost, and
+/// are excluded from the reward accounting.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(try_from = "pb::StakingRewards", into = "pb::StakingRewards")]
+pub struct StakingRewards {
+    pub identity_key: IdentityKey,
+    pub current_rate_data: RateData,
+    /// The amount of delegation tokens currently held by the wallet.
+    pub delegation_tokens: Amount,
+    /// The value of the held delegation tokens at the current exchange rate.
+    pub current_value: Amount,
+    /// The amount of stake originally delegated for the held delegation tokens.
+    pub cost_basis: Amount,
+    pub realized_rewards: Amount,
+    pub unrealized_rewards: Amount,
+    /// The projected annual rate of return, in basis points, annualized from the growth of the
+    /// validator's exchange rate over the recent epochs.
+    pub projected_apr_bps: u64,
+    pub history: Vec<StakingRewardsEvent>,
+}
+
+/// Accumulates the history of the wallet's delegations to a validator into [`StakingRewards`].
+///
+/// The cost basis of the delegation tokens is tracked at their average cost.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct StakingRewardsTracker {
+    /// The delegation tokens acquired by delegating and not yet disposed of.
+    tracked: Amount,
+    cost_basis: Amount,
+    realized_rewards: Amount,
+    history: Vec<StakingRewardsEvent>,
+}
+
+impl StakingRewardsTracker {
+    pub fn record_delegation(
+        &mut self,
+        height: u64,
+        epoch_index: u64,
+        delegation_amount: Amount,
+        unbonded_amount: Amount,
+    ) {
+        self.tracked += delegation_amount;
+        self.cost_basis += unbonded_amount;
+        self.history.push(StakingRewardsEvent {
+            kind: StakingRewardsEventKind::Delegation,
+            height,
+            epoch_index,
+            delegation_amount,
+            unbonded_amount,
+            realized_rewards: Amount::zero(),
+        });
+    }
+
+    pub fn record_undelegation(
+        &mut self,
+        height: u64,
+        epoch_index: u64,
+        delegation_amount: Amount,
+        unbonded_amount: Amount,
+    ) {
+        // Only the tokens with a known cost contribute to the realized rewards.
+        let covered = delegation_amount.min(self.tracked);
+        let cost = share(self.cost_basis, covered, self.tracked);
+        let proceeds = share(unbonded_amount, covered, delegation_amount);
+        let realized_rewards = proceeds.saturating_sub(&cost);
+
+        self.tracked -= covered;
+        self.cost_basis -= cost;
+        self.realized_rewards += realized_rewards;
+        self.history.push(StakingRewardsEvent {
+            kind: StakingRewardsEventKind::Undelegation,
+            height,
+            epoch_index,
+            delegation_amount,
+            unbonded_amount,
+            realized_rewards,
+        });
+    }
+
+    /// Values the `delegation_tokens` currently held using the validator's current rate data,
+    /// projecting its returns from its `rate_history` of recent epochs.
+    pub fn finish(
+        self,
+        current_rate_data: RateData,
+        rate_history: &[(u64, RateData)],
+        delegation_tokens: Amount,
+        epochs_per_year: f64,
+    ) -> StakingRewards {
+        // Tokens may have been transferred away, so the held tokens only
+        // carry the cost basis of the tracked tokens they cover.
+        let covered = delegation_tokens.min(self.tracked);
+        let cost_basis = share(self.cost_basis, covered, self.tracked);
+        let unrealized_rewards = current_rate_data
+            .unbonded_amount(covered)
+            .saturating_sub(&cost_basis);
+
+        StakingRewards {
+            identity_key: current_rate_data.identity_key,
+            current_value: current_rate_data.unbonded_amount(delegation_tokens),
+            projected_apr_bps: projected_apr_bps(&current_rate_data, rate_history, epochs_per_year),
+            current_rate_data,
+            delegation_tokens,
+            cost_basis,
+            realized_rewards: self.realized_rewards,
+            unrealized_rewards,
+            history: self.history,
+        }
+    }
+}
+
+/// Computes `amount * part / whole`, for `part <= whole`.
+fn share(amount: Amount, part: Amount, whole: Amount) -> Amount {
+    if part == whole {
+        return amount;
+    }
+    U128x128::ratio(part, whole)
+        .and_then(|ratio| ratio.apply_to_amount(&amount))
+        .unwrap_or_default()
+}
+
+/// Records the changes to the wallet's delegations made by the `actions` of a transaction at
+/// `height`, given the assets of the wallet's notes that the transaction `spent` and `created`.
+///
+/// A transaction may include the actions of other parties, so a delegation is only recorded if
+/// the wallet received its delegation tokens, and an undelegation if the wallet spent them.
+pub(crate) fn record_actions<'a>(
+    trackers: &mut BTreeMap<IdentityKey, StakingRewardsTracker>,
+    height: u64,
+    actions: impl IntoIterator<Item = &'a Action>,
+    spent: &BTreeSet<asset::Id>,
+    created: &BTreeSet<asset::Id>,
+) {
+    let token = |identity_key: &IdentityKey| DelegationToken::new(*identity_key).id();
+    for action in actions {
+        match action {
+            Action::Delegate(d) if created.contains(&token(&d.validator_identity)) => trackers
+                .entry(d.validator_identity)
+                .or_default()
+                .record_delegation(
+                    height,
+                    d.epoch_index,
+                    d.delegation_amount,
+                    d.unbonded_amount,
+                ),
+            Action::Undelegate(u) if spent.contains(&token(&u.validator_identity)) => trackers
+                .entry(u.validator_identity)
+                .or_default()
+                .record_undelegation(
+                    height,
+                    u.from_epoch.index,
+                    u.delegation_amount,
+                    u.unbonded_amount,
+                ),
+            Action::Redelegate(r) => {
+                if spent.contains(&token(&r.source_validator)) {
+                    trackers
+                        .entry(r.source_validator)
+                        .or_default()
+                        .record_undelegation(
+                            height,
+                            r.epoch_index,
+                            r.source_delegation_amount,
+                            r.unbonded_amount,
+                        );
+                }
+                if created.contains(&token(&r.destination_validator)) {
+                    trackers
+                        .entry(r.destination_validator)
+                        .or_default()
+                        .record_delegation(
+                            height,
+                            r.epoch_index,
+                            r.destination_delegation_amount,
+                            r.unbonded_amount,
+                        );
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Returns the number of epochs in a year, given the observed `block_time`.
+///
+/// Epochs may end early, so this is a lower bound.
+pub(crate) fn epochs_per_year(block_time: Duration, epoch_duration: u64) -> f64 {
+    let epoch_seconds = block_time.as_secs_f64() * epoch_duration as f64;
+    if epoch_seconds == 0.0 {
+        return 0.0;
+    }
+    SECONDS_PER_YEAR / epoch_seconds
+}
+
+/// Returns the number of epochs whose rate data the projected returns are annualized from.
+pub(crate) fn apr_window_epochs(epochs_per_year: f64) -> u64 {
+    (epochs_per_year * APR_WINDOW.as_secs_f64() / SECONDS_PER_YEAR)
+        .ceil()
+        .max(1.0) as u64
+}
+
+/// Annualizes the growth of the validator's exchange rate over its `rate_history`, compounding
+/// every epoch.
+///
+/// Without enough history to measure the growth over, the validator's current reward rate is
+/// annualized instead. Slashing penalties reduce the exchange rate, so a validator slashed over
+/// the history is projected to return less, down to nothing.
+fn projected_apr_bps(
+    current_rate_data: &RateData,
+    rate_history: &[(u64, RateData)],
+    epochs_per_year: f64,
+) -> u64 {
+    let growth_per_epoch = match (rate_history.first(), rate_history.last()) {
+        (Some((first_epoch, first)), Some((last_epoch, last))) if last_epoch > first_epoch => {
+            let growth = last.validator_exchange_rate.value() as f64
+                / first.validator_exchange_rate.value() as f64;
+            growth.powf(1.0 / (last_epoch - first_epoch) as f64)
+        }
+        // Reward rates are expressed in basis points squared.
+        _ => 1.0 + current_rate_data.validator_reward_rate.value() as f64 / 1_0000_0000.0,
+    };
+    let apr = growth_per_epoch.powf(epochs_per_year) - 1.0;
+    (apr.max(0.0) * 10_000.0).round() as u64
+}
+
+impl DomainType for StakingRewardsEvent {
+    type Proto = pb::StakingRewardsEvent;
+}
+
+impl From<StakingRewardsEvent> for pb::StakingRewardsEvent {
+    fn from(msg: StakingRewardsEvent) -> Self {
+        let kind = match msg.kind {
+            StakingRewardsEventKind::Delegation => pb::staking_rewards_event::Kind::Delegation,
+            StakingRewardsEventKind::Undelegation => pb::staking_rewards_event::Kind::Undelegation,
+        };
+        pb::StakingRewardsEvent {
+            kind: kind as i32,
+            height: msg.height,
+            epoch_index: msg.epoch_index,
+            delegation_amount: Some(msg.delegation_amount.into()),
+            unbonded_amount: Some(msg.unbonded_amount.into()),
+            realized_rewards: Some(msg.realized_rewards.into()),
+        }
+    }
+}
+
+impl TryFrom<pb::StakingRewardsEvent> for StakingRewardsEvent {
+    type Error = anyhow::Error;
+
+    fn try_from(msg: pb::StakingRewardsEvent) -> Result<Self, Self::Error> {
+        let kind = match pb::staking_rewards_event::Kind::try_from(msg.kind) {
+            Ok(pb::staking_rewards_event::Kind::Delegation) => StakingRewardsEventKind::Delegation,
+            Ok(pb::staking_rewards_event::Kind::Undelegation) => {
+                StakingRewardsEventKind::Undelegation
+            }
+            _ => return Err(anyhow!("invalid staking rewards event kind {}", msg.kind)),
+        };
+        Ok(Self {
+            kind,
+            height: msg.height,
+            epoch_index: msg.epoch_index,
+            delegation_amount: msg
+                .delegation_amount
+                .ok_or_else(|| anyhow!("missing delegation amount"))?
+                .try_into()?,
+            unbonded_amount: msg
+                .unbonded_amount
+                .ok_or_else(|| anyhow!("missing unbonded amount"))?
+                .try_into()?,
+            realized_rewards: msg
+                .realized_rewards
+                .ok_or_else(|| anyhow!("missing realized rewards"))?
+                .try_into()?,
+        })
+    }
+}
+
+impl DomainType for StakingRewards {
+    type Proto = pb::StakingRewards;
+}
+
+impl From<StakingRewards> for pb::StakingRewards {
+    fn from(msg: StakingRewards) -> Self {
+        pb::StakingRewards {
+            identity_key: Some(msg.identity_key.into()),
+            current_rate_data: Some(msg.current_rate_data.into()),
+            delegation_tokens: Some(msg.delegation_tokens.into()),
+            current_value: Some(msg.current_value.into()),
+            cost_basis: Some(msg.cost_basis.into()),
+            realized_rewards: Some(msg.realized_rewards.into()),
+            unrealized_rewards: Some(msg.unrealized_rewards.into()),
+            projected_apr_bps: msg.projected_apr_bps,
+            history: msg.history.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+impl TryFrom<pb::StakingRewards> for StakingRewards {
+    type Error = anyhow::Error;
+
+    fn try_from(msg: pb::StakingRewards) -> Result<Self, Self::Error> {
+        let amount = |amount: Option<penumbra_proto::core::num::v1::Amount>, name: &str| {
+            amount
+                .ok_or_else(|| anyhow!("missing {}", name))?
+                .try_into()
+        };
+        Ok(Self {
+            identity_key: msg
+                .identity_key
+                .ok_or_else(|| anyhow!("missing identity key"))?
+                .try_into()?,
+            current_rate_data: msg
+                .current_rate_data
+                .ok_or_else(|| anyhow!("missing current rate data"))?
+                .try_into()?,
+            delegation_tokens: amount(msg.delegation_tokens, "delegation tokens")?,
+            current_value: amount(msg.current_value, "current value")?,
+            cost_basis: amount(msg.cost_basis, "cost basis")?,
+            realized_rewards: amount(msg.realized_rewards, "realized rewards")?,
+            unrealized_rewards: amount(msg.unrealized_rewards, "unrealized rewards")?,
+            projected_apr_bps: msg.projected_apr_bps,
+            history: msg
+                .history
+                .into_iter()
+                .map(TryInto::try_into)
+                .collect::<Result<_, _>>()?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use penumbra_sct::epoch::Epoch;
+    use penumbra_stake::{Delegate, Redelegate, Undelegate};
+
+    use super::*;
+
+    fn identity_key(byte: u8) -> IdentityKey {
+        penumbra_proto::core::keys::v1::IdentityKey { ik: vec![byte; 32] }
+            .try_into()
+            .expect("identity key is valid")
+    }
+
+    fn token(identity_key: IdentityKey) -> asset::Id {
+        DelegationToken::new(identity_key).id()
+    }
+
+    fn rate_data(identity_key: IdentityKey, reward_rate: u64, exchange_rate: u64) -> RateData {
+        RateData {
+            identity_key,
+            validator_reward_rate: reward_rate.into(),
+            validator_exchange_rate: exchange_rate.into(),
+        }
+    }
+
+    fn delegate(validator: IdentityKey, delegation_amount: u64, unbonded_amount: u64) -> Action {
+        Action::Delegate(Delegate {
+            validator_identity: validator,
+            epoch_index: 1,
+            unbonded_amount: unbonded_amount.into(),
+            delegation_amount: delegation_amount.into(),
+        })
+    }
+
+    fn undelegate(validator: IdentityKey, delegation_amount: u64, unbonded_amount: u64) -> Action {
+        Action::Undelegate(Undelegate {
+            validator_identity: validator,
+            from_epoch: Epoch {
+                index: 2,
+                start_height: 100,
+            },
+            unbonded_amount: unbonded_amount.into(),
+            delegation_amount: delegation_amount.into(),
+        })
+    }
+
+    #[test]
+    fn rewards_are_realized_at_average_cost() {
+        let mut tracker = StakingRewardsTracker::default();
+        tracker.record_delegation(1, 1, 100u64.into(), 100u64.into());
+        tracker.record_delegation(2, 2, 100u64.into(), 200u64.into());
+        // Half of the tokens, with a cost of 150, are undelegated for 250.
+        tracker.record_undelegation(3, 3, 100u64.into(), 250u64.into());
+
+        let validator = identity_key(1);
+        let rewards = tracker.finish(
+            rate_data(validator, 0, 3_0000_0000),
+            &[],
+            100u64.into(),
+            0.0,
+        );
+        assert_eq!(rewards.realized_rewards, 100u64.into());
+        assert_eq!(rewards.cost_basis, 150u64.into());
+        assert_eq!(rewards.current_value, 300u64.into());
+        assert_eq!(rewards.unrealized_rewards, 150u64.into());
+        assert_eq!(rewards.history.len(), 3);
+    }
+
+    #[test]
+    fn tokens_without_a_known_cost_earn_no_rewards() {
+        let mut tracker = StakingRewardsTracker::default();
+        tracker.record_delegation(1, 1, 100u64.into(), 100u64.into());
+
+        // Another 100 tokens were received in a transfer, and are valued but not costed.
+        let validator = identity_key(1);
+        let rewards = tracker.finish(
+            rate_data(validator, 0, 2_0000_0000),
+            &[],
+            200u64.into(),
+            0.0,
+        );
+        assert_eq!(rewards.cost_basis, 100u64.into());
+        assert_eq!(rewards.current_value, 400u64.into());
+        assert_eq!(rewards.unrealized_rewards, 100u64.into());
+    }
+
+    #[test]
+    fn only_the_wallets_actions_are_recorded() {
+        let ours = identity_key(1);
+        let theirs = identity_key(2);
+        let actions = [
+            delegate(ours, 100, 100),
+            delegate(theirs, 100, 100),
+            undelegate(ours, 10, 11),
+            undelegate(theirs, 10, 11),
+        ];
+        // The wallet received the delegation tokens of one delegation, and spent those of
+        // one undelegation; the others belong to another party to the transaction.
+        let spent = BTreeSet::from([token(ours)]);
+        let created = BTreeSet::from([token(ours)]);
+
+        let mut trackers = BTreeMap::new();
+        record_actions(&mut trackers, 10, &actions, &spent, &created);
+
+        assert_eq!(trackers.keys().copied().collect::<Vec<_>>(), vec![ours]);
+        let history = &trackers[&ours].history;
+        assert_eq!(history.len(), 2);
+        assert_eq!(history[0].kind, StakingRewardsEventKind::Delegation);
+        assert_eq!(history[1].kind, StakingRewardsEventKind::Undelegation);
+    }
+
+    #[test]
+    fn redelegations_are_recorded_on_the_wallets_side() {
+        let source = identity_key(1);
+        let destination = identity_key(2);
+        let actions = [Action::Redelegate(Redelegate {
+            source_validator: source,
+            destination_validator: destination,
+            epoch_index: 3,
+            source_delegation_amount: 100u64.into(),
+            unbonded_amount: 120u64.into(),
+            destination_delegation_amount: 110u64.into(),
+        })];
+
+        // The wallet spent the source delegation tokens, but the destination tokens went to
+        // someone else.
+        let mut trackers = BTreeMap::new();
+        record_actions(
+            &mut trackers,
+            10,
+            &actions,
+            &BTreeSet::from([token(source)]),
+            &BTreeSet::new(),
+        );
+        assert_eq!(trackers.keys().copied().collect::<Vec<_>>(), vec![source]);
+
+        let mut trackers = BTreeMap::new();
+        record_actions(
+            &mut trackers,
+            10,
+            &actions,
+            &BTreeSet::from([token(source)]),
+            &BTreeSet::from([token(destination)]),
+        );
+        assert_eq!(
+            trackers.keys().copied().collect::<Vec<_>>(),
+            vec![source, destination]
+        );
+        assert_eq!(
+            trackers[&destination].history[0].kind,
+            StakingRewardsEventKind::Delegation
+        );
+    }
+
+    #[test]
+    fn apr_is_annualized_from_the_rate_history() {
+        let validator = identity_key(1);
+        // The exchange rate grew by 1% per epoch over two epochs, with 10 epochs per year.
+        let history = [
+            (5, rate_data(validator, 0, 1_0000_0000)),
+            (6, rate_data(validator, 0, 1_0100_0000)),
+            (7, rate_data(validator, 0, 1_0201_0000)),
+        ];
+        let current = rate_data(validator, 5_0000, 1_0201_0000);
+        // 1.01^10 - 1 = 10.46%
+        assert_eq!(projected_apr_bps(&current, &history, 10.0), 1046);
+    }
+
+    #[test]
+    fn apr_falls_back_to_the_current_reward_rate() {
+        let validator = identity_key(1);
+        // A reward rate of 1% per epoch, with 10 epochs per year.
+        let current = rate_data(validator, 100_0000, 1_0000_0000);
+        assert_eq!(projected_apr_bps(&current, &[], 10.0), 1046);
+        assert_eq!(
+            projected_apr_bps(&current, &[(7, current.clone())], 10.0),
+            1046
+        );
+    }
+
+    #[test]
+    fn slashed_validators_project_no_returns() {
+        let validator = identity_key(1);
+        let history = [
+            (5, rate_data(validator, 0, 1_0000_0000)),
+            (6, rate_data(validator, 0, 9000_0000)),
+        ];
+        let current = rate_data(validator, 100_0000, 9000_0000);
+        assert_eq!(projected_apr_bps(&current, &history, 10.0), 0);
+    }
+
+    #[test]
+    fn epochs_are_timed_by_the_observed_block_time() {
+        // 5 second blocks and 1000 block epochs make for 6307.2 epochs per year.
+        let epochs = epochs_per_year(Duration::from_secs(5), 1000);
+        assert!((epochs - 6307.2).abs() < 1e-6);
+        assert_eq!(epochs_per_year(Duration::ZERO, 1000), 0.0);
+
+        // 30 days of such epochs.
+        assert_eq!(apr_window_epochs(epochs), 519);
+        assert_eq!(apr_window_epochs(0.0), 1);
+    }
+}
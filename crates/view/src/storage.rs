@@ -1,7 +1,7 @@
 use std::{collections::BTreeMap, num::NonZeroU64, str::FromStr, sync::Arc, time::Duration};
 
 use anyhow::{anyhow, Context};
-use camino::Utf8Path;
+use camino::{Utf8Path, Utf8PathBuf};
 use decaf377::Fq;
 use once_cell::sync::Lazy;
 use parking_lot::Mutex;
@@ -22,27 +22,42 @@ use url::Url;
 use penumbra_app::params::AppParameters;
 use penumbra_asset::{asset, asset::Id, asset::Metadata, Value};
 use penumbra_dex::{
-    lp::position::{self, Position, State},
+    lp::{
+        position::{self, Position, State},
+        PositionExecutionSummary, Reserves,
+    },
     TradingPair,
 };
 use penumbra_fee::GasPrices;
+use penumbra_governance::{proposal_state::State as ProposalState, Proposal, Vote};
 use penumbra_keys::{keys::AddressIndex, Address, FullViewingKey};
 use penumbra_num::Amount;
 use penumbra_proto::{
-    core::app::v1::{
-        query_service_client::QueryServiceClient as AppQueryServiceClient, AppParametersRequest,
+    core::{
+        app::v1::{
+            query_service_client::QueryServiceClient as AppQueryServiceClient, AppParametersRequest,
+        },
+        component::ibc::v1::FungibleTokenPacketData,
     },
-    DomainType,
+    view::v1 as pb,
+    DomainType, Message as _,
 };
 use penumbra_sct::{CommitmentSource, Nullifier};
-use penumbra_shielded_pool::{fmd, note, Note, Rseed};
-use penumbra_stake::{DelegationToken, IdentityKey};
+use penumbra_shielded_pool::{fmd, note, Ics20Withdrawal, Note, Rseed};
+use penumbra_stake::{rate::RateData, DelegationToken, IdentityKey};
 use penumbra_tct as tct;
-use penumbra_transaction::Transaction;
+use penumbra_transaction::{txhash::TransactionId, Transaction};
 use sct::TreeStore;
 use tct::StateCommitment;
 
-use crate::{sync::FilteredBlock, SpendableNoteRecord, SwapRecord};
+use crate::{
+    governance_feed::{ProposalRecord, WalletVotes},
+    ics20_withdrawal_record::packet_commitment,
+    sync::FilteredBlock,
+    ForwardingAccount, Ics20WithdrawalRecord, Ics20WithdrawalStatus, PositionPerformance,
+    ScheduleStatus, ScheduledTransaction, ScheduledTransactionExecution, SpendableNoteRecord,
+    SwapRecord,
+};
 
 mod sct;
 
@@ -53,6 +68,16 @@ pub struct BalanceEntry {
     pub address_index: AddressIndex,
 }
 
+/// An ICS-20 withdrawal that is still in flight, as tracked by the view worker.
+#[derive(Debug, Clone)]
+pub struct PendingIcs20Withdrawal {
+    pub tx_hash: [u8; 32],
+    pub action_index: u64,
+    pub channel_id: String,
+    pub packet_commitment: Vec<u8>,
+    pub sequence: Option<u64>,
+}
+
 /// The hash of the schema for the database.
 static SCHEMA_HASH: Lazy<String> =
     Lazy::new(|| hex::encode(Sha256::digest(include_str!("storage/schema.sql"))));
@@ -115,21 +140,38 @@ impl Storage {
         Self::initialize(storage_path, fvk.clone(), params).await
     }
 
+    /// The path of the registry database kept alongside the view database at `storage_path`.
+    ///
+    /// The registry holds the forwarding accounts and scheduled transactions entered by the user,
+    /// which can't be recovered by resynchronizing, so it is left in place when the view database
+    /// is reset.
+    pub fn registry_path(storage_path: impl AsRef<Utf8Path>) -> Utf8PathBuf {
+        storage_path.as_ref().with_extension("registry.sqlite")
+    }
+
     fn connect(
         path: Option<impl AsRef<Utf8Path>>,
     ) -> anyhow::Result<r2d2::Pool<SqliteConnectionManager>> {
         if let Some(path) = path {
+            let registry_path = Self::registry_path(path.as_ref());
             let manager = SqliteConnectionManager::file(path.as_ref())
                 .with_flags(
                     // Don't allow opening URIs, because they can change the behavior of the database; we
                     // just want to open normal filepaths.
                     OpenFlags::default() & !OpenFlags::SQLITE_OPEN_URI,
                 )
-                .with_init(|conn| {
+                .with_init(move |conn| {
+                    conn.execute(
+                        "ATTACH DATABASE ?1 AS registry",
+                        [registry_path.as_str()],
+                    )?;
                     // "NORMAL" will be consistent, but maybe not durable -- this is fine,
                     // since all our data is being synced from the chain, so if we lose a dbtx,
-                    // it's like we're resuming sync from a previous height.
-                    conn.execute_batch("PRAGMA journal_mode=WAL; PRAGMA synchronous=NORMAL;")?;
+                    // it's like we're resuming sync from a previous height. That's not the case
+                    // for the registry, which keeps the default of "FULL".
+                    conn.execute_batch(
+                        "PRAGMA journal_mode=WAL; PRAGMA registry.journal_mode=WAL; PRAGMA synchronous=NORMAL;",
+                    )?;
                     // We use `prepare_cached` a fair amount: this is an overestimate of the number
                     // of cached prepared statements likely to be used.
                     conn.set_prepared_statement_cache_capacity(32);
@@ -141,7 +183,8 @@ impl Storage {
                 .max_size(1)
                 .build(manager)?)
         } else {
-            let manager = SqliteConnectionManager::memory();
+            let manager = SqliteConnectionManager::memory()
+                .with_init(|conn| conn.execute_batch("ATTACH DATABASE ':memory:' AS registry"));
             // Max size needs to be set to 1, otherwise a new in-memory database is created for each
             // connection to the pool, which results in very confusing errors.
             //
@@ -192,6 +235,12 @@ impl Storage {
                 );
             }
 
+            // Create the registry, if it doesn't already exist.
+            storage
+                .pool
+                .get()?
+                .execute_batch(include_str!("storage/registry.sql"))?;
+
             Ok(storage)
         })
             .await?
@@ -212,8 +261,9 @@ impl Storage {
             let mut conn = pool.get()?;
             let tx = conn.transaction()?;
 
-            // Create the tables
+            // Create the tables, and the registry if it doesn't already exist
             tx.execute_batch(include_str!("storage/schema.sql"))?;
+            tx.execute_batch(include_str!("storage/registry.sql"))?;
 
             let params_bytes = params.encode_to_vec();
             tx.execute(
@@ -1183,19 +1233,21 @@ impl Storage {
         .await?
     }
 
-    pub async fn record_position(&self, position: Position) -> anyhow::Result<()> {
+    pub async fn record_position(&self, position: Position, height: u64) -> anyhow::Result<()> {
         let position_id = position.id().0.to_vec();
 
         let position_state = position.state.to_string();
         let trading_pair = position.phi.pair.to_string();
+        let position_bytes = position.encode_to_vec();
+        let height_opened = height as i64;
 
         let pool = self.pool.clone();
 
         spawn_blocking(move || {
             pool.get()?
                 .execute(
-                    "INSERT OR REPLACE INTO positions (position_id, position_state, trading_pair) VALUES (?1, ?2, ?3)",
-                    (position_id, position_state, trading_pair),
+                    "INSERT OR REPLACE INTO positions (position_id, position_state, trading_pair, position, height_opened) VALUES (?1, ?2, ?3, ?4, ?5)",
+                    (position_id, position_state, trading_pair, position_bytes, height_opened),
                 )
                 .map_err(anyhow::Error::from)
         })
@@ -1208,8 +1260,14 @@ impl Storage {
         &self,
         position_id: position::Id,
         position_state: position::State,
+        height: u64,
     ) -> anyhow::Result<()> {
         let position_id = position_id.0.to_vec();
+        // Only the first withdrawal realizes the position's reserves.
+        let height_withdrawn = match position_state {
+            State::Withdrawn { .. } => Some(height as i64),
+            _ => None,
+        };
         let position_state = position_state.to_string();
 
         let pool = self.pool.clone();
@@ -1217,8 +1275,8 @@ impl Storage {
         spawn_blocking(move || {
             pool.get()?
                 .execute(
-                    "UPDATE positions SET (position_state) = ?1 WHERE position_id = ?2",
-                    (position_state, position_id),
+                    "UPDATE positions SET position_state = ?1, height_withdrawn = COALESCE(height_withdrawn, ?2) WHERE position_id = ?3",
+                    (position_state, height_withdrawn, position_id),
                 )
                 .map_err(anyhow::Error::from)
         })
@@ -1227,6 +1285,729 @@ impl Storage {
         Ok(())
     }
 
+    /// Computes the performance of the owned positions with the given IDs, or of all
+    /// owned positions if `position_ids` is empty.
+    pub async fn position_performance(
+        &self,
+        position_ids: Vec<position::Id>,
+    ) -> anyhow::Result<Vec<PositionPerformance>> {
+        let pool = self.pool.clone();
+
+        spawn_blocking(move || {
+            let conn = pool.get()?;
+
+            let positions = conn
+                .prepare_cached(
+                    "SELECT position_state, position, height_opened, height_withdrawn, height_evicted FROM positions ORDER BY height_opened ASC",
+                )?
+                .query_and_then([], |row| {
+                    let state: String = row.get("position_state")?;
+                    let position_bytes: Vec<u8> = row.get("position")?;
+                    let height_opened: u64 = row.get("height_opened")?;
+                    let height_withdrawn: Option<u64> = row.get("height_withdrawn")?;
+                    let height_evicted: Option<u64> = row.get("height_evicted")?;
+                    let mut performance = PositionPerformance::new(
+                        Position::decode(position_bytes.as_slice())?,
+                        height_opened,
+                        State::from_str(&state)?,
+                        height_withdrawn,
+                    );
+                    performance.height_evicted = height_evicted;
+                    anyhow::Ok(performance)
+                })?
+                .filter(|performance| match performance {
+                    Ok(performance) => {
+                        position_ids.is_empty() || position_ids.contains(&performance.position_id)
+                    }
+                    Err(_) => true,
+                })
+                .collect::<anyhow::Result<Vec<_>>>()?;
+
+            let mut executions = conn.prepare_cached(
+                "SELECT prev_reserves_1, prev_reserves_2, reserves_1, reserves_2, inflow_1, inflow_2
+                FROM position_executions WHERE position_id = ?1 ORDER BY height ASC",
+            )?;
+
+            positions
+                .into_iter()
+                .map(|mut performance| {
+                    let rows = executions.query_and_then(
+                        [performance.position_id.0.to_vec()],
+                        |row| {
+                            let reserves = |r1: &str, r2: &str| -> anyhow::Result<Reserves> {
+                                Ok(Reserves {
+                                    r1: Amount::from_be_bytes(row.get::<_, [u8; 16]>(r1)?),
+                                    r2: Amount::from_be_bytes(row.get::<_, [u8; 16]>(r2)?),
+                                })
+                            };
+                            anyhow::Ok(PositionExecutionSummary {
+                                position_id: performance.position_id,
+                                prev_reserves: reserves("prev_reserves_1", "prev_reserves_2")?,
+                                reserves: reserves("reserves_1", "reserves_2")?,
+                                inflows: reserves("inflow_1", "inflow_2")?,
+                            })
+                        },
+                    )?;
+                    for summary in rows {
+                        performance.record_execution(&summary?);
+                    }
+                    anyhow::Ok(performance)
+                })
+                .collect()
+        })
+        .await?
+    }
+
+    /// Stores a new scheduled transaction, returning its ID.
+    pub async fn create_schedule(
+        &self,
+        plan_request: pb::TransactionPlannerRequest,
+        start_height: u64,
+        interval: u64,
+        max_occurrences: u64,
+        every_epoch: bool,
+    ) -> anyhow::Result<u64> {
+        let pool = self.pool.clone();
+
+        spawn_blocking(move || {
+            let conn = pool.get()?;
+            conn.execute(
+                "INSERT INTO scheduled_transactions
+                    (plan_request, start_height, interval, max_occurrences, occurrences, next_height, status, every_epoch)
+                    VALUES (?1, ?2, ?3, ?4, 0, ?2, ?5, ?6)",
+                (
+                    plan_request.encode_to_vec(),
+                    start_height,
+                    interval,
+                    max_occurrences,
+                    ScheduleStatus::Active.to_string(),
+                    every_epoch,
+                ),
+            )?;
+            anyhow::Ok(conn.last_insert_rowid() as u64)
+        })
+        .await?
+    }
+
+    /// Returns the scheduled transactions with the given IDs, or all of them if `schedule_ids` is empty.
+    pub async fn schedules(
+        &self,
+        schedule_ids: Vec<u64>,
+    ) -> anyhow::Result<Vec<ScheduledTransaction>> {
+        let pool = self.pool.clone();
+
+        spawn_blocking(move || {
+            pool.get()?
+                .prepare_cached("SELECT * FROM scheduled_transactions ORDER BY schedule_id ASC")?
+                .query_and_then([], scheduled_transaction_from_row)?
+                .filter(|schedule| match schedule {
+                    Ok(schedule) => schedule_ids.is_empty() || schedule_ids.contains(&schedule.id),
+                    Err(_) => true,
+                })
+                .collect()
+        })
+        .await?
+    }
+
+    /// Returns the active scheduled transactions whose next occurrence is at or before `height`.
+    pub async fn due_schedules(&self, height: u64) -> anyhow::Result<Vec<ScheduledTransaction>> {
+        let pool = self.pool.clone();
+
+        spawn_blocking(move || {
+            pool.get()?
+                .prepare_cached(
+                    "SELECT * FROM scheduled_transactions
+                    WHERE status = ?1 AND next_height <= ?2
+                    ORDER BY next_height ASC, schedule_id ASC",
+                )?
+                .query_and_then(
+                    (ScheduleStatus::Active.to_string(), height),
+                    scheduled_transaction_from_row,
+                )?
+                .collect()
+        })
+        .await?
+    }
+
+    /// Returns the past executions of a scheduled transaction, ordered by height.
+    pub async fn schedule_executions(
+        &self,
+        schedule_id: u64,
+    ) -> anyhow::Result<Vec<ScheduledTransactionExecution>> {
+        let pool = self.pool.clone();
+
+        spawn_blocking(move || {
+            pool.get()?
+                .prepare_cached(
+                    "SELECT height, tx_hash, error FROM scheduled_transaction_executions
+                    WHERE schedule_id = ?1 ORDER BY height ASC",
+                )?
+                .query_and_then([schedule_id], |row| {
+                    let tx_hash: Option<[u8; 32]> = row.get("tx_hash")?;
+                    anyhow::Ok(ScheduledTransactionExecution {
+                        schedule_id,
+                        height: row.get("height")?,
+                        transaction_id: tx_hash.map(TransactionId),
+                        error: row.get("error")?,
+                    })
+                })?
+                .collect()
+        })
+        .await?
+    }
+
+    /// Sets the status of a scheduled transaction.
+    ///
+    /// Cancelled and completed schedules can no longer be updated.
+    pub async fn update_schedule_status(
+        &self,
+        schedule_id: u64,
+        status: ScheduleStatus,
+    ) -> anyhow::Result<()> {
+        let pool = self.pool.clone();
+
+        spawn_blocking(move || {
+            let mut lock = pool.get()?;
+            let tx = lock.transaction()?;
+            let current: String = tx
+                .query_row(
+                    "SELECT status FROM scheduled_transactions WHERE schedule_id = ?1",
+                    [schedule_id],
+                    |row| row.get("status"),
+                )
+                .optional()?
+                .ok_or_else(|| anyhow!("no schedule with id {}", schedule_id))?;
+            let current = ScheduleStatus::from_str(&current)?;
+            if current.is_terminal() {
+                anyhow::bail!("schedule {} is already {}", schedule_id, current);
+            }
+            tx.execute(
+                "UPDATE scheduled_transactions SET status = ?2 WHERE schedule_id = ?1",
+                (schedule_id, status.to_string()),
+            )?;
+            tx.commit()?;
+            anyhow::Ok(())
+        })
+        .await?
+    }
+
+    /// Brings the epoch-aligned schedules forward to the start of the next epoch, at
+    /// `next_epoch_start_height`, once the current epoch has ended.
+    pub async fn align_epoch_schedules(&self, next_epoch_start_height: u64) -> anyhow::Result<()> {
+        let pool = self.pool.clone();
+
+        spawn_blocking(move || {
+            let mut lock = pool.get()?;
+            let tx = lock.transaction()?;
+            let schedules = tx
+                .prepare_cached("SELECT * FROM scheduled_transactions WHERE every_epoch")?
+                .query_and_then([], scheduled_transaction_from_row)?
+                .collect::<anyhow::Result<Vec<_>>>()?;
+            for schedule in schedules {
+                if schedule.status.is_terminal() {
+                    continue;
+                }
+                tx.execute(
+                    "UPDATE scheduled_transactions SET next_height = ?2 WHERE schedule_id = ?1",
+                    (
+                        schedule.id,
+                        schedule.align_to_epoch(next_epoch_start_height),
+                    ),
+                )?;
+            }
+            tx.commit()?;
+            anyhow::Ok(())
+        })
+        .await?
+    }
+
+    /// Records the transaction built for the occurrence of a scheduled transaction at `height`,
+    /// before it is broadcast, until the outcome of the occurrence is recorded.
+    pub async fn record_schedule_in_flight(
+        &self,
+        schedule_id: u64,
+        height: u64,
+        transaction: &Transaction,
+    ) -> anyhow::Result<()> {
+        let pool = self.pool.clone();
+        let tx_bytes = transaction.encode_to_vec();
+
+        spawn_blocking(move || {
+            pool.get()?.execute(
+                "INSERT OR REPLACE INTO scheduled_transactions_in_flight (schedule_id, height, tx_bytes)
+                    VALUES (?1, ?2, ?3)",
+                (schedule_id, height, tx_bytes),
+            )?;
+            anyhow::Ok(())
+        })
+        .await?
+    }
+
+    /// Returns the occurrences of scheduled transactions whose transaction was built, but whose
+    /// outcome was never recorded, with the height of each occurrence and its transaction.
+    pub async fn schedules_in_flight(
+        &self,
+    ) -> anyhow::Result<Vec<(ScheduledTransaction, u64, Transaction)>> {
+        let pool = self.pool.clone();
+
+        spawn_blocking(move || {
+            pool.get()?
+                .prepare_cached(
+                    "SELECT s.*, f.height AS in_flight_height, f.tx_bytes
+                    FROM scheduled_transactions_in_flight f
+                    JOIN scheduled_transactions s ON s.schedule_id = f.schedule_id
+                    ORDER BY f.schedule_id ASC",
+                )?
+                .query_and_then([], |row| {
+                    let tx_bytes: Vec<u8> = row.get("tx_bytes")?;
+                    anyhow::Ok((
+                        scheduled_transaction_from_row(row)?,
+                        row.get("in_flight_height")?,
+                        Transaction::decode(tx_bytes.as_slice())?,
+                    ))
+                })?
+                .collect()
+        })
+        .await?
+    }
+
+    /// Records the outcome of an occurrence of a scheduled transaction at `height`,
+    /// and advances the schedule to its next occurrence.
+    pub async fn record_schedule_execution(
+        &self,
+        schedule: &ScheduledTransaction,
+        height: u64,
+        transaction_id: Option<TransactionId>,
+        error: Option<String>,
+    ) -> anyhow::Result<()> {
+        let pool = self.pool.clone();
+        let schedule_id = schedule.id;
+        let (status, next_height) = schedule.advance(height);
+
+        spawn_blocking(move || {
+            let mut lock = pool.get()?;
+            let tx = lock.transaction()?;
+            tx.execute(
+                "INSERT INTO scheduled_transaction_executions (schedule_id, height, tx_hash, error)
+                    VALUES (?1, ?2, ?3, ?4)",
+                (
+                    schedule_id,
+                    height,
+                    transaction_id.map(|id| id.0.to_vec()),
+                    error,
+                ),
+            )?;
+            tx.execute(
+                "DELETE FROM scheduled_transactions_in_flight WHERE schedule_id = ?1",
+                [schedule_id],
+            )?;
+            // Leave the status alone unless the schedule completed, so that a schedule
+            // paused or cancelled while this occurrence was in flight stays that way.
+            tx.execute(
+                "UPDATE scheduled_transactions
+                    SET occurrences = occurrences + 1,
+                        next_height = ?2,
+                        status = CASE WHEN ?3 = 'completed' AND status != 'cancelled' THEN ?3 ELSE status END
+                    WHERE schedule_id = ?1",
+                (schedule_id, next_height, status.to_string()),
+            )?;
+            tx.commit()?;
+            anyhow::Ok(())
+        })
+        .await?
+    }
+
+    /// Returns the encoded state of each governance proposal as of the last time the governance
+    /// feed was marked as seen.
+    pub async fn seen_proposal_states(&self) -> anyhow::Result<BTreeMap<u64, Vec<u8>>> {
+        let pool = self.pool.clone();
+
+        spawn_blocking(move || {
+            pool.get()?
+                .prepare_cached("SELECT proposal_id, state FROM seen_proposal_states")?
+                .query_and_then([], |row| {
+                    let proposal_id: u64 = row.get("proposal_id")?;
+                    let state: Vec<u8> = row.get("state")?;
+                    anyhow::Ok((proposal_id, state))
+                })?
+                .collect()
+        })
+        .await?
+    }
+
+    /// Records the encoded states of governance proposals as seen.
+    pub async fn record_seen_proposal_states(
+        &self,
+        states: Vec<(u64, Vec<u8>)>,
+    ) -> anyhow::Result<()> {
+        let pool = self.pool.clone();
+
+        spawn_blocking(move || {
+            let mut lock = pool.get()?;
+            let tx = lock.transaction()?;
+            for (proposal_id, state) in states {
+                tx.execute(
+                    "INSERT OR REPLACE INTO seen_proposal_states (proposal_id, state) VALUES (?1, ?2)",
+                    (proposal_id, state),
+                )?;
+            }
+            tx.commit()?;
+            anyhow::Ok(())
+        })
+        .await?
+    }
+
+    /// Returns the governance proposals on chain, as of the last time they were updated during
+    /// sync.
+    pub(crate) async fn governance_proposals(
+        &self,
+    ) -> anyhow::Result<BTreeMap<u64, ProposalRecord>> {
+        let pool = self.pool.clone();
+
+        spawn_blocking(move || {
+            pool.get()?
+                .prepare_cached(
+                    "SELECT proposal_id, proposal, state, start_block_height, end_block_height, deposit_amount
+                    FROM governance_proposals",
+                )?
+                .query_and_then([], |row| {
+                    let proposal_id: u64 = row.get("proposal_id")?;
+                    let proposal: Vec<u8> = row.get("proposal")?;
+                    let state: Vec<u8> = row.get("state")?;
+                    let deposit_amount: Vec<u8> = row.get("deposit_amount")?;
+                    let record = ProposalRecord {
+                        proposal: Proposal::decode(proposal.as_slice())?,
+                        state: ProposalState::decode(state.as_slice())?,
+                        start_block_height: row.get("start_block_height")?,
+                        end_block_height: row.get("end_block_height")?,
+                        deposit_amount: Amount::decode(deposit_amount.as_slice())?,
+                    };
+                    anyhow::Ok((proposal_id, record))
+                })?
+                .collect()
+        })
+        .await?
+    }
+
+    /// Records a governance proposal, replacing any earlier record of it.
+    ///
+    /// If `rate_data` is given, it replaces the recorded rate data of the validators at the start
+    /// of the proposal.
+    pub(crate) async fn record_governance_proposal(
+        &self,
+        record: ProposalRecord,
+        rate_data: Option<Vec<RateData>>,
+    ) -> anyhow::Result<()> {
+        let pool = self.pool.clone();
+
+        spawn_blocking(move || {
+            let mut lock = pool.get()?;
+            let tx = lock.transaction()?;
+            let proposal_id = record.proposal.id;
+            tx.execute(
+                "INSERT OR REPLACE INTO governance_proposals
+                (proposal_id, proposal, state, start_block_height, end_block_height, deposit_amount)
+                VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
+                (
+                    proposal_id,
+                    record.proposal.encode_to_vec(),
+                    record.state.encode_to_vec(),
+                    record.start_block_height,
+                    record.end_block_height,
+                    record.deposit_amount.encode_to_vec(),
+                ),
+            )?;
+            if let Some(rate_data) = rate_data {
+                tx.execute(
+                    "DELETE FROM governance_proposal_rate_data WHERE proposal_id = ?1",
+                    [proposal_id],
+                )?;
+                for rate_data in rate_data {
+                    tx.execute(
+                        "INSERT INTO governance_proposal_rate_data (proposal_id, identity_key, rate_data)
+                        VALUES (?1, ?2, ?3)",
+                        (
+                            proposal_id,
+                            rate_data.identity_key.encode_to_vec(),
+                            rate_data.encode_to_vec(),
+                        ),
+                    )?;
+                }
+            }
+            tx.commit()?;
+            anyhow::Ok(())
+        })
+        .await?
+    }
+
+    /// Returns the rate data of the validators at the start of a governance proposal.
+    pub(crate) async fn governance_proposal_rate_data(
+        &self,
+        proposal_id: u64,
+    ) -> anyhow::Result<BTreeMap<IdentityKey, RateData>> {
+        let pool = self.pool.clone();
+
+        spawn_blocking(move || {
+            pool.get()?
+                .prepare_cached(
+                    "SELECT rate_data FROM governance_proposal_rate_data WHERE proposal_id = ?1",
+                )?
+                .query_and_then([proposal_id], |row| {
+                    let rate_data: Vec<u8> = row.get("rate_data")?;
+                    let rate_data = RateData::decode(rate_data.as_slice())?;
+                    anyhow::Ok((rate_data.identity_key.clone(), rate_data))
+                })?
+                .collect()
+        })
+        .await?
+    }
+
+    /// Records a vote cast by the wallet on a governance proposal, with the nullifier of the note
+    /// voted with, or without one for a vote cast as a proxy.
+    pub(crate) async fn record_governance_vote(
+        &self,
+        proposal_id: u64,
+        nullifier: Option<Nullifier>,
+        vote: Vote,
+    ) -> anyhow::Result<()> {
+        let pool = self.pool.clone();
+
+        spawn_blocking(move || {
+            pool.get()?.execute(
+                "INSERT OR IGNORE INTO governance_votes (proposal_id, nullifier, vote)
+                VALUES (?1, ?2, ?3)",
+                (
+                    proposal_id,
+                    nullifier.map(|nullifier| nullifier.to_bytes().to_vec()),
+                    vote.encode_to_vec(),
+                ),
+            )?;
+            anyhow::Ok(())
+        })
+        .await?
+    }
+
+    /// Returns the votes cast by the wallet on governance proposals, by proposal.
+    pub(crate) async fn governance_votes(&self) -> anyhow::Result<BTreeMap<u64, WalletVotes>> {
+        let pool = self.pool.clone();
+
+        spawn_blocking(move || {
+            let mut votes = BTreeMap::<u64, WalletVotes>::new();
+            let rows = pool
+                .get()?
+                .prepare_cached("SELECT proposal_id, nullifier, vote FROM governance_votes")?
+                .query_and_then([], |row| {
+                    let proposal_id: u64 = row.get("proposal_id")?;
+                    let nullifier: Option<Vec<u8>> = row.get("nullifier")?;
+                    let vote: Vec<u8> = row.get("vote")?;
+                    anyhow::Ok((
+                        proposal_id,
+                        nullifier.map(Nullifier::try_from).transpose()?,
+                        Vote::decode(vote.as_slice())?,
+                    ))
+                })?
+                .collect::<anyhow::Result<Vec<_>>>()?;
+            for (proposal_id, nullifier, vote) in rows {
+                let votes = votes.entry(proposal_id).or_default();
+                match nullifier {
+                    Some(nullifier) => {
+                        votes.delegator_votes.insert(nullifier, vote);
+                    }
+                    None => votes.proxy_vote = Some(vote),
+                }
+            }
+            anyhow::Ok(votes)
+        })
+        .await?
+    }
+
+    /// Returns the ICS-20 withdrawals sent by the wallet, in the order they were sent, restricted
+    /// to those in the transaction `transaction_id` if it is given.
+    pub async fn ics20_withdrawals(
+        &self,
+        transaction_id: Option<TransactionId>,
+    ) -> anyhow::Result<Vec<Ics20WithdrawalRecord>> {
+        let pool = self.pool.clone();
+
+        spawn_blocking(move || {
+            pool.get()?
+                .prepare_cached(
+                    "SELECT * FROM ics20_withdrawals
+                    WHERE ?1 IS NULL OR tx_hash = ?1
+                    ORDER BY height_sent ASC, tx_hash ASC, action_index ASC",
+                )?
+                .query_and_then([transaction_id.map(|id| id.0.to_vec())], |row| {
+                    let withdrawal: Vec<u8> = row.get("withdrawal")?;
+                    let tx_hash: [u8; 32] = row.get("tx_hash")?;
+                    let status: String = row.get("status")?;
+                    let refund_note_commitment: Option<Vec<u8>> =
+                        row.get("refund_note_commitment")?;
+                    anyhow::Ok(Ics20WithdrawalRecord {
+                        withdrawal: Ics20Withdrawal::decode(withdrawal.as_slice())?,
+                        transaction_id: TransactionId(tx_hash),
+                        height_sent: row.get("height_sent")?,
+                        sequence: row.get("sequence")?,
+                        status: Ics20WithdrawalStatus::from_str(&status)?,
+                        height_settled: row.get("height_settled")?,
+                        refund_note_commitment: refund_note_commitment
+                            .map(|bytes| StateCommitment::try_from(bytes.as_slice()))
+                            .transpose()?,
+                    })
+                })?
+                .collect()
+        })
+        .await?
+    }
+
+    /// Returns the ICS-20 withdrawals which are still in flight.
+    pub async fn pending_ics20_withdrawals(&self) -> anyhow::Result<Vec<PendingIcs20Withdrawal>> {
+        let pool = self.pool.clone();
+
+        spawn_blocking(move || {
+            pool.get()?
+                .prepare_cached(
+                    "SELECT tx_hash, action_index, channel_id, packet_commitment, sequence
+                    FROM ics20_withdrawals WHERE status = ?1",
+                )?
+                .query_and_then([Ics20WithdrawalStatus::Pending.to_string()], |row| {
+                    anyhow::Ok(PendingIcs20Withdrawal {
+                        tx_hash: row.get("tx_hash")?,
+                        action_index: row.get("action_index")?,
+                        channel_id: row.get("channel_id")?,
+                        packet_commitment: row.get("packet_commitment")?,
+                        sequence: row.get("sequence")?,
+                    })
+                })?
+                .collect()
+        })
+        .await?
+    }
+
+    /// Records the sequence number of a pending ICS-20 withdrawal's packet.
+    pub async fn record_ics20_withdrawal_sequence(
+        &self,
+        withdrawal: &PendingIcs20Withdrawal,
+        sequence: u64,
+    ) -> anyhow::Result<()> {
+        let pool = self.pool.clone();
+        let (tx_hash, action_index) = (withdrawal.tx_hash, withdrawal.action_index);
+
+        spawn_blocking(move || {
+            pool.get()?.execute(
+                "UPDATE ics20_withdrawals SET sequence = ?3 WHERE tx_hash = ?1 AND action_index = ?2",
+                (tx_hash, action_index, sequence),
+            )?;
+            anyhow::Ok(())
+        })
+        .await?
+    }
+
+    /// Records that a pending ICS-20 withdrawal was acknowledged by the counterparty, as of
+    /// `height`.
+    pub async fn record_ics20_withdrawal_acknowledged(
+        &self,
+        withdrawal: &PendingIcs20Withdrawal,
+        height: u64,
+    ) -> anyhow::Result<()> {
+        let pool = self.pool.clone();
+        let (tx_hash, action_index) = (withdrawal.tx_hash, withdrawal.action_index);
+
+        spawn_blocking(move || {
+            pool.get()?.execute(
+                "UPDATE ics20_withdrawals SET status = ?3, height_settled = ?4
+                WHERE tx_hash = ?1 AND action_index = ?2 AND status = ?5",
+                (
+                    tx_hash,
+                    action_index,
+                    Ics20WithdrawalStatus::Acknowledged.to_string(),
+                    height,
+                    Ics20WithdrawalStatus::Pending.to_string(),
+                ),
+            )?;
+            anyhow::Ok(())
+        })
+        .await?
+    }
+
+    /// Adds a forwarding account to the wallet's registry, replacing any existing account on the
+    /// same chain for the same address index.
+    pub async fn record_forwarding_account(
+        &self,
+        account: ForwardingAccount,
+    ) -> anyhow::Result<()> {
+        let pool = self.pool.clone();
+
+        spawn_blocking(move || {
+            pool.get()?.execute(
+                "INSERT OR REPLACE INTO forwarding_accounts
+                (chain_id, address_index, node, channel, forwarding_address, registered)
+                VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
+                (
+                    account.chain_id,
+                    account.address_index.to_bytes().to_vec(),
+                    account.node.to_string(),
+                    account.channel,
+                    account.forwarding_address,
+                    account.registered,
+                ),
+            )?;
+            anyhow::Ok(())
+        })
+        .await?
+    }
+
+    /// Returns the forwarding accounts in the wallet's registry, restricted to those on the chain
+    /// `chain_id` if it is given.
+    pub async fn forwarding_accounts(
+        &self,
+        chain_id: Option<String>,
+    ) -> anyhow::Result<Vec<ForwardingAccount>> {
+        let pool = self.pool.clone();
+
+        spawn_blocking(move || {
+            pool.get()?
+                .prepare_cached(
+                    "SELECT * FROM forwarding_accounts
+                    WHERE ?1 IS NULL OR chain_id = ?1
+                    ORDER BY chain_id ASC, address_index ASC",
+                )?
+                .query_and_then([chain_id], |row| {
+                    let address_index: Vec<u8> = row.get("address_index")?;
+                    let node: String = row.get("node")?;
+                    anyhow::Ok(ForwardingAccount {
+                        chain_id: row.get("chain_id")?,
+                        node: node.parse()?,
+                        channel: row.get("channel")?,
+                        address_index: AddressIndex::try_from(address_index.as_slice())?,
+                        forwarding_address: row.get("forwarding_address")?,
+                        registered: row.get("registered")?,
+                    })
+                })?
+                .collect()
+        })
+        .await?
+    }
+
+    /// Records that a forwarding account was registered on its counterparty chain.
+    pub async fn record_forwarding_account_registered(
+        &self,
+        chain_id: &str,
+        address_index: AddressIndex,
+    ) -> anyhow::Result<()> {
+        let pool = self.pool.clone();
+        let chain_id = chain_id.to_string();
+
+        spawn_blocking(move || {
+            pool.get()?.execute(
+                "UPDATE forwarding_accounts SET registered = TRUE
+                WHERE chain_id = ?1 AND address_index = ?2",
+                (chain_id, address_index.to_bytes().to_vec()),
+            )?;
+            anyhow::Ok(())
+        })
+        .await?
+    }
+
     pub async fn record_empty_block(&self, height: u64) -> anyhow::Result<()> {
         // Check that the incoming block height follows the latest recorded height
         let last_sync_height = self.last_sync_height().await?.ok_or_else(|| {
@@ -1480,6 +2261,38 @@ impl Storage {
                 )?;
             }
 
+            // Mark the withdrawals refunded by new notes. Notes minted for inbound transfers have
+            // the same source, but their sender is an address on the counterparty chain.
+            for note_record in filtered_block.new_notes.values() {
+                if let CommitmentSource::Ics20Transfer {
+                    packet_seq,
+                    channel_id,
+                    sender,
+                } = &note_record.source
+                {
+                    dbtx.execute(
+                        "UPDATE ics20_withdrawals
+                        SET status = ?1, sequence = ?2, height_settled = ?3, refund_note_commitment = ?4
+                        WHERE rowid = (
+                            SELECT rowid FROM ics20_withdrawals
+                            WHERE channel_id = ?5 AND sender = ?6 AND status = ?7
+                            AND (sequence IS NULL OR sequence = ?2)
+                            ORDER BY sequence IS NULL, height_sent ASC
+                            LIMIT 1
+                        )",
+                        (
+                            Ics20WithdrawalStatus::Refunded.to_string(),
+                            *packet_seq as i64,
+                            filtered_block.height as i64,
+                            note_record.note_commitment.0.to_bytes().to_vec(),
+                            channel_id,
+                            sender,
+                            Ics20WithdrawalStatus::Pending.to_string(),
+                        ),
+                    )?;
+                }
+            }
+
             // Insert new swap records into storage
             for swap in filtered_block.new_swaps.values() {
                 let swap_commitment = swap.swap_commitment.0.to_bytes().to_vec();
@@ -1608,6 +2421,67 @@ impl Storage {
                         (&nf_bytes, &tx_hash),
                     )?;
                 }
+
+                // Track the withdrawals refundable to our own addresses.
+                for (action_index, action) in transaction.actions().enumerate() {
+                    let penumbra_transaction::Action::Ics20Withdrawal(withdrawal) = action else {
+                        continue;
+                    };
+                    if fvk.address_index(&withdrawal.return_address).is_none() {
+                        continue;
+                    }
+                    let sender = FungibleTokenPacketData::from(withdrawal.clone()).sender;
+                    dbtx.execute(
+                        "INSERT OR IGNORE INTO ics20_withdrawals
+                        (tx_hash, action_index, withdrawal, channel_id, sender, packet_commitment, height_sent, sequence, status, height_settled, refund_note_commitment)
+                        VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, NULL, ?8, NULL, NULL)",
+                        (
+                            &tx_hash,
+                            action_index as i64,
+                            withdrawal.encode_to_vec(),
+                            withdrawal.source_channel.to_string(),
+                            sender,
+                            packet_commitment(withdrawal),
+                            tx_block_height,
+                            Ics20WithdrawalStatus::Pending.to_string(),
+                        ),
+                    )?;
+                }
+            }
+
+            // Record executions against our own liquidity positions, ignoring all others.
+            for execution in &filtered_block.position_executions {
+                dbtx.execute(
+                    "INSERT OR IGNORE INTO position_executions
+                    (position_id, height, prev_reserves_1, prev_reserves_2, reserves_1, reserves_2,
+                        inflow_1, inflow_2)
+                    SELECT ?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8
+                    WHERE EXISTS (SELECT 1 FROM positions WHERE position_id = ?1)",
+                    (
+                        execution.position_id.0.to_vec(),
+                        filtered_block.height as i64,
+                        execution.prev_reserves.r1.to_be_bytes().to_vec(),
+                        execution.prev_reserves.r2.to_be_bytes().to_vec(),
+                        execution.reserves.r1.to_be_bytes().to_vec(),
+                        execution.reserves.r2.to_be_bytes().to_vec(),
+                        execution.inflows.r1.to_be_bytes().to_vec(),
+                        execution.inflows.r2.to_be_bytes().to_vec(),
+                    ),
+                )?;
+            }
+
+            // Close our own liquidity positions that were evicted by the DEX.
+            for evicted in &filtered_block.evicted_positions {
+                dbtx.execute(
+                    "UPDATE positions SET position_state = ?1, height_evicted = ?2
+                    WHERE position_id = ?3 AND position_state = ?4",
+                    (
+                        State::Closed.to_string(),
+                        filtered_block.height as i64,
+                        evicted.position_id.0.to_vec(),
+                        State::Opened.to_string(),
+                    ),
+                )?;
             }
 
             // Update FMD parameters if they've changed.
@@ -1786,3 +2660,21 @@ impl Storage {
         .await?
     }
 }
+
+fn scheduled_transaction_from_row(
+    row: &r2d2_sqlite::rusqlite::Row<'_>,
+) -> anyhow::Result<ScheduledTransaction> {
+    let plan_request: Vec<u8> = row.get("plan_request")?;
+    let status: String = row.get("status")?;
+    Ok(ScheduledTransaction {
+        id: row.get("schedule_id")?,
+        plan_request: pb::TransactionPlannerRequest::decode(plan_request.as_slice())?,
+        start_height: row.get("start_height")?,
+        interval: row.get("interval")?,
+        max_occurrences: row.get("max_occurrences")?,
+        occurrences: row.get("occurrences")?,
+        next_height: row.get("next_height")?,
+        status: ScheduleStatus::from_str(&status)?,
+        every_epoch: row.get("every_epoch")?,
+    })
+}
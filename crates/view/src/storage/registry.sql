@@ -0,0 +1,61 @@
+-- The registry holds the records entered into the view service by the user,
+-- which can't be recovered by resynchronizing from the chain. It is kept in a
+-- separate database, attached as `registry`, which is left in place when the
+-- view database is reset.
+--
+-- The registry outlives changes to the view database's schema, so its tables
+-- are created only if they don't already exist, and must not be changed once
+-- released: add new tables instead.
+
+-- This table records the transactions scheduled to be planned and
+-- submitted by the view service, once, at a fixed interval, or at the
+-- start of every epoch.
+CREATE TABLE IF NOT EXISTS registry.scheduled_transactions (
+     schedule_id            INTEGER PRIMARY KEY,
+     -- the encoded `TransactionPlannerRequest` planned at each occurrence
+     plan_request           BLOB NOT NULL,
+     start_height           BIGINT NOT NULL,
+     interval               BIGINT NOT NULL,
+     max_occurrences        BIGINT NOT NULL,
+     occurrences            BIGINT NOT NULL,
+     next_height            BIGINT NOT NULL,
+     status                 TEXT NOT NULL,
+     every_epoch            BOOLEAN NOT NULL
+);
+
+-- This table records the outcome of each occurrence of a scheduled
+-- transaction: either the ID of the submitted transaction, or an error.
+CREATE TABLE IF NOT EXISTS registry.scheduled_transaction_executions (
+     schedule_id            INTEGER NOT NULL,
+     height                 BIGINT NOT NULL,
+     tx_hash                BLOB,
+     error                  TEXT
+);
+
+CREATE INDEX IF NOT EXISTS registry.scheduled_transaction_executions_idx ON scheduled_transaction_executions (schedule_id, height);
+
+-- This table records the transaction of each occurrence of a scheduled
+-- transaction which has been built but whose outcome has not been recorded
+-- yet, so that an occurrence interrupted by a restart is reconciled with the
+-- chain rather than executed again.
+CREATE TABLE IF NOT EXISTS registry.scheduled_transactions_in_flight (
+     schedule_id            INTEGER PRIMARY KEY,
+     height                 BIGINT NOT NULL,
+     -- the encoded, authorized `Transaction`
+     tx_bytes               BLOB NOT NULL
+);
+
+-- This table records the wallet's forwarding accounts on counterparty chains,
+-- which forward their deposits over IBC to one of the wallet's addresses.
+CREATE TABLE IF NOT EXISTS registry.forwarding_accounts (
+     chain_id               TEXT NOT NULL,
+     -- the address index of the wallet's address receiving the forwarded deposits
+     address_index          BLOB NOT NULL,
+     -- the gRPC endpoint of a counterparty chain node
+     node                   TEXT NOT NULL,
+     -- the counterparty chain's IBC channel to Penumbra
+     channel                TEXT NOT NULL,
+     forwarding_address     TEXT NOT NULL,
+     registered             BOOLEAN NOT NULL,
+     PRIMARY KEY (chain_id, address_index)
+);
@@ -128,7 +128,27 @@ CREATE INDEX swaps_nullifier_idx ON swaps (nullifier);
 CREATE TABLE positions (
      position_id            BLOB PRIMARY KEY NOT NULL,
      position_state         TEXT NOT NULL,
-     trading_pair           TEXT NOT NULL
+     trading_pair           TEXT NOT NULL,
+     -- the position as it was opened, including its initial reserves
+     position               BLOB NOT NULL,
+     height_opened          BIGINT NOT NULL,
+     height_withdrawn       BIGINT,
+     -- the height at which the position was closed by the DEX eviction mechanism
+     height_evicted         BIGINT
+);
+
+-- This table records the executions against the user's own positions,
+-- netted per block, as reported in the compact blocks.
+CREATE TABLE position_executions (
+     position_id            BLOB NOT NULL,
+     height                 BIGINT NOT NULL,
+     prev_reserves_1        BLOB NOT NULL,
+     prev_reserves_2        BLOB NOT NULL,
+     reserves_1             BLOB NOT NULL,
+     reserves_2             BLOB NOT NULL,
+     inflow_1               BLOB NOT NULL,
+     inflow_2               BLOB NOT NULL,
+     PRIMARY KEY (position_id, height)
 );
 
 -- This table records the user's own auction state, using the
@@ -140,3 +160,71 @@ CREATE TABLE auctions (
      auction_state          BIGINT NOT NULL,
      note_commitment        BLOB
 );
+
+-- This table records the state of each governance proposal as of the last
+-- time the governance feed was marked as seen, so that changes to it can be
+-- flagged.
+CREATE TABLE seen_proposal_states (
+     proposal_id            BIGINT PRIMARY KEY NOT NULL,
+     -- the encoded `ProposalState`
+     state                  BLOB NOT NULL
+);
+
+-- This table records the governance proposals on chain, as of the last time
+-- they were updated during sync.
+CREATE TABLE governance_proposals (
+     proposal_id            BIGINT PRIMARY KEY NOT NULL,
+     -- the encoded `Proposal`
+     proposal               BLOB NOT NULL,
+     -- the encoded `ProposalState`
+     state                  BLOB NOT NULL,
+     start_block_height     BIGINT NOT NULL,
+     end_block_height       BIGINT NOT NULL,
+     deposit_amount         BLOB NOT NULL
+);
+
+-- This table records the rate data of each validator at the start of each
+-- governance proposal which was voting when it was first synced, used to
+-- compute the voting power of the wallet's notes.
+CREATE TABLE governance_proposal_rate_data (
+     proposal_id            BIGINT NOT NULL,
+     identity_key           BLOB NOT NULL,
+     -- the encoded `RateData`
+     rate_data              BLOB NOT NULL,
+     PRIMARY KEY (proposal_id, identity_key)
+);
+
+-- This table records the votes cast by the wallet on governance proposals.
+CREATE TABLE governance_votes (
+     proposal_id            BIGINT NOT NULL,
+     -- the nullifier of the note voted with, or null for a vote cast as a proxy
+     nullifier              BLOB,
+     -- the encoded `Vote`
+     vote                   BLOB NOT NULL,
+     UNIQUE (proposal_id, nullifier)
+);
+
+-- This table records the ICS-20 withdrawals sent by the wallet, and what
+-- became of them: the status is 'pending' until the withdrawal is
+-- acknowledged by the counterparty, or refunded to its return address.
+CREATE TABLE ics20_withdrawals (
+     tx_hash                BLOB NOT NULL,
+     -- the index of the withdrawal among the transaction's actions
+     action_index           BIGINT NOT NULL,
+     -- the encoded `Ics20Withdrawal` action
+     withdrawal             BLOB NOT NULL,
+     channel_id             TEXT NOT NULL,
+     -- the sender of the withdrawal's packet, to which refunds are attributed
+     sender                 TEXT NOT NULL,
+     -- the commitment to the withdrawal's packet stored by the chain while it is in flight
+     packet_commitment      BLOB NOT NULL,
+     height_sent            BIGINT NOT NULL,
+     -- null until the sequence number of the withdrawal's packet is known
+     sequence               BIGINT,
+     status                 TEXT NOT NULL,
+     height_settled         BIGINT,
+     refund_note_commitment BLOB,
+     PRIMARY KEY (tx_hash, action_index)
+);
+
+CREATE INDEX ics20_withdrawals_by_channel_idx ON ics20_withdrawals (channel_id, status);
@@ -1,7 +1,11 @@
 use std::collections::BTreeMap;
 
 use penumbra_compact_block::{CompactBlock, StatePayload};
-use penumbra_dex::swap::{SwapPayload, SwapPlaintext};
+use penumbra_dex::{
+    event::EventPositionEvicted,
+    lp::PositionExecutionSummary,
+    swap::{SwapPayload, SwapPlaintext},
+};
 use penumbra_fee::GasPrices;
 use penumbra_keys::FullViewingKey;
 use penumbra_sct::Nullifier;
@@ -21,6 +25,10 @@ pub struct FilteredBlock {
     pub fmd_parameters: Option<fmd::Parameters>,
     pub app_parameters_updated: bool,
     pub gas_prices: Option<GasPrices>,
+    /// Executions against liquidity positions, which may or may not be ours.
+    pub position_executions: Vec<PositionExecutionSummary>,
+    /// Positions evicted by the DEX, which may or may not be ours.
+    pub evicted_positions: Vec<EventPositionEvicted>,
 }
 
 #[tracing::instrument(skip_all, fields(height = %height))]
@@ -37,6 +45,8 @@ pub async fn scan_block(
         swap_outputs,
         app_parameters_updated,
         gas_prices,
+        position_executions,
+        evicted_positions,
         // TODO: do we need this, or is there a bug in scan_block?
         // proposal_started,
         ..
@@ -235,6 +245,8 @@ pub async fn scan_block(
         fmd_parameters,
         app_parameters_updated,
         gas_prices,
+        position_executions,
+        evicted_positions,
     };
 
     Ok(result)
@@ -5,9 +5,15 @@ use std::{
 };
 
 use anyhow::Context;
+use futures::TryStreamExt;
+use ibc_proto::ibc::core::channel::v1::{
+    query_client::QueryClient as IbcChannelQueryClient, PacketState, QueryPacketCommitmentsRequest,
+};
+use ibc_types::core::channel::PortId;
 use penumbra_auction::auction::AuctionNft;
 use penumbra_compact_block::CompactBlock;
 use penumbra_dex::lp::{position, LpNft};
+use penumbra_governance::{proposal_state::State as ProposalState, Proposal};
 use penumbra_keys::FullViewingKey;
 use penumbra_proto::core::{
     app::v1::{
@@ -19,6 +25,11 @@ use penumbra_proto::core::{
             query_service_client::QueryServiceClient as CompactBlockQueryServiceClient,
             CompactBlockRangeRequest,
         },
+        governance::v1::{
+            query_service_client::QueryServiceClient as GovernanceQueryServiceClient,
+            ProposalDataRequest, ProposalListRequest, ProposalListResponse,
+            ProposalRateDataRequest,
+        },
         shielded_pool::v1::{
             query_service_client::QueryServiceClient as ShieldedPoolQueryServiceClient,
             AssetMetadataByIdRequest,
@@ -26,6 +37,7 @@ use penumbra_proto::core::{
     },
 };
 use penumbra_sct::{CommitmentSource, Nullifier};
+use penumbra_stake::{rate::RateData, GovernanceKey};
 use penumbra_transaction::Transaction;
 use tap::Tap;
 use tokio::sync::{watch, RwLock};
@@ -33,6 +45,9 @@ use tonic::transport::Channel;
 use tracing::instrument;
 
 use crate::{
+    governance_feed::ProposalRecord,
+    ics20_withdrawal_record::{pending_ics20_withdrawal_update, PendingIcs20WithdrawalUpdate},
+    storage::PendingIcs20Withdrawal,
     sync::{scan_block, FilteredBlock},
     Storage,
 };
@@ -40,6 +55,9 @@ use crate::{
 // The maximum size of a compact block, in bytes (12MB).
 const MAX_CB_SIZE_BYTES: usize = 12 * 1024 * 1024;
 
+// The number of blocks between checks on the packet commitments of in-flight ICS-20 withdrawals.
+const ICS20_WITHDRAWAL_CHECK_INTERVAL: u64 = 10;
+
 pub struct Worker {
     storage: Storage,
     sct: Arc<RwLock<penumbra_tct::Tree>>,
@@ -223,16 +241,30 @@ impl Worker {
 
         let mut expected_height = start_height;
 
+        // The governance proposals are updated once caught up with the chain, if a proposal may
+        // have started or changed state in the blocks synced since the last update.
+        let mut governance_stale = true;
+        let mut proposal_end_heights = BTreeSet::new();
+        let proxy_key = GovernanceKey(*self.fvk.spend_verification_key());
+        // The height at which the in-flight ICS-20 withdrawals were last checked on, if any.
+        let mut ics20_withdrawals_checked: Option<u64> = None;
+
         while let Some(block) = buffered_stream.recv().await {
             let block: CompactBlock = block?.try_into()?;
 
             let height = block.height;
+            let ends_epoch = block.epoch_root.is_some();
             if height != expected_height {
                 tracing::warn!("out of order block detected");
                 continue;
             }
             expected_height += 1;
 
+            // Proposals can be withdrawn at any time, so check on them at least once per epoch.
+            if block.proposal_started || ends_epoch || proposal_end_heights.contains(&height) {
+                governance_stale = true;
+            }
+
             // Lock the SCT only while processing this block.
             let mut sct_guard = self.sct.write().await;
 
@@ -287,7 +319,10 @@ impl Worker {
 
                                 // Record the position itself
                                 self.storage
-                                    .record_position(position_open.position.clone())
+                                    .record_position(
+                                        position_open.position.clone(),
+                                        filtered_block.height,
+                                    )
                                     .await?;
                             }
                             penumbra_transaction::Action::PositionClose(position_close) => {
@@ -295,7 +330,11 @@ impl Worker {
 
                                 // Update the position record
                                 self.storage
-                                    .update_position(position_id, position::State::Closed)
+                                    .update_position(
+                                        position_id,
+                                        position::State::Closed,
+                                        filtered_block.height,
+                                    )
                                     .await?;
                             }
                             penumbra_transaction::Action::PositionWithdraw(position_withdraw) => {
@@ -310,7 +349,9 @@ impl Worker {
                                 self.storage.record_asset(denom).await?;
 
                                 // Update the position record
-                                self.storage.update_position(position_id, state).await?;
+                                self.storage
+                                    .update_position(position_id, state, filtered_block.height)
+                                    .await?;
                             }
                             penumbra_transaction::Action::ActionDutchAuctionSchedule(
                                 schedule_da,
@@ -352,6 +393,35 @@ impl Worker {
                                     .record_auction_with_state(auction_id, withdraw_da.seq)
                                     .await?;
                             }
+                            penumbra_transaction::Action::DelegatorVote(delegator_vote) => {
+                                let body = &delegator_vote.body;
+                                // Only record votes cast with the wallet's own notes.
+                                if self
+                                    .storage
+                                    .note_by_nullifier(body.nullifier, false)
+                                    .await
+                                    .is_ok()
+                                {
+                                    self.storage
+                                        .record_governance_vote(
+                                            body.proposal,
+                                            Some(body.nullifier),
+                                            body.vote,
+                                        )
+                                        .await?;
+                                }
+                            }
+                            penumbra_transaction::Action::ProxyVote(proxy_vote) => {
+                                if proxy_vote.body.proxy == proxy_key {
+                                    self.storage
+                                        .record_governance_vote(
+                                            proxy_vote.body.proposal,
+                                            None,
+                                            proxy_vote.body.vote,
+                                        )
+                                        .await?;
+                                }
+                            }
                             _ => (),
                         };
                     }
@@ -419,6 +489,35 @@ impl Worker {
             // Release the SCT RwLock
             drop(sct_guard);
 
+            // Epochs may end early, so epoch-aligned schedules are brought forward to the start
+            // of the next epoch once the current one ends.
+            if ends_epoch {
+                self.storage.align_epoch_schedules(height + 1).await?;
+            }
+
+            // Once caught up with the chain, check on any ICS-20 withdrawals still in flight.
+            if buffered_stream.is_empty() {
+                // Withdrawals take several blocks to settle, so only query the chain for them
+                // every few blocks.
+                if ics20_withdrawals_checked.map_or(true, |checked| {
+                    height >= checked + ICS20_WITHDRAWAL_CHECK_INTERVAL
+                }) {
+                    ics20_withdrawals_checked = Some(height);
+                    if let Err(e) = self.update_ics20_withdrawals(height).await {
+                        tracing::warn!(?e, "failed to update ics20 withdrawal statuses");
+                    }
+                }
+                if governance_stale {
+                    match self.update_governance_proposals().await {
+                        Ok(end_heights) => {
+                            proposal_end_heights = end_heights;
+                            governance_stale = false;
+                        }
+                        Err(e) => tracing::warn!(?e, "failed to update governance proposals"),
+                    }
+                }
+            }
+
             // Check if we should stop waiting for blocks to arrive, because the view
             // services are dropped and we're supposed to shut down.
             if self.sync_height_tx.is_closed() {
@@ -429,6 +528,179 @@ impl Worker {
         Ok(())
     }
 
+    /// Updates the pending ICS-20 withdrawals against the packet commitments on their channels.
+    ///
+    /// A withdrawal's packet commitment is stored until the packet is acknowledged or times out.
+    /// Refunds are detected when scanning blocks, so a commitment that has been deleted as of a
+    /// height we have already synced means the withdrawal was acknowledged.
+    async fn update_ics20_withdrawals(&self, sync_height: u64) -> anyhow::Result<()> {
+        let pending = self.storage.pending_ics20_withdrawals().await?;
+        if pending.is_empty() {
+            return Ok(());
+        }
+
+        let mut client = IbcChannelQueryClient::new(self.channel.clone());
+        let channel_ids = pending
+            .iter()
+            .map(|withdrawal| withdrawal.channel_id.clone())
+            .collect::<BTreeSet<_>>();
+
+        for channel_id in channel_ids {
+            let response = client
+                .packet_commitments(QueryPacketCommitmentsRequest {
+                    port_id: PortId::transfer().to_string(),
+                    channel_id: channel_id.clone(),
+                    pagination: None,
+                })
+                .await?
+                .into_inner();
+            let query_height = response
+                .height
+                .map(|height| height.revision_height)
+                .context("missing height in packet commitments response")?;
+
+            let withdrawals = pending
+                .iter()
+                .filter(|withdrawal| withdrawal.channel_id == channel_id);
+            for withdrawal in withdrawals {
+                self.update_ics20_withdrawal(
+                    withdrawal,
+                    &response.commitments,
+                    query_height,
+                    sync_height,
+                )
+                .await?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Updates the recorded governance proposals, returning the end heights of the proposals
+    /// whose voting has yet to conclude.
+    ///
+    /// Only the proposals which are new, or whose state has changed since they were recorded, are
+    /// fetched in full.
+    async fn update_governance_proposals(&self) -> anyhow::Result<BTreeSet<u64>> {
+        let mut client = GovernanceQueryServiceClient::new(self.channel.clone());
+        let mut recorded = self.storage.governance_proposals().await?;
+
+        // The first time, also pick up the proposals which concluded before the wallet synced;
+        // after that, only unfinished proposals can change state.
+        let listed: Vec<ProposalListResponse> = client
+            .proposal_list(ProposalListRequest {
+                inactive: recorded.is_empty(),
+            })
+            .await?
+            .into_inner()
+            .try_collect()
+            .await?;
+
+        let mut listed_ids = BTreeSet::new();
+        let mut changed = BTreeSet::new();
+        for response in listed {
+            let proposal: Proposal = response
+                .proposal
+                .context("missing proposal in ProposalListResponse")?
+                .try_into()?;
+            let state: ProposalState = response
+                .state
+                .context("missing state in ProposalListResponse")?
+                .try_into()?;
+            if recorded.get(&proposal.id).map(|record| &record.state) != Some(&state) {
+                changed.insert(proposal.id);
+            }
+            listed_ids.insert(proposal.id);
+        }
+        // Unfinished proposals which are no longer listed have concluded since they were recorded.
+        changed.extend(
+            recorded
+                .iter()
+                .filter(|(id, record)| record.is_unfinished() && !listed_ids.contains(id))
+                .map(|(id, _)| *id),
+        );
+
+        for proposal_id in changed {
+            let response = client
+                .proposal_data(ProposalDataRequest { proposal_id })
+                .await?
+                .into_inner();
+            let record = ProposalRecord {
+                proposal: response
+                    .proposal
+                    .context("missing proposal in ProposalDataResponse")?
+                    .try_into()?,
+                state: response
+                    .state
+                    .context("missing state in ProposalDataResponse")?
+                    .try_into()?,
+                start_block_height: response.start_block_height,
+                end_block_height: response.end_block_height,
+                deposit_amount: response
+                    .proposal_deposit_amount
+                    .context("missing deposit amount in ProposalDataResponse")?
+                    .try_into()?,
+            };
+
+            // The rate data at the start of a proposal never changes, and is only needed to count
+            // the voting power of the wallet's notes while the proposal is voting.
+            let rate_data = if record.state.is_voting() && !recorded.contains_key(&proposal_id) {
+                let rate_data: Vec<RateData> = client
+                    .proposal_rate_data(ProposalRateDataRequest { proposal_id })
+                    .await?
+                    .into_inner()
+                    .map_err(anyhow::Error::from)
+                    .and_then(|response| async move {
+                        RateData::try_from(
+                            response
+                                .rate_data
+                                .context("missing rate data in ProposalRateDataResponse")?,
+                        )
+                    })
+                    .try_collect()
+                    .await?;
+                Some(rate_data)
+            } else {
+                None
+            };
+
+            self.storage
+                .record_governance_proposal(record.clone(), rate_data)
+                .await?;
+            recorded.insert(proposal_id, record);
+        }
+
+        Ok(recorded
+            .values()
+            .filter(|record| record.is_unfinished())
+            .map(|record| record.end_block_height)
+            .collect())
+    }
+
+    async fn update_ics20_withdrawal(
+        &self,
+        withdrawal: &PendingIcs20Withdrawal,
+        commitments: &[PacketState],
+        query_height: u64,
+        sync_height: u64,
+    ) -> anyhow::Result<()> {
+        match pending_ics20_withdrawal_update(withdrawal, commitments, query_height, sync_height) {
+            Some(PendingIcs20WithdrawalUpdate::Sequence(sequence)) => {
+                self.storage
+                    .record_ics20_withdrawal_sequence(withdrawal, sequence)
+                    .await?;
+            }
+            Some(PendingIcs20WithdrawalUpdate::Acknowledged) => {
+                self.storage
+                    .record_ics20_withdrawal_acknowledged(withdrawal, query_height)
+                    .await?;
+            }
+            None => {}
+        }
+
+        Ok(())
+    }
+
     pub async fn run(mut self) -> anyhow::Result<()> {
         loop {
             // Do a single sync run, recording any errors.
@@ -119,6 +119,7 @@ fn main() -> anyhow::Result<()> {
                 "../../proto/rust-vendored/tendermint/abci/types.proto",
                 "../../proto/rust-vendored/tendermint/types/validator.proto",
                 "../../proto/rust-vendored/tendermint/p2p/types.proto",
+                "../../proto/rust-vendored/cosmos/auth/v1beta1/query.proto",
                 "../../proto/rust-vendored/cosmos/bank/v1beta1/query.proto",
                 "../../proto/rust-vendored/cosmos/tx/v1beta1/service.proto",
                 "../../proto/rust-vendored/cosmos/tx/v1beta1/tx.proto",
@@ -126,6 +127,7 @@ fn main() -> anyhow::Result<()> {
                 "../../proto/rust-vendored/cosmos/tx/signing/v1beta1/signing.proto",
                 "../../proto/rust-vendored/cosmos/base/abci/v1beta1/abci.proto",
                 "../../proto/rust-vendored/cosmos/crypto/multisig/v1beta1/multisig.proto",
+                "../../proto/rust-vendored/cosmos/crypto/secp256k1/keys.proto",
                 "../../proto/rust-vendored/ibc/applications/transfer/v1/query.proto",
                 "../../proto/rust-vendored/ibc/core/channel/v1/query.proto",
                 "../../proto/rust-vendored/ibc/core/client/v1/query.proto",
@@ -160,6 +162,8 @@ fn main() -> anyhow::Result<()> {
             ".penumbra.util.tendermint_proxy.v1.ABCIQueryResponse".to_owned(),
             ".penumbra.util.tendermint_proxy.v1.GetBlockByHeightResponse".to_owned(),
             ".penumbra.util.tendermint_proxy.v1.GetStatusResponse".to_owned(),
+            ".penumbra.util.tendermint_proxy.v1.GetValidatorSetResponse".to_owned(),
+            ".penumbra.util.tendermint_proxy.v1.SearchTxEventsResponse".to_owned(),
         ])
         .build(&[".penumbra"])?;
 